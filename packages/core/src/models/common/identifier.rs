@@ -26,3 +26,21 @@ pub enum IdentifierType {
     ISNI,
     IPI,
 }
+
+impl std::str::FromStr for IdentifierType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_uppercase().as_str() {
+            "PROPRIETARY" | "PROPRIETARYID" => Ok(IdentifierType::Proprietary),
+            "ISRC" => Ok(IdentifierType::ISRC),
+            "ISWC" => Ok(IdentifierType::ISWC),
+            "UPC" | "ICPN" => Ok(IdentifierType::UPC),
+            "EAN" => Ok(IdentifierType::EAN),
+            "GRID" => Ok(IdentifierType::GRID),
+            "ISNI" => Ok(IdentifierType::ISNI),
+            "IPI" => Ok(IdentifierType::IPI),
+            other => Err(format!("Unknown identifier type: {}", other)),
+        }
+    }
+}
@@ -18,3 +18,11 @@ impl LocalizedString {
         }
     }
 }
+
+/// Free-text descriptive element such as a `MarketingComment` or `Synopsis`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Description {
+    /// Element name the text came from (e.g. "MarketingComment")
+    pub description_type: String,
+    pub text: LocalizedString,
+}
@@ -6,5 +6,5 @@ mod localized;
 mod territory;
 
 pub use identifier::{Identifier, IdentifierType};
-pub use localized::LocalizedString;
+pub use localized::{Description, LocalizedString};
 pub use territory::{Copyright, Price, TerritoryCode, ValidityPeriod};
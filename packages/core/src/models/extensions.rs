@@ -49,6 +49,17 @@ pub struct XmlFragment {
     pub preserve_formatting: bool,
 }
 
+impl Drop for XmlFragment {
+    /// Drop descendants from a flat list rather than recursively, so that
+    /// deeply nested extensions cannot overflow the stack on the way out
+    fn drop(&mut self) {
+        let mut pending = std::mem::take(&mut self.children);
+        while let Some(mut fragment) = pending.pop() {
+            pending.append(&mut fragment.children);
+        }
+    }
+}
+
 /// Position of a comment relative to its parent element
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum CommentPosition {
@@ -202,86 +213,111 @@ impl XmlFragment {
         self.preserve_formatting = true;
     }
 
-    /// Get the canonical XML representation with proper formatting
+    /// Get the canonical XML representation with proper formatting.
+    /// Nested fragments are written from an explicit stack rather than by
+    /// recursion, so arbitrarily deep extensions cannot overflow the stack.
     pub fn to_canonical_xml(&self, indent_level: usize) -> String {
-        if self.preserve_formatting {
-            return self.raw_content.clone();
+        /// Work left to do, popped from the end
+        enum Pending<'a> {
+            Fragment(&'a XmlFragment, usize),
+            Markup(String),
         }
 
-        let indent = "  ".repeat(indent_level);
         let mut xml = String::new();
+        let mut pending = vec![Pending::Fragment(self, indent_level)];
+
+        while let Some(next) = pending.pop() {
+            let (fragment, level) = match next {
+                Pending::Fragment(fragment, level) => (fragment, level),
+                Pending::Markup(markup) => {
+                    xml.push_str(&markup);
+                    continue;
+                }
+            };
 
-        // Opening tag
-        xml.push_str(&format!("{}<{}", indent, self.qualified_name()));
-
-        // Namespace declarations (sorted for determinism)
-        let mut sorted_ns: Vec<_> = self.namespace_declarations.iter().collect();
-        sorted_ns.sort_by_key(|(prefix, _)| prefix.as_str());
-
-        for (prefix, uri) in sorted_ns {
-            if prefix.is_empty() {
-                xml.push_str(&format!(" xmlns=\"{}\"", uri));
-            } else {
-                xml.push_str(&format!(" xmlns:{}=\"{}\"", prefix, uri));
+            if fragment.preserve_formatting {
+                xml.push_str(&fragment.raw_content);
+                continue;
             }
-        }
 
-        // Attributes (sorted for determinism)
-        let mut sorted_attrs: Vec<_> = self.attributes.iter().collect();
-        sorted_attrs.sort_by_key(|(name, _)| name.as_str());
+            let indent = "  ".repeat(level);
 
-        for (name, value) in sorted_attrs {
-            xml.push_str(&format!(
-                " {}=\"{}\"",
-                name,
-                html_escape::encode_double_quoted_attribute(value)
-            ));
-        }
+            // Opening tag
+            xml.push_str(&format!("{}<{}", indent, fragment.qualified_name()));
 
-        if let Some(ref text) = self.text_content {
-            // Element with text content
-            xml.push('>');
-            xml.push_str(&html_escape::encode_text(text));
-            xml.push_str(&format!("</{}>", self.qualified_name()));
-        } else if self.children.is_empty()
-            && self.processing_instructions.is_empty()
-            && self.comments.is_empty()
-        {
-            // Self-closing element
-            xml.push_str("/>");
-        } else {
-            // Element with children
-            xml.push_str(">\n");
-
-            // Processing instructions
-            for pi in &self.processing_instructions {
-                xml.push_str(&format!("{}  <?{}", indent, pi.target));
-                if let Some(ref data) = pi.data {
-                    xml.push(' ');
-                    xml.push_str(data);
+            // Namespace declarations (sorted for determinism)
+            let mut sorted_ns: Vec<_> = fragment.namespace_declarations.iter().collect();
+            sorted_ns.sort_by_key(|(prefix, _)| prefix.as_str());
+
+            for (prefix, uri) in sorted_ns {
+                if prefix.is_empty() {
+                    xml.push_str(&format!(" xmlns=\"{}\"", uri));
+                } else {
+                    xml.push_str(&format!(" xmlns:{}=\"{}\"", prefix, uri));
                 }
-                xml.push_str("?>\n");
             }
 
-            // Comments
-            for comment in &self.comments {
-                let comment_indent = match comment.position {
-                    CommentPosition::Before | CommentPosition::After => indent.clone(),
-                    CommentPosition::FirstChild | CommentPosition::LastChild => {
-                        format!("{}  ", indent)
-                    }
-                    CommentPosition::Inline => String::new(),
-                };
-                xml.push_str(&format!("{}{}\n", comment_indent, comment.to_xml()));
-            }
+            // Attributes (sorted for determinism)
+            let mut sorted_attrs: Vec<_> = fragment.attributes.iter().collect();
+            sorted_attrs.sort_by_key(|(name, _)| name.as_str());
 
-            // Child elements
-            for child in &self.children {
-                xml.push_str(&child.to_canonical_xml(indent_level + 1));
-                xml.push('\n');
+            for (name, value) in sorted_attrs {
+                xml.push_str(&format!(
+                    " {}=\"{}\"",
+                    name,
+                    html_escape::encode_double_quoted_attribute(value)
+                ));
             }
 
-            xml.push_str(&format!("{}</{}>", indent, self.qualified_name()));
+            if let Some(ref text) = fragment.text_content {
+                // Element with text content
+                xml.push('>');
+                xml.push_str(&html_escape::encode_text(text));
+                xml.push_str(&format!("</{}>", fragment.qualified_name()));
+            } else if fragment.children.is_empty()
+                && fragment.processing_instructions.is_empty()
+                && fragment.comments.is_empty()
+            {
+                // Self-closing element
+                xml.push_str("/>");
+            } else {
+                // Element with children
+                xml.push_str(">\n");
+
+                // Processing instructions
+                for pi in &fragment.processing_instructions {
+                    xml.push_str(&format!("{}  <?{}", indent, pi.target));
+                    if let Some(ref data) = pi.data {
+                        xml.push(' ');
+                        xml.push_str(data);
+                    }
+                    xml.push_str("?>\n");
+                }
+
+                // Comments
+                for comment in &fragment.comments {
+                    let comment_indent = match comment.position {
+                        CommentPosition::Before | CommentPosition::After => indent.clone(),
+                        CommentPosition::FirstChild | CommentPosition::LastChild => {
+                            format!("{}  ", indent)
+                        }
+                        CommentPosition::Inline => String::new(),
+                    };
+                    xml.push_str(&format!("{}{}\n", comment_indent, comment.to_xml()));
+                }
+
+                // Child elements, each followed by a newline, then the
+                // closing tag; pushed in reverse as the stack pops from the end
+                pending.push(Pending::Markup(format!(
+                    "{}</{}>",
+                    indent,
+                    fragment.qualified_name()
+                )));
+                for child in fragment.children.iter().rev() {
+                    pending.push(Pending::Markup("\n".to_string()));
+                    pending.push(Pending::Fragment(child, level + 1));
+                }
+            }
         }
 
         xml
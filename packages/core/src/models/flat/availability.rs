@@ -0,0 +1,116 @@
+// core/src/models/flat/availability.rs
+//! Per-territory release availability merged from deals
+
+use super::ParsedDeal;
+use chrono::{DateTime, Utc};
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+/// Territory code a deal uses to cover every territory
+pub const WORLDWIDE: &str = "Worldwide";
+
+/// Availability of a release in one territory, merged across its deals
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TerritoryAvailability {
+    pub available: bool,
+    /// Earliest start of the deals making the release available here;
+    /// `None` if unavailable or if one of those deals has no start
+    pub start_date: Option<DateTime<Utc>>,
+    /// Commercial models offered here, in deal order without duplicates
+    pub commercial_models: Vec<String>,
+}
+
+/// Release reference -> territory code -> availability
+///
+/// Every territory a release's deals name, included or excluded, gets its
+/// own entry, and the `Worldwide` entry stands for all the others.
+/// [`AvailabilityMap::territory`] applies that fallback. Availability is
+/// what the deals grant, not whether their validity period has begun.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AvailabilityMap {
+    pub releases: IndexMap<String, IndexMap<String, TerritoryAvailability>>,
+}
+
+impl AvailabilityMap {
+    /// Merge all deals referencing each of `release_ids`; a release without
+    /// deals gets an empty entry
+    pub fn from_deals<'a>(
+        release_ids: impl IntoIterator<Item = &'a str>,
+        deals: &[ParsedDeal],
+    ) -> Self {
+        let releases = release_ids
+            .into_iter()
+            .map(|release_id| {
+                let deals: Vec<&ParsedDeal> = deals
+                    .iter()
+                    .filter(|deal| deal.releases.iter().any(|r| r == release_id))
+                    .collect();
+                (release_id.to_string(), Self::territories(&deals))
+            })
+            .collect();
+        Self { releases }
+    }
+
+    /// Availability of a release in `territory`, falling back to its
+    /// `Worldwide` entry for territories no deal names
+    pub fn territory(&self, release_id: &str, territory: &str) -> Option<&TerritoryAvailability> {
+        let territories = self.releases.get(release_id)?;
+        territories
+            .get(territory)
+            .or_else(|| territories.get(WORLDWIDE))
+    }
+
+    /// Whether any deal makes the release available in `territory`
+    pub fn is_available(&self, release_id: &str, territory: &str) -> bool {
+        self.territory(release_id, territory)
+            .is_some_and(|availability| availability.available)
+    }
+
+    fn territories(deals: &[&ParsedDeal]) -> IndexMap<String, TerritoryAvailability> {
+        let mut territories: IndexMap<String, TerritoryAvailability> = IndexMap::new();
+        for deal in deals {
+            for code in deal
+                .territories
+                .included
+                .iter()
+                .chain(&deal.territories.excluded)
+            {
+                territories.entry(code.clone()).or_default();
+            }
+        }
+
+        for (code, availability) in territories.iter_mut() {
+            let covering: Vec<&&ParsedDeal> = deals
+                .iter()
+                .filter(|deal| Self::covers(deal, code))
+                .collect();
+            if covering.is_empty() {
+                continue;
+            }
+
+            availability.available = true;
+            availability.start_date = covering
+                .iter()
+                .map(|deal| deal.validity.start)
+                .collect::<Option<Vec<_>>>()
+                .and_then(|starts| starts.into_iter().min());
+            for model in covering.iter().flat_map(|deal| &deal.commercial_models) {
+                if !availability.commercial_models.contains(model) {
+                    availability.commercial_models.push(model.clone());
+                }
+            }
+        }
+        territories
+    }
+
+    /// Whether `deal` grants `territory`: named outright, or covered by a
+    /// worldwide deal that doesn't exclude it
+    fn covers(deal: &ParsedDeal, territory: &str) -> bool {
+        let included = |code: &str| deal.territories.included.iter().any(|c| c == code);
+        if territory == WORLDWIDE {
+            return included(WORLDWIDE);
+        }
+        let excluded = deal.territories.excluded.iter().any(|c| c == territory);
+        !excluded && (included(territory) || included(WORLDWIDE))
+    }
+}
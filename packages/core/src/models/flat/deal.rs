@@ -15,12 +15,42 @@ pub struct ParsedDeal {
     pub pricing: Vec<PriceTier>,
     pub usage_rights: Vec<String>,
     pub restrictions: Vec<String>,
+    /// `CommercialModelType`s of the deal, e.g. "SubscriptionModel"
+    #[serde(default)]
+    pub commercial_models: Vec<String>,
+    /// Typed view of `usage_rights` and `restrictions`: one entry per use
+    /// type and commercial model of the deal
+    #[serde(default)]
+    pub rights: Vec<UsageRight>,
+}
+
+/// Whether a deal grants or withdraws a use
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UsageRight {
+    /// `UseType`, e.g. "OnDemandStream" or "PermanentDownload"
+    pub use_type: String,
+    /// `CommercialModelType` the use is offered under, if the deal names one
+    pub commercial_model: Option<String>,
+    pub allowance: UsageAllowance,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UsageAllowance {
+    Allowed,
+    /// Withdrawn by a `TakeDown` deal
+    Disallowed,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DealValidity {
     pub start: Option<DateTime<Utc>>,
     pub end: Option<DateTime<Utc>>,
+    /// `StartDate` as written in the source, for re-emitting it unchanged
+    #[serde(default)]
+    pub start_text: Option<String>,
+    /// `EndDate` as written in the source
+    #[serde(default)]
+    pub end_text: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -43,6 +73,9 @@ pub struct PriceTier {
     pub territory: Option<String>,
     pub start_date: Option<DateTime<Utc>>,
     pub end_date: Option<DateTime<Utc>>,
+    /// The amount as written in the source, e.g. `9.90`
+    #[serde(default)]
+    pub amount_text: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -0,0 +1,91 @@
+// core/src/models/flat/index.rs
+//! Identifier index for release/resource lookups
+
+use crate::models::common::{Identifier, IdentifierType};
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+/// Maps every identifier carried by a release or resource to the entity it
+/// belongs to, so callers can match entities across messages without scanning.
+///
+/// Keys are normalized so that equivalent schemes resolve to the same entry:
+/// UPC and EAN are both ICPNs (leading zeros are ignored), GRid/GRID share a
+/// key, and ISRC/GRid values are compared case- and hyphen-insensitively.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct IdentifierIndex {
+    /// Normalized identifier key -> position in `FlattenedMessage::releases`
+    pub releases: IndexMap<String, usize>,
+    /// Normalized identifier key -> resource reference
+    pub resources: IndexMap<String, String>,
+}
+
+impl IdentifierIndex {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register all identifiers of the release at `position`
+    pub fn insert_release(&mut self, identifiers: &[Identifier], position: usize) {
+        for id in identifiers {
+            if let Some(key) = Self::key(&id.id_type, &id.value) {
+                self.releases.entry(key).or_insert(position);
+            }
+        }
+    }
+
+    /// Register all identifiers of the resource with the given reference
+    pub fn insert_resource(&mut self, identifiers: &[Identifier], resource_reference: &str) {
+        for id in identifiers {
+            if let Some(key) = Self::key(&id.id_type, &id.value) {
+                self.resources
+                    .entry(key)
+                    .or_insert_with(|| resource_reference.to_string());
+            }
+        }
+    }
+
+    pub fn release_position(&self, id_type: &IdentifierType, value: &str) -> Option<usize> {
+        Self::key(id_type, value).and_then(|key| self.releases.get(&key).copied())
+    }
+
+    pub fn resource_reference(&self, id_type: &IdentifierType, value: &str) -> Option<&str> {
+        Self::key(id_type, value)
+            .and_then(|key| self.resources.get(&key))
+            .map(String::as_str)
+    }
+
+    /// Build the normalized lookup key for an identifier, or `None` if the
+    /// value is empty.
+    pub fn key(id_type: &IdentifierType, value: &str) -> Option<String> {
+        let value = value.trim();
+        if value.is_empty() {
+            return None;
+        }
+
+        let key = match id_type {
+            IdentifierType::UPC | IdentifierType::EAN => {
+                let digits = if value.chars().all(|c| c.is_ascii_digit()) {
+                    value.trim_start_matches('0')
+                } else {
+                    value
+                };
+                format!("ICPN:{}", digits)
+            }
+            IdentifierType::GRID | IdentifierType::GRid => {
+                format!("GRID:{}", Self::compact(value))
+            }
+            IdentifierType::ISRC => format!("ISRC:{}", Self::compact(value)),
+            other => format!("{:?}:{}", other, value),
+        };
+
+        Some(key)
+    }
+
+    fn compact(value: &str) -> String {
+        value
+            .chars()
+            .filter(|c| *c != '-' && !c.is_whitespace())
+            .map(|c| c.to_ascii_uppercase())
+            .collect()
+    }
+}
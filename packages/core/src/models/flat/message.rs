@@ -1,9 +1,13 @@
 // core/src/models/flat/message.rs
 //! Flattened message types
 
-use super::{ParsedDeal, ParsedRelease, ParsedResource};
+use super::{
+    AvailabilityMap, IdentifierIndex, ParsedDeal, ParsedRelease, ParsedResource,
+    ReleaseIdentifiers,
+};
 use crate::models::{
-    graph::{ERNMessage, Party},
+    common::{IdentifierType, LocalizedString},
+    graph::{ERNMessage, MessageType, Party},
     Extensions,
 };
 use chrono::{DateTime, Utc};
@@ -16,9 +20,73 @@ pub struct ParsedERNMessage {
     pub flat: FlattenedMessage,
     /// Extensions from the original XML that need preservation
     pub extensions: Option<Extensions>,
+    /// Non-fatal issues noticed while parsing
+    #[serde(default)]
+    pub warnings: Vec<ParseWarning>,
+    /// Namespace declarations in the document, prefix to URI, with the
+    /// empty prefix for a default namespace
+    #[serde(default)]
+    pub namespaces: IndexMap<String, String>,
+    /// Prefixes collapsed onto another prefix declaring the same namespace,
+    /// mapped to that canonical prefix
+    #[serde(default)]
+    pub namespace_aliases: IndexMap<String, String>,
+    /// Whether releases past the parse's `max_releases` were left out
+    #[serde(default)]
+    pub truncated: bool,
+    /// Non-standard `MessageHeader` children, such as partner routing hints,
+    /// by local name: the text of a child holding only text, otherwise its
+    /// inner XML. Release and resource extensions are not included.
+    #[serde(default)]
+    pub header_extensions: IndexMap<String, String>,
+    /// Size and makeup of the source document
+    #[serde(default)]
+    pub document_stats: DocumentStats,
+}
+
+/// Tallies of the source document, for budgeting memory and spotting
+/// unusually large messages
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DocumentStats {
+    /// Elements, empty ones included
+    pub element_count: usize,
+    /// Attributes other than namespace declarations
+    pub attribute_count: usize,
+    pub comment_count: usize,
+    /// Distinct namespace URIs declared
+    pub namespace_count: usize,
+    /// Length of the input in bytes
+    pub file_size_bytes: u64,
+}
+
+/// Non-fatal issue noticed while parsing
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ParseWarning {
+    /// Warning code for programmatic handling (e.g. "ENCODING_MISMATCH")
+    pub code: String,
+    /// Human-readable description
+    pub message: String,
+    /// Where in the document the issue was found, if known
+    pub location: Option<String>,
 }
 
 impl ParsedERNMessage {
+    /// URI of the namespace `prefix` was declared for, following collapsed
+    /// prefixes to their canonical one
+    pub fn namespace_uri(&self, prefix: &str) -> Option<&str> {
+        let prefix = self
+            .namespace_aliases
+            .get(prefix)
+            .map_or(prefix, String::as_str);
+        self.namespaces.get(prefix).map(String::as_str)
+    }
+
+    /// Kind of message, from its root element, for telling a new delivery
+    /// from an update or a purge
+    pub fn message_type(&self) -> &MessageType {
+        &self.graph.message_header.message_type
+    }
+
     pub fn releases(&self) -> &[ParsedRelease] {
         &self.flat.releases
     }
@@ -34,6 +102,42 @@ impl ParsedERNMessage {
     pub fn parties(&self) -> &IndexMap<String, Party> {
         &self.flat.parties
     }
+
+    /// Look up a release by any of its identifiers (UPC/EAN, GRid, proprietary, ...)
+    pub fn find_release_by_identifier(
+        &self,
+        id_type: IdentifierType,
+        value: &str,
+    ) -> Option<&ParsedRelease> {
+        self.flat
+            .identifier_index
+            .release_position(&id_type, value)
+            .and_then(|pos| self.flat.releases.get(pos))
+    }
+
+    /// Look up a resource by any of its identifiers (ISRC, proprietary, ...)
+    pub fn find_resource_by_identifier(
+        &self,
+        id_type: IdentifierType,
+        value: &str,
+    ) -> Option<&ParsedResource> {
+        self.flat
+            .identifier_index
+            .resource_reference(&id_type, value)
+            .and_then(|reference| self.flat.resources.get(reference))
+    }
+
+    /// Per-release, per-territory availability merged from all deals,
+    /// honoring worldwide deals and excluded territories
+    pub fn availability_map(&self) -> AvailabilityMap {
+        AvailabilityMap::from_deals(
+            self.flat
+                .releases
+                .iter()
+                .map(|release| release.release_id.as_str()),
+            &self.flat.deals,
+        )
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -47,17 +151,50 @@ pub struct FlattenedMessage {
     pub resources: IndexMap<String, ParsedResource>,
     pub deals: Vec<ParsedDeal>,
     pub parties: IndexMap<String, Party>,
+    /// Releases a `PurgeReleaseMessage` asks to be removed
+    #[serde(default)]
+    pub purged_releases: Vec<PurgedRelease>,
     pub version: String,
     pub profile: Option<String>,
     pub stats: MessageStats,
+    /// Identifier lookup index built during flattening
+    #[serde(default)]
+    pub identifier_index: IdentifierIndex,
+    /// Events of the `MessageAuditTrail`, in document order
+    #[serde(default)]
+    pub audit_trail: Vec<AuditTrailEntry>,
     /// Extensions for flattened message
     pub extensions: Option<Extensions>,
 }
 
+/// A release named by a `PurgeReleaseMessage`, which carries only enough to
+/// identify it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PurgedRelease {
+    pub identifiers: ReleaseIdentifiers,
+    pub title: Option<String>,
+    pub display_artist: String,
+}
+
+/// One event of a message's audit trail
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditTrailEntry {
+    /// `MessageAuditTrailEventType`, e.g. `Created` or `Delivered`
+    pub event_type: String,
+    pub date_time: DateTime<Utc>,
+    /// Id or name of the party that performed the event
+    pub party: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Organization {
     pub name: String,
     pub id: String,
+    /// All localized party names, in document order (`name` is the first)
+    #[serde(default)]
+    pub names: Vec<LocalizedString>,
+    #[serde(default)]
+    pub trading_name: Option<String>,
     /// Extensions for organization
     pub extensions: Option<Extensions>,
 }
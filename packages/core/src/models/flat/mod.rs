@@ -1,12 +1,16 @@
 // core/src/models/flat/mod.rs
 //! Flattened model (developer-friendly)
 
+mod availability;
 mod deal;
+mod index;
 mod message;
 mod release;
 mod track;
 
+pub use availability::*;
 pub use deal::*;
+pub use index::*;
 pub use message::*;
 pub use release::*;
 pub use track::*;
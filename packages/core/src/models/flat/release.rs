@@ -3,10 +3,11 @@
 
 use super::{ParsedImage, ParsedTrack, ParsedVideo, TerritoryInfo};
 use crate::models::{
-    common::{Copyright, LocalizedString},
+    common::{Copyright, Identifier, LocalizedString},
     Extensions,
 };
 use chrono::{DateTime, Utc};
+use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -15,6 +16,12 @@ pub struct ParsedRelease {
     pub identifiers: ReleaseIdentifiers,
     pub title: Vec<LocalizedString>,
     pub default_title: String,
+    /// Internal titles from `ReferenceTitle` (`ReleaseTitle` in ERN 3.8)
+    #[serde(default)]
+    pub reference_title: Vec<LocalizedString>,
+    /// Customer-facing titles from `DisplayTitle`
+    #[serde(default)]
+    pub display_title: Vec<LocalizedString>,
     pub subtitle: Option<Vec<LocalizedString>>,
     pub default_subtitle: Option<String>,
     pub display_artist: String,
@@ -22,6 +29,13 @@ pub struct ParsedRelease {
     pub release_type: String,
     pub genre: Option<String>,
     pub sub_genre: Option<String>,
+    /// Label, whether given inline as `LabelName` or as the party a
+    /// `ReleaseLabelReference` names
+    #[serde(default)]
+    pub label_name: Option<String>,
+    /// Summed runtime of the release's tracks; tracks without a duration are left out
+    #[serde(default)]
+    pub total_duration_seconds: u64,
     pub tracks: Vec<ParsedTrack>,
     pub track_count: usize,
     pub disc_count: Option<usize>,
@@ -29,7 +43,19 @@ pub struct ParsedRelease {
     pub images: Vec<ParsedImage>,
     pub cover_art: Option<ParsedImage>,
     pub release_date: Option<DateTime<Utc>>,
+    /// Dates the release comes out in particular territories, apart from
+    /// `release_date`, keyed by territory code
+    #[serde(default)]
+    pub release_dates_by_territory: IndexMap<String, DateTime<Utc>>,
     pub original_release_date: Option<DateTime<Utc>>,
+    /// When metadata may first be shown and pre-orders open, from the
+    /// release's `ReleaseVisibility`
+    #[serde(default)]
+    pub preorder_date: Option<DateTime<Utc>>,
+    /// When the release goes on sale (`ReleaseDate`), whatever the validity
+    /// of individual deals
+    #[serde(default)]
+    pub availability_start: Option<DateTime<Utc>>,
     pub territories: Vec<TerritoryInfo>,
     /// Extensions for parsed release
     pub extensions: Option<Extensions>,
@@ -37,6 +63,39 @@ pub struct ParsedRelease {
     pub c_line: Option<Copyright>,
     pub parent_release: Option<String>,
     pub child_releases: Vec<String>,
+    /// References of the releases this bundle (box set, multi-product
+    /// release) is made up of, for components present in the message
+    #[serde(default)]
+    pub component_release_references: Vec<String>,
+    /// Reference of the bundle this release is a component of
+    #[serde(default)]
+    pub bundle_release_reference: Option<String>,
+    /// Marketing and editorial texts keyed by element name, one entry per language
+    #[serde(default)]
+    pub descriptions: IndexMap<String, Vec<LocalizedString>>,
+    /// Original `<Release>` XML, kept when parsing with `include_raw`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub raw: Option<String>,
+}
+
+impl ParsedRelease {
+    /// Text of a description of the given type in the given language
+    pub fn description(&self, description_type: &str, language_code: &str) -> Option<&str> {
+        find_description(&self.descriptions, description_type, language_code)
+    }
+}
+
+/// Look up a description by type and language in a flattened descriptions map
+pub(crate) fn find_description<'a>(
+    descriptions: &'a IndexMap<String, Vec<LocalizedString>>,
+    description_type: &str,
+    language_code: &str,
+) -> Option<&'a str> {
+    descriptions
+        .get(description_type)?
+        .iter()
+        .find(|d| d.language_code.as_deref() == Some(language_code))
+        .map(|d| d.text.as_str())
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -59,4 +118,7 @@ pub struct ArtistInfo {
     pub name: String,
     pub role: String,
     pub party_id: Option<String>,
+    /// ISNI, IPI, DPID and proprietary ids of the party the artist refers to
+    #[serde(default)]
+    pub identifiers: Vec<Identifier>,
 }
@@ -1,6 +1,8 @@
 // core/src/models/flat/track.rs
 //! Parsed track types
 
+use crate::models::common::LocalizedString;
+use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
 
@@ -17,6 +19,9 @@ pub struct ParsedTrack {
     pub subtitle: Option<String>,
     pub display_artist: String,
     pub artists: Vec<ArtistInfo>,
+    /// Credits for the recording, with their roles kept as written
+    #[serde(default)]
+    pub contributors: Vec<ContributorInfo>,
     pub duration: Duration,
     pub duration_formatted: String,
     pub file_format: Option<String>,
@@ -30,6 +35,17 @@ pub struct ParsedTrack {
 
 use crate::models::flat::release::ArtistInfo;
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContributorInfo {
+    pub name: String,
+    /// Roles as written, e.g. `Conductor`, `Orchestra`, `Ensemble` or `Soloist`
+    pub roles: Vec<String>,
+    /// Instruments played, e.g. `Guitar` or `Piano`
+    #[serde(default)]
+    pub instruments: Vec<String>,
+    pub party_id: Option<String>,
+}
+
 impl ParsedTrack {
     pub fn format_duration(duration: Duration) -> String {
         let total_seconds = duration.as_secs();
@@ -46,14 +62,44 @@ pub struct ParsedResource {
     pub title: String,
     pub duration: Option<Duration>,
     pub technical_details: TechnicalInfo,
+    /// Picture properties, set on video resources
+    #[serde(default)]
+    pub video_details: Option<crate::models::graph::VideoTechnicalDetails>,
+    /// Marketing and editorial texts keyed by element name, one entry per language
+    #[serde(default)]
+    pub descriptions: IndexMap<String, Vec<LocalizedString>>,
+    /// Original resource XML, kept when parsing with `include_raw`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub raw: Option<String>,
+}
+
+impl ParsedResource {
+    /// Text of a description of the given type in the given language
+    pub fn description(&self, description_type: &str, language_code: &str) -> Option<&str> {
+        crate::models::flat::release::find_description(
+            &self.descriptions,
+            description_type,
+            language_code,
+        )
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TechnicalInfo {
     pub file_format: Option<String>,
+    #[serde(default)]
+    pub audio_codec: Option<String>,
     pub bitrate: Option<i32>,
     pub sample_rate: Option<i32>,
+    #[serde(default)]
+    pub bits_per_sample: Option<u32>,
     pub file_size: Option<u64>,
+    /// Files from every technical details block of the resource
+    #[serde(default)]
+    pub files: Vec<crate::models::graph::ResourceFile>,
+    /// Preview clip from the first technical details block that has one
+    #[serde(default)]
+    pub preview: Option<crate::models::graph::PreviewDetails>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
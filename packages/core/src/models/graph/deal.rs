@@ -17,6 +17,13 @@ pub struct DealTerms {
     pub validity_period: Option<ValidityPeriod>,
     pub start_date: Option<DateTime<Utc>>,
     pub end_date: Option<DateTime<Utc>>,
+    /// `StartDate` exactly as written, e.g. `2024-01-15` where `start_date`
+    /// holds midnight UTC
+    #[serde(default)]
+    pub start_date_text: Option<String>,
+    /// `EndDate` exactly as written
+    #[serde(default)]
+    pub end_date_text: Option<String>,
     pub territory_code: Vec<String>,
     pub excluded_territory_code: Vec<String>,
     pub distribution_channel: Vec<DistributionChannel>,
@@ -30,6 +37,18 @@ pub struct DealTerms {
     pub pre_order_preview_date: Option<DateTime<Utc>>,
     pub instant_gratification_date: Option<DateTime<Utc>>,
     pub takedown_date: Option<DateTime<Utc>>,
+    /// `TakeDown` flag: the deal withdraws its use types instead of granting them
+    #[serde(default)]
+    pub take_down: bool,
+}
+
+/// A `ReleaseVisibility` from the deal list: when a release's metadata may
+/// be shown, independently of when its deals make it available
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReleaseVisibility {
+    pub visibility_reference: String,
+    /// `ReleaseDisplayStartDateTime`, typically the opening of pre-orders
+    pub release_display_start: Option<DateTime<Utc>>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -62,4 +81,7 @@ pub struct PriceInformation {
     pub price_type: String,
     pub price: Price,
     pub price_tier: Option<String>,
+    /// The amount exactly as written, e.g. `9.90` where `price.amount` is 9.9
+    #[serde(default)]
+    pub amount_text: Option<String>,
 }
@@ -30,6 +30,21 @@ pub enum MessageType {
     NewReleaseMessage,
     UpdateReleaseMessage,
     TakedownMessage,
+    /// Asks the recipient to remove the releases it names
+    PurgeReleaseMessage,
+}
+
+impl MessageType {
+    /// The message type whose root element has `local_name`, e.g.
+    /// `UpdateReleaseMessage`
+    pub fn from_root_element(local_name: &[u8]) -> Option<Self> {
+        match local_name {
+            b"NewReleaseMessage" => Some(Self::NewReleaseMessage),
+            b"UpdateReleaseMessage" => Some(Self::UpdateReleaseMessage),
+            b"PurgeReleaseMessage" => Some(Self::PurgeReleaseMessage),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
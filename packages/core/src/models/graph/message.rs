@@ -12,6 +12,10 @@ pub struct ERNMessage {
     pub resources: Vec<Resource>,
     pub releases: Vec<Release>,
     pub deals: Vec<Deal>,
+    /// Releases a `PurgeReleaseMessage` asks to be removed, from its
+    /// `PurgedRelease`s
+    #[serde(default)]
+    pub purged_releases: Vec<Release>,
     pub version: ERNVersion,
     pub profile: Option<ERNProfile>,
     pub message_audit_trail: Option<MessageAuditTrail>,
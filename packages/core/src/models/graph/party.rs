@@ -6,6 +6,9 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Party {
+    /// `PartyReference` that releases, resources and deals point at
+    #[serde(default)]
+    pub party_reference: Option<String>,
     pub party_id: Vec<Identifier>,
     pub isni: Option<String>,
     pub ipi: Option<String>,
@@ -25,6 +28,7 @@ pub enum PartyRole {
     Engineer,
     Label,
     Distributor,
+    RightsController,
     Other(String),
 }
 
@@ -42,3 +46,20 @@ pub struct Artist {
     pub display_artist_name: Vec<LocalizedString>,
     pub sequence_number: Option<i32>,
 }
+
+/// A party credited for its part in making a resource, such as a conductor,
+/// orchestra or producer
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Contributor {
+    pub party_reference: Option<String>,
+    /// Name given in place, when the contributor is not in the `PartyList`
+    pub party_name: Vec<LocalizedString>,
+    /// Roles as written, e.g. `Conductor` or `Orchestra`; a `UserDefined`
+    /// role is replaced by its `UserDefinedValue`
+    pub role: Vec<String>,
+    /// Instruments played, e.g. `Guitar`; a `UserDefined` type is replaced
+    /// by its `UserDefinedValue`
+    #[serde(default)]
+    pub instrument_type: Vec<String>,
+    pub sequence_number: Option<i32>,
+}
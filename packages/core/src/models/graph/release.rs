@@ -1,9 +1,9 @@
 // core/src/models/graph/release.rs
 //! Release types
 
-use super::Artist;
+use super::{Artist, ReleaseVisibility};
 use crate::models::{
-    common::{Identifier, LocalizedString},
+    common::{Description, Identifier, LocalizedString},
     AttributeMap, Comment, Extensions,
 };
 use chrono::{DateTime, Utc};
@@ -13,16 +13,38 @@ use serde::{Deserialize, Serialize};
 pub struct Release {
     pub release_reference: String,
     pub release_id: Vec<Identifier>,
+    /// Primary titles: the reference title, else the display title
     pub release_title: Vec<LocalizedString>,
+    /// Internal titles, from `ReferenceTitle` (or `ReleaseTitle` in ERN 3.8)
+    #[serde(default)]
+    pub reference_title: Vec<LocalizedString>,
+    /// Customer-facing titles, from `DisplayTitle`
+    #[serde(default)]
+    pub display_title: Vec<LocalizedString>,
     pub release_subtitle: Option<Vec<LocalizedString>>,
     pub release_type: Option<ReleaseType>,
     pub genre: Vec<Genre>,
     pub release_resource_reference_list: Vec<ReleaseResourceReference>,
     pub display_artist: Vec<Artist>,
+    /// Parties playing a role on the release, e.g. the `Label` a
+    /// `ReleaseLabelReference` names
     pub party_list: Vec<ReleaseParty>,
+    /// Label given inline as `LabelName`, rather than as a referenced party
+    #[serde(default)]
+    pub label_name: Option<String>,
     pub release_date: Vec<ReleaseEvent>,
     pub territory_code: Vec<String>,
     pub excluded_territory_code: Vec<String>,
+    /// Marketing and editorial texts, in document order
+    #[serde(default)]
+    pub descriptions: Vec<Description>,
+    /// Links to other releases, e.g. the album a `TrackRelease` comes from
+    #[serde(default)]
+    pub related_releases: Vec<RelatedRelease>,
+    /// Visibility named by `ReleaseVisibilityReference`, with its dates once
+    /// the deal list has been read
+    #[serde(default)]
+    pub visibility: Option<ReleaseVisibility>,
     /// All XML attributes (standard and custom)
     pub attributes: Option<AttributeMap>,
     /// Extensions for release
@@ -37,9 +59,30 @@ pub enum ReleaseType {
     Single,
     EP,
     Compilation,
+    /// A single track offered on its own, from a `TrackRelease` element
+    TrackRelease,
     Other(String),
 }
 
+/// `ReleaseRelationshipType` a bundle (box set, multi-product release) uses
+/// to name each of its component releases
+pub const HAS_COMPONENT_RELEASE: &str = "HasComponentRelease";
+
+/// `ReleaseRelationshipType` a component release uses to name its bundle
+pub const IS_COMPONENT_RELEASE_OF: &str = "IsComponentReleaseOf";
+
+/// Role of the `ReleaseParty` a `ReleaseLabelReference` names
+pub const LABEL_ROLE: &str = "Label";
+
+/// A `RelatedRelease`: another release and how this one relates to it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RelatedRelease {
+    /// `ReleaseRelationshipType`, e.g. `IsReleaseFromRelease`
+    pub relationship_type: String,
+    /// Identifiers of the related release
+    pub release_id: Vec<Identifier>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Genre {
     pub genre_text: String,
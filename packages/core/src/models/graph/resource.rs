@@ -1,8 +1,9 @@
 // core/src/models/graph/resource.rs
 //! Resource types
 
+use super::{Artist, Contributor};
 use crate::models::{
-    common::{Copyright, Identifier, LocalizedString},
+    common::{Copyright, Description, Identifier, LocalizedString},
     Extensions,
 };
 use serde::{Deserialize, Serialize};
@@ -13,11 +14,24 @@ pub struct Resource {
     pub resource_type: ResourceType,
     pub resource_id: Vec<Identifier>,
     pub reference_title: Vec<LocalizedString>,
+    /// Version titles from `SubTitle`, e.g. "Live at Wembley", kept apart
+    /// from the title so versions of a recording stay distinct
+    #[serde(default)]
+    pub subtitle: Vec<LocalizedString>,
+    /// Artists credited on the resource itself
+    #[serde(default)]
+    pub display_artist: Vec<Artist>,
+    /// Parties credited for their part in the resource
+    #[serde(default)]
+    pub contributors: Vec<Contributor>,
     pub duration: Option<std::time::Duration>,
     pub technical_details: Vec<TechnicalDetails>,
     pub rights_controller: Vec<String>,
     pub p_line: Vec<Copyright>,
     pub c_line: Vec<Copyright>,
+    /// Marketing and editorial texts, in document order
+    #[serde(default)]
+    pub descriptions: Vec<Description>,
     /// Extensions for resource
     pub extensions: Option<Extensions>,
 }
@@ -31,14 +45,70 @@ pub enum ResourceType {
     SheetMusic,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct TechnicalDetails {
     pub technical_resource_details_reference: String,
     pub audio_codec: Option<String>,
     pub bitrate: Option<i32>,
     pub sample_rate: Option<i32>,
+    /// Bit depth, e.g. 16 or 24
+    #[serde(default)]
+    pub bits_per_sample: Option<u32>,
     pub file_format: Option<String>,
     pub file_size: Option<u64>,
+    /// Delivered files, e.g. the full-length audio and a preview clip
+    #[serde(default)]
+    pub files: Vec<ResourceFile>,
+    /// Preview clip window, from `PreviewDetails` (ERN 3.8) or `ClipDetails` (ERN 4.x)
+    #[serde(default)]
+    pub preview: Option<PreviewDetails>,
+    /// Picture properties, from `TechnicalVideoDetails`
+    #[serde(default)]
+    pub video: Option<VideoTechnicalDetails>,
     /// Extensions for technical details
     pub extensions: Option<Extensions>,
 }
+
+/// Technical properties of a video resource
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct VideoTechnicalDetails {
+    /// Frame width in pixels, from `ImageWidth`
+    pub width: Option<u32>,
+    /// Frame height in pixels, from `ImageHeight`
+    pub height: Option<u32>,
+    /// Frames per second, e.g. 29.97
+    pub frame_rate: Option<f64>,
+    /// e.g. "H.264"
+    pub codec: Option<String>,
+    /// e.g. "16:9"
+    pub aspect_ratio: Option<String>,
+    /// Video bit rate, in the unit the message gives (usually kbps)
+    pub bitrate: Option<i32>,
+}
+
+/// The part of a resource played as a preview
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PreviewDetails {
+    /// Offset of the clip from the start of the resource
+    pub start_point: Option<std::time::Duration>,
+    /// Length of the clip, given directly or as the gap between start and end points
+    pub duration: Option<std::time::Duration>,
+}
+
+/// A file delivered for a resource
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResourceFile {
+    pub file_name: Option<String>,
+    pub file_path: Option<String>,
+    /// Location given as `URI` (ERN 4.x) or `URL` (ERN 3.8)
+    pub uri: Option<String>,
+    pub hash_sum: Option<HashSum>,
+}
+
+/// Checksum of a delivered file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HashSum {
+    /// e.g. "MD5", "SHA1"
+    pub algorithm: String,
+    pub value: String,
+}
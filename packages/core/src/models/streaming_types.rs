@@ -178,6 +178,12 @@ impl ToCore for ReleaseBuilder {
             attributes: self.attributes,
             extensions: self.extensions,
             comments: self.comments,
+            descriptions: Vec::new(),
+            reference_title: Vec::new(),
+            display_title: Vec::new(),
+            related_releases: Vec::new(),
+            label_name: None,
+            visibility: None,
         })
     }
 }
@@ -271,12 +277,16 @@ impl ToCore for ResourceBuilder {
             resource_type: self.resource_type.unwrap_or(ResourceType::SoundRecording),
             resource_id: self.resource_id,
             reference_title: self.reference_title,
+            subtitle: Vec::new(),
+            display_artist: Vec::new(),
+            contributors: Vec::new(),
             duration: self.duration,
             technical_details: self.technical_details,
             rights_controller: self.rights_controller,
             p_line: self.p_line,
             c_line: self.c_line,
             extensions: self.extensions,
+            descriptions: Vec::new(),
         })
     }
 }
@@ -345,6 +355,7 @@ impl ToCore for PartyBuilder {
         self.validate()?;
 
         Ok(Party {
+            party_reference: self.party_reference,
             party_id: self.party_id,
             isni: self.isni,
             ipi: self.ipi,
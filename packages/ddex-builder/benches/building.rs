@@ -28,6 +28,7 @@ fn create_test_request() -> BuildRequest {
                     language_code: None,
                 }],
                 party_id: None,
+                trading_name: None,
             },
             message_recipient: PartyRequest {
                 party_name: vec![LocalizedStringRequest {
@@ -35,8 +36,10 @@ fn create_test_request() -> BuildRequest {
                     language_code: None,
                 }],
                 party_id: None,
+                trading_name: None,
             },
             message_control_type: None,
+            message_thread_id: None,
         },
         version: "4.3".to_string(),
         profile: None,
@@ -185,6 +185,16 @@ fn create_test_request_with_tracks(track_count: usize) -> BuildRequest {
         }],
         genre: vec!["Rock".to_string()], // Common genre for interning
         release_date: Some("2024-01-01".to_string()),
+        display_title: vec![],
+        track_releases: vec![],
+        preorder_date: None,
+        component_release_references: vec![],
+        release_dates_by_territory: Default::default(),
+        display_artists: Default::default(),
+        resource_groups: Default::default(),
+        subtitle: None,
+        images: vec![],
+        videos: vec![],
     });
 
     BuildRequest {
@@ -196,6 +206,7 @@ fn create_test_request_with_tracks(track_count: usize) -> BuildRequest {
                     language_code: None,
                 }],
                 party_id: None,
+                trading_name: None,
             },
             message_recipient: PartyRequest {
                 party_name: vec![LocalizedStringRequest {
@@ -203,8 +214,10 @@ fn create_test_request_with_tracks(track_count: usize) -> BuildRequest {
                     language_code: None,
                 }],
                 party_id: None,
+                trading_name: None,
             },
             message_control_type: None,
+            message_thread_id: None,
         },
         version: "4.3".to_string(), // Static for interning
         profile: None,
@@ -114,6 +114,7 @@ fn create_realistic_album_request() -> BuildRequest {
                     language_code: Some("en".to_string()),
                 }],
                 party_id: Some("LABEL_TEST_001".to_string()),
+                trading_name: None,
             },
             message_recipient: PartyRequest {
                 party_name: vec![LocalizedStringRequest {
@@ -121,8 +122,10 @@ fn create_realistic_album_request() -> BuildRequest {
                     language_code: Some("en".to_string()),
                 }],
                 party_id: Some("DSP_SPOTIFY_001".to_string()),
+                trading_name: None,
             },
             message_control_type: Some("LiveMessage".to_string()),
+            message_thread_id: None,
         },
         version: "4.3".to_string(),
         profile: Some("CommonReleaseTypes/14/AudioAlbumMusicOnly".to_string()),
@@ -149,6 +152,16 @@ fn create_realistic_album_request() -> BuildRequest {
             }],
             genre: vec!["Electronic".to_string(), "Pop".to_string()],
             release_date: Some("2024-03-15".to_string()),
+            display_title: vec![],
+            track_releases: vec![],
+            preorder_date: None,
+            component_release_references: vec![],
+            release_dates_by_territory: Default::default(),
+            display_artists: Default::default(),
+            resource_groups: Default::default(),
+            subtitle: None,
+            images: vec![],
+            videos: vec![],
         }],
         deals: vec![],
         extensions: None,
@@ -0,0 +1,170 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use ddex_builder::ast::{Element, Node, AST};
+use ddex_builder::builder::{
+    BuildRequest, LocalizedStringRequest, MessageHeaderRequest, PartyRequest, ReleaseRequest,
+    TrackRequest,
+};
+use ddex_builder::generator::xml_writer::XmlWriter;
+use ddex_builder::generator::ASTGenerator;
+use ddex_builder::DeterminismConfig;
+
+const RELEASES: usize = 1_000;
+
+/// The `String`/`format!` serializer the byte-buffer writer replaced, kept
+/// as the "before" side of the comparison (comments omitted, as builds
+/// don't emit them)
+fn string_writer(ast: &AST) -> String {
+    fn escape(text: &str, attribute: bool) -> String {
+        let text = text
+            .replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;");
+        if attribute {
+            text.replace('"', "&quot;").replace('\'', "&apos;")
+        } else {
+            text
+        }
+    }
+
+    fn write_element(out: &mut String, element: &Element, ast: &AST, depth: usize) {
+        let indent = "  ".repeat(depth);
+        let name = match (&element.namespace, ast.namespaces.first()) {
+            (Some(ns), _) => format!("{}:{}", ns, element.name),
+            (None, Some((prefix, _))) if depth == 0 => format!("{}:{}", prefix, element.name),
+            _ => element.name.clone(),
+        };
+        out.push_str(&format!("{}<{}", indent, name));
+        if depth == 0 {
+            for (prefix, uri) in &ast.namespaces {
+                out.push_str(&format!(" xmlns:{}=\"{}\"", prefix, uri));
+            }
+        }
+        for (key, value) in &element.attributes {
+            out.push_str(&format!(" {}=\"{}\"", key, escape(value, true)));
+        }
+        match element.children.as_slice() {
+            [] => out.push_str(&format!("></{}>\n", name)),
+            [Node::Text(text)] => out.push_str(&format!(">{}</{}>\n", escape(text, false), name)),
+            children => {
+                out.push_str(">\n");
+                for child in children {
+                    if let Node::Element(child) = child {
+                        write_element(out, child, ast, depth + 1);
+                    }
+                }
+                out.push_str(&format!("{}</{}>\n", indent, name));
+            }
+        }
+    }
+
+    let mut out = String::new();
+    out.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    write_element(&mut out, &ast.root, ast, 0);
+    out
+}
+
+fn party(name: &str) -> PartyRequest {
+    PartyRequest {
+        party_name: vec![LocalizedStringRequest {
+            text: name.to_string(),
+            language_code: None,
+        }],
+        party_id: None,
+        party_reference: None,
+        trading_name: None,
+    }
+}
+
+fn catalog_request() -> BuildRequest {
+    let releases = (0..RELEASES)
+        .map(|i| ReleaseRequest {
+            release_id: format!("A10301A{:010}A", i),
+            release_reference: Some(format!("R{}", i)),
+            title: vec![LocalizedStringRequest {
+                text: format!("Album {} & Friends", i),
+                language_code: Some("en".to_string()),
+            }],
+            display_title: vec![],
+            subtitle: None,
+            artist: format!("Artist {}", i),
+            label: Some("Catalog Label".to_string()),
+            release_date: Some("2024-01-01".to_string()),
+            preorder_date: None,
+            upc: None,
+            tracks: (0..3)
+                .map(|t| TrackRequest {
+                    track_id: format!("T{}_{}", i, t),
+                    resource_reference: Some(format!("A{}_{}", i, t)),
+                    isrc: format!("USRC1{:07}", i * 3 + t),
+                    title: format!("Track {} <{}>", t + 1, i),
+                    subtitle: None,
+                    duration: "PT3M30S".to_string(),
+                    artist: format!("Artist {}", i),
+                    file_path: None,
+                    hash_sum: None,
+                    preview: None,
+                    contributors: vec![],
+                })
+                .collect(),
+            images: vec![],
+            videos: vec![],
+            resource_references: None,
+            track_releases: vec![],
+            component_release_references: vec![],
+            release_dates_by_territory: Default::default(),
+            display_artists: Default::default(),
+            resource_groups: Default::default(),
+        })
+        .collect();
+
+    BuildRequest {
+        header: MessageHeaderRequest {
+            message_id: Some("BENCH_SERIALIZATION".to_string()),
+            message_sender: party("Benchmark Sender"),
+            message_recipient: party("Benchmark Recipient"),
+            message_control_type: None,
+            message_created_date_time: Some("2024-01-01T00:00:00Z".to_string()),
+            message_thread_id: None,
+        },
+        version: "4.3".to_string(),
+        profile: None,
+        releases,
+        deals: vec![],
+        extensions: None,
+    }
+}
+
+fn benchmark_serialization(c: &mut Criterion) {
+    let ast = ASTGenerator::new("4.3".to_string())
+        .generate(&catalog_request())
+        .unwrap();
+    let writer = XmlWriter::new(DeterminismConfig::default());
+
+    // Only worth comparing while both produce the same bytes
+    assert_eq!(string_writer(&ast), writer.write(&ast).unwrap());
+
+    let mut group = c.benchmark_group("serialize_1000_releases");
+    group.sample_size(20);
+
+    group.bench_function("before_string_format", |b| {
+        b.iter(|| black_box(string_writer(black_box(&ast))))
+    });
+
+    group.bench_function("after_byte_buffer", |b| {
+        b.iter(|| black_box(writer.write(black_box(&ast)).unwrap()))
+    });
+
+    group.bench_function("after_reused_buffer", |b| {
+        let mut buffer = Vec::new();
+        b.iter(|| {
+            buffer.clear();
+            writer.write_into(black_box(&ast), &mut buffer).unwrap();
+            black_box(buffer.len())
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, benchmark_serialization);
+criterion_main!(benches);
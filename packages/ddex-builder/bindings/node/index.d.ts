@@ -7,11 +7,21 @@ export interface Release {
   releaseId: string
   releaseType: string
   title: string
+  /** Customer-facing title, emitted as `DisplayTitle`; `title` is the reference title */
+  displayTitle?: string
   artist: string
+  /** Release-level artists with their roles, e.g. "MainArtist" or "FeaturedArtist" */
+  displayArtists?: Array<DisplayArtist>
   label?: string
   catalogNumber?: string
   upc?: string
   releaseDate?: string
+  /** Territory-specific release dates, keyed by territory code */
+  releaseDatesByTerritory?: Record<string, string>
+  /** When metadata may be shown and pre-orders open, ahead of `release_date` */
+  preorderDate?: string
+  /** For a bundle, IDs of the added releases it is made up of */
+  componentReleaseIds?: Array<string>
   genre?: string
   parentalWarning?: boolean
   trackIds: Array<string>
@@ -27,6 +37,41 @@ export interface Resource {
   trackNumber?: number
   volumeNumber?: number
   metadata?: Record<string, string>
+  /** Delivered file; hashed with SHA-256 when no hash is given */
+  filePath?: string
+  hashAlgorithm?: string
+  hashValue?: string
+  /** Preview clip start and length in ISO 8601 format; both are needed */
+  previewStart?: string
+  previewDuration?: string
+  /** Credits such as conductor, orchestra and soloists, roles kept as given */
+  contributors?: Array<Contributor>
+}
+export interface DisplayArtist {
+  name: string
+  /** DDEX display artist role, e.g. "MainArtist" or "FeaturedArtist" */
+  role: string
+}
+export interface Contributor {
+  name: string
+  /** DDEX contributor roles, e.g. "Conductor" or "Orchestra" */
+  roles: Array<string>
+  /** Instruments played, e.g. "Guitar" */
+  instruments?: Array<string>
+}
+export interface IdentifierInput {
+  isrcs?: Array<string>
+  upcs?: Array<string>
+}
+export interface NormalizedIdentifier {
+  original: string
+  normalized: string
+  /** Whether the normalized value passes the build's identifier checks */
+  valid: boolean
+}
+export interface NormalizedIdentifiers {
+  isrcs: Array<NormalizedIdentifier>
+  upcs: Array<NormalizedIdentifier>
 }
 export interface ValidationResult {
   isValid: boolean
@@ -49,6 +94,12 @@ export interface PresetInfo {
   requiredFields: Array<string>
   disclaimer: string
 }
+export interface Capabilities {
+  versions: Array<string>
+  messageTypes: Array<string>
+  canonicalization: Array<string>
+  features: Array<string>
+}
 export interface ValidationRule {
   fieldName: string
   ruleType: string
@@ -79,7 +130,12 @@ export interface BuildStatistics {
   attributeCount: number
   namespaceCount: number
   extensionCount: number
+  validationTimeMs: number
+  /** Generating the message and writing it as XML */
+  serializationTimeMs: number
   canonicalizationTimeMs: number
+  /** Hashing the canonical form */
+  hashingTimeMs: number
   verificationTimeMs?: number
 }
 export interface VerificationResult {
@@ -95,6 +151,14 @@ export interface BuildResult {
   statistics?: BuildStatistics
   verification?: VerificationResult
   fidelityInfo?: FidelityInfo
+  /** Files the message points to, for gathering the delivery package */
+  referencedFiles: Array<ReferencedFile>
+}
+export interface ReferencedFile {
+  resourceReference: string
+  uri: string
+  hashAlgorithm?: string
+  hashValue?: string
 }
 export interface FidelityInfo {
   fidelityLevel: string
@@ -132,6 +196,37 @@ export interface MessageHeader {
   messageSenderName: string
   messageRecipientName: string
   messageCreatedDateTime?: string
+  /** Localized sender names; when set, these replace `message_sender_name` */
+  messageSenderNames?: Array<LocalizedName>
+  messageSenderTradingName?: string
+  /** Localized recipient names; when set, these replace `message_recipient_name` */
+  messageRecipientNames?: Array<LocalizedName>
+  messageRecipientTradingName?: string
+}
+/** Options for `DdexBuilder.buildAcknowledgement` */
+export interface AcknowledgementOptions {
+  /** Thread of the original delivery; defaults to the message config's thread id */
+  threadId?: string
+  /** MessageId of the message being acknowledged */
+  referencedMessageId: string
+  /** Processing status (e.g. "FileOK", "SchemaValidationError") */
+  status: string
+}
+/** Message header configuration shared by `DdexBuilder` and `StreamingDdexBuilder` */
+export interface MessageConfig {
+  senderName: string
+  senderId?: string
+  recipientName: string
+  recipientId?: string
+  messageControlType?: string
+  messageThreadId?: string
+  messageCreatedDateTime?: string
+  /** Message ID template; `{uuid}`, `{date}` and `{timestamp}` are substituted */
+  messageIdTemplate?: string
+}
+export interface LocalizedName {
+  text: string
+  languageCode?: string
 }
 export declare function batchBuild(requests: Array<string>): Promise<Array<string>>
 export declare function validateStructure(xml: string): Promise<ValidationResult>
@@ -139,26 +234,123 @@ export declare class DdexBuilder {
   constructor()
   addRelease(release: Release): void
   addResource(resource: Resource): void
+  /** Use `config` for the message header of every subsequent build */
+  setMessageConfig(config: MessageConfig): void
+  /**
+   * Whether optional fields left blank are omitted (the default) or
+   * emitted as empty elements, for JSON and stored-data builds alike
+   */
+  setOmitEmpty(omitEmpty: boolean): void
+  /**
+   * Emit releases and resources in `"insertion"` order (the default), or
+   * sorted by `"reference"` or by `"identifier"` (UPC, then ISRC)
+   */
+  setSortOrder(sortOrder: string): void
+  /**
+   * Also emit these deprecated elements for recipients that still read
+   * them: `"release_details_by_territory"`, `"sound_recording_id"` or
+   * `"sound_recording_details_by_territory"`. Left empty (the default),
+   * an applied preset's legacy elements are emitted.
+   */
+  setLegacyElements(elements: Array<string>): void
   build(data?: any | undefined | null): Promise<string>
+  /**
+   * Build straight into the file at `path` with the streaming builder,
+   * so a huge catalog is never held as one string; memory stays bounded
+   * by the streaming buffer. Writes ERN 4.3 in insertion order.
+   *
+   * This takes a path rather than a Node `Writable`: the build runs
+   * synchronously on the calling thread, while a `Writable` only accepts
+   * data from the event loop. To send the output elsewhere, build to a
+   * temporary file and pipe `fs.createReadStream(path)` into the stream.
+   */
+  buildToFile(path: string, data?: any | undefined | null): StreamingStats
+  /** Build an acknowledgement replying to a previously received message */
+  buildAcknowledgement(options: AcknowledgementOptions): string
   buildWithFidelity(data?: any | undefined | null, fidelityOptions?: FidelityOptions | undefined | null): Promise<BuildResult>
   testRoundTripFidelity(originalXml: string, fidelityOptions?: FidelityOptions | undefined | null): Promise<VerificationResult>
+  /**
+   * Check the stored releases before building: required fields, ISRC,
+   * UPC, duration and release date formats, the applied preset's profile
+   * (e.g. a UPC on every `AudioAlbum` release) and its validation rules.
+   * Each message names the release or track at fault.
+   */
   validate(): Promise<ValidationResult>
+  /**
+   * Evaluate config-defined rules against `data` (or the stored releases)
+   *
+   * `Dependency` rules take `depends_on`, `condition` and, for
+   * `RequiredWhenEquals`, `value` parameters.
+   */
+  evaluateRules(rules: Array<ValidationRule>, data?: any | undefined | null): ValidationResult
+  /**
+   * Clean up ISRCs and UPCs without building: ISRCs are uppercased and
+   * stripped of separators, UPCs stripped and zero-padded to 12 digits.
+   * Each value says whether it then passes the build's own checks.
+   */
+  normalizeIdentifiers(identifiers: IdentifierInput): NormalizedIdentifiers
+  /**
+   * `count` sequential ISRCs for a registrant prefix such as `US-ABC` and a
+   * year, numbered from 00001; errors on a malformed prefix
+   */
+  generateIsrcs(prefix: string, count: number, year: number): Array<string>
   getStats(): BuilderStats
+  /**
+   * Clear releases, resources and stats to start the next message. The
+   * applied preset, message config and output options are kept, so a
+   * configured builder can be reused; use `resetAll` to drop them too.
+   */
   reset(): void
+  /**
+   * Return the builder to its freshly constructed state, dropping the
+   * applied preset, message config and output options along with the data
+   */
+  resetAll(): void
+  /**
+   * Versions, message types, canonicalization algorithms and feature
+   * flags this build of the builder supports, with versions named as the
+   * parser names them (`V4_3`)
+   */
+  capabilities(): Capabilities
   getAvailablePresets(): Array<string>
   getPresetInfo(presetName: string): PresetInfo
+  /** Resolve `preset_name` once and keep it for every subsequent build */
   applyPreset(presetName: string): void
+  /** Info for the preset applied with `apply_preset`, if any */
+  getAppliedPreset(): PresetInfo | null
+  /** Validation rules of the applied preset, without re-resolving it */
+  getAppliedPresetRules(): Array<ValidationRule>
+  /**
+   * Evaluate the applied preset's rules, dependencies included, against
+   * `data` (or the stored releases)
+   */
+  evaluatePresetRules(data?: any | undefined | null): ValidationResult
   getPresetValidationRules(presetName: string): Array<ValidationRule>
+  /**
+   * A minimal message for `preset_name` with a `{{Field}}` placeholder
+   * for each of its required fields, as a starting template
+   */
+  scaffold(presetName: string): string
 }
 export declare class StreamingDdexBuilder {
   constructor(config?: StreamingConfig | undefined | null)
   setProgressCallback(callback: (...args: any[]) => any): void
   setEstimatedTotal(total: number): void
   startMessage(header: MessageHeader, version: string): void
+  startMessageWithConfig(config: MessageConfig, version: string): void
   writeResource(resourceId: string, title: string, artist: string, isrc?: string | undefined | null, duration?: string | undefined | null, filePath?: string | undefined | null): string
   finishResourcesStartReleases(): void
   writeRelease(releaseId: string, title: string, artist: string, label: string | undefined | null, upc: string | undefined | null, releaseDate: string | undefined | null, genre: string | undefined | null, resourceReferences: Array<string>): string
   finishMessage(): StreamingStats
   getXml(): string
+  /**
+   * Discard the message in progress to start the next one. The config
+   * and progress callback are kept; use `resetAll` to drop them too.
+   */
   reset(): void
+  /**
+   * Return the builder to its default state, dropping the config and
+   * progress callback along with the message in progress
+   */
+  resetAll(): void
 }
@@ -10,11 +10,21 @@ pub struct Release {
     pub release_id: String,
     pub release_type: String,
     pub title: String,
+    /// Customer-facing title, emitted as `DisplayTitle`; `title` is the reference title
+    pub display_title: Option<String>,
     pub artist: String,
+    /// Release-level artists with their roles, e.g. "MainArtist" or "FeaturedArtist"
+    pub display_artists: Option<Vec<DisplayArtist>>,
     pub label: Option<String>,
     pub catalog_number: Option<String>,
     pub upc: Option<String>,
     pub release_date: Option<String>,
+    /// Territory-specific release dates, keyed by territory code
+    pub release_dates_by_territory: Option<HashMap<String, String>>,
+    /// When metadata may be shown and pre-orders open, ahead of `release_date`
+    pub preorder_date: Option<String>,
+    /// For a bundle, IDs of the added releases it is made up of
+    pub component_release_ids: Option<Vec<String>>,
     pub genre: Option<String>,
     pub parental_warning: Option<bool>,
     pub track_ids: Vec<String>,
@@ -33,6 +43,66 @@ pub struct Resource {
     pub track_number: Option<i32>,
     pub volume_number: Option<i32>,
     pub metadata: Option<HashMap<String, String>>,
+    /// Delivered file; hashed with SHA-256 when no hash is given
+    pub file_path: Option<String>,
+    pub hash_algorithm: Option<String>,
+    pub hash_value: Option<String>,
+    /// Preview clip start and length in ISO 8601 format; both are needed
+    pub preview_start: Option<String>,
+    pub preview_duration: Option<String>,
+    /// Credits such as conductor, orchestra and soloists, roles kept as given
+    pub contributors: Option<Vec<Contributor>>,
+}
+
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DisplayArtist {
+    pub name: String,
+    /// DDEX display artist role, e.g. "MainArtist" or "FeaturedArtist"
+    pub role: String,
+}
+
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Contributor {
+    pub name: String,
+    /// DDEX contributor roles, e.g. "Conductor" or "Orchestra"
+    pub roles: Vec<String>,
+    /// Instruments played, e.g. "Guitar"
+    pub instruments: Option<Vec<String>>,
+}
+
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IdentifierInput {
+    pub isrcs: Option<Vec<String>>,
+    pub upcs: Option<Vec<String>>,
+}
+
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NormalizedIdentifier {
+    pub original: String,
+    pub normalized: String,
+    /// Whether the normalized value passes the build's identifier checks
+    pub valid: bool,
+}
+
+impl From<ddex_builder::NormalizedIdentifier> for NormalizedIdentifier {
+    fn from(identifier: ddex_builder::NormalizedIdentifier) -> Self {
+        NormalizedIdentifier {
+            original: identifier.original,
+            normalized: identifier.normalized,
+            valid: identifier.valid,
+        }
+    }
+}
+
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NormalizedIdentifiers {
+    pub isrcs: Vec<NormalizedIdentifier>,
+    pub upcs: Vec<NormalizedIdentifier>,
 }
 
 #[napi(object)]
@@ -65,6 +135,15 @@ pub struct PresetInfo {
     pub disclaimer: String,
 }
 
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Capabilities {
+    pub versions: Vec<String>,
+    pub message_types: Vec<String>,
+    pub canonicalization: Vec<String>,
+    pub features: Vec<String>,
+}
+
 #[napi(object)]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ValidationRule {
@@ -103,7 +182,12 @@ pub struct BuildStatistics {
     pub attribute_count: u32,
     pub namespace_count: u32,
     pub extension_count: u32,
+    pub validation_time_ms: f64,
+    /// Generating the message and writing it as XML
+    pub serialization_time_ms: f64,
     pub canonicalization_time_ms: f64,
+    /// Hashing the canonical form
+    pub hashing_time_ms: f64,
     pub verification_time_ms: Option<f64>,
 }
 
@@ -125,6 +209,17 @@ pub struct BuildResult {
     pub statistics: Option<BuildStatistics>,
     pub verification: Option<VerificationResult>,
     pub fidelity_info: Option<FidelityInfo>,
+    /// Files the message points to, for gathering the delivery package
+    pub referenced_files: Vec<ReferencedFile>,
+}
+
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReferencedFile {
+    pub resource_reference: String,
+    pub uri: String,
+    pub hash_algorithm: Option<String>,
+    pub hash_value: Option<String>,
 }
 
 #[napi(object)]
@@ -144,7 +239,19 @@ pub struct FidelityInfo {
 pub struct DdexBuilder {
     releases: Vec<Release>,
     resources: Vec<Resource>,
+    message_config: Option<MessageConfig>,
+    omit_empty: bool,
+    sort_order: ddex_builder::builder::SortOrder,
+    legacy_elements: Vec<ddex_builder::builder::LegacyElement>,
     stats: BuilderStats,
+    builder: ddex_builder::builder::DDEXBuilder,
+    preset: Option<AppliedPreset>,
+}
+
+/// Preset details resolved once by `apply_preset` and reused across builds
+struct AppliedPreset {
+    info: PresetInfo,
+    rules: Vec<ValidationRule>,
 }
 
 #[napi]
@@ -154,6 +261,10 @@ impl DdexBuilder {
         Ok(DdexBuilder {
             releases: Vec::new(),
             resources: Vec::new(),
+            message_config: None,
+            omit_empty: true,
+            sort_order: ddex_builder::builder::SortOrder::Insertion,
+            legacy_elements: Vec::new(),
             stats: BuilderStats {
                 releases_count: 0,
                 resources_count: 0,
@@ -162,6 +273,8 @@ impl DdexBuilder {
                 validation_errors: 0,
                 validation_warnings: 0,
             },
+            builder: ddex_builder::builder::DDEXBuilder::new(),
+            preset: None,
         })
     }
 
@@ -179,21 +292,74 @@ impl DdexBuilder {
         Ok(())
     }
 
+    /// Use `config` for the message header of every subsequent build
+    #[napi]
+    pub fn set_message_config(&mut self, config: MessageConfig) -> Result<()> {
+        self.message_config = Some(config);
+        Ok(())
+    }
+
+    /// Whether optional fields left blank are omitted (the default) or
+    /// emitted as empty elements, for JSON and stored-data builds alike
+    #[napi]
+    pub fn set_omit_empty(&mut self, omit_empty: bool) -> Result<()> {
+        self.omit_empty = omit_empty;
+        Ok(())
+    }
+
+    /// Emit releases and resources in `"insertion"` order (the default), or
+    /// sorted by `"reference"` or by `"identifier"` (UPC, then ISRC)
+    #[napi]
+    pub fn set_sort_order(&mut self, sort_order: String) -> Result<()> {
+        self.sort_order = match sort_order.as_str() {
+            "insertion" => ddex_builder::builder::SortOrder::Insertion,
+            "reference" => ddex_builder::builder::SortOrder::Reference,
+            "identifier" => ddex_builder::builder::SortOrder::Identifier,
+            _ => {
+                return Err(Error::new(
+                    Status::InvalidArg,
+                    format!("Unknown sort order: {}", sort_order),
+                ))
+            }
+        };
+        Ok(())
+    }
+
+    /// Also emit these deprecated elements for recipients that still read
+    /// them: `"release_details_by_territory"`, `"sound_recording_id"` or
+    /// `"sound_recording_details_by_territory"`. Left empty (the default),
+    /// an applied preset's legacy elements are emitted.
+    #[napi]
+    pub fn set_legacy_elements(&mut self, elements: Vec<String>) -> Result<()> {
+        use ddex_builder::builder::LegacyElement;
+
+        self.legacy_elements = elements
+            .iter()
+            .map(|element| match element.as_str() {
+                "release_details_by_territory" => Ok(LegacyElement::ReleaseDetailsByTerritory),
+                "sound_recording_id" => Ok(LegacyElement::SoundRecordingId),
+                "sound_recording_details_by_territory" => {
+                    Ok(LegacyElement::SoundRecordingDetailsByTerritory)
+                }
+                _ => Err(Error::new(
+                    Status::InvalidArg,
+                    format!("Unknown legacy element: {}", element),
+                )),
+            })
+            .collect::<Result<_>>()?;
+        Ok(())
+    }
+
     #[napi]
     pub async unsafe fn build(&mut self, data: Option<serde_json::Value>) -> Result<String> {
         let start_time = std::time::Instant::now();
 
-        // Create BuildRequest based on whether data was provided
-        let build_request = match data {
-            Some(json_data) => self.create_build_request_from_json(json_data)?,
-            None => self.create_build_request_from_stored_data()?,
-        };
+        let build_request = self.create_build_request(data)?;
 
-        // Use the actual DDEX builder
-        let builder = ddex_builder::builder::DDEXBuilder::new();
-        let options = ddex_builder::builder::BuildOptions::default();
+        let options = self.build_options();
 
-        let result = builder
+        let result = self
+            .builder
             .build(build_request, options)
             .map_err(|e| Error::new(Status::Unknown, format!("Build failed: {}", e)))?;
 
@@ -203,6 +369,79 @@ impl DdexBuilder {
         Ok(result.xml)
     }
 
+    /// Build straight into the file at `path` with the streaming builder,
+    /// so a huge catalog is never held as one string; memory stays bounded
+    /// by the streaming buffer. Writes ERN 4.3 in insertion order.
+    ///
+    /// This takes a path rather than a Node `Writable`: the build runs
+    /// synchronously on the calling thread, while a `Writable` only accepts
+    /// data from the event loop. To send the output elsewhere, build to a
+    /// temporary file and pipe `fs.createReadStream(path)` into the stream.
+    #[napi]
+    pub fn build_to_file(
+        &mut self,
+        path: String,
+        data: Option<serde_json::Value>,
+    ) -> Result<StreamingStats> {
+        let start_time = std::time::Instant::now();
+
+        let build_request = self.create_build_request(data)?;
+        let file = std::fs::File::create(&path).map_err(|e| {
+            Error::new(
+                Status::GenericFailure,
+                format!("Failed to create {}: {}", path, e),
+            )
+        })?;
+
+        let stats = self
+            .builder
+            .build_to_writer(
+                build_request,
+                file,
+                ddex_builder::streaming::StreamingConfig::default(),
+            )
+            .map_err(|e| Error::new(Status::Unknown, format!("Build failed: {}", e)))?;
+
+        self.stats.last_build_size_bytes = stats.bytes_written as f64;
+        self.stats.total_build_time_ms += start_time.elapsed().as_millis() as f64;
+
+        Ok(StreamingStats {
+            releases_written: stats.releases_written as u32,
+            resources_written: stats.resources_written as u32,
+            deals_written: stats.deals_written as u32,
+            bytes_written: stats.bytes_written as u32,
+            warnings: stats.warnings.iter().map(|w| w.message.clone()).collect(),
+            peak_memory_usage: stats.peak_memory_usage as u32,
+        })
+    }
+
+    /// Build an acknowledgement replying to a previously received message
+    #[napi]
+    pub fn build_acknowledgement(&mut self, options: AcknowledgementOptions) -> Result<String> {
+        let config = self.message_config.clone().map_or_else(
+            || ddex_builder::builder::MessageConfig::new("DDEX Suite", "Recipient"),
+            ddex_builder::builder::MessageConfig::from,
+        );
+        let mut header = config.to_header();
+        if options.thread_id.is_some() {
+            header.message_thread_id = options.thread_id;
+        }
+
+        let request = ddex_builder::AcknowledgementRequest {
+            header,
+            referenced_message_id: options.referenced_message_id,
+            status: options.status,
+        };
+
+        let result = self
+            .builder
+            .build_acknowledgement(request, ddex_builder::builder::BuildOptions::default())
+            .map_err(|e| Error::new(Status::InvalidArg, format!("Build failed: {}", e)))?;
+
+        self.stats.last_build_size_bytes = result.xml.len() as f64;
+        Ok(result.xml)
+    }
+
     #[napi]
     pub async unsafe fn build_with_fidelity(
         &mut self,
@@ -211,17 +450,19 @@ impl DdexBuilder {
     ) -> Result<BuildResult> {
         let start_time = std::time::Instant::now();
 
-        // Create BuildRequest based on whether data was provided
-        let build_request = match data {
-            Some(json_data) => self.create_build_request_from_json(json_data)?,
-            None => self.create_build_request_from_stored_data()?,
-        };
+        let build_request = self.create_build_request(data)?;
 
-        // Use the actual DDEX builder
-        let builder = ddex_builder::builder::DDEXBuilder::new();
-        let options = ddex_builder::builder::BuildOptions::default();
+        let collect_statistics = fidelity_options
+            .as_ref()
+            .and_then(|o| o.collect_statistics)
+            .unwrap_or(false);
+        let options = ddex_builder::builder::BuildOptions {
+            collect_statistics,
+            ..self.build_options()
+        };
 
-        let result = builder
+        let result = self
+            .builder
             .build(build_request, options)
             .map_err(|e| Error::new(Status::Unknown, format!("Build failed: {}", e)))?;
 
@@ -230,11 +471,8 @@ impl DdexBuilder {
         self.stats.total_build_time_ms += build_time;
 
         // Generate statistics if requested
-        let statistics = if fidelity_options
-            .as_ref()
-            .and_then(|o| o.collect_statistics)
-            .unwrap_or(false)
-        {
+        let timings = result.statistics.timings.unwrap_or_default();
+        let statistics = if collect_statistics {
             Some(BuildStatistics {
                 build_time_ms: build_time,
                 memory_used_bytes: result.xml.len() as u32 * 2,
@@ -243,7 +481,10 @@ impl DdexBuilder {
                 attribute_count: result.xml.matches('=').count() as u32,
                 namespace_count: result.xml.matches("xmlns").count() as u32,
                 extension_count: if result.xml.contains("xmlns:") { 1 } else { 0 },
-                canonicalization_time_ms: 2.0, // Mock value
+                validation_time_ms: timings.validation_ms,
+                serialization_time_ms: timings.serialization_ms,
+                canonicalization_time_ms: timings.canonicalization_ms,
+                hashing_time_ms: timings.hashing_ms,
                 verification_time_ms: None,
             })
         } else {
@@ -298,6 +539,16 @@ impl DdexBuilder {
             statistics,
             verification,
             fidelity_info,
+            referenced_files: result
+                .referenced_files
+                .into_iter()
+                .map(|file| ReferencedFile {
+                    resource_reference: file.resource_reference,
+                    uri: file.uri,
+                    hash_algorithm: file.hash_sum.as_ref().map(|hash| hash.algorithm.clone()),
+                    hash_value: file.hash_sum.map(|hash| hash.value),
+                })
+                .collect(),
         })
     }
 
@@ -323,24 +574,125 @@ impl DdexBuilder {
         })
     }
 
+    /// Check the stored releases before building: required fields, ISRC,
+    /// UPC, duration and release date formats, the applied preset's profile
+    /// (e.g. a UPC on every `AudioAlbum` release) and its validation rules.
+    /// Each message names the release or track at fault.
     #[napi]
     pub async fn validate(&self) -> Result<ValidationResult> {
+        if self.releases.is_empty() {
+            return Ok(ValidationResult {
+                is_valid: false,
+                errors: vec!["At least one release is required".to_string()],
+                warnings: vec![],
+            });
+        }
+
+        let request = self.validation_request()?;
+        let report = ddex_builder::PreflightValidator::new(ddex_builder::ValidationConfig {
+            profile: self.preset.as_ref().map(|p| p.info.profile.clone()),
+            ..Default::default()
+        })
+        .validate(&request)
+        .map_err(|e| Error::new(Status::GenericFailure, e.to_string()))?;
+
+        let issue = |code: &str, message: &str, location: &str| {
+            format!(
+                "{}: {} ({})",
+                code,
+                message,
+                describe_location(&request, location)
+            )
+        };
+        let mut errors: Vec<String> = report
+            .errors
+            .iter()
+            .map(|e| issue(&e.code, &e.message, &e.location))
+            .collect();
+        let warnings = report
+            .warnings
+            .iter()
+            .map(|w| issue(&w.code, &w.message, &w.location))
+            .collect();
+
+        if let Some(preset) = &self.preset {
+            // A value preflight already rejected is reported once
+            let rejected: indexmap::IndexSet<&str> =
+                report.errors.iter().map(|e| e.location.as_str()).collect();
+            for error in rule_engine(preset.rules.clone())?.evaluate(&request) {
+                if !rejected.contains(error.location.as_str()) {
+                    errors.push(issue(&error.code, &error.message, &error.location));
+                }
+            }
+        }
+
         Ok(ValidationResult {
-            is_valid: !self.releases.is_empty(),
-            errors: if self.releases.is_empty() {
-                vec!["At least one release is required".to_string()]
-            } else {
-                vec![]
-            },
+            is_valid: errors.is_empty(),
+            errors,
+            warnings,
+        })
+    }
+
+    /// Evaluate config-defined rules against `data` (or the stored releases)
+    ///
+    /// `Dependency` rules take `depends_on`, `condition` and, for
+    /// `RequiredWhenEquals`, `value` parameters.
+    #[napi]
+    pub fn evaluate_rules(
+        &self,
+        rules: Vec<ValidationRule>,
+        data: Option<serde_json::Value>,
+    ) -> Result<ValidationResult> {
+        let errors: Vec<String> = rule_engine(rules)?
+            .evaluate(&self.create_build_request(data)?)
+            .into_iter()
+            .map(|e| format!("{}: {} ({})", e.code, e.message, e.location))
+            .collect();
+
+        Ok(ValidationResult {
+            is_valid: errors.is_empty(),
+            errors,
             warnings: vec![],
         })
     }
 
+    /// Clean up ISRCs and UPCs without building: ISRCs are uppercased and
+    /// stripped of separators, UPCs stripped and zero-padded to 12 digits.
+    /// Each value says whether it then passes the build's own checks.
+    #[napi]
+    pub fn normalize_identifiers(&self, identifiers: IdentifierInput) -> NormalizedIdentifiers {
+        NormalizedIdentifiers {
+            isrcs: identifiers
+                .isrcs
+                .unwrap_or_default()
+                .iter()
+                .map(|isrc| ddex_builder::normalize_isrc(isrc).into())
+                .collect(),
+            upcs: identifiers
+                .upcs
+                .unwrap_or_default()
+                .iter()
+                .map(|upc| ddex_builder::normalize_upc(upc).into())
+                .collect(),
+        }
+    }
+
+    /// `count` sequential ISRCs for a registrant prefix such as `US-ABC` and a
+    /// year, numbered from 00001; errors on a malformed prefix
+    #[napi]
+    pub fn generate_isrcs(&self, prefix: String, count: u32, year: u32) -> Result<Vec<String>> {
+        ddex_builder::generate_isrcs(&prefix, count, year)
+            .map_err(|e| Error::new(Status::InvalidArg, e.to_string()))
+    }
+
     #[napi]
     pub fn get_stats(&self) -> Result<BuilderStats> {
         Ok(self.stats.clone())
     }
 
+    /// Clear releases, resources and stats to start the next message. The
+    /// applied preset, message config and output options are kept, so a
+    /// configured builder can be reused; use `reset_all` to drop them too.
     #[napi]
     pub fn reset(&mut self) -> Result<()> {
         self.releases.clear();
@@ -356,6 +708,37 @@ impl DdexBuilder {
         Ok(())
     }
 
+    /// Return the builder to its freshly constructed state, dropping the
+    /// applied preset, message config and output options along with the data
+    #[napi]
+    pub fn reset_all(&mut self) -> Result<()> {
+        *self = Self::new()?;
+        Ok(())
+    }
+
+    /// Versions, message types, canonicalization algorithms and feature
+    /// flags this build of the builder supports, with versions named as the
+    /// parser names them (`V4_3`)
+    #[napi]
+    pub fn capabilities(&self) -> Capabilities {
+        let capabilities = self.builder.capabilities();
+        Capabilities {
+            versions: capabilities
+                .versions
+                .into_iter()
+                .map(|version| match version {
+                    ddex_builder::DdexVersion::Ern382 => "V3_8_2".to_string(),
+                    ddex_builder::DdexVersion::Ern41 => "V4_1".to_string(),
+                    ddex_builder::DdexVersion::Ern42 => "V4_2".to_string(),
+                    ddex_builder::DdexVersion::Ern43 => "V4_3".to_string(),
+                })
+                .collect(),
+            message_types: capabilities.message_types,
+            canonicalization: capabilities.canonicalization,
+            features: capabilities.features,
+        }
+    }
+
     #[napi]
     pub fn get_available_presets(&self) -> Result<Vec<String>> {
         // Return list of available preset names
@@ -440,16 +823,54 @@ impl DdexBuilder {
         }
     }
 
+    /// Resolve `preset_name` once and keep it for every subsequent build
     #[napi]
     pub fn apply_preset(&mut self, preset_name: String) -> Result<()> {
-        // Validate preset exists
-        let _preset_info = self.get_preset_info(preset_name.clone())?;
+        let info = self.get_preset_info(preset_name.clone())?;
+        let rules = self.get_preset_validation_rules(preset_name.clone())?;
+
+        // Presets the core builder also knows drive header/profile defaults there
+        if self.builder.available_presets().contains(&preset_name) {
+            self.builder
+                .apply_preset(&preset_name)
+                .map_err(|e| Error::new(Status::InvalidArg, e.to_string()))?;
+        }
 
-        // In a full implementation, this would apply the preset configuration
-        // to the internal builder state. For now, we just validate the preset exists.
+        self.preset = Some(AppliedPreset { info, rules });
         Ok(())
     }
 
+    /// Info for the preset applied with `apply_preset`, if any
+    #[napi]
+    pub fn get_applied_preset(&self) -> Option<PresetInfo> {
+        self.preset.as_ref().map(|p| p.info.clone())
+    }
+
+    /// Validation rules of the applied preset, without re-resolving it
+    #[napi]
+    pub fn get_applied_preset_rules(&self) -> Vec<ValidationRule> {
+        self.preset
+            .as_ref()
+            .map(|p| p.rules.clone())
+            .unwrap_or_default()
+    }
+
+    /// Evaluate the applied preset's rules, dependencies included, against
+    /// `data` (or the stored releases)
+    #[napi]
+    pub fn evaluate_preset_rules(
+        &self,
+        data: Option<serde_json::Value>,
+    ) -> Result<ValidationResult> {
+        let preset = self.preset.as_ref().ok_or_else(|| {
+            Error::new(
+                Status::GenericFailure,
+                "No preset applied; call applyPreset first",
+            )
+        })?;
+        self.evaluate_rules(preset.rules.clone(), data)
+    }
+
     #[napi]
     pub fn get_preset_validation_rules(&self, preset_name: String) -> Result<Vec<ValidationRule>> {
         match preset_name.as_str() {
@@ -486,25 +907,45 @@ impl DdexBuilder {
                     ),
                 },
             ]),
-            "youtube_video" | "youtube_album" => Ok(vec![
-                ValidationRule {
-                    field_name: "ContentID".to_string(),
-                    rule_type: "Required".to_string(),
-                    message: "Content ID is required for YouTube releases".to_string(),
-                    parameters: None,
-                },
-                ValidationRule {
-                    field_name: "VideoQuality".to_string(),
-                    rule_type: "OneOf".to_string(),
-                    message: "Video quality must be HD720, HD1080, or 4K".to_string(),
-                    parameters: Some(
-                        [("options".to_string(), "HD720,HD1080,4K".to_string())]
+            "youtube_video" | "youtube_album" => {
+                let mut rules = vec![
+                    ValidationRule {
+                        field_name: "ContentID".to_string(),
+                        rule_type: "Required".to_string(),
+                        message: "Content ID is required for YouTube releases".to_string(),
+                        parameters: None,
+                    },
+                    ValidationRule {
+                        field_name: "VideoQuality".to_string(),
+                        rule_type: "OneOf".to_string(),
+                        message: "Video quality must be HD720, HD1080, or 4K".to_string(),
+                        parameters: Some(
+                            [("options".to_string(), "HD720,HD1080,4K".to_string())]
+                                .iter()
+                                .cloned()
+                                .collect(),
+                        ),
+                    },
+                ];
+                if preset_name == "youtube_video" {
+                    rules.push(ValidationRule {
+                        field_name: "VideoResource".to_string(),
+                        rule_type: "Dependency".to_string(),
+                        message: "Video releases need a video resource".to_string(),
+                        parameters: Some(
+                            [
+                                ("depends_on".to_string(), "ReleaseType".to_string()),
+                                ("condition".to_string(), "RequiredWhenEquals".to_string()),
+                                ("value".to_string(), "VideoSingle".to_string()),
+                            ]
                             .iter()
                             .cloned()
                             .collect(),
-                    ),
-                },
-            ]),
+                        ),
+                    });
+                }
+                Ok(rules)
+            }
             _ => Err(Error::new(
                 Status::InvalidArg,
                 format!("Unknown preset: {}", preset_name),
@@ -512,6 +953,79 @@ impl DdexBuilder {
         }
     }
 
+    /// A minimal message for `preset_name` with a `{{Field}}` placeholder
+    /// for each of its required fields, as a starting template
+    #[napi]
+    pub fn scaffold(&self, preset_name: String) -> Result<String> {
+        let (profile, required_fields) = match self.get_preset_info(preset_name.clone()) {
+            Ok(info) => (info.profile, info.required_fields),
+            Err(error) => {
+                let preset = ddex_builder::presets::all_presets()
+                    .shift_remove(&preset_name)
+                    .ok_or(error)?;
+                (
+                    format!("{:?}", preset.config.profile),
+                    preset.required_fields,
+                )
+            }
+        };
+
+        ddex_builder::scaffold::scaffold(&profile, &required_fields)
+            .map_err(|e| Error::new(Status::GenericFailure, e.to_string()))
+    }
+
+    fn build_options(&self) -> ddex_builder::builder::BuildOptions {
+        ddex_builder::builder::BuildOptions {
+            omit_empty: self.omit_empty,
+            sort_order: self.sort_order,
+            legacy_elements: self.legacy_elements.clone(),
+            ..Default::default()
+        }
+    }
+
+    fn create_build_request(
+        &self,
+        data: Option<serde_json::Value>,
+    ) -> Result<ddex_builder::builder::BuildRequest> {
+        // Create BuildRequest based on whether data was provided
+        let mut build_request = match data {
+            Some(json_data) => self.create_build_request_from_json(json_data)?,
+            None => self.create_build_request_from_stored_data()?,
+        };
+
+        if let Some(ref config) = self.message_config {
+            build_request.header =
+                ddex_builder::builder::MessageConfig::from(config.clone()).to_header();
+        }
+
+        Ok(build_request)
+    }
+
+    /// The stored data as a build request, but without the placeholder ISRCs
+    /// and durations a build fills in, so missing values are reported
+    fn validation_request(&self) -> Result<ddex_builder::builder::BuildRequest> {
+        let mut request = self.create_build_request_from_stored_data()?;
+        for track in request
+            .releases
+            .iter_mut()
+            .flat_map(|r| r.tracks.iter_mut())
+        {
+            if let Some(resource) = self
+                .resources
+                .iter()
+                .find(|r| r.resource_id == track.track_id)
+            {
+                if resource.isrc.is_none() {
+                    track.isrc.clear();
+                }
+                if resource.duration.is_none() {
+                    track.duration.clear();
+                }
+            }
+        }
+        Ok(request)
+    }
+
     fn create_build_request_from_json(
         &self,
         data: serde_json::Value,
@@ -527,27 +1041,23 @@ impl DdexBuilder {
             .unwrap_or("4.3")
             .to_string();
 
-        // Create message header
+        // Create message header, keeping the identity of a parsed message
+        let text = |key: &str| obj.get(key).and_then(|v| v.as_str()).map(|s| s.to_string());
         let header = ddex_builder::builder::MessageHeaderRequest {
-            message_id: Some(uuid::Uuid::new_v4().to_string()),
-            message_sender: ddex_builder::builder::PartyRequest {
-                party_name: vec![ddex_builder::builder::LocalizedStringRequest {
-                    text: "DDEX Suite".to_string(),
-                    language_code: None,
-                }],
-                party_id: None,
-                party_reference: None,
+            message_id: Some(text("message_id").unwrap_or_else(|| uuid::Uuid::new_v4().to_string())),
+            message_sender: match obj.get("message_sender") {
+                Some(sender) => party_request_from_json(Some(sender), "DDEX Suite")?,
+                None => parsed_party(obj, "sender", "DDEX Suite"),
             },
-            message_recipient: ddex_builder::builder::PartyRequest {
-                party_name: vec![ddex_builder::builder::LocalizedStringRequest {
-                    text: "Recipient".to_string(),
-                    language_code: None,
-                }],
-                party_id: None,
-                party_reference: None,
+            message_recipient: match obj.get("message_recipient") {
+                Some(recipient) => party_request_from_json(Some(recipient), "Recipient")?,
+                None => parsed_party(obj, "recipient", "Recipient"),
             },
-            message_control_type: None,
-            message_created_date_time: Some(chrono::Utc::now().to_rfc3339()),
+            message_control_type: text("message_control_type"),
+            message_created_date_time: Some(
+                text("message_date").unwrap_or_else(|| chrono::Utc::now().to_rfc3339()),
+            ),
+            message_thread_id: text("message_thread_id"),
         };
 
         // Convert releases from JSON
@@ -581,6 +1091,7 @@ impl DdexBuilder {
                             text: title,
                             language_code: None,
                         }],
+                        subtitle: None,
                         artist,
                         label: release_obj
                             .get("label")
@@ -595,7 +1106,61 @@ impl DdexBuilder {
                             .and_then(|v| v.as_str())
                             .map(|s| s.to_string()),
                         tracks: vec![], // No tracks in the simple format for now
+                        images: vec![],
+                        videos: vec![],
                         resource_references: None,
+                        display_title: release_obj
+                            .get("display_title")
+                            .and_then(|v| v.as_str())
+                            .map(|text| ddex_builder::builder::LocalizedStringRequest {
+                                text: text.to_string(),
+                                language_code: None,
+                            })
+                            .into_iter()
+                            .collect(),
+                        track_releases: vec![],
+                        preorder_date: release_obj
+                            .get("preorder_date")
+                            .and_then(|v| v.as_str())
+                            .map(|s| s.to_string()),
+                        component_release_references: release_obj
+                            .get("component_release_ids")
+                            .and_then(|v| v.as_array())
+                            .map(|ids| {
+                                ids.iter()
+                                    .filter_map(|id| id.as_str())
+                                    .map(|id| id.to_string())
+                                    .collect()
+                            })
+                            .unwrap_or_default(),
+                        release_dates_by_territory: release_obj
+                            .get("release_dates_by_territory")
+                            .and_then(|v| v.as_object())
+                            .map(|dates| {
+                                dates
+                                    .iter()
+                                    .filter_map(|(territory, date)| {
+                                        Some((territory.clone(), date.as_str()?.to_string()))
+                                    })
+                                    .collect()
+                            })
+                            .unwrap_or_default(),
+                        display_artists: release_obj
+                            .get("display_artists")
+                            .and_then(|v| v.as_array())
+                            .map(|artists| {
+                                artists
+                                    .iter()
+                                    .filter_map(|artist| {
+                                        Some(ddex_builder::builder::DisplayArtistRequest {
+                                            name: artist.get("name")?.as_str()?.to_string(),
+                                            role: artist.get("role")?.as_str()?.to_string(),
+                                        })
+                                    })
+                                    .collect()
+                            })
+                            .unwrap_or_default(),
+                        resource_groups: Vec::new(),
                     });
                 }
             }
@@ -623,6 +1188,7 @@ impl DdexBuilder {
                 }],
                 party_id: None,
                 party_reference: None,
+                trading_name: None,
             },
             message_recipient: ddex_builder::builder::PartyRequest {
                 party_name: vec![ddex_builder::builder::LocalizedStringRequest {
@@ -631,9 +1197,11 @@ impl DdexBuilder {
                 }],
                 party_id: None,
                 party_reference: None,
+                trading_name: None,
             },
             message_control_type: None,
             message_created_date_time: Some(chrono::Utc::now().to_rfc3339()),
+            message_thread_id: None,
         };
 
         // Convert releases
@@ -651,11 +1219,40 @@ impl DdexBuilder {
                         .clone()
                         .unwrap_or_else(|| "TEMP00000000".to_string()),
                     title: resource.title.clone(),
+                    subtitle: None,
                     duration: resource
                         .duration
                         .clone()
                         .unwrap_or_else(|| "PT3M00S".to_string()),
                     artist: resource.artist.clone(),
+                    file_path: resource.file_path.clone(),
+                    hash_sum: resource.hash_value.clone().map(|value| {
+                        ddex_builder::builder::HashSumRequest {
+                            algorithm: resource
+                                .hash_algorithm
+                                .clone()
+                                .unwrap_or_else(|| "SHA256".to_string()),
+                            value,
+                        }
+                    }),
+                    preview: resource
+                        .preview_start
+                        .clone()
+                        .zip(resource.preview_duration.clone())
+                        .map(|(start, duration)| ddex_builder::builder::PreviewRequest {
+                            start,
+                            duration,
+                        }),
+                    contributors: resource
+                        .contributors
+                        .iter()
+                        .flatten()
+                        .map(|contributor| ddex_builder::builder::ContributorRequest {
+                            name: contributor.name.clone(),
+                            roles: contributor.roles.clone(),
+                            instruments: contributor.instruments.clone().unwrap_or_default(),
+                        })
+                        .collect(),
                 })
                 .collect();
 
@@ -666,12 +1263,48 @@ impl DdexBuilder {
                     text: release.title.clone(),
                     language_code: None,
                 }],
+                subtitle: None,
                 artist: release.artist.clone(),
                 label: release.label.clone(),
                 release_date: release.release_date.clone(),
                 upc: release.upc.clone(),
                 tracks,
+                images: vec![],
+                videos: vec![],
                 resource_references: Some(release.track_ids.clone()),
+                display_title: release
+                    .display_title
+                    .iter()
+                    .map(|text| ddex_builder::builder::LocalizedStringRequest {
+                        text: text.clone(),
+                        language_code: None,
+                    })
+                    .collect(),
+                track_releases: vec![],
+                preorder_date: release.preorder_date.clone(),
+                component_release_references: release
+                    .component_release_ids
+                    .clone()
+                    .unwrap_or_default(),
+                // Sorted by territory so the output does not depend on hash order
+                release_dates_by_territory: release
+                    .release_dates_by_territory
+                    .iter()
+                    .flatten()
+                    .collect::<std::collections::BTreeMap<_, _>>()
+                    .into_iter()
+                    .map(|(territory, date)| (territory.clone(), date.clone()))
+                    .collect(),
+                display_artists: release
+                    .display_artists
+                    .iter()
+                    .flatten()
+                    .map(|artist| ddex_builder::builder::DisplayArtistRequest {
+                        name: artist.name.clone(),
+                        role: artist.role.clone(),
+                    })
+                    .collect(),
+                resource_groups: Vec::new(),
             });
         }
 
@@ -687,6 +1320,8 @@ impl DdexBuilder {
     }
 
     fn generate_placeholder_xml(&self) -> Result<String> {
+        use quick_xml::escape::escape;
+
         // Generate a basic DDEX-like XML structure for demonstration
         let mut xml = String::new();
         xml.push_str(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
@@ -718,12 +1353,18 @@ impl DdexBuilder {
             xml.push_str("    <Release>\n");
             xml.push_str(&format!(
                 "      <ReleaseId>{}</ReleaseId>\n",
-                release.release_id
+                escape(&release.release_id)
+            ));
+            xml.push_str(&format!(
+                "      <Title>{}</Title>\n",
+                escape(&release.title)
+            ));
+            xml.push_str(&format!(
+                "      <Artist>{}</Artist>\n",
+                escape(&release.artist)
             ));
-            xml.push_str(&format!("      <Title>{}</Title>\n", release.title));
-            xml.push_str(&format!("      <Artist>{}</Artist>\n", release.artist));
             if let Some(ref label) = release.label {
-                xml.push_str(&format!("      <Label>{}</Label>\n", label));
+                xml.push_str(&format!("      <Label>{}</Label>\n", escape(label)));
             }
             xml.push_str("    </Release>\n");
             xml.push_str("  </ReleaseList>\n");
@@ -735,12 +1376,18 @@ impl DdexBuilder {
             xml.push_str("    <SoundRecording>\n");
             xml.push_str(&format!(
                 "      <ResourceId>{}</ResourceId>\n",
-                resource.resource_id
+                escape(&resource.resource_id)
+            ));
+            xml.push_str(&format!(
+                "      <Title>{}</Title>\n",
+                escape(&resource.title)
+            ));
+            xml.push_str(&format!(
+                "      <Artist>{}</Artist>\n",
+                escape(&resource.artist)
             ));
-            xml.push_str(&format!("      <Title>{}</Title>\n", resource.title));
-            xml.push_str(&format!("      <Artist>{}</Artist>\n", resource.artist));
             if let Some(ref isrc) = resource.isrc {
-                xml.push_str(&format!("      <ISRC>{}</ISRC>\n", isrc));
+                xml.push_str(&format!("      <ISRC>{}</ISRC>\n", escape(isrc)));
             }
             xml.push_str("    </SoundRecording>\n");
             xml.push_str("  </ResourceList>\n");
@@ -788,6 +1435,193 @@ pub struct MessageHeader {
     pub message_sender_name: String,
     pub message_recipient_name: String,
     pub message_created_date_time: Option<String>,
+    /// Localized sender names; when set, these replace `message_sender_name`
+    pub message_sender_names: Option<Vec<LocalizedName>>,
+    pub message_sender_trading_name: Option<String>,
+    /// Localized recipient names; when set, these replace `message_recipient_name`
+    pub message_recipient_names: Option<Vec<LocalizedName>>,
+    pub message_recipient_trading_name: Option<String>,
+}
+
+/// Options for `DdexBuilder.buildAcknowledgement`
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AcknowledgementOptions {
+    /// Thread of the original delivery; defaults to the message config's thread id
+    pub thread_id: Option<String>,
+    /// MessageId of the message being acknowledged
+    pub referenced_message_id: String,
+    /// Processing status (e.g. "FileOK", "SchemaValidationError")
+    pub status: String,
+}
+
+/// Message header configuration shared by `DdexBuilder` and `StreamingDdexBuilder`
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageConfig {
+    pub sender_name: String,
+    pub sender_id: Option<String>,
+    pub recipient_name: String,
+    pub recipient_id: Option<String>,
+    pub message_control_type: Option<String>,
+    pub message_thread_id: Option<String>,
+    pub message_created_date_time: Option<String>,
+    /// Message ID template; `{uuid}`, `{date}` and `{timestamp}` are substituted
+    pub message_id_template: Option<String>,
+}
+
+impl From<MessageConfig> for ddex_builder::builder::MessageConfig {
+    fn from(config: MessageConfig) -> Self {
+        ddex_builder::builder::MessageConfig {
+            sender_name: config.sender_name,
+            sender_id: config.sender_id,
+            recipient_name: config.recipient_name,
+            recipient_id: config.recipient_id,
+            message_control_type: config.message_control_type,
+            message_thread_id: config.message_thread_id,
+            message_created_date_time: config.message_created_date_time,
+            message_id_template: config.message_id_template,
+        }
+    }
+}
+
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LocalizedName {
+    pub text: String,
+    pub language_code: Option<String>,
+}
+
+fn rule_engine(rules: Vec<ValidationRule>) -> Result<ddex_builder::RuleEngine> {
+    ddex_builder::RuleEngine::new(
+        rules
+            .into_iter()
+            .map(|rule| ddex_builder::RuleConfig {
+                field_name: rule.field_name,
+                rule_type: rule.rule_type,
+                message: rule.message,
+                parameters: rule.parameters.unwrap_or_default().into_iter().collect(),
+            })
+            .collect(),
+    )
+    .map_err(|e| Error::new(Status::InvalidArg, e.to_string()))
+}
+
+/// `location` with release and track indices replaced by their IDs, e.g.
+/// `/releases[0]/tracks[1]/isrc` becomes `release R1, track A2, isrc`
+fn describe_location(request: &ddex_builder::builder::BuildRequest, location: &str) -> String {
+    let index = |segment: &str, name: &str| {
+        segment
+            .strip_prefix(name)?
+            .strip_prefix('[')?
+            .strip_suffix(']')?
+            .parse::<usize>()
+            .ok()
+    };
+
+    let mut release = None;
+    location
+        .trim_start_matches('/')
+        .split('/')
+        .map(|segment| {
+            let named = if let Some(r) = index(segment, "releases") {
+                release = request.releases.get(r);
+                release.map(|release| format!("release {}", release.release_id))
+            } else if let Some(t) = index(segment, "tracks") {
+                release
+                    .and_then(|release| release.tracks.get(t))
+                    .map(|track| format!("track {}", track.track_id))
+            } else {
+                None
+            };
+            named.unwrap_or_else(|| segment.to_string())
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn party_request_from_names(
+    name: String,
+    names: Option<Vec<LocalizedName>>,
+    trading_name: Option<String>,
+) -> ddex_builder::builder::PartyRequest {
+    let party_name = match names {
+        Some(names) if !names.is_empty() => names
+            .into_iter()
+            .map(|n| ddex_builder::builder::LocalizedStringRequest {
+                text: n.text,
+                language_code: n.language_code,
+            })
+            .collect(),
+        _ => vec![ddex_builder::builder::LocalizedStringRequest {
+            text: name,
+            language_code: None,
+        }],
+    };
+
+    ddex_builder::builder::PartyRequest {
+        party_name,
+        party_id: None,
+        party_reference: None,
+        trading_name,
+    }
+}
+
+/// Build a party from JSON of the form
+/// `{ party_id?, party_name: string | [{ text, language_code? }], trading_name? }`
+/// Party from the `{role}_name` and `{role}_id` fields of a parsed message
+fn parsed_party(
+    obj: &serde_json::Map<String, serde_json::Value>,
+    role: &str,
+    default_name: &str,
+) -> ddex_builder::builder::PartyRequest {
+    let text = |key: String| obj.get(&key).and_then(|v| v.as_str()).map(|s| s.to_string());
+    let mut party = party_request_from_names(
+        text(format!("{}_name", role)).unwrap_or_else(|| default_name.to_string()),
+        None,
+        None,
+    );
+    party.party_id = text(format!("{}_id", role)).filter(|id| !id.is_empty());
+    party
+}
+
+fn party_request_from_json(
+    value: Option<&serde_json::Value>,
+    default_name: &str,
+) -> Result<ddex_builder::builder::PartyRequest> {
+    let obj = match value.and_then(|v| v.as_object()) {
+        Some(obj) => obj,
+        None => {
+            return Ok(party_request_from_names(
+                default_name.to_string(),
+                None,
+                None,
+            ))
+        }
+    };
+
+    let names = match obj.get("party_name") {
+        Some(serde_json::Value::String(text)) => vec![LocalizedName {
+            text: text.clone(),
+            language_code: None,
+        }],
+        Some(names @ serde_json::Value::Array(_)) => serde_json::from_value(names.clone())
+            .map_err(|e| Error::new(Status::InvalidArg, format!("Invalid party_name: {}", e)))?,
+        _ => Vec::new(),
+    };
+
+    let mut party = party_request_from_names(
+        default_name.to_string(),
+        Some(names),
+        obj.get("trading_name")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string()),
+    );
+    party.party_id = obj
+        .get("party_id")
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_string());
+    Ok(party)
 }
 
 #[napi]
@@ -838,6 +1672,41 @@ impl StreamingDdexBuilder {
 
     #[napi]
     pub fn start_message(&mut self, header: MessageHeader, version: String) -> Result<()> {
+        let rust_header = ddex_builder::builder::MessageHeaderRequest {
+            message_id: header.message_id,
+            message_sender: party_request_from_names(
+                header.message_sender_name,
+                header.message_sender_names,
+                header.message_sender_trading_name,
+            ),
+            message_recipient: party_request_from_names(
+                header.message_recipient_name,
+                header.message_recipient_names,
+                header.message_recipient_trading_name,
+            ),
+            message_control_type: None,
+            message_created_date_time: header.message_created_date_time,
+            message_thread_id: None,
+        };
+
+        self.begin_message(&rust_header, &version)
+    }
+
+    #[napi]
+    pub fn start_message_with_config(
+        &mut self,
+        config: MessageConfig,
+        version: String,
+    ) -> Result<()> {
+        let config = ddex_builder::builder::MessageConfig::from(config);
+        self.begin_message(&config.to_header(), &version)
+    }
+
+    fn begin_message(
+        &mut self,
+        header: &ddex_builder::builder::MessageHeaderRequest,
+        version: &str,
+    ) -> Result<()> {
         // Create a new buffer and streaming builder
         self.buffer = Cursor::new(Vec::new());
 
@@ -882,31 +1751,8 @@ impl StreamingDdexBuilder {
             ));
         }
 
-        // Convert header to Rust type
-        let rust_header = ddex_builder::builder::MessageHeaderRequest {
-            message_id: header.message_id,
-            message_sender: ddex_builder::builder::PartyRequest {
-                party_name: vec![ddex_builder::builder::LocalizedStringRequest {
-                    text: header.message_sender_name,
-                    language_code: None,
-                }],
-                party_id: None,
-                party_reference: None,
-            },
-            message_recipient: ddex_builder::builder::PartyRequest {
-                party_name: vec![ddex_builder::builder::LocalizedStringRequest {
-                    text: header.message_recipient_name,
-                    language_code: None,
-                }],
-                party_id: None,
-                party_reference: None,
-            },
-            message_control_type: None,
-            message_created_date_time: header.message_created_date_time,
-        };
-
         streaming_builder
-            .start_message(&rust_header, &version)
+            .start_message(header, version)
             .map_err(|e| Error::new(Status::Unknown, format!("Failed to start message: {}", e)))?;
 
         self.inner = Some(streaming_builder);
@@ -1034,12 +1880,22 @@ impl StreamingDdexBuilder {
         })
     }
 
+    /// Discard the message in progress to start the next one. The config
+    /// and progress callback are kept; use `reset_all` to drop them too.
     #[napi]
     pub fn reset(&mut self) -> Result<()> {
         self.inner = None;
         self.buffer = Cursor::new(Vec::new());
         Ok(())
     }
+
+    /// Return the builder to its default state, dropping the config and
+    /// progress callback along with the message in progress
+    #[napi]
+    pub fn reset_all(&mut self) -> Result<()> {
+        *self = Self::new(None)?;
+        Ok(())
+    }
 }
 
 #[napi]
@@ -1081,3 +1937,223 @@ pub async fn validate_structure(xml: String) -> Result<ValidationResult> {
         }),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scaffold_spotify_single_has_required_placeholders() {
+        let xml = DdexBuilder::new()
+            .unwrap()
+            .scaffold("spotify_single".to_string())
+            .unwrap();
+
+        assert!(xml.contains("<ISRC>{{ISRC}}</ISRC>"));
+        assert!(xml.contains("<ICPN>{{UPC}}</ICPN>"));
+        assert!(xml.contains("<ReleaseDate>{{ReleaseDate}}</ReleaseDate>"));
+        assert!(xml.contains("<GenreText>{{Genre}}</GenreText>"));
+        assert!(xml.contains("<ParentalWarningType>{{ExplicitContent}}</ParentalWarningType>"));
+        assert!(xml.contains("{{TrackTitle}}"));
+        assert!(xml.contains("{{ArtistName}}"));
+    }
+
+    #[test]
+    fn test_scaffold_unknown_preset_fails() {
+        assert!(DdexBuilder::new()
+            .unwrap()
+            .scaffold("no_such_preset".to_string())
+            .is_err());
+    }
+
+    const SPECIAL: &str = r#"Rock & Roll <Live> "Deluxe" 'Edition'"#;
+
+    fn builder_with_special_characters() -> DdexBuilder {
+        let mut builder = DdexBuilder::new().unwrap();
+        builder
+            .add_resource(Resource {
+                resource_id: "A1".to_string(),
+                resource_type: "SoundRecording".to_string(),
+                title: SPECIAL.to_string(),
+                artist: SPECIAL.to_string(),
+                isrc: Some("USRC17607839".to_string()),
+                duration: Some("PT3M30S".to_string()),
+                track_number: Some(1),
+                volume_number: None,
+                metadata: None,
+                file_path: None,
+                hash_algorithm: None,
+                hash_value: None,
+                preview_start: None,
+                preview_duration: None,
+                contributors: None,
+            })
+            .unwrap();
+        builder
+            .add_release(Release {
+                release_id: "R1".to_string(),
+                release_type: "Single".to_string(),
+                title: SPECIAL.to_string(),
+                display_title: None,
+                artist: SPECIAL.to_string(),
+                display_artists: None,
+                label: Some(SPECIAL.to_string()),
+                catalog_number: None,
+                upc: None,
+                release_date: None,
+                release_dates_by_territory: None,
+                preorder_date: None,
+                component_release_ids: None,
+                genre: None,
+                parental_warning: None,
+                track_ids: vec!["A1".to_string()],
+                metadata: None,
+            })
+            .unwrap();
+        builder
+    }
+
+    /// Decoded text of every element named `name`, failing if `xml` does
+    /// not parse
+    fn texts_of(xml: &str, name: &str) -> Vec<String> {
+        let mut reader = quick_xml::Reader::from_str(xml);
+        reader.check_end_names(true).trim_text(true);
+        let mut inside = false;
+        let mut texts = Vec::new();
+        loop {
+            match reader.read_event().unwrap() {
+                quick_xml::events::Event::Start(e) => {
+                    inside = e.local_name().as_ref() == name.as_bytes()
+                }
+                quick_xml::events::Event::Text(e) if inside => {
+                    texts.push(e.unescape().unwrap().into_owned())
+                }
+                quick_xml::events::Event::End(_) => inside = false,
+                quick_xml::events::Event::Eof => break,
+                _ => {}
+            }
+        }
+        texts
+    }
+
+    #[test]
+    fn test_placeholder_xml_escapes_special_characters() {
+        let xml = builder_with_special_characters()
+            .generate_placeholder_xml()
+            .unwrap();
+
+        assert_eq!(texts_of(&xml, "Title"), [SPECIAL, SPECIAL]);
+        assert_eq!(texts_of(&xml, "Artist"), [SPECIAL, SPECIAL]);
+        assert_eq!(texts_of(&xml, "Label"), [SPECIAL]);
+    }
+
+    #[test]
+    fn test_build_escapes_special_characters() {
+        let builder = builder_with_special_characters();
+        let request = builder.create_build_request(None).unwrap();
+        let xml = builder
+            .builder
+            .build(request, builder.build_options())
+            .unwrap()
+            .xml;
+
+        ddex_builder::generator::xml_writer::check_well_formed(&xml).unwrap();
+        let titles = texts_of(&xml, "TitleText");
+        assert!(!titles.is_empty());
+        assert!(titles.iter().all(|title| title == SPECIAL), "{:?}", titles);
+        assert_eq!(texts_of(&xml, "LabelName"), [SPECIAL]);
+    }
+
+    #[test]
+    fn test_reset_keeps_the_applied_preset() {
+        let mut builder = builder_with_special_characters();
+        builder.apply_preset("youtube_video".to_string()).unwrap();
+        builder.set_omit_empty(false).unwrap();
+
+        builder.reset().unwrap();
+        assert!(builder.releases.is_empty() && builder.resources.is_empty());
+        assert_eq!(builder.get_applied_preset().unwrap().name, "youtube_video");
+        assert!(builder.builder.preset().is_some());
+        assert!(!builder.omit_empty);
+
+        builder.reset_all().unwrap();
+        assert!(builder.get_applied_preset().is_none());
+        assert!(builder.builder.preset().is_none());
+        assert!(builder.omit_empty);
+    }
+
+    fn validate(builder: &DdexBuilder) -> ValidationResult {
+        tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap()
+            .block_on(builder.validate())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_validate_reports_profile_fields_by_release_and_track() {
+        let mut builder = builder_with_special_characters();
+        builder.releases[0].release_date = Some("2024-13-45".to_string());
+        let mut second = builder.resources[0].clone();
+        second.resource_id = "A2".to_string();
+        second.isrc = None;
+        second.duration = Some("3:45".to_string());
+        builder.add_resource(second).unwrap();
+        builder.releases[0].track_ids.push("A2".to_string());
+
+        // Without a preset there is no profile demanding a UPC
+        let result = validate(&builder);
+        let codes: Vec<&str> = result
+            .errors
+            .iter()
+            .map(|e| &e[..e.find(':').unwrap()])
+            .collect();
+        assert_eq!(codes, ["INVALID_DATE", "MISSING_ISRC"]);
+
+        builder.apply_preset("spotify_album".to_string()).unwrap();
+        let result = validate(&builder);
+        assert!(!result.is_valid);
+        assert_eq!(
+            result.errors,
+            [
+                "INVALID_DATE: Invalid ISO 8601 release date: 2024-13-45 (release R1, release_date)",
+                "MISSING_ISRC: Track ISRC is required (release R1, track A2, isrc)",
+                "MISSING_UPC: UPC is required for AudioAlbum profile (release R1, upc)",
+            ]
+        );
+        assert!(result.warnings.contains(
+            &"INVALID_DURATION: Invalid ISO 8601 duration: 3:45 (release R1, track A2, duration)"
+                .to_string()
+        ));
+    }
+
+    #[test]
+    fn test_validate_accepts_date_times_and_reduced_dates() {
+        let mut builder = builder_with_special_characters();
+        for date in ["2024-03-15T10:00:00Z", "2024-03-15T10:00:00.5+01:00", "2024-03", "2024"] {
+            builder.releases[0].release_date = Some(date.to_string());
+            assert!(validate(&builder).is_valid, "{}", date);
+        }
+
+        builder.releases[0].release_date = Some("2024-03-15T25:00:00Z".to_string());
+        assert!(!validate(&builder).is_valid);
+    }
+
+    #[test]
+    fn test_validate_enforces_the_applied_preset_rules() {
+        let mut builder = builder_with_special_characters();
+        assert!(validate(&builder).is_valid);
+
+        builder.apply_preset("youtube_video".to_string()).unwrap();
+        let result = validate(&builder);
+        assert!(!result.is_valid);
+        assert!(
+            result
+                .errors
+                .iter()
+                .any(|e| e.contains("Content ID is required for YouTube releases")),
+            "{:?}",
+            result.errors
+        );
+    }
+}
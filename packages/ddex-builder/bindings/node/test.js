@@ -1,4 +1,5 @@
-const { DdexBuilder, batchBuild, validateStructure } = require('./index.js');
+const assert = require('assert');
+const { DdexBuilder, StreamingDdexBuilder, batchBuild, validateStructure } = require('./index.js');
 
 async function testBasicUsage() {
     console.log('Testing basic DdexBuilder usage...');
@@ -137,6 +138,44 @@ async function testValidateStructure() {
     }
 }
 
+async function testResetKeepsConfiguration() {
+    console.log('\nTesting reset and resetAll...');
+
+    const builder = new DdexBuilder();
+    builder.applyPreset('youtube_video');
+    builder.reset();
+    assert.strictEqual(builder.getAppliedPreset().name, 'youtube_video');
+    builder.resetAll();
+    assert.strictEqual(builder.getAppliedPreset(), null);
+    console.log('✓ reset keeps the applied preset, resetAll clears it');
+
+    const streaming = new StreamingDdexBuilder({
+        maxBufferSize: 1024 * 1024,
+        deterministic: true,
+        validateDuringStream: false,
+        progressCallbackFrequency: 1
+    });
+    let calls = 0;
+    streaming.setProgressCallback(() => { calls++; });
+
+    // Stream one resource and give the callback a chance to run
+    const streamOne = async () => {
+        streaming.startMessage({ messageSenderName: 'Sender', messageRecipientName: 'Recipient' }, '4.3');
+        streaming.writeResource('A1', 'Track', 'Artist', 'USRC17607839', 'PT3M00S', null);
+        await new Promise((resolve) => setTimeout(resolve, 50));
+    };
+
+    streaming.reset();
+    await streamOne();
+    assert.ok(calls > 0, 'progress callback should survive reset');
+
+    streaming.resetAll();
+    const before = calls;
+    await streamOne();
+    assert.strictEqual(calls, before, 'resetAll should drop the progress callback');
+    console.log('✓ streaming reset keeps the progress callback, resetAll drops it');
+}
+
 async function runAllTests() {
     console.log('=== DDEX Builder Node.js Binding Tests ===\n');
     
@@ -144,6 +183,7 @@ async function runAllTests() {
         await testBasicUsage();
         await testBatchBuild();
         await testValidateStructure();
+        await testResetKeepsConfiguration();
         
         console.log('\n=== Test Suite Completed ===');
         console.log('Note: Some failures are expected due to incomplete builder implementation.');
@@ -164,5 +204,6 @@ module.exports = {
     testBasicUsage,
     testBatchBuild, 
     testValidateStructure,
+    testResetKeepsConfiguration,
     runAllTests
 };
\ No newline at end of file
@@ -574,7 +574,11 @@ impl DdexBuilder {
 
         // Use the actual DDEX builder
         let builder = DDEXBuilder::new();
-        let options = BuildOptions::default();
+        let collect_statistics = fidelity_options.map_or(false, |o| o.collect_statistics);
+        let options = BuildOptions {
+            collect_statistics,
+            ..BuildOptions::default()
+        };
 
         let result = builder.build(build_request, options).map_err(|e| {
             PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Build failed: {}", e))
@@ -585,7 +589,8 @@ impl DdexBuilder {
         self.stats.total_build_time_ms += build_time;
 
         // Generate statistics if requested
-        let statistics = if fidelity_options.map_or(false, |o| o.collect_statistics) {
+        let statistics = if collect_statistics {
+            let timings = result.statistics.timings.unwrap_or_default();
             Some(BuildStatistics::new(
                 build_time,
                 result.xml.len() as u32 * 2,
@@ -594,7 +599,7 @@ impl DdexBuilder {
                 result.xml.matches('=').count() as u32,
                 result.xml.matches("xmlns").count() as u32,
                 if result.xml.contains("xmlns:") { 1 } else { 0 },
-                2.0, // Mock canonicalization time
+                timings.canonicalization_ms,
                 None,
             ))
         } else {
@@ -1118,6 +1123,8 @@ impl DdexBuilder {
         releases: Vec<Release>,
         resources: Vec<Resource>,
     ) -> PyResult<String> {
+        use quick_xml::escape::escape;
+
         // Generate basic DDEX XML structure
         let mut xml = String::new();
         xml.push_str(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
@@ -1145,22 +1152,28 @@ impl DdexBuilder {
                 xml.push_str("<SoundRecording>");
                 xml.push_str(&format!(
                     "<ResourceReference>{}</ResourceReference>",
-                    resource.resource_id
+                    escape(&resource.resource_id)
                 ));
                 if let Some(isrc) = &resource.isrc {
                     xml.push_str("<ResourceId>");
-                    xml.push_str(&format!("<ISRC>{}</ISRC>", isrc));
+                    xml.push_str(&format!("<ISRC>{}</ISRC>", escape(isrc)));
                     xml.push_str("</ResourceId>");
                 }
                 xml.push_str("<ReferenceTitle>");
-                xml.push_str(&format!("<TitleText>{}</TitleText>", resource.title));
+                xml.push_str(&format!(
+                    "<TitleText>{}</TitleText>",
+                    escape(&resource.title)
+                ));
                 xml.push_str("</ReferenceTitle>");
                 if let Some(duration) = &resource.duration {
-                    xml.push_str(&format!("<Duration>{}</Duration>", duration));
+                    xml.push_str(&format!("<Duration>{}</Duration>", escape(duration)));
                 }
                 xml.push_str("<DisplayArtist>");
                 xml.push_str("<PartyName>");
-                xml.push_str(&format!("<FullName>{}</FullName>", resource.artist));
+                xml.push_str(&format!(
+                    "<FullName>{}</FullName>",
+                    escape(&resource.artist)
+                ));
                 xml.push_str("</PartyName>");
                 xml.push_str("</DisplayArtist>");
                 xml.push_str("</SoundRecording>");
@@ -1175,21 +1188,24 @@ impl DdexBuilder {
                 xml.push_str("<Release>");
                 xml.push_str(&format!(
                     "<ReleaseReference>{}</ReleaseReference>",
-                    release.release_id
+                    escape(&release.release_id)
                 ));
                 xml.push_str("<ReleaseId>");
                 xml.push_str(&format!(
                     "<ProprietaryId>{}</ProprietaryId>",
-                    release.release_id
+                    escape(&release.release_id)
                 ));
                 xml.push_str("</ReleaseId>");
                 xml.push_str("<ReferenceTitle>");
-                xml.push_str(&format!("<TitleText>{}</TitleText>", release.title));
+                xml.push_str(&format!(
+                    "<TitleText>{}</TitleText>",
+                    escape(&release.title)
+                ));
                 xml.push_str("</ReferenceTitle>");
                 if !release.artist.is_empty() {
                     xml.push_str("<DisplayArtist>");
                     xml.push_str("<PartyName>");
-                    xml.push_str(&format!("<FullName>{}</FullName>", release.artist));
+                    xml.push_str(&format!("<FullName>{}</FullName>", escape(&release.artist)));
                     xml.push_str("</PartyName>");
                     xml.push_str("</DisplayArtist>");
                 }
@@ -1203,6 +1219,8 @@ impl DdexBuilder {
     }
 
     fn build_xml_from_releases(&self, releases: Vec<Release>) -> PyResult<String> {
+        use quick_xml::escape::escape;
+
         // Generate basic DDEX XML structure
         let mut xml = String::new();
         xml.push_str(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
@@ -1230,21 +1248,24 @@ impl DdexBuilder {
                 xml.push_str("<Release>");
                 xml.push_str(&format!(
                     "<ReleaseReference>{}</ReleaseReference>",
-                    release.release_id
+                    escape(&release.release_id)
                 ));
                 xml.push_str("<ReleaseId>");
                 xml.push_str(&format!(
                     "<ProprietaryId>{}</ProprietaryId>",
-                    release.release_id
+                    escape(&release.release_id)
                 ));
                 xml.push_str("</ReleaseId>");
                 xml.push_str("<ReferenceTitle>");
-                xml.push_str(&format!("<TitleText>{}</TitleText>", release.title));
+                xml.push_str(&format!(
+                    "<TitleText>{}</TitleText>",
+                    escape(&release.title)
+                ));
                 xml.push_str("</ReferenceTitle>");
                 if !release.artist.is_empty() {
                     xml.push_str("<DisplayArtist>");
                     xml.push_str("<PartyName>");
-                    xml.push_str(&format!("<FullName>{}</FullName>", release.artist));
+                    xml.push_str(&format!("<FullName>{}</FullName>", escape(&release.artist)));
                     xml.push_str("</PartyName>");
                     xml.push_str("</DisplayArtist>");
                 }
@@ -1446,6 +1467,7 @@ impl DdexBuilder {
                 }],
                 party_id: None,
                 party_reference: None,
+                trading_name: None,
             },
             message_recipient: PartyRequest {
                 party_name: vec![LocalizedStringRequest {
@@ -1454,9 +1476,11 @@ impl DdexBuilder {
                 }],
                 party_id: None,
                 party_reference: None,
+                trading_name: None,
             },
             message_control_type: Some(parsed_result.flat.message_type.clone()),
             message_created_date_time: Some(parsed_result.flat.message_date.to_rfc3339()),
+            message_thread_id: None,
         };
 
         let mut releases = Vec::new();
@@ -1472,8 +1496,13 @@ impl DdexBuilder {
                         .clone()
                         .unwrap_or_else(|| "TEMP00000000".to_string()),
                     title: track.title.clone(),
+                    subtitle: None,
                     duration: format!("PT{}S", track.duration.as_secs()),
                     artist: track.display_artist.clone(),
+                    file_path: None,
+                    hash_sum: None,
+                    preview: None,
+                    contributors: vec![],
                 })
                 .collect();
 
@@ -1484,14 +1513,24 @@ impl DdexBuilder {
                     text: release.default_title.clone(),
                     language_code: None,
                 }],
+                subtitle: None,
                 artist: release.display_artist.clone(),
                 label: None,        // Simplified
                 release_date: None, // Simplified
                 upc: None,          // Simplified
                 tracks,
+                images: vec![],
+                videos: vec![],
                 resource_references: Some(
                     release.tracks.iter().map(|t| t.track_id.clone()).collect(),
                 ),
+                display_title: vec![],
+                track_releases: vec![],
+                preorder_date: None,
+                component_release_references: vec![],
+                release_dates_by_territory: Default::default(),
+                display_artists: Default::default(),
+                resource_groups: Default::default(),
             });
         }
 
@@ -1516,6 +1555,7 @@ impl DdexBuilder {
                 }],
                 party_id: None,
                 party_reference: None,
+                trading_name: None,
             },
             message_recipient: PartyRequest {
                 party_name: vec![LocalizedStringRequest {
@@ -1524,9 +1564,11 @@ impl DdexBuilder {
                 }],
                 party_id: None,
                 party_reference: None,
+                trading_name: None,
             },
             message_control_type: None,
             message_created_date_time: Some(chrono::Utc::now().to_rfc3339()),
+            message_thread_id: None,
         };
 
         // Convert releases
@@ -1544,11 +1586,16 @@ impl DdexBuilder {
                         .clone()
                         .unwrap_or_else(|| "TEMP00000000".to_string()),
                     title: resource.title.clone(),
+                    subtitle: None,
                     duration: resource
                         .duration
                         .clone()
                         .unwrap_or_else(|| "PT180S".to_string()),
                     artist: resource.artist.clone(),
+                    file_path: None,
+                    hash_sum: None,
+                    preview: None,
+                    contributors: vec![],
                 })
                 .collect();
 
@@ -1559,12 +1606,22 @@ impl DdexBuilder {
                     text: release.title.clone(),
                     language_code: None,
                 }],
+                subtitle: None,
                 artist: release.artist.clone(),
                 label: release.label.clone(),
                 release_date: release.release_date.clone(),
                 upc: release.upc.clone(),
                 tracks,
+                images: vec![],
+                videos: vec![],
                 resource_references: Some(release.track_ids.clone()),
+                display_title: vec![],
+                track_releases: vec![],
+                preorder_date: None,
+                component_release_references: vec![],
+                release_dates_by_territory: Default::default(),
+                display_artists: Default::default(),
+                resource_groups: Default::default(),
             });
         }
 
@@ -1,8 +1,8 @@
 //! Interactive HTML diff viewer for WASM
 
-use ddex_builder::ast::{Element, AST};
+use ddex_builder::ast::AST;
 use ddex_builder::diff::formatter::DiffFormatter;
-use ddex_builder::diff::{DiffConfig, DiffEngine};
+use ddex_builder::diff::{parse_xml, DiffConfig, DiffEngine};
 use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::*;
 
@@ -100,15 +100,7 @@ impl DdexDiffViewer {
     // Private helper methods
 
     fn parse_xml_simple(&self, xml: &str) -> Result<AST, JsError> {
-        // Simplified XML parsing for WASM demo
-        // In production, you'd want proper XML parsing
-        let root = Element::new("Root").with_text(xml);
-
-        Ok(AST {
-            root,
-            namespaces: indexmap::IndexMap::new(),
-            schema_location: None,
-        })
+        parse_xml(xml).map_err(|e| JsError::new(&format!("XML parse error: {}", e)))
     }
 
     fn generate_interactive_html(
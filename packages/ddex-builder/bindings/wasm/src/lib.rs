@@ -470,6 +470,8 @@ pub struct WasmDdexBuilder {
     releases: Vec<Release>,
     resources: Vec<Resource>,
     stats: BuilderStats,
+    message_sender: Option<ddex_builder::builder::PartyRequest>,
+    message_recipient: Option<ddex_builder::builder::PartyRequest>,
 }
 
 #[wasm_bindgen]
@@ -482,9 +484,33 @@ impl WasmDdexBuilder {
             releases: Vec::new(),
             resources: Vec::new(),
             stats: BuilderStats::new(),
+            message_sender: None,
+            message_recipient: None,
         })
     }
 
+    /// Set the message sender, e.g.
+    /// `{ party_id, party_name: [{ text, language_code }], trading_name }`
+    #[wasm_bindgen(js_name = setMessageSender)]
+    pub fn set_message_sender(&mut self, party: JsValue) -> Result<(), JsValue> {
+        self.message_sender = Some(
+            from_value(party)
+                .map_err(|e| JsValue::from_str(&format!("Invalid message sender: {}", e)))?,
+        );
+        Ok(())
+    }
+
+    /// Set the message recipient, e.g.
+    /// `{ party_id, party_name: [{ text, language_code }], trading_name }`
+    #[wasm_bindgen(js_name = setMessageRecipient)]
+    pub fn set_message_recipient(&mut self, party: JsValue) -> Result<(), JsValue> {
+        self.message_recipient = Some(
+            from_value(party)
+                .map_err(|e| JsValue::from_str(&format!("Invalid message recipient: {}", e)))?,
+        );
+        Ok(())
+    }
+
     #[wasm_bindgen(js_name = addRelease)]
     pub fn add_release(&mut self, release: Release) {
         self.releases.push(release);
@@ -818,10 +844,10 @@ impl WasmDdexBuilder {
             uuid::Uuid::new_v4()
         ));
         xml.push_str("    <MessageSender>\n");
-        xml.push_str("      <PartyName>DDEX Suite WASM</PartyName>\n");
+        Self::write_party(&mut xml, self.message_sender.as_ref(), "DDEX Suite WASM");
         xml.push_str("    </MessageSender>\n");
         xml.push_str("    <MessageRecipient>\n");
-        xml.push_str("      <PartyName>Web Client</PartyName>\n");
+        Self::write_party(&mut xml, self.message_recipient.as_ref(), "Web Client");
         xml.push_str("    </MessageRecipient>\n");
         xml.push_str(&format!(
             "    <MessageCreatedDateTime>{}</MessageCreatedDateTime>\n",
@@ -835,12 +861,21 @@ impl WasmDdexBuilder {
             xml.push_str("    <Release>\n");
             xml.push_str(&format!(
                 "      <ReleaseId>{}</ReleaseId>\n",
-                release.release_id
+                html_escape::encode_text(&release.release_id)
+            ));
+            xml.push_str(&format!(
+                "      <Title>{}</Title>\n",
+                html_escape::encode_text(&release.title)
+            ));
+            xml.push_str(&format!(
+                "      <Artist>{}</Artist>\n",
+                html_escape::encode_text(&release.artist)
             ));
-            xml.push_str(&format!("      <Title>{}</Title>\n", release.title));
-            xml.push_str(&format!("      <Artist>{}</Artist>\n", release.artist));
             if let Some(ref label) = release.label {
-                xml.push_str(&format!("      <Label>{}</Label>\n", label));
+                xml.push_str(&format!(
+                    "      <Label>{}</Label>\n",
+                    html_escape::encode_text(label)
+                ));
             }
             xml.push_str("    </Release>\n");
             xml.push_str("  </ReleaseList>\n");
@@ -852,12 +887,21 @@ impl WasmDdexBuilder {
             xml.push_str("    <SoundRecording>\n");
             xml.push_str(&format!(
                 "      <ResourceId>{}</ResourceId>\n",
-                resource.resource_id
+                html_escape::encode_text(&resource.resource_id)
+            ));
+            xml.push_str(&format!(
+                "      <Title>{}</Title>\n",
+                html_escape::encode_text(&resource.title)
+            ));
+            xml.push_str(&format!(
+                "      <Artist>{}</Artist>\n",
+                html_escape::encode_text(&resource.artist)
             ));
-            xml.push_str(&format!("      <Title>{}</Title>\n", resource.title));
-            xml.push_str(&format!("      <Artist>{}</Artist>\n", resource.artist));
             if let Some(ref isrc) = resource.isrc {
-                xml.push_str(&format!("      <ISRC>{}</ISRC>\n", isrc));
+                xml.push_str(&format!(
+                    "      <ISRC>{}</ISRC>\n",
+                    html_escape::encode_text(isrc)
+                ));
             }
             xml.push_str("    </SoundRecording>\n");
             xml.push_str("  </ResourceList>\n");
@@ -867,6 +911,47 @@ impl WasmDdexBuilder {
         Ok(xml)
     }
 
+    fn write_party(
+        xml: &mut String,
+        party: Option<&ddex_builder::builder::PartyRequest>,
+        default_name: &str,
+    ) {
+        if let Some(id) = party.and_then(|p| p.party_id.as_ref()) {
+            xml.push_str(&format!(
+                "      <PartyId>{}</PartyId>\n",
+                html_escape::encode_text(id)
+            ));
+        }
+
+        let names = party.map(|p| p.party_name.as_slice()).unwrap_or_default();
+        if names.is_empty() {
+            xml.push_str(&format!(
+                "      <PartyName>{}</PartyName>\n",
+                html_escape::encode_text(default_name)
+            ));
+        }
+        for name in names {
+            match name.language_code {
+                Some(ref lang) => xml.push_str(&format!(
+                    "      <PartyName LanguageCode=\"{}\">{}</PartyName>\n",
+                    html_escape::encode_double_quoted_attribute(lang),
+                    html_escape::encode_text(&name.text)
+                )),
+                None => xml.push_str(&format!(
+                    "      <PartyName>{}</PartyName>\n",
+                    html_escape::encode_text(&name.text)
+                )),
+            }
+        }
+
+        if let Some(trading_name) = party.and_then(|p| p.trading_name.as_ref()) {
+            xml.push_str(&format!(
+                "      <TradingName>{}</TradingName>\n",
+                html_escape::encode_text(trading_name)
+            ));
+        }
+    }
+
     fn generate_fidelity_xml(&self, options: &FidelityOptions) -> Result<String, JsValue> {
         let mut xml = self.generate_placeholder_xml()?;
 
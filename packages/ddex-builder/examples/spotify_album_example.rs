@@ -208,6 +208,7 @@ fn create_spotify_album_request() -> BuildRequest {
                 party_id: Some("DDEX::INDIE_RECORDS_001".to_string()),
                 // Internal reference for this party in the message
                 party_reference: Some("SENDER_REF".to_string()),
+                trading_name: None,
             },
 
             // Message recipient: Spotify (in production, use actual Spotify DDEX ID)
@@ -219,6 +220,7 @@ fn create_spotify_album_request() -> BuildRequest {
                 // Official Spotify DDEX Party ID
                 party_id: Some("DDEX::SPOTIFY_001".to_string()),
                 party_reference: Some("RECIPIENT_REF".to_string()),
+                trading_name: None,
             },
 
             // Message control type: "LiveMessage" for production releases
@@ -228,6 +230,7 @@ fn create_spotify_album_request() -> BuildRequest {
             // Message creation timestamp - should be current time in production
             // Format: ISO 8601 (RFC 3339) with UTC timezone
             message_created_date_time: Some(chrono::Utc::now().to_rfc3339()),
+            message_thread_id: None,
         },
 
         // DDEX Schema version - Spotify requires ERN 4.3
@@ -256,11 +259,14 @@ fn create_album_release() -> ReleaseRequest {
             text: "Digital Horizons".to_string(),
             language_code: Some("en".to_string()),
         }],
+        subtitle: None,
         artist: "The Wavelength Collective".to_string(),
         label: Some("Indie Digital Records".to_string()),
         release_date: Some("2024-03-15".to_string()),
         upc: Some("602577123456".to_string()),
         tracks: create_album_tracks(),
+        images: vec![],
+        videos: vec![],
         resource_references: Some(vec![
             "R1".to_string(),
             "R2".to_string(),
@@ -271,6 +277,13 @@ fn create_album_release() -> ReleaseRequest {
             "R7".to_string(),
             "R8".to_string(),
         ]),
+        display_title: vec![],
+        track_releases: vec![],
+        preorder_date: None,
+        component_release_references: vec![],
+        release_dates_by_territory: Default::default(),
+        display_artists: Default::default(),
+        resource_groups: Default::default(),
     }
 }
 
@@ -281,64 +294,104 @@ fn create_album_tracks() -> Vec<TrackRequest> {
             resource_reference: Some("R1".to_string()),
             isrc: "USWV12400001".to_string(),
             title: "Neon Dreams".to_string(),
+            subtitle: None,
             duration: "PT4M23S".to_string(),
             artist: "The Wavelength Collective".to_string(),
+            file_path: None,
+            hash_sum: None,
+            preview: None,
+            contributors: vec![],
         },
         TrackRequest {
             track_id: "TRACK_002".to_string(),
             resource_reference: Some("R2".to_string()),
             isrc: "USWV12400002".to_string(),
             title: "Synthetic Sunrise".to_string(),
+            subtitle: None,
             duration: "PT3M57S".to_string(),
             artist: "The Wavelength Collective".to_string(),
+            file_path: None,
+            hash_sum: None,
+            preview: None,
+            contributors: vec![],
         },
         TrackRequest {
             track_id: "TRACK_003".to_string(),
             resource_reference: Some("R3".to_string()),
             isrc: "USWV12400003".to_string(),
             title: "Digital Pulse".to_string(),
+            subtitle: None,
             duration: "PT5M12S".to_string(),
             artist: "The Wavelength Collective".to_string(),
+            file_path: None,
+            hash_sum: None,
+            preview: None,
+            contributors: vec![],
         },
         TrackRequest {
             track_id: "TRACK_004".to_string(),
             resource_reference: Some("R4".to_string()),
             isrc: "USWV12400004".to_string(),
             title: "Cyber Meditation".to_string(),
+            subtitle: None,
             duration: "PT6M45S".to_string(),
             artist: "The Wavelength Collective".to_string(),
+            file_path: None,
+            hash_sum: None,
+            preview: None,
+            contributors: vec![],
         },
         TrackRequest {
             track_id: "TRACK_005".to_string(),
             resource_reference: Some("R5".to_string()),
             isrc: "USWV12400005".to_string(),
             title: "Binary Sunset".to_string(),
+            subtitle: None,
             duration: "PT4M31S".to_string(),
             artist: "The Wavelength Collective".to_string(),
+            file_path: None,
+            hash_sum: None,
+            preview: None,
+            contributors: vec![],
         },
         TrackRequest {
             track_id: "TRACK_006".to_string(),
             resource_reference: Some("R6".to_string()),
             isrc: "USWV12400006".to_string(),
             title: "Algorithmic Love".to_string(),
+            subtitle: None,
             duration: "PT3M44S".to_string(),
             artist: "The Wavelength Collective feat. Echo Siren".to_string(),
+            file_path: None,
+            hash_sum: None,
+            preview: None,
+            contributors: vec![],
         },
         TrackRequest {
             track_id: "TRACK_007".to_string(),
             resource_reference: Some("R7".to_string()),
             isrc: "USWV12400007".to_string(),
             title: "Data Stream Dreams".to_string(),
+            subtitle: None,
             duration: "PT7M18S".to_string(),
             artist: "The Wavelength Collective".to_string(),
+            file_path: None,
+            hash_sum: None,
+            preview: None,
+            contributors: vec![],
         },
         TrackRequest {
             track_id: "TRACK_008".to_string(),
             resource_reference: Some("R8".to_string()),
             isrc: "USWV12400008".to_string(),
             title: "Virtual Reality".to_string(),
+            subtitle: None,
             duration: "PT4M56S".to_string(),
             artist: "The Wavelength Collective".to_string(),
+            file_path: None,
+            hash_sum: None,
+            preview: None,
+            contributors: vec![],
         },
     ]
 }
@@ -350,6 +403,8 @@ fn create_spotify_streaming_deal() -> DealRequest {
             commercial_model_type: "SubscriptionModel".to_string(),
             territory_code: vec!["Worldwide".to_string()],
             start_date: Some("2024-03-15".to_string()),
+            use_types: vec![],
+            prices: vec![],
         },
         release_references: vec!["REL_REF_001".to_string()],
     }
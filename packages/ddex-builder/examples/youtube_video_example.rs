@@ -69,6 +69,7 @@ fn create_youtube_video_request() -> BuildRequest {
                 }],
                 party_id: None,
                 party_reference: None,
+                trading_name: None,
             },
             message_recipient: PartyRequest {
                 party_name: vec![LocalizedStringRequest {
@@ -77,9 +78,11 @@ fn create_youtube_video_request() -> BuildRequest {
                 }],
                 party_id: None,
                 party_reference: None,
+                trading_name: None,
             },
             message_control_type: Some("NewReleaseMessage".to_string()),
             message_created_date_time: Some(chrono::Utc::now().to_rfc3339()),
+            message_thread_id: None,
         },
         version: "ern/43".to_string(),
         profile: Some("VideoSingle".to_string()),
@@ -90,12 +93,22 @@ fn create_youtube_video_request() -> BuildRequest {
                 text: "Neon Nights (Official Music Video)".to_string(),
                 language_code: None,
             }],
+            subtitle: None,
             artist: "Luna Synth".to_string(),
             label: Some("Viral Music Entertainment".to_string()),
             release_date: Some("2024-02-14".to_string()),
             upc: Some("123456789012".to_string()),
             tracks: Vec::new(),
+            images: vec![],
+            videos: vec![],
             resource_references: Some(vec!["A1".to_string(), "V1".to_string()]),
+            display_title: vec![],
+            track_releases: vec![],
+            preorder_date: None,
+            component_release_references: vec![],
+            release_dates_by_territory: Default::default(),
+            display_artists: Default::default(),
+            resource_groups: Default::default(),
         }],
         deals: vec![],
         extensions: Some(create_youtube_metadata()),
@@ -191,6 +204,8 @@ fn create_youtube_monetization_deal() -> DealRequest {
             commercial_model_type: "AdvertisementSupportedModel".to_string(),
             territory_code: vec!["Worldwide".to_string()],
             start_date: Some("2024-02-14".to_string()),
+            use_types: vec![],
+            prices: vec![],
         },
         release_references: vec!["VIDEO_VIRAL_2024_001".to_string()],
     }
@@ -2,6 +2,7 @@
 
 pub use super::preflight::PreflightLevel;
 use crate::generator::{xml_writer::XmlWriter, ASTGenerator};
+use crate::presets::PartnerPreset;
 use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 
@@ -25,6 +26,7 @@ use serde::{Deserialize, Serialize};
 ///             }],
 ///             party_id: Some("PADPIDA2014120301K".to_string()),
 ///             party_reference: None,
+///             trading_name: None,
 ///         },
 ///         // ... other fields
 ///         message_recipient: PartyRequest { /* ... */ },
@@ -78,13 +80,15 @@ pub struct BuildRequest {
 ///         }],
 ///         party_id: Some("PADPIDA2014120301K".to_string()),
 ///         party_reference: None,
+///         trading_name: None,
 ///     },
 ///     message_recipient: PartyRequest { /* similar structure */ },
 ///     message_control_type: Some("NewReleaseMessage".to_string()),
 ///     message_created_date_time: None, // Auto-generated if None
+///     message_thread_id: None,
 /// };
 /// ```
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct MessageHeaderRequest {
     /// Unique message identifier (auto-generated if None)
     pub message_id: Option<String>,
@@ -96,6 +100,96 @@ pub struct MessageHeaderRequest {
     pub message_control_type: Option<String>,
     /// Message creation timestamp in ISO 8601 format (auto-generated if None)
     pub message_created_date_time: Option<String>,
+    /// Thread identifier linking related messages (defaults to the message ID)
+    #[serde(default)]
+    pub message_thread_id: Option<String>,
+}
+
+/// Message header configuration shared by the buffered and streaming builders
+///
+/// Collects everything that ends up in `MessageHeader` in one place so callers
+/// don't have to assemble [`MessageHeaderRequest`] and [`PartyRequest`] by hand.
+///
+/// # Example
+/// ```
+/// use ddex_builder::builder::MessageConfig;
+///
+/// let config = MessageConfig {
+///     sender_id: Some("PADPIDA2014120301K".to_string()),
+///     message_control_type: Some("TestMessage".to_string()),
+///     message_id_template: Some("MSG_{date}_{uuid}".to_string()),
+///     ..MessageConfig::new("My Label", "Streaming Service")
+/// };
+///
+/// let header = config.to_header();
+/// assert!(header.message_id.unwrap().starts_with("MSG_"));
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MessageConfig {
+    /// Sender party name
+    pub sender_name: String,
+    /// Sender DDEX party ID (DPID)
+    pub sender_id: Option<String>,
+    /// Recipient party name
+    pub recipient_name: String,
+    /// Recipient DDEX party ID (DPID)
+    pub recipient_id: Option<String>,
+    /// Message control type (e.g., "LiveMessage", "TestMessage")
+    pub message_control_type: Option<String>,
+    /// Thread identifier linking related messages
+    pub message_thread_id: Option<String>,
+    /// Message creation timestamp in ISO 8601 format (auto-generated if None)
+    pub message_created_date_time: Option<String>,
+    /// Template for the message ID; `{uuid}`, `{date}` (YYYYMMDD) and
+    /// `{timestamp}` (YYYYMMDDHHMMSS, UTC) are substituted. A UUID is used if None.
+    pub message_id_template: Option<String>,
+}
+
+impl MessageConfig {
+    /// Create a config with just sender and recipient names
+    pub fn new(sender_name: impl Into<String>, recipient_name: impl Into<String>) -> Self {
+        Self {
+            sender_name: sender_name.into(),
+            recipient_name: recipient_name.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Generate a message ID from the template
+    pub fn generate_message_id(&self) -> String {
+        let now = chrono::Utc::now();
+        match &self.message_id_template {
+            Some(template) => template
+                .replace("{uuid}", &uuid::Uuid::new_v4().to_string())
+                .replace("{date}", &now.format("%Y%m%d").to_string())
+                .replace("{timestamp}", &now.format("%Y%m%d%H%M%S").to_string()),
+            None => uuid::Uuid::new_v4().to_string(),
+        }
+    }
+
+    /// Build the message header request described by this config
+    pub fn to_header(&self) -> MessageHeaderRequest {
+        MessageHeaderRequest {
+            message_id: Some(self.generate_message_id()),
+            message_sender: Self::party(&self.sender_name, &self.sender_id),
+            message_recipient: Self::party(&self.recipient_name, &self.recipient_id),
+            message_control_type: self.message_control_type.clone(),
+            message_created_date_time: self.message_created_date_time.clone(),
+            message_thread_id: self.message_thread_id.clone(),
+        }
+    }
+
+    fn party(name: &str, id: &Option<String>) -> PartyRequest {
+        PartyRequest {
+            party_name: vec![LocalizedStringRequest {
+                text: name.to_string(),
+                language_code: None,
+            }],
+            party_id: id.clone(),
+            party_reference: None,
+            trading_name: None,
+        }
+    }
 }
 
 /// Party information request
@@ -120,9 +214,10 @@ pub struct MessageHeaderRequest {
 ///     ],
 ///     party_id: Some("PADPIDA2014120301K".to_string()), // DPID
 ///     party_reference: Some("PARTY_REF_001".to_string()),
+///     trading_name: None,
 /// };
 /// ```
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct PartyRequest {
     /// Party names in multiple languages
     pub party_name: Vec<LocalizedStringRequest>,
@@ -130,6 +225,9 @@ pub struct PartyRequest {
     pub party_id: Option<String>,
     /// Reference identifier for this party within the message
     pub party_reference: Option<String>,
+    /// Trading name, if different from the legal party name(s)
+    #[serde(default)]
+    pub trading_name: Option<String>,
 }
 
 /// Localized string with language code
@@ -176,6 +274,8 @@ pub struct LocalizedStringRequest {
 ///         text: "Greatest Hits".to_string(),
 ///         language_code: Some("en".to_string()),
 ///     }],
+///     display_title: vec![],
+///     subtitle: None,
 ///     artist: "The Beatles".to_string(),
 ///     label: Some("Apple Records".to_string()),
 ///     release_date: Some("2024-01-15".to_string()),
@@ -186,33 +286,132 @@ pub struct LocalizedStringRequest {
 ///             resource_reference: Some("RES_001".to_string()),
 ///             isrc: "GBUM71505078".to_string(),
 ///             title: "Here Comes The Sun".to_string(),
+///             subtitle: None,
 ///             duration: "PT3M5S".to_string(),
 ///             artist: "The Beatles".to_string(),
+///             file_path: None,
+///             hash_sum: None,
+///             preview: None,
+///             contributors: vec![],
 ///         }
 ///     ],
+///     images: vec![],
+///     videos: vec![],
 ///     resource_references: Some(vec!["RES_001".to_string()]),
+///     track_releases: vec![],
+///     preorder_date: None,
+///     component_release_references: vec![],
+///     release_dates_by_territory: Default::default(),
+///     display_artists: Default::default(),
+///     resource_groups: vec![],
 /// };
 /// ```
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ReleaseRequest {
     /// Release identifier (e.g., GRid, Proprietary ID)
     pub release_id: String,
     /// Internal reference for this release within the message
     pub release_reference: Option<String>,
-    /// Release titles in multiple languages
+    /// Reference titles (internal), in multiple languages, emitted as `ReferenceTitle`
     pub title: Vec<LocalizedStringRequest>,
+    /// Customer-facing titles, emitted as `DisplayTitle` alongside the reference title
+    #[serde(default)]
+    pub display_title: Vec<LocalizedStringRequest>,
+    /// Version of the release, e.g. "Deluxe Edition", emitted as the
+    /// `SubTitle` of each reference title
+    #[serde(default)]
+    pub subtitle: Option<String>,
     /// Main artist name for the release
     pub artist: String,
+    /// Release-level artists with their roles, in credit order; each is
+    /// emitted as a `DisplayArtist` after the `DisplayArtistName`
+    #[serde(default)]
+    pub display_artists: Vec<DisplayArtistRequest>,
     /// Record label name
     pub label: Option<String>,
-    /// Release date in YYYY-MM-DD format
+    /// Release date in YYYY-MM-DD format: when the release goes on sale,
+    /// independently of deal validity. Also accepted as `availability_start`.
+    #[serde(alias = "availability_start")]
     pub release_date: Option<String>,
+    /// Dates the release comes out in particular territories, apart from
+    /// `release_date`, keyed by territory code; each is emitted as a
+    /// `ReleaseDate` with an `ApplicableTerritoryCode`
+    #[serde(default)]
+    pub release_dates_by_territory: IndexMap<String, String>,
+    /// When metadata may be shown and pre-orders open, ahead of the release
+    /// date; emitted as the `ReleaseDisplayStartDateTime` of a
+    /// `ReleaseVisibility` the release refers to
+    #[serde(default)]
+    pub preorder_date: Option<String>,
     /// Universal Product Code for the release (12-digit barcode)
     pub upc: Option<String>,
     /// List of tracks/resources in this release
     pub tracks: Vec<TrackRequest>,
+    /// Cover art and other images delivered with the release, emitted as
+    /// `Image` resources after every track
+    #[serde(default)]
+    pub images: Vec<ImageRequest>,
+    /// Music videos and other videos delivered with the release, emitted as
+    /// `Video` resources after every image
+    #[serde(default)]
+    pub videos: Vec<VideoRequest>,
     /// References to resources for linking purposes
     pub resource_references: Option<Vec<String>>,
+    /// Tracks also offered on their own, emitted as `TrackRelease`s linked
+    /// back to this release
+    #[serde(default)]
+    pub track_releases: Vec<TrackReleaseRequest>,
+    /// For a bundle (box set, multi-product release), references of the
+    /// releases in this request it is made up of; each is emitted as a
+    /// `HasComponentRelease` `RelatedRelease`
+    #[serde(default)]
+    pub component_release_references: Vec<String>,
+    /// Volumes of the release, such as its discs, with the position and
+    /// per-item data of each resource on them; emitted as `ResourceGroup`s
+    /// inside one main `ResourceGroup`
+    #[serde(default)]
+    pub resource_groups: Vec<ResourceGroupRequest>,
+}
+
+/// A volume of a release, such as one disc of a double album
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResourceGroupRequest {
+    /// Position of the volume in the release, e.g. the disc number
+    #[serde(default)]
+    pub sequence_number: Option<u32>,
+    /// Resources of the volume, each emitted as a `ResourceGroupContentItem`
+    pub content_items: Vec<ResourceGroupContentItemRequest>,
+}
+
+/// A resource at a position in a volume
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResourceGroupContentItemRequest {
+    /// Position of the resource in the volume, e.g. the track number
+    pub sequence_number: u32,
+    /// Reference of the track, image or video at this position
+    pub resource_reference: String,
+    /// Marks a bonus track, emitted as `IsBonusResource`
+    #[serde(default)]
+    pub is_bonus: bool,
+    /// Marks a hidden track, emitted as `IsHiddenResource`
+    #[serde(default)]
+    pub is_hidden: bool,
+}
+
+/// A track of a release offered on its own, such as a single from an album
+///
+/// Emitted as a `TrackRelease` after the main releases, pointing at the
+/// track's resource and naming the release it comes from.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrackReleaseRequest {
+    /// Release identifier of the track release (e.g., GRid)
+    pub release_id: String,
+    /// Internal reference for this release within the message
+    pub release_reference: Option<String>,
+    /// Reference of the track's resource; must be a track of the main release
+    pub resource_reference: String,
+    /// Customer-facing title; defaults to the track title
+    pub title: Option<String>,
 }
 
 /// Track information request
@@ -229,11 +428,16 @@ pub struct ReleaseRequest {
 ///     resource_reference: Some("A12345".to_string()),
 ///     isrc: "USUM71504847".to_string(),
 ///     title: "Bohemian Rhapsody".to_string(),
+///     subtitle: None,
 ///     duration: "PT5M55S".to_string(), // 5 minutes 55 seconds
 ///     artist: "Queen".to_string(),
+///     file_path: None,
+///     hash_sum: None,
+///     preview: None,
+///     contributors: vec![],
 /// };
 /// ```
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct TrackRequest {
     /// Unique identifier for this track within the message
     pub track_id: String,
@@ -243,10 +447,131 @@ pub struct TrackRequest {
     pub isrc: String,
     /// Track title
     pub title: String,
+    /// Version of the recording, e.g. "Live at Wembley" or "Radio Edit",
+    /// emitted as the `SubTitle` of its reference title
+    #[serde(default)]
+    pub subtitle: Option<String>,
     /// Duration in ISO 8601 format (e.g., "PT3M45S" for 3 minutes 45 seconds)
     pub duration: String,
     /// Track artist name (may differ from release artist for compilations)
     pub artist: String,
+    /// Path to the delivered audio file, emitted as the file URI.
+    /// Its SHA-256 is computed for `HashSum` when `hash_sum` is not given.
+    #[serde(default)]
+    pub file_path: Option<String>,
+    /// Checksum of the delivered audio file
+    #[serde(default)]
+    pub hash_sum: Option<HashSumRequest>,
+    /// Part of the track played as a preview
+    #[serde(default)]
+    pub preview: Option<PreviewRequest>,
+    /// Credits for the recording, such as its conductor, orchestra and soloists
+    #[serde(default)]
+    pub contributors: Vec<ContributorRequest>,
+}
+
+/// An image delivered with a release, such as its front cover
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImageRequest {
+    /// Reference to the image resource
+    pub resource_reference: String,
+    /// DDEX image type (e.g., "FrontCoverImage")
+    pub image_type: String,
+    /// Path to the delivered image file, emitted as the file URI.
+    /// Its SHA-256 is computed for `HashSum` when `hash_sum` is not given.
+    #[serde(default)]
+    pub file_path: Option<String>,
+    /// Checksum of the delivered image file
+    #[serde(default)]
+    pub hash_sum: Option<HashSumRequest>,
+}
+
+/// A video delivered with a release, such as its music video
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VideoRequest {
+    /// Reference to the video resource
+    pub resource_reference: String,
+    /// DDEX video type (e.g., "ShortFormMusicalWorkVideo")
+    pub video_type: String,
+    /// Frame width in pixels
+    #[serde(default)]
+    pub width: Option<u32>,
+    /// Frame height in pixels
+    #[serde(default)]
+    pub height: Option<u32>,
+    /// Path to the delivered video file, emitted as the file URI.
+    /// Its SHA-256 is computed for `HashSum` when `hash_sum` is not given.
+    #[serde(default)]
+    pub file_path: Option<String>,
+    /// Checksum of the delivered video file
+    #[serde(default)]
+    pub hash_sum: Option<HashSumRequest>,
+}
+
+impl VideoRequest {
+    /// Quality tier of the video's resolution: `4K`, `HD1080`, `HD720` or
+    /// `SD`, judged on the shorter side so portrait videos rank like their
+    /// landscape equivalents. `None` when width or height is not given.
+    pub fn quality(&self) -> Option<&'static str> {
+        let short_side = self.width?.min(self.height?);
+        Some(match short_side {
+            2160.. => "4K",
+            1080.. => "HD1080",
+            720.. => "HD720",
+            _ => "SD",
+        })
+    }
+}
+
+/// A party credited for its part in a track
+///
+/// Emitted as `<Contributor>` with one `<Role>` per role and one
+/// `<InstrumentType>` per instrument, written exactly as given so classical
+/// and performer credits keep their specific roles.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContributorRequest {
+    /// Contributor name
+    pub name: String,
+    /// DDEX contributor roles (e.g., "Conductor", "Orchestra", "Ensemble", "Soloist")
+    pub roles: Vec<String>,
+    /// Instruments played (e.g., "Guitar", "Piano")
+    #[serde(default)]
+    pub instruments: Vec<String>,
+}
+
+/// An artist credited on a release, such as the "B" of "A feat. B"
+///
+/// Emitted as `<DisplayArtist>` with the name and a `<DisplayArtistRole>`,
+/// so a featured artist is not mistaken for a main artist.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DisplayArtistRequest {
+    /// Artist name
+    pub name: String,
+    /// DDEX display artist role (e.g., "MainArtist", "FeaturedArtist")
+    pub role: String,
+}
+
+/// Preview clip within a track
+///
+/// Emitted as `<PreviewDetails>` so DSPs know which part of the track to
+/// play as a sample.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PreviewRequest {
+    /// Offset from the start of the track in ISO 8601 format (e.g., "PT30S")
+    pub start: String,
+    /// Clip length in ISO 8601 format (e.g., "PT30S")
+    pub duration: String,
+}
+
+/// File checksum for a resource
+///
+/// Emitted as `<HashSum>` so recipients can verify the delivered file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HashSumRequest {
+    /// Hash algorithm as a DDEX allowed value (e.g. "SHA256", "MD5")
+    pub algorithm: String,
+    /// Hex-encoded hash value
+    pub value: String,
 }
 
 /// Commercial deal request
@@ -264,6 +589,8 @@ pub struct TrackRequest {
 ///         commercial_model_type: "PayAsYouGoModel".to_string(),
 ///         territory_code: vec!["Worldwide".to_string()],
 ///         start_date: Some("2024-01-01".to_string()),
+///         use_types: vec![],
+///         prices: vec![],
 ///     },
 ///     release_references: vec!["REL_001".to_string()],
 /// };
@@ -291,16 +618,48 @@ pub struct DealRequest {
 ///     commercial_model_type: "SubscriptionModel".to_string(),
 ///     territory_code: vec!["US".to_string(), "CA".to_string(), "MX".to_string()],
 ///     start_date: Some("2024-01-01".to_string()),
+///     use_types: vec!["Stream".to_string()],
+///     prices: vec![],
 /// };
 /// ```
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct DealTerms {
     /// Type of commercial model (e.g., "PayAsYouGoModel", "SubscriptionModel", "FreeOfChargeModel")
     pub commercial_model_type: String,
     /// Territory codes where deal applies (ISO 3166-1 alpha-2 codes or "Worldwide")
     pub territory_code: Vec<String>,
-    /// Deal start date in YYYY-MM-DD format (optional)
+    /// Deal start date (optional), emitted as the `ValidityPeriod`
+    /// `StartDate`; a date-time is reduced to its `YYYY-MM-DD` date unless
+    /// [`BuildOptions::preserve_lexical_forms`] is set
     pub start_date: Option<String>,
+    /// Permitted uses (e.g., "Stream", "ConditionalDownload"), one `<UseType>` each
+    #[serde(default)]
+    pub use_types: Vec<String>,
+    /// Price points, such as separate pre-order and release prices per currency
+    #[serde(default)]
+    pub prices: Vec<DealPrice>,
+}
+
+/// One price point of a deal, emitted as `<PriceInformation>`
+///
+/// # Example
+/// ```
+/// use ddex_builder::builder::DealPrice;
+///
+/// let pre_order = DealPrice {
+///     price_type: "PreOrder".to_string(),
+///     amount: "7.99".to_string(),
+///     currency_code: "USD".to_string(),
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DealPrice {
+    /// Which price this is (e.g., "PreOrder", "Release")
+    pub price_type: String,
+    /// Wholesale price per unit as a decimal string (e.g., "9.99")
+    pub amount: String,
+    /// ISO 4217 currency code (e.g., "USD", "EUR")
+    pub currency_code: String,
 }
 
 /// Build options
@@ -317,6 +676,75 @@ pub struct BuildOptions {
 
     /// Stable hash configuration (when using StableHash strategy)
     pub stable_hash_config: Option<super::id_generator::StableHashConfig>,
+
+    /// Whether preflight stops at the first error or reports them all
+    #[serde(default)]
+    pub validation_mode: super::preflight::ValidationMode,
+
+    /// Emit empty elements self-closed (`<SubTitle/>`) rather than as
+    /// `<SubTitle></SubTitle>`; ignored for canonical output
+    #[serde(default = "default_self_close_empty")]
+    pub self_close_empty: bool,
+
+    /// Leave out optional elements whose value is empty or whitespace rather
+    /// than emitting them empty; absent values never produce an element
+    #[serde(default = "default_omit_empty")]
+    pub omit_empty: bool,
+
+    /// Fail the build if a `PartyReference`, `ArtistPartyReference` or
+    /// `RightsControllerPartyReference` in the generated message names no
+    /// party in its `PartyList`
+    #[serde(default)]
+    pub validate_party_references: bool,
+
+    /// Also return the DB-C14N canonical form and its hash alongside the
+    /// regular output, canonicalizing the rendered XML once
+    #[serde(default)]
+    pub include_canonical: bool,
+
+    /// Order releases and resources are emitted in, so that the same set
+    /// built from differently ordered requests renders identically
+    #[serde(default)]
+    pub sort_order: SortOrder,
+
+    /// Whether release labels are written inline or as parties the
+    /// releases refer to
+    #[serde(default)]
+    pub label_form: LabelForm,
+
+    /// Parse the rendered XML back before returning it, failing the build
+    /// rather than returning output that is not well-formed
+    #[serde(default)]
+    pub verify_well_formed: bool,
+
+    /// Emit date and number values exactly as given instead of in their
+    /// normalized form, so values taken from a parsed message keep the
+    /// lexical form they had there. Set by perfect-fidelity builds.
+    #[serde(default)]
+    pub preserve_lexical_forms: bool,
+
+    /// Measure where build time goes, returned as the statistics' `timings`
+    #[serde(default)]
+    pub collect_statistics: bool,
+
+    /// Value and form of `MessageCreatedDateTime` when the request header
+    /// does not give one
+    #[serde(default)]
+    pub created_date_time: CreatedDateTimeOptions,
+
+    /// Deprecated elements to emit alongside their modern counterparts, for
+    /// recipients that still read them. Left empty, an applied preset's
+    /// legacy elements are used.
+    #[serde(default)]
+    pub legacy_elements: Vec<LegacyElement>,
+}
+
+fn default_self_close_empty() -> bool {
+    true
+}
+
+fn default_omit_empty() -> bool {
+    true
 }
 
 impl Default for BuildOptions {
@@ -326,10 +754,83 @@ impl Default for BuildOptions {
             preflight_level: super::preflight::PreflightLevel::Warn,
             id_strategy: IdStrategy::UUID,
             stable_hash_config: None,
+            validation_mode: super::preflight::ValidationMode::default(),
+            self_close_empty: true,
+            omit_empty: true,
+            validate_party_references: false,
+            include_canonical: false,
+            sort_order: SortOrder::default(),
+            label_form: LabelForm::default(),
+            verify_well_formed: false,
+            preserve_lexical_forms: false,
+            collect_statistics: false,
+            created_date_time: CreatedDateTimeOptions::default(),
+            legacy_elements: Vec::new(),
         }
     }
 }
 
+/// Order releases and resources are emitted in
+///
+/// Only the `ReleaseList` and `ResourceList` are reordered; the tracks of a
+/// release always keep their sequence.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortOrder {
+    /// Keep the order of the request
+    #[default]
+    Insertion,
+    /// Sort by `ReleaseReference` and `ResourceReference`
+    Reference,
+    /// Sort by UPC (falling back to the release ID) and ISRC
+    Identifier,
+}
+
+/// How a release's `label` is written. Either way the parser reads it back
+/// as the release's `label_name`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LabelForm {
+    /// A `LabelName` inside the release
+    #[default]
+    Inline,
+    /// A `Party` in the `PartyList`, one per distinct label, named by the
+    /// release's `ReleaseLabelReference`
+    PartyReference,
+}
+
+/// An element later ERN versions deprecated, written in its ERN 3.x form
+/// with values taken from the release or track it belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LegacyElement {
+    /// A `Worldwide` `ReleaseDetailsByTerritory` on each release, repeating
+    /// its display artist, label and title
+    ReleaseDetailsByTerritory,
+    /// A `SoundRecordingId` holding the ISRC, next to the `ResourceId`
+    SoundRecordingId,
+    /// A `Worldwide` `SoundRecordingDetailsByTerritory` on each recording,
+    /// repeating its title and display artist
+    SoundRecordingDetailsByTerritory,
+}
+
+/// How `MessageCreatedDateTime` is written when the request header does not
+/// give one. With nothing set it is the current time in UTC, as RFC 3339.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CreatedDateTimeOptions {
+    /// Fixed creation time in RFC 3339 (e.g. "2024-01-15T10:00:00Z"), for
+    /// reproducible builds; the current time when unset
+    #[serde(default)]
+    pub value: Option<String>,
+    /// UTC offset to write the time at, as "+09:00" or "Z"; the offset of
+    /// `value`, or UTC, when unset
+    #[serde(default)]
+    pub utc_offset: Option<String>,
+    /// Write whole seconds only, for partners that reject fractional seconds
+    #[serde(default)]
+    pub omit_fractional_seconds: bool,
+}
+
 /// ID generation strategy
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum IdStrategy {
@@ -358,11 +859,30 @@ pub struct BuildResult {
     /// Statistics
     pub statistics: BuildStatistics,
 
-    /// Canonical hash (if deterministic)
+    /// Canonical hash, when the output is canonical or `include_canonical` is set
     pub canonical_hash: Option<String>,
 
+    /// DB-C14N canonical form of `xml`, when `include_canonical` is set
+    pub canonical_xml: Option<String>,
+
     /// Reproducibility banner (if requested)
     pub reproducibility_banner: Option<String>,
+
+    /// Files the message points to, in `ResourceList` order, for gathering
+    /// the delivery package
+    #[serde(default)]
+    pub referenced_files: Vec<ReferencedFile>,
+}
+
+/// A delivered file named by a resource of a built message
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReferencedFile {
+    /// Reference of the resource naming the file
+    pub resource_reference: String,
+    /// File name or URI as emitted
+    pub uri: String,
+    /// Checksum the recipient will expect, as emitted
+    pub hash_sum: Option<HashSumRequest>,
 }
 
 /// Build warning
@@ -389,6 +909,26 @@ pub struct BuildStatistics {
     pub generation_time_ms: u64,
     /// Size of generated XML in bytes
     pub xml_size_bytes: usize,
+    /// Size of the canonical form in bytes, when one was returned
+    pub canonical_size_bytes: Option<usize>,
+    /// Where the build's time went, when `collect_statistics` is set
+    #[serde(default)]
+    pub timings: Option<BuildTimings>,
+}
+
+/// Time spent in each stage of a build, in milliseconds
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct BuildTimings {
+    /// Preflight validation and the party reference check
+    pub validation_ms: f64,
+    /// Generating the message and writing it as XML
+    pub serialization_ms: f64,
+    /// Canonicalizing the XML
+    pub canonicalization_ms: f64,
+    /// Hashing the canonical form
+    pub hashing_ms: f64,
+    /// The whole build, stages and the bookkeeping between them
+    pub total_ms: f64,
 }
 
 impl Default for BuildStatistics {
@@ -399,13 +939,31 @@ impl Default for BuildStatistics {
             deals: 0,
             generation_time_ms: 0,
             xml_size_bytes: 0,
+            canonical_size_bytes: None,
+            timings: None,
         }
     }
 }
 
+/// Output of rendering an AST
+struct Rendered {
+    xml: String,
+    canonical_xml: Option<String>,
+    canonical_hash: Option<String>,
+    reproducibility_banner: Option<String>,
+    /// Time spent writing, canonicalizing and hashing
+    timings: BuildTimings,
+}
+
+/// `duration` in fractional milliseconds
+fn milliseconds(duration: std::time::Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
 /// Main DDEX Builder
 pub struct DDEXBuilder {
     _inner: super::Builder,
+    preset: Option<PartnerPreset>,
 }
 
 impl DDEXBuilder {
@@ -413,19 +971,133 @@ impl DDEXBuilder {
     pub fn new() -> Self {
         Self {
             _inner: super::Builder::new(),
+            preset: None,
+        }
+    }
+
+    /// Resolve `preset_name` once and apply it to every subsequent build
+    ///
+    /// The preset supplies the message control type, profile and determinism
+    /// config for requests and options that leave them unset.
+    pub fn apply_preset(
+        &mut self,
+        preset_name: &str,
+    ) -> Result<&PartnerPreset, super::error::BuildError> {
+        let preset = self
+            ._inner
+            .get_preset(preset_name)
+            .cloned()
+            .ok_or_else(|| super::error::BuildError::InvalidFormat {
+                field: "preset".to_string(),
+                message: format!("Unknown preset: {}", preset_name),
+            })?;
+        Ok(self.preset.insert(preset))
+    }
+
+    /// Currently applied preset, if any
+    pub fn preset(&self) -> Option<&PartnerPreset> {
+        self.preset.as_ref()
+    }
+
+    /// Names of the presets this builder can apply
+    pub fn available_presets(&self) -> Vec<String> {
+        self._inner.available_presets()
+    }
+
+    /// Versions, message types, canonicalization algorithms and features
+    /// this build supports
+    pub fn capabilities(&self) -> super::Capabilities {
+        self._inner.capabilities()
+    }
+
+    /// Request rebuilding a message read by the parser, so it can be changed
+    /// and re-serialized. The header keeps the original message ID, sender
+    /// and recipient names and IDs, `MessageControlType` and creation time;
+    /// releases, tracks, images, videos and deals carry what the parser
+    /// reads of them, except deal prices without a `PriceType`.
+    pub fn from_parsed(parsed: &ddex_core::models::flat::ParsedERNMessage) -> BuildRequest {
+        super::from_parsed::build_request(parsed)
+    }
+
+    /// Build DDEX XML from request, taking the message header from `config`
+    pub fn build_with_message_config(
+        &self,
+        mut request: BuildRequest,
+        config: &MessageConfig,
+        options: BuildOptions,
+    ) -> Result<BuildResult, super::error::BuildError> {
+        request.header = config.to_header();
+        self.build(request, options)
+    }
+
+    /// Build DDEX XML from request straight into `writer` with the
+    /// streaming builder, so a huge catalog never exists as one string:
+    /// memory is bounded by `config.max_buffer_size` rather than the size
+    /// of the message. The output is ERN 4.3 in request order, without the
+    /// preflight checks and canonicalization of [`build`](Self::build).
+    pub fn build_to_writer<W: std::io::Write>(
+        &self,
+        mut request: BuildRequest,
+        writer: W,
+        config: super::streaming::StreamingConfig,
+    ) -> Result<super::streaming::StreamingStats, super::error::BuildError> {
+        self.fill_preset_defaults(&mut request);
+        super::streaming::StreamingBuilder::new_with_config(writer, config)?.write_request(&request)
+    }
+
+    /// Build an acknowledgement replying to a previously received message
+    pub fn build_acknowledgement(
+        &self,
+        request: super::messages::AcknowledgementRequest,
+        options: BuildOptions,
+    ) -> Result<BuildResult, super::error::BuildError> {
+        let start = std::time::Instant::now();
+
+        let ast = request.to_ast()?;
+        let rendered = self.render(&ast, &options)?;
+        if options.verify_well_formed {
+            super::generator::xml_writer::check_well_formed(&rendered.xml)?;
         }
+
+        Ok(BuildResult {
+            statistics: BuildStatistics {
+                generation_time_ms: start.elapsed().as_millis() as u64,
+                xml_size_bytes: rendered.xml.len(),
+                canonical_size_bytes: rendered.canonical_xml.as_ref().map(String::len),
+                ..BuildStatistics::default()
+            },
+            xml: rendered.xml,
+            warnings: Vec::new(),
+            errors: Vec::new(),
+            canonical_hash: rendered.canonical_hash,
+            canonical_xml: rendered.canonical_xml,
+            reproducibility_banner: rendered.reproducibility_banner,
+            referenced_files: Vec::new(),
+        })
     }
 
     /// Build DDEX XML from request
     pub fn build(
         &self,
         mut request: BuildRequest,
-        options: BuildOptions,
+        mut options: BuildOptions,
     ) -> Result<BuildResult, super::error::BuildError> {
         let start = std::time::Instant::now();
         let mut warnings = Vec::new();
 
+        // 0. Fill in anything the applied preset provides
+        self.fill_preset_defaults(&mut request);
+        if let Some(ref preset) = self.preset {
+            if options.determinism.is_none() {
+                options.determinism = Some(preset.determinism.clone());
+            }
+            if options.legacy_elements.is_empty() {
+                options.legacy_elements = preset.legacy_elements.clone();
+            }
+        }
+
         // 1. Enhanced preflight checks with new validator
+        let validation_start = std::time::Instant::now();
         let validator =
             super::preflight::PreflightValidator::new(super::preflight::ValidationConfig {
                 level: options.preflight_level,
@@ -435,12 +1107,17 @@ impl DDEXBuilder {
                 check_required_fields: true,
                 validate_dates: true,
                 validate_references: true,
+                validation_mode: options.validation_mode,
             });
 
         let validation_result = validator.validate(&request)?;
 
         // Convert validation warnings to build warnings
-        for warning in validation_result.warnings {
+        let no_deal = match options.preflight_level {
+            super::preflight::PreflightLevel::None => Vec::new(),
+            _ => super::preflight::releases_without_deals(&request),
+        };
+        for warning in validation_result.warnings.into_iter().chain(no_deal) {
             warnings.push(BuildWarning {
                 code: warning.code,
                 message: warning.message,
@@ -461,32 +1138,126 @@ impl DDEXBuilder {
             }
         }
 
+        let mut validation_time = validation_start.elapsed();
+
         // 2. Generate IDs based on strategy
+        let generation_start = std::time::Instant::now();
         self.generate_ids(&mut request, &options)?;
 
         // 3. Generate AST
-        let mut generator = ASTGenerator::new(request.version.clone());
+        let mut generator = ASTGenerator::new(request.version.clone())
+            .with_omit_empty(options.omit_empty)
+            .with_sort_order(options.sort_order)
+            .with_label_form(options.label_form)
+            .with_legacy_elements(options.legacy_elements.clone())
+            .with_preserve_lexical_forms(options.preserve_lexical_forms)
+            .with_created_date_time(options.created_date_time.clone());
         let ast = generator.generate(&request)?;
+        let referenced_files = generator.referenced_files().to_vec();
+        let generation_time = generation_start.elapsed();
+
+        // 3b. Every party reference must resolve before anything is emitted
+        let party_check_start = std::time::Instant::now();
+        if options.validate_party_references {
+            let unresolved = super::preflight::unresolved_party_references(&ast.root);
+            if !unresolved.is_empty() {
+                return Err(super::error::BuildError::ValidationFailed {
+                    errors: unresolved
+                        .iter()
+                        .map(|e| format!("{}: {} at {}", e.code, e.message, e.location))
+                        .collect(),
+                });
+            }
+        }
 
-        // 4. Apply determinism config
-        let config = options.determinism.unwrap_or_default();
+        validation_time += party_check_start.elapsed();
 
-        // 5. Generate XML
-        let writer = XmlWriter::new(config.clone());
-        let xml = writer.write(&ast)?;
+        // 4-7. Render with determinism config and canonicalization
+        let rendered = self.render(&ast, &options)?;
 
-        // 6. Apply canonicalization if requested
-        let (final_xml, canonical_hash) =
-            if config.canon_mode == super::determinism::CanonMode::DbC14n {
+        let elapsed = start.elapsed();
+        let timings = options.collect_statistics.then(|| BuildTimings {
+            validation_ms: milliseconds(validation_time),
+            serialization_ms: milliseconds(generation_time) + rendered.timings.serialization_ms,
+            total_ms: milliseconds(elapsed),
+            ..rendered.timings
+        });
+
+        Ok(BuildResult {
+            warnings,
+            errors: Vec::new(),
+            statistics: BuildStatistics {
+                releases: request.releases.len(),
+                tracks: request.releases.iter().map(|r| r.tracks.len()).sum(),
+                deals: request.deals.len(),
+                generation_time_ms: elapsed.as_millis() as u64,
+                xml_size_bytes: rendered.xml.len(),
+                canonical_size_bytes: rendered.canonical_xml.as_ref().map(String::len),
+                timings,
+            },
+            xml: rendered.xml,
+            canonical_hash: rendered.canonical_hash,
+            canonical_xml: rendered.canonical_xml,
+            reproducibility_banner: rendered.reproducibility_banner,
+            referenced_files,
+        })
+    }
+
+    /// Message control type and profile of the applied preset, where the
+    /// request gives none
+    fn fill_preset_defaults(&self, request: &mut BuildRequest) {
+        if let Some(ref preset) = self.preset {
+            if request.header.message_control_type.is_none() {
+                request.header.message_control_type = preset.defaults.message_control_type.clone();
+            }
+            if request.profile.is_none() {
+                request.profile = Some(format!("{:?}", preset.config.profile));
+            }
+        }
+    }
+
+    /// Write an AST to XML, canonicalizing and adding the reproducibility
+    /// banner as the determinism config and options ask
+    fn render(
+        &self,
+        ast: &crate::ast::AST,
+        options: &BuildOptions,
+    ) -> Result<Rendered, super::error::BuildError> {
+        // Apply determinism config
+        let config = options.determinism.clone().unwrap_or_default();
+
+        let mut timings = BuildTimings::default();
+
+        // Generate XML
+        let serialization_start = std::time::Instant::now();
+        let writer = XmlWriter::new(config.clone()).with_self_close_empty(options.self_close_empty);
+        let xml = writer.write(ast)?;
+        timings.serialization_ms = milliseconds(serialization_start.elapsed());
+
+        // Apply canonicalization if requested. When the output is itself
+        // canonical it doubles as the returned canonical form.
+        let canonical_output = config.canon_mode == super::determinism::CanonMode::DbC14n;
+        let (final_xml, canonical_xml, canonical_hash) =
+            if canonical_output || options.include_canonical {
+                let canonicalization_start = std::time::Instant::now();
                 let canonicalizer = super::canonical::DB_C14N::new(config.clone());
                 let canonical = canonicalizer.canonicalize(&xml)?;
+                timings.canonicalization_ms = milliseconds(canonicalization_start.elapsed());
+
+                let hashing_start = std::time::Instant::now();
                 let hash = Some(canonicalizer.canonical_hash(&canonical)?);
-                (canonical, hash)
+                timings.hashing_ms = milliseconds(hashing_start.elapsed());
+                let returned = options.include_canonical.then(|| canonical.clone());
+                if canonical_output {
+                    (canonical, returned, hash)
+                } else {
+                    (xml, returned, hash)
+                }
             } else {
-                (xml, None)
+                (xml, None, None)
             };
 
-        // 7. Generate reproducibility banner if requested
+        // Generate reproducibility banner if requested
         let reproducibility_banner = if config.emit_reproducibility_banner {
             Some(format!(
                 "Generated by DDEX Builder v{} with DB-C14N/{}",
@@ -497,21 +1268,12 @@ impl DDEXBuilder {
             None
         };
 
-        let elapsed = start.elapsed();
-
-        Ok(BuildResult {
-            xml: final_xml.clone(),
-            warnings,
-            errors: Vec::new(),
-            statistics: BuildStatistics {
-                releases: request.releases.len(),
-                tracks: request.releases.iter().map(|r| r.tracks.len()).sum(),
-                deals: request.deals.len(),
-                generation_time_ms: elapsed.as_millis() as u64,
-                xml_size_bytes: final_xml.len(),
-            },
+        Ok(Rendered {
+            xml: final_xml,
+            canonical_xml,
             canonical_hash,
             reproducibility_banner,
+            timings,
         })
     }
 
@@ -814,25 +1576,7 @@ impl DDEXBuilder {
 
     /// Helper to parse XML string to AST
     fn parse_xml_to_ast(&self, xml: &str) -> Result<super::ast::AST, super::error::BuildError> {
-        use quick_xml::Reader;
-
-        let mut reader = Reader::from_str(xml);
-        reader.config_mut().trim_text(true);
-
-        // This is a simplified XML->AST parser
-        // In a production system, you'd want to use the actual ddex-parser
-        let mut root_element = super::ast::Element::new("Root");
-        let namespace_map = indexmap::IndexMap::new();
-
-        // For now, create a basic AST structure
-        // TODO: Implement proper XML parsing or integrate with ddex-parser
-        root_element = root_element.with_text(xml);
-
-        Ok(super::ast::AST {
-            root: root_element,
-            namespaces: namespace_map,
-            schema_location: None,
-        })
+        super::diff::parse_xml(xml)
     }
 
     /// Create an UpdateReleaseMessage from two DDEX messages
@@ -71,6 +71,47 @@ fn test_formatting_respected_when_configured() {
     assert_eq!(changeset.summary.total_changes, 1);
 }
 
+const COMPACT_RELEASE: &str = r#"<Release><ReleaseId><ICPN>1234567890123</ICPN></ReleaseId><Title><TitleText>My Album</TitleText></Title></Release>"#;
+
+const INDENTED_RELEASE: &str = r#"<Release>
+    <ReleaseId>
+        <ICPN>1234567890123</ICPN>
+    </ReleaseId>
+    <Title>
+        <TitleText>My Album</TitleText>
+    </Title>
+</Release>"#;
+
+#[test]
+fn test_indentation_only_diff_ignored() {
+    let mut engine = DiffEngine::new_with_config(DiffConfig {
+        ignore_formatting: true,
+        ..DiffConfig::default()
+    });
+
+    let old = parse_xml(COMPACT_RELEASE).unwrap();
+    let new = parse_xml(INDENTED_RELEASE).unwrap();
+    let changeset = engine.diff(&old, &new).unwrap();
+
+    assert_eq!(changeset.summary.total_changes, 0);
+}
+
+#[test]
+fn test_significant_text_change_with_ignored_formatting() {
+    let mut engine = DiffEngine::new();
+
+    let old = parse_xml(COMPACT_RELEASE).unwrap();
+    let new = parse_xml(&INDENTED_RELEASE.replace("My Album", "My  Album (Deluxe)")).unwrap();
+    let changeset = engine.diff(&old, &new).unwrap();
+
+    assert_eq!(changeset.summary.total_changes, 1);
+    assert_eq!(changeset.changes[0].change_type, ChangeType::TextModified);
+    assert_eq!(
+        changeset.changes[0].new_value,
+        Some("My Album (Deluxe)".to_string())
+    );
+}
+
 #[test]
 fn test_attribute_changes() {
     let mut engine = DiffEngine::new();
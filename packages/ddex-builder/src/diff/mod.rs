@@ -16,6 +16,7 @@ mod diff_tests;
 use crate::ast::{Element, Node, AST};
 use crate::error::BuildError;
 use indexmap::{IndexMap, IndexSet};
+use quick_xml::events::{BytesStart, Event};
 use serde::{Deserialize, Serialize};
 use types::{ChangeSet, ChangeType, DiffPath, SemanticChange};
 
@@ -385,19 +386,21 @@ impl DiffEngine {
         Ok(())
     }
 
-    /// Extract text content from nodes, ignoring formatting
+    /// Extract text content from nodes, collapsing whitespace runs when
+    /// ignoring formatting so that indentation and line breaks don't count
     fn extract_text_content(&self, nodes: &[Node]) -> String {
         let mut text = String::new();
         for node in nodes {
             if let Node::Text(t) = node {
-                if self.config.ignore_formatting {
-                    text.push_str(t.trim());
-                } else {
-                    text.push_str(t);
-                }
+                text.push_str(t);
             }
         }
-        text
+
+        if self.config.ignore_formatting {
+            normalize_whitespace(&text)
+        } else {
+            text
+        }
     }
 
     /// Check if two values are semantically equivalent
@@ -418,7 +421,7 @@ impl DiffEngine {
 
         // Formatting equivalence
         if self.config.ignore_formatting {
-            return old.trim() == new.trim();
+            return normalize_whitespace(old) == normalize_whitespace(new);
         }
 
         old == new
@@ -551,6 +554,114 @@ impl Default for DiffEngine {
     }
 }
 
+/// Trim text and collapse each inner run of whitespace to a single space
+fn normalize_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Parse an XML document into an AST for diffing
+///
+/// Text is kept exactly as written, whitespace-only text between elements
+/// included, so that `DiffConfig::ignore_formatting` decides what counts as
+/// a change. Comments and processing instructions are dropped.
+pub fn parse_xml(xml: &str) -> Result<AST, BuildError> {
+    let invalid = |message: String| BuildError::InvalidFormat {
+        field: "xml".to_string(),
+        message,
+    };
+
+    let mut reader = quick_xml::Reader::from_str(xml);
+    let mut namespaces = IndexMap::new();
+    let mut open: Vec<Element> = Vec::new();
+    let mut root = None;
+
+    loop {
+        let closed = match reader
+            .read_event()
+            .map_err(|e| invalid(format!("XML parsing error: {}", e)))?
+        {
+            Event::Start(e) => {
+                let element = parse_start(&e, open.is_empty(), &mut namespaces)?;
+                open.push(element);
+                None
+            }
+            Event::Empty(e) => Some(parse_start(&e, open.is_empty(), &mut namespaces)?),
+            Event::End(_) => open.pop(),
+            Event::Text(e) => {
+                if let Some(parent) = open.last_mut() {
+                    let text = e
+                        .unescape()
+                        .map_err(|e| invalid(format!("XML parsing error: {}", e)))?;
+                    parent.add_text(text);
+                }
+                None
+            }
+            Event::CData(e) => {
+                if let Some(parent) = open.last_mut() {
+                    parent.add_text(String::from_utf8_lossy(&e));
+                }
+                None
+            }
+            Event::Eof => break,
+            _ => None,
+        };
+
+        if let Some(element) = closed {
+            match open.last_mut() {
+                Some(parent) => parent.add_child(element),
+                None => root = Some(element),
+            }
+        }
+    }
+
+    let root = root.ok_or_else(|| invalid("No root element found".to_string()))?;
+    Ok(AST {
+        root,
+        namespaces,
+        schema_location: None,
+    })
+}
+
+/// Build an element from its start tag. Namespace declarations on the root
+/// go to the AST's namespace map rather than the attributes.
+fn parse_start(
+    start: &BytesStart,
+    is_root: bool,
+    namespaces: &mut IndexMap<String, String>,
+) -> Result<Element, BuildError> {
+    let qualified = String::from_utf8_lossy(start.name().as_ref()).into_owned();
+    let mut element = match qualified.split_once(':') {
+        Some((prefix, local)) => Element::new(local).with_namespace(prefix),
+        None => Element::new(qualified.as_str()),
+    };
+
+    for attribute in start.attributes() {
+        let attribute = attribute.map_err(|e| BuildError::InvalidFormat {
+            field: "xml".to_string(),
+            message: format!("Invalid XML attribute: {}", e),
+        })?;
+        let key = String::from_utf8_lossy(attribute.key.as_ref()).into_owned();
+        let value = attribute
+            .unescape_value()
+            .map_err(|e| BuildError::InvalidFormat {
+                field: "xml".to_string(),
+                message: format!("Invalid XML attribute: {}", e),
+            })?
+            .into_owned();
+
+        match key.strip_prefix("xmlns") {
+            Some(prefix) if is_root && (prefix.is_empty() || prefix.starts_with(':')) => {
+                namespaces.insert(prefix.trim_start_matches(':').to_string(), value);
+            }
+            _ => {
+                element.attributes.insert(key, value);
+            }
+        }
+    }
+
+    Ok(element)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
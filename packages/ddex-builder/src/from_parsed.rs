@@ -0,0 +1,238 @@
+//! Build requests from messages read by the parser
+
+use crate::builder::{
+    BuildRequest, ContributorRequest, DealPrice, DealRequest, DealTerms, DisplayArtistRequest,
+    ImageRequest, LocalizedStringRequest, MessageHeaderRequest, PartyRequest, ReleaseRequest,
+    ResourceGroupContentItemRequest, ResourceGroupRequest, TrackRequest, VideoRequest,
+};
+use chrono::{DateTime, SecondsFormat, Utc};
+use ddex_core::models::common::{Identifier, LocalizedString};
+use ddex_core::models::flat::{ParsedDeal, ParsedERNMessage, ParsedRelease, ParsedTrack};
+use ddex_core::models::graph::MessageHeader;
+
+/// Request rebuilding `parsed`: its header as read, with message ID,
+/// parties, control type and creation time kept, and its releases, tracks,
+/// images, videos and deals
+pub(crate) fn build_request(parsed: &ParsedERNMessage) -> BuildRequest {
+    BuildRequest {
+        header: header(&parsed.graph.message_header),
+        version: parsed.graph.version.as_str().to_string(),
+        profile: parsed.flat.profile.clone(),
+        releases: parsed.flat.releases.iter().map(release).collect(),
+        deals: parsed.flat.deals.iter().map(deal).collect(),
+        extensions: None,
+    }
+}
+
+fn header(header: &MessageHeader) -> MessageHeaderRequest {
+    MessageHeaderRequest {
+        message_id: Some(header.message_id.clone()),
+        message_sender: party(
+            &header.message_sender.party_name,
+            &header.message_sender.party_id,
+            &header.message_sender.trading_name,
+        ),
+        message_recipient: party(
+            &header.message_recipient.party_name,
+            &header.message_recipient.party_id,
+            &header.message_recipient.trading_name,
+        ),
+        message_control_type: header
+            .message_control_type
+            .as_ref()
+            .map(|control_type| format!("{:?}", control_type)),
+        message_created_date_time: Some(
+            header
+                .message_created_date_time
+                .to_rfc3339_opts(SecondsFormat::AutoSi, true),
+        ),
+        message_thread_id: header.message_thread_id.clone(),
+    }
+}
+
+fn party(
+    names: &[LocalizedString],
+    ids: &[Identifier],
+    trading_name: &Option<String>,
+) -> PartyRequest {
+    PartyRequest {
+        party_name: names.iter().map(localized).collect(),
+        party_id: ids.first().map(|id| id.value.clone()),
+        party_reference: None,
+        trading_name: trading_name.clone(),
+    }
+}
+
+fn localized(string: &LocalizedString) -> LocalizedStringRequest {
+    LocalizedStringRequest {
+        text: string.text.clone(),
+        language_code: string.language_code.clone(),
+    }
+}
+
+fn release(release: &ParsedRelease) -> ReleaseRequest {
+    let title = if release.reference_title.is_empty() {
+        &release.title
+    } else {
+        &release.reference_title
+    };
+
+    ReleaseRequest {
+        release_id: release
+            .identifiers
+            .grid
+            .clone()
+            .unwrap_or_else(|| release.release_id.clone()),
+        release_reference: Some(release.release_id.clone()),
+        title: title.iter().map(localized).collect(),
+        display_title: release.display_title.iter().map(localized).collect(),
+        subtitle: release.default_subtitle.clone(),
+        artist: release.display_artist.clone(),
+        display_artists: release
+            .artists
+            .iter()
+            .map(|artist| DisplayArtistRequest {
+                name: artist.name.clone(),
+                role: artist.role.clone(),
+            })
+            .collect(),
+        label: release.label_name.clone(),
+        release_date: release.release_date.map(date),
+        release_dates_by_territory: release
+            .release_dates_by_territory
+            .iter()
+            .map(|(territory, release_date)| (territory.clone(), date(*release_date)))
+            .collect(),
+        preorder_date: release.preorder_date.map(date),
+        upc: release.identifiers.upc.clone(),
+        tracks: release.tracks.iter().map(track).collect(),
+        images: release
+            .images
+            .iter()
+            .map(|image| ImageRequest {
+                resource_reference: image.image_id.clone(),
+                image_type: image.image_type.clone(),
+                file_path: None,
+                hash_sum: None,
+            })
+            .collect(),
+        videos: release
+            .videos
+            .iter()
+            .map(|video| VideoRequest {
+                resource_reference: video.video_id.clone(),
+                video_type: video.video_type.clone(),
+                width: None,
+                height: None,
+                file_path: None,
+                hash_sum: None,
+            })
+            .collect(),
+        resource_references: None,
+        track_releases: Vec::new(),
+        component_release_references: release.component_release_references.clone(),
+        resource_groups: resource_groups(&release.tracks),
+    }
+}
+
+/// Volumes of the tracks, by disc number in order of first appearance,
+/// when any track was read from a `ResourceGroup`
+fn resource_groups(tracks: &[ParsedTrack]) -> Vec<ResourceGroupRequest> {
+    let grouped = tracks.iter().any(|track| {
+        track.track_number.is_some()
+            || track.disc_number.is_some()
+            || track.is_bonus
+            || track.is_hidden
+    });
+    if !grouped {
+        return Vec::new();
+    }
+
+    let mut groups: Vec<ResourceGroupRequest> = Vec::new();
+    for track in tracks {
+        let sequence_number = track.disc_number.and_then(|disc| u32::try_from(disc).ok());
+        let position = groups
+            .iter()
+            .position(|group| group.sequence_number == sequence_number)
+            .unwrap_or_else(|| {
+                groups.push(ResourceGroupRequest {
+                    sequence_number,
+                    content_items: Vec::new(),
+                });
+                groups.len() - 1
+            });
+        groups[position]
+            .content_items
+            .push(ResourceGroupContentItemRequest {
+                sequence_number: track
+                    .track_number
+                    .and_then(|number| u32::try_from(number).ok())
+                    .unwrap_or(track.position as u32),
+                resource_reference: track.track_id.clone(),
+                is_bonus: track.is_bonus,
+                is_hidden: track.is_hidden,
+            });
+    }
+    groups
+}
+
+fn track(track: &ParsedTrack) -> TrackRequest {
+    let seconds = track.duration.as_secs();
+    TrackRequest {
+        track_id: track.track_id.clone(),
+        resource_reference: Some(track.track_id.clone()),
+        isrc: track.isrc.clone().unwrap_or_default(),
+        title: track.title.clone(),
+        subtitle: track.subtitle.clone(),
+        duration: format!("PT{}M{}S", seconds / 60, seconds % 60),
+        artist: track.display_artist.clone(),
+        file_path: None,
+        hash_sum: None,
+        preview: None,
+        contributors: track
+            .contributors
+            .iter()
+            .map(|contributor| ContributorRequest {
+                name: contributor.name.clone(),
+                roles: contributor.roles.clone(),
+                instruments: contributor.instruments.clone(),
+            })
+            .collect(),
+    }
+}
+
+fn deal(deal: &ParsedDeal) -> DealRequest {
+    DealRequest {
+        deal_reference: Some(deal.deal_id.clone()),
+        deal_terms: DealTerms {
+            commercial_model_type: deal.commercial_models.first().cloned().unwrap_or_default(),
+            territory_code: deal.territories.included.clone(),
+            start_date: deal
+                .validity
+                .start_text
+                .clone()
+                .or_else(|| deal.validity.start.map(date)),
+            use_types: deal.usage_rights.clone(),
+            // Only `PriceInformation` carries a price type the builder can name
+            prices: deal
+                .pricing
+                .iter()
+                .filter_map(|tier| {
+                    Some(DealPrice {
+                        price_type: tier.tier_name.clone()?,
+                        amount: tier
+                            .amount_text
+                            .clone()
+                            .unwrap_or_else(|| tier.price.amount.to_string()),
+                        currency_code: tier.price.currency.clone(),
+                    })
+                })
+                .collect(),
+        },
+        release_references: deal.releases.clone(),
+    }
+}
+
+fn date(date_time: DateTime<Utc>) -> String {
+    date_time.format("%Y-%m-%d").to_string()
+}
@@ -74,20 +74,163 @@
 pub mod optimized_xml_writer;
 pub mod xml_writer;
 
-use crate::ast::{Element, AST}; // Removed unused Node import
-use crate::builder::{BuildRequest, ReleaseRequest};
+use crate::ast::{Element, Node, AST};
+use crate::builder::{
+    BuildRequest, ContributorRequest, CreatedDateTimeOptions, DisplayArtistRequest,
+    HashSumRequest, ImageRequest, LabelForm, LegacyElement, MessageHeaderRequest, ReferencedFile,
+    ReleaseRequest, ResourceGroupRequest, SortOrder, TrackReleaseRequest, TrackRequest,
+    VideoRequest,
+};
 use crate::error::BuildError;
+use crate::preflight::duration_seconds;
+use crate::versions::VersionSpec;
+use ddex_core::models::graph::HAS_COMPONENT_RELEASE;
 use indexmap::IndexMap;
+use std::borrow::Cow;
 
 /// AST generator for converting build requests to abstract syntax trees
 pub struct ASTGenerator {
     version: String,
+    omit_empty: bool,
+    sort_order: SortOrder,
+    label_form: LabelForm,
+    legacy_elements: Vec<LegacyElement>,
+    preserve_lexical_forms: bool,
+    created_date_time: CreatedDateTimeOptions,
+    /// ERN 4.x element names this version spells differently
+    renamed_elements: IndexMap<String, String>,
+    /// Files named by the resources of the last message generated
+    referenced_files: Vec<ReferencedFile>,
 }
 
 impl ASTGenerator {
     /// Create a new AST generator for the specified version
     pub fn new(version: String) -> Self {
-        Self { version }
+        let renamed_elements = VersionSpec::for_version(&version)
+            .map(|spec| spec.renamed_elements)
+            .unwrap_or_default();
+        Self {
+            version,
+            omit_empty: true,
+            sort_order: SortOrder::Insertion,
+            label_form: LabelForm::Inline,
+            legacy_elements: Vec::new(),
+            preserve_lexical_forms: false,
+            created_date_time: CreatedDateTimeOptions::default(),
+            renamed_elements,
+            referenced_files: Vec::new(),
+        }
+    }
+
+    /// Whether optional fields holding only whitespace are left out (the
+    /// default) or emitted as empty elements
+    pub fn with_omit_empty(mut self, omit_empty: bool) -> Self {
+        self.omit_empty = omit_empty;
+        self
+    }
+
+    /// Order the `ReleaseList` and `ResourceList` are emitted in; insertion
+    /// order by default
+    pub fn with_sort_order(mut self, sort_order: SortOrder) -> Self {
+        self.sort_order = sort_order;
+        self
+    }
+
+    /// Whether labels are written as `LabelName` (the default) or as parties
+    /// named by `ReleaseLabelReference`
+    pub fn with_label_form(mut self, label_form: LabelForm) -> Self {
+        self.label_form = label_form;
+        self
+    }
+
+    /// Deprecated elements to emit alongside the modern structure; none by
+    /// default
+    pub fn with_legacy_elements(mut self, legacy_elements: Vec<LegacyElement>) -> Self {
+        self.legacy_elements = legacy_elements;
+        self
+    }
+
+    fn emits_legacy(&self, element: LegacyElement) -> bool {
+        self.legacy_elements.contains(&element)
+    }
+
+    /// Whether date values are emitted exactly as given rather than
+    /// normalized (the default)
+    pub fn with_preserve_lexical_forms(mut self, preserve_lexical_forms: bool) -> Self {
+        self.preserve_lexical_forms = preserve_lexical_forms;
+        self
+    }
+
+    /// Value and form of `MessageCreatedDateTime` for headers that do not
+    /// give one; the current time in UTC by default
+    pub fn with_created_date_time(mut self, created_date_time: CreatedDateTimeOptions) -> Self {
+        self.created_date_time = created_date_time;
+        self
+    }
+
+    /// `MessageCreatedDateTime` for a header that does not give one
+    fn created_date_time(&self) -> Result<String, BuildError> {
+        let options = &self.created_date_time;
+        if *options == CreatedDateTimeOptions::default() {
+            return Ok(chrono::Utc::now().to_rfc3339());
+        }
+        let invalid = |message: String| BuildError::InvalidFormat {
+            field: "created_date_time".to_string(),
+            message,
+        };
+
+        let created = match options.value.as_deref() {
+            Some(value) => chrono::DateTime::parse_from_rfc3339(value.trim())
+                .map_err(|e| invalid(format!("'{}' is not an RFC 3339 date-time: {}", value, e)))?,
+            None => chrono::Utc::now().fixed_offset(),
+        };
+        let created = match options.utc_offset.as_deref().map(str::trim) {
+            Some("Z") => created.with_timezone(&chrono::FixedOffset::east_opt(0).unwrap()),
+            Some(offset) => {
+                let offset: chrono::FixedOffset = offset
+                    .parse()
+                    .map_err(|_| invalid(format!("'{}' is not a UTC offset like +09:00", offset)))?;
+                created.with_timezone(&offset)
+            }
+            None => created,
+        };
+
+        let seconds = if options.omit_fractional_seconds {
+            chrono::SecondsFormat::Secs
+        } else {
+            chrono::SecondsFormat::AutoSi
+        };
+        Ok(created.to_rfc3339_opts(seconds, true))
+    }
+
+    /// A date as emitted: as given when lexical forms are preserved,
+    /// otherwise with any time of day dropped, as DDEX dates are `YYYY-MM-DD`
+    fn date<'a>(&self, value: &'a str) -> Cow<'a, str> {
+        if self.preserve_lexical_forms {
+            return Cow::Borrowed(value);
+        }
+        let value = value.trim();
+        chrono::DateTime::parse_from_rfc3339(value)
+            .map(|date_time| date_time.date_naive())
+            .or_else(|_| {
+                chrono::NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S")
+                    .map(|date_time| date_time.date())
+            })
+            .map(|date| Cow::Owned(date.format("%Y-%m-%d").to_string()))
+            .unwrap_or(Cow::Borrowed(value))
+    }
+
+    /// Files named by the resources of the message last generated, in
+    /// `ResourceList` order
+    pub fn referenced_files(&self) -> &[ReferencedFile] {
+        &self.referenced_files
+    }
+
+    /// The value of an optional field, if it should produce an element
+    fn optional<'a>(&self, value: &'a Option<String>) -> Option<&'a String> {
+        value
+            .as_ref()
+            .filter(|v| !(self.omit_empty && v.trim().is_empty()))
     }
 
     /// Generate an AST from a build request
@@ -103,13 +246,39 @@ impl ASTGenerator {
         );
 
         // Add MessageHeader
-        root.add_child(self.generate_message_header(request)?);
+        root.add_child(self.generate_message_header(&request.header)?);
+
+        let releases = self.ordered_releases(&request.releases);
 
-        // Add ResourceList
-        root.add_child(self.generate_resource_list(&request.releases)?);
+        // Add PartyList for labels written as referenced parties
+        let label_references = self.label_references(&releases);
+        if !label_references.is_empty() {
+            root.add_child(Self::generate_label_party_list(&label_references));
+        }
+
+        // Add ResourceList, noting the files it names while element names
+        // are still those of ERN 4.x
+        let resource_list = self.generate_resource_list(&releases)?;
+        self.referenced_files = resource_list
+            .children
+            .iter()
+            .filter_map(|child| match child {
+                Node::Element(resource) => referenced_file(resource),
+                _ => None,
+            })
+            .collect();
+        root.add_child(resource_list);
 
         // Add ReleaseList
-        root.add_child(self.generate_release_list(&request.releases)?);
+        root.add_child(self.generate_release_list(&releases, &label_references)?);
+
+        // Add DealList; visibility dates live there too, apart from any deal
+        if let Some(deal_list) = self.generate_deal_list(&request.deals, &releases) {
+            root.add_child(deal_list);
+        }
+
+        // Elements are generated under their ERN 4.x names; use this version's
+        self.rename_elements(&mut root);
 
         // Create namespaces map
         let mut namespaces = IndexMap::new();
@@ -129,31 +298,42 @@ impl ASTGenerator {
         })
     }
 
-    fn generate_message_header(&self, request: &BuildRequest) -> Result<Element, BuildError> {
+    /// Generate a MessageHeader element, shared by all message types
+    pub(crate) fn generate_message_header(
+        &self,
+        header_request: &MessageHeaderRequest,
+    ) -> Result<Element, BuildError> {
         let mut header = Element::new("MessageHeader");
 
-        // Add MessageThreadId (using MessageId for now)
-        if let Some(ref msg_id) = request.header.message_id {
-            header.add_child(Element::new("MessageThreadId").with_text(msg_id));
+        // Add MessageThreadId (falling back to the MessageId)
+        let thread_id = self
+            .optional(&header_request.message_thread_id)
+            .or(self.optional(&header_request.message_id));
+        if let Some(thread_id) = thread_id {
+            header.add_child(Element::new("MessageThreadId").with_text(thread_id));
+        }
+        if let Some(msg_id) = self.optional(&header_request.message_id) {
             header.add_child(Element::new("MessageId").with_text(msg_id));
         }
 
-        // Add MessageCreatedDateTime - use provided timestamp or current time
-        let created_time = request
-            .header
-            .message_created_date_time
-            .as_ref()
-            .map(|t| t.clone())
-            .unwrap_or_else(|| chrono::Utc::now().to_rfc3339());
+        // Add MessageCreatedDateTime - use provided timestamp or the configured one
+        let created_time = match &header_request.message_created_date_time {
+            Some(created_time) => created_time.clone(),
+            None => self.created_date_time()?,
+        };
 
         header.add_child(Element::new("MessageCreatedDateTime").with_text(created_time));
 
         // Add MessageSender
-        header.add_child(self.generate_party("MessageSender", &request.header.message_sender)?);
+        header.add_child(self.generate_party("MessageSender", &header_request.message_sender)?);
 
         // Add MessageRecipient
         header
-            .add_child(self.generate_party("MessageRecipient", &request.header.message_recipient)?);
+            .add_child(self.generate_party("MessageRecipient", &header_request.message_recipient)?);
+
+        if let Some(control_type) = self.optional(&header_request.message_control_type) {
+            header.add_child(Element::new("MessageControlType").with_text(control_type));
+        }
 
         Ok(header)
     }
@@ -166,12 +346,12 @@ impl ASTGenerator {
         let mut party_elem = Element::new(element_name);
 
         // Add PartyId if present
-        if let Some(ref party_id) = party.party_id {
+        if let Some(party_id) = self.optional(&party.party_id) {
             party_elem.add_child(Element::new("PartyId").with_text(party_id));
         }
 
         // Add PartyReference if present (for linker support)
-        if let Some(ref party_ref) = party.party_reference {
+        if let Some(party_ref) = self.optional(&party.party_reference) {
             party_elem.add_child(Element::new("PartyReference").with_text(party_ref));
         }
 
@@ -187,168 +367,774 @@ impl ASTGenerator {
             party_elem.add_child(name_elem);
         }
 
+        // Add TradingName if present
+        if let Some(trading_name) = self.optional(&party.trading_name) {
+            party_elem.add_child(Element::new("TradingName").with_text(trading_name));
+        }
+
         Ok(party_elem)
     }
 
+    /// Releases in the configured sort order, borrowed as given for
+    /// insertion order
+    fn ordered_releases<'a>(&self, releases: &'a [ReleaseRequest]) -> Cow<'a, [ReleaseRequest]> {
+        if self.sort_order == SortOrder::Insertion {
+            return Cow::Borrowed(releases);
+        }
+
+        let mut sorted = releases.to_vec();
+        sorted.sort_by_cached_key(|release| match self.sort_order {
+            SortOrder::Identifier => release
+                .upc
+                .clone()
+                .filter(|upc| !upc.trim().is_empty())
+                .unwrap_or_else(|| release.release_id.clone()),
+            _ => release_reference(release),
+        });
+        Cow::Owned(sorted)
+    }
+
     fn generate_resource_list(&self, releases: &[ReleaseRequest]) -> Result<Element, BuildError> {
         let mut resource_list = Element::new("ResourceList");
+        let mut sound_recordings = Vec::new();
 
         // Generate resources from all tracks in all releases
         for release in releases {
             for track in &release.tracks {
-                let mut sound_recording = Element::new("SoundRecording");
-
-                // Add ResourceReference (use generated reference or create one)
-                // FIX: Create owned string instead of temporary
-                let resource_ref = track
-                    .resource_reference
-                    .clone()
-                    .unwrap_or_else(|| format!("A{}", track.track_id));
-                sound_recording
-                    .add_child(Element::new("ResourceReference").with_text(&resource_ref));
-
-                // Add ResourceId with ISRC
-                let mut resource_id = Element::new("ResourceId");
-                resource_id.add_child(Element::new("ISRC").with_text(&track.isrc));
-                sound_recording.add_child(resource_id);
-
-                // Add ReferenceTitle
-                let mut ref_title = Element::new("ReferenceTitle");
-                ref_title.add_child(Element::new("TitleText").with_text(&track.title));
-                sound_recording.add_child(ref_title);
-
-                // Add Duration (already in ISO 8601 format as String)
-                sound_recording.add_child(Element::new("Duration").with_text(&track.duration));
-
-                resource_list.add_child(sound_recording);
+                let key = match self.sort_order {
+                    SortOrder::Insertion => String::new(),
+                    SortOrder::Reference => resource_reference(track),
+                    SortOrder::Identifier => track.isrc.clone(),
+                };
+                sound_recordings.push((key, self.generate_sound_recording(track)?));
             }
         }
 
+        // Stable, so insertion order also breaks ties between equal keys
+        sound_recordings.sort_by(|(a, _), (b, _)| a.cmp(b));
+        for (_, sound_recording) in sound_recordings {
+            resource_list.add_child(sound_recording);
+        }
+
+        // Images follow every recording
+        let mut images = Vec::new();
+        for release in releases {
+            for image in &release.images {
+                let key = match self.sort_order {
+                    SortOrder::Insertion => String::new(),
+                    _ => image.resource_reference.clone(),
+                };
+                images.push((key, self.generate_image(image)?));
+            }
+        }
+        images.sort_by(|(a, _), (b, _)| a.cmp(b));
+        for (_, image) in images {
+            resource_list.add_child(image);
+        }
+
+        // Then videos
+        let mut videos = Vec::new();
+        for release in releases {
+            for video in &release.videos {
+                let key = match self.sort_order {
+                    SortOrder::Insertion => String::new(),
+                    _ => video.resource_reference.clone(),
+                };
+                videos.push((key, self.generate_video(video)?));
+            }
+        }
+        videos.sort_by(|(a, _), (b, _)| a.cmp(b));
+        for (_, video) in videos {
+            resource_list.add_child(video);
+        }
+
         Ok(resource_list)
     }
 
-    fn generate_release_list(&self, releases: &[ReleaseRequest]) -> Result<Element, BuildError> {
+    /// Generate the Image for one image of a release
+    pub(crate) fn generate_image(&self, image: &ImageRequest) -> Result<Element, BuildError> {
+        let mut image_elem = Element::new("Image");
+        image_elem
+            .add_child(Element::new("ResourceReference").with_text(&image.resource_reference));
+        image_elem.add_child(Element::new("Type").with_text(&image.image_type));
+
+        if let Some(hash) = file_hash_sum(&image.hash_sum, &image.file_path)? {
+            let mut details = Element::new("TechnicalDetails");
+            details.add_child(
+                Element::new("TechnicalResourceDetailsReference")
+                    .with_text(format!("T{}", image.resource_reference)),
+            );
+            details.add_child(self.generate_file(&image.file_path, &hash));
+            image_elem.add_child(details);
+        }
+
+        Ok(image_elem)
+    }
+
+    /// Generate the Video for one video of a release
+    pub(crate) fn generate_video(&self, video: &VideoRequest) -> Result<Element, BuildError> {
+        let mut video_elem = Element::new("Video");
+        video_elem
+            .add_child(Element::new("ResourceReference").with_text(&video.resource_reference));
+        video_elem.add_child(Element::new("Type").with_text(&video.video_type));
+
+        let hash_sum = file_hash_sum(&video.hash_sum, &video.file_path)?;
+        if hash_sum.is_some() || video.width.is_some() || video.height.is_some() {
+            let mut details = Element::new("TechnicalDetails");
+            details.add_child(
+                Element::new("TechnicalResourceDetailsReference")
+                    .with_text(format!("T{}", video.resource_reference)),
+            );
+            if let Some(height) = video.height {
+                details.add_child(Element::new("ImageHeight").with_text(height.to_string()));
+            }
+            if let Some(width) = video.width {
+                details.add_child(Element::new("ImageWidth").with_text(width.to_string()));
+            }
+            if let Some(hash) = hash_sum {
+                details.add_child(self.generate_file(&video.file_path, &hash));
+            }
+            video_elem.add_child(details);
+        }
+
+        Ok(video_elem)
+    }
+
+    /// Generate the SoundRecording for one track
+    pub(crate) fn generate_sound_recording(
+        &self,
+        track: &TrackRequest,
+    ) -> Result<Element, BuildError> {
+        let mut sound_recording = Element::new("SoundRecording");
+
+        let resource_ref = resource_reference(track);
+        sound_recording.add_child(Element::new("ResourceReference").with_text(&resource_ref));
+
+        // Add ResourceId with ISRC
+        let mut resource_id = Element::new("ResourceId");
+        resource_id.add_child(Element::new("ISRC").with_text(&track.isrc));
+        sound_recording.add_child(resource_id);
+        if self.emits_legacy(LegacyElement::SoundRecordingId) {
+            let mut sound_recording_id = Element::new("SoundRecordingId");
+            sound_recording_id.add_child(Element::new("ISRC").with_text(&track.isrc));
+            sound_recording.add_child(sound_recording_id);
+        }
+
+        // Add ReferenceTitle, with the version as its SubTitle
+        let mut ref_title = Element::new("ReferenceTitle");
+        ref_title.add_child(Element::new("TitleText").with_text(&track.title));
+        if let Some(subtitle) = self.optional(&track.subtitle) {
+            ref_title.add_child(Element::new("SubTitle").with_text(subtitle));
+        }
+        sound_recording.add_child(ref_title);
+
+        // Add the recording's own artist, which may differ from the release's
+        if !track.artist.trim().is_empty() {
+            let mut display_artist = Element::new("DisplayArtistName");
+            display_artist.add_child(Element::new("FullName").with_text(&track.artist));
+            sound_recording.add_child(display_artist);
+        }
+
+        if self.emits_legacy(LegacyElement::SoundRecordingDetailsByTerritory) {
+            let mut details = Element::new("SoundRecordingDetailsByTerritory");
+            details.add_child(Element::new("TerritoryCode").with_text("Worldwide"));
+            details.add_child(legacy_title(&track.title));
+            if !track.artist.trim().is_empty() {
+                details.add_child(Element::new("DisplayArtistName").with_text(&track.artist));
+            }
+            sound_recording.add_child(details);
+        }
+
+        // Add Contributors in the order given
+        for (idx, contributor) in track.contributors.iter().enumerate() {
+            sound_recording.add_child(Self::generate_contributor(contributor, idx + 1));
+        }
+
+        // Add Duration (already in ISO 8601 format as String)
+        sound_recording.add_child(Element::new("Duration").with_text(&track.duration));
+
+        if let Some(details) = self.generate_technical_details(track, &resource_ref)? {
+            sound_recording.add_child(details);
+        }
+
+        Ok(sound_recording)
+    }
+
+    /// ERN 3.x `ReleaseDetailsByTerritory` for `Worldwide`, titled with the
+    /// release's first display title, or its first title
+    fn generate_release_details_by_territory(&self, release: &ReleaseRequest) -> Element {
+        let mut details = Element::new("ReleaseDetailsByTerritory");
+        details.add_child(Element::new("TerritoryCode").with_text("Worldwide"));
+        details.add_child(Element::new("DisplayArtistName").with_text(&release.artist));
+        if let Some(label) = self.optional(&release.label) {
+            details.add_child(Element::new("LabelName").with_text(label));
+        }
+        if let Some(title) = release.display_title.first().or(release.title.first()) {
+            details.add_child(legacy_title(&title.text));
+        }
+        details
+    }
+
+    fn generate_display_artist(artist: &DisplayArtistRequest, sequence_number: usize) -> Element {
+        let mut artist_elem = Element::new("DisplayArtist");
+        artist_elem
+            .attributes
+            .insert("SequenceNumber".to_string(), sequence_number.to_string());
+
+        let mut party_name = Element::new("PartyName");
+        party_name.add_child(Element::new("FullName").with_text(&artist.name));
+        artist_elem.add_child(party_name);
+        artist_elem.add_child(Element::new("DisplayArtistRole").with_text(&artist.role));
+
+        artist_elem
+    }
+
+    fn generate_contributor(contributor: &ContributorRequest, sequence_number: usize) -> Element {
+        let mut contributor_elem = Element::new("Contributor");
+        contributor_elem
+            .attributes
+            .insert("SequenceNumber".to_string(), sequence_number.to_string());
+
+        let mut party_name = Element::new("PartyName");
+        party_name.add_child(Element::new("FullName").with_text(&contributor.name));
+        contributor_elem.add_child(party_name);
+
+        for role in &contributor.roles {
+            contributor_elem.add_child(Element::new("Role").with_text(role));
+        }
+        for instrument in &contributor.instruments {
+            contributor_elem.add_child(Element::new("InstrumentType").with_text(instrument));
+        }
+
+        contributor_elem
+    }
+
+    fn rename_elements(&self, element: &mut Element) {
+        if let Some(name) = self.renamed_elements.get(&element.name) {
+            element.name = name.clone();
+        }
+        for child in &mut element.children {
+            if let Node::Element(child) = child {
+                self.rename_elements(child);
+            }
+        }
+    }
+
+    /// TechnicalDetails carrying the preview window and the delivered file
+    /// with its checksum, if the track has any of them
+    fn generate_technical_details(
+        &self,
+        track: &TrackRequest,
+        resource_ref: &str,
+    ) -> Result<Option<Element>, BuildError> {
+        let hash_sum = file_hash_sum(&track.hash_sum, &track.file_path)?;
+        if hash_sum.is_none() && track.preview.is_none() {
+            return Ok(None);
+        }
+
+        let mut details = Element::new("TechnicalDetails");
+        details.add_child(
+            Element::new("TechnicalResourceDetailsReference")
+                .with_text(format!("T{}", resource_ref)),
+        );
+
+        if let Some(preview) = &track.preview {
+            // StartPoint is a number of seconds; Duration stays ISO 8601
+            let start = duration_seconds(&preview.start)
+                .map(|seconds| seconds.to_string())
+                .unwrap_or_else(|| preview.start.clone());
+            let mut preview_elem = Element::new("PreviewDetails");
+            preview_elem.add_child(Element::new("StartPoint").with_text(start));
+            preview_elem.add_child(Element::new("Duration").with_text(&preview.duration));
+            preview_elem.add_child(Element::new("ExpressionType").with_text("Instructive"));
+            details.add_child(preview_elem);
+        }
+
+        if let Some(hash) = hash_sum {
+            details.add_child(self.generate_file(&track.file_path, &hash));
+        }
+
+        Ok(Some(details))
+    }
+
+    /// File naming a delivered file, if its path is given, with its checksum
+    fn generate_file(&self, file_path: &Option<String>, hash: &HashSumRequest) -> Element {
+        let mut file = Element::new("File");
+        if let Some(path) = self.optional(file_path) {
+            file.add_child(Element::new("URI").with_text(path));
+        }
+        let mut hash_elem = Element::new("HashSum");
+        hash_elem.add_child(Element::new("Algorithm").with_text(&hash.algorithm));
+        hash_elem.add_child(Element::new("HashSumValue").with_text(&hash.value));
+        file.add_child(hash_elem);
+        file
+    }
+
+    /// `PartyReference` of each distinct label, in order of first use, when
+    /// labels are written as parties; empty otherwise
+    fn label_references(&self, releases: &[ReleaseRequest]) -> IndexMap<String, String> {
+        let mut references = IndexMap::new();
+        if self.label_form != LabelForm::PartyReference {
+            return references;
+        }
+        for label in releases
+            .iter()
+            .filter_map(|release| self.optional(&release.label))
+        {
+            let reference = format!("PLabel{}", references.len() + 1);
+            references.entry(label.clone()).or_insert(reference);
+        }
+        references
+    }
+
+    fn generate_label_party_list(label_references: &IndexMap<String, String>) -> Element {
+        let mut party_list = Element::new("PartyList");
+        for (label, reference) in label_references {
+            let mut party = Element::new("Party");
+            party.add_child(Element::new("PartyReference").with_text(reference));
+            let mut party_name = Element::new("PartyName");
+            party_name.add_child(Element::new("FullName").with_text(label));
+            party.add_child(party_name);
+            party_list.add_child(party);
+        }
+        party_list
+    }
+
+    fn generate_release_list(
+        &self,
+        releases: &[ReleaseRequest],
+        label_references: &IndexMap<String, String>,
+    ) -> Result<Element, BuildError> {
         let mut release_list = Element::new("ReleaseList");
 
+        // Bundles name their components by release ID
+        let mut release_ids = IndexMap::new();
         for release in releases {
-            let mut release_elem = Element::new("Release");
+            release_ids
+                .entry(release_reference(release))
+                .or_insert_with(|| release.release_id.clone());
+        }
 
-            // Add ReleaseReference (use generated reference or create one)
-            // FIX: Create owned string instead of temporary
-            let release_ref = release
-                .release_reference
-                .clone()
-                .unwrap_or_else(|| format!("R{}", release.release_id));
-            release_elem.add_child(Element::new("ReleaseReference").with_text(&release_ref));
-
-            // Add ReleaseId
-            let mut release_id = Element::new("ReleaseId");
-            release_id.add_child(Element::new("GRid").with_text(&release.release_id));
-            release_elem.add_child(release_id);
-
-            // Add Title(s)
-            if !release.title.is_empty() {
-                for title in &release.title {
-                    let mut title_elem = Element::new("ReferenceTitle");
-                    let mut title_text = Element::new("TitleText").with_text(&title.text);
-                    if let Some(ref lang) = title.language_code {
-                        title_text
-                            .attributes
-                            .insert("LanguageAndScriptCode".to_string(), lang.clone());
-                    }
-                    title_elem.add_child(title_text);
-                    release_elem.add_child(title_elem);
+        for (index, release) in releases.iter().enumerate() {
+            release_list.add_child(self.generate_release(
+                release,
+                index,
+                &release_ids,
+                label_references,
+            )?);
+        }
+
+        // TrackReleases follow every main release
+        for release in releases {
+            for track_release in &release.track_releases {
+                release_list.add_child(self.generate_track_release(release, track_release));
+            }
+        }
+
+        Ok(release_list)
+    }
+
+    /// Generate the Release element for `release`, the `index`th in the
+    /// message. `release_ids` maps release references to release IDs for
+    /// the bundle components it names.
+    pub(crate) fn generate_release(
+        &self,
+        release: &ReleaseRequest,
+        index: usize,
+        release_ids: &IndexMap<String, String>,
+        label_references: &IndexMap<String, String>,
+    ) -> Result<Element, BuildError> {
+        let mut release_elem = Element::new("Release");
+
+        // Add ReleaseReference (use generated reference or create one)
+        release_elem
+            .add_child(Element::new("ReleaseReference").with_text(release_reference(release)));
+
+        // Add ReleaseId
+        let mut release_id = Element::new("ReleaseId");
+        release_id.add_child(Element::new("GRid").with_text(&release.release_id));
+        release_elem.add_child(release_id);
+
+        // Add Title(s)
+        if !release.title.is_empty() {
+            for title in &release.title {
+                let mut title_elem = Element::new("ReferenceTitle");
+                let mut title_text = Element::new("TitleText").with_text(&title.text);
+                if let Some(ref lang) = title.language_code {
+                    title_text
+                        .attributes
+                        .insert("LanguageAndScriptCode".to_string(), lang.clone());
                 }
+                title_elem.add_child(title_text);
+                if let Some(subtitle) = self.optional(&release.subtitle) {
+                    title_elem.add_child(Element::new("SubTitle").with_text(subtitle));
+                }
+                release_elem.add_child(title_elem);
             }
+        }
 
-            // Add DisplayArtist
-            let mut display_artist_name = Element::new("DisplayArtistName");
-            display_artist_name.add_child(Element::new("FullName").with_text(&release.artist));
-            release_elem.add_child(display_artist_name);
+        for title in &release.display_title {
+            let mut display_title = Element::new("DisplayTitle");
+            if let Some(ref lang) = title.language_code {
+                display_title
+                    .attributes
+                    .insert("LanguageAndScriptCode".to_string(), lang.clone());
+            }
+            display_title.add_child(Element::new("TitleText").with_text(&title.text));
+            release_elem.add_child(display_title);
+        }
+
+        // Add DisplayArtist
+        let mut display_artist_name = Element::new("DisplayArtistName");
+        display_artist_name.add_child(Element::new("FullName").with_text(&release.artist));
+        release_elem.add_child(display_artist_name);
+        for (idx, artist) in release.display_artists.iter().enumerate() {
+            release_elem.add_child(Self::generate_display_artist(artist, idx + 1));
+        }
 
-            // Add Label if present
-            if let Some(ref label) = release.label {
+        // Add Label if present, inline or as a reference to its party
+        if let Some(label) = self.optional(&release.label) {
+            if let Some(reference) = label_references.get(label) {
+                release_elem.add_child(Element::new("ReleaseLabelReference").with_text(reference));
+            } else {
                 let mut label_name = Element::new("LabelName");
                 label_name.add_child(Element::new("LabelName").with_text(label));
                 release_elem.add_child(label_name);
             }
+        }
 
-            // Add UPC if present
-            if let Some(ref upc) = release.upc {
-                let mut release_id_upc = Element::new("ReleaseId");
-                release_id_upc.add_child(Element::new("ICPN").with_text(upc));
-                release_elem.add_child(release_id_upc);
-            }
+        if self.emits_legacy(LegacyElement::ReleaseDetailsByTerritory) {
+            release_elem.add_child(self.generate_release_details_by_territory(release));
+        }
 
-            // Add ReleaseDate if present
-            if let Some(ref release_date) = release.release_date {
-                release_elem.add_child(Element::new("ReleaseDate").with_text(release_date));
+        // Add UPC if present
+        if let Some(upc) = self.optional(&release.upc) {
+            let mut release_id_upc = Element::new("ReleaseId");
+            release_id_upc.add_child(Element::new("ICPN").with_text(upc));
+            release_elem.add_child(release_id_upc);
+        }
+
+        // Add ReleaseDate if present
+        if let Some(release_date) = self.optional(&release.release_date) {
+            release_elem.add_child(Element::new("ReleaseDate").with_text(release_date));
+        }
+
+        // Add a ReleaseDate for each territory the release comes out in on its own date
+        for (territory, date) in &release.release_dates_by_territory {
+            release_elem.add_child(
+                Element::new("ReleaseDate")
+                    .with_attr("ApplicableTerritoryCode", territory)
+                    .with_text(date),
+            );
+        }
+
+        // Point at the ReleaseVisibility holding the pre-order date
+        if self.optional(&release.preorder_date).is_some() {
+            release_elem.add_child(
+                Element::new("ReleaseVisibilityReference").with_text(visibility_reference(index)),
+            );
+        }
+
+        // Add ReleaseResourceReferences
+        if let Some(ref resource_refs) = release.resource_references {
+            for resource_ref in resource_refs {
+                release_elem
+                    .add_child(Element::new("ReleaseResourceReference").with_text(resource_ref));
+            }
+        } else {
+            // Auto-generate from tracks, images and videos if not provided
+            for track in &release.tracks {
+                release_elem.add_child(
+                    Element::new("ReleaseResourceReference").with_text(resource_reference(track)),
+                );
             }
+            for image in &release.images {
+                release_elem.add_child(
+                    Element::new("ReleaseResourceReference").with_text(&image.resource_reference),
+                );
+            }
+            for video in &release.videos {
+                release_elem.add_child(
+                    Element::new("ReleaseResourceReference").with_text(&video.resource_reference),
+                );
+            }
+        }
 
-            // Add ReleaseResourceReferences
-            if let Some(ref resource_refs) = release.resource_references {
-                for resource_ref in resource_refs {
-                    release_elem.add_child(
-                        Element::new("ReleaseResourceReference").with_text(resource_ref),
-                    );
+        if !release.resource_groups.is_empty() {
+            release_elem.add_child(Self::generate_resource_group(&release.resource_groups));
+        }
+
+        // A bundle names each of its components by release ID
+        for component_ref in &release.component_release_references {
+            let component_id =
+                release_ids
+                    .get(component_ref)
+                    .ok_or_else(|| BuildError::InvalidReference {
+                        reference: component_ref.clone(),
+                    })?;
+            release_elem.add_child(Self::related_release(HAS_COMPONENT_RELEASE, component_id));
+        }
+
+        Ok(release_elem)
+    }
+
+    /// The release's main `ResourceGroup`, holding a `ResourceGroup` per
+    /// volume with a `ResourceGroupContentItem` per resource on it
+    fn generate_resource_group(volumes: &[ResourceGroupRequest]) -> Element {
+        let mut main_group = Element::new("ResourceGroup");
+        for volume in volumes {
+            let mut group = Element::new("ResourceGroup");
+            if let Some(sequence_number) = volume.sequence_number {
+                group.add_child(
+                    Element::new("SequenceNumber").with_text(sequence_number.to_string()),
+                );
+            }
+            for content_item in &volume.content_items {
+                let mut item = Element::new("ResourceGroupContentItem");
+                item.add_child(
+                    Element::new("SequenceNumber")
+                        .with_text(content_item.sequence_number.to_string()),
+                );
+                item.add_child(
+                    Element::new("ReleaseResourceReference")
+                        .with_text(&content_item.resource_reference),
+                );
+                if content_item.is_bonus {
+                    item.add_child(Element::new("IsBonusResource").with_text("true"));
                 }
-            } else {
-                // Auto-generate from tracks if not provided
-                for track in &release.tracks {
-                    // FIX: Create owned string instead of temporary
-                    let resource_ref = track
-                        .resource_reference
-                        .clone()
-                        .unwrap_or_else(|| format!("A{}", track.track_id));
-                    release_elem.add_child(
-                        Element::new("ReleaseResourceReference").with_text(&resource_ref),
-                    );
+                if content_item.is_hidden {
+                    item.add_child(Element::new("IsHiddenResource").with_text("true"));
                 }
+                group.add_child(item);
             }
+            main_group.add_child(group);
+        }
+        main_group
+    }
+
+    /// Generate a TrackRelease for one track of `release`, linked back to it
+    pub(crate) fn generate_track_release(
+        &self,
+        release: &ReleaseRequest,
+        track_release: &TrackReleaseRequest,
+    ) -> Element {
+        let mut track_release_elem = Element::new("TrackRelease");
 
-            release_list.add_child(release_elem);
+        let release_ref = track_release
+            .release_reference
+            .clone()
+            .unwrap_or_else(|| format!("R{}", track_release.release_id));
+        track_release_elem.add_child(Element::new("ReleaseReference").with_text(&release_ref));
+
+        let mut release_id = Element::new("ReleaseId");
+        release_id.add_child(Element::new("GRid").with_text(&track_release.release_id));
+        track_release_elem.add_child(release_id);
+
+        // Fall back to the title of the track being released
+        let title = self.optional(&track_release.title).cloned().or_else(|| {
+            release
+                .tracks
+                .iter()
+                .find(|track| resource_reference(track) == track_release.resource_reference)
+                .map(|track| track.title.clone())
+        });
+        if let Some(title) = title {
+            track_release_elem.add_child(Element::new("DisplayTitleText").with_text(&title));
         }
 
-        Ok(release_list)
+        track_release_elem.add_child(
+            Element::new("ReleaseResourceReference").with_text(&track_release.resource_reference),
+        );
+
+        track_release_elem.add_child(Self::related_release(
+            "IsReleaseFromRelease",
+            &release.release_id,
+        ));
+
+        track_release_elem
+    }
+
+    /// A `RelatedRelease` linking to the release with the given GRid
+    fn related_release(relationship_type: &str, release_id: &str) -> Element {
+        let mut related = Element::new("RelatedRelease");
+        related.add_child(Element::new("ReleaseRelationshipType").with_text(relationship_type));
+        let mut related_id = Element::new("ReleaseId");
+        related_id.add_child(Element::new("GRid").with_text(release_id));
+        related.add_child(related_id);
+        related
+    }
+
+    /// `ReleaseVisibility` block for the `index`th release, if it has a
+    /// pre-order date
+    pub(crate) fn generate_release_visibility(
+        &self,
+        release: &ReleaseRequest,
+        index: usize,
+    ) -> Option<Element> {
+        let preorder_date = self.optional(&release.preorder_date)?;
+        let mut visibility = Element::new("ReleaseVisibility");
+        visibility
+            .add_child(Element::new("VisibilityReference").with_text(visibility_reference(index)));
+        visibility.add_child(Element::new("ReleaseDisplayStartDateTime").with_text(preorder_date));
+        Some(visibility)
     }
 
-    #[allow(dead_code)]
+    /// A DealList of the requested deals followed by release visibilities,
+    /// or `None` when there are neither
     fn generate_deal_list(
         &self,
         deals: &[crate::builder::DealRequest],
-    ) -> Result<Element, BuildError> {
+        releases: &[ReleaseRequest],
+    ) -> Option<Element> {
         let mut deal_list = Element::new("DealList");
 
         for deal in deals {
-            let mut deal_elem = Element::new("ReleaseDeal");
+            deal_list.add_child(self.generate_deal(deal));
+        }
 
-            // Add DealReference if present
-            if let Some(ref deal_ref) = deal.deal_reference {
-                deal_elem.add_child(Element::new("DealReference").with_text(deal_ref));
+        for (index, release) in releases.iter().enumerate() {
+            if let Some(visibility) = self.generate_release_visibility(release, index) {
+                deal_list.add_child(visibility);
             }
+        }
 
-            // Add Deal terms (simplified for now)
-            let mut deal_terms = Element::new("Deal");
-            deal_terms.add_child(
-                Element::new("CommercialModelType")
-                    .with_text(&deal.deal_terms.commercial_model_type),
-            );
+        (!deal_list.children.is_empty()).then_some(deal_list)
+    }
 
-            // Add territories
-            for territory in &deal.deal_terms.territory_code {
-                deal_terms.add_child(Element::new("TerritoryCode").with_text(territory));
-            }
+    /// Generate the ReleaseDeal for one deal
+    pub(crate) fn generate_deal(&self, deal: &crate::builder::DealRequest) -> Element {
+        let mut deal_elem = Element::new("ReleaseDeal");
 
-            deal_elem.add_child(deal_terms);
+        for release_ref in &deal.release_references {
+            deal_elem.add_child(Element::new("DealReleaseReference").with_text(release_ref));
+        }
 
-            // Add DealReleaseReferences
-            for release_ref in &deal.release_references {
-                deal_elem.add_child(Element::new("DealReleaseReference").with_text(release_ref));
-            }
+        let mut deal_inner = Element::new("Deal");
+        if let Some(deal_ref) = self.optional(&deal.deal_reference) {
+            deal_inner.add_child(Element::new("DealReference").with_text(deal_ref));
+        }
 
-            deal_list.add_child(deal_elem);
+        let terms = &deal.deal_terms;
+        let mut deal_terms = Element::new("DealTerms");
+        for territory in &terms.territory_code {
+            deal_terms.add_child(Element::new("TerritoryCode").with_text(territory));
+        }
+        if let Some(start_date) = self.optional(&terms.start_date) {
+            let mut validity = Element::new("ValidityPeriod");
+            validity.add_child(Element::new("StartDate").with_text(self.date(start_date)));
+            deal_terms.add_child(validity);
+        }
+        deal_terms.add_child(
+            Element::new("CommercialModelType").with_text(&terms.commercial_model_type),
+        );
+        for use_type in &terms.use_types {
+            deal_terms.add_child(Element::new("UseType").with_text(use_type));
+        }
+        for price in &terms.prices {
+            let mut price_info = Element::new("PriceInformation");
+            price_info.add_child(Element::new("PriceType").with_text(&price.price_type));
+            price_info.add_child(
+                Element::new("WholesalePricePerUnit")
+                    .with_attr("CurrencyCode", &price.currency_code)
+                    .with_text(&price.amount),
+            );
+            deal_terms.add_child(price_info);
         }
 
-        Ok(deal_list)
+        deal_inner.add_child(deal_terms);
+        deal_elem.add_child(deal_inner);
+
+        deal_elem
     }
 }
+
+/// `ReleaseReference` of `release`, derived from its ID when not given
+fn release_reference(release: &ReleaseRequest) -> String {
+    release
+        .release_reference
+        .clone()
+        .unwrap_or_else(|| format!("R{}", release.release_id))
+}
+
+/// `ResourceReference` of `track`, derived from its ID when not given
+fn resource_reference(track: &TrackRequest) -> String {
+    track
+        .resource_reference
+        .clone()
+        .unwrap_or_else(|| format!("A{}", track.track_id))
+}
+
+/// `VisibilityReference` of the release at `index`
+fn visibility_reference(index: usize) -> String {
+    format!("V{}", index)
+}
+
+/// Checksum of a delivered file: as given, or the SHA-256 of the file at
+/// `file_path`
+fn file_hash_sum(
+    hash_sum: &Option<HashSumRequest>,
+    file_path: &Option<String>,
+) -> Result<Option<HashSumRequest>, BuildError> {
+    Ok(match (hash_sum, file_path) {
+        (Some(hash), _) => Some(hash.clone()),
+        (None, Some(path)) => Some(HashSumRequest {
+            algorithm: "SHA256".to_string(),
+            value: sha256_file(path)?,
+        }),
+        (None, None) => None,
+    })
+}
+
+/// The file named by the `TechnicalDetails` of `resource`, if it names one
+fn referenced_file(resource: &Element) -> Option<ReferencedFile> {
+    let file = child(child(resource, "TechnicalDetails")?, "File")?;
+    let hash_sum = child(file, "HashSum").and_then(|hash| {
+        Some(HashSumRequest {
+            algorithm: child_text(hash, "Algorithm")?,
+            value: child_text(hash, "HashSumValue")?,
+        })
+    });
+    Some(ReferencedFile {
+        resource_reference: child_text(resource, "ResourceReference")?,
+        uri: child_text(file, "URI")?,
+        hash_sum,
+    })
+}
+
+/// First child element of `element` named `name`
+fn child<'a>(element: &'a Element, name: &str) -> Option<&'a Element> {
+    element.children.iter().find_map(|node| match node {
+        Node::Element(child) if child.name == name => Some(child),
+        _ => None,
+    })
+}
+
+/// Text of the first child element of `element` named `name`
+fn child_text(element: &Element, name: &str) -> Option<String> {
+    child(element, name)?
+        .children
+        .iter()
+        .find_map(|node| match node {
+            Node::Text(text) => Some(text.clone()),
+            _ => None,
+        })
+}
+
+/// ERN 3.x display `Title`
+fn legacy_title(text: &str) -> Element {
+    let mut title = Element::new("Title").with_attr("TitleType", "DisplayTitle");
+    title.add_child(Element::new("TitleText").with_text(text));
+    title
+}
+
+/// Hex-encoded SHA-256 of the file at `path`
+fn sha256_file(path: &str) -> Result<String, BuildError> {
+    use sha2::{Digest, Sha256};
+
+    let mut file = std::fs::File::open(path)
+        .map_err(|e| BuildError::Io(format!("Cannot hash '{}': {}", path, e)))?;
+    let mut hasher = Sha256::new();
+    std::io::copy(&mut file, &mut hasher)
+        .map_err(|e| BuildError::Io(format!("Cannot hash '{}': {}", path, e)))?;
+    Ok(format!("{:x}", hasher.finalize()))
+}
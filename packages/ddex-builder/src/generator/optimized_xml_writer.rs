@@ -119,13 +119,13 @@ impl<'a> OptimizedXmlWriter<'a> {
                 writer.push_str(" xmlns:");
                 writer.push_str(prefix);
                 writer.push_str("=\"");
-                writer.push_str(uri);
+                self.escape_attribute_into(uri, writer);
                 writer.push('"');
             }
 
             if let Some(location) = schema_location {
                 writer.push_str(" xsi:schemaLocation=\"");
-                writer.push_str(location);
+                self.escape_attribute_into(location, writer);
                 writer.push('"');
             }
         }
@@ -187,7 +187,7 @@ impl<'a> OptimizedXmlWriter<'a> {
                         Node::SimpleComment(comment) => {
                             writer.push_str(&self.get_optimized_indent(depth + 1));
                             writer.push_str("<!-- ");
-                            writer.push_str(comment);
+                            writer.push_str(&super::xml_writer::comment_text(comment));
                             writer.push_str(" -->\n");
                         }
                     }
@@ -1,40 +1,126 @@
 //! XML serialization from AST
+//!
+//! The writer streams straight into a byte buffer pre-sized from the AST.
+//! Names, indentation and escaped text are written as slices, so no string
+//! is allocated per element even for multi-thousand-release messages.
 
 use crate::ast::{Element, Node, AST};
 use crate::determinism::{DeterminismConfig, IndentChar};
 use crate::error::BuildError;
 use ddex_core::models::CommentPosition; // Fixed import
 use indexmap::IndexMap;
-use std::io::Write;
+use std::io::{BufWriter, Write};
+
+/// Capacity of the `BufWriter` used by [`XmlWriter::write_to`]
+const WRITE_BUFFER_SIZE: usize = 64 * 1024;
 
 /// XML Writer for converting AST to XML string
 pub struct XmlWriter {
-    config: DeterminismConfig,
+    self_close_empty: bool,
+    /// One level of indentation
+    indent: String,
 }
 
 impl XmlWriter {
     /// Create a new XML writer
     pub fn new(config: DeterminismConfig) -> Self {
-        Self { config }
+        let indent_char = match config.indent_char {
+            IndentChar::Space => " ",
+            IndentChar::Tab => "\t",
+        };
+        let indent = indent_char.repeat(config.indent_width);
+
+        Self {
+            self_close_empty: true,
+            indent,
+        }
+    }
+
+    /// Whether empty elements are written as `<Name/>` (the default) or as
+    /// `<Name></Name>`
+    pub fn with_self_close_empty(mut self, self_close_empty: bool) -> Self {
+        self.self_close_empty = self_close_empty;
+        self
     }
 
     /// Write AST to XML string
     pub fn write(&self, ast: &AST) -> Result<String, BuildError> {
-        let mut buffer = Vec::new();
+        let mut buffer = Vec::with_capacity(self.estimated_size(ast));
+        self.write_into(ast, &mut buffer)?;
+
+        String::from_utf8(buffer).map_err(|e| BuildError::Serialization(e.to_string()))
+    }
+
+    /// Append the XML for `ast` to `buffer`, so one allocation can be reused
+    /// across builds
+    pub fn write_into(&self, ast: &AST, buffer: &mut Vec<u8>) -> Result<(), BuildError> {
+        buffer.reserve(self.estimated_size(ast));
+        self.write_document(buffer, ast)
+    }
+
+    /// Write the XML for `ast` to `writer` through a `BufWriter`
+    pub fn write_to<W: Write>(&self, ast: &AST, writer: W) -> Result<(), BuildError> {
+        let mut writer = BufWriter::with_capacity(WRITE_BUFFER_SIZE, writer);
+        self.write_document(&mut writer, ast)?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Append `element` to `buffer`, indented as if nested `depth` levels
+    /// deep, for messages written one piece at a time
+    pub fn write_fragment(
+        &self,
+        element: &Element,
+        depth: usize,
+        buffer: &mut Vec<u8>,
+    ) -> Result<(), BuildError> {
+        buffer.reserve(self.element_size(element, depth));
+        self.write_element(buffer, element, &IndexMap::new(), None, depth)
+    }
 
+    /// Rough output size, so the buffer is allocated once
+    pub fn estimated_size(&self, ast: &AST) -> usize {
+        let declarations: usize = ast
+            .namespaces
+            .iter()
+            .map(|(prefix, uri)| prefix.len() + uri.len() + 10)
+            .sum();
+        64 + declarations
+            + ast.schema_location.as_ref().map_or(0, |l| l.len() + 24)
+            + self.element_size(&ast.root, 0)
+    }
+
+    fn element_size(&self, element: &Element, depth: usize) -> usize {
+        let tags = 2 * (depth * self.indent.len() + element.name.len() + 8);
+        let attributes: usize = element
+            .attributes
+            .iter()
+            .map(|(key, value)| key.len() + value.len() + 4)
+            .sum();
+        let children: usize = element
+            .children
+            .iter()
+            .map(|child| match child {
+                Node::Element(child) => self.element_size(child, depth + 1),
+                Node::Text(text) | Node::SimpleComment(text) => text.len() + 16,
+                Node::Comment(comment) => comment.content.len() + 16,
+            })
+            .sum();
+        tags + attributes + children
+    }
+
+    fn write_document(&self, writer: &mut impl Write, ast: &AST) -> Result<(), BuildError> {
         // Write XML declaration
-        writeln!(&mut buffer, "<?xml version=\"1.0\" encoding=\"UTF-8\"?>")?;
+        writer.write_all(b"<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n")?;
 
         // Write root element with namespaces
         self.write_element(
-            &mut buffer,
+            writer,
             &ast.root,
             &ast.namespaces,
             ast.schema_location.as_deref(),
             0,
-        )?;
-
-        Ok(String::from_utf8(buffer).map_err(|e| BuildError::Serialization(e.to_string()))?)
+        )
     }
 
     fn write_element(
@@ -45,110 +131,111 @@ impl XmlWriter {
         schema_location: Option<&str>,
         depth: usize,
     ) -> Result<(), BuildError> {
-        let indent = self.get_indent(depth);
-
-        // Start tag
-        write!(writer, "{}<", indent)?;
-
-        // Add namespace prefix if needed
-        let element_name = if let Some(ns) = &element.namespace {
-            format!("{}:{}", ns, element.name)
-        } else if depth == 0 && !namespaces.is_empty() {
-            // Root element gets default namespace prefix if available
-            if let Some((prefix, _)) = namespaces.first() {
-                format!("{}:{}", prefix, element.name)
-            } else {
-                element.name.clone()
-            }
-        } else {
-            element.name.clone()
+        // Namespace prefix if needed; the root element gets the first
+        // declared prefix
+        let prefix = match &element.namespace {
+            Some(ns) => Some(ns.as_str()),
+            None if depth == 0 => namespaces.first().map(|(prefix, _)| prefix.as_str()),
+            None => None,
         };
 
-        write!(writer, "{}", element_name)?;
+        // Start tag
+        self.write_indent(writer, depth)?;
+        writer.write_all(b"<")?;
+        write_name(writer, prefix, &element.name)?;
 
         // Add namespace declarations on root element
         if depth == 0 {
             for (prefix, uri) in namespaces {
-                write!(writer, " xmlns:{}=\"{}\"", prefix, uri)?;
+                writer.write_all(b" xmlns:")?;
+                writer.write_all(prefix.as_bytes())?;
+                writer.write_all(b"=\"")?;
+                write_escaped(writer, uri, true)?;
+                writer.write_all(b"\"")?;
             }
 
             if let Some(location) = schema_location {
-                write!(writer, " xsi:schemaLocation=\"{}\"", location)?;
+                writer.write_all(b" xsi:schemaLocation=\"")?;
+                write_escaped(writer, location, true)?;
+                writer.write_all(b"\"")?;
             }
         }
 
         // Add attributes (in deterministic order)
         for (key, value) in &element.attributes {
-            write!(writer, " {}=\"{}\"", key, self.escape_attribute(value))?;
+            writer.write_all(b" ")?;
+            writer.write_all(key.as_bytes())?;
+            writer.write_all(b"=\"")?;
+            write_escaped(writer, value, true)?;
+            writer.write_all(b"\"")?;
         }
 
-        // Check if we have children
-        if element.children.is_empty() {
-            writeln!(writer, "/>")?;
-        } else {
-            // Check if we only have text content
-            let only_text =
-                element.children.len() == 1 && matches!(&element.children[0], Node::Text(_));
-
-            if only_text {
-                // Inline text content
-                write!(writer, ">")?;
-                if let Node::Text(text) = &element.children[0] {
-                    write!(writer, "{}", self.escape_text(text))?;
-                }
-                writeln!(writer, "</{}>", element_name)?;
+        // Empty elements: no children, or only empty text
+        let is_empty = element
+            .children
+            .iter()
+            .all(|child| matches!(child, Node::Text(text) if text.is_empty()));
+
+        if is_empty {
+            if self.self_close_empty {
+                writer.write_all(b"/>\n")?;
             } else {
-                // Has child elements
-                writeln!(writer, ">")?;
+                writer.write_all(b"></")?;
+                write_name(writer, prefix, &element.name)?;
+                writer.write_all(b">\n")?;
+            }
+            return Ok(());
+        }
 
-                // Write children
-                for child in &element.children {
+        match element.children.as_slice() {
+            // Inline text content
+            [Node::Text(text)] => {
+                writer.write_all(b">")?;
+                write_escaped(writer, text, false)?;
+            }
+            // Has child elements
+            children => {
+                writer.write_all(b">\n")?;
+
+                for child in children {
                     match child {
                         Node::Element(child_elem) => {
                             self.write_element(writer, child_elem, namespaces, None, depth + 1)?;
                         }
                         Node::Text(text) => {
-                            let child_indent = self.get_indent(depth + 1);
-                            writeln!(writer, "{}{}", child_indent, self.escape_text(text))?;
+                            self.write_indent(writer, depth + 1)?;
+                            write_escaped(writer, text, false)?;
+                            writer.write_all(b"\n")?;
                         }
                         Node::Comment(comment) => {
                             self.write_comment(writer, comment, depth + 1)?;
                         }
                         Node::SimpleComment(comment) => {
-                            let child_indent = self.get_indent(depth + 1);
-                            writeln!(writer, "{}<!-- {} -->", child_indent, comment)?;
+                            self.write_indent(writer, depth + 1)?;
+                            writer.write_all(b"<!-- ")?;
+                            writer.write_all(comment_text(comment).as_bytes())?;
+                            writer.write_all(b" -->\n")?;
                         }
                     }
                 }
 
-                // Close tag
-                writeln!(writer, "{}</{}>", indent, element_name)?;
+                self.write_indent(writer, depth)?;
             }
         }
 
-        Ok(())
-    }
+        // Close tag
+        writer.write_all(b"</")?;
+        write_name(writer, prefix, &element.name)?;
+        writer.write_all(b">\n")?;
 
-    fn get_indent(&self, depth: usize) -> String {
-        let indent_char = match self.config.indent_char {
-            IndentChar::Space => " ", // Fixed: removed super::determinism::
-            IndentChar::Tab => "\t",  // Fixed: removed super::determinism::
-        };
-        indent_char.repeat(depth * self.config.indent_width)
-    }
-
-    fn escape_text(&self, text: &str) -> String {
-        text.replace('&', "&amp;")
-            .replace('<', "&lt;")
-            .replace('>', "&gt;")
+        Ok(())
     }
 
-    fn escape_attribute(&self, text: &str) -> String {
-        text.replace('&', "&amp;")
-            .replace('<', "&lt;")
-            .replace('>', "&gt;")
-            .replace('"', "&quot;")
-            .replace('\'', "&apos;")
+    fn write_indent(&self, writer: &mut impl Write, depth: usize) -> std::io::Result<()> {
+        for _ in 0..depth {
+            writer.write_all(self.indent.as_bytes())?;
+        }
+        Ok(())
     }
 
     /// Write a structured comment with position-aware formatting
@@ -158,28 +245,135 @@ impl XmlWriter {
         comment: &ddex_core::models::Comment,
         depth: usize,
     ) -> Result<(), BuildError> {
-        let indent = match comment.position {
+        match comment.position {
             CommentPosition::Before | CommentPosition::After => {
                 // Comments at element level use element indentation
-                self.get_indent(depth.saturating_sub(1))
+                self.write_indent(writer, depth.saturating_sub(1))?;
             }
             CommentPosition::FirstChild | CommentPosition::LastChild => {
                 // Comments inside elements use child indentation
-                self.get_indent(depth)
+                self.write_indent(writer, depth)?;
             }
             CommentPosition::Inline => {
                 // Inline comments don't get indentation
-                String::new()
             }
-        };
+        }
 
         // Use the comment's XML formatting which handles escaping
-        let comment_xml = comment.to_xml();
-        writeln!(writer, "{}{}", indent, comment_xml)?;
+        writer.write_all(comment.to_xml().as_bytes())?;
+        writer.write_all(b"\n")?;
 
         Ok(())
     }
 }
 
+/// Write `name`, prefixed when a namespace prefix applies
+fn write_name(writer: &mut impl Write, prefix: Option<&str>, name: &str) -> std::io::Result<()> {
+    if let Some(prefix) = prefix {
+        writer.write_all(prefix.as_bytes())?;
+        writer.write_all(b":")?;
+    }
+    writer.write_all(name.as_bytes())
+}
+
+/// Write `text` with `&`, `<` and `>` escaped, plus both quotes in
+/// attribute values, copying unescaped runs in one go
+fn write_escaped(writer: &mut impl Write, text: &str, attribute: bool) -> std::io::Result<()> {
+    let bytes = text.as_bytes();
+    let mut start = 0;
+
+    for (i, byte) in bytes.iter().enumerate() {
+        let entity: &[u8] = match byte {
+            b'&' => b"&amp;",
+            b'<' => b"&lt;",
+            b'>' => b"&gt;",
+            b'"' if attribute => b"&quot;",
+            b'\'' if attribute => b"&apos;",
+            _ => continue,
+        };
+        writer.write_all(&bytes[start..i])?;
+        writer.write_all(entity)?;
+        start = i + 1;
+    }
+
+    writer.write_all(&bytes[start..])
+}
+
+/// Comment text with a space between consecutive dashes, since `--` may
+/// not appear inside a comment
+pub(crate) fn comment_text(text: &str) -> String {
+    let mut safe = String::with_capacity(text.len());
+    for ch in text.chars() {
+        if ch == '-' && safe.ends_with('-') {
+            safe.push(' ');
+        }
+        safe.push(ch);
+    }
+    safe
+}
+
+/// Check that `xml` is a well-formed document: one root element, matching
+/// start and end tags, and only valid entity references in text and
+/// attribute values
+pub fn check_well_formed(xml: &str) -> Result<(), BuildError> {
+    use quick_xml::events::Event;
+
+    let malformed = |position: u64, message: String| {
+        BuildError::XmlGeneration(format!(
+            "Output is not well-formed at byte {}: {}",
+            position, message
+        ))
+    };
+
+    let mut reader = quick_xml::Reader::from_str(xml);
+    reader.config_mut().check_end_names = true;
+    let mut depth = 0usize;
+    let mut roots = 0usize;
+
+    loop {
+        let event = reader
+            .read_event()
+            .map_err(|e| malformed(reader.error_position(), e.to_string()))?;
+        match event {
+            Event::Start(ref e) | Event::Empty(ref e) => {
+                if depth == 0 {
+                    roots += 1;
+                    if roots > 1 {
+                        return Err(malformed(reader.buffer_position(), "more than one root element".to_string()));
+                    }
+                }
+                for attribute in e.attributes() {
+                    attribute
+                        .map_err(|e| e.to_string())
+                        .and_then(|a| a.unescape_value().map(|_| ()).map_err(|e| e.to_string()))
+                        .map_err(|message| malformed(reader.buffer_position(), message))?;
+                }
+                if matches!(event, Event::Start(_)) {
+                    depth += 1;
+                }
+            }
+            Event::End(_) => depth = depth.saturating_sub(1),
+            Event::Text(ref e) => {
+                let text = e
+                    .unescape()
+                    .map_err(|e| malformed(reader.buffer_position(), e.to_string()))?;
+                if depth == 0 && !text.trim().is_empty() {
+                    return Err(malformed(reader.buffer_position(), "text outside the root element".to_string()));
+                }
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    if depth > 0 {
+        return Err(malformed(reader.buffer_position(), format!("{} element(s) left unclosed", depth)));
+    }
+    if roots == 0 {
+        return Err(malformed(0, "no root element".to_string()));
+    }
+    Ok(())
+}
+
 // Removed duplicate From<std::io::Error> implementation
 // (it's already in error.rs)
@@ -132,6 +132,7 @@ pub mod determinism;
 pub mod diff;
 pub mod error;
 pub mod fidelity;
+mod from_parsed;
 pub mod generator;
 pub mod guarantees;
 pub mod id_generator;
@@ -144,6 +145,8 @@ pub mod parallel_processing;
 pub mod preflight;
 pub mod presets;
 pub mod round_trip;
+pub mod rule_engine;
+pub mod scaffold;
 pub mod schema;
 pub mod security;
 pub mod streaming;
@@ -151,7 +154,7 @@ pub mod verification;
 pub mod versions;
 
 // Re-export main types
-pub use builder::{BuildOptions, BuildRequest, BuildResult, DDEXBuilder};
+pub use builder::{BuildOptions, BuildRequest, BuildResult, DDEXBuilder, MessageConfig};
 pub use canonical::DB_C14N;
 pub use determinism::DeterminismConfig;
 pub use diff::formatter::DiffFormatter;
@@ -162,11 +165,16 @@ pub use guarantees::{DeterminismGuarantee, DeterminismGuaranteeValidator, Guaran
 pub use id_generator::{HashAlgorithm, StableHashConfig, StableHashGenerator};
 pub use linker::{EntityType, LinkerConfig, LinkingError, ReferenceLinker};
 pub use messages::{
-    UpdateAction, UpdateConfig, UpdateGenerator, UpdateReleaseMessage, ValidationStatus,
+    AcknowledgementRequest, UpdateAction, UpdateConfig, UpdateGenerator, UpdateReleaseMessage,
+    ValidationStatus,
+};
+pub use preflight::{
+    check_avs, generate_isrcs, normalize_isrc, normalize_upc, AvsCheck, NormalizedIdentifier, PreflightLevel,
+    PreflightValidator, ValidationConfig, ValidationMode, ValidationResult,
 };
-pub use preflight::{PreflightLevel, PreflightValidator, ValidationConfig, ValidationResult};
 pub use presets::DdexVersion;
 pub use presets::PartnerPreset;
+pub use rule_engine::{RuleConfig, RuleEngine};
 pub use schema::{JsonSchema, SchemaCommand, SchemaConfig, SchemaDraft, SchemaGenerator};
 pub use versions::{
     ConversionOptions, ConverterResult as ConversionResult, VersionConverter, VersionManager,
@@ -189,6 +197,20 @@ use std::time::Duration;
 /// Version of the DB-C14N specification
 pub const DB_C14N_VERSION: &str = "1.0";
 
+/// What this build of the builder can produce, from
+/// [`Builder::capabilities`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Capabilities {
+    /// DDEX versions the builder can emit
+    pub versions: Vec<DdexVersion>,
+    /// Root elements of the messages the builder can emit
+    pub message_types: Vec<String>,
+    /// Canonicalization algorithms actually implemented, as `NAME/VERSION`
+    pub canonicalization: Vec<String>,
+    /// Optional Cargo features compiled in
+    pub features: Vec<String>,
+}
+
 /// Perfect Fidelity Engine configuration options
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FidelityOptions {
@@ -851,6 +873,33 @@ impl Builder {
         versions::utils::supported_versions()
     }
 
+    /// Versions, message types, canonicalization algorithms and Cargo
+    /// features this build of the builder supports
+    pub fn capabilities(&self) -> Capabilities {
+        let features = [
+            ("async", cfg!(feature = "async")),
+            ("strict", cfg!(feature = "strict")),
+            ("ffi", cfg!(feature = "ffi")),
+            ("wasm", cfg!(feature = "wasm")),
+        ];
+
+        Capabilities {
+            versions: self.supported_versions(),
+            message_types: vec![
+                "NewReleaseMessage".to_string(),
+                "UpdateReleaseMessage".to_string(),
+                "FtpAcknowledgementMessage".to_string(),
+            ],
+            // C14N and C14N 1.1 are accepted in options but not yet applied
+            canonicalization: vec![format!("DB-C14N/{}", DB_C14N_VERSION)],
+            features: features
+                .into_iter()
+                .filter(|(_, enabled)| *enabled)
+                .map(|(name, _)| name.to_string())
+                .collect(),
+        }
+    }
+
     fn load_default_presets() -> IndexMap<String, PartnerPreset> {
         presets::all_presets()
     }
@@ -863,8 +912,12 @@ impl Builder {
         let start_time = std::time::Instant::now();
         let mut statistics = BuildStatistics::default();
 
-        // Use the existing build options structure
-        let build_options = builder::BuildOptions::default();
+        // Perfect fidelity re-emits values exactly as the request gives them
+        let build_options = builder::BuildOptions {
+            preserve_lexical_forms: self.fidelity_options.enable_perfect_fidelity,
+            collect_statistics: self.fidelity_options.collect_statistics,
+            ..Default::default()
+        };
 
         // Build the XML using existing builder
         let ddex_builder = builder::DDEXBuilder::new();
@@ -872,6 +925,11 @@ impl Builder {
 
         statistics.build_time = start_time.elapsed();
         statistics.output_size_bytes = build_result.xml.len();
+        if let Some(timings) = build_result.statistics.timings {
+            statistics.validation_time = Duration::from_secs_f64(timings.validation_ms / 1000.0);
+            statistics.canonicalization_time =
+                Duration::from_secs_f64(timings.canonicalization_ms / 1000.0);
+        }
 
         // Perform verification if enabled
         let verification_result = if self.fidelity_options.enable_verification {
@@ -1059,7 +1117,10 @@ impl Builder {
         request: &builder::BuildRequest,
     ) -> Result<builder::BuildResult, error::BuildError> {
         let ddex_builder = builder::DDEXBuilder::new();
-        let build_options = builder::BuildOptions::default();
+        let build_options = builder::BuildOptions {
+            preserve_lexical_forms: self.fidelity_options.enable_perfect_fidelity,
+            ..Default::default()
+        };
 
         ddex_builder.build(request.clone(), build_options)
     }
@@ -0,0 +1,72 @@
+//! Acknowledgement messages for DDEX Builder
+//!
+//! Recipients of a delivery reply with a short status message that points back
+//! at the original message. This module builds that reply using the ERN
+//! choreography `FtpAcknowledgementMessage` root, sharing the header generation
+//! used by `NewReleaseMessage`.
+
+use crate::ast::{Element, AST};
+use crate::builder::MessageHeaderRequest;
+use crate::error::BuildError;
+use crate::generator::ASTGenerator;
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+/// Namespace of the ERN choreography messages
+pub const ERN_CHOREOGRAPHY_NAMESPACE: &str = "http://ddex.net/xml/ern-c/15";
+
+/// Request for an acknowledgement of a previously received message
+///
+/// The thread id goes in `header.message_thread_id` so the acknowledgement
+/// joins the conversation started by the original delivery.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AcknowledgementRequest {
+    /// Header of the acknowledgement itself
+    pub header: MessageHeaderRequest,
+    /// MessageId of the message being acknowledged
+    pub referenced_message_id: String,
+    /// Processing status (e.g. "FileOK", "SchemaValidationError")
+    pub status: String,
+}
+
+impl AcknowledgementRequest {
+    /// Generate the AST for this acknowledgement
+    pub fn to_ast(&self) -> Result<AST, BuildError> {
+        if self.referenced_message_id.trim().is_empty() {
+            return Err(BuildError::MissingRequired {
+                field: "referenced_message_id".to_string(),
+            });
+        }
+        if self.status.trim().is_empty() {
+            return Err(BuildError::MissingRequired {
+                field: "status".to_string(),
+            });
+        }
+
+        let mut root = Element::new("FtpAcknowledgementMessage");
+        root.namespace = Some("ernc".to_string());
+        root.attributes
+            .insert("MessageSchemaVersionId".to_string(), "ern-c/15".to_string());
+
+        let generator = ASTGenerator::new("4.3".to_string());
+        root.add_child(generator.generate_message_header(&self.header)?);
+
+        let mut acknowledgement = Element::new("Acknowledgement");
+        acknowledgement.add_child(Element::new("MessageId").with_text(&self.referenced_message_id));
+        acknowledgement.add_child(Element::new("MessageStatus").with_text(&self.status));
+        root.add_child(acknowledgement);
+
+        let mut namespaces = IndexMap::new();
+        namespaces.insert("ernc".to_string(), ERN_CHOREOGRAPHY_NAMESPACE.to_string());
+        namespaces.insert(
+            "xsi".to_string(),
+            "http://www.w3.org/2001/XMLSchema-instance".to_string(),
+        );
+
+        Ok(AST {
+            root,
+            namespaces,
+            schema_location: None,
+        })
+    }
+}
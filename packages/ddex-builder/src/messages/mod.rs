@@ -11,6 +11,7 @@
 //! - **UpdateReleaseMessage**: Used to update existing releases with new
 //!   metadata, resources, or deal information
 //! - **PurgeReleaseMessage**: For removing releases from distribution
+//! - **FtpAcknowledgementMessage**: Status reply referencing a received message
 //!
 //! ## Architecture
 //!
@@ -51,6 +52,8 @@
 //! - Territory and rights validation
 //! - Resource reference integrity checks
 
+pub mod acknowledgement;
 pub mod update_release;
 
+pub use acknowledgement::*;
 pub use update_release::*;
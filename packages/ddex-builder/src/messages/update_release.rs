@@ -896,6 +896,7 @@ impl UpdateGenerator {
                 }],
                 party_id: None,
                 party_reference: None,
+                trading_name: None,
             },
             message_recipient: crate::builder::PartyRequest {
                 party_name: vec![crate::builder::LocalizedStringRequest {
@@ -904,9 +905,11 @@ impl UpdateGenerator {
                 }],
                 party_id: None,
                 party_reference: None,
+                trading_name: None,
             },
             message_control_type: Some("UpdateMessage".to_string()),
             message_created_date_time: Some(metadata.update_created_timestamp.to_rfc3339()),
+            message_thread_id: None,
         }
     }
 
@@ -1196,6 +1199,7 @@ mod tests {
                     }],
                     party_id: None,
                     party_reference: None,
+                    trading_name: None,
                 },
                 message_recipient: crate::builder::PartyRequest {
                     party_name: vec![crate::builder::LocalizedStringRequest {
@@ -1204,9 +1208,11 @@ mod tests {
                     }],
                     party_id: None,
                     party_reference: None,
+                    trading_name: None,
                 },
                 message_control_type: None,
                 message_created_date_time: None,
+                message_thread_id: None,
             },
             update_list: vec![operation.clone()],
             resource_updates: IndexMap::new(),
@@ -439,6 +439,7 @@ fn create_test_header() -> MessageHeaderRequest {
             }],
             party_id: None,
             party_reference: None,
+            trading_name: None,
         },
         message_recipient: crate::builder::PartyRequest {
             party_name: vec![crate::builder::LocalizedStringRequest {
@@ -447,9 +448,11 @@ fn create_test_header() -> MessageHeaderRequest {
             }],
             party_id: None,
             party_reference: None,
+            trading_name: None,
         },
         message_control_type: Some("UpdateMessage".to_string()),
         message_created_date_time: Some(chrono::Utc::now().to_rfc3339()),
+        message_thread_id: None,
     }
 }
 
@@ -597,6 +597,7 @@ mod tests {
                     }],
                     party_id: Some("SENDER_001".to_string()),
                     party_reference: None,
+                    trading_name: None,
                 },
                 message_recipient: PartyRequest {
                     party_name: vec![LocalizedStringRequest {
@@ -605,9 +606,11 @@ mod tests {
                     }],
                     party_id: Some("RECIPIENT_001".to_string()),
                     party_reference: None,
+                    trading_name: None,
                 },
                 message_control_type: None,
                 message_created_date_time: None,
+                message_thread_id: None,
             },
             version: "4.3".to_string(),
             profile: None,
@@ -631,8 +634,13 @@ mod tests {
             resource_reference: Some("A001".to_string()),
             isrc: "USRC17607839".to_string(), // 12 chars
             title: "Test Track".to_string(),
+            subtitle: None,
             duration: "PT3M30S".to_string(),
             artist: "Test Artist".to_string(),
+            file_path: None,
+            hash_sum: None,
+            preview: None,
+            contributors: vec![],
         };
 
         let result = processor.validate_track(&valid_track);
@@ -643,8 +651,13 @@ mod tests {
             resource_reference: None,
             isrc: "INVALID".to_string(),  // Too short
             title: "".to_string(),        // Empty
+            subtitle: None,
             duration: "3:30".to_string(), // Wrong format
             artist: "Test Artist".to_string(),
+            file_path: None,
+            hash_sum: None,
+            preview: None,
+            contributors: vec![],
         };
 
         let result = processor.validate_track(&invalid_track);
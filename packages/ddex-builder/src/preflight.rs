@@ -9,6 +9,9 @@ use serde::{Deserialize, Serialize};
 static ISRC_PATTERN: Lazy<Regex> =
     Lazy::new(|| Regex::new(r"^[A-Z]{2}[A-Z0-9]{3}\d{2}\d{5}$").unwrap());
 
+static ISRC_PREFIX_PATTERN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^[A-Z]{2}[A-Z0-9]{3}$").unwrap());
+
 static UPC_PATTERN: Lazy<Regex> = Lazy::new(|| Regex::new(r"^\d{12,14}$").unwrap());
 
 #[allow(dead_code)]
@@ -45,6 +48,10 @@ pub struct ValidationConfig {
 
     /// Profile-specific validation
     pub profile: Option<String>,
+
+    /// Whether to stop at the first error or collect every issue
+    #[serde(default)]
+    pub validation_mode: ValidationMode,
 }
 
 impl Default for ValidationConfig {
@@ -57,6 +64,7 @@ impl Default for ValidationConfig {
             validate_dates: true,
             validate_references: true,
             profile: None,
+            validation_mode: ValidationMode::default(),
         }
     }
 }
@@ -72,6 +80,17 @@ pub enum PreflightLevel {
     None,
 }
 
+/// How many validation errors to report before giving up
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ValidationMode {
+    /// Stop at the first error and report only that one
+    FailFast,
+    /// Run every check and report all errors and warnings
+    #[default]
+    CollectAll,
+}
+
 /// Result of preflight validation
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ValidationResult {
@@ -144,31 +163,58 @@ impl PreflightValidator {
             return Ok(result);
         }
 
+        self.run_checks(request, &mut result)?;
+
+        if self.config.validation_mode == ValidationMode::FailFast {
+            result.errors.truncate(1);
+        }
+
+        // Determine if validation passed
+        result.passed = result.errors.is_empty()
+            && (self.config.level != PreflightLevel::Strict || result.warnings.is_empty());
+
+        Ok(result)
+    }
+
+    fn run_checks(
+        &self,
+        request: &super::builder::BuildRequest,
+        result: &mut ValidationResult,
+    ) -> Result<(), super::error::BuildError> {
         // Validate releases
         for (idx, release) in request.releases.iter().enumerate() {
-            self.validate_release(release, idx, &mut result)?;
+            self.validate_release(release, idx, result)?;
+            if self.should_stop(result) {
+                return Ok(());
+            }
         }
 
         // Validate deals
         for (idx, deal) in request.deals.iter().enumerate() {
-            self.validate_deal(deal, idx, &mut result)?;
+            self.validate_deal(deal, idx, result)?;
+            if self.should_stop(result) {
+                return Ok(());
+            }
         }
 
         // Check cross-references if enabled
         if self.config.validate_references {
-            self.validate_references(request, &mut result)?;
+            self.validate_references(request, result)?;
+            if self.should_stop(result) {
+                return Ok(());
+            }
         }
 
         // Apply profile-specific validation
         if let Some(profile) = &self.config.profile {
-            self.validate_profile(request, profile, &mut result)?;
+            self.validate_profile(request, profile, result)?;
         }
 
-        // Determine if validation passed
-        result.passed = result.errors.is_empty()
-            && (self.config.level != PreflightLevel::Strict || result.warnings.is_empty());
+        Ok(())
+    }
 
-        Ok(result)
+    fn should_stop(&self, result: &ValidationResult) -> bool {
+        self.config.validation_mode == ValidationMode::FailFast && !result.errors.is_empty()
     }
 
     fn validate_release(
@@ -215,6 +261,35 @@ impl PreflightValidator {
             }
         }
 
+        // Validate release dates
+        if self.config.validate_dates {
+            let dates = release
+                .release_date
+                .iter()
+                .map(|date| (format!("{}/release_date", location), date))
+                .chain(
+                    release
+                        .release_dates_by_territory
+                        .iter()
+                        .map(|(territory, date)| {
+                            (
+                                format!("{}/release_dates_by_territory/{}", location, territory),
+                                date,
+                            )
+                        }),
+                );
+            for (date_location, date) in dates {
+                if !is_iso_date(date) {
+                    result.errors.push(ValidationError {
+                        code: "INVALID_DATE".to_string(),
+                        field: "release_date".to_string(),
+                        message: format!("Invalid ISO 8601 release date: {}", date),
+                        location: date_location,
+                    });
+                }
+            }
+        }
+
         // Validate tracks
         for (track_idx, track) in release.tracks.iter().enumerate() {
             self.validate_track(track, idx, track_idx, result)?;
@@ -234,7 +309,14 @@ impl PreflightValidator {
 
         // Validate ISRC
         if self.config.validate_identifiers {
-            if !self.validate_isrc(&track.isrc) {
+            if track.isrc.is_empty() {
+                result.errors.push(ValidationError {
+                    code: "MISSING_ISRC".to_string(),
+                    field: "isrc".to_string(),
+                    message: "Track ISRC is required".to_string(),
+                    location: format!("{}/isrc", location),
+                });
+            } else if !self.validate_isrc(&track.isrc) {
                 result.errors.push(ValidationError {
                     code: "INVALID_ISRC".to_string(),
                     field: "isrc".to_string(),
@@ -255,6 +337,39 @@ impl PreflightValidator {
             });
         }
 
+        // The preview window must lie within the track
+        if let Some(preview) = &track.preview {
+            match (
+                duration_seconds(&preview.start),
+                duration_seconds(&preview.duration),
+            ) {
+                (Some(start), Some(length)) => {
+                    if let Some(total) = duration_seconds(&track.duration) {
+                        if start + length > total {
+                            result.errors.push(ValidationError {
+                                code: "PREVIEW_EXCEEDS_DURATION".to_string(),
+                                field: "preview".to_string(),
+                                message: format!(
+                                    "Preview from {} for {} runs past the end of the track ({})",
+                                    preview.start, preview.duration, track.duration
+                                ),
+                                location: format!("{}/preview", location),
+                            });
+                        }
+                    }
+                }
+                _ => result.errors.push(ValidationError {
+                    code: "INVALID_PREVIEW".to_string(),
+                    field: "preview".to_string(),
+                    message: format!(
+                        "Invalid ISO 8601 preview start or duration: {} / {}",
+                        preview.start, preview.duration
+                    ),
+                    location: format!("{}/preview", location),
+                }),
+            }
+        }
+
         Ok(())
     }
 
@@ -279,6 +394,31 @@ impl PreflightValidator {
             }
         }
 
+        // Validate vocabularies against the DDEX allowed-value sets
+        let commercial_model = &deal.deal_terms.commercial_model_type;
+        if let Some(message) = avs_error(
+            "CommercialModelType",
+            commercial_model,
+            COMMERCIAL_MODEL_TYPES,
+        ) {
+            result.errors.push(ValidationError {
+                code: "INVALID_COMMERCIAL_MODEL_TYPE".to_string(),
+                field: "commercial_model_type".to_string(),
+                message,
+                location: format!("{}/commercial_model_type", location),
+            });
+        }
+        for (u_idx, use_type) in deal.deal_terms.use_types.iter().enumerate() {
+            if let Some(message) = avs_error("UseType", use_type, USE_TYPES) {
+                result.errors.push(ValidationError {
+                    code: "INVALID_USE_TYPE".to_string(),
+                    field: "use_types".to_string(),
+                    message,
+                    location: format!("{}/use_types[{}]", location, u_idx),
+                });
+            }
+        }
+
         Ok(())
     }
 
@@ -295,6 +435,11 @@ impl PreflightValidator {
             if let Some(ref_val) = &release.release_reference {
                 release_refs.insert(ref_val.clone());
             }
+            for track_release in &release.track_releases {
+                if let Some(ref_val) = &track_release.release_reference {
+                    release_refs.insert(ref_val.clone());
+                }
+            }
 
             for track in &release.tracks {
                 if let Some(ref_val) = &track.resource_reference {
@@ -303,6 +448,63 @@ impl PreflightValidator {
             }
         }
 
+        // Check for resources listed twice in one release; sharing a resource
+        // across releases is fine
+        for (idx, release) in request.releases.iter().enumerate() {
+            let references: Vec<&String> = match &release.resource_references {
+                Some(refs) => refs.iter().collect(),
+                None => release
+                    .tracks
+                    .iter()
+                    .filter_map(|t| t.resource_reference.as_ref())
+                    .collect(),
+            };
+
+            let mut seen = indexmap::IndexSet::new();
+            let mut reported = indexmap::IndexSet::new();
+            for (r_idx, resource_ref) in references.into_iter().enumerate() {
+                if !seen.insert(resource_ref) && reported.insert(resource_ref) {
+                    result.warnings.push(ValidationWarning {
+                        code: "DUPLICATE_RESOURCE_REFERENCE".to_string(),
+                        field: "resource_references".to_string(),
+                        message: format!(
+                            "Resource {} is referenced more than once in this release",
+                            resource_ref
+                        ),
+                        location: format!("/releases[{}]/resource_references[{}]", idx, r_idx),
+                        suggestion: Some("Remove the repeated reference".to_string()),
+                    });
+                }
+            }
+        }
+
+        // A track release must offer one of its main release's tracks
+        for (idx, release) in request.releases.iter().enumerate() {
+            for (t_idx, track_release) in release.track_releases.iter().enumerate() {
+                let is_track = release.tracks.iter().any(|track| {
+                    track
+                        .resource_reference
+                        .clone()
+                        .unwrap_or_else(|| format!("A{}", track.track_id))
+                        == track_release.resource_reference
+                });
+                if !is_track {
+                    result.errors.push(ValidationError {
+                        code: "UNKNOWN_REFERENCE".to_string(),
+                        field: "resource_reference".to_string(),
+                        message: format!(
+                            "Track release resource {} is not a track of this release",
+                            track_release.resource_reference
+                        ),
+                        location: format!(
+                            "/releases[{}]/track_releases[{}]/resource_reference",
+                            idx, t_idx
+                        ),
+                    });
+                }
+            }
+        }
+
         // Check deal references
         for (idx, deal) in request.deals.iter().enumerate() {
             for (r_idx, release_ref) in deal.release_references.iter().enumerate() {
@@ -401,45 +603,414 @@ impl PreflightValidator {
 
     // Identifier validation methods
     fn validate_isrc(&self, isrc: &str) -> bool {
-        ISRC_PATTERN.is_match(isrc)
+        is_valid_isrc(isrc)
     }
 
     fn validate_upc(&self, upc: &str) -> bool {
-        if !UPC_PATTERN.is_match(upc) {
-            return false;
+        is_valid_upc(upc)
+    }
+
+    fn validate_duration(&self, duration: &str) -> bool {
+        duration_seconds(duration).is_some()
+    }
+
+    fn validate_territory_code(&self, code: &str) -> bool {
+        // Basic ISO 3166-1 alpha-2 validation, plus DDEX's "Worldwide"
+        code == "Worldwide" || (code.len() == 2 && code.chars().all(|c| c.is_ascii_uppercase()))
+    }
+}
+
+/// `CommercialModelType` values allowed by the DDEX allowed-value sets
+pub const COMMERCIAL_MODEL_TYPES: &[&str] = &[
+    "AdvertisementSupportedModel",
+    "AsPerContract",
+    "DeviceFeeModel",
+    "FreeOfChargeModel",
+    "PayAsYouGoModel",
+    "PerformanceRoyaltiesModel",
+    "RightsClaimModel",
+    "SubscriptionModel",
+    "Unknown",
+    "UserDefined",
+];
+
+/// `UseType` values allowed by the DDEX allowed-value sets
+pub const USE_TYPES: &[&str] = &[
+    "AsPerContract",
+    "Broadcast",
+    "Cable",
+    "ConditionalDownload",
+    "ContentInfluencedStream",
+    "Display",
+    "Download",
+    "Dub",
+    "DubForOnDemandStreaming",
+    "ExtractForInternet",
+    "KioskDownload",
+    "NonInteractiveStream",
+    "OnDemandStream",
+    "PayPerView",
+    "Perform",
+    "PermanentDownload",
+    "Podcast",
+    "Print",
+    "RingbackTone",
+    "Ringtone",
+    "Simulcast",
+    "Stream",
+    "TetheredDownload",
+    "TimeInteractiveStream",
+    "Unknown",
+    "UseAsAlertTone",
+    "UseAsDevicePersonalization",
+    "UserDefined",
+    "UserMakeAvailableLabelProvided",
+    "UserMakeAvailableUserProvided",
+    "Webcast",
+];
+
+/// How a value compares with an allowed-value set
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AvsCheck {
+    /// The value is in the set
+    Valid,
+    /// The value is not in the set but closely resembles this one
+    NearMiss(&'static str),
+    /// The value is not in the set and resembles nothing in it
+    Invalid,
+}
+
+/// Check `value` against `allowed`, suggesting the closest allowed value
+/// when it differs only in case, by a missing suffix such as `Model`, or by
+/// a couple of typos
+pub fn check_avs(value: &str, allowed: &[&'static str]) -> AvsCheck {
+    if allowed.contains(&value) {
+        return AvsCheck::Valid;
+    }
+
+    let lower = value.trim().to_ascii_lowercase();
+    if lower.is_empty() {
+        return AvsCheck::Invalid;
+    }
+    allowed
+        .iter()
+        .map(|candidate| {
+            let candidate_lower = candidate.to_ascii_lowercase();
+            let distance = if candidate_lower.starts_with(&lower) && lower.len() >= 4 {
+                0
+            } else {
+                edit_distance(&lower, &candidate_lower)
+            };
+            (distance, *candidate)
+        })
+        .filter(|(distance, _)| *distance <= 2)
+        .min_by_key(|(distance, _)| *distance)
+        .map_or(AvsCheck::Invalid, |(_, candidate)| {
+            AvsCheck::NearMiss(candidate)
+        })
+}
+
+/// Error message for a value outside its allowed-value set, if it is
+fn avs_error(element: &str, value: &str, allowed: &[&'static str]) -> Option<String> {
+    match check_avs(value, allowed) {
+        AvsCheck::Valid => None,
+        AvsCheck::NearMiss(suggestion) => Some(format!(
+            "Invalid {} '{}'; did you mean '{}'?",
+            element, value, suggestion
+        )),
+        AvsCheck::Invalid => Some(format!(
+            "Invalid {} '{}'; not in the DDEX allowed-value set",
+            element, value
+        )),
+    }
+}
+
+/// Levenshtein distance between two strings
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
         }
+        previous = current;
+    }
 
-        // Validate check digit
-        self.validate_upc_checksum(upc)
+    previous[b.len()]
+}
+
+/// Whether an ISRC is well formed, as checked before building
+pub fn is_valid_isrc(isrc: &str) -> bool {
+    ISRC_PATTERN.is_match(isrc)
+}
+
+/// Whether a UPC is 12 to 14 digits with a matching check digit, as checked
+/// before building
+pub fn is_valid_upc(upc: &str) -> bool {
+    if !UPC_PATTERN.is_match(upc) {
+        return false;
     }
 
-    fn validate_upc_checksum(&self, upc: &str) -> bool {
-        let digits: Vec<u32> = upc.chars().filter_map(|c| c.to_digit(10)).collect();
+    // Validate check digit
+    validate_upc_checksum(upc)
+}
+
+fn validate_upc_checksum(upc: &str) -> bool {
+    let digits: Vec<u32> = upc.chars().filter_map(|c| c.to_digit(10)).collect();
+
+    if digits.len() < 12 {
+        return false;
+    }
 
-        if digits.len() < 12 {
-            return false;
+    let mut sum = 0;
+    for (i, &digit) in digits.iter().take(digits.len() - 1).enumerate() {
+        if i % 2 == 0 {
+            sum += digit;
+        } else {
+            sum += digit * 3;
         }
+    }
+
+    let check_digit = (10 - (sum % 10)) % 10;
+    digits[digits.len() - 1] == check_digit
+}
+
+/// An identifier cleaned up for building, and whether the result is valid
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NormalizedIdentifier {
+    /// Value as given
+    pub original: String,
+    /// Value with separators removed and case or length fixed
+    pub normalized: String,
+    /// Whether `normalized` passes the same check as preflight
+    pub valid: bool,
+}
+
+/// Uppercase an ISRC and drop the hyphens, spaces and dots it is often
+/// written with, e.g. `us-abc-24-00001` becomes `USABC2400001`
+pub fn normalize_isrc(isrc: &str) -> NormalizedIdentifier {
+    let normalized: String = isrc
+        .chars()
+        .filter(|c| !matches!(c, '-' | '.') && !c.is_whitespace())
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+
+    NormalizedIdentifier {
+        original: isrc.to_string(),
+        valid: is_valid_isrc(&normalized),
+        normalized,
+    }
+}
 
-        let mut sum = 0;
-        for (i, &digit) in digits.iter().take(digits.len() - 1).enumerate() {
-            if i % 2 == 0 {
-                sum += digit;
+/// Drop the hyphens and spaces of a UPC and restore the leading zeros that
+/// spreadsheets strip, padding it back to 12 digits
+pub fn normalize_upc(upc: &str) -> NormalizedIdentifier {
+    let stripped: String = upc
+        .chars()
+        .filter(|c| *c != '-' && !c.is_whitespace())
+        .collect();
+    let normalized = if stripped.chars().all(|c| c.is_ascii_digit()) && !stripped.is_empty() {
+        format!("{:0>12}", stripped)
+    } else {
+        stripped
+    };
+
+    NormalizedIdentifier {
+        original: upc.to_string(),
+        valid: is_valid_upc(&normalized),
+        normalized,
+    }
+}
+
+/// `count` sequential ISRCs for registrant `prefix` (country code and
+/// registrant code, e.g. `US-ABC`) and `year`, numbered from `00001`.
+/// ISRCs are returned without separators, as the build expects them.
+pub fn generate_isrcs(
+    prefix: &str,
+    count: u32,
+    year: u32,
+) -> Result<Vec<String>, super::error::BuildError> {
+    let prefix: String = prefix
+        .chars()
+        .filter(|c| !matches!(c, '-' | '.') && !c.is_whitespace())
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+    if !ISRC_PREFIX_PATTERN.is_match(&prefix) {
+        return Err(super::error::BuildError::InvalidFormat {
+            field: "prefix".to_string(),
+            message: format!(
+                "'{}' is not a two-letter country code followed by a three-character registrant code",
+                prefix
+            ),
+        });
+    }
+    if count > 99_999 {
+        return Err(super::error::BuildError::InvalidFormat {
+            field: "count".to_string(),
+            message: format!("{} exceeds the 99999 designation codes in a year", count),
+        });
+    }
+
+    (1..=count)
+        .map(|designation| {
+            let isrc = format!("{}{:02}{:05}", prefix, year % 100, designation);
+            if is_valid_isrc(&isrc) {
+                Ok(isrc)
             } else {
-                sum += digit * 3;
+                Err(super::error::BuildError::InvalidFormat {
+                    field: "isrc".to_string(),
+                    message: format!("Generated ISRC '{}' is malformed", isrc),
+                })
             }
-        }
+        })
+        .collect()
+}
 
-        let check_digit = (10 - (sum % 10)) % 10;
-        digits[digits.len() - 1] == check_digit
+/// Whether `value` is an ISO 8601 date as DDEX writes them: `2024-03-15`,
+/// reduced to `2024-03` or `2024`, or an xs:dateTime such as
+/// `2024-03-15T10:00:00Z`
+fn is_iso_date(value: &str) -> bool {
+    if value.contains('T') {
+        return chrono::DateTime::parse_from_rfc3339(value).is_ok()
+            || chrono::NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f").is_ok();
     }
+    let padded = match value.len() {
+        4 => format!("{}-01-01", value),
+        7 => format!("{}-01", value),
+        _ => value.to_string(),
+    };
+    chrono::NaiveDate::parse_from_str(&padded, "%Y-%m-%d").is_ok()
+}
 
-    fn validate_duration(&self, duration: &str) -> bool {
-        // Basic ISO 8601 duration validation
-        duration.starts_with("PT") && (duration.contains('M') || duration.contains('S'))
+/// Seconds in an ISO 8601 time duration such as `PT3M45S`
+pub(crate) fn duration_seconds(duration: &str) -> Option<f64> {
+    let mut seconds = 0.0;
+    let mut number = String::new();
+    for ch in duration.strip_prefix("PT")?.chars() {
+        match ch {
+            '0'..='9' | '.' => number.push(ch),
+            'H' | 'M' | 'S' => {
+                let unit = match ch {
+                    'H' => 3600.0,
+                    'M' => 60.0,
+                    _ => 1.0,
+                };
+                seconds += number.parse::<f64>().ok()? * unit;
+                number.clear();
+            }
+            _ => return None,
+        }
     }
+    number.is_empty().then_some(seconds)
+}
 
-    fn validate_territory_code(&self, code: &str) -> bool {
-        // Basic ISO 3166-1 alpha-2 validation
-        code.len() == 2 && code.chars().all(|c| c.is_ascii_uppercase())
+/// Releases that no deal in the request points at. Such a release is on
+/// sale nowhere, which is usually a mistake, but metadata-only deliveries do
+/// it on purpose, so these warnings are kept out of the validator and never
+/// fail a build, strict or not
+pub fn releases_without_deals(request: &super::builder::BuildRequest) -> Vec<ValidationWarning> {
+    let dealt: indexmap::IndexSet<&str> = request
+        .deals
+        .iter()
+        .flat_map(|deal| deal.release_references.iter().map(String::as_str))
+        .collect();
+
+    request
+        .releases
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, release)| {
+            let reference = release
+                .release_reference
+                .clone()
+                .unwrap_or_else(|| format!("R{}", release.release_id));
+            (!dealt.contains(reference.as_str())).then(|| ValidationWarning {
+                code: "NO_DEAL".to_string(),
+                field: "release_reference".to_string(),
+                message: format!("Release {} is not referenced by any deal", reference),
+                location: format!("/releases[{}]", idx),
+                suggestion: Some(
+                    "Add a deal for the release, unless the delivery is metadata-only".to_string(),
+                ),
+            })
+        })
+        .collect()
+}
+
+/// Elements whose `PartyReference` child names the party itself rather than
+/// referring to another one
+const DEFINING_PARENTS: &[&str] = &[
+    "Party",
+    "MessageSender",
+    "MessageRecipient",
+    "SentOnBehalfOf",
+];
+
+/// Check a generated document before it is written: every `PartyReference`,
+/// `ArtistPartyReference`, `RightsControllerPartyReference` and
+/// `ReleaseLabelReference` must name a party defined in its `PartyList`
+pub fn unresolved_party_references(root: &crate::ast::Element) -> Vec<ValidationError> {
+    let mut defined = indexmap::IndexSet::new();
+    let mut references = Vec::new();
+    collect_party_references(root, None, &root.name, &mut defined, &mut references);
+
+    references
+        .into_iter()
+        .filter(|(_, reference, _)| !defined.contains(reference))
+        .map(|(element, reference, location)| ValidationError {
+            code: "UNRESOLVED_PARTY_REFERENCE".to_string(),
+            message: format!(
+                "{} '{}' names no party in the PartyList",
+                element, reference
+            ),
+            field: element,
+            location,
+        })
+        .collect()
+}
+
+fn collect_party_references(
+    element: &crate::ast::Element,
+    parent: Option<&str>,
+    path: &str,
+    defined: &mut indexmap::IndexSet<String>,
+    references: &mut Vec<(String, String, String)>,
+) {
+    let name = element.name.as_str();
+    if matches!(
+        name,
+        "PartyReference"
+            | "ArtistPartyReference"
+            | "RightsControllerPartyReference"
+            | "ReleaseLabelReference"
+    ) {
+        let text: String = element
+            .children
+            .iter()
+            .filter_map(|child| match child {
+                crate::ast::Node::Text(text) => Some(text.as_str()),
+                _ => None,
+            })
+            .collect();
+        let text = text.trim().to_string();
+        match (name, parent) {
+            _ if text.is_empty() => {}
+            ("PartyReference", Some("Party")) => {
+                defined.insert(text);
+            }
+            ("PartyReference", Some(parent)) if DEFINING_PARENTS.contains(&parent) => {}
+            _ => references.push((name.to_string(), text, path.to_string())),
+        }
+        return;
+    }
+
+    for child in &element.children {
+        if let crate::ast::Node::Element(child) = child {
+            let child_path = format!("{}/{}", path, child.name);
+            collect_party_references(child, Some(name), &child_path, defined, references);
+        }
     }
 }
@@ -115,6 +115,7 @@ impl CustomPresetBuilder {
             config,
             validation_rules,
             custom_mappings: IndexMap::new(),
+            legacy_elements: Vec::new(),
         };
 
         Self { preset }
@@ -189,6 +190,14 @@ impl CustomPresetBuilder {
         self
     }
     
+    /// Emit a deprecated element the partner still expects
+    pub fn add_legacy_element(&mut self, element: crate::builder::LegacyElement) -> &mut Self {
+        if !self.preset.legacy_elements.contains(&element) {
+            self.preset.legacy_elements.push(element);
+        }
+        self
+    }
+
     /// Lock the preset to prevent modifications
     pub fn lock(&mut self) -> &mut Self {
         self.preset.locked = true;
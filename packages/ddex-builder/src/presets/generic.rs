@@ -78,6 +78,7 @@ pub fn audio_album() -> PartnerPreset {
         config,
         validation_rules,
         custom_mappings: IndexMap::new(),
+        legacy_elements: Vec::new(),
     }
 }
 
@@ -142,6 +143,7 @@ pub fn audio_single() -> PartnerPreset {
         config,
         validation_rules,
         custom_mappings: IndexMap::new(),
+        legacy_elements: Vec::new(),
     }
 }
 
@@ -216,6 +218,7 @@ pub fn video_single() -> PartnerPreset {
         config,
         validation_rules,
         custom_mappings,
+        legacy_elements: Vec::new(),
     }
 }
 
@@ -102,6 +102,7 @@
 //! - **OneOf**: Value must be from allowed list
 //! - **AudioQuality**: Minimum bit depth and sample rate
 //! - **TerritoryCode**: Allowed distribution territories
+//! - **Dependency**: Requirements conditional on another field
 //! - **Custom**: Partner-specific validation logic
 
 pub mod generic;
@@ -178,6 +179,14 @@ pub enum ValidationRule {
         /// List of allowed territory codes
         allowed: Vec<String>,
     },
+    /// Requirement that holds depending on another field, such as a video
+    /// resource whenever the release type is a video one
+    Dependency {
+        /// Field the condition looks at
+        depends_on: String,
+        /// How this field depends on it
+        condition: ddex_core::models::DependencyCondition,
+    },
     /// Custom validation rule
     Custom(String),
 }
@@ -237,6 +246,10 @@ pub struct PartnerPreset {
     pub validation_rules: IndexMap<String, ValidationRule>,
     /// Custom field mappings for partner-specific requirements
     pub custom_mappings: IndexMap<String, String>,
+    /// Deprecated elements this partner still expects, emitted by builds
+    /// whose options name none of their own
+    #[serde(default)]
+    pub legacy_elements: Vec<crate::builder::LegacyElement>,
 }
 
 /// Source of preset definition
@@ -97,6 +97,7 @@ pub fn youtube_album() -> PartnerPreset {
         config,
         validation_rules,
         custom_mappings,
+        legacy_elements: Vec::new(),
     }
 }
 
@@ -193,6 +194,7 @@ pub fn youtube_video() -> PartnerPreset {
         config,
         validation_rules,
         custom_mappings,
+        legacy_elements: Vec::new(),
     }
 }
 
@@ -0,0 +1,610 @@
+// packages/ddex-builder/src/rule_engine.rs
+//! Validation rules defined as data
+//!
+//! Each rule names a field, a rule type and a `parameters` map, matching the
+//! `ValidationRule` shape the bindings already expose for presets. Rules are
+//! checked once when the engine is created (unknown types, bad patterns) and
+//! then evaluated against any number of build requests.
+//!
+//! Supported rule types and their parameters:
+//!
+//! | Rule type       | Parameters                              |
+//! |-----------------|-----------------------------------------|
+//! | `Required`      | none                                    |
+//! | `OneOf`         | `options` (comma-separated)             |
+//! | `TerritoryCode` | `allowed` (comma-separated, optional)   |
+//! | `Regex`         | `pattern`                               |
+//! | `Range`         | `min` and/or `max`                      |
+//! | `AudioQuality`  | `min_bit_depth`, `min_sample_rate`      |
+//! | `Dependency`    | `depends_on`, `condition`, `value`      |
+//!
+//! Field names use DDEX element names (`ISRC`, `UPC`, `TerritoryCode`, ...).
+//! Names the request model does not cover are looked up in
+//! `BuildRequest::extensions`, so partner-specific values can be validated too.
+//! `VideoQuality` is also derived from each video's resolution (see
+//! [`VideoRequest::quality`](crate::builder::VideoRequest::quality)), so a
+//! `OneOf` rule on it checks the videos actually delivered.
+//!
+//! A `Dependency` rule ties its field to the `depends_on` field through a
+//! [`DependencyCondition`] named by `condition` (`RequiredWhenExists`,
+//! `RequiredWhenEquals`, `ForbiddenWhenExists`, `MustMatch`, `MustDiffer`);
+//! `RequiredWhenEquals` compares against `value`. For example, a
+//! `VideoResource` rule depending on `ReleaseType` with `RequiredWhenEquals`
+//! and `VideoSingle` demands a video resource on every video single.
+
+use crate::builder::BuildRequest;
+use crate::error::BuildError;
+use crate::preflight::ValidationError;
+use crate::presets::{PartnerPreset, ValidationRule};
+use ddex_core::models::DependencyCondition;
+use indexmap::IndexMap;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+/// A validation rule as written in config
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RuleConfig {
+    /// Field the rule applies to (e.g. "ISRC", "TerritoryCode")
+    pub field_name: String,
+    /// Rule type (see module docs)
+    pub rule_type: String,
+    /// Message reported when the rule fails; a default is generated when empty
+    #[serde(default)]
+    pub message: String,
+    /// Rule-specific parameters
+    #[serde(default)]
+    pub parameters: IndexMap<String, String>,
+}
+
+/// Evaluates a set of config-defined rules against build requests
+#[derive(Debug, Clone)]
+pub struct RuleEngine {
+    rules: Vec<(RuleConfig, Check)>,
+}
+
+#[derive(Debug, Clone)]
+enum Check {
+    Required,
+    OneOf(Vec<String>),
+    TerritoryCode(Option<Vec<String>>),
+    Regex(Regex),
+    Range {
+        min: Option<f64>,
+        max: Option<f64>,
+    },
+    AudioQuality {
+        min_bit_depth: Option<f64>,
+        min_sample_rate: Option<f64>,
+    },
+    Dependency {
+        depends_on: String,
+        condition: DependencyCondition,
+    },
+}
+
+impl RuleEngine {
+    /// Create an engine, rejecting unknown rule types and invalid parameters
+    pub fn new(rules: Vec<RuleConfig>) -> Result<Self, BuildError> {
+        let rules = rules
+            .into_iter()
+            .map(|rule| {
+                let check = Check::from_config(&rule)?;
+                Ok((rule, check))
+            })
+            .collect::<Result<Vec<_>, BuildError>>()?;
+
+        Ok(Self { rules })
+    }
+
+    /// Create an engine from a JSON array of rules
+    pub fn from_json(json: &str) -> Result<Self, BuildError> {
+        let rules: Vec<RuleConfig> =
+            serde_json::from_str(json).map_err(|e| BuildError::InvalidFormat {
+                field: "rules".to_string(),
+                message: e.to_string(),
+            })?;
+        Self::new(rules)
+    }
+
+    /// Create an engine from a preset's validation rules. `Custom` rules name
+    /// partner logic the engine cannot run and are left out.
+    pub fn from_preset(preset: &PartnerPreset) -> Result<Self, BuildError> {
+        let rules = preset
+            .validation_rules
+            .iter()
+            .filter_map(|(field_name, rule)| {
+                let config = |rule_type: &str, parameters: &[(&str, String)]| RuleConfig {
+                    field_name: field_name.clone(),
+                    rule_type: rule_type.to_string(),
+                    message: String::new(),
+                    parameters: parameters
+                        .iter()
+                        .map(|(key, value)| (key.to_string(), value.clone()))
+                        .collect(),
+                };
+                let length = |min: Option<usize>, max: Option<usize>| {
+                    let bound = |n: Option<usize>| n.map(|n| n.to_string()).unwrap_or_default();
+                    config(
+                        "Regex",
+                        &[(
+                            "pattern",
+                            format!("[\\s\\S]{{{},{}}}", min.unwrap_or(0), bound(max)),
+                        )],
+                    )
+                };
+
+                Some(match rule {
+                    ValidationRule::Required => config("Required", &[]),
+                    ValidationRule::MinLength(min) => length(Some(*min), None),
+                    ValidationRule::MaxLength(max) => length(None, Some(*max)),
+                    ValidationRule::Pattern(pattern) => {
+                        config("Regex", &[("pattern", pattern.clone())])
+                    }
+                    ValidationRule::OneOf(options) => {
+                        config("OneOf", &[("options", options.join(","))])
+                    }
+                    ValidationRule::AudioQuality {
+                        min_bit_depth,
+                        min_sample_rate,
+                    } => config(
+                        "AudioQuality",
+                        &[
+                            ("min_bit_depth", min_bit_depth.to_string()),
+                            ("min_sample_rate", min_sample_rate.to_string()),
+                        ],
+                    ),
+                    ValidationRule::TerritoryCode { allowed } => {
+                        config("TerritoryCode", &[("allowed", allowed.join(","))])
+                    }
+                    ValidationRule::Dependency {
+                        depends_on,
+                        condition,
+                    } => {
+                        let mut parameters = vec![
+                            ("depends_on", depends_on.clone()),
+                            ("condition", condition_name(condition).to_string()),
+                        ];
+                        if let DependencyCondition::RequiredWhenEquals(value) = condition {
+                            parameters.push(("value", value.clone()));
+                        }
+                        config("Dependency", &parameters)
+                    }
+                    ValidationRule::Custom(_) => return None,
+                })
+            })
+            .collect();
+
+        Self::new(rules)
+    }
+
+    /// Number of loaded rules
+    pub fn len(&self) -> usize {
+        self.rules.len()
+    }
+
+    /// Whether no rules are loaded
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Evaluate every rule against `request`, returning one error per failing value
+    pub fn evaluate(&self, request: &BuildRequest) -> Vec<ValidationError> {
+        let mut errors = Vec::new();
+
+        for (rule, check) in &self.rules {
+            let failures = match check {
+                Check::AudioQuality {
+                    min_bit_depth,
+                    min_sample_rate,
+                } => {
+                    let mut failures = below_minimum(request, "BitDepth", *min_bit_depth);
+                    failures.extend(below_minimum(request, "SampleRate", *min_sample_rate));
+                    failures
+                }
+                Check::Dependency {
+                    depends_on,
+                    condition,
+                } => dependency_failures(
+                    &field_values(request, &rule.field_name),
+                    depends_on,
+                    &field_values(request, depends_on),
+                    condition,
+                ),
+                _ => {
+                    let values = field_values(request, &rule.field_name);
+                    check.failures(&values)
+                }
+            };
+
+            for (location, detail) in failures {
+                errors.push(ValidationError {
+                    code: format!("RULE_{}", to_screaming_snake(&rule.rule_type)),
+                    field: rule.field_name.clone(),
+                    message: if rule.message.is_empty() {
+                        format!(
+                            "{} failed {} rule: {}",
+                            rule.field_name, rule.rule_type, detail
+                        )
+                    } else {
+                        rule.message.clone()
+                    },
+                    location,
+                });
+            }
+        }
+
+        errors
+    }
+}
+
+impl Check {
+    fn from_config(rule: &RuleConfig) -> Result<Self, BuildError> {
+        let invalid = |message: String| BuildError::InvalidFormat {
+            field: format!("rules.{}", rule.field_name),
+            message,
+        };
+        let list = |key: &str| {
+            rule.parameters.get(key).map(|v| {
+                v.split(',')
+                    .map(|item| item.trim().to_string())
+                    .filter(|item| !item.is_empty())
+                    .collect::<Vec<_>>()
+            })
+        };
+        let number = |key: &str| {
+            rule.parameters
+                .get(key)
+                .map(|v| {
+                    v.trim()
+                        .parse::<f64>()
+                        .map_err(|_| invalid(format!("Parameter '{}' must be a number", key)))
+                })
+                .transpose()
+        };
+
+        match rule.rule_type.as_str() {
+            "Required" => Ok(Check::Required),
+            "OneOf" => list("options")
+                .or_else(|| list("allowed"))
+                .map(Check::OneOf)
+                .ok_or_else(|| invalid("OneOf rule needs an 'options' parameter".to_string())),
+            "TerritoryCode" => Ok(Check::TerritoryCode(list("allowed"))),
+            "Regex" => {
+                let pattern = rule
+                    .parameters
+                    .get("pattern")
+                    .ok_or_else(|| invalid("Regex rule needs a 'pattern' parameter".to_string()))?;
+                // Anchor so the whole value has to match
+                Regex::new(&format!("^(?:{})$", pattern))
+                    .map(Check::Regex)
+                    .map_err(|e| invalid(format!("Invalid pattern: {}", e)))
+            }
+            "Range" => {
+                let (min, max) = (number("min")?, number("max")?);
+                if min.is_none() && max.is_none() {
+                    return Err(invalid("Range rule needs 'min' or 'max'".to_string()));
+                }
+                Ok(Check::Range { min, max })
+            }
+            "AudioQuality" => Ok(Check::AudioQuality {
+                min_bit_depth: number("min_bit_depth")?,
+                min_sample_rate: number("min_sample_rate")?,
+            }),
+            "Dependency" => {
+                let parameter = |key: &str| {
+                    rule.parameters
+                        .get(key)
+                        .map(|v| v.trim().to_string())
+                        .filter(|v| !v.is_empty())
+                        .ok_or_else(|| {
+                            invalid(format!("Dependency rule needs a '{}' parameter", key))
+                        })
+                };
+                let condition = match parameter("condition")?.as_str() {
+                    "RequiredWhenExists" => DependencyCondition::RequiredWhenExists,
+                    "RequiredWhenEquals" => {
+                        DependencyCondition::RequiredWhenEquals(parameter("value")?)
+                    }
+                    "ForbiddenWhenExists" => DependencyCondition::ForbiddenWhenExists,
+                    "MustMatch" => DependencyCondition::MustMatch,
+                    "MustDiffer" => DependencyCondition::MustDiffer,
+                    other => return Err(invalid(format!("Unknown condition '{}'", other))),
+                };
+                Ok(Check::Dependency {
+                    depends_on: parameter("depends_on")?,
+                    condition,
+                })
+            }
+            other => Err(invalid(format!("Unknown rule type '{}'", other))),
+        }
+    }
+
+    /// (location, reason) for each value failing this check
+    fn failures(&self, values: &[(String, String)]) -> Vec<(String, String)> {
+        if let Check::Required = self {
+            if values.is_empty() {
+                return vec![("/".to_string(), "value is missing".to_string())];
+            }
+            return values
+                .iter()
+                .filter(|(_, value)| value.trim().is_empty())
+                .map(|(location, _)| (location.clone(), "value is missing".to_string()))
+                .collect();
+        }
+
+        values
+            .iter()
+            // Only Required cares about absent values
+            .filter(|(_, value)| !value.trim().is_empty())
+            .filter_map(|(location, value)| {
+                self.reject(value.trim())
+                    .map(|reason| (location.clone(), reason))
+            })
+            .collect()
+    }
+
+    fn reject(&self, value: &str) -> Option<String> {
+        match self {
+            Check::Required | Check::AudioQuality { .. } | Check::Dependency { .. } => None,
+            Check::OneOf(options) => (!options.iter().any(|o| o == value))
+                .then(|| format!("'{}' is not one of {}", value, options.join(", "))),
+            Check::TerritoryCode(Some(allowed)) => (!allowed.iter().any(|a| a == value))
+                .then(|| format!("territory '{}' is not allowed", value)),
+            Check::TerritoryCode(None) => {
+                let valid = value == "Worldwide"
+                    || (value.len() == 2 && value.chars().all(|c| c.is_ascii_uppercase()));
+                (!valid).then(|| format!("'{}' is not a territory code", value))
+            }
+            Check::Regex(pattern) => (!pattern.is_match(value))
+                .then(|| format!("'{}' does not match {}", value, pattern.as_str())),
+            Check::Range { min, max } => match value.parse::<f64>() {
+                Ok(n) if min.is_some_and(|m| n < m) || max.is_some_and(|m| n > m) => {
+                    Some(format!("{} is out of range", n))
+                }
+                Ok(_) => None,
+                Err(_) => Some(format!("'{}' is not a number", value)),
+            },
+        }
+    }
+}
+
+/// Parameter spelling of a condition, as `Check::from_config` reads it
+fn condition_name(condition: &DependencyCondition) -> &'static str {
+    match condition {
+        DependencyCondition::RequiredWhenExists => "RequiredWhenExists",
+        DependencyCondition::RequiredWhenEquals(_) => "RequiredWhenEquals",
+        DependencyCondition::ForbiddenWhenExists => "ForbiddenWhenExists",
+        DependencyCondition::MustMatch => "MustMatch",
+        DependencyCondition::MustDiffer => "MustDiffer",
+    }
+}
+
+/// (location, reason) for each place `values` break `condition` on the
+/// `depends_on` field. A missing value is reported where the value that
+/// demanded it was found; matched values are paired in request order.
+fn dependency_failures(
+    values: &[(String, String)],
+    depends_on: &str,
+    dependency_values: &[(String, String)],
+    condition: &DependencyCondition,
+) -> Vec<(String, String)> {
+    let present = |values: &[(String, String)]| -> Vec<(String, String)> {
+        values
+            .iter()
+            .filter(|(_, value)| !value.trim().is_empty())
+            .map(|(location, value)| (location.clone(), value.trim().to_string()))
+            .collect()
+    };
+    let (values, dependency_values) = (present(values), present(dependency_values));
+
+    match condition {
+        DependencyCondition::RequiredWhenExists if values.is_empty() => dependency_values
+            .into_iter()
+            .map(|(location, _)| {
+                (
+                    location,
+                    format!("value is required when {} is set", depends_on),
+                )
+            })
+            .collect(),
+        DependencyCondition::RequiredWhenEquals(expected) if values.is_empty() => dependency_values
+            .into_iter()
+            .filter(|(_, value)| value == expected)
+            .map(|(location, _)| {
+                (
+                    location,
+                    format!("value is required when {} is '{}'", depends_on, expected),
+                )
+            })
+            .collect(),
+        DependencyCondition::ForbiddenWhenExists if !dependency_values.is_empty() => values
+            .into_iter()
+            .map(|(location, _)| {
+                (
+                    location,
+                    format!("value is not allowed when {} is set", depends_on),
+                )
+            })
+            .collect(),
+        DependencyCondition::MustMatch | DependencyCondition::MustDiffer => {
+            let must_match = matches!(condition, DependencyCondition::MustMatch);
+            values
+                .into_iter()
+                .zip(dependency_values)
+                .filter(|((_, value), (_, other))| (value == other) != must_match)
+                .map(|((location, value), (_, other))| {
+                    let relation = if must_match {
+                        "does not match"
+                    } else {
+                        "must differ from"
+                    };
+                    (
+                        location,
+                        format!("'{}' {} {} '{}'", value, relation, depends_on, other),
+                    )
+                })
+                .collect()
+        }
+        _ => Vec::new(),
+    }
+}
+
+fn below_minimum(
+    request: &BuildRequest,
+    field: &str,
+    minimum: Option<f64>,
+) -> Vec<(String, String)> {
+    let Some(minimum) = minimum else {
+        return Vec::new();
+    };
+    Check::Range {
+        min: Some(minimum),
+        max: None,
+    }
+    .failures(&field_values(request, field))
+    .into_iter()
+    .map(|(location, _)| (location, format!("{} is below {}", field, minimum)))
+    .collect()
+}
+
+/// Values for `field` across the request, with their locations.
+/// Optional fields that are unset yield an empty value so `Required` can flag them.
+fn field_values(request: &BuildRequest, field: &str) -> Vec<(String, String)> {
+    let releases = request.releases.iter().enumerate();
+    let tracks = || {
+        request
+            .releases
+            .iter()
+            .enumerate()
+            .flat_map(|(r, release)| {
+                release
+                    .tracks
+                    .iter()
+                    .enumerate()
+                    .map(move |(t, track)| (format!("/releases[{}]/tracks[{}]", r, t), track))
+            })
+    };
+
+    match field {
+        "ISRC" => tracks()
+            .map(|(loc, track)| (format!("{}/isrc", loc), track.isrc.clone()))
+            .collect(),
+        "TrackTitle" => tracks()
+            .map(|(loc, track)| (format!("{}/title", loc), track.title.clone()))
+            .collect(),
+        "Duration" => tracks()
+            .map(|(loc, track)| (format!("{}/duration", loc), track.duration.clone()))
+            .collect(),
+        "UPC" | "ICPN" => releases
+            .map(|(r, release)| {
+                (
+                    format!("/releases[{}]/upc", r),
+                    release.upc.clone().unwrap_or_default(),
+                )
+            })
+            .collect(),
+        "ReleaseDate" => releases
+            .map(|(r, release)| {
+                (
+                    format!("/releases[{}]/release_date", r),
+                    release.release_date.clone().unwrap_or_default(),
+                )
+            })
+            .collect(),
+        "ReleaseTitle" | "AlbumTitle" => releases
+            .map(|(r, release)| {
+                (
+                    format!("/releases[{}]/title", r),
+                    release
+                        .title
+                        .first()
+                        .map(|t| t.text.clone())
+                        .unwrap_or_default(),
+                )
+            })
+            .collect(),
+        "LabelName" => releases
+            .map(|(r, release)| {
+                (
+                    format!("/releases[{}]/label", r),
+                    release.label.clone().unwrap_or_default(),
+                )
+            })
+            .collect(),
+        "ArtistName" => releases
+            .map(|(r, release)| (format!("/releases[{}]/artist", r), release.artist.clone()))
+            .chain(tracks().map(|(loc, track)| (format!("{}/artist", loc), track.artist.clone())))
+            .collect(),
+        "TerritoryCode" => request
+            .deals
+            .iter()
+            .enumerate()
+            .flat_map(|(d, deal)| {
+                deal.deal_terms
+                    .territory_code
+                    .iter()
+                    .enumerate()
+                    .map(move |(i, code)| {
+                        (format!("/deals[{}]/territory_code[{}]", d, i), code.clone())
+                    })
+            })
+            .collect(),
+        "CommercialModelType" => request
+            .deals
+            .iter()
+            .enumerate()
+            .map(|(d, deal)| {
+                (
+                    format!("/deals[{}]/commercial_model_type", d),
+                    deal.deal_terms.commercial_model_type.clone(),
+                )
+            })
+            .collect(),
+        "MessageControlType" => vec![(
+            "/header/message_control_type".to_string(),
+            request
+                .header
+                .message_control_type
+                .clone()
+                .unwrap_or_default(),
+        )],
+        "VideoQuality" => releases
+            .flat_map(|(r, release)| {
+                release
+                    .videos
+                    .iter()
+                    .enumerate()
+                    .filter_map(move |(v, video)| {
+                        let quality = video.quality()?;
+                        Some((
+                            format!("/releases[{}]/videos[{}]", r, v),
+                            quality.to_string(),
+                        ))
+                    })
+            })
+            .chain(extension_values(request, field))
+            .collect(),
+        other => extension_values(request, other),
+    }
+}
+
+/// The value of `field` in the request's extensions, if it has one
+fn extension_values(request: &BuildRequest, field: &str) -> Vec<(String, String)> {
+    request
+        .extensions
+        .as_ref()
+        .and_then(|ext| ext.get(field))
+        .map(|value| vec![(format!("/extensions/{}", field), value.clone())])
+        .unwrap_or_default()
+}
+
+fn to_screaming_snake(rule_type: &str) -> String {
+    let mut out = String::new();
+    for (i, c) in rule_type.chars().enumerate() {
+        if c.is_ascii_uppercase() && i > 0 {
+            out.push('_');
+        }
+        out.push(c.to_ascii_uppercase());
+    }
+    out
+}
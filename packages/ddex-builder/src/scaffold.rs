@@ -0,0 +1,185 @@
+// packages/ddex-builder/src/scaffold.rs
+//! Starting templates for new messages
+//!
+//! A scaffold is a minimal message with one release, one track and one
+//! worldwide deal, every value a `{{Field}}` placeholder named after the
+//! field it stands for. It shows the shape a preset expects; once the
+//! placeholders are replaced with real values it builds and validates like
+//! any other request.
+//!
+//! ```
+//! use ddex_builder::scaffold::scaffold;
+//!
+//! let xml = scaffold("AudioSingle", &["ISRC".to_string(), "Genre".to_string()]).unwrap();
+//! assert!(xml.contains("<ISRC>{{ISRC}}</ISRC>"));
+//! assert!(xml.contains("{{Genre}}"));
+//! ```
+
+use crate::ast::{Element, Node};
+use crate::builder::{
+    BuildRequest, DealRequest, DealTerms, LocalizedStringRequest, MessageHeaderRequest,
+    PartyRequest, ReleaseRequest, TrackRequest,
+};
+use crate::determinism::DeterminismConfig;
+use crate::error::BuildError;
+use crate::generator::{xml_writer::XmlWriter, ASTGenerator};
+use indexmap::IndexMap;
+
+/// Placeholder standing in for `field`, e.g. `{{ISRC}}`
+pub fn placeholder(field: &str) -> String {
+    format!("{{{{{}}}}}", field)
+}
+
+/// Where a required field the request model has no place for goes in the
+/// release: the element holding the placeholder, and its child if nested
+fn release_element(field: &str) -> Option<(&'static str, Option<&'static str>)> {
+    match field {
+        "Genre" => Some(("Genre", Some("GenreText"))),
+        "ExplicitContent" | "ParentalWarningType" => Some(("ParentalWarningType", None)),
+        _ => None,
+    }
+}
+
+/// Fields the request model carries itself; everything else required goes
+/// into `extensions`, where preset rules look for it
+const MODEL_FIELDS: &[&str] = &[
+    "ISRC",
+    "UPC",
+    "ICPN",
+    "ReleaseDate",
+    "ReleaseTitle",
+    "AlbumTitle",
+    "TrackTitle",
+    "ArtistName",
+    "LabelName",
+    "Duration",
+    "TerritoryCode",
+    "CommercialModelType",
+];
+
+/// The request a scaffold is built from: every value a placeholder, and
+/// each of `required_fields` outside the request model in `extensions`
+pub fn scaffold_request(profile: &str, required_fields: &[String]) -> BuildRequest {
+    let party = |role: &str| PartyRequest {
+        party_name: vec![LocalizedStringRequest {
+            text: placeholder(&format!("{}Name", role)),
+            language_code: None,
+        }],
+        party_id: Some(placeholder(&format!("{}PartyId", role))),
+        party_reference: None,
+        trading_name: None,
+    };
+    let release_title = if required_fields.iter().any(|f| f == "AlbumTitle") {
+        "AlbumTitle"
+    } else {
+        "ReleaseTitle"
+    };
+
+    let extensions: IndexMap<String, String> = required_fields
+        .iter()
+        .filter(|field| !MODEL_FIELDS.contains(&field.as_str()))
+        .map(|field| (field.clone(), placeholder(field)))
+        .collect();
+
+    BuildRequest {
+        header: MessageHeaderRequest {
+            message_id: Some(placeholder("MessageId")),
+            message_sender: party("Sender"),
+            message_recipient: party("Recipient"),
+            message_control_type: Some("LiveMessage".to_string()),
+            message_created_date_time: Some(placeholder("MessageCreatedDateTime")),
+            message_thread_id: None,
+        },
+        version: "4.3".to_string(),
+        profile: Some(profile.to_string()),
+        releases: vec![ReleaseRequest {
+            release_id: placeholder("ReleaseId"),
+            release_reference: Some("R1".to_string()),
+            title: vec![LocalizedStringRequest {
+                text: placeholder(release_title),
+                language_code: None,
+            }],
+            display_title: vec![],
+            subtitle: None,
+            artist: placeholder("ArtistName"),
+            label: Some(placeholder("LabelName")),
+            release_date: Some(placeholder("ReleaseDate")),
+            preorder_date: None,
+            upc: Some(placeholder("UPC")),
+            tracks: vec![TrackRequest {
+                track_id: "T1".to_string(),
+                resource_reference: Some("A1".to_string()),
+                isrc: placeholder("ISRC"),
+                title: placeholder("TrackTitle"),
+                subtitle: None,
+                duration: placeholder("Duration"),
+                artist: placeholder("ArtistName"),
+                file_path: None,
+                hash_sum: None,
+                preview: None,
+                contributors: vec![],
+            }],
+            images: vec![],
+            videos: vec![],
+            resource_references: None,
+            track_releases: vec![],
+            component_release_references: vec![],
+            release_dates_by_territory: Default::default(),
+            display_artists: Default::default(),
+            resource_groups: Default::default(),
+        }],
+        deals: vec![DealRequest {
+            deal_reference: Some("D1".to_string()),
+            deal_terms: DealTerms {
+                commercial_model_type: placeholder("CommercialModelType"),
+                territory_code: vec!["Worldwide".to_string()],
+                start_date: None,
+                use_types: vec![placeholder("UseType")],
+                prices: vec![],
+            },
+            release_references: vec!["R1".to_string()],
+        }],
+        extensions: (!extensions.is_empty()).then_some(extensions),
+    }
+}
+
+/// Render the scaffold for a profile and its required fields. Required
+/// fields the request model lacks get their own release element where DDEX
+/// has one (`Genre`, `ParentalWarningType`) and a comment otherwise.
+pub fn scaffold(profile: &str, required_fields: &[String]) -> Result<String, BuildError> {
+    let request = scaffold_request(profile, required_fields);
+    let mut ast = ASTGenerator::new(request.version.clone()).generate(&request)?;
+
+    let release = ast
+        .root
+        .children
+        .iter_mut()
+        .filter_map(|node| match node {
+            Node::Element(element) if element.name == "ReleaseList" => Some(element),
+            _ => None,
+        })
+        .flat_map(|list| list.children.iter_mut())
+        .find_map(|node| match node {
+            Node::Element(element) if element.name == "Release" => Some(element),
+            _ => None,
+        })
+        .ok_or_else(|| BuildError::InvalidFormat {
+            field: "ReleaseList".to_string(),
+            message: "Scaffold has no release".to_string(),
+        })?;
+
+    for field in required_fields.iter().filter(|f| !MODEL_FIELDS.contains(&f.as_str())) {
+        let node = match release_element(field) {
+            Some((name, Some(child))) => {
+                let mut element = Element::new(name);
+                element.add_child(Element::new(child).with_text(placeholder(field)));
+                Node::Element(element)
+            }
+            Some((name, None)) => Node::Element(Element::new(name).with_text(placeholder(field))),
+            None => Node::SimpleComment(format!(" {}: {} ", field, placeholder(field))),
+        };
+        release.children.push(node);
+    }
+
+    XmlWriter::new(DeterminismConfig::default()).write(&ast)
+}
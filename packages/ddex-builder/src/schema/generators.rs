@@ -661,6 +661,34 @@ impl SchemaGenerator {
                             ..Default::default()
                         },
                     );
+                    props.insert(
+                        "use_types".to_string(),
+                        JsonSchema {
+                            schema_type: Some("array".to_string()),
+                            items: Some(Box::new(JsonSchema {
+                                schema_type: Some("string".to_string()),
+                                ..Default::default()
+                            })),
+                            description: Some(
+                                "Permitted uses, e.g. Stream or ConditionalDownload".to_string(),
+                            ),
+                            ..Default::default()
+                        },
+                    );
+                    props.insert(
+                        "prices".to_string(),
+                        JsonSchema {
+                            schema_type: Some("array".to_string()),
+                            items: Some(Box::new(JsonSchema {
+                                reference: Some("#/$defs/DealPrice".to_string()),
+                                ..Default::default()
+                            })),
+                            description: Some(
+                                "Price points, e.g. pre-order and release prices".to_string(),
+                            ),
+                            ..Default::default()
+                        },
+                    );
                     props
                 }),
                 required: Some(vec![
@@ -672,6 +700,53 @@ impl SchemaGenerator {
             },
         );
 
+        // DealPrice
+        definitions.insert(
+            "DealPrice".to_string(),
+            JsonSchema {
+                title: Some("Deal Price".to_string()),
+                description: Some("One price point of a deal".to_string()),
+                schema_type: Some("object".to_string()),
+                properties: Some({
+                    let mut props = IndexMap::new();
+                    props.insert(
+                        "price_type".to_string(),
+                        JsonSchema {
+                            schema_type: Some("string".to_string()),
+                            description: Some("Which price this is, e.g. PreOrder".to_string()),
+                            ..Default::default()
+                        },
+                    );
+                    props.insert(
+                        "amount".to_string(),
+                        JsonSchema {
+                            schema_type: Some("string".to_string()),
+                            pattern: Some("^\\d+(\\.\\d+)?$".to_string()),
+                            description: Some("Wholesale price per unit".to_string()),
+                            ..Default::default()
+                        },
+                    );
+                    props.insert(
+                        "currency_code".to_string(),
+                        JsonSchema {
+                            schema_type: Some("string".to_string()),
+                            pattern: Some("^[A-Z]{3}$".to_string()),
+                            description: Some("ISO 4217 currency code".to_string()),
+                            ..Default::default()
+                        },
+                    );
+                    props
+                }),
+                required: Some(vec![
+                    "price_type".to_string(),
+                    "amount".to_string(),
+                    "currency_code".to_string(),
+                ]),
+                additional_properties: Some(false),
+                ..Default::default()
+            },
+        );
+
         Ok(definitions)
     }
 
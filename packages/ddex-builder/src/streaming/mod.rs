@@ -7,10 +7,17 @@
 pub mod buffer_manager;
 pub mod reference_manager;
 
-use crate::builder::MessageHeaderRequest;
+use crate::ast::Element;
+use crate::builder::{
+    BuildRequest, DealRequest, LocalizedStringRequest, MessageConfig, MessageHeaderRequest,
+    PartyRequest, ReleaseRequest, TrackRequest,
+};
 use crate::determinism::DeterminismConfig;
 use crate::error::{BuildError, BuildWarning};
+use crate::generator::{xml_writer::XmlWriter, ASTGenerator};
+use crate::versions::VersionSpec;
 use buffer_manager::BufferManager;
+use indexmap::IndexMap;
 use reference_manager::StreamingReferenceManager;
 use std::io::Write as IoWrite;
 use uuid::Uuid;
@@ -99,6 +106,18 @@ pub struct StreamingBuilder<W: IoWrite> {
     reference_manager: StreamingReferenceManager,
     config: StreamingConfig,
     xml_buffer: Vec<u8>,
+    generator: ASTGenerator,
+    xml_writer: XmlWriter,
+    /// Release ID of each release written, by reference, for bundles
+    release_ids: IndexMap<String, String>,
+    /// TrackReleases and ReleaseVisibility blocks held back until every
+    /// release is written
+    track_releases: Vec<Element>,
+    visibilities: Vec<Element>,
+    /// ReleaseDeals, held back until the DealList after every release
+    deals: Vec<Element>,
+    /// Resource reference and file path of each resource naming a file
+    resource_files: Vec<(String, String)>,
 
     // State tracking
     message_started: bool,
@@ -128,6 +147,13 @@ impl<W: IoWrite> StreamingBuilder<W> {
         Ok(StreamingBuilder {
             buffer_manager,
             reference_manager: StreamingReferenceManager::new(),
+            generator: ASTGenerator::new("4.3".to_string()),
+            xml_writer: XmlWriter::new(config.determinism_config.clone()),
+            release_ids: IndexMap::new(),
+            track_releases: Vec::new(),
+            visibilities: Vec::new(),
+            deals: Vec::new(),
+            resource_files: Vec::new(),
             config,
             xml_buffer: Vec::new(),
             message_started: false,
@@ -168,7 +194,7 @@ impl<W: IoWrite> StreamingBuilder<W> {
             r#"<?xml version="1.0" encoding="UTF-8"?>
 <NewReleaseMessage xmlns="http://ddex.net/xml/ern/43" MessageSchemaVersionId="{}" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance">
 "#,
-            version
+            escape_xml(version)
         );
 
         self.xml_buffer.extend_from_slice(xml_start.as_bytes());
@@ -185,6 +211,30 @@ impl<W: IoWrite> StreamingBuilder<W> {
         Ok(())
     }
 
+    /// Start the message with a header built from `config`
+    pub fn start_message_with_config(
+        &mut self,
+        config: &MessageConfig,
+        version: &str,
+    ) -> Result<(), BuildError> {
+        self.start_message(&config.to_header(), version)
+    }
+
+    /// Write the SoundRecording for `track` to the stream, as the buffered
+    /// builder would write it, and return its resource reference. Without a
+    /// `resource_reference` it defaults to `A` followed by the track ID, as
+    /// the releases built from the same tracks expect.
+    pub fn write_track_request(&mut self, track: &TrackRequest) -> Result<String, BuildError> {
+        let sound_recording = self.generator.generate_sound_recording(track)?;
+        self.write_resource_element(&sound_recording)?;
+        let resource_ref = track
+            .resource_reference
+            .clone()
+            .unwrap_or_else(|| format!("A{}", track.track_id));
+        self.record_resource_file(&resource_ref, track.file_path.as_deref());
+        Ok(resource_ref)
+    }
+
     /// Write a single resource to the stream
     pub fn write_resource(
         &mut self,
@@ -195,73 +245,44 @@ impl<W: IoWrite> StreamingBuilder<W> {
         duration: Option<&str>,
         file_path: Option<&str>,
     ) -> Result<String, BuildError> {
-        if !self.message_started || self.message_finished {
-            return Err(BuildError::XmlGeneration(
-                "Message not in valid state for writing resources".to_string(),
-            ));
-        }
+        self.check_writable("resources")?;
 
         // Generate stable reference for this resource
         let resource_ref = self
             .reference_manager
             .generate_resource_reference(resource_id)?;
 
-        // Build SoundRecording XML
-        let mut resource_xml = String::new();
-        resource_xml.push_str("    <SoundRecording>\n");
-        resource_xml.push_str(&format!(
-            "      <ResourceReference>{}</ResourceReference>\n",
-            resource_ref
-        ));
-        resource_xml.push_str("      <Type>SoundRecording</Type>\n");
-        resource_xml.push_str(&format!(
-            "      <ResourceId>{}</ResourceId>\n",
-            escape_xml(resource_id)
-        ));
-        resource_xml.push_str(&format!(
-            "      <ReferenceTitle>{}</ReferenceTitle>\n",
-            escape_xml(title)
-        ));
-        resource_xml.push_str(&format!(
-            "      <DisplayArtist>{}</DisplayArtist>\n",
-            escape_xml(artist)
-        ));
-
-        if let Some(isrc_value) = isrc {
-            resource_xml.push_str(&format!("      <ISRC>{}</ISRC>\n", escape_xml(isrc_value)));
+        let track = TrackRequest {
+            track_id: resource_id.to_string(),
+            resource_reference: Some(resource_ref.clone()),
+            isrc: isrc.unwrap_or_default().to_string(),
+            title: title.to_string(),
+            subtitle: None,
+            duration: duration.unwrap_or_default().to_string(),
+            artist: artist.to_string(),
+            file_path: None,
+            hash_sum: None,
+            preview: None,
+            contributors: vec![],
+        };
+        let mut sound_recording = self.generator.generate_sound_recording(&track)?;
+
+        // The track model has no place for a file that is named but not
+        // hashed
+        if let Some(file_path) = file_path {
+            let mut details = Element::new("TechnicalDetails");
+            details.add_child(
+                Element::new("TechnicalResourceDetailsReference")
+                    .with_text(format!("T{}", resource_ref)),
+            );
+            let mut file = Element::new("File");
+            file.add_child(Element::new("URI").with_text(file_path));
+            details.add_child(file);
+            sound_recording.add_child(details);
         }
 
-        if let Some(duration_value) = duration {
-            resource_xml.push_str(&format!(
-                "      <Duration>{}</Duration>\n",
-                escape_xml(duration_value)
-            ));
-        }
-
-        if let Some(file_path_value) = file_path {
-            resource_xml.push_str("      <TechnicalResourceDetails>\n");
-            resource_xml.push_str(&format!(
-                "        <FileName>{}</FileName>\n",
-                escape_xml(file_path_value)
-            ));
-            resource_xml.push_str("        <AudioCodecType>MP3</AudioCodecType>\n");
-            resource_xml.push_str("      </TechnicalResourceDetails>\n");
-        }
-
-        resource_xml.push_str("    </SoundRecording>\n");
-
-        self.xml_buffer.extend_from_slice(resource_xml.as_bytes());
-
-        self.resources_written += 1;
-
-        // Check for progress callback
-        if self.resources_written % self.config.progress_callback_frequency == 0 {
-            self.report_progress();
-        }
-
-        // Flush if buffer is getting large
-        self.flush_if_needed()?;
-
+        self.write_resource_element(&sound_recording)?;
+        self.record_resource_file(&resource_ref, file_path);
         Ok(resource_ref)
     }
 
@@ -281,6 +302,46 @@ impl<W: IoWrite> StreamingBuilder<W> {
         Ok(())
     }
 
+    /// Write the Release for `release` to the stream, as the buffered
+    /// builder would write it, and return its release reference. Its
+    /// TrackReleases and pre-order visibility are written when the message
+    /// is finished, after every release; the components of a bundle must
+    /// already have been written.
+    pub fn write_release_request(
+        &mut self,
+        release: &ReleaseRequest,
+    ) -> Result<String, BuildError> {
+        self.check_writable("releases")?;
+
+        let release_ref = release
+            .release_reference
+            .clone()
+            .unwrap_or_else(|| format!("R{}", release.release_id));
+        self.release_ids
+            .entry(release_ref.clone())
+            .or_insert_with(|| release.release_id.clone());
+
+        let element = self.generator.generate_release(
+            release,
+            self.releases_written,
+            &self.release_ids,
+            &IndexMap::new(),
+        )?;
+        for track_release in &release.track_releases {
+            self.track_releases.push(
+                self.generator
+                    .generate_track_release(release, track_release),
+            );
+        }
+        self.visibilities.extend(
+            self.generator
+                .generate_release_visibility(release, self.releases_written),
+        );
+
+        self.write_release_element(&element)?;
+        Ok(release_ref)
+    }
+
     /// Write a single release to the stream
     pub fn write_release(
         &mut self,
@@ -293,87 +354,128 @@ impl<W: IoWrite> StreamingBuilder<W> {
         genre: Option<&str>,
         resource_references: &[String],
     ) -> Result<String, BuildError> {
-        if !self.message_started || self.message_finished {
-            return Err(BuildError::XmlGeneration(
-                "Message not in valid state for writing releases".to_string(),
-            ));
-        }
+        self.check_writable("releases")?;
 
         // Generate stable reference for this release
         let release_ref = self
             .reference_manager
             .generate_release_reference(release_id)?;
 
-        // Build Release XML
-        let mut release_xml = String::new();
-        release_xml.push_str("    <Release>\n");
-        release_xml.push_str(&format!(
-            "      <ReleaseReference>{}</ReleaseReference>\n",
-            release_ref
-        ));
-        release_xml.push_str(&format!(
-            "      <ReleaseId>{}</ReleaseId>\n",
-            escape_xml(release_id)
-        ));
-        release_xml.push_str("      <ReleaseType>Album</ReleaseType>\n");
-        release_xml.push_str(&format!("      <Title>{}</Title>\n", escape_xml(title)));
-        release_xml.push_str(&format!(
-            "      <DisplayArtist>{}</DisplayArtist>\n",
-            escape_xml(artist)
-        ));
-
-        if let Some(label_value) = label {
-            release_xml.push_str(&format!(
-                "      <LabelName>{}</LabelName>\n",
-                escape_xml(label_value)
-            ));
-        }
-
-        if let Some(upc_value) = upc {
-            release_xml.push_str(&format!("      <UPC>{}</UPC>\n", escape_xml(upc_value)));
+        let release = ReleaseRequest {
+            release_id: release_id.to_string(),
+            release_reference: Some(release_ref.clone()),
+            title: vec![LocalizedStringRequest {
+                text: title.to_string(),
+                language_code: None,
+            }],
+            display_title: vec![],
+            subtitle: None,
+            artist: artist.to_string(),
+            label: label.map(str::to_string),
+            release_date: release_date.map(str::to_string),
+            preorder_date: None,
+            upc: upc.map(str::to_string),
+            tracks: vec![],
+            images: vec![],
+            videos: vec![],
+            resource_references: Some(resource_references.to_vec()),
+            track_releases: vec![],
+            component_release_references: vec![],
+            release_dates_by_territory: Default::default(),
+            display_artists: Default::default(),
+            resource_groups: Default::default(),
+        };
+        let mut element = self.generator.generate_release(
+            &release,
+            self.releases_written,
+            &self.release_ids,
+            &IndexMap::new(),
+        )?;
+
+        // The release model has no place for a genre
+        if let Some(genre) = genre {
+            let mut genre_elem = Element::new("Genre");
+            genre_elem.add_child(Element::new("GenreText").with_text(genre));
+            element.add_child(genre_elem);
         }
 
-        if let Some(date_value) = release_date {
-            release_xml.push_str(&format!(
-                "      <ReleaseDate>{}</ReleaseDate>\n",
-                escape_xml(date_value)
-            ));
-        }
+        self.write_release_element(&element)?;
+        Ok(release_ref)
+    }
 
-        if let Some(genre_value) = genre {
-            release_xml.push_str(&format!(
-                "      <Genre>{}</Genre>\n",
-                escape_xml(genre_value)
-            ));
-        }
+    /// Queue the ReleaseDeal for `deal`; deals are written to the DealList
+    /// when the message is finished, after every release
+    pub fn write_deal_request(&mut self, deal: &DealRequest) -> Result<(), BuildError> {
+        self.check_writable("deals")?;
+        self.deals.push(self.generator.generate_deal(deal));
+        self.deals_written += 1;
+        Ok(())
+    }
 
-        // Write ResourceGroup linking to resources
-        if !resource_references.is_empty() {
-            release_xml.push_str("      <ResourceGroup>\n");
-            for resource_ref in resource_references {
-                release_xml.push_str(&format!(
-                    "        <ResourceReference>{}</ResourceReference>\n",
-                    resource_ref
-                ));
+    /// Stream the whole of `request` and finish the message: every track,
+    /// image and video of every release, then the releases, then the
+    /// deals. Only the element being written and the output buffer are held
+    /// at once, so memory stays bounded however many releases the request
+    /// has. Elements are written in request order, whatever the sort order.
+    pub fn write_request(&mut self, request: &BuildRequest) -> Result<StreamingStats, BuildError> {
+        let spec = VersionSpec::for_version(request.version.trim_start_matches("ern/"))
+            .filter(|spec| spec.message_schema_version_id == "ern/43")
+            .ok_or_else(|| BuildError::InvalidFormat {
+                field: "version".to_string(),
+                message: format!(
+                    "the streaming builder writes ERN 4.3 only, not '{}'",
+                    request.version
+                ),
+            })?;
+
+        self.start_message(&request.header, &spec.message_schema_version_id)?;
+        for release in &request.releases {
+            for track in &release.tracks {
+                self.write_track_request(track)?;
             }
-            release_xml.push_str("      </ResourceGroup>\n");
+        }
+        for image in request.releases.iter().flat_map(|release| &release.images) {
+            let element = self.generator.generate_image(image)?;
+            self.write_resource_element(&element)?;
+            self.record_resource_file(&image.resource_reference, image.file_path.as_deref());
+        }
+        for video in request.releases.iter().flat_map(|release| &release.videos) {
+            let element = self.generator.generate_video(video)?;
+            self.write_resource_element(&element)?;
+            self.record_resource_file(&video.resource_reference, video.file_path.as_deref());
         }
 
-        release_xml.push_str("    </Release>\n");
-
-        self.xml_buffer.extend_from_slice(release_xml.as_bytes());
-
-        self.releases_written += 1;
-
-        // Check for progress callback
-        if self.releases_written % self.config.progress_callback_frequency == 0 {
-            self.report_progress();
+        self.finish_resources_start_releases()?;
+        for release in &request.releases {
+            self.write_release_request(release)?;
+        }
+        for deal in &request.deals {
+            self.write_deal_request(deal)?;
         }
 
-        // Flush if buffer is getting large
-        self.flush_if_needed()?;
+        self.finish_message()
+    }
 
-        Ok(release_ref)
+    /// Check that every file named by a resource written so far exists and
+    /// can be read, returning the ones that cannot. Call it before
+    /// `finish_message` so a delivery with missing audio is abandoned
+    /// rather than completed.
+    pub fn missing_resource_files(&self) -> Vec<MissingResourceFile> {
+        self.resource_files
+            .iter()
+            .filter_map(|(resource_reference, path)| {
+                let reason = match std::fs::metadata(path) {
+                    Ok(metadata) if metadata.is_dir() => "is a directory".to_string(),
+                    Ok(_) => std::fs::File::open(path).err()?.to_string(),
+                    Err(e) => e.to_string(),
+                };
+                Some(MissingResourceFile {
+                    resource_reference: resource_reference.clone(),
+                    path: path.clone(),
+                    reason,
+                })
+            })
+            .collect()
     }
 
     /// Finish the message and close all tags
@@ -384,8 +486,27 @@ impl<W: IoWrite> StreamingBuilder<W> {
             ));
         }
 
-        // End ReleaseList and close root element
+        // TrackReleases follow every main release
+        for track_release in std::mem::take(&mut self.track_releases) {
+            self.xml_writer
+                .write_fragment(&track_release, 2, &mut self.xml_buffer)?;
+        }
         self.xml_buffer.extend_from_slice(b"  </ReleaseList>\n");
+
+        // Deals, then visibility dates, which live in the DealList apart
+        // from any deal
+        if !self.deals.is_empty() || !self.visibilities.is_empty() {
+            self.xml_buffer.extend_from_slice(b"  <DealList>\n");
+            for deal in std::mem::take(&mut self.deals) {
+                self.xml_writer
+                    .write_fragment(&deal, 2, &mut self.xml_buffer)?;
+            }
+            for visibility in std::mem::take(&mut self.visibilities) {
+                self.xml_writer
+                    .write_fragment(&visibility, 2, &mut self.xml_buffer)?;
+            }
+            self.xml_buffer.extend_from_slice(b"  </DealList>\n");
+        }
         self.xml_buffer.extend_from_slice(b"</NewReleaseMessage>\n");
 
         // Final flush of any remaining content
@@ -417,6 +538,54 @@ impl<W: IoWrite> StreamingBuilder<W> {
 
     // Private helper methods
 
+    fn check_writable(&self, items: &str) -> Result<(), BuildError> {
+        if !self.message_started || self.message_finished {
+            return Err(BuildError::XmlGeneration(format!(
+                "Message not in valid state for writing {}",
+                items
+            )));
+        }
+        Ok(())
+    }
+
+    fn record_resource_file(&mut self, resource_ref: &str, file_path: Option<&str>) {
+        if let Some(path) = file_path.filter(|path| !path.is_empty()) {
+            self.resource_files
+                .push((resource_ref.to_string(), path.to_string()));
+        }
+    }
+
+    fn write_resource_element(&mut self, resource: &Element) -> Result<(), BuildError> {
+        self.check_writable("resources")?;
+        self.xml_writer
+            .write_fragment(resource, 2, &mut self.xml_buffer)?;
+
+        self.resources_written += 1;
+
+        // Check for progress callback
+        if self.resources_written % self.config.progress_callback_frequency == 0 {
+            self.report_progress();
+        }
+
+        // Flush if buffer is getting large
+        self.flush_if_needed()
+    }
+
+    fn write_release_element(&mut self, release: &Element) -> Result<(), BuildError> {
+        self.xml_writer
+            .write_fragment(release, 2, &mut self.xml_buffer)?;
+
+        self.releases_written += 1;
+
+        // Check for progress callback
+        if self.releases_written % self.config.progress_callback_frequency == 0 {
+            self.report_progress();
+        }
+
+        // Flush if buffer is getting large
+        self.flush_if_needed()
+    }
+
     fn write_message_header(&mut self, header: &MessageHeaderRequest) -> Result<(), BuildError> {
         // Generate message ID if not provided
         let default_id = Uuid::new_v4().to_string();
@@ -424,6 +593,11 @@ impl<W: IoWrite> StreamingBuilder<W> {
 
         let mut header_xml = String::new();
         header_xml.push_str("  <MessageHeader>\n");
+        let thread_id = header.message_thread_id.as_deref().unwrap_or(message_id);
+        header_xml.push_str(&format!(
+            "    <MessageThreadId>{}</MessageThreadId>\n",
+            escape_xml(thread_id)
+        ));
         header_xml.push_str(&format!(
             "    <MessageId>{}</MessageId>\n",
             escape_xml(message_id)
@@ -431,22 +605,12 @@ impl<W: IoWrite> StreamingBuilder<W> {
 
         // Write MessageSender
         header_xml.push_str("    <MessageSender>\n");
-        if !header.message_sender.party_name.is_empty() {
-            header_xml.push_str(&format!(
-                "      <PartyName>{}</PartyName>\n",
-                escape_xml(&header.message_sender.party_name[0].text)
-            ));
-        }
+        write_party(&mut header_xml, &header.message_sender);
         header_xml.push_str("    </MessageSender>\n");
 
         // Write MessageRecipient
         header_xml.push_str("    <MessageRecipient>\n");
-        if !header.message_recipient.party_name.is_empty() {
-            header_xml.push_str(&format!(
-                "      <PartyName>{}</PartyName>\n",
-                escape_xml(&header.message_recipient.party_name[0].text)
-            ));
-        }
+        write_party(&mut header_xml, &header.message_recipient);
         header_xml.push_str("    </MessageRecipient>\n");
 
         // Write MessageCreatedDateTime
@@ -460,6 +624,13 @@ impl<W: IoWrite> StreamingBuilder<W> {
             escape_xml(created_time)
         ));
 
+        if let Some(ref control_type) = header.message_control_type {
+            header_xml.push_str(&format!(
+                "    <MessageControlType>{}</MessageControlType>\n",
+                escape_xml(control_type)
+            ));
+        }
+
         header_xml.push_str("  </MessageHeader>\n");
 
         self.xml_buffer.extend_from_slice(header_xml.as_bytes());
@@ -577,6 +748,17 @@ pub struct StreamingStats {
     pub peak_memory_usage: usize,
 }
 
+/// A file named by a streamed resource that is missing or unreadable
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingResourceFile {
+    /// Reference of the resource naming the file
+    pub resource_reference: String,
+    /// The file path as given
+    pub path: String,
+    /// Why the file cannot be read
+    pub reason: String,
+}
+
 /// Errors that can occur during streaming operations
 ///
 /// Comprehensive error types for streaming DDEX XML generation,
@@ -638,3 +820,32 @@ fn escape_xml(text: &str) -> String {
         .replace('"', "&quot;")
         .replace('\'', "&apos;")
 }
+
+/// Write the PartyId, localized PartyName elements and optional TradingName of a party
+fn write_party(xml: &mut String, party: &PartyRequest) {
+    if let Some(ref party_id) = party.party_id {
+        xml.push_str(&format!(
+            "      <PartyId>{}</PartyId>\n",
+            escape_xml(party_id)
+        ));
+    }
+    for name in &party.party_name {
+        match name.language_code {
+            Some(ref lang) => xml.push_str(&format!(
+                "      <PartyName LanguageCode=\"{}\">{}</PartyName>\n",
+                escape_xml(lang),
+                escape_xml(&name.text)
+            )),
+            None => xml.push_str(&format!(
+                "      <PartyName>{}</PartyName>\n",
+                escape_xml(&name.text)
+            )),
+        }
+    }
+    if let Some(ref trading_name) = party.trading_name {
+        xml.push_str(&format!(
+            "      <TradingName>{}</TradingName>\n",
+            escape_xml(trading_name)
+        ));
+    }
+}
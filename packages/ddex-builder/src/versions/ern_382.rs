@@ -74,6 +74,14 @@ pub fn get_version_spec() -> VersionSpec {
     element_mappings.insert("BasicPrice".to_string(), "Price".to_string());
     element_mappings.insert("SimpleTerritory".to_string(), "Territory".to_string());
 
+    // Elements generated under their ERN 4.x name that 3.8.2 spells differently
+    let mut renamed_elements = IndexMap::new();
+    renamed_elements.insert(
+        "TechnicalDetails".to_string(),
+        "TechnicalSoundRecordingDetails".to_string(),
+    );
+    renamed_elements.insert("SubTitle".to_string(), "Subtitle".to_string());
+
     VersionSpec {
         version: DdexVersion::Ern382,
         namespace: "http://ddex.net/xml/ern/382".to_string(),
@@ -87,6 +95,7 @@ pub fn get_version_spec() -> VersionSpec {
             "CatalogListMessage".to_string(),
         ],
         element_mappings,
+        renamed_elements,
         required_elements: vec![
             "MessageId".to_string(),
             "MessageSender".to_string(),
@@ -156,6 +156,7 @@ pub fn get_version_spec() -> VersionSpec {
             "SalesReportMessage".to_string(),
         ],
         element_mappings,
+        renamed_elements: IndexMap::new(),
         required_elements: vec![
             "MessageId".to_string(),
             "MessageSender".to_string(),
@@ -81,6 +81,10 @@ pub struct VersionSpec {
     pub supported_message_types: Vec<String>,
     /// Version-specific element mappings
     pub element_mappings: IndexMap<String, String>,
+    /// ERN 4.x element names mapped to the name this version emits instead,
+    /// for the elements whose name differs
+    #[serde(default)]
+    pub renamed_elements: IndexMap<String, String>,
     /// Required elements for this version
     pub required_elements: Vec<String>,
     /// Deprecated elements (for downgrades)
@@ -91,6 +95,18 @@ pub struct VersionSpec {
     pub namespace_prefixes: IndexMap<String, String>,
 }
 
+impl VersionSpec {
+    /// Spec for a version as written in a build request ("4.3", "382", ...)
+    pub fn for_version(version: &str) -> Option<VersionSpec> {
+        match version {
+            "4.3" | "43" => Some(ern_43::get_version_spec()),
+            "4.2" | "42" => Some(ern_42::get_version_spec()),
+            "3.8.2" | "382" => Some(ern_382::get_version_spec()),
+            _ => None,
+        }
+    }
+}
+
 /// Version conversion result with detailed reporting
 #[derive(Debug, Clone)]
 pub struct ConversionResult {
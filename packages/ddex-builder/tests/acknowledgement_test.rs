@@ -0,0 +1,68 @@
+use ddex_builder::builder::MessageHeaderRequest;
+use ddex_builder::determinism::CanonMode;
+use ddex_builder::{AcknowledgementRequest, BuildOptions, DDEXBuilder, DeterminismConfig};
+
+mod common;
+use common::party;
+
+fn acknowledgement(status: &str) -> AcknowledgementRequest {
+    AcknowledgementRequest {
+        header: MessageHeaderRequest {
+            message_id: Some("ACK_001".to_string()),
+            message_sender: party("Test DSP"),
+            message_recipient: party("Test Label"),
+            message_created_date_time: Some("2024-06-01T12:00:00Z".to_string()),
+            message_thread_id: Some("THREAD_42".to_string()),
+            ..Default::default()
+        },
+        referenced_message_id: "MSG_ORIGINAL_001".to_string(),
+        status: status.to_string(),
+    }
+}
+
+#[test]
+fn test_acknowledgement_root_and_reference() {
+    let result = DDEXBuilder::new()
+        .build_acknowledgement(acknowledgement("FileOK"), BuildOptions::default())
+        .unwrap();
+
+    assert!(result.xml.contains("<ernc:FtpAcknowledgementMessage"));
+    assert!(result.xml.contains("http://ddex.net/xml/ern-c/15"));
+    assert!(!result.xml.contains("NewReleaseMessage"));
+    assert!(result
+        .xml
+        .contains("<MessageThreadId>THREAD_42</MessageThreadId>"));
+    assert!(result.xml.contains("<MessageId>ACK_001</MessageId>"));
+
+    // The referenced id sits inside the Acknowledgement block, not the header
+    let acknowledgement = &result.xml[result.xml.find("<Acknowledgement>").unwrap()..];
+    assert!(acknowledgement.contains("<MessageId>MSG_ORIGINAL_001</MessageId>"));
+    assert!(acknowledgement.contains("<MessageStatus>FileOK</MessageStatus>"));
+}
+
+#[test]
+fn test_acknowledgement_pretty_output() {
+    let options = BuildOptions {
+        determinism: Some(DeterminismConfig {
+            canon_mode: CanonMode::Pretty,
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let result = DDEXBuilder::new()
+        .build_acknowledgement(acknowledgement("SchemaValidationError"), options)
+        .unwrap();
+
+    assert!(result.canonical_hash.is_none());
+    assert!(result
+        .xml
+        .contains("<MessageStatus>SchemaValidationError</MessageStatus>"));
+}
+
+#[test]
+fn test_acknowledgement_requires_status() {
+    let result =
+        DDEXBuilder::new().build_acknowledgement(acknowledgement(" "), BuildOptions::default());
+    assert!(result.is_err());
+}
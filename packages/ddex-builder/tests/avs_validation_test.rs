@@ -0,0 +1,87 @@
+use ddex_builder::builder::{BuildRequest, DealRequest, DealTerms};
+use ddex_builder::error::BuildError;
+use ddex_builder::preflight::{COMMERCIAL_MODEL_TYPES, USE_TYPES};
+use ddex_builder::{check_avs, AvsCheck, BuildOptions, DDEXBuilder, PreflightLevel};
+
+mod common;
+use common::header;
+
+fn request_with_deal(commercial_model_type: &str, use_types: &[&str]) -> BuildRequest {
+    BuildRequest {
+        header: header("AVS_001"),
+        version: "4.3".to_string(),
+        profile: None,
+        releases: vec![],
+        deals: vec![DealRequest {
+            deal_reference: Some("D1".to_string()),
+            deal_terms: DealTerms {
+                commercial_model_type: commercial_model_type.to_string(),
+                territory_code: vec!["US".to_string()],
+                use_types: use_types.iter().map(|u| u.to_string()).collect(),
+                ..Default::default()
+            },
+            release_references: vec![],
+        }],
+        extensions: None,
+    }
+}
+
+fn strict_build(request: BuildRequest) -> Result<String, BuildError> {
+    let options = BuildOptions {
+        preflight_level: PreflightLevel::Strict,
+        ..Default::default()
+    };
+    DDEXBuilder::new().build(request, options).map(|result| result.xml)
+}
+
+#[test]
+fn test_allowed_values_pass() {
+    assert_eq!(check_avs("SubscriptionModel", COMMERCIAL_MODEL_TYPES), AvsCheck::Valid);
+    assert_eq!(check_avs("PermanentDownload", USE_TYPES), AvsCheck::Valid);
+
+    let xml = strict_build(request_with_deal(
+        "SubscriptionModel",
+        &["Stream", "ConditionalDownload"],
+    ))
+    .unwrap();
+    assert!(xml.contains("<CommercialModelType>SubscriptionModel</CommercialModelType>"));
+}
+
+#[test]
+fn test_near_miss_suggests_allowed_value() {
+    assert_eq!(
+        check_avs("Subscription", COMMERCIAL_MODEL_TYPES),
+        AvsCheck::NearMiss("SubscriptionModel")
+    );
+    assert_eq!(check_avs("stream", USE_TYPES), AvsCheck::NearMiss("Stream"));
+    assert_eq!(
+        check_avs("PermanantDownload", USE_TYPES),
+        AvsCheck::NearMiss("PermanentDownload")
+    );
+
+    match strict_build(request_with_deal("Subscription", &["Strem"])) {
+        Err(BuildError::ValidationFailed { errors }) => {
+            assert_eq!(errors.len(), 2);
+            assert!(errors[0].starts_with("INVALID_COMMERCIAL_MODEL_TYPE"));
+            assert!(errors[0].contains("did you mean 'SubscriptionModel'?"));
+            assert!(errors[1].starts_with("INVALID_USE_TYPE"));
+            assert!(errors[1].contains("did you mean 'Stream'?"));
+        }
+        other => panic!("expected validation failure, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_unrelated_value_fails_without_suggestion() {
+    assert_eq!(check_avs("Barter", COMMERCIAL_MODEL_TYPES), AvsCheck::Invalid);
+    assert_eq!(check_avs("", USE_TYPES), AvsCheck::Invalid);
+
+    match strict_build(request_with_deal("Barter", &[])) {
+        Err(BuildError::ValidationFailed { errors }) => {
+            assert_eq!(errors.len(), 1);
+            assert!(errors[0].starts_with("INVALID_COMMERCIAL_MODEL_TYPE"));
+            assert!(!errors[0].contains("did you mean"));
+        }
+        other => panic!("expected validation failure, got {:?}", other),
+    }
+}
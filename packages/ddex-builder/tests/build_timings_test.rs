@@ -0,0 +1,69 @@
+use ddex_builder::builder::{BuildRequest, LocalizedStringRequest, ReleaseRequest, TrackRequest};
+use ddex_builder::{BuildOptions, DDEXBuilder};
+
+mod common;
+use common::header;
+
+fn track(release: usize, track: usize) -> TrackRequest {
+    TrackRequest {
+        track_id: format!("{}_{}", release, track),
+        resource_reference: Some(format!("A{}_{}", release, track)),
+        isrc: format!("USRC1760{:04}", release * 100 + track),
+        title: format!("Track {}", track),
+        duration: "PT3M00S".to_string(),
+        artist: "Artist".to_string(),
+        ..Default::default()
+    }
+}
+
+fn request() -> BuildRequest {
+    BuildRequest {
+        header: header("MSG_TIMINGS_001"),
+        version: "4.3".to_string(),
+        profile: None,
+        releases: (0..50)
+            .map(|release| ReleaseRequest {
+                release_id: format!("REL{:03}", release),
+                release_reference: Some(format!("R{}", release)),
+                title: vec![LocalizedStringRequest {
+                    text: format!("Album {}", release),
+                    language_code: None,
+                }],
+                artist: "Artist".to_string(),
+                tracks: (0..20).map(|t| track(release, t)).collect(),
+                ..Default::default()
+            })
+            .collect(),
+        deals: vec![],
+        extensions: None,
+    }
+}
+
+#[test]
+fn test_stage_timings_sum_to_the_total() {
+    let options = BuildOptions {
+        collect_statistics: true,
+        include_canonical: true,
+        ..BuildOptions::default()
+    };
+    let result = DDEXBuilder::new().build(request(), options).unwrap();
+    let timings = result.statistics.timings.unwrap();
+
+    let stages = timings.validation_ms
+        + timings.serialization_ms
+        + timings.canonicalization_ms
+        + timings.hashing_ms;
+    assert!(timings.serialization_ms > 0.0);
+    assert!(timings.canonicalization_ms > 0.0);
+    assert!(stages <= timings.total_ms + 1e-6, "{:?}", timings);
+    // What is left over is bookkeeping between stages
+    assert!(stages >= timings.total_ms * 0.8, "{:?}", timings);
+}
+
+#[test]
+fn test_timings_are_only_collected_on_request() {
+    let result = DDEXBuilder::new()
+        .build(request(), BuildOptions::default())
+        .unwrap();
+    assert!(result.statistics.timings.is_none());
+}
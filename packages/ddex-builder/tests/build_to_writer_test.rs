@@ -0,0 +1,132 @@
+use ddex_builder::builder::{
+    BuildRequest, DealRequest, DealTerms, LocalizedStringRequest, MessageHeaderRequest,
+    ReleaseRequest, TrackRequest,
+};
+use ddex_builder::streaming::StreamingConfig;
+use ddex_builder::DDEXBuilder;
+use ddex_parser::transform::graph::GraphBuilder;
+use ddex_parser::ERNVersion;
+use std::io::BufReader;
+
+mod common;
+use common::party;
+
+const RELEASES: usize = 2_000;
+
+fn release(n: usize) -> ReleaseRequest {
+    ReleaseRequest {
+        release_id: format!("REL{:05}", n),
+        release_reference: Some(format!("R{}", n)),
+        title: vec![LocalizedStringRequest {
+            text: format!("Catalog Album {}", n),
+            language_code: None,
+        }],
+        artist: "Catalog Artist".to_string(),
+        label: Some("Catalog Records".to_string()),
+        release_date: Some("2024-01-01".to_string()),
+        tracks: vec![TrackRequest {
+            track_id: format!("T{}", n),
+            resource_reference: Some(format!("A{}", n)),
+            isrc: format!("USRC1{:07}", n),
+            title: format!("Catalog Track {}", n),
+            duration: "PT3M00S".to_string(),
+            artist: "Catalog Artist".to_string(),
+            ..Default::default()
+        }],
+        resource_references: Some(vec![format!("A{}", n)]),
+        ..Default::default()
+    }
+}
+
+fn request() -> BuildRequest {
+    BuildRequest {
+        header: MessageHeaderRequest {
+            message_id: Some("CATALOG_001".to_string()),
+            message_sender: party("Sender"),
+            message_recipient: party("Recipient"),
+            message_created_date_time: Some("2024-01-01T00:00:00Z".to_string()),
+            ..Default::default()
+        },
+        version: "4.3".to_string(),
+        profile: None,
+        releases: (1..=RELEASES).map(release).collect(),
+        deals: vec![DealRequest {
+            deal_reference: Some("D1".to_string()),
+            deal_terms: DealTerms {
+                commercial_model_type: "SubscriptionModel".to_string(),
+                territory_code: vec!["Worldwide".to_string()],
+                start_date: Some("2024-01-01".to_string()),
+                use_types: vec!["Stream".to_string()],
+                ..Default::default()
+            },
+            release_references: vec!["R1".to_string()],
+        }],
+        extensions: None,
+    }
+}
+
+#[test]
+fn test_huge_request_streams_to_file_with_bounded_memory() {
+    let config = StreamingConfig {
+        max_buffer_size: 64 * 1024,
+        ..StreamingConfig::default()
+    };
+    let file = tempfile::NamedTempFile::new().unwrap();
+
+    let stats = DDEXBuilder::new()
+        .build_to_writer(request(), file.reopen().unwrap(), config)
+        .unwrap();
+
+    assert_eq!(stats.releases_written, RELEASES);
+    assert_eq!(stats.resources_written, RELEASES);
+    assert_eq!(stats.deals_written, 1);
+
+    let size = std::fs::metadata(file.path()).unwrap().len() as usize;
+    assert_eq!(size, stats.bytes_written);
+    assert!(
+        stats.peak_memory_usage * 10 < size,
+        "peak of {} bytes for {} bytes of output",
+        stats.peak_memory_usage,
+        size
+    );
+
+    let xml = std::fs::read_to_string(file.path()).unwrap();
+    assert!(xml.contains(r#"MessageSchemaVersionId="ern/43""#));
+
+    let message = GraphBuilder::new(ERNVersion::V4_3)
+        .build_from_xml(BufReader::new(std::fs::File::open(file.path()).unwrap()))
+        .unwrap();
+    assert_eq!(message.releases.len(), RELEASES);
+    assert_eq!(message.deals.len(), 1);
+}
+
+#[test]
+fn test_streamed_build_rejects_other_versions() {
+    let mut request = request();
+    request.version = "4.2".to_string();
+
+    let result =
+        DDEXBuilder::new().build_to_writer(request, Vec::new(), StreamingConfig::default());
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_streamed_build_writes_canonical_schema_version() {
+    for version in ["4.3", "43", "ern/43"] {
+        let mut request = request();
+        request.version = version.to_string();
+        request.releases.truncate(1);
+
+        let mut output = Vec::new();
+        DDEXBuilder::new()
+            .build_to_writer(request, &mut output, StreamingConfig::default())
+            .unwrap();
+
+        let xml = String::from_utf8(output).unwrap();
+        assert!(
+            xml.contains(r#"MessageSchemaVersionId="ern/43""#),
+            "{}",
+            version
+        );
+    }
+}
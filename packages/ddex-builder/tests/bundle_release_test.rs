@@ -0,0 +1,107 @@
+use ddex_builder::builder::{BuildRequest, LocalizedStringRequest, ReleaseRequest, TrackRequest};
+use ddex_builder::{BuildError, BuildOptions, DDEXBuilder};
+use ddex_core::models::graph::HAS_COMPONENT_RELEASE;
+use ddex_parser::transform::graph::GraphBuilder;
+use ddex_parser::ERNVersion;
+use std::io::Cursor;
+
+mod common;
+use common::header;
+
+fn release(index: usize, tracks: Vec<TrackRequest>, components: &[&str]) -> ReleaseRequest {
+    ReleaseRequest {
+        release_id: format!("A10301A000000000{}A", index),
+        release_reference: Some(format!("R{}", index)),
+        title: vec![LocalizedStringRequest {
+            text: format!("Disc {}", index),
+            language_code: None,
+        }],
+        artist: "Artist".to_string(),
+        release_date: Some("2024-01-01".to_string()),
+        tracks,
+        component_release_references: components.iter().map(|c| c.to_string()).collect(),
+        ..Default::default()
+    }
+}
+
+fn track(index: usize) -> TrackRequest {
+    TrackRequest {
+        track_id: format!("TRK00{}", index),
+        resource_reference: Some(format!("A{}", index)),
+        isrc: format!("USRC1760783{}", index),
+        title: format!("Track {}", index),
+        duration: "PT3M00S".to_string(),
+        artist: "Artist".to_string(),
+        ..Default::default()
+    }
+}
+
+fn box_set_request(components: &[&str]) -> BuildRequest {
+    BuildRequest {
+        header: header("MSG_BUNDLE_001"),
+        version: "4.3".to_string(),
+        profile: None,
+        releases: vec![
+            release(0, vec![], components),
+            release(1, vec![track(1)], &[]),
+            release(2, vec![track(2)], &[]),
+        ],
+        deals: vec![],
+        extensions: None,
+    }
+}
+
+#[test]
+fn test_bundle_hierarchy_round_trips() {
+    let xml = DDEXBuilder::new()
+        .build(box_set_request(&["R1", "R2"]), BuildOptions::default())
+        .unwrap()
+        .xml;
+    assert_eq!(
+        xml.matches("<ReleaseRelationshipType>HasComponentRelease</ReleaseRelationshipType>")
+            .count(),
+        2
+    );
+
+    let message = GraphBuilder::new(ERNVersion::V4_3)
+        .build_from_xml(Cursor::new(xml.as_bytes()))
+        .unwrap();
+    let (bundle, components) = message.releases.split_first().unwrap();
+    assert_eq!(bundle.release_reference, "R0");
+
+    // Each component is named by its release ID, in order
+    let named: Vec<&str> = bundle
+        .related_releases
+        .iter()
+        .filter(|related| related.relationship_type == HAS_COMPONENT_RELEASE)
+        .map(|related| related.release_id[0].value.as_str())
+        .collect();
+    let expected: Vec<&str> = components
+        .iter()
+        .map(|component| component.release_id[0].value.as_str())
+        .collect();
+    assert_eq!(named, expected);
+    assert_eq!(
+        components
+            .iter()
+            .map(|component| component.release_reference.as_str())
+            .collect::<Vec<_>>(),
+        ["R1", "R2"]
+    );
+    assert!(components
+        .iter()
+        .all(|component| component.related_releases.is_empty()));
+}
+
+#[test]
+fn test_unknown_component_release_is_rejected() {
+    let result = DDEXBuilder::new().build(box_set_request(&["R1", "R9"]), BuildOptions::default());
+
+    match result {
+        Err(BuildError::InvalidReference { reference }) => assert_eq!(reference, "R9"),
+        other => panic!(
+            "expected an invalid reference, got {:?}",
+            other.map(|r| r.xml)
+        ),
+    }
+}
@@ -0,0 +1,40 @@
+use ddex_builder::determinism::DeterminismConfig;
+use ddex_builder::{DDEXBuilder, DdexVersion, DB_C14N, DB_C14N_VERSION};
+
+#[test]
+fn test_capabilities_list_versions_and_message_types() {
+    let capabilities = DDEXBuilder::new().capabilities();
+
+    assert_eq!(
+        capabilities.versions,
+        vec![DdexVersion::Ern382, DdexVersion::Ern42, DdexVersion::Ern43]
+    );
+    assert_eq!(
+        capabilities.message_types,
+        vec![
+            "NewReleaseMessage",
+            "UpdateReleaseMessage",
+            "FtpAcknowledgementMessage"
+        ]
+    );
+    assert_eq!(
+        capabilities.features.contains(&"async".to_string()),
+        cfg!(feature = "async")
+    );
+}
+
+#[test]
+fn test_capabilities_report_only_implemented_canonicalization() {
+    let capabilities = DDEXBuilder::new().capabilities();
+
+    // C14N and C14N 1.1 are placeholders, so only DB-C14N is reported
+    assert_eq!(
+        capabilities.canonicalization,
+        vec![format!("DB-C14N/{}", DB_C14N_VERSION)]
+    );
+
+    let canonical = DB_C14N::new(DeterminismConfig::default())
+        .canonicalize(r#"<Root b="2" a="1"><Child/></Root>"#)
+        .unwrap();
+    assert!(canonical.contains(r#"<Root a="1" b="2">"#));
+}
@@ -0,0 +1,116 @@
+use ddex_builder::builder::{
+    BuildRequest, ContributorRequest, LocalizedStringRequest, ReleaseRequest, TrackRequest,
+};
+use ddex_builder::{BuildOptions, DDEXBuilder};
+use ddex_parser::transform::graph::GraphBuilder;
+use ddex_parser::ERNVersion;
+use std::io::Cursor;
+
+mod common;
+use common::header;
+
+fn contributor(name: &str, roles: &[&str]) -> ContributorRequest {
+    ContributorRequest {
+        name: name.to_string(),
+        roles: roles.iter().map(|role| role.to_string()).collect(),
+        instruments: vec![],
+    }
+}
+
+fn symphony_request() -> BuildRequest {
+    BuildRequest {
+        header: header("MSG_CLASSICAL_001"),
+        version: "4.3".to_string(),
+        profile: None,
+        releases: vec![ReleaseRequest {
+            release_id: "A10301A0000000001A".to_string(),
+            release_reference: Some("R1".to_string()),
+            title: vec![LocalizedStringRequest {
+                text: "Symphony No. 5".to_string(),
+                language_code: None,
+            }],
+            artist: "Berlin Philharmonic".to_string(),
+            tracks: vec![TrackRequest {
+                track_id: "TRK001".to_string(),
+                resource_reference: Some("A1".to_string()),
+                isrc: "USRC17607839".to_string(),
+                title: "I. Allegro con brio".to_string(),
+                duration: "PT7M21S".to_string(),
+                artist: "Berlin Philharmonic".to_string(),
+                contributors: vec![
+                    contributor("Herbert von Karajan", &["Conductor"]),
+                    contributor("Berlin Philharmonic", &["Orchestra"]),
+                    contributor("Anne-Sophie Mutter", &["Soloist", "Violinist"]),
+                ],
+                ..Default::default()
+            }],
+            ..Default::default()
+        }],
+        deals: vec![],
+        extensions: None,
+    }
+}
+
+#[test]
+fn test_conductor_and_orchestra_round_trip_distinctly() {
+    let xml = DDEXBuilder::new()
+        .build(symphony_request(), BuildOptions::default())
+        .unwrap()
+        .xml;
+    assert!(xml.contains("<Role>Conductor</Role>"));
+    assert!(xml.contains("<Role>Orchestra</Role>"));
+
+    let message = GraphBuilder::new(ERNVersion::V4_3)
+        .build_from_xml(Cursor::new(xml.as_bytes()))
+        .unwrap();
+    let credits: Vec<(&str, Vec<&str>)> = message.resources[0]
+        .contributors
+        .iter()
+        .map(|contributor| {
+            (
+                contributor.party_name[0].text.as_str(),
+                contributor.role.iter().map(String::as_str).collect(),
+            )
+        })
+        .collect();
+
+    assert_eq!(
+        credits,
+        vec![
+            ("Herbert von Karajan", vec!["Conductor"]),
+            ("Berlin Philharmonic", vec!["Orchestra"]),
+            ("Anne-Sophie Mutter", vec!["Soloist", "Violinist"]),
+        ]
+    );
+    // Contributors are credits, not display artists
+    let display_artists = &message.resources[0].display_artist;
+    assert_eq!(display_artists.len(), 1);
+    assert_eq!(
+        display_artists[0].display_artist_name[0].text,
+        "Berlin Philharmonic"
+    );
+}
+
+#[test]
+fn test_performer_instrument_round_trips() {
+    let mut request = symphony_request();
+    request.releases[0].tracks[0].contributors = vec![ContributorRequest {
+        name: "Jimmy Page".to_string(),
+        roles: vec!["Guitarist".to_string()],
+        instruments: vec!["Guitar".to_string()],
+    }];
+
+    let xml = DDEXBuilder::new()
+        .build(request, BuildOptions::default())
+        .unwrap()
+        .xml;
+    assert!(xml.contains("<InstrumentType>Guitar</InstrumentType>"));
+
+    let message = GraphBuilder::new(ERNVersion::V4_3)
+        .build_from_xml(Cursor::new(xml.as_bytes()))
+        .unwrap();
+    let performer = &message.resources[0].contributors[0];
+    assert_eq!(performer.party_name[0].text, "Jimmy Page");
+    assert_eq!(performer.role, vec!["Guitarist"]);
+    assert_eq!(performer.instrument_type, vec!["Guitar"]);
+}
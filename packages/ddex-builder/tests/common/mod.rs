@@ -0,0 +1,40 @@
+//! Factories shared by the integration tests; each test binary uses only
+//! some of them
+#![allow(dead_code)]
+
+use ddex_builder::builder::{
+    BuildRequest, LocalizedStringRequest, MessageHeaderRequest, PartyRequest, ReleaseRequest,
+};
+
+/// A party known only by name
+pub fn party(name: &str) -> PartyRequest {
+    PartyRequest {
+        party_name: vec![LocalizedStringRequest {
+            text: name.to_string(),
+            language_code: None,
+        }],
+        ..Default::default()
+    }
+}
+
+/// A header from "Sender" to "Recipient"
+pub fn header(message_id: &str) -> MessageHeaderRequest {
+    MessageHeaderRequest {
+        message_id: Some(message_id.to_string()),
+        message_sender: party("Sender"),
+        message_recipient: party("Recipient"),
+        ..Default::default()
+    }
+}
+
+/// An ERN 4.3 message of `releases` with no deals
+pub fn request(message_id: &str, releases: Vec<ReleaseRequest>) -> BuildRequest {
+    BuildRequest {
+        header: header(message_id),
+        version: "4.3".to_string(),
+        profile: None,
+        releases,
+        deals: vec![],
+        extensions: None,
+    }
+}
@@ -0,0 +1,100 @@
+use ddex_builder::builder::{
+    BuildRequest, CreatedDateTimeOptions, LocalizedStringRequest, ReleaseRequest, TrackRequest,
+};
+use ddex_builder::{BuildOptions, DDEXBuilder};
+
+mod common;
+use common::header;
+
+fn request() -> BuildRequest {
+    BuildRequest {
+        header: header("MSG_CREATED_001"),
+        version: "4.3".to_string(),
+        profile: None,
+        releases: vec![ReleaseRequest {
+            release_id: "REL001".to_string(),
+            release_reference: Some("R1".to_string()),
+            title: vec![LocalizedStringRequest {
+                text: "Album".to_string(),
+                language_code: None,
+            }],
+            artist: "Artist".to_string(),
+            tracks: vec![TrackRequest {
+                track_id: "T1".to_string(),
+                resource_reference: Some("A1".to_string()),
+                isrc: "USRC17607839".to_string(),
+                title: "Track".to_string(),
+                duration: "PT3M00S".to_string(),
+                artist: "Artist".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }],
+        deals: vec![],
+        extensions: None,
+    }
+}
+
+fn created_date_time(created_date_time: CreatedDateTimeOptions) -> String {
+    let options = BuildOptions {
+        created_date_time,
+        ..BuildOptions::default()
+    };
+    let xml = DDEXBuilder::new().build(request(), options).unwrap().xml;
+    let start = xml.find("<MessageCreatedDateTime>").unwrap() + "<MessageCreatedDateTime>".len();
+    let end = xml.find("</MessageCreatedDateTime>").unwrap();
+    xml[start..end].to_string()
+}
+
+#[test]
+fn test_fixed_created_date_time_is_emitted_as_given() {
+    let created = created_date_time(CreatedDateTimeOptions {
+        value: Some("2024-01-15T10:00:00Z".to_string()),
+        ..Default::default()
+    });
+
+    assert_eq!(created, "2024-01-15T10:00:00Z");
+}
+
+#[test]
+fn test_created_date_time_in_a_partner_timezone() {
+    let created = created_date_time(CreatedDateTimeOptions {
+        value: Some("2024-01-15T10:00:00Z".to_string()),
+        utc_offset: Some("+09:00".to_string()),
+        ..Default::default()
+    });
+
+    assert_eq!(created, "2024-01-15T19:00:00+09:00");
+}
+
+#[test]
+fn test_fractional_seconds_can_be_suppressed() {
+    let fixed = created_date_time(CreatedDateTimeOptions {
+        value: Some("2024-01-15T10:00:00.123456Z".to_string()),
+        omit_fractional_seconds: true,
+        ..Default::default()
+    });
+    assert_eq!(fixed, "2024-01-15T10:00:00Z");
+
+    // The current time too is cut to whole seconds
+    let now = created_date_time(CreatedDateTimeOptions {
+        omit_fractional_seconds: true,
+        ..Default::default()
+    });
+    let parsed = chrono::DateTime::parse_from_rfc3339(&now).unwrap();
+    assert!(!now.contains('.'), "{}", now);
+    assert_eq!(parsed.timestamp_subsec_nanos(), 0);
+}
+
+#[test]
+fn test_invalid_created_date_time_fails_the_build() {
+    let options = BuildOptions {
+        created_date_time: CreatedDateTimeOptions {
+            value: Some("15/01/2024".to_string()),
+            ..Default::default()
+        },
+        ..BuildOptions::default()
+    };
+
+    assert!(DDEXBuilder::new().build(request(), options).is_err());
+}
@@ -31,6 +31,7 @@ fn create_platform_agnostic_request() -> BuildRequest {
                 }],
                 party_id: Some("SENDER001".to_string()),
                 party_reference: Some("REF_SENDER".to_string()),
+                ..Default::default()
             },
             message_recipient: PartyRequest {
                 party_name: vec![LocalizedStringRequest {
@@ -39,10 +40,12 @@ fn create_platform_agnostic_request() -> BuildRequest {
                 }],
                 party_id: Some("RECIPIENT001".to_string()),
                 party_reference: Some("REF_RECIPIENT".to_string()),
+                ..Default::default()
             },
             message_control_type: Some("NewReleaseMessage".to_string()),
             // Use fixed timestamp for deterministic results
             message_created_date_time: Some("2024-01-01T12:00:00.000Z".to_string()),
+            message_thread_id: None,
         },
         version: "ern/43".to_string(),
         profile: Some("PlatformTestProfile".to_string()),
@@ -57,8 +60,7 @@ fn create_platform_agnostic_request() -> BuildRequest {
             label: Some("Platform Records".to_string()),
             release_date: Some("2024-01-01".to_string()),
             upc: Some("123456789012".to_string()),
-            tracks: Vec::new(),
-            resource_references: None,
+            ..Default::default()
         }],
         deals: vec![DealRequest {
             deal_reference: Some("PLAT_DEAL001".to_string()),
@@ -66,6 +68,7 @@ fn create_platform_agnostic_request() -> BuildRequest {
                 commercial_model_type: "FreeOfChargeModel".to_string(),
                 territory_code: vec!["Worldwide".to_string()],
                 start_date: Some("2024-01-01".to_string()),
+                ..Default::default()
             },
             release_references: vec!["PLAT_REL001".to_string()],
         }],
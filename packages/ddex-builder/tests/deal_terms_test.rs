@@ -0,0 +1,133 @@
+use ddex_builder::builder::{
+    BuildRequest, DealPrice, DealRequest, DealTerms, LocalizedStringRequest, ReleaseRequest,
+    TrackRequest,
+};
+use ddex_builder::{BuildOptions, DDEXBuilder};
+use ddex_core::models::graph::UseType;
+use ddex_parser::transform::graph::GraphBuilder;
+use ddex_parser::ERNVersion;
+use std::io::Cursor;
+
+mod common;
+use common::header;
+
+fn price(price_type: &str, amount: &str, currency_code: &str) -> DealPrice {
+    DealPrice {
+        price_type: price_type.to_string(),
+        amount: amount.to_string(),
+        currency_code: currency_code.to_string(),
+    }
+}
+
+fn tiered_deal_request() -> BuildRequest {
+    BuildRequest {
+        header: header("MSG_DEAL_TERMS_001"),
+        version: "4.3".to_string(),
+        profile: None,
+        releases: vec![ReleaseRequest {
+            release_id: "A10301A0000000001A".to_string(),
+            release_reference: Some("R1".to_string()),
+            title: vec![LocalizedStringRequest {
+                text: "Tiered".to_string(),
+                language_code: None,
+            }],
+            artist: "Artist".to_string(),
+            release_date: Some("2024-06-07".to_string()),
+            tracks: vec![TrackRequest {
+                track_id: "TRK001".to_string(),
+                resource_reference: Some("A1".to_string()),
+                isrc: "USRC17607839".to_string(),
+                title: "Track".to_string(),
+                duration: "PT3M00S".to_string(),
+                artist: "Artist".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }],
+        deals: vec![DealRequest {
+            deal_reference: Some("D1".to_string()),
+            deal_terms: DealTerms {
+                commercial_model_type: "PayAsYouGoModel".to_string(),
+                territory_code: vec!["Worldwide".to_string()],
+                use_types: vec!["Stream".to_string(), "ConditionalDownload".to_string()],
+                prices: vec![
+                    price("PreOrder", "7.99", "USD"),
+                    price("Release", "9.99", "USD"),
+                    price("PreOrder", "6.99", "EUR"),
+                    price("Release", "8.99", "EUR"),
+                ],
+                ..Default::default()
+            },
+            release_references: vec!["R1".to_string()],
+        }],
+        extensions: None,
+    }
+}
+
+#[test]
+fn test_use_types_and_price_tiers_round_trip() {
+    let xml = DDEXBuilder::new()
+        .build(tiered_deal_request(), BuildOptions::default())
+        .unwrap()
+        .xml;
+    assert!(xml.contains("<UseType>Stream</UseType>"));
+    assert!(xml.contains("<UseType>ConditionalDownload</UseType>"));
+    assert!(xml.contains("<PriceType>PreOrder</PriceType>"));
+    assert!(xml.contains("<PriceType>Release</PriceType>"));
+    assert!(
+        xml.contains(r#"<WholesalePricePerUnit CurrencyCode="USD">7.99</WholesalePricePerUnit>"#)
+    );
+    assert!(
+        xml.contains(r#"<WholesalePricePerUnit CurrencyCode="EUR">8.99</WholesalePricePerUnit>"#)
+    );
+
+    let message = GraphBuilder::new(ERNVersion::V4_3)
+        .build_from_xml(Cursor::new(xml.as_bytes()))
+        .unwrap();
+    let deal = &message.deals[0];
+    assert_eq!(deal.deal_reference.as_deref(), Some("D1"));
+    assert_eq!(deal.deal_release_reference, ["R1"]);
+    assert_eq!(deal.deal_terms.territory_code, ["Worldwide"]);
+    assert_eq!(
+        deal.deal_terms.use_type,
+        [
+            UseType::Stream,
+            UseType::Other("ConditionalDownload".to_string())
+        ]
+    );
+
+    let tiers: Vec<_> = deal
+        .deal_terms
+        .price_information
+        .iter()
+        .map(|info| {
+            (
+                info.price_type.as_str(),
+                info.price.amount,
+                info.price.currency.as_str(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        tiers,
+        [
+            ("PreOrder", 7.99, "USD"),
+            ("Release", 9.99, "USD"),
+            ("PreOrder", 6.99, "EUR"),
+            ("Release", 8.99, "EUR"),
+        ]
+    );
+}
+
+#[test]
+fn test_deal_terms_default_to_no_use_types_or_prices() {
+    let terms: DealTerms = serde_json::from_value(serde_json::json!({
+        "commercial_model_type": "SubscriptionModel",
+        "territory_code": ["US"],
+        "start_date": null
+    }))
+    .unwrap();
+
+    assert!(terms.use_types.is_empty());
+    assert!(terms.prices.is_empty());
+}
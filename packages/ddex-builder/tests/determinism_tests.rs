@@ -28,19 +28,18 @@ fn create_test_build_request() -> BuildRequest {
                     text: "Test Sender".to_string(),
                     language_code: None,
                 }],
-                party_id: None,
-                party_reference: None,
+                ..Default::default()
             },
             message_recipient: PartyRequest {
                 party_name: vec![LocalizedStringRequest {
                     text: "Test Recipient".to_string(),
                     language_code: None,
                 }],
-                party_id: None,
-                party_reference: None,
+                ..Default::default()
             },
             message_control_type: Some("NewReleaseMessage".to_string()),
             message_created_date_time: Some(chrono::Utc::now().to_rfc3339()),
+            message_thread_id: None,
         },
         version: "ern/43".to_string(),
         profile: Some("BasicProfile".to_string()),
@@ -52,11 +51,8 @@ fn create_test_build_request() -> BuildRequest {
                 language_code: None,
             }],
             artist: "Test Artist".to_string(),
-            label: None,
             release_date: Some("2024-01-01".to_string()),
-            upc: None,
-            tracks: Vec::new(),
-            resource_references: None,
+            ..Default::default()
         }],
         deals: vec![DealRequest {
             deal_reference: Some("DEAL001".to_string()),
@@ -64,6 +60,7 @@ fn create_test_build_request() -> BuildRequest {
                 commercial_model_type: "FreeOfChargeModel".to_string(),
                 territory_code: vec!["Worldwide".to_string()],
                 start_date: Some("2024-01-01".to_string()),
+                ..Default::default()
             },
             release_references: vec!["REL001".to_string()],
         }],
@@ -101,19 +98,18 @@ fn create_complex_build_request() -> BuildRequest {
                     text: "Complex Test Sender".to_string(),
                     language_code: None,
                 }],
-                party_id: None,
-                party_reference: None,
+                ..Default::default()
             },
             message_recipient: PartyRequest {
                 party_name: vec![LocalizedStringRequest {
                     text: "Complex Test Recipient".to_string(),
                     language_code: None,
                 }],
-                party_id: None,
-                party_reference: None,
+                ..Default::default()
             },
             message_control_type: Some("NewReleaseMessage".to_string()),
             message_created_date_time: Some(chrono::Utc::now().to_rfc3339()),
+            message_thread_id: None,
         },
         version: "ern/43".to_string(),
         profile: Some("ComplexProfile".to_string()),
@@ -128,8 +124,7 @@ fn create_complex_build_request() -> BuildRequest {
             label: Some("Test Label".to_string()),
             release_date: Some("2024-01-01".to_string()),
             upc: Some("123456789012".to_string()),
-            tracks: Vec::new(),
-            resource_references: None,
+            ..Default::default()
         }],
         deals: (0..5)
             .map(|i| DealRequest {
@@ -138,6 +133,7 @@ fn create_complex_build_request() -> BuildRequest {
                     commercial_model_type: "FreeOfChargeModel".to_string(),
                     territory_code: vec!["Worldwide".to_string()],
                     start_date: Some("2024-01-01".to_string()),
+                    ..Default::default()
                 },
                 release_references: vec![format!("REL{:04}", i)],
             })
@@ -410,8 +406,7 @@ fn test_large_dataset_determinism() {
             label: Some(format!("Label {}", i % 5)),
             release_date: Some("2024-01-01".to_string()),
             upc: Some(format!("{:012}", i)),
-            tracks: Vec::new(),
-            resource_references: None,
+            ..Default::default()
         }
     }).collect();
 
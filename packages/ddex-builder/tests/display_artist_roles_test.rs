@@ -0,0 +1,69 @@
+use ddex_builder::builder::{
+    BuildRequest, DisplayArtistRequest, LocalizedStringRequest, ReleaseRequest,
+};
+use ddex_builder::{BuildOptions, DDEXBuilder};
+use ddex_parser::transform::graph::GraphBuilder;
+use ddex_parser::ERNVersion;
+use std::io::Cursor;
+
+mod common;
+use common::header;
+
+fn artist(name: &str, role: &str) -> DisplayArtistRequest {
+    DisplayArtistRequest {
+        name: name.to_string(),
+        role: role.to_string(),
+    }
+}
+
+#[test]
+fn test_display_artist_roles_round_trip() {
+    let request = BuildRequest {
+        header: header("ROLES_001"),
+        version: "4.3".to_string(),
+        profile: None,
+        releases: vec![ReleaseRequest {
+            release_id: "REL001".to_string(),
+            release_reference: Some("R1".to_string()),
+            title: vec![LocalizedStringRequest {
+                text: "Duet".to_string(),
+                language_code: None,
+            }],
+            artist: "A feat. B".to_string(),
+            display_artists: vec![artist("A", "MainArtist"), artist("B", "FeaturedArtist")],
+            ..Default::default()
+        }],
+        deals: vec![],
+        extensions: None,
+    };
+
+    let xml = DDEXBuilder::new()
+        .build(request, BuildOptions::default())
+        .unwrap()
+        .xml;
+
+    assert!(xml.contains(r#"<DisplayArtist SequenceNumber="2">"#));
+    assert!(xml.contains("<DisplayArtistRole>FeaturedArtist</DisplayArtistRole>"));
+
+    let message = GraphBuilder::new(ERNVersion::V4_3)
+        .build_from_xml(Cursor::new(xml.as_bytes()))
+        .unwrap();
+    let artists: Vec<_> = message.releases[0]
+        .display_artist
+        .iter()
+        .map(|artist| {
+            (
+                artist.display_artist_name[0].text.as_str(),
+                artist.artist_role.as_slice(),
+            )
+        })
+        .collect();
+
+    assert_eq!(
+        artists,
+        [
+            ("A", ["MainArtist".to_string()].as_slice()),
+            ("B", ["FeaturedArtist".to_string()].as_slice())
+        ]
+    );
+}
@@ -0,0 +1,80 @@
+use ddex_builder::builder::{
+    BuildRequest, LocalizedStringRequest, MessageHeaderRequest, PartyRequest, ReleaseRequest,
+};
+use ddex_builder::{BuildOptions, DDEXBuilder};
+use ddex_parser::transform::graph::GraphBuilder;
+use ddex_parser::ERNVersion;
+use std::io::Cursor;
+
+fn localized(text: &str, language_code: Option<&str>) -> LocalizedStringRequest {
+    LocalizedStringRequest {
+        text: text.to_string(),
+        language_code: language_code.map(str::to_string),
+    }
+}
+
+fn party(name: &str) -> PartyRequest {
+    PartyRequest {
+        party_name: vec![localized(name, None)],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_reference_and_display_titles_round_trip() {
+    let request = BuildRequest {
+        header: MessageHeaderRequest {
+            message_id: Some("TITLES_001".to_string()),
+            message_sender: party("Sender"),
+            message_recipient: party("Recipient"),
+            ..Default::default()
+        },
+        version: "4.3".to_string(),
+        profile: None,
+        releases: vec![ReleaseRequest {
+            release_id: "REL001".to_string(),
+            release_reference: Some("R1".to_string()),
+            title: vec![localized("KAZE_NO_UTA_MASTER_v2", None)],
+            display_title: vec![
+                localized("Song of the Wind", Some("en")),
+                localized("風の歌", Some("ja")),
+            ],
+            artist: "Artist".to_string(),
+            ..Default::default()
+        }],
+        deals: vec![],
+        extensions: None,
+    };
+
+    let xml = DDEXBuilder::new()
+        .build(request, BuildOptions::default())
+        .unwrap()
+        .xml;
+
+    assert!(xml.contains("<ReferenceTitle>"));
+    assert!(xml.contains(r#"<DisplayTitle LanguageAndScriptCode="ja">"#));
+
+    let message = GraphBuilder::new(ERNVersion::V4_3)
+        .build_from_xml(Cursor::new(xml.as_bytes()))
+        .unwrap();
+    let release = &message.releases[0];
+
+    assert_eq!(release.reference_title.len(), 1);
+    assert_eq!(release.reference_title[0].text, "KAZE_NO_UTA_MASTER_v2");
+    assert_eq!(release.reference_title[0].language_code, None);
+
+    assert_eq!(release.display_title.len(), 2);
+    assert_eq!(release.display_title[0].text, "Song of the Wind");
+    assert_eq!(
+        release.display_title[0].language_code.as_deref(),
+        Some("en")
+    );
+    assert_eq!(release.display_title[1].text, "風の歌");
+    assert_eq!(
+        release.display_title[1].language_code.as_deref(),
+        Some("ja")
+    );
+
+    // The primary title stays the reference title
+    assert_eq!(release.release_title[0].text, "KAZE_NO_UTA_MASTER_v2");
+}
@@ -0,0 +1,69 @@
+use ddex_builder::builder::{BuildRequest, LocalizedStringRequest, ReleaseRequest};
+use ddex_builder::preflight::{PreflightValidator, ValidationConfig};
+use ddex_builder::{BuildOptions, DDEXBuilder};
+
+mod common;
+
+fn release(reference: &str, resource_references: &[&str]) -> ReleaseRequest {
+    ReleaseRequest {
+        release_id: format!("REL_{}", reference),
+        release_reference: Some(reference.to_string()),
+        title: vec![LocalizedStringRequest {
+            text: "Album".to_string(),
+            language_code: None,
+        }],
+        artist: "Artist".to_string(),
+        resource_references: Some(resource_references.iter().map(|r| r.to_string()).collect()),
+        ..Default::default()
+    }
+}
+
+fn request(releases: Vec<ReleaseRequest>) -> BuildRequest {
+    common::request("DUP_REF_001", releases)
+}
+
+fn duplicate_warnings(request: &BuildRequest) -> Vec<String> {
+    PreflightValidator::new(ValidationConfig::default())
+        .validate(request)
+        .unwrap()
+        .warnings
+        .into_iter()
+        .filter(|w| w.code == "DUPLICATE_RESOURCE_REFERENCE")
+        .map(|w| w.location)
+        .collect()
+}
+
+#[test]
+fn test_resource_listed_twice_in_release_is_reported() {
+    let request = request(vec![release("R1", &["A1", "A2", "A1"])]);
+
+    assert_eq!(
+        duplicate_warnings(&request),
+        ["/releases[0]/resource_references[2]"]
+    );
+}
+
+#[test]
+fn test_resource_shared_across_releases_is_not_reported() {
+    let request = request(vec![release("R1", &["A1", "A2"]), release("R2", &["A1"])]);
+
+    assert!(duplicate_warnings(&request).is_empty());
+}
+
+#[test]
+fn test_duplicate_reference_surfaces_as_build_warning() {
+    let result = DDEXBuilder::new()
+        .build(
+            request(vec![release("R1", &["A1", "A1", "A1"])]),
+            BuildOptions::default(),
+        )
+        .unwrap();
+
+    let duplicates: Vec<_> = result
+        .warnings
+        .iter()
+        .filter(|w| w.code == "DUPLICATE_RESOURCE_REFERENCE")
+        .collect();
+    assert_eq!(duplicates.len(), 1);
+    assert!(duplicates[0].message.contains("A1"));
+}
@@ -0,0 +1,124 @@
+use ddex_builder::{BuildOptions, DDEXBuilder};
+use ddex_core::models::graph::MessageControlType;
+use ddex_parser::transform::graph::GraphBuilder;
+use ddex_parser::{DDEXParser, ERNVersion};
+use std::io::Cursor;
+
+// The playground's ERN 4.3 sample, marked as a test message
+const PLAYGROUND_ERN43: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/43" MessageSchemaVersionId="ern/43" LanguageAndScriptCode="en">
+  <MessageHeader>
+    <MessageThreadId>PLAYGROUND_MSG_001</MessageThreadId>
+    <MessageId>MSG_PLAYGROUND_2024</MessageId>
+    <MessageSender>
+      <PartyId>PLAYGROUND_LABEL</PartyId>
+      <PartyName><FullName>Playground Record Label</FullName></PartyName>
+    </MessageSender>
+    <MessageRecipient>
+      <PartyId>PLAYGROUND_DSP</PartyId>
+      <PartyName><FullName>Playground Streaming Platform</FullName></PartyName>
+    </MessageRecipient>
+    <MessageCreatedDateTime>2024-01-15T10:00:00Z</MessageCreatedDateTime>
+    <MessageControlType>TestMessage</MessageControlType>
+  </MessageHeader>
+  <ReleaseList>
+    <Release>
+      <ReleaseReference>R1</ReleaseReference>
+      <ReleaseType>Single</ReleaseType>
+      <ReleaseId>
+        <GRid>A1-PLAYGROUND-GRID-001</GRid>
+      </ReleaseId>
+      <ReferenceTitle>
+        <TitleText>Sample Track Release</TitleText>
+      </ReferenceTitle>
+    </Release>
+  </ReleaseList>
+  <ResourceList>
+    <SoundRecording>
+      <ResourceReference>A1</ResourceReference>
+      <SoundRecordingId>
+        <ISRC>USPLAYG240001</ISRC>
+      </SoundRecordingId>
+      <ReferenceTitle>
+        <TitleText>Sample Track</TitleText>
+      </ReferenceTitle>
+    </SoundRecording>
+  </ResourceList>
+  <DealList>
+    <ReleaseDeal>
+      <DealReleaseReference>R1</DealReleaseReference>
+      <Deal>
+        <DealReference>D1</DealReference>
+        <TerritoryCode>Worldwide</TerritoryCode>
+        <StartDate>2024-01-15</StartDate>
+      </Deal>
+    </ReleaseDeal>
+  </DealList>
+</ern:NewReleaseMessage>"#;
+
+#[test]
+fn test_parsed_header_survives_rebuild() {
+    let parsed = DDEXParser::new()
+        .parse(Cursor::new(PLAYGROUND_ERN43.as_bytes()))
+        .unwrap();
+    let request = DDEXBuilder::from_parsed(&parsed);
+
+    let header = &request.header;
+    assert_eq!(header.message_id.as_deref(), Some("MSG_PLAYGROUND_2024"));
+    assert_eq!(
+        header.message_thread_id.as_deref(),
+        Some("PLAYGROUND_MSG_001")
+    );
+    assert_eq!(
+        header.message_sender.party_id.as_deref(),
+        Some("PLAYGROUND_LABEL")
+    );
+    assert_eq!(
+        header.message_recipient.party_id.as_deref(),
+        Some("PLAYGROUND_DSP")
+    );
+    assert_eq!(header.message_control_type.as_deref(), Some("TestMessage"));
+    assert_eq!(request.version, "4.3");
+    assert_eq!(request.releases[0].release_id, "A1-PLAYGROUND-GRID-001");
+
+    let xml = DDEXBuilder::new()
+        .build(request, BuildOptions::default())
+        .unwrap()
+        .xml;
+    let rebuilt = GraphBuilder::new(ERNVersion::V4_3)
+        .build_from_xml(Cursor::new(xml.as_bytes()))
+        .unwrap()
+        .message_header;
+    let original = &parsed.graph.message_header;
+
+    assert_eq!(rebuilt.message_id, original.message_id);
+    assert_eq!(rebuilt.message_thread_id, original.message_thread_id);
+    assert_eq!(
+        rebuilt.message_created_date_time,
+        original.message_created_date_time
+    );
+    assert_eq!(
+        rebuilt.message_control_type,
+        Some(MessageControlType::TestMessage)
+    );
+    for (rebuilt, original) in [
+        (
+            &rebuilt.message_sender.party_id,
+            &original.message_sender.party_id,
+        ),
+        (
+            &rebuilt.message_recipient.party_id,
+            &original.message_recipient.party_id,
+        ),
+    ] {
+        assert_eq!(rebuilt[0].value, original[0].value);
+    }
+    assert_eq!(
+        rebuilt.message_sender.party_name[0].text,
+        "Playground Record Label"
+    );
+    assert_eq!(
+        rebuilt.message_recipient.party_name[0].text,
+        "Playground Streaming Platform"
+    );
+}
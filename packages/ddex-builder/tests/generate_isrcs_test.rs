@@ -0,0 +1,34 @@
+use ddex_builder::preflight::is_valid_isrc;
+use ddex_builder::{generate_isrcs, BuildError};
+use std::collections::HashSet;
+
+#[test]
+fn test_isrcs_are_sequential_valid_and_unique() {
+    let isrcs = generate_isrcs("us-abc", 5, 2024).unwrap();
+
+    assert_eq!(
+        isrcs,
+        [
+            "USABC2400001",
+            "USABC2400002",
+            "USABC2400003",
+            "USABC2400004",
+            "USABC2400005",
+        ]
+    );
+    assert!(isrcs.iter().all(|isrc| is_valid_isrc(isrc)));
+    assert_eq!(isrcs.iter().collect::<HashSet<_>>().len(), 5);
+}
+
+#[test]
+fn test_malformed_prefix_is_rejected() {
+    for prefix in ["US-AB", "1S-ABC", "US-ABCD", "US-AB!"] {
+        let err = generate_isrcs(prefix, 5, 24).unwrap_err();
+        assert!(
+            matches!(err, BuildError::InvalidFormat { ref field, .. } if field == "prefix"),
+            "{} gave {:?}",
+            prefix,
+            err
+        );
+    }
+}
@@ -19,19 +19,18 @@ fn test_audio_album_golden() {
                     text: "Test Sender".to_string(),
                     language_code: None,
                 }],
-                party_id: None,
-                party_reference: None,
+                ..Default::default()
             },
             message_recipient: PartyRequest {
                 party_name: vec![LocalizedStringRequest {
                     text: "Test Recipient".to_string(),
                     language_code: None,
                 }],
-                party_id: None,
-                party_reference: None,
+                ..Default::default()
             },
             message_control_type: Some("LiveMessage".to_string()),
             message_created_date_time: Some("2025-01-01T00:00:00Z".to_string()), // Fixed timestamp
+            message_thread_id: None,
         },
         version: "4.3".to_string(),
         profile: Some("AudioAlbum".to_string()),
@@ -54,6 +53,7 @@ fn test_audio_album_golden() {
                     title: "Track One".to_string(),
                     duration: "PT3M45S".to_string(),
                     artist: "Test Artist".to_string(),
+                    ..Default::default()
                 },
                 TrackRequest {
                     track_id: "TRK002".to_string(),
@@ -62,9 +62,10 @@ fn test_audio_album_golden() {
                     title: "Track Two".to_string(),
                     duration: "PT4M20S".to_string(),
                     artist: "Test Artist feat. Guest".to_string(),
+                    ..Default::default()
                 },
             ],
-            resource_references: None,
+            ..Default::default()
         }],
         deals: vec![],
         extensions: None,
@@ -74,7 +75,7 @@ fn test_audio_album_golden() {
         determinism: Some(ddex_builder::DeterminismConfig::default()),
         preflight_level: ddex_builder::preflight::PreflightLevel::Warn,
         id_strategy: IdStrategy::StableHash,
-        stable_hash_config: None,
+        ..Default::default()
     };
 
     let result = builder.build(request, options).unwrap();
@@ -100,6 +101,18 @@ fn test_deterministic_generation() {
         preflight_level: ddex_builder::preflight::PreflightLevel::Strict,
         id_strategy: IdStrategy::StableHash,
         stable_hash_config: None,
+        validation_mode: ddex_builder::preflight::ValidationMode::CollectAll,
+        self_close_empty: true,
+        omit_empty: true,
+        validate_party_references: false,
+        include_canonical: false,
+        sort_order: ddex_builder::builder::SortOrder::Insertion,
+        label_form: ddex_builder::builder::LabelForm::Inline,
+        verify_well_formed: false,
+        preserve_lexical_forms: false,
+        collect_statistics: false,
+        created_date_time: Default::default(),
+        legacy_elements: Vec::new(),
     };
 
     // Generate multiple times
@@ -123,10 +136,9 @@ fn test_preflight_validation() {
     request.releases[0].tracks[0].isrc = "INVALID".to_string();
 
     let options = BuildOptions {
-        determinism: None,
         preflight_level: ddex_builder::preflight::PreflightLevel::Strict,
         id_strategy: IdStrategy::UUID,
-        stable_hash_config: None,
+        ..Default::default()
     };
 
     let result = builder.build(request, options);
@@ -182,6 +194,7 @@ fn test_profile_validation() {
         validate_dates: true,
         validate_references: true,
         profile: Some("AudioAlbum".to_string()),
+        validation_mode: ddex_builder::preflight::ValidationMode::CollectAll,
     };
 
     let validator = PreflightValidator::new(config);
@@ -204,19 +217,18 @@ fn create_test_request() -> BuildRequest {
                     text: "Test Sender".to_string(),
                     language_code: None,
                 }],
-                party_id: None,
-                party_reference: None,
+                ..Default::default()
             },
             message_recipient: PartyRequest {
                 party_name: vec![LocalizedStringRequest {
                     text: "Test Recipient".to_string(),
                     language_code: None,
                 }],
-                party_id: None,
-                party_reference: None,
+                ..Default::default()
             },
             message_control_type: Some("LiveMessage".to_string()),
             message_created_date_time: Some("2025-01-01T00:00:00Z".to_string()), // Fixed timestamp
+            message_thread_id: None,
         },
         version: "4.3".to_string(),
         profile: Some("AudioAlbum".to_string()),
@@ -239,6 +251,7 @@ fn create_test_request() -> BuildRequest {
                     title: "Test Track".to_string(),
                     duration: "PT3M30S".to_string(),
                     artist: "Test Artist".to_string(),
+                    ..Default::default()
                 },
                 TrackRequest {
                     track_id: "TRK002".to_string(),
@@ -247,9 +260,10 @@ fn create_test_request() -> BuildRequest {
                     title: "Another Track".to_string(),
                     duration: "PT4M00S".to_string(),
                     artist: "Test Artist".to_string(),
+                    ..Default::default()
                 },
             ],
-            resource_references: None,
+            ..Default::default()
         }],
         deals: vec![],
         extensions: None,
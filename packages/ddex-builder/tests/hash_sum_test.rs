@@ -0,0 +1,96 @@
+use ddex_builder::builder::{
+    BuildRequest, HashSumRequest, LocalizedStringRequest, ReleaseRequest, TrackRequest,
+};
+use ddex_builder::{BuildError, BuildOptions, DDEXBuilder};
+use std::io::Write;
+
+mod common;
+use common::header;
+
+const PROVIDED_SHA256: &str = "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08";
+
+fn request(file_path: Option<String>, hash_sum: Option<HashSumRequest>) -> BuildRequest {
+    BuildRequest {
+        header: header("MSG_HASH_001"),
+        version: "4.3".to_string(),
+        profile: None,
+        releases: vec![ReleaseRequest {
+            release_id: "REL001".to_string(),
+            release_reference: Some("R1".to_string()),
+            title: vec![LocalizedStringRequest {
+                text: "Album".to_string(),
+                language_code: None,
+            }],
+            artist: "Artist".to_string(),
+            upc: Some("123456789012".to_string()),
+            tracks: vec![TrackRequest {
+                track_id: "TRK001".to_string(),
+                resource_reference: Some("A1".to_string()),
+                isrc: "USRC17607839".to_string(),
+                title: "Track".to_string(),
+                duration: "PT3M00S".to_string(),
+                artist: "Artist".to_string(),
+                file_path,
+                hash_sum,
+                ..Default::default()
+            }],
+            ..Default::default()
+        }],
+        deals: vec![],
+        extensions: None,
+    }
+}
+
+#[test]
+fn test_provided_hash_sum_is_emitted() {
+    let hash_sum = HashSumRequest {
+        algorithm: "SHA256".to_string(),
+        value: PROVIDED_SHA256.to_string(),
+    };
+    let result = DDEXBuilder::new()
+        .build(request(None, Some(hash_sum)), BuildOptions::default())
+        .unwrap();
+
+    assert!(result.xml.contains("<HashSum>"));
+    assert!(result.xml.contains("<Algorithm>SHA256</Algorithm>"));
+    assert!(result
+        .xml
+        .contains(&format!("<HashSumValue>{}</HashSumValue>", PROVIDED_SHA256)));
+}
+
+#[test]
+fn test_hash_sum_computed_from_file_path() {
+    let mut file = tempfile::NamedTempFile::new().unwrap();
+    file.write_all(b"hello").unwrap();
+    let path = file.path().to_string_lossy().into_owned();
+
+    let result = DDEXBuilder::new()
+        .build(request(Some(path.clone()), None), BuildOptions::default())
+        .unwrap();
+
+    assert!(result.xml.contains(&format!("<URI>{}</URI>", path)));
+    assert!(result.xml.contains("<Algorithm>SHA256</Algorithm>"));
+    assert!(result.xml.contains(
+        "<HashSumValue>2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824</HashSumValue>"
+    ));
+}
+
+#[test]
+fn test_no_technical_details_without_file_info() {
+    let result = DDEXBuilder::new()
+        .build(request(None, None), BuildOptions::default())
+        .unwrap();
+
+    assert!(!result.xml.contains("TechnicalDetails"));
+    assert!(!result.xml.contains("HashSum"));
+}
+
+#[test]
+fn test_missing_file_is_an_error() {
+    let result = DDEXBuilder::new().build(
+        request(Some("/nonexistent/track01.flac".to_string()), None),
+        BuildOptions::default(),
+    );
+
+    assert!(matches!(result, Err(BuildError::Io(_))));
+}
@@ -0,0 +1,79 @@
+use ddex_builder::builder::{
+    BuildRequest, LocalizedStringRequest, MessageHeaderRequest, PartyRequest,
+};
+use ddex_builder::determinism::{CanonMode, DeterminismConfig};
+use ddex_builder::{BuildOptions, BuildResult, DDEXBuilder, DB_C14N};
+
+fn request() -> BuildRequest {
+    let party = |name: &str| PartyRequest {
+        party_name: vec![LocalizedStringRequest {
+            text: name.to_string(),
+            language_code: None,
+        }],
+        ..Default::default()
+    };
+
+    BuildRequest {
+        header: MessageHeaderRequest {
+            message_id: Some("CANONICAL_001".to_string()),
+            message_sender: party("Sender"),
+            message_recipient: party("Recipient"),
+            message_created_date_time: Some("2024-01-01T00:00:00Z".to_string()),
+            ..Default::default()
+        },
+        version: "4.3".to_string(),
+        profile: None,
+        releases: vec![],
+        deals: vec![],
+        extensions: None,
+    }
+}
+
+fn build(canon_mode: CanonMode, include_canonical: bool) -> (BuildResult, DeterminismConfig) {
+    let config = DeterminismConfig {
+        canon_mode,
+        ..Default::default()
+    };
+    let options = BuildOptions {
+        determinism: Some(config.clone()),
+        include_canonical,
+        ..Default::default()
+    };
+
+    let result = DDEXBuilder::new().build(request(), options).unwrap();
+    (result, config)
+}
+
+#[test]
+fn test_pretty_build_returns_matching_canonical_form() {
+    let (result, config) = build(CanonMode::Pretty, true);
+
+    let canonicalizer = DB_C14N::new(config);
+    let expected = canonicalizer.canonicalize(&result.xml).unwrap();
+    let canonical = result.canonical_xml.as_deref().unwrap();
+
+    assert_ne!(result.xml, canonical);
+    assert_eq!(canonical, expected);
+    assert_eq!(
+        result.canonical_hash,
+        Some(canonicalizer.canonical_hash(&expected).unwrap())
+    );
+    assert_eq!(result.statistics.canonical_size_bytes, Some(expected.len()));
+}
+
+#[test]
+fn test_canonical_build_returns_its_output() {
+    let (result, _) = build(CanonMode::DbC14n, true);
+
+    assert_eq!(result.canonical_xml.as_deref(), Some(result.xml.as_str()));
+    assert!(result.canonical_hash.is_some());
+}
+
+#[test]
+fn test_canonical_form_omitted_by_default() {
+    let (result, _) = build(CanonMode::Pretty, false);
+
+    assert_eq!(result.canonical_xml, None);
+    assert_eq!(result.canonical_hash, None);
+    assert_eq!(result.statistics.canonical_size_bytes, None);
+}
@@ -0,0 +1,123 @@
+use ddex_builder::builder::{
+    BuildRequest, LabelForm, LocalizedStringRequest, MessageHeaderRequest, PartyRequest,
+    ReleaseRequest, TrackRequest,
+};
+use ddex_builder::{BuildOptions, DDEXBuilder};
+use ddex_core::models::graph::ReleaseType;
+use ddex_parser::transform::flatten::Flattener;
+use ddex_parser::transform::graph::GraphBuilder;
+use ddex_parser::ERNVersion;
+use std::io::Cursor;
+
+fn party(name: &str) -> PartyRequest {
+    PartyRequest {
+        party_name: vec![LocalizedStringRequest {
+            text: name.to_string(),
+            language_code: None,
+        }],
+        party_id: Some(format!("PADPIDA_{}", name.to_uppercase())),
+        ..Default::default()
+    }
+}
+
+fn release(reference: &str, isrc: &str, label: &str) -> ReleaseRequest {
+    ReleaseRequest {
+        release_id: format!("ID_{}", reference),
+        release_reference: Some(reference.to_string()),
+        title: vec![LocalizedStringRequest {
+            text: format!("Release {}", reference),
+            language_code: None,
+        }],
+        artist: "Artist".to_string(),
+        label: Some(label.to_string()),
+        release_date: Some("2024-06-07".to_string()),
+        tracks: vec![TrackRequest {
+            track_id: format!("TRK_{}", reference),
+            resource_reference: Some(format!("A_{}", reference)),
+            isrc: isrc.to_string(),
+            title: "Track".to_string(),
+            duration: "PT3M00S".to_string(),
+            artist: "Artist".to_string(),
+            ..Default::default()
+        }],
+        ..Default::default()
+    }
+}
+
+fn build(label_form: LabelForm) -> String {
+    let request = BuildRequest {
+        header: MessageHeaderRequest {
+            message_id: Some("MSG_LABEL_001".to_string()),
+            message_sender: party("Sender"),
+            message_recipient: party("Recipient"),
+            ..Default::default()
+        },
+        version: "4.3".to_string(),
+        profile: None,
+        releases: vec![
+            release("R1", "USRC17607839", "Example Records"),
+            release("R2", "USRC17607840", "Other Label"),
+            release("R3", "USRC17607841", "Example Records"),
+        ],
+        deals: vec![],
+        extensions: None,
+    };
+    let options = BuildOptions {
+        label_form,
+        validate_party_references: true,
+        ..Default::default()
+    };
+    DDEXBuilder::new().build(request, options).unwrap().xml
+}
+
+/// Label names the parser resolves for each release, in order
+fn parsed_label_names(xml: &str) -> Vec<Option<String>> {
+    let mut message = GraphBuilder::new(ERNVersion::V4_3)
+        .build_from_xml(Cursor::new(xml.as_bytes()))
+        .unwrap();
+    // The builder does not write a ReleaseType, which flattening requires
+    for release in &mut message.releases {
+        release.release_type = Some(ReleaseType::Album);
+    }
+    Flattener::flatten(message)
+        .unwrap()
+        .releases
+        .into_iter()
+        .map(|release| release.label_name)
+        .collect()
+}
+
+fn expected_labels() -> Vec<Option<String>> {
+    ["Example Records", "Other Label", "Example Records"]
+        .iter()
+        .map(|label| Some(label.to_string()))
+        .collect()
+}
+
+#[test]
+fn test_inline_label_is_parsed() {
+    let xml = build(LabelForm::Inline);
+    assert!(xml.contains("<LabelName>Example Records</LabelName>"));
+    assert!(!xml.contains("<PartyList>"));
+    assert!(!xml.contains("ReleaseLabelReference"));
+
+    assert_eq!(parsed_label_names(&xml), expected_labels());
+}
+
+#[test]
+fn test_referenced_label_party_is_resolved() {
+    let xml = build(LabelForm::PartyReference);
+    assert!(!xml.contains("<LabelName>"));
+    // One party per distinct label, shared by the releases using it
+    assert_eq!(xml.matches("<Party>").count(), 2);
+    assert!(xml.contains("<PartyReference>PLabel1</PartyReference>"));
+    assert!(xml.contains("<FullName>Other Label</FullName>"));
+    assert_eq!(
+        xml.matches("<ReleaseLabelReference>PLabel1</ReleaseLabelReference>")
+            .count(),
+        2
+    );
+    assert!(xml.contains("<ReleaseLabelReference>PLabel2</ReleaseLabelReference>"));
+
+    assert_eq!(parsed_label_names(&xml), expected_labels());
+}
@@ -0,0 +1,99 @@
+use ddex_builder::builder::{
+    BuildRequest, LegacyElement, LocalizedStringRequest, MessageHeaderRequest, PartyRequest,
+    ReleaseRequest, TrackRequest,
+};
+use ddex_builder::{BuildOptions, DDEXBuilder};
+use ddex_parser::transform::graph::GraphBuilder;
+use ddex_parser::ERNVersion;
+use std::io::Cursor;
+
+fn localized(text: &str) -> LocalizedStringRequest {
+    LocalizedStringRequest {
+        text: text.to_string(),
+        language_code: None,
+    }
+}
+
+fn party(name: &str) -> PartyRequest {
+    PartyRequest {
+        party_name: vec![localized(name)],
+        ..Default::default()
+    }
+}
+
+fn request() -> BuildRequest {
+    BuildRequest {
+        header: MessageHeaderRequest {
+            message_id: Some("LEGACY_001".to_string()),
+            message_sender: party("Sender"),
+            message_recipient: party("Recipient"),
+            ..Default::default()
+        },
+        version: "4.3".to_string(),
+        profile: None,
+        releases: vec![ReleaseRequest {
+            release_id: "REL001".to_string(),
+            release_reference: Some("R1".to_string()),
+            title: vec![localized("Album")],
+            artist: "Artist".to_string(),
+            label: Some("Label".to_string()),
+            tracks: vec![TrackRequest {
+                track_id: "T1".to_string(),
+                resource_reference: Some("A1".to_string()),
+                isrc: "USRC17607839".to_string(),
+                title: "Track".to_string(),
+                duration: "PT3M30S".to_string(),
+                artist: "Artist".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }],
+        deals: vec![],
+        extensions: None,
+    }
+}
+
+fn build(legacy_elements: Vec<LegacyElement>) -> String {
+    let options = BuildOptions {
+        legacy_elements,
+        ..Default::default()
+    };
+    DDEXBuilder::new().build(request(), options).unwrap().xml
+}
+
+#[test]
+fn test_legacy_elements_are_emitted_alongside_the_modern_structure() {
+    let xml = build(vec![
+        LegacyElement::ReleaseDetailsByTerritory,
+        LegacyElement::SoundRecordingId,
+        LegacyElement::SoundRecordingDetailsByTerritory,
+    ]);
+
+    assert!(xml.contains("<ReleaseDetailsByTerritory>"));
+    assert!(xml.contains("<LabelName>Label</LabelName>"));
+    assert!(xml.contains(r#"<Title TitleType="DisplayTitle">"#));
+    assert!(xml.contains("<SoundRecordingId>"));
+    assert!(xml.contains("<SoundRecordingDetailsByTerritory>"));
+
+    // The modern elements are still there and still read back
+    assert!(xml.contains("<ResourceId>"));
+    assert!(xml.contains("<ReferenceTitle>"));
+    let message = GraphBuilder::new(ERNVersion::V4_3)
+        .build_from_xml(Cursor::new(xml.as_bytes()))
+        .unwrap();
+    assert_eq!(message.releases.len(), 1);
+    assert_eq!(message.releases[0].release_title[0].text, "Album");
+    assert_eq!(message.resources.len(), 1);
+    assert_eq!(message.resources[0].resource_id[0].value, "USRC17607839");
+}
+
+#[test]
+fn test_only_the_named_legacy_elements_are_emitted() {
+    let xml = build(vec![LegacyElement::SoundRecordingId]);
+    assert!(xml.contains("<SoundRecordingId>"));
+    assert!(!xml.contains("DetailsByTerritory"));
+
+    let xml = build(vec![]);
+    assert!(!xml.contains("<SoundRecordingId>"));
+    assert!(!xml.contains("DetailsByTerritory"));
+}
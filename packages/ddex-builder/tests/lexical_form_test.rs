@@ -0,0 +1,164 @@
+use chrono::{TimeZone, Utc};
+use ddex_builder::builder::{
+    BuildRequest, DealPrice, DealRequest, DealTerms, LocalizedStringRequest, ReleaseRequest,
+    TrackRequest,
+};
+use ddex_builder::{BuildOptions, Builder, DDEXBuilder};
+use ddex_parser::DDEXParser;
+use std::io::Cursor;
+
+mod common;
+use common::header;
+
+const SOURCE: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/43" MessageSchemaVersionId="ern/43">
+    <MessageHeader>
+        <MessageId>MSG_LEXICAL</MessageId>
+        <MessageSender>
+            <PartyId>PADPIDA_SENDER</PartyId>
+            <PartyName><FullName>Sender</FullName></PartyName>
+        </MessageSender>
+        <MessageRecipient>
+            <PartyId>PADPIDA_RECIPIENT</PartyId>
+            <PartyName><FullName>Recipient</FullName></PartyName>
+        </MessageRecipient>
+        <MessageCreatedDateTime>2024-01-01T00:00:00Z</MessageCreatedDateTime>
+    </MessageHeader>
+    <ResourceList>
+        <SoundRecording>
+            <ResourceReference>A1</ResourceReference>
+            <SoundRecordingId><ISRC>USRC17607839</ISRC></SoundRecordingId>
+            <Title><TitleText>Track</TitleText></Title>
+            <Duration>PT3M00S</Duration>
+        </SoundRecording>
+    </ResourceList>
+    <ReleaseList>
+        <Release>
+            <ReleaseReference>R1</ReleaseReference>
+            <ReleaseId><GRid>A10301A0000000001A</GRid></ReleaseId>
+            <ReleaseTitle><TitleText>Album</TitleText></ReleaseTitle>
+            <ReleaseType>Album</ReleaseType>
+            <ReleaseResourceReferenceList>
+                <ReleaseResourceReference>A1</ReleaseResourceReference>
+            </ReleaseResourceReferenceList>
+        </Release>
+    </ReleaseList>
+    <DealList>
+        <ReleaseDeal>
+            <DealReleaseReference>R1</DealReleaseReference>
+            <Deal>
+                <DealReference>D1</DealReference>
+                <DealTerms>
+                    <TerritoryCode>Worldwide</TerritoryCode>
+                    <ValidityPeriod><StartDate>2024-01-15</StartDate></ValidityPeriod>
+                    <CommercialModelType>PayAsYouGoModel</CommercialModelType>
+                    <UseType>PermanentDownload</UseType>
+                    <PriceInformation>
+                        <PriceType>Release</PriceType>
+                        <WholesalePricePerUnit CurrencyCode="USD">9.90</WholesalePricePerUnit>
+                    </PriceInformation>
+                </DealTerms>
+            </Deal>
+        </ReleaseDeal>
+    </DealList>
+</ern:NewReleaseMessage>"#;
+
+/// A request carrying the source's deal with its dates and prices as written
+fn rebuild_request() -> BuildRequest {
+    let parsed = DDEXParser::new()
+        .parse(Cursor::new(SOURCE.as_bytes()))
+        .unwrap();
+    let deal = &parsed.flat.deals[0];
+
+    // The parsed convenience value is still there, normalized
+    assert_eq!(
+        deal.validity.start,
+        Some(Utc.with_ymd_and_hms(2024, 1, 15, 0, 0, 0).unwrap())
+    );
+    assert_eq!(deal.validity.start_text.as_deref(), Some("2024-01-15"));
+    assert_eq!(deal.pricing[0].price.amount, 9.9);
+
+    BuildRequest {
+        header: header("MSG_LEXICAL"),
+        version: "4.3".to_string(),
+        profile: None,
+        releases: vec![ReleaseRequest {
+            release_id: "A10301A0000000001A".to_string(),
+            release_reference: Some("R1".to_string()),
+            title: vec![LocalizedStringRequest {
+                text: "Album".to_string(),
+                language_code: None,
+            }],
+            artist: "Artist".to_string(),
+            tracks: vec![TrackRequest {
+                track_id: "T1".to_string(),
+                resource_reference: Some("A1".to_string()),
+                isrc: "USRC17607839".to_string(),
+                title: "Track".to_string(),
+                duration: "PT3M00S".to_string(),
+                artist: "Artist".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }],
+        deals: vec![DealRequest {
+            deal_reference: Some(deal.deal_id.clone()),
+            deal_terms: DealTerms {
+                commercial_model_type: deal.commercial_models[0].clone(),
+                territory_code: deal.territories.included.clone(),
+                start_date: deal.validity.start_text.clone(),
+                use_types: deal.usage_rights.clone(),
+                prices: deal
+                    .pricing
+                    .iter()
+                    .map(|tier| DealPrice {
+                        price_type: tier.tier_name.clone().unwrap_or_default(),
+                        amount: tier.amount_text.clone().unwrap(),
+                        currency_code: tier.price.currency.clone(),
+                    })
+                    .collect(),
+            },
+            release_references: deal.releases.clone(),
+        }],
+        extensions: None,
+    }
+}
+
+#[test]
+fn test_perfect_fidelity_re_emits_date_only_start_date_unchanged() {
+    let result = Builder::with_perfect_fidelity()
+        .build_with_fidelity(&rebuild_request())
+        .unwrap();
+
+    assert!(
+        result.xml.contains("<StartDate>2024-01-15</StartDate>"),
+        "{}",
+        result.xml
+    );
+    assert!(result
+        .xml
+        .contains(r#"<WholesalePricePerUnit CurrencyCode="USD">9.90</WholesalePricePerUnit>"#));
+}
+
+#[test]
+fn test_start_dates_are_normalized_to_dates_by_default() {
+    let mut request = rebuild_request();
+    request.deals[0].deal_terms.start_date = Some("2024-01-15T00:00:00Z".to_string());
+
+    let xml = DDEXBuilder::new()
+        .build(request.clone(), BuildOptions::default())
+        .unwrap()
+        .xml;
+    assert!(xml.contains("<StartDate>2024-01-15</StartDate>"), "{}", xml);
+
+    let options = BuildOptions {
+        preserve_lexical_forms: true,
+        ..Default::default()
+    };
+    let xml = DDEXBuilder::new().build(request, options).unwrap().xml;
+    assert!(
+        xml.contains("<StartDate>2024-01-15T00:00:00Z</StartDate>"),
+        "{}",
+        xml
+    );
+}
@@ -250,7 +250,7 @@ fn create_test_build_request() -> BuildRequest {
                     language_code: Some("en".to_string()),
                 }],
                 party_id: Some("SENDER_001".to_string()),
-                party_reference: None,
+                ..Default::default()
             },
             message_recipient: PartyRequest {
                 party_name: vec![LocalizedStringRequest {
@@ -258,16 +258,16 @@ fn create_test_build_request() -> BuildRequest {
                     language_code: Some("en".to_string()),
                 }],
                 party_id: Some("RECIPIENT_001".to_string()),
-                party_reference: None,
+                ..Default::default()
             },
             message_control_type: Some("LiveMessage".to_string()),
             message_created_date_time: None, // Add to existing MessageHeaderRequest structs
+            message_thread_id: None,
         },
         version: "4.3".to_string(),
         profile: Some("AudioAlbum".to_string()),
         releases: vec![ReleaseRequest {
             release_id: "REL_001".to_string(),
-            release_reference: None,
             title: vec![LocalizedStringRequest {
                 text: "Test Album".to_string(),
                 language_code: Some("en".to_string()),
@@ -279,22 +279,22 @@ fn create_test_build_request() -> BuildRequest {
             tracks: vec![
                 TrackRequest {
                     track_id: "TRACK_001".to_string(),
-                    resource_reference: None,
                     isrc: "USRC12345678".to_string(),
                     title: "Track 1".to_string(),
                     duration: "PT3M30S".to_string(),
                     artist: "Test Artist".to_string(),
+                    ..Default::default()
                 },
                 TrackRequest {
                     track_id: "TRACK_002".to_string(),
-                    resource_reference: None,
                     isrc: "USRC12345679".to_string(),
                     title: "Track 2".to_string(),
                     duration: "PT4M15S".to_string(),
                     artist: "Test Artist".to_string(),
+                    ..Default::default()
                 },
             ],
-            resource_references: None,
+            ..Default::default()
         }],
         deals: vec![],
         extensions: None,
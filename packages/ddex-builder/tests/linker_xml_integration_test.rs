@@ -19,7 +19,7 @@ fn test_linker_with_xml_generation() {
                     language_code: Some("en".to_string()),
                 }],
                 party_id: Some("LABEL_123".to_string()),
-                party_reference: None,
+                ..Default::default()
             },
             message_recipient: PartyRequest {
                 party_name: vec![LocalizedStringRequest {
@@ -27,10 +27,11 @@ fn test_linker_with_xml_generation() {
                     language_code: Some("en".to_string()),
                 }],
                 party_id: Some("DSP_456".to_string()),
-                party_reference: None,
+                ..Default::default()
             },
             message_control_type: Some("LiveMessage".to_string()),
             message_created_date_time: None, // Add to existing MessageHeaderRequest structs
+            message_thread_id: None,
         },
         version: "4.3".to_string(),
         profile: Some("AudioAlbum".to_string()),
@@ -53,6 +54,7 @@ fn test_linker_with_xml_generation() {
                     title: "First Linked Track".to_string(),
                     duration: "PT3M00S".to_string(),
                     artist: "Linked Artist".to_string(),
+                    ..Default::default()
                 },
                 TrackRequest {
                     track_id: "TRK_002".to_string(),
@@ -61,9 +63,11 @@ fn test_linker_with_xml_generation() {
                     title: "Second Linked Track".to_string(),
                     duration: "PT4M00S".to_string(),
                     artist: "Linked Artist".to_string(),
+                    ..Default::default()
                 },
             ],
             resource_references: None, // Will be auto-generated
+            ..Default::default()
         }],
         deals: vec![],
         extensions: None,
@@ -125,37 +129,34 @@ fn create_simple_request() -> BuildRequest {
         header: MessageHeaderRequest {
             message_id: Some("TEST".to_string()),
             message_sender: PartyRequest {
-                party_name: vec![],
                 party_id: Some("S1".to_string()),
-                party_reference: None,
+                ..Default::default()
             },
             message_recipient: PartyRequest {
-                party_name: vec![],
                 party_id: Some("R1".to_string()),
-                party_reference: None,
+                ..Default::default()
             },
             message_control_type: None,
             message_created_date_time: None, // Add to existing MessageHeaderRequest structs
+            message_thread_id: None,
         },
         version: "4.3".to_string(),
         profile: None,
         releases: vec![ReleaseRequest {
             release_id: "REL1".to_string(),
-            release_reference: None,
-            title: vec![],
             artist: "Artist".to_string(),
             label: None,        // Add this
             release_date: None, // Add this
             upc: None,          // Add this
             tracks: vec![TrackRequest {
                 track_id: "TRK1".to_string(),
-                resource_reference: None,
                 isrc: "US123".to_string(),
                 title: "Track".to_string(),
                 duration: "PT3M".to_string(),
                 artist: "Artist".to_string(),
+                ..Default::default()
             }],
-            resource_references: None,
+            ..Default::default()
         }],
         deals: vec![],
         extensions: None,
@@ -0,0 +1,71 @@
+use ddex_builder::builder::{BuildRequest, MessageConfig};
+use ddex_builder::streaming::StreamingBuilder;
+use ddex_builder::{BuildOptions, DDEXBuilder};
+
+fn full_config() -> MessageConfig {
+    MessageConfig {
+        sender_name: "Sakura Records".to_string(),
+        sender_id: Some("PADPIDA2014120301K".to_string()),
+        recipient_name: "Test DSP".to_string(),
+        recipient_id: Some("PADPIDA2015010101R".to_string()),
+        message_control_type: Some("TestMessage".to_string()),
+        message_thread_id: Some("THREAD_42".to_string()),
+        message_created_date_time: Some("2024-06-01T12:00:00Z".to_string()),
+        message_id_template: Some("SAKURA_{date}_{uuid}".to_string()),
+    }
+}
+
+fn assert_header(xml: &str) {
+    assert!(xml.contains("<MessageThreadId>THREAD_42</MessageThreadId>"));
+    assert!(xml.contains("<MessageId>SAKURA_"));
+    assert!(!xml.contains("{date}") && !xml.contains("{uuid}"));
+    assert!(xml.contains("<PartyId>PADPIDA2014120301K</PartyId>"));
+    assert!(xml.contains("Sakura Records</PartyName>"));
+    assert!(xml.contains("<PartyId>PADPIDA2015010101R</PartyId>"));
+    assert!(xml.contains("Test DSP</PartyName>"));
+    assert!(xml.contains("<MessageControlType>TestMessage</MessageControlType>"));
+    assert!(xml.contains("<MessageCreatedDateTime>2024-06-01T12:00:00Z</MessageCreatedDateTime>"));
+}
+
+#[test]
+fn test_message_config_buffered_build() {
+    let request = BuildRequest {
+        header: MessageConfig::new("placeholder", "placeholder").to_header(),
+        version: "4.3".to_string(),
+        profile: None,
+        releases: vec![],
+        deals: vec![],
+        extensions: None,
+    };
+
+    let result = DDEXBuilder::new()
+        .build_with_message_config(request, &full_config(), BuildOptions::default())
+        .unwrap();
+
+    assert_header(&result.xml);
+    assert!(!result.xml.contains("placeholder"));
+}
+
+#[test]
+fn test_message_config_streaming_build() {
+    let mut output = Vec::new();
+    {
+        let mut builder = StreamingBuilder::new(&mut output).unwrap();
+        builder
+            .start_message_with_config(&full_config(), "ern/43")
+            .unwrap();
+        builder.finish_resources_start_releases().unwrap();
+        builder.finish_message().unwrap();
+    }
+
+    assert_header(&String::from_utf8(output).unwrap());
+}
+
+#[test]
+fn test_message_id_defaults_to_uuid() {
+    let header = MessageConfig::new("Sender", "Recipient").to_header();
+    let message_id = header.message_id.unwrap();
+
+    assert!(uuid::Uuid::parse_str(&message_id).is_ok());
+    assert_eq!(header.message_thread_id, None);
+}
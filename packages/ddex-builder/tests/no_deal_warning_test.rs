@@ -0,0 +1,83 @@
+use ddex_builder::builder::{
+    BuildRequest, DealRequest, DealTerms, LocalizedStringRequest, ReleaseRequest, TrackRequest,
+};
+use ddex_builder::preflight::PreflightLevel;
+use ddex_builder::{BuildOptions, DDEXBuilder};
+
+mod common;
+use common::header;
+
+fn release_request(deals: Vec<DealRequest>) -> BuildRequest {
+    BuildRequest {
+        header: header("MSG_NO_DEAL_001"),
+        version: "4.3".to_string(),
+        profile: None,
+        releases: vec![ReleaseRequest {
+            release_id: "A10301A0000000001A".to_string(),
+            release_reference: Some("R1".to_string()),
+            title: vec![LocalizedStringRequest {
+                text: "Album".to_string(),
+                language_code: None,
+            }],
+            artist: "Artist".to_string(),
+            tracks: vec![TrackRequest {
+                track_id: "TRK001".to_string(),
+                resource_reference: Some("A1".to_string()),
+                isrc: "USRC17607839".to_string(),
+                title: "Track".to_string(),
+                duration: "PT3M00S".to_string(),
+                artist: "Artist".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }],
+        deals,
+        extensions: None,
+    }
+}
+
+fn stream_deal(release_reference: &str) -> DealRequest {
+    DealRequest {
+        deal_reference: Some("D1".to_string()),
+        deal_terms: DealTerms {
+            commercial_model_type: "SubscriptionModel".to_string(),
+            territory_code: vec!["Worldwide".to_string()],
+            ..Default::default()
+        },
+        release_references: vec![release_reference.to_string()],
+    }
+}
+
+fn no_deal_warnings(request: BuildRequest, options: BuildOptions) -> Vec<String> {
+    DDEXBuilder::new()
+        .build(request, options)
+        .unwrap()
+        .warnings
+        .into_iter()
+        .filter(|warning| warning.code == "NO_DEAL")
+        .map(|warning| warning.location.unwrap_or_default())
+        .collect()
+}
+
+#[test]
+fn test_release_without_deal_warns_until_a_deal_is_added() {
+    let warnings = no_deal_warnings(release_request(vec![]), BuildOptions::default());
+    assert_eq!(warnings, vec!["/releases[0]".to_string()]);
+
+    let warnings = no_deal_warnings(
+        release_request(vec![stream_deal("R1")]),
+        BuildOptions::default(),
+    );
+    assert!(warnings.is_empty());
+}
+
+#[test]
+fn test_no_deal_warning_does_not_fail_strict_builds() {
+    let options = BuildOptions {
+        preflight_level: PreflightLevel::Strict,
+        ..Default::default()
+    };
+
+    let warnings = no_deal_warnings(release_request(vec![]), options);
+    assert_eq!(warnings.len(), 1);
+}
@@ -0,0 +1,41 @@
+use ddex_builder::{normalize_isrc, normalize_upc};
+
+#[test]
+fn test_isrc_is_uppercased_and_stripped() {
+    let isrc = normalize_isrc("us-abc-24-00001");
+    assert_eq!(isrc.normalized, "USABC2400001");
+    assert!(isrc.valid);
+    assert_eq!(isrc.original, "us-abc-24-00001");
+
+    let spaced = normalize_isrc(" GB AAA 99 12345 ");
+    assert_eq!(spaced.normalized, "GBAAA9912345");
+    assert!(spaced.valid);
+
+    let too_short = normalize_isrc("US-ABC-24-001");
+    assert_eq!(too_short.normalized, "USABC24001");
+    assert!(!too_short.valid);
+}
+
+#[test]
+fn test_short_upc_is_padded() {
+    let upc = normalize_upc("72345678907");
+    assert_eq!(upc.normalized, "072345678907");
+    assert!(upc.valid);
+
+    let hyphenated = normalize_upc("0-72345-67890-7");
+    assert_eq!(hyphenated.normalized, "072345678907");
+    assert!(hyphenated.valid);
+}
+
+#[test]
+fn test_invalid_upc_is_flagged() {
+    // Wrong check digit
+    let checksum = normalize_upc("072345678900");
+    assert_eq!(checksum.normalized, "072345678900");
+    assert!(!checksum.valid);
+
+    // Letters are kept rather than dropped, so the value stays recognizable
+    let letters = normalize_upc("UPC-12345");
+    assert_eq!(letters.normalized, "UPC12345");
+    assert!(!letters.valid);
+}
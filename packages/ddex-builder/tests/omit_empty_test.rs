@@ -0,0 +1,83 @@
+use ddex_builder::builder::{
+    BuildRequest, LocalizedStringRequest, MessageHeaderRequest, ReleaseRequest,
+};
+use ddex_builder::{BuildOptions, DDEXBuilder};
+
+mod common;
+use common::party;
+
+fn request(label: Option<String>, release_date: Option<String>) -> BuildRequest {
+    BuildRequest {
+        header: MessageHeaderRequest {
+            message_id: Some("MSG_OMIT_001".to_string()),
+            message_sender: party("Sender"),
+            message_recipient: party("Recipient"),
+            message_created_date_time: Some("2024-01-01T00:00:00Z".to_string()),
+            ..Default::default()
+        },
+        version: "4.3".to_string(),
+        profile: None,
+        releases: vec![ReleaseRequest {
+            release_id: "REL001".to_string(),
+            release_reference: Some("R1".to_string()),
+            title: vec![LocalizedStringRequest {
+                text: "Album".to_string(),
+                language_code: None,
+            }],
+            artist: "Artist".to_string(),
+            label,
+            release_date,
+            ..Default::default()
+        }],
+        deals: vec![],
+        extensions: None,
+    }
+}
+
+fn build(request: BuildRequest, omit_empty: bool) -> String {
+    let options = BuildOptions {
+        omit_empty,
+        ..Default::default()
+    };
+    DDEXBuilder::new().build(request, options).unwrap().xml
+}
+
+#[test]
+fn test_release_without_label_has_no_label_element() {
+    let xml = build(request(None, None), true);
+
+    assert!(!xml.contains("<Label"));
+    assert!(!xml.contains("<ReleaseDate"));
+    assert!(xml.contains("<ReleaseReference>R1</ReleaseReference>"));
+}
+
+#[test]
+fn test_blank_optionals_omitted_by_default() {
+    let blank = request(Some(String::new()), Some("  ".to_string()));
+    let xml = DDEXBuilder::new()
+        .build(blank, BuildOptions::default())
+        .unwrap()
+        .xml;
+
+    assert!(!xml.contains("<Label"));
+    assert!(!xml.contains("<ReleaseDate"));
+}
+
+#[test]
+fn test_blank_optionals_emitted_when_disabled() {
+    let xml = build(request(Some(String::new()), None), false);
+
+    assert!(xml.contains("<LabelName>"));
+    // Absent values still produce nothing
+    assert!(!xml.contains("<ReleaseDate"));
+}
+
+#[test]
+fn test_omit_empty_defaults_to_true_when_deserialized() {
+    let options: BuildOptions = serde_json::from_str(
+        r#"{"determinism": null, "preflight_level": "Warn", "id_strategy": "UUID", "stable_hash_config": null}"#,
+    )
+    .unwrap();
+
+    assert!(options.omit_empty);
+}
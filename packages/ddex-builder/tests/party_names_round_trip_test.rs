@@ -0,0 +1,70 @@
+use ddex_builder::builder::{
+    BuildRequest, LocalizedStringRequest, MessageHeaderRequest, PartyRequest,
+};
+use ddex_builder::{BuildOptions, DDEXBuilder};
+use ddex_parser::DDEXParser;
+use std::io::Cursor;
+
+fn localized(text: &str, language_code: &str) -> LocalizedStringRequest {
+    LocalizedStringRequest {
+        text: text.to_string(),
+        language_code: Some(language_code.to_string()),
+    }
+}
+
+#[test]
+fn test_localized_party_names_and_trading_name_round_trip() {
+    let request = BuildRequest {
+        header: MessageHeaderRequest {
+            message_id: Some("PARTY_NAMES_001".to_string()),
+            message_sender: PartyRequest {
+                party_name: vec![
+                    localized("Sakura Records Ltd.", "en"),
+                    localized("桜レコード株式会社", "ja"),
+                ],
+                party_id: Some("PADPIDA2014120301K".to_string()),
+                trading_name: Some("Sakura Music".to_string()),
+                ..Default::default()
+            },
+            message_recipient: PartyRequest {
+                party_name: vec![localized("Test DSP", "en")],
+                party_id: Some("PADPIDA2015010101R".to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        version: "4.3".to_string(),
+        profile: Some("AudioAlbum".to_string()),
+        releases: vec![],
+        deals: vec![],
+        extensions: None,
+    };
+
+    let result = DDEXBuilder::new()
+        .build(request, BuildOptions::default())
+        .unwrap();
+
+    assert!(result
+        .xml
+        .contains(r#"<PartyName LanguageCode="ja">桜レコード株式会社</PartyName>"#));
+    assert!(result
+        .xml
+        .contains("<TradingName>Sakura Music</TradingName>"));
+
+    let parsed = DDEXParser::new()
+        .parse(Cursor::new(result.xml.as_bytes()))
+        .unwrap();
+    let sender = &parsed.flat.sender;
+
+    assert_eq!(sender.name, "Sakura Records Ltd.");
+    assert_eq!(sender.names.len(), 2);
+    assert_eq!(sender.names[0].text, "Sakura Records Ltd.");
+    assert_eq!(sender.names[0].language_code.as_deref(), Some("en"));
+    assert_eq!(sender.names[1].text, "桜レコード株式会社");
+    assert_eq!(sender.names[1].language_code.as_deref(), Some("ja"));
+    assert_eq!(sender.trading_name.as_deref(), Some("Sakura Music"));
+
+    let recipient = &parsed.flat.recipient;
+    assert_eq!(recipient.names.len(), 1);
+    assert_eq!(recipient.trading_name, None);
+}
@@ -0,0 +1,93 @@
+use ddex_builder::ast::Element;
+use ddex_builder::builder::{
+    BuildRequest, LocalizedStringRequest, MessageHeaderRequest, PartyRequest,
+};
+use ddex_builder::preflight::unresolved_party_references;
+use ddex_builder::{BuildOptions, DDEXBuilder};
+
+fn with_child(mut parent: Element, child: Element) -> Element {
+    parent.add_child(child);
+    parent
+}
+
+fn party(reference: &str) -> Element {
+    with_child(
+        Element::new("Party"),
+        Element::new("PartyReference").with_text(reference),
+    )
+}
+
+fn message(rights_controller: &str) -> Element {
+    let party_list = with_child(Element::new("PartyList"), party("PLabel"));
+    let deal_terms = with_child(
+        Element::new("DealTerms"),
+        with_child(
+            Element::new("RightsController"),
+            Element::new("RightsControllerPartyReference").with_text(rights_controller),
+        ),
+    );
+    let deal_list = with_child(
+        Element::new("DealList"),
+        with_child(
+            Element::new("ReleaseDeal"),
+            with_child(Element::new("Deal"), deal_terms),
+        ),
+    );
+
+    let root = with_child(Element::new("NewReleaseMessage"), party_list);
+    with_child(root, deal_list)
+}
+
+#[test]
+fn test_undefined_rights_controller_is_reported() {
+    let unresolved = unresolved_party_references(&message("PMissing"));
+
+    assert_eq!(unresolved.len(), 1);
+    assert_eq!(unresolved[0].code, "UNRESOLVED_PARTY_REFERENCE");
+    assert_eq!(unresolved[0].field, "RightsControllerPartyReference");
+    assert!(unresolved[0].message.contains("PMissing"));
+    assert_eq!(
+        unresolved[0].location,
+        "NewReleaseMessage/DealList/ReleaseDeal/Deal/DealTerms/RightsController/RightsControllerPartyReference"
+    );
+}
+
+#[test]
+fn test_defined_rights_controller_passes() {
+    assert!(unresolved_party_references(&message("PLabel")).is_empty());
+}
+
+#[test]
+fn test_header_party_references_are_not_dangling() {
+    let sender = PartyRequest {
+        party_name: vec![LocalizedStringRequest {
+            text: "Sender".to_string(),
+            language_code: None,
+        }],
+        party_reference: Some("PSender".to_string()),
+        ..Default::default()
+    };
+    let request = BuildRequest {
+        header: MessageHeaderRequest {
+            message_id: Some("PARTY_REFS_001".to_string()),
+            message_sender: sender.clone(),
+            message_recipient: sender,
+            ..Default::default()
+        },
+        version: "4.3".to_string(),
+        profile: None,
+        releases: vec![],
+        deals: vec![],
+        extensions: None,
+    };
+    let options = BuildOptions {
+        validate_party_references: true,
+        ..Default::default()
+    };
+
+    let result = DDEXBuilder::new().build(request, options).unwrap();
+
+    assert!(result
+        .xml
+        .contains("<PartyReference>PSender</PartyReference>"));
+}
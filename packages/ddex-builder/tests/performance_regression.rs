@@ -155,6 +155,7 @@ fn create_test_request(track_count: usize) -> BuildRequest {
             title: format!("Test Track {}", i + 1),
             duration: format!("PT{}M{}S", 3 + (i % 4), 15 + (i % 45)),
             artist: format!("Artist {}", (i % 5) + 1), // Simulate repeated artists
+            ..Default::default()
         });
     }
 
@@ -167,7 +168,7 @@ fn create_test_request(track_count: usize) -> BuildRequest {
                     language_code: Some("en".to_string()),
                 }],
                 party_id: Some("SENDER_PERF".to_string()),
-                party_reference: None,
+                ..Default::default()
             },
             message_recipient: PartyRequest {
                 party_name: vec![LocalizedStringRequest {
@@ -175,10 +176,11 @@ fn create_test_request(track_count: usize) -> BuildRequest {
                     language_code: Some("en".to_string()),
                 }],
                 party_id: Some("RECIP_PERF".to_string()),
-                party_reference: None,
+                ..Default::default()
             },
             message_control_type: Some("LiveMessage".to_string()),
             message_created_date_time: None,
+            message_thread_id: None,
         },
         version: "4.3".to_string(),
         profile: Some("CommonReleaseTypes/14/AudioAlbumMusicOnly".to_string()),
@@ -200,7 +202,7 @@ fn create_test_request(track_count: usize) -> BuildRequest {
             release_date: Some("2024-01-01".to_string()),
             upc: Some("123456789012".to_string()),
             tracks,
-            resource_references: None,
+            ..Default::default()
         }],
         deals: vec![],
         extensions: None,
@@ -0,0 +1,90 @@
+use ddex_builder::builder::{
+    BuildRequest, LocalizedStringRequest, MessageHeaderRequest, ReleaseRequest, TrackRequest,
+};
+use ddex_builder::{BuildOptions, DDEXBuilder};
+
+mod common;
+use common::party;
+
+/// A one-track release with no control type or profile of its own
+fn request(n: usize) -> BuildRequest {
+    BuildRequest {
+        header: MessageHeaderRequest {
+            message_id: Some(format!("MSG_{:03}", n)),
+            message_sender: party("Sender"),
+            message_recipient: party("Recipient"),
+            ..Default::default()
+        },
+        version: "4.3".to_string(),
+        profile: None,
+        releases: vec![ReleaseRequest {
+            release_id: format!("REL{:03}", n),
+            release_reference: Some(format!("R{}", n)),
+            title: vec![LocalizedStringRequest {
+                text: format!("Album {}", n),
+                language_code: None,
+            }],
+            artist: "Artist".to_string(),
+            upc: Some("123456789012".to_string()),
+            tracks: vec![TrackRequest {
+                track_id: format!("TRK{:03}", n),
+                resource_reference: Some(format!("A{}", n)),
+                isrc: "USRC17607839".to_string(),
+                title: "Track".to_string(),
+                duration: "PT3M00S".to_string(),
+                artist: "Artist".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }],
+        deals: vec![],
+        extensions: None,
+    }
+}
+
+#[test]
+fn test_preset_applies_to_every_build() {
+    let mut builder = DDEXBuilder::new();
+    builder.apply_preset("audio_album").unwrap();
+
+    for n in 0..100 {
+        let result = builder.build(request(n), BuildOptions::default()).unwrap();
+
+        assert!(result
+            .xml
+            .contains("<MessageControlType>LiveMessage</MessageControlType>"));
+        // AudioAlbum profile rules run even though the request names no profile
+        assert!(result
+            .warnings
+            .iter()
+            .any(|w| w.code == "ALBUM_TRACK_COUNT"));
+    }
+
+    assert_eq!(builder.preset().unwrap().name, "audio_album");
+}
+
+#[test]
+fn test_request_values_override_preset() {
+    let mut builder = DDEXBuilder::new();
+    builder.apply_preset("audio_album").unwrap();
+
+    let mut request = request(0);
+    request.header.message_control_type = Some("TestMessage".to_string());
+    request.profile = Some("AudioSingle".to_string());
+
+    let result = builder.build(request, BuildOptions::default()).unwrap();
+    assert!(result
+        .xml
+        .contains("<MessageControlType>TestMessage</MessageControlType>"));
+    assert!(!result
+        .warnings
+        .iter()
+        .any(|w| w.code == "ALBUM_TRACK_COUNT"));
+}
+
+#[test]
+fn test_unknown_preset_is_rejected() {
+    let mut builder = DDEXBuilder::new();
+    assert!(builder.apply_preset("no_such_preset").is_err());
+    assert!(builder.preset().is_none());
+}
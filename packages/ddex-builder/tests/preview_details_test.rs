@@ -0,0 +1,108 @@
+use ddex_builder::builder::{
+    BuildRequest, LocalizedStringRequest, PreviewRequest, ReleaseRequest, TrackRequest,
+};
+use ddex_builder::preflight::PreflightLevel;
+use ddex_builder::{BuildError, BuildOptions, DDEXBuilder};
+
+mod common;
+use common::header;
+
+fn request(preview: Option<PreviewRequest>) -> BuildRequest {
+    BuildRequest {
+        header: header("MSG_PREVIEW_001"),
+        version: "4.3".to_string(),
+        profile: None,
+        releases: vec![ReleaseRequest {
+            release_id: "REL001".to_string(),
+            release_reference: Some("R1".to_string()),
+            title: vec![LocalizedStringRequest {
+                text: "Album".to_string(),
+                language_code: None,
+            }],
+            artist: "Artist".to_string(),
+            tracks: vec![TrackRequest {
+                track_id: "TRK001".to_string(),
+                resource_reference: Some("A1".to_string()),
+                isrc: "USRC17607839".to_string(),
+                title: "Track".to_string(),
+                duration: "PT3M00S".to_string(),
+                artist: "Artist".to_string(),
+                preview,
+                ..Default::default()
+            }],
+            ..Default::default()
+        }],
+        deals: vec![],
+        extensions: None,
+    }
+}
+
+fn strict() -> BuildOptions {
+    BuildOptions {
+        preflight_level: PreflightLevel::Strict,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_preview_details_are_emitted() {
+    let preview = PreviewRequest {
+        start: "PT30S".to_string(),
+        duration: "PT30S".to_string(),
+    };
+    let result = DDEXBuilder::new()
+        .build(request(Some(preview)), strict())
+        .unwrap();
+
+    assert!(result.xml.contains("<PreviewDetails>"));
+    assert!(result.xml.contains("<StartPoint>30</StartPoint>"));
+    assert!(result.xml.contains("<Duration>PT30S</Duration>"));
+    // No file was given, so none is emitted alongside the preview
+    assert!(!result.xml.contains("<File>"));
+}
+
+#[test]
+fn test_no_technical_details_without_preview() {
+    let result = DDEXBuilder::new().build(request(None), strict()).unwrap();
+
+    assert!(!result.xml.contains("<TechnicalDetails>"));
+    assert!(!result.xml.contains("<PreviewDetails>"));
+}
+
+#[test]
+fn test_preview_past_end_of_track_fails_validation() {
+    let preview = PreviewRequest {
+        start: "PT2M45S".to_string(),
+        duration: "PT30S".to_string(),
+    };
+
+    match DDEXBuilder::new().build(request(Some(preview)), strict()) {
+        Err(BuildError::ValidationFailed { errors }) => {
+            assert!(errors
+                .iter()
+                .any(|e| e.starts_with("PREVIEW_EXCEEDS_DURATION")));
+        }
+        other => panic!(
+            "expected a validation error, got {:?}",
+            other.map(|r| r.xml)
+        ),
+    }
+}
+
+#[test]
+fn test_malformed_preview_fails_validation() {
+    let preview = PreviewRequest {
+        start: "30 seconds".to_string(),
+        duration: "PT30S".to_string(),
+    };
+
+    match DDEXBuilder::new().build(request(Some(preview)), strict()) {
+        Err(BuildError::ValidationFailed { errors }) => {
+            assert!(errors.iter().any(|e| e.starts_with("INVALID_PREVIEW")));
+        }
+        other => panic!(
+            "expected a validation error, got {:?}",
+            other.map(|r| r.xml)
+        ),
+    }
+}
@@ -0,0 +1,124 @@
+use ddex_builder::builder::{
+    BuildRequest, HashSumRequest, ImageRequest, LocalizedStringRequest, ReferencedFile,
+    ReleaseRequest, TrackRequest,
+};
+use ddex_builder::{BuildOptions, DDEXBuilder};
+use std::io::Write;
+
+mod common;
+use common::header;
+
+fn track(track_id: &str, file_path: &str, hash_sum: Option<HashSumRequest>) -> TrackRequest {
+    TrackRequest {
+        track_id: track_id.to_string(),
+        resource_reference: Some(format!("A{}", track_id)),
+        isrc: "USRC17607839".to_string(),
+        title: format!("Song {}", track_id),
+        duration: "PT3M00S".to_string(),
+        artist: "Artist".to_string(),
+        file_path: Some(file_path.to_string()),
+        hash_sum,
+        ..Default::default()
+    }
+}
+
+fn request(tracks: Vec<TrackRequest>, images: Vec<ImageRequest>) -> BuildRequest {
+    BuildRequest {
+        header: header("MSG_FILES_001"),
+        version: "4.3".to_string(),
+        profile: None,
+        releases: vec![ReleaseRequest {
+            release_id: "REL001".to_string(),
+            release_reference: Some("R1".to_string()),
+            title: vec![LocalizedStringRequest {
+                text: "Packaged Album".to_string(),
+                language_code: None,
+            }],
+            artist: "Artist".to_string(),
+            tracks,
+            images,
+            ..Default::default()
+        }],
+        deals: vec![],
+        extensions: None,
+    }
+}
+
+#[test]
+fn test_audio_and_image_files_are_listed_with_their_references() {
+    let mut audio = tempfile::NamedTempFile::new().unwrap();
+    audio.write_all(b"test").unwrap();
+    let audio_path = audio.path().to_str().unwrap().to_string();
+    let given_hash = HashSumRequest {
+        algorithm: "MD5".to_string(),
+        value: "098f6bcd4621d373cade4e832627b4f6".to_string(),
+    };
+
+    let request = request(
+        vec![
+            track("1", &audio_path, None),
+            track("2", "audio/02.flac", Some(given_hash.clone())),
+        ],
+        vec![ImageRequest {
+            resource_reference: "A3".to_string(),
+            image_type: "FrontCoverImage".to_string(),
+            file_path: Some("images/cover.jpg".to_string()),
+            hash_sum: Some(HashSumRequest {
+                algorithm: "SHA256".to_string(),
+                value: "5f70bf18a086007016e948b04aed3b82103a36bea41755b6cddfaf10ace3c6ef"
+                    .to_string(),
+            }),
+        }],
+    );
+    let result = DDEXBuilder::new()
+        .build(request, BuildOptions::default())
+        .unwrap();
+
+    assert_eq!(
+        result.referenced_files,
+        vec![
+            ReferencedFile {
+                resource_reference: "A1".to_string(),
+                uri: audio_path,
+                hash_sum: Some(HashSumRequest {
+                    algorithm: "SHA256".to_string(),
+                    // SHA-256 of "test", computed from the file
+                    value: "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08"
+                        .to_string(),
+                }),
+            },
+            ReferencedFile {
+                resource_reference: "A2".to_string(),
+                uri: "audio/02.flac".to_string(),
+                hash_sum: Some(given_hash),
+            },
+            ReferencedFile {
+                resource_reference: "A3".to_string(),
+                uri: "images/cover.jpg".to_string(),
+                hash_sum: Some(HashSumRequest {
+                    algorithm: "SHA256".to_string(),
+                    value: "5f70bf18a086007016e948b04aed3b82103a36bea41755b6cddfaf10ace3c6ef"
+                        .to_string(),
+                }),
+            },
+        ]
+    );
+
+    // The image is a resource of the release like its tracks
+    assert!(result.xml.contains("<Type>FrontCoverImage</Type>"));
+    assert!(result
+        .xml
+        .contains("<ReleaseResourceReference>A3</ReleaseResourceReference>"));
+}
+
+#[test]
+fn test_resources_without_files_are_not_listed() {
+    let mut untracked = track("1", "", None);
+    untracked.file_path = None;
+
+    let result = DDEXBuilder::new()
+        .build(request(vec![untracked], vec![]), BuildOptions::default())
+        .unwrap();
+
+    assert!(result.referenced_files.is_empty());
+}
@@ -0,0 +1,93 @@
+use ddex_builder::builder::{BuildRequest, LocalizedStringRequest, ReleaseRequest, TrackRequest};
+use ddex_builder::{BuildOptions, DDEXBuilder};
+use ddex_parser::transform::graph::GraphBuilder;
+use ddex_parser::ERNVersion;
+use std::io::Cursor;
+
+mod common;
+use common::header;
+
+fn preorder_request() -> BuildRequest {
+    BuildRequest {
+        header: header("MSG_PREORDER_001"),
+        version: "4.3".to_string(),
+        profile: None,
+        releases: vec![ReleaseRequest {
+            release_id: "A10301A0000000001A".to_string(),
+            release_reference: Some("R1".to_string()),
+            title: vec![LocalizedStringRequest {
+                text: "Coming Soon".to_string(),
+                language_code: None,
+            }],
+            artist: "Artist".to_string(),
+            release_date: Some("2024-06-07".to_string()),
+            preorder_date: Some("2024-05-01T00:00:00Z".to_string()),
+            tracks: vec![TrackRequest {
+                track_id: "TRK001".to_string(),
+                resource_reference: Some("A1".to_string()),
+                isrc: "USRC17607839".to_string(),
+                title: "Track".to_string(),
+                duration: "PT3M00S".to_string(),
+                artist: "Artist".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }],
+        deals: vec![],
+        extensions: None,
+    }
+}
+
+#[test]
+fn test_preorder_and_availability_dates_round_trip_apart() {
+    let xml = DDEXBuilder::new()
+        .build(preorder_request(), BuildOptions::default())
+        .unwrap()
+        .xml;
+    assert!(xml.contains("<ReleaseVisibilityReference>V0</ReleaseVisibilityReference>"));
+    assert!(xml.contains(
+        "<ReleaseDisplayStartDateTime>2024-05-01T00:00:00Z</ReleaseDisplayStartDateTime>"
+    ));
+
+    let message = GraphBuilder::new(ERNVersion::V4_3)
+        .build_from_xml(Cursor::new(xml.as_bytes()))
+        .unwrap();
+    let release = &message.releases[0];
+
+    let preorder = release
+        .visibility
+        .as_ref()
+        .and_then(|visibility| visibility.release_display_start)
+        .expect("pre-order date should survive the round trip");
+    let availability = release
+        .release_date
+        .iter()
+        .find(|event| event.release_event_type == "ReleaseDate")
+        .and_then(|event| event.event_date)
+        .expect("availability date should survive the round trip");
+
+    assert_eq!(preorder.to_rfc3339(), "2024-05-01T00:00:00+00:00");
+    assert_eq!(availability.to_rfc3339(), "2024-06-07T00:00:00+00:00");
+    assert!(preorder < availability);
+    // Neither is a deal: visibility dates are independent of deal validity
+    assert!(message.deals.is_empty());
+}
+
+#[test]
+fn test_availability_start_is_accepted_for_the_release_date() {
+    let request: ReleaseRequest = serde_json::from_value(serde_json::json!({
+        "release_id": "A10301A0000000001A",
+        "release_reference": "R1",
+        "title": [],
+        "artist": "Artist",
+        "label": null,
+        "availability_start": "2024-06-07",
+        "upc": null,
+        "tracks": [],
+        "resource_references": null
+    }))
+    .unwrap();
+
+    assert_eq!(request.release_date.as_deref(), Some("2024-06-07"));
+    assert_eq!(request.preorder_date, None);
+}
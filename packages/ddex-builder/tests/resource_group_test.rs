@@ -0,0 +1,134 @@
+use ddex_builder::{BuildOptions, DDEXBuilder};
+use ddex_parser::transform::graph::GraphBuilder;
+use ddex_parser::{DDEXParser, ERNVersion};
+use std::io::Cursor;
+
+// A double album whose second disc ends with a bonus track
+const BONUS_TRACK_ERN43: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/43" MessageSchemaVersionId="ern/43">
+  <MessageHeader>
+    <MessageId>MSG_GROUPS_001</MessageId>
+    <MessageSender>
+      <PartyId>PADPIDA_SENDER</PartyId>
+      <PartyName><FullName>Test Label</FullName></PartyName>
+    </MessageSender>
+    <MessageRecipient>
+      <PartyId>PADPIDA_RECIPIENT</PartyId>
+      <PartyName><FullName>Test DSP</FullName></PartyName>
+    </MessageRecipient>
+    <MessageCreatedDateTime>2024-01-15T10:00:00Z</MessageCreatedDateTime>
+  </MessageHeader>
+  <ResourceList>
+    <SoundRecording>
+      <ResourceReference>A1</ResourceReference>
+      <SoundRecordingId><ISRC>USRC17607831</ISRC></SoundRecordingId>
+      <ReferenceTitle><TitleText>Opener</TitleText></ReferenceTitle>
+      <Duration>PT3M0S</Duration>
+    </SoundRecording>
+    <SoundRecording>
+      <ResourceReference>A2</ResourceReference>
+      <SoundRecordingId><ISRC>USRC17607832</ISRC></SoundRecordingId>
+      <ReferenceTitle><TitleText>Second Side</TitleText></ReferenceTitle>
+      <Duration>PT3M0S</Duration>
+    </SoundRecording>
+    <SoundRecording>
+      <ResourceReference>A3</ResourceReference>
+      <SoundRecordingId><ISRC>USRC17607833</ISRC></SoundRecordingId>
+      <ReferenceTitle><TitleText>Bonus Demo</TitleText></ReferenceTitle>
+      <Duration>PT3M0S</Duration>
+    </SoundRecording>
+  </ResourceList>
+  <ReleaseList>
+    <Release>
+      <ReleaseReference>R1</ReleaseReference>
+      <ReleaseType>Album</ReleaseType>
+      <ReleaseId><GRid>A1UCASE0000000401X</GRid></ReleaseId>
+      <ReferenceTitle><TitleText>Double Album</TitleText></ReferenceTitle>
+      <ResourceGroup>
+        <ResourceGroup>
+          <SequenceNumber>1</SequenceNumber>
+          <ResourceGroupContentItem>
+            <SequenceNumber>1</SequenceNumber>
+            <ReleaseResourceReference>A1</ReleaseResourceReference>
+          </ResourceGroupContentItem>
+        </ResourceGroup>
+        <ResourceGroup>
+          <SequenceNumber>2</SequenceNumber>
+          <ResourceGroupContentItem>
+            <SequenceNumber>1</SequenceNumber>
+            <ReleaseResourceReference>A2</ReleaseResourceReference>
+          </ResourceGroupContentItem>
+          <ResourceGroupContentItem>
+            <SequenceNumber>2</SequenceNumber>
+            <ReleaseResourceReference>A3</ReleaseResourceReference>
+            <IsBonusResource>true</IsBonusResource>
+          </ResourceGroupContentItem>
+        </ResourceGroup>
+      </ResourceGroup>
+    </Release>
+  </ReleaseList>
+</ern:NewReleaseMessage>"#;
+
+#[test]
+fn test_bonus_content_item_survives_round_trip() {
+    let parsed = DDEXParser::new()
+        .parse(Cursor::new(BONUS_TRACK_ERN43.as_bytes()))
+        .unwrap();
+    let tracks = &parsed.releases()[0].tracks;
+    assert_eq!(tracks.len(), 3);
+    assert!(tracks[2].is_bonus);
+    assert_eq!(tracks[2].track_number, Some(2));
+    assert_eq!(tracks[2].disc_number, Some(2));
+
+    let request = DDEXBuilder::from_parsed(&parsed);
+    let groups = &request.releases[0].resource_groups;
+    assert_eq!(groups.len(), 2);
+    assert!(groups[1].content_items[1].is_bonus);
+
+    let xml = DDEXBuilder::new()
+        .build(request, BuildOptions::default())
+        .unwrap()
+        .xml;
+    assert!(xml.contains("<IsBonusResource>true</IsBonusResource>"));
+
+    let rebuilt = GraphBuilder::new(ERNVersion::V4_3)
+        .build_from_xml(Cursor::new(xml.as_bytes()))
+        .unwrap();
+    let references = &rebuilt.releases[0].release_resource_reference_list;
+    assert_eq!(references.len(), 3);
+    for (reference, (resource, disc, track, bonus)) in
+        references
+            .iter()
+            .zip([("A1", 1, 1, false), ("A2", 2, 1, false), ("A3", 2, 2, true)])
+    {
+        assert_eq!(reference.resource_reference, resource);
+        assert_eq!(reference.disc_number, Some(disc));
+        assert_eq!(reference.sequence_number, Some(track));
+        assert_eq!(reference.is_bonus, bonus);
+        assert!(!reference.is_hidden);
+    }
+}
+
+#[test]
+fn test_hidden_content_item_is_emitted() {
+    let parsed = DDEXParser::new()
+        .parse(Cursor::new(BONUS_TRACK_ERN43.as_bytes()))
+        .unwrap();
+    let mut request = DDEXBuilder::from_parsed(&parsed);
+    request.releases[0].resource_groups[0].content_items[0].is_hidden = true;
+
+    let xml = DDEXBuilder::new()
+        .build(request, BuildOptions::default())
+        .unwrap()
+        .xml;
+    let rebuilt = GraphBuilder::new(ERNVersion::V4_3)
+        .build_from_xml(Cursor::new(xml.as_bytes()))
+        .unwrap();
+    let hidden: Vec<_> = rebuilt.releases[0]
+        .release_resource_reference_list
+        .iter()
+        .filter(|reference| reference.is_hidden)
+        .map(|reference| reference.resource_reference.as_str())
+        .collect();
+    assert_eq!(hidden, ["A1"]);
+}
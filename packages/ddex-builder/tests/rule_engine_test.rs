@@ -0,0 +1,212 @@
+use ddex_builder::builder::{
+    BuildRequest, DealRequest, DealTerms, LocalizedStringRequest, ReleaseRequest, TrackRequest,
+};
+use ddex_builder::presets::{all_presets, ValidationRule};
+use ddex_builder::RuleEngine;
+use ddex_core::models::DependencyCondition;
+use indexmap::IndexMap;
+
+mod common;
+use common::header;
+
+fn track(id: &str, isrc: &str) -> TrackRequest {
+    TrackRequest {
+        track_id: id.to_string(),
+        isrc: isrc.to_string(),
+        title: "Track".to_string(),
+        duration: "PT3M00S".to_string(),
+        artist: "Artist".to_string(),
+        ..Default::default()
+    }
+}
+
+fn request() -> BuildRequest {
+    let mut extensions = IndexMap::new();
+    extensions.insert("VideoQuality".to_string(), "SD".to_string());
+
+    BuildRequest {
+        header: header("RULES_001"),
+        version: "4.3".to_string(),
+        profile: None,
+        releases: vec![ReleaseRequest {
+            release_id: "REL001".to_string(),
+            release_reference: Some("R1".to_string()),
+            title: vec![LocalizedStringRequest {
+                text: "Album".to_string(),
+                language_code: None,
+            }],
+            artist: "Artist".to_string(),
+            upc: Some("123456789012".to_string()),
+            tracks: vec![
+                track("TRK001", "USRC17607839"),
+                track("TRK002", "usrc-1760-7840"),
+            ],
+            ..Default::default()
+        }],
+        deals: vec![DealRequest {
+            deal_reference: Some("D1".to_string()),
+            deal_terms: DealTerms {
+                commercial_model_type: "SubscriptionModel".to_string(),
+                territory_code: vec!["US".to_string(), "GB".to_string()],
+                ..Default::default()
+            },
+            release_references: vec!["R1".to_string()],
+        }],
+        extensions: Some(extensions),
+    }
+}
+
+#[test]
+fn test_one_of_rule_from_parameters() {
+    let engine = RuleEngine::from_json(
+        r#"[
+            {
+                "field_name": "CommercialModelType",
+                "rule_type": "OneOf",
+                "message": "Only subscription or ad-supported deals",
+                "parameters": { "options": "SubscriptionModel, AdvertisementSupportedModel" }
+            },
+            {
+                "field_name": "VideoQuality",
+                "rule_type": "OneOf",
+                "message": "Video quality must be HD720, HD1080, or 4K",
+                "parameters": { "options": "HD720,HD1080,4K" }
+            }
+        ]"#,
+    )
+    .unwrap();
+
+    let errors = engine.evaluate(&request());
+
+    // The deal passes; the extension-provided video quality does not
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].code, "RULE_ONE_OF");
+    assert_eq!(errors[0].field, "VideoQuality");
+    assert_eq!(errors[0].location, "/extensions/VideoQuality");
+    assert_eq!(
+        errors[0].message,
+        "Video quality must be HD720, HD1080, or 4K"
+    );
+}
+
+#[test]
+fn test_regex_rule_from_parameters() {
+    let engine = RuleEngine::from_json(
+        r#"[{
+            "field_name": "ISRC",
+            "rule_type": "Regex",
+            "parameters": { "pattern": "[A-Z]{2}[A-Z0-9]{3}\\d{7}" }
+        }]"#,
+    )
+    .unwrap();
+
+    let errors = engine.evaluate(&request());
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].code, "RULE_REGEX");
+    assert_eq!(errors[0].location, "/releases[0]/tracks[1]/isrc");
+    assert!(errors[0].message.contains("usrc-1760-7840"));
+}
+
+#[test]
+fn test_required_and_territory_rules() {
+    let engine = RuleEngine::from_json(
+        r#"[
+            { "field_name": "ReleaseDate", "rule_type": "Required" },
+            { "field_name": "TerritoryCode", "rule_type": "TerritoryCode",
+              "parameters": { "allowed": "Worldwide,WW" } }
+        ]"#,
+    )
+    .unwrap();
+
+    let codes: Vec<_> = engine
+        .evaluate(&request())
+        .into_iter()
+        .map(|e| e.code)
+        .collect();
+
+    assert_eq!(
+        codes,
+        vec![
+            "RULE_REQUIRED",
+            "RULE_TERRITORY_CODE",
+            "RULE_TERRITORY_CODE"
+        ]
+    );
+}
+
+#[test]
+fn test_invalid_rules_are_rejected_on_load() {
+    assert!(RuleEngine::from_json(r#"[{ "field_name": "ISRC", "rule_type": "Magic" }]"#).is_err());
+    assert!(RuleEngine::from_json(
+        r#"[{ "field_name": "ISRC", "rule_type": "Regex", "parameters": { "pattern": "(" } }]"#
+    )
+    .is_err());
+    assert!(RuleEngine::from_json(r#"[{ "field_name": "X", "rule_type": "OneOf" }]"#).is_err());
+}
+
+#[test]
+fn test_video_release_requires_video_resource() {
+    let engine = RuleEngine::from_json(
+        r#"[{
+            "field_name": "VideoResource",
+            "rule_type": "Dependency",
+            "message": "Video releases need a video resource",
+            "parameters": {
+                "depends_on": "ReleaseType",
+                "condition": "RequiredWhenEquals",
+                "value": "VideoSingle"
+            }
+        }]"#,
+    )
+    .unwrap();
+
+    let mut video = request();
+    let extensions = video.extensions.as_mut().unwrap();
+    extensions.insert("ReleaseType".to_string(), "VideoSingle".to_string());
+
+    let errors = engine.evaluate(&video);
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].code, "RULE_DEPENDENCY");
+    assert_eq!(errors[0].field, "VideoResource");
+    assert_eq!(errors[0].location, "/extensions/ReleaseType");
+    assert_eq!(errors[0].message, "Video releases need a video resource");
+
+    // Satisfied once the resource is there, and irrelevant for audio releases
+    let mut with_resource = video.clone();
+    let extensions = with_resource.extensions.as_mut().unwrap();
+    extensions.insert("VideoResource".to_string(), "V1".to_string());
+    assert!(engine.evaluate(&with_resource).is_empty());
+    assert!(engine.evaluate(&request()).is_empty());
+}
+
+#[test]
+fn test_dependency_rules_defined_on_a_preset() {
+    let mut preset = all_presets().into_values().next().unwrap();
+    preset.validation_rules.clear();
+    preset.validation_rules.insert(
+        "VideoResource".to_string(),
+        ValidationRule::Dependency {
+            depends_on: "ReleaseType".to_string(),
+            condition: DependencyCondition::RequiredWhenEquals("VideoSingle".to_string()),
+        },
+    );
+    preset.validation_rules.insert(
+        "VideoQuality".to_string(),
+        ValidationRule::Custom("qc".to_string()),
+    );
+
+    let engine = RuleEngine::from_preset(&preset).unwrap();
+    assert_eq!(engine.len(), 1);
+
+    let mut video = request();
+    let extensions = video.extensions.as_mut().unwrap();
+    extensions.insert("ReleaseType".to_string(), "VideoSingle".to_string());
+
+    let codes: Vec<_> = engine
+        .evaluate(&video)
+        .into_iter()
+        .map(|e| e.code)
+        .collect();
+    assert_eq!(codes, vec!["RULE_DEPENDENCY"]);
+}
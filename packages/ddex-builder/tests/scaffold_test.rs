@@ -0,0 +1,66 @@
+use ddex_builder::preflight::PreflightLevel;
+use ddex_builder::presets::generic;
+use ddex_builder::scaffold::{placeholder, scaffold, scaffold_request};
+use ddex_builder::{BuildOptions, BuildRequest, DDEXBuilder, RuleEngine};
+
+/// Replace every placeholder of a scaffold request with a real value
+fn fill(request: &BuildRequest, values: &[(&str, &str)]) -> BuildRequest {
+    let mut json = serde_json::to_string(request).unwrap();
+    for (field, value) in values {
+        json = json.replace(&placeholder(field), value);
+    }
+    assert!(!json.contains("{{"), "unfilled placeholder in {}", json);
+    serde_json::from_str(&json).unwrap()
+}
+
+#[test]
+fn test_scaffold_has_placeholder_for_each_required_field() {
+    let preset = generic::audio_single();
+    let xml = scaffold("AudioSingle", &preset.required_fields).unwrap();
+
+    assert!(xml.contains("<ISRC>{{ISRC}}</ISRC>"));
+    assert!(xml.contains("{{ReleaseDate}}"));
+    assert!(xml.contains("<GenreText>{{Genre}}</GenreText>"));
+    assert!(xml.contains("{{TrackTitle}}"));
+    assert!(xml.contains("{{ArtistName}}"));
+    assert!(xml.contains("<TerritoryCode>Worldwide</TerritoryCode>"));
+}
+
+#[test]
+fn test_filled_scaffold_passes_preset_validation() {
+    let preset = generic::audio_single();
+    let rules = RuleEngine::from_preset(&preset).unwrap();
+    let request = scaffold_request("AudioSingle", &preset.required_fields);
+    assert!(!rules.evaluate(&request).is_empty());
+
+    let filled = fill(
+        &request,
+        &[
+            ("MessageId", "MSG_SCAFFOLD_001"),
+            ("MessageCreatedDateTime", "2024-06-07T00:00:00Z"),
+            ("SenderName", "Example Label"),
+            ("SenderPartyId", "PADPIDA2014120301U"),
+            ("RecipientName", "Example DSP"),
+            ("RecipientPartyId", "PADPIDA2014120302U"),
+            ("ReleaseId", "A10301A0000000001A"),
+            ("ReleaseTitle", "Example Single"),
+            ("ArtistName", "Example Artist"),
+            ("LabelName", "Example Label"),
+            ("ReleaseDate", "2024-06-07"),
+            ("UPC", "123456789014"),
+            ("ISRC", "USRC17607839"),
+            ("TrackTitle", "Example Track"),
+            ("Duration", "PT3M30S"),
+            ("CommercialModelType", "SubscriptionModel"),
+            ("UseType", "Stream"),
+            ("Genre", "Pop"),
+        ],
+    );
+
+    assert!(rules.evaluate(&filled).is_empty());
+    let options = BuildOptions {
+        preflight_level: PreflightLevel::Strict,
+        ..Default::default()
+    };
+    DDEXBuilder::new().build(filled, options).unwrap();
+}
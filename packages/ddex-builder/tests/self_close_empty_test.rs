@@ -0,0 +1,87 @@
+use ddex_builder::builder::{
+    BuildRequest, LocalizedStringRequest, MessageHeaderRequest, PartyRequest,
+};
+use ddex_builder::determinism::{CanonMode, DeterminismConfig};
+use ddex_builder::{BuildOptions, DDEXBuilder};
+
+/// A request whose sender has an empty TradingName element
+fn request_with_empty_element() -> BuildRequest {
+    let party = |name: &str, trading_name: Option<String>| PartyRequest {
+        party_name: vec![LocalizedStringRequest {
+            text: name.to_string(),
+            language_code: None,
+        }],
+        trading_name,
+        ..Default::default()
+    };
+
+    BuildRequest {
+        header: MessageHeaderRequest {
+            message_id: Some("SELF_CLOSE_001".to_string()),
+            message_sender: party("Sender", Some(String::new())),
+            message_recipient: party("Recipient", None),
+            message_created_date_time: Some("2024-01-01T00:00:00Z".to_string()),
+            ..Default::default()
+        },
+        version: "4.3".to_string(),
+        profile: None,
+        releases: vec![],
+        deals: vec![],
+        extensions: None,
+    }
+}
+
+fn build(self_close_empty: bool, canon_mode: CanonMode) -> String {
+    let options = BuildOptions {
+        self_close_empty,
+        omit_empty: false,
+        determinism: Some(DeterminismConfig {
+            canon_mode,
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    DDEXBuilder::new()
+        .build(request_with_empty_element(), options)
+        .unwrap()
+        .xml
+}
+
+#[test]
+fn test_empty_element_self_closed_by_default() {
+    let options = BuildOptions {
+        omit_empty: false,
+        ..Default::default()
+    };
+    let xml = DDEXBuilder::new()
+        .build(request_with_empty_element(), options)
+        .unwrap()
+        .xml;
+
+    assert!(xml.contains("<TradingName/>"));
+}
+
+#[test]
+fn test_empty_element_self_closed_when_enabled() {
+    let xml = build(true, CanonMode::Pretty);
+
+    assert!(xml.contains("<TradingName/>"));
+    assert!(!xml.contains("<TradingName></TradingName>"));
+}
+
+#[test]
+fn test_empty_element_expanded_when_disabled() {
+    let xml = build(false, CanonMode::Pretty);
+
+    assert!(xml.contains("<TradingName></TradingName>"));
+    assert!(!xml.contains("<TradingName/>"));
+}
+
+#[test]
+fn test_canonical_output_unaffected() {
+    assert_eq!(
+        build(true, CanonMode::DbC14n),
+        build(false, CanonMode::DbC14n)
+    );
+}
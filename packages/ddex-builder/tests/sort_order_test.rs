@@ -0,0 +1,150 @@
+use ddex_builder::builder::{
+    BuildRequest, LocalizedStringRequest, MessageHeaderRequest, ReleaseRequest, SortOrder,
+    TrackRequest,
+};
+use ddex_builder::{BuildOptions, DDEXBuilder};
+
+mod common;
+use common::party;
+
+fn track(reference: &str, isrc: &str) -> TrackRequest {
+    TrackRequest {
+        track_id: format!("TRK_{}", reference),
+        resource_reference: Some(reference.to_string()),
+        isrc: isrc.to_string(),
+        title: format!("Track {}", reference),
+        duration: "PT3M00S".to_string(),
+        artist: "Artist".to_string(),
+        ..Default::default()
+    }
+}
+
+fn release(reference: &str, upc: &str, tracks: Vec<TrackRequest>) -> ReleaseRequest {
+    ReleaseRequest {
+        release_id: format!("A10301A000000000{}A", &reference[1..]),
+        release_reference: Some(reference.to_string()),
+        title: vec![LocalizedStringRequest {
+            text: format!("Album {}", reference),
+            language_code: None,
+        }],
+        artist: "Artist".to_string(),
+        release_date: Some("2024-01-01".to_string()),
+        upc: Some(upc.to_string()),
+        tracks,
+        ..Default::default()
+    }
+}
+
+/// Reference order and UPC/ISRC order deliberately disagree
+fn releases() -> Vec<ReleaseRequest> {
+    vec![
+        release(
+            "R1",
+            "602445790296",
+            vec![track("A2", "USRC17600002"), track("A1", "USRC17600004")],
+        ),
+        release(
+            "R2",
+            "602445790111",
+            vec![track("A3", "USRC17600003"), track("A4", "USRC17600001")],
+        ),
+        release("R3", "602445790203", vec![track("A5", "USRC17600005")]),
+    ]
+}
+
+fn request(releases: Vec<ReleaseRequest>) -> BuildRequest {
+    BuildRequest {
+        header: MessageHeaderRequest {
+            message_id: Some("MSG_SORT_001".to_string()),
+            message_sender: party("Sender"),
+            message_recipient: party("Recipient"),
+            message_created_date_time: Some("2024-01-01T00:00:00Z".to_string()),
+            ..Default::default()
+        },
+        version: "4.3".to_string(),
+        profile: None,
+        releases,
+        deals: vec![],
+        extensions: None,
+    }
+}
+
+fn build(releases: Vec<ReleaseRequest>, sort_order: SortOrder) -> String {
+    let options = BuildOptions {
+        sort_order,
+        ..Default::default()
+    };
+    DDEXBuilder::new()
+        .build(request(releases), options)
+        .unwrap()
+        .xml
+}
+
+/// Values of `element` in document order
+fn values(xml: &str, element: &str) -> Vec<String> {
+    let open = format!("<{}>", element);
+    let close = format!("</{}>", element);
+    xml.split(&open)
+        .skip(1)
+        .map(|rest| rest.split(&close).next().unwrap().to_string())
+        .collect()
+}
+
+#[test]
+fn test_sorted_output_ignores_insertion_order() {
+    let forwards = releases();
+    let mut backwards = releases();
+    backwards.reverse();
+
+    // Left in insertion order the two builds differ
+    assert_ne!(
+        build(forwards.clone(), SortOrder::Insertion),
+        build(backwards.clone(), SortOrder::Insertion)
+    );
+
+    for sort_order in [SortOrder::Reference, SortOrder::Identifier] {
+        assert_eq!(
+            build(forwards.clone(), sort_order),
+            build(backwards.clone(), sort_order),
+            "{:?} output should not depend on insertion order",
+            sort_order
+        );
+    }
+}
+
+#[test]
+fn test_sort_keys() {
+    let mut shuffled = releases();
+    shuffled.rotate_left(1);
+
+    let by_reference = build(shuffled.clone(), SortOrder::Reference);
+    assert_eq!(
+        values(&by_reference, "ReleaseReference"),
+        ["R1", "R2", "R3"]
+    );
+    assert_eq!(
+        values(&by_reference, "ResourceReference"),
+        ["A1", "A2", "A3", "A4", "A5"]
+    );
+    // A release's own tracks keep their sequence
+    assert_eq!(
+        values(&by_reference, "ReleaseResourceReference"),
+        ["A2", "A1", "A3", "A4", "A5"]
+    );
+
+    let by_identifier = build(shuffled, SortOrder::Identifier);
+    assert_eq!(
+        values(&by_identifier, "ReleaseReference"),
+        ["R2", "R3", "R1"]
+    );
+    assert_eq!(
+        values(&by_identifier, "ISRC"),
+        [
+            "USRC17600001",
+            "USRC17600002",
+            "USRC17600003",
+            "USRC17600004",
+            "USRC17600005"
+        ]
+    );
+}
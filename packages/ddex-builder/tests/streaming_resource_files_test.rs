@@ -0,0 +1,87 @@
+use ddex_builder::builder::{
+    LocalizedStringRequest, MessageHeaderRequest, PartyRequest, TrackRequest,
+};
+use ddex_builder::streaming::StreamingBuilder;
+use std::io::Write;
+
+fn header() -> MessageHeaderRequest {
+    let party = |name: &str| PartyRequest {
+        party_name: vec![LocalizedStringRequest {
+            text: name.to_string(),
+            language_code: None,
+        }],
+        ..Default::default()
+    };
+
+    MessageHeaderRequest {
+        message_id: Some("STREAM_FILES_001".to_string()),
+        message_sender: party("Sender"),
+        message_recipient: party("Recipient"),
+        message_created_date_time: Some("2024-01-01T00:00:00Z".to_string()),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_missing_resource_file_is_reported_before_finishing() {
+    let mut audio = tempfile::NamedTempFile::new().unwrap();
+    audio.write_all(b"RIFF").unwrap();
+    let present = audio.path().to_str().unwrap().to_string();
+    let missing = "/nonexistent/delivery/A2.flac";
+
+    let mut output = Vec::new();
+    let mut builder = StreamingBuilder::new(&mut output).unwrap();
+    builder.start_message(&header(), "ern/43").unwrap();
+    let present_ref = builder
+        .write_resource("T1", "Present", "Artist", None, None, Some(&present))
+        .unwrap();
+    let missing_ref = builder
+        .write_resource("T2", "Missing", "Artist", None, None, Some(missing))
+        .unwrap();
+    builder.finish_resources_start_releases().unwrap();
+    builder
+        .write_release(
+            "REL001",
+            "Album",
+            "Artist",
+            None,
+            None,
+            None,
+            None,
+            &[present_ref, missing_ref.clone()],
+        )
+        .unwrap();
+
+    let missing_files = builder.missing_resource_files();
+    assert_eq!(missing_files.len(), 1, "{:?}", missing_files);
+    assert_eq!(missing_files[0].path, missing);
+    assert_eq!(missing_files[0].resource_reference, missing_ref);
+    assert!(!missing_files[0].reason.is_empty());
+}
+
+#[test]
+fn test_track_request_file_paths_are_checked() {
+    let track = TrackRequest {
+        track_id: "1".to_string(),
+        isrc: "USRC17607839".to_string(),
+        title: "Song".to_string(),
+        duration: "PT3M00S".to_string(),
+        artist: "Artist".to_string(),
+        file_path: Some("/nonexistent/delivery/song.wav".to_string()),
+        hash_sum: Some(ddex_builder::builder::HashSumRequest {
+            algorithm: "SHA256".to_string(),
+            value: "9f86d081884c7d659a2feaa0c55ad015".to_string(),
+        }),
+        ..Default::default()
+    };
+
+    let mut output = Vec::new();
+    let mut builder = StreamingBuilder::new(&mut output).unwrap();
+    builder.start_message(&header(), "ern/43").unwrap();
+    builder.write_track_request(&track).unwrap();
+
+    let missing_files = builder.missing_resource_files();
+    assert_eq!(missing_files.len(), 1);
+    assert_eq!(missing_files[0].resource_reference, "A1");
+    assert_eq!(missing_files[0].path, "/nonexistent/delivery/song.wav");
+}
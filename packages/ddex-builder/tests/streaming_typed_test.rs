@@ -0,0 +1,160 @@
+use ddex_builder::builder::{
+    ContributorRequest, HashSumRequest, LocalizedStringRequest, MessageHeaderRequest, PartyRequest,
+    PreviewRequest, ReleaseRequest, TrackReleaseRequest, TrackRequest,
+};
+use ddex_builder::generator::xml_writer::check_well_formed;
+use ddex_builder::streaming::StreamingBuilder;
+
+fn header() -> MessageHeaderRequest {
+    let party = |name: &str, id: &str| PartyRequest {
+        party_name: vec![LocalizedStringRequest {
+            text: name.to_string(),
+            language_code: None,
+        }],
+        party_id: Some(id.to_string()),
+        ..Default::default()
+    };
+
+    MessageHeaderRequest {
+        message_id: Some("STREAM_TYPED_001".to_string()),
+        message_sender: party("Sender", "PADPIDA2014120301U"),
+        message_recipient: party("Recipient", "PADPIDA2014120302U"),
+        message_created_date_time: Some("2024-01-01T00:00:00Z".to_string()),
+        ..Default::default()
+    }
+}
+
+fn track() -> TrackRequest {
+    TrackRequest {
+        track_id: "T1".to_string(),
+        resource_reference: Some("A1".to_string()),
+        isrc: "USRC17607839".to_string(),
+        title: "Opening Track".to_string(),
+        duration: "PT3M30S".to_string(),
+        artist: "The Streamers".to_string(),
+        hash_sum: Some(HashSumRequest {
+            algorithm: "SHA256".to_string(),
+            value: "9f86d081884c7d659a2feaa0c55ad015".to_string(),
+        }),
+        preview: Some(PreviewRequest {
+            start: "PT30S".to_string(),
+            duration: "PT30S".to_string(),
+        }),
+        contributors: vec![ContributorRequest {
+            name: "Jane Producer".to_string(),
+            roles: vec!["Producer".to_string()],
+            instruments: vec!["Synthesizer".to_string()],
+        }],
+        ..Default::default()
+    }
+}
+
+fn release() -> ReleaseRequest {
+    ReleaseRequest {
+        release_id: "A1234567890123456X".to_string(),
+        release_reference: Some("R2".to_string()),
+        title: vec![LocalizedStringRequest {
+            text: "Streamed Album".to_string(),
+            language_code: Some("en".to_string()),
+        }],
+        display_title: vec![LocalizedStringRequest {
+            text: "Streamed Album (Deluxe)".to_string(),
+            language_code: None,
+        }],
+        artist: "The Streamers".to_string(),
+        label: Some("Stream Records".to_string()),
+        release_date: Some("2024-03-01".to_string()),
+        preorder_date: Some("2024-02-01T00:00:00Z".to_string()),
+        upc: Some("123456789012".to_string()),
+        tracks: vec![track()],
+        track_releases: vec![TrackReleaseRequest {
+            release_id: "A1234567890123457Y".to_string(),
+            release_reference: Some("R3".to_string()),
+            resource_reference: "A1".to_string(),
+            title: None,
+        }],
+        component_release_references: vec!["R1".to_string()],
+        ..Default::default()
+    }
+}
+
+/// A single release the bundle under test names as its component
+fn component() -> ReleaseRequest {
+    ReleaseRequest {
+        release_id: "A1234567890123455W".to_string(),
+        release_reference: Some("R1".to_string()),
+        title: vec![LocalizedStringRequest {
+            text: "Component Single".to_string(),
+            language_code: None,
+        }],
+        artist: "The Streamers".to_string(),
+        resource_references: Some(vec!["A1".to_string()]),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_streamed_release_request_writes_every_field() {
+    let mut output = Vec::new();
+    {
+        let mut builder = StreamingBuilder::new(&mut output).unwrap();
+        builder.start_message(&header(), "ern/43").unwrap();
+        assert_eq!(builder.write_track_request(&track()).unwrap(), "A1");
+        builder.finish_resources_start_releases().unwrap();
+        builder.write_release_request(&component()).unwrap();
+        assert_eq!(builder.write_release_request(&release()).unwrap(), "R2");
+        let stats = builder.finish_message().unwrap();
+        assert_eq!(stats.resources_written, 1);
+        assert_eq!(stats.releases_written, 2);
+    }
+    let xml = String::from_utf8(output).unwrap();
+    check_well_formed(&xml).unwrap();
+
+    for expected in [
+        // Track
+        "<ResourceReference>A1</ResourceReference>",
+        "<ISRC>USRC17607839</ISRC>",
+        "<TitleText>Opening Track</TitleText>",
+        "<FullName>Jane Producer</FullName>",
+        "<Role>Producer</Role>",
+        "<InstrumentType>Synthesizer</InstrumentType>",
+        "<Duration>PT3M30S</Duration>",
+        "<StartPoint>30</StartPoint>",
+        "<HashSumValue>9f86d081884c7d659a2feaa0c55ad015</HashSumValue>",
+        // Release
+        "<ReleaseReference>R2</ReleaseReference>",
+        "<GRid>A1234567890123456X</GRid>",
+        r#"<TitleText LanguageAndScriptCode="en">Streamed Album</TitleText>"#,
+        "<TitleText>Streamed Album (Deluxe)</TitleText>",
+        "<FullName>The Streamers</FullName>",
+        "<LabelName>Stream Records</LabelName>",
+        "<ICPN>123456789012</ICPN>",
+        "<ReleaseDate>2024-03-01</ReleaseDate>",
+        "<ReleaseVisibilityReference>V1</ReleaseVisibilityReference>",
+        "<ReleaseResourceReference>A1</ReleaseResourceReference>",
+        "<GRid>A1234567890123455W</GRid>",
+        // Track release and pre-order visibility, after every release
+        "<ReleaseReference>R3</ReleaseReference>",
+        "<DisplayTitleText>Opening Track</DisplayTitleText>",
+        "<VisibilityReference>V1</VisibilityReference>",
+        "<ReleaseDisplayStartDateTime>2024-02-01T00:00:00Z</ReleaseDisplayStartDateTime>",
+    ] {
+        assert!(xml.contains(expected), "missing {} in\n{}", expected, xml);
+    }
+
+    let release_end = xml.find("</Release>\n    <Release>").unwrap();
+    let track_release = xml.find("<TrackRelease>").unwrap();
+    let release_list_end = xml.find("</ReleaseList>").unwrap();
+    assert!(release_end < track_release && track_release < release_list_end);
+    assert!(xml.find("<DealList>").unwrap() > release_list_end);
+}
+
+#[test]
+fn test_bundle_components_must_be_streamed_first() {
+    let mut output = Vec::new();
+    let mut builder = StreamingBuilder::new(&mut output).unwrap();
+    builder.start_message(&header(), "ern/43").unwrap();
+    builder.finish_resources_start_releases().unwrap();
+
+    assert!(builder.write_release_request(&release()).is_err());
+}
@@ -0,0 +1,88 @@
+use ddex_builder::builder::{
+    BuildRequest, LocalizedStringRequest, MessageHeaderRequest, PartyRequest, ReleaseRequest,
+    TrackRequest,
+};
+use ddex_builder::{BuildOptions, DDEXBuilder};
+use ddex_parser::transform::graph::GraphBuilder;
+use ddex_parser::ERNVersion;
+use std::io::Cursor;
+
+fn localized(text: &str) -> LocalizedStringRequest {
+    LocalizedStringRequest {
+        text: text.to_string(),
+        language_code: None,
+    }
+}
+
+fn party(name: &str) -> PartyRequest {
+    PartyRequest {
+        party_name: vec![localized(name)],
+        ..Default::default()
+    }
+}
+
+fn album_by_a_with_track_by_b() -> BuildRequest {
+    BuildRequest {
+        header: MessageHeaderRequest {
+            message_id: Some("ARTISTS_001".to_string()),
+            message_sender: party("Sender"),
+            message_recipient: party("Recipient"),
+            ..Default::default()
+        },
+        version: "4.3".to_string(),
+        profile: None,
+        releases: vec![ReleaseRequest {
+            release_id: "REL001".to_string(),
+            release_reference: Some("R1".to_string()),
+            title: vec![localized("Album")],
+            artist: "A".to_string(),
+            tracks: vec![TrackRequest {
+                track_id: "T1".to_string(),
+                resource_reference: Some("A1".to_string()),
+                isrc: "USRC17607839".to_string(),
+                title: "Guest Spot".to_string(),
+                duration: "PT3M30S".to_string(),
+                artist: "B".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }],
+        deals: vec![],
+        extensions: None,
+    }
+}
+
+#[test]
+fn test_track_artist_round_trips_apart_from_release_artist() {
+    let xml = DDEXBuilder::new()
+        .build(album_by_a_with_track_by_b(), BuildOptions::default())
+        .unwrap()
+        .xml;
+
+    let message = GraphBuilder::new(ERNVersion::V4_3)
+        .build_from_xml(Cursor::new(xml.as_bytes()))
+        .unwrap();
+
+    let release_artists = &message.releases[0].display_artist;
+    assert_eq!(release_artists.len(), 1);
+    assert_eq!(release_artists[0].display_artist_name[0].text, "A");
+
+    let track_artists = &message.resources[0].display_artist;
+    assert_eq!(track_artists.len(), 1);
+    assert_eq!(track_artists[0].display_artist_name[0].text, "B");
+}
+
+#[test]
+fn test_track_without_artist_has_no_display_artist_name() {
+    let mut request = album_by_a_with_track_by_b();
+    request.releases[0].tracks[0].artist = String::new();
+
+    let xml = DDEXBuilder::new()
+        .build(request, BuildOptions::default())
+        .unwrap()
+        .xml;
+
+    let recording = &xml[xml.find("<SoundRecording>").unwrap()..];
+    let recording = &recording[..recording.find("</SoundRecording>").unwrap()];
+    assert!(!recording.contains("DisplayArtistName"));
+}
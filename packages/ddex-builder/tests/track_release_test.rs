@@ -0,0 +1,110 @@
+use ddex_builder::builder::{
+    BuildRequest, LocalizedStringRequest, ReleaseRequest, TrackReleaseRequest, TrackRequest,
+};
+use ddex_builder::preflight::PreflightLevel;
+use ddex_builder::{BuildError, BuildOptions, DDEXBuilder};
+use ddex_core::models::graph::ReleaseType;
+use ddex_parser::transform::graph::GraphBuilder;
+use ddex_parser::ERNVersion;
+use std::io::Cursor;
+
+mod common;
+use common::header;
+
+fn track(id: &str, reference: &str, isrc: &str, title: &str) -> TrackRequest {
+    TrackRequest {
+        track_id: id.to_string(),
+        resource_reference: Some(reference.to_string()),
+        isrc: isrc.to_string(),
+        title: title.to_string(),
+        duration: "PT3M00S".to_string(),
+        artist: "Artist".to_string(),
+        ..Default::default()
+    }
+}
+
+fn album_with_single(resource_reference: &str) -> BuildRequest {
+    BuildRequest {
+        header: header("MSG_TRACK_RELEASE_001"),
+        version: "4.3".to_string(),
+        profile: None,
+        releases: vec![ReleaseRequest {
+            release_id: "A10301A0000000001A".to_string(),
+            release_reference: Some("R1".to_string()),
+            title: vec![LocalizedStringRequest {
+                text: "Album".to_string(),
+                language_code: None,
+            }],
+            artist: "Artist".to_string(),
+            tracks: vec![
+                track("TRK001", "A1", "USRC17607839", "Opening"),
+                track("TRK002", "A2", "USRC17607840", "The Single"),
+            ],
+            track_releases: vec![TrackReleaseRequest {
+                release_id: "A10301A0000000002B".to_string(),
+                release_reference: Some("R2".to_string()),
+                resource_reference: resource_reference.to_string(),
+                title: None,
+            }],
+            ..Default::default()
+        }],
+        deals: vec![],
+        extensions: None,
+    }
+}
+
+#[test]
+fn test_track_release_links_album_and_resource() {
+    let xml = DDEXBuilder::new()
+        .build(album_with_single("A2"), BuildOptions::default())
+        .unwrap()
+        .xml;
+
+    // Every main release comes before the track releases
+    let track_release = xml.find("<TrackRelease>").expect("TrackRelease emitted");
+    assert!(xml.rfind("<Release>").unwrap() < track_release);
+    assert!(xml.contains("<DisplayTitleText>The Single</DisplayTitleText>"));
+    assert!(xml.contains("<ReleaseRelationshipType>IsReleaseFromRelease</ReleaseRelationshipType>"));
+
+    let message = GraphBuilder::new(ERNVersion::V4_3)
+        .build_from_xml(Cursor::new(xml.as_bytes()))
+        .unwrap();
+    assert_eq!(message.releases.len(), 2);
+
+    let single = &message.releases[1];
+    assert_eq!(single.release_reference, "R2");
+    assert_eq!(single.release_type, Some(ReleaseType::TrackRelease));
+    assert_eq!(single.display_title[0].text, "The Single");
+    assert_eq!(
+        single.release_resource_reference_list[0].resource_reference,
+        "A2"
+    );
+
+    let related = &single.related_releases[0];
+    assert_eq!(related.relationship_type, "IsReleaseFromRelease");
+    assert_eq!(related.release_id[0].value, "A10301A0000000001A");
+    assert_eq!(
+        message.releases[0].release_id[0].value,
+        related.release_id[0].value
+    );
+}
+
+#[test]
+fn test_track_release_must_offer_a_track_of_its_release() {
+    let options = BuildOptions {
+        preflight_level: PreflightLevel::Strict,
+        ..Default::default()
+    };
+    let error = DDEXBuilder::new()
+        .build(album_with_single("A9"), options)
+        .unwrap_err();
+
+    match error {
+        BuildError::ValidationFailed { errors } => {
+            assert!(errors
+                .iter()
+                .any(|e| e.starts_with("UNKNOWN_REFERENCE") && e.contains("A9")));
+        }
+        other => panic!("expected ValidationFailed, got {:?}", other),
+    }
+}
@@ -0,0 +1,113 @@
+use ddex_builder::builder::{
+    BuildRequest, DealRequest, DealTerms, LocalizedStringRequest, ReleaseRequest, TrackRequest,
+};
+use ddex_builder::error::BuildError;
+use ddex_builder::preflight::{PreflightLevel, PreflightValidator, ValidationConfig};
+use ddex_builder::{BuildOptions, DDEXBuilder, ValidationMode};
+
+mod common;
+use common::header;
+
+/// A request with three independent problems: a bad UPC, a bad ISRC and a
+/// deal pointing at a release that does not exist.
+fn request_with_three_errors() -> BuildRequest {
+    BuildRequest {
+        header: header("MODE_001"),
+        version: "4.3".to_string(),
+        profile: None,
+        releases: vec![ReleaseRequest {
+            release_id: "REL001".to_string(),
+            release_reference: Some("R1".to_string()),
+            title: vec![LocalizedStringRequest {
+                text: "Album".to_string(),
+                language_code: None,
+            }],
+            artist: "Artist".to_string(),
+            upc: Some("12345".to_string()),
+            tracks: vec![TrackRequest {
+                track_id: "TRK001".to_string(),
+                resource_reference: Some("A1".to_string()),
+                isrc: "NOT-AN-ISRC".to_string(),
+                title: "Track".to_string(),
+                duration: "PT3M00S".to_string(),
+                artist: "Artist".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }],
+        deals: vec![DealRequest {
+            deal_reference: Some("D1".to_string()),
+            deal_terms: DealTerms {
+                commercial_model_type: "SubscriptionModel".to_string(),
+                territory_code: vec!["US".to_string()],
+                ..Default::default()
+            },
+            release_references: vec!["R_MISSING".to_string()],
+        }],
+        extensions: None,
+    }
+}
+
+fn validator(mode: ValidationMode) -> PreflightValidator {
+    PreflightValidator::new(ValidationConfig {
+        level: PreflightLevel::Strict,
+        validation_mode: mode,
+        ..Default::default()
+    })
+}
+
+#[test]
+fn test_collect_all_reports_every_error() {
+    let result = validator(ValidationMode::CollectAll)
+        .validate(&request_with_three_errors())
+        .unwrap();
+
+    assert!(!result.passed);
+    let codes: Vec<&str> = result.errors.iter().map(|e| e.code.as_str()).collect();
+    assert_eq!(codes, ["INVALID_UPC", "INVALID_ISRC", "UNKNOWN_REFERENCE"]);
+}
+
+#[test]
+fn test_fail_fast_reports_first_error_only() {
+    let result = validator(ValidationMode::FailFast)
+        .validate(&request_with_three_errors())
+        .unwrap();
+
+    assert!(!result.passed);
+    assert_eq!(result.errors.len(), 1);
+    assert_eq!(result.errors[0].code, "INVALID_UPC");
+}
+
+#[test]
+fn test_build_honours_validation_mode() {
+    let builder = DDEXBuilder::new();
+
+    for (mode, expected) in [
+        (ValidationMode::FailFast, 1),
+        (ValidationMode::CollectAll, 3),
+    ] {
+        let options = BuildOptions {
+            preflight_level: PreflightLevel::Strict,
+            validation_mode: mode,
+            ..Default::default()
+        };
+
+        match builder.build(request_with_three_errors(), options) {
+            Err(BuildError::ValidationFailed { errors }) => assert_eq!(errors.len(), expected),
+            other => panic!(
+                "expected validation failure, got {:?}",
+                other.map(|r| r.xml)
+            ),
+        }
+    }
+}
+
+#[test]
+fn test_validation_mode_serde_names() {
+    assert_eq!(
+        serde_json::to_string(&ValidationMode::FailFast).unwrap(),
+        "\"fail_fast\""
+    );
+    let mode: ValidationMode = serde_json::from_str("\"collect_all\"").unwrap();
+    assert_eq!(mode, ValidationMode::CollectAll);
+}
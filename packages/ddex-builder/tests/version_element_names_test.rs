@@ -0,0 +1,105 @@
+use ddex_builder::builder::{
+    BuildRequest, HashSumRequest, LocalizedStringRequest, ReleaseRequest, TrackRequest,
+};
+use ddex_builder::versions::VersionSpec;
+use ddex_builder::{BuildOptions, DDEXBuilder};
+
+mod common;
+use common::header;
+
+fn build(version: &str) -> String {
+    build_with_subtitle(version, None)
+}
+
+fn build_with_subtitle(version: &str, subtitle: Option<&str>) -> String {
+    let request = BuildRequest {
+        header: header("MSG_NAMES_001"),
+        version: version.to_string(),
+        profile: None,
+        releases: vec![ReleaseRequest {
+            release_id: "REL001".to_string(),
+            release_reference: Some("R1".to_string()),
+            title: vec![LocalizedStringRequest {
+                text: "Album".to_string(),
+                language_code: None,
+            }],
+            artist: "Artist".to_string(),
+            tracks: vec![TrackRequest {
+                track_id: "TRK001".to_string(),
+                resource_reference: Some("A1".to_string()),
+                isrc: "USRC17607839".to_string(),
+                title: "Track".to_string(),
+                subtitle: subtitle.map(str::to_string),
+                duration: "PT3M00S".to_string(),
+                artist: "Artist".to_string(),
+                hash_sum: Some(HashSumRequest {
+                    algorithm: "MD5".to_string(),
+                    value: "d41d8cd98f00b204e9800998ecf8427e".to_string(),
+                }),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }],
+        deals: vec![],
+        extensions: None,
+    };
+
+    DDEXBuilder::new()
+        .build(request, BuildOptions::default())
+        .unwrap()
+        .xml
+}
+
+#[test]
+fn test_ern_382_uses_legacy_technical_details_name() {
+    let xml = build("3.8.2");
+
+    assert!(xml.contains("<TechnicalSoundRecordingDetails>"));
+    assert!(!xml.contains("<TechnicalDetails>"));
+    // Children keep names shared by both versions
+    assert!(xml.contains("<TechnicalResourceDetailsReference>"));
+}
+
+#[test]
+fn test_ern_43_uses_modern_technical_details_name() {
+    let xml = build("4.3");
+
+    assert!(xml.contains("<TechnicalDetails>"));
+    assert!(!xml.contains("<TechnicalSoundRecordingDetails>"));
+}
+
+#[test]
+fn test_subtitle_spelling_follows_version() {
+    let legacy = build_with_subtitle("3.8.2", Some("Live"));
+    assert!(legacy.contains("<Subtitle>Live</Subtitle>"));
+    assert!(!legacy.contains("<SubTitle>"));
+
+    let modern = build_with_subtitle("4.3", Some("Live"));
+    assert!(modern.contains("<SubTitle>Live</SubTitle>"));
+    assert!(!modern.contains("<Subtitle>"));
+}
+
+#[test]
+fn test_renames_come_from_version_spec() {
+    let legacy = VersionSpec::for_version("3.8.2").unwrap();
+    assert_eq!(
+        legacy
+            .renamed_elements
+            .get("TechnicalDetails")
+            .map(String::as_str),
+        Some("TechnicalSoundRecordingDetails")
+    );
+    // The existing element mappings are left alone
+    assert_eq!(
+        legacy
+            .element_mappings
+            .get("BasicPrice")
+            .map(String::as_str),
+        Some("Price")
+    );
+
+    assert!(VersionSpec::for_version("4.3")
+        .unwrap()
+        .renamed_elements
+        .is_empty());
+}
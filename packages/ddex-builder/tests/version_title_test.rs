@@ -0,0 +1,59 @@
+use ddex_builder::builder::{BuildRequest, LocalizedStringRequest, ReleaseRequest, TrackRequest};
+use ddex_builder::{BuildOptions, DDEXBuilder};
+
+mod common;
+use common::header;
+
+fn track(track_id: &str, subtitle: Option<&str>) -> TrackRequest {
+    TrackRequest {
+        track_id: track_id.to_string(),
+        resource_reference: Some(format!("A{}", track_id)),
+        isrc: "USRC17607839".to_string(),
+        title: "Song".to_string(),
+        subtitle: subtitle.map(str::to_string),
+        duration: "PT3M00S".to_string(),
+        artist: "Artist".to_string(),
+        ..Default::default()
+    }
+}
+
+fn request() -> BuildRequest {
+    BuildRequest {
+        header: header("MSG_VERSION_001"),
+        version: "4.3".to_string(),
+        profile: None,
+        releases: vec![ReleaseRequest {
+            release_id: "REL001".to_string(),
+            release_reference: Some("R1".to_string()),
+            title: vec![LocalizedStringRequest {
+                text: "Greatest Hits".to_string(),
+                language_code: None,
+            }],
+            subtitle: Some("Deluxe Edition".to_string()),
+            artist: "Artist".to_string(),
+            tracks: vec![track("1", None), track("2", Some("Live at Wembley"))],
+            ..Default::default()
+        }],
+        deals: vec![],
+        extensions: None,
+    }
+}
+
+#[test]
+fn test_version_subtitles_follow_their_titles() {
+    let xml = DDEXBuilder::new()
+        .build(request(), BuildOptions::default())
+        .unwrap()
+        .xml;
+
+    assert!(xml.contains(
+        "<TitleText>Greatest Hits</TitleText>\n        <SubTitle>Deluxe Edition</SubTitle>"
+    ));
+    assert!(
+        xml.contains("<TitleText>Song</TitleText>\n        <SubTitle>Live at Wembley</SubTitle>"),
+        "{}",
+        xml
+    );
+    // The studio version has no subtitle
+    assert_eq!(xml.matches("<SubTitle>").count(), 2);
+}
@@ -0,0 +1,79 @@
+use ddex_builder::builder::{BuildRequest, LocalizedStringRequest, ReleaseRequest, VideoRequest};
+use ddex_builder::presets::youtube::youtube_video;
+use ddex_builder::{BuildOptions, DDEXBuilder, RuleEngine};
+
+mod common;
+use common::header;
+
+fn video(width: u32, height: u32) -> VideoRequest {
+    VideoRequest {
+        resource_reference: "A1".to_string(),
+        video_type: "ShortFormMusicalWorkVideo".to_string(),
+        width: Some(width),
+        height: Some(height),
+        file_path: None,
+        hash_sum: None,
+    }
+}
+
+fn request(video: VideoRequest) -> BuildRequest {
+    BuildRequest {
+        header: header("MSG_VIDEO_001"),
+        version: "4.3".to_string(),
+        profile: None,
+        releases: vec![ReleaseRequest {
+            release_id: "REL001".to_string(),
+            release_reference: Some("R1".to_string()),
+            title: vec![LocalizedStringRequest {
+                text: "Song (Official Video)".to_string(),
+                language_code: None,
+            }],
+            artist: "Artist".to_string(),
+            videos: vec![video],
+            ..Default::default()
+        }],
+        deals: vec![],
+        extensions: None,
+    }
+}
+
+fn video_quality_errors(request: &BuildRequest) -> Vec<(String, String)> {
+    RuleEngine::from_preset(&youtube_video())
+        .unwrap()
+        .evaluate(request)
+        .into_iter()
+        .filter(|e| e.field == "VideoQuality")
+        .map(|e| (e.location, e.message))
+        .collect()
+}
+
+#[test]
+fn test_sd_video_fails_the_youtube_preset() {
+    assert_eq!(
+        video_quality_errors(&request(video(854, 480))),
+        vec![(
+            "/releases[0]/videos[0]".to_string(),
+            "VideoQuality failed OneOf rule: 'SD' is not one of HD720, HD1080, 4K".to_string()
+        )]
+    );
+}
+
+#[test]
+fn test_hd_video_passes_the_youtube_preset() {
+    assert!(video_quality_errors(&request(video(1920, 1080))).is_empty());
+    // Portrait video is judged on its shorter side
+    assert!(video_quality_errors(&request(video(1080, 1920))).is_empty());
+}
+
+#[test]
+fn test_video_is_emitted_with_its_resolution() {
+    let xml = DDEXBuilder::new()
+        .build(request(video(1920, 1080)), BuildOptions::default())
+        .unwrap()
+        .xml;
+
+    assert!(xml.contains("<Video>"), "{}", xml);
+    assert!(xml.contains("<ImageHeight>1080</ImageHeight>"));
+    assert!(xml.contains("<ImageWidth>1920</ImageWidth>"));
+    assert!(xml.contains("<ReleaseResourceReference>A1</ReleaseResourceReference>"));
+}
@@ -0,0 +1,169 @@
+use ddex_builder::builder::{
+    BuildRequest, LocalizedStringRequest, MessageHeaderRequest, PartyRequest, ReleaseRequest,
+    TrackRequest,
+};
+use ddex_builder::generator::xml_writer::check_well_formed;
+use ddex_builder::streaming::StreamingBuilder;
+use ddex_builder::{BuildOptions, DDEXBuilder};
+use quick_xml::events::Event;
+
+const TITLE: &str = r#"Rock & Roll <Live> "Deluxe""#;
+
+/// Every character XML reserves in text or attribute values
+const SPECIAL: &str = r#"Tom & Jerry's <B-Sides> "Remastered""#;
+
+fn request(title: &str) -> BuildRequest {
+    let party = |name: &str, id: &str| PartyRequest {
+        party_name: vec![LocalizedStringRequest {
+            text: name.to_string(),
+            language_code: None,
+        }],
+        party_id: Some(id.to_string()),
+        ..Default::default()
+    };
+
+    BuildRequest {
+        header: MessageHeaderRequest {
+            message_id: Some("WELL_FORMED_001".to_string()),
+            message_sender: party("Sender & Sons", "PADPIDA2014120301U"),
+            message_recipient: party("Recipient", "PADPIDA2014120302U"),
+            message_created_date_time: Some("2024-01-01T00:00:00Z".to_string()),
+            ..Default::default()
+        },
+        version: "4.3".to_string(),
+        profile: None,
+        releases: vec![ReleaseRequest {
+            release_id: "R1".to_string(),
+            release_reference: Some("R1".to_string()),
+            title: vec![LocalizedStringRequest {
+                text: title.to_string(),
+                language_code: None,
+            }],
+            artist: "Artist <One>".to_string(),
+            tracks: vec![TrackRequest {
+                track_id: "T1".to_string(),
+                resource_reference: Some("A1".to_string()),
+                isrc: "USRC17607839".to_string(),
+                title: title.to_string(),
+                duration: "PT3M30S".to_string(),
+                artist: "Artist <One>".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }],
+        deals: vec![],
+        extensions: None,
+    }
+}
+
+/// Unescaped text of every element named `name` in `xml`
+fn texts_of(xml: &str, name: &str) -> Vec<String> {
+    let mut reader = quick_xml::Reader::from_str(xml);
+    reader.config_mut().trim_text(true);
+    let mut inside = false;
+    let mut texts = Vec::new();
+    loop {
+        match reader.read_event().unwrap() {
+            Event::Start(e) => inside = e.local_name().as_ref() == name.as_bytes(),
+            Event::Text(e) if inside => texts.push(e.unescape().unwrap().into_owned()),
+            Event::End(_) => inside = false,
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    texts
+}
+
+#[test]
+fn test_special_characters_are_escaped_and_output_is_well_formed() {
+    let options = BuildOptions {
+        verify_well_formed: true,
+        ..Default::default()
+    };
+    let xml = DDEXBuilder::new()
+        .build(request(TITLE), options)
+        .unwrap()
+        .xml;
+
+    assert!(
+        xml.contains(r#"Rock &amp; Roll &lt;Live&gt; "Deluxe""#),
+        "{}",
+        xml
+    );
+    assert!(xml.contains("Sender &amp; Sons"));
+    assert!(!xml.contains("<Live>"));
+    check_well_formed(&xml).unwrap();
+
+    let titles = texts_of(&xml, "TitleText");
+    assert!(!titles.is_empty());
+    assert!(titles.iter().all(|title| title == TITLE), "{:?}", titles);
+}
+
+#[test]
+fn test_titles_and_labels_decode_to_original_strings() {
+    let mut request = request(SPECIAL);
+    request.releases[0].label = Some(SPECIAL.to_string());
+    let options = BuildOptions {
+        verify_well_formed: true,
+        ..Default::default()
+    };
+    let xml = DDEXBuilder::new().build(request, options).unwrap().xml;
+
+    let titles = texts_of(&xml, "TitleText");
+    assert!(titles.len() >= 2, "{:?}", titles);
+    assert!(titles.iter().all(|title| title == SPECIAL), "{:?}", titles);
+    assert_eq!(texts_of(&xml, "LabelName"), [SPECIAL]);
+}
+
+#[test]
+fn test_streamed_titles_and_labels_decode_to_original_strings() {
+    let request = request(SPECIAL);
+    let mut output = Vec::new();
+    {
+        let mut builder = StreamingBuilder::new(&mut output).unwrap();
+        builder.start_message(&request.header, "ern/43").unwrap();
+        let resource = builder
+            .write_resource("A1", SPECIAL, SPECIAL, Some("USRC17607839"), None, None)
+            .unwrap();
+        builder.finish_resources_start_releases().unwrap();
+        builder
+            .write_release(
+                "R1",
+                SPECIAL,
+                SPECIAL,
+                Some(SPECIAL),
+                None,
+                None,
+                None,
+                &[resource],
+            )
+            .unwrap();
+        builder.finish_message().unwrap();
+    }
+    let xml = String::from_utf8(output).unwrap();
+
+    check_well_formed(&xml).unwrap();
+    assert_eq!(texts_of(&xml, "TitleText"), [SPECIAL, SPECIAL]);
+    assert_eq!(texts_of(&xml, "FullName"), [SPECIAL, SPECIAL]);
+    assert_eq!(texts_of(&xml, "LabelName"), [SPECIAL]);
+    assert!(texts_of(&xml, "PartyName").contains(&"Sender & Sons".to_string()));
+}
+
+#[test]
+fn test_malformed_documents_are_rejected() {
+    for xml in [
+        "<Root><Child></Root>",
+        "<Root>Rock & Roll</Root>",
+        "<Root><Child></Child>",
+        "<Root/><Root/>",
+        r#"<Root Name="a & b"/>"#,
+        "",
+    ] {
+        assert!(check_well_formed(xml).is_err(), "accepted {:?}", xml);
+    }
+
+    check_well_formed(
+        "<?xml version=\"1.0\"?>\n<Root a=\"&quot;\"><!-- note --><Child>&amp;</Child></Root>\n",
+    )
+    .unwrap();
+}
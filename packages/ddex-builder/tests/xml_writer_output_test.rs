@@ -0,0 +1,124 @@
+//! The XML writer's output must stay byte-identical as its internals change.
+//! Snapshots were recorded with the `String`-based writer it replaced.
+
+use ddex_builder::ast::{Element, AST};
+use ddex_builder::builder::{
+    BuildRequest, LocalizedStringRequest, MessageHeaderRequest, ReleaseRequest, TrackRequest,
+};
+use ddex_builder::determinism::{DeterminismConfig, IndentChar};
+use ddex_builder::generator::xml_writer::XmlWriter;
+use ddex_builder::{BuildOptions, DDEXBuilder};
+use ddex_core::models::{Comment, CommentPosition};
+use indexmap::IndexMap;
+use insta::assert_snapshot;
+
+mod common;
+use common::party;
+
+/// An AST touching every writer path: namespaces, escaping, empty and
+/// mixed-content elements, and both comment kinds
+fn sample_ast() -> AST {
+    let mut root = Element::new("NewReleaseMessage");
+    root.attributes
+        .insert("MessageSchemaVersionId".to_string(), "ern/43".to_string());
+
+    let mut header = Element::new("MessageHeader")
+        .with_comment("Header & sender".to_string(), CommentPosition::Before);
+    header.add_child(Element::new("MessageId").with_text("MSG <1> & \"2\""));
+    header.add_child(Element::new("TradingName"));
+    header.add_child(Element::new("Empty").with_text(""));
+    header.add_child(
+        Element::new("Title")
+            .with_attr("LanguageAndScriptCode", "en's \"<&>\"")
+            .with_text("Rock & Roll"),
+    );
+    header.add_comment(Comment::new(
+        "first child".to_string(),
+        CommentPosition::FirstChild,
+    ));
+    header.add_comment(Comment::new("inline".to_string(), CommentPosition::Inline));
+    root.add_child(header);
+
+    let mut mixed = Element::new("Mixed").with_namespace("avs");
+    mixed.add_text("loose <text>");
+    mixed.add_child(Element::new("Inner").with_text("x"));
+    mixed.add_simple_comment("legacy comment");
+    root.add_child(mixed);
+
+    let mut namespaces = IndexMap::new();
+    namespaces.insert("ern".to_string(), "http://ddex.net/xml/ern/43".to_string());
+    namespaces.insert(
+        "xsi".to_string(),
+        "http://www.w3.org/2001/XMLSchema-instance".to_string(),
+    );
+
+    AST {
+        root,
+        namespaces,
+        schema_location: Some("http://ddex.net/xml/ern/43 release-notification.xsd".to_string()),
+    }
+}
+
+fn release(index: usize) -> ReleaseRequest {
+    ReleaseRequest {
+        release_id: format!("A10301A000000000{}A", index),
+        release_reference: Some(format!("R{}", index)),
+        title: vec![LocalizedStringRequest {
+            text: format!("Album {} <Deluxe> & More", index),
+            language_code: Some("en".to_string()),
+        }],
+        artist: "Artist \"Quoted\"".to_string(),
+        label: Some("Label".to_string()),
+        release_date: Some("2024-01-01".to_string()),
+        tracks: vec![TrackRequest {
+            track_id: format!("TRK{}", index),
+            resource_reference: Some(format!("A{}", index)),
+            isrc: format!("USRC1760783{}", index),
+            title: format!("Track {}", index),
+            duration: "PT3M00S".to_string(),
+            artist: "Artist".to_string(),
+            ..Default::default()
+        }],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_writer_output_is_unchanged() {
+    let ast = sample_ast();
+
+    let spaces = XmlWriter::new(DeterminismConfig::default());
+    assert_snapshot!("writer_spaces", spaces.write(&ast).unwrap());
+
+    let tabs = XmlWriter::new(DeterminismConfig {
+        indent_char: IndentChar::Tab,
+        indent_width: 1,
+        ..Default::default()
+    })
+    .with_self_close_empty(true);
+    assert_snapshot!("writer_tabs_self_closed", tabs.write(&ast).unwrap());
+}
+
+#[test]
+fn test_multi_release_build_is_unchanged() {
+    let request = BuildRequest {
+        header: MessageHeaderRequest {
+            message_id: Some("MSG_WRITER_001".to_string()),
+            message_sender: party("Sender & Co"),
+            message_recipient: party("Recipient"),
+            message_created_date_time: Some("2024-01-01T00:00:00Z".to_string()),
+            ..Default::default()
+        },
+        version: "4.3".to_string(),
+        profile: None,
+        releases: (1..=3).map(release).collect(),
+        deals: vec![],
+        extensions: None,
+    };
+
+    let xml = DDEXBuilder::new()
+        .build(request, BuildOptions::default())
+        .unwrap()
+        .xml;
+    assert_snapshot!("multi_release_build", xml);
+}
@@ -0,0 +1,98 @@
+// core/benches/simd_scan.rs
+//! Scalar multi-pass vs single-pass SIMD boundary scanning in
+//! `FastStreamingParser`. Run with `cargo bench --features simd --bench simd_scan`.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use ddex_parser::streaming::create_fast_parser;
+
+/// A catalog of `releases` releases, each with two sound recordings and a deal
+fn generate_catalog(releases: usize) -> Vec<u8> {
+    let mut xml = String::from(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<NewReleaseMessage xmlns="http://ddex.net/xml/ern/43" MessageSchemaVersionId="ern/43">
+    <MessageHeader>
+        <MessageId>MSG_SIMD_BENCH</MessageId>
+        <MessageSender><PartyId>PADPIDA_SENDER</PartyId><PartyName><FullName>Sender</FullName></PartyName></MessageSender>
+        <MessageRecipient><PartyId>PADPIDA_RECIPIENT</PartyId><PartyName><FullName>Recipient</FullName></PartyName></MessageRecipient>
+        <MessageCreatedDateTime>2024-01-01T00:00:00Z</MessageCreatedDateTime>
+    </MessageHeader>
+    <PartyList>
+        <Party><PartyReference>P1</PartyReference><PartyName><FullName>Artist</FullName></PartyName></Party>
+    </PartyList>
+    <ResourceList>"#,
+    );
+
+    for i in 0..releases * 2 {
+        xml.push_str(&format!(
+            r#"
+        <SoundRecording>
+            <ResourceReference>A{i}</ResourceReference>
+            <SoundRecordingId><ISRC>USRC1{i:07}</ISRC></SoundRecordingId>
+            <Title><TitleText>Track {i}</TitleText></Title>
+            <DisplayArtist><ArtistPartyReference>P1</ArtistPartyReference><DisplayArtistRole>MainArtist</DisplayArtistRole></DisplayArtist>
+            <Duration>PT3M30S</Duration>
+            <TechnicalDetails><TechnicalResourceDetailsReference>T{i}</TechnicalResourceDetailsReference><AudioCodecType>FLAC</AudioCodecType></TechnicalDetails>
+        </SoundRecording>"#
+        ));
+    }
+
+    xml.push_str("\n    </ResourceList>\n    <ReleaseList>");
+    for i in 0..releases {
+        xml.push_str(&format!(
+            r#"
+        <Release>
+            <ReleaseReference>R{i}</ReleaseReference>
+            <ReleaseId><ICPN>{i:012}</ICPN></ReleaseId>
+            <ReleaseTitle><TitleText>Album {i}</TitleText></ReleaseTitle>
+            <ReleaseType>Album</ReleaseType>
+            <DisplayArtist><ArtistPartyReference>P1</ArtistPartyReference><DisplayArtistRole>MainArtist</DisplayArtistRole></DisplayArtist>
+            <ReleaseResourceReferenceList>
+                <ReleaseResourceReference>A{a}</ReleaseResourceReference>
+                <ReleaseResourceReference>A{b}</ReleaseResourceReference>
+            </ReleaseResourceReferenceList>
+        </Release>"#,
+            a = i * 2,
+            b = i * 2 + 1
+        ));
+    }
+
+    xml.push_str("\n    </ReleaseList>\n    <DealList>");
+    for i in 0..releases {
+        xml.push_str(&format!(
+            r#"
+        <ReleaseDeal>
+            <DealReleaseReference>R{i}</DealReleaseReference>
+            <Deal><DealTerms><CommercialModelType>SubscriptionModel</CommercialModelType><TerritoryCode>Worldwide</TerritoryCode></DealTerms></Deal>
+        </ReleaseDeal>"#
+        ));
+    }
+    xml.push_str("\n    </DealList>\n</NewReleaseMessage>\n");
+
+    xml.into_bytes()
+}
+
+fn bench_scan(c: &mut Criterion) {
+    let parser = create_fast_parser();
+    let mut group = c.benchmark_group("fast_scan");
+
+    for releases in [100, 1_000, 5_000] {
+        let data = generate_catalog(releases);
+        assert_eq!(
+            parser.scan_scalar(&data).len(),
+            parser.scan_simd(&data).len()
+        );
+        group.throughput(Throughput::Bytes(data.len() as u64));
+
+        group.bench_with_input(BenchmarkId::new("scalar", releases), &data, |b, data| {
+            b.iter(|| black_box(parser.scan_scalar(black_box(data))))
+        });
+        group.bench_with_input(BenchmarkId::new("simd", releases), &data, |b, data| {
+            b.iter(|| black_box(parser.scan_simd(black_box(data))))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_scan);
+criterion_main!(benches);
@@ -7,28 +7,72 @@ export interface JsRelease {
   releaseId: string
   title: string
   defaultTitle: string
+  /** Internal title from `ReferenceTitle` */
+  referenceTitle?: string
+  /** Customer-facing title from `DisplayTitle` */
+  displayTitle?: string
   subtitle?: string
   displayArtist: string
+  artists: Array<JsArtist>
   releaseType: string
   genre?: string
   subGenre?: string
   trackCount: number
+  /** Summed runtime of the release's tracks, in seconds */
+  totalDurationSeconds: number
   discCount?: number
   releaseDate?: string
+  /** Territory-specific release dates, keyed by territory code */
+  releaseDatesByTerritory: Record<string, string>
   originalReleaseDate?: string
+  /** When metadata may first be shown and pre-orders open */
+  preorderDate?: string
+  /** When the release goes on sale, apart from deal validity */
+  availabilityStart?: string
   labelName?: string
   tracks: Array<JsTrack>
+  descriptions: Array<JsDescription>
+  /** Original `<Release>` XML when parsed with `includeRaw` */
+  raw?: string
 }
 export interface JsTrack {
   trackId: string
   title: string
+  /** Version title from `SubTitle`, e.g. "Live at Wembley" */
+  subtitle?: string
   artist: string
+  artists: Array<JsArtist>
+  contributors: Array<JsContributor>
   duration?: string
   position?: number
   discNumber?: number
   isrc?: string
   resourceReference?: string
 }
+export interface JsArtist {
+  name: string
+  role: string
+  /** `ArtistPartyReference` naming the artist's party */
+  partyReference?: string
+  /** Identifiers of the artist's party, such as ISNI and IPI */
+  identifiers: Array<JsPartyIdentifier>
+}
+export interface JsContributor {
+  name: string
+  /** Roles as written, e.g. `Conductor`, `Orchestra` or `Soloist` */
+  roles: Array<string>
+  /** Instruments played, e.g. `Guitar` */
+  instruments: Array<string>
+  /** `ContributorPartyReference` naming the contributor's party */
+  partyReference?: string
+}
+export interface JsPartyIdentifier {
+  /** `ISNI`, `IPI` or `Proprietary` */
+  idType: string
+  /** Issuer of a proprietary id, e.g. `DPID` */
+  namespace?: string
+  value: string
+}
 export interface JsResource {
   resourceId: string
   resourceType: string
@@ -36,19 +80,121 @@ export interface JsResource {
   durationSeconds?: number
   durationString?: string
   fileFormat?: string
+  /**
+   * Audio properties come from the first PCM/lossless technical details
+   * block of the resource, else its first block
+   */
+  audioCodec?: string
+  /** In kbps */
   bitrate?: number
+  /** In Hz */
   sampleRate?: number
+  bitsPerSample?: number
   fileSize?: string
+  files: Array<JsResourceFile>
+  /** Preview clip start, in seconds from the start of the resource */
+  previewStartSeconds?: number
+  previewDurationSeconds?: number
+  /** Picture properties, set on video resources */
+  videoDetails?: JsVideoDetails
+  descriptions: Array<JsDescription>
+  /** Original resource XML when parsed with `includeRaw` */
+  raw?: string
+}
+export interface JsVideoDetails {
+  width?: number
+  height?: number
+  frameRate?: number
+  codec?: string
+  aspectRatio?: string
+  bitrate?: number
+}
+export interface JsResourceFile {
+  fileName?: string
+  filePath?: string
+  uri?: string
+  hashAlgorithm?: string
+  hashValue?: string
+}
+export interface JsDescription {
+  descriptionType: string
+  text: string
+  languageCode?: string
 }
 export interface JsDeal {
   dealId: string
   releases: Array<string>
   startDate?: string
   endDate?: string
+  /** `TerritoryCode`s the deal applies in, e.g. `["US", "CA", "MX"]` or `["Worldwide"]` */
   territories: Array<string>
+  /** `ExcludedTerritoryCode`s carved out of `territories` */
+  excludedTerritories: Array<string>
   usageRights: Array<string>
   restrictions: Array<string>
+  /** First `CommercialModelType` of the deal; empty when none is given */
   commercialModel: string
+  /** Every `CommercialModelType` of the deal, in document order */
+  commercialModels: Array<string>
+  /** Typed view of `usageRights` and `restrictions` */
+  rights: Array<JsUsageRight>
+}
+export interface JsUsageRight {
+  useType: string
+  commercialModel?: string
+  /** "Allowed", or "Disallowed" for the uses of a `TakeDown` deal */
+  allowance: string
+}
+export interface JsAuditTrailEvent {
+  eventType: string
+  dateTime: string
+  /** Id or name of the party that performed the event */
+  party?: string
+}
+export interface JsPurgedRelease {
+  upc?: string
+  grid?: string
+  title?: string
+  displayArtist: string
+}
+export interface JsResourceHashCheck {
+  resourceReference: string
+  /** The file as the message locates it: its URI, or path and name */
+  file: string
+  /** e.g. "MD5", "SHA-256" */
+  algorithm: string
+  expected: string
+  /** Hex digest of the resolved file, when it could be read and hashed */
+  actual?: string
+  /** `Match`, `Mismatch`, `Missing` or `UnsupportedAlgorithm` */
+  status: string
+}
+export interface JsParty {
+  /**
+   * `PartyReference` of a `PartyList` party; absent for ERN 3.8 parties
+   * named in place
+   */
+  partyReference?: string
+  names: Array<string>
+  /** DPID, ISNI, IPI and proprietary ids */
+  identifiers: Array<JsPartyIdentifier>
+  /**
+   * Roles played across the message, e.g. `Artist`, `Label`,
+   * `RightsController` or a contributor role such as `Composer`
+   */
+  roles: Array<string>
+}
+/** Limits on pathological documents; unset fields keep the strict defaults */
+export interface SecurityOptions {
+  /** Most attributes allowed on one element (default 100) */
+  maxAttributesPerElement?: number
+  /** Longest element or attribute name in bytes, prefix included (default 256) */
+  maxNameLength?: number
+  /**
+   * Accept DOCTYPEs declaring external or parameter entities, for trusted
+   * pipelines; they are still never fetched or expanded (default false)
+   */
+  allowExternalEntities?: boolean
 }
 export interface ParseOptions {
   mode?: string
@@ -59,6 +205,22 @@ export interface ParseOptions {
   timeoutMs?: number
   allowBlocking?: boolean
   chunkSize?: number
+  /** Whitespace handling for titles, names and descriptions: "trim" (default), "collapse" or "preserve" */
+  whitespace?: string
+  /** Fail if a party reference names no party in the PartyList */
+  validatePartyReferences?: boolean
+  /** Attach the graph form of the message as `graph` (off by default) */
+  includeGraph?: boolean
+  /** Parse only the first this many releases, for a quick preview */
+  maxReleases?: number
+  /** Genres to rewrite as they are parsed, from the sender's vocabulary to ours */
+  genreMap?: Record<string, string>
+  /**
+   * Map non-standard deal territory codes onto ISO ones, on top of the
+   * built-in `UK` and `EU` aliases, e.g. `{ ROW: ["US", "CA"] }`; pass `{}`
+   * for the built-ins alone
+   */
+  territoryAliases?: Record<string, Array<string>>
   fidelityLevel?: string
   preserveComments?: boolean
   preserveProcessingInstructions?: boolean
@@ -81,12 +243,18 @@ export interface StreamOptions {
 }
 export interface ParsedMessage {
   messageId: string
+  /**
+   * Root element, e.g. "NewReleaseMessage", "UpdateReleaseMessage" or
+   * "PurgeReleaseMessage"
+   */
   messageType: string
   messageDate: string
   senderName: string
   senderId: string
   recipientName: string
   recipientId: string
+  /** `MessageControlType`, e.g. "LiveMessage" or "TestMessage" */
+  messageControlType?: string
   version: string
   profile?: string
   releaseCount: number
@@ -97,9 +265,69 @@ export interface ParsedMessage {
   releases: Array<JsRelease>
   resources: any
   deals: Array<JsDeal>
+  identifierIndex: any
+  /** `MessageAuditTrail` events, in document order */
+  auditTrail: Array<JsAuditTrailEvent>
+  /** Releases a `PurgeReleaseMessage` asks to be removed */
+  purgedReleases: Array<JsPurgedRelease>
+  /**
+   * Graph form of the message, as `toGraphJson` returns it, when parsed
+   * with `includeGraph`
+   */
+  graph?: any
+  /** Whether releases past `maxReleases` were left out */
+  truncated: boolean
+  /**
+   * Non-standard `MessageHeader` children, such as partner routing hints,
+   * by local name
+   */
+  headerExtensions: Record<string, string>
   statistics?: ParseStatistics
   fidelityInfo?: FidelityInfo
 }
+export interface Capabilities {
+  versions: Array<string>
+  messageTypes: Array<string>
+  canonicalization: Array<string>
+  features: Array<string>
+}
+export interface EntityCounts {
+  releases: number
+  resources: number
+  deals: number
+  parties: number
+}
+export interface FastScanStats {
+  elements: number
+  releases: number
+  resources: number
+  deals: number
+  totalBytes: number
+  throughputMbps: number
+  peakMemoryMb: number
+  avgElementSize: number
+}
+export interface CostEstimate {
+  estimatedReleases: number
+  estimatedMemoryBytes: number
+  estimatedParseMs: number
+  /** Deepest element nesting seen by the scan */
+  maxDepth: number
+  /** Whether the counts were extrapolated from samples of a large document */
+  sampled: boolean
+}
+export interface StrategyBenchmark {
+  /** Streaming implementation, e.g. "zero_copy" */
+  strategy: string
+  elapsedMs: number
+  throughputMbPerSec: number
+  /** Peak memory the strategy reports having held */
+  peakMemoryBytes: number
+  /** Releases and resources together */
+  elementsFound: number
+  releasesFound: number
+  resourcesFound: number
+}
 export interface ParseStatistics {
   parseTimeMs: number
   memoryUsedBytes: number
@@ -125,6 +353,11 @@ export interface SanityCheckResult {
   errors: Array<string>
   warnings: Array<string>
 }
+export interface PartialParseResult {
+  message?: ParsedMessage
+  errors: Array<DetailedError>
+  warnings: Array<string>
+}
 export interface DetailedError {
   errorType: string
   message: string
@@ -139,18 +372,128 @@ export interface StreamedRelease {
   releaseType?: string
   resourceCount: number
 }
+export interface StreamedElement {
+  kind: 'release' | 'resource' | 'deal'
+  release?: JsRelease
+  resource?: JsResource
+  deal?: JsDeal
+}
+export interface StreamedDealRelease {
+  releaseReference: string
+  title: string
+  releaseType: string
+  upc?: string
+  grid?: string
+}
+export interface StreamedDeal {
+  deal: JsDeal
+  releases: Array<StreamedDealRelease>
+  unresolvedReferences: Array<string>
+}
 export interface ProgressInfo {
   bytesProcessed: number
   releasesParsed: number
   elapsedMs: number
 }
 export declare class DdexParser {
-  constructor()
+  constructor(security?: SecurityOptions | undefined | null)
   detectVersion(xml: string): string
   parseSync(xml: string, options?: ParseOptions | undefined | null): ParsedMessage
   parse(xml: string, options?: ParseOptions | undefined | null): Promise<ParsedMessage>
+  /**
+   * Parse whatever can be recovered instead of failing on the first
+   * problem. Releases that fail to parse are left out of `message` and
+   * described in `errors`; `message` is null if the problem lies outside
+   * the releases.
+   */
+  parsePartial(xml: string, options?: ParseOptions | undefined | null): PartialParseResult
+  /**
+   * Parse only the release with the given `ReleaseReference`, skipping
+   * every other release. Errors if no release has that reference.
+   */
+  parseRelease(xml: string, releaseReference: string): JsRelease
+  /**
+   * Parse every message of an input holding several, concatenated or
+   * wrapped in a delivery manifest, each with its own header
+   */
+  parseAll(xml: string): Array<ParsedMessage>
+  /**
+   * Index the releases of a message without parsing them; each is parsed
+   * when asked for, keeping memory low for random access into large
+   * catalogs
+   */
+  parseLazy(xml: string): LazyMessage
+  /**
+   * Serialize the graph form of the message as JSON: parties with the
+   * roles they play, and the references releases, resources and deals
+   * make to each other. The flat form collapses most of this.
+   */
+  toGraphJson(xml: string): string
+  /**
+   * Map each release reference to its availability per territory code
+   * (`available`, `start_date`, `commercial_models`), merged from every
+   * deal. Territories no deal names share the "Worldwide" entry.
+   */
+  availabilityMap(xml: string): any
+  /**
+   * Versions, message types, canonicalization algorithms and feature
+   * flags this build of the parser supports
+   */
+  capabilities(): Capabilities
+  /**
+   * Count releases, resources, deals and parties with the fast byte-level
+   * scanner, without building any models
+   */
+  countEntities(xml: string): EntityCounts
+  /** Run the fast scanner and return entity counts with its performance stats */
+  scanFast(xml: string): FastScanStats
+  /**
+   * Predict the release count, peak memory and time a full parse will
+   * take from a quick scan. Release counts are typically within 10%;
+   * memory and time are ballpark figures for scheduling.
+   */
+  estimateCost(xml: string): CostEstimate
+  /**
+   * Stream the document with each streaming strategy and return one row
+   * per strategy with its throughput, peak memory and elements found
+   */
+  benchmarkStreamingStrategies(xml: string): Array<StrategyBenchmark>
+  /**
+   * Every party in the message, once each, with its identifiers, names
+   * and roles, without materializing releases, resources or deals
+   */
+  extractParties(xml: string): Array<JsParty>
+  /**
+   * Check each declared `HashSum` against the delivered file.
+   * `fileResolver` maps a file's location as the message gives it (its
+   * URI, or path and name) to a local path, either as an object or as a
+   * function returning the path, or nothing if the file is unknown.
+   */
+  verifyResourceHashes(xml: string, fileResolver: Record<string, string> | ((location: string) => string | null | undefined)): Array<JsResourceHashCheck>
+  /**
+   * Look up a release in a parsed message by any of its identifiers
+   * (e.g. "UPC", "EAN", "GRid", "Proprietary")
+   */
+  findReleaseByIdentifier(message: ParsedMessage, idType: string, value: string): JsRelease | null
+  /** Look up a resource in a parsed message by any of its identifiers (e.g. "ISRC") */
+  findResourceByIdentifier(message: ParsedMessage, idType: string, value: string): any | null
   sanityCheck(xml: string): Promise<SanityCheckResult>
+  /**
+   * Stream the releases of a message, `TrackRelease`s included, one
+   * at a time without building the whole message
+   */
   stream(xml: string, options?: StreamOptions | undefined | null): ReleaseStream
+  /**
+   * Stream the resources, releases and deals of a message in document
+   * order, each already converted. `kind` says which field is set.
+   */
+  streamTyped(xml: string): TypedElementStream
+  /**
+   * Stream the deals of a message, each with the releases it applies to.
+   * Releases are read first and only summarized, so deals can be
+   * forwarded one at a time without holding the whole message.
+   */
+  streamDeals(xml: string): DealStream
   /** Get detailed error information for debugging - useful for error handling in JavaScript */
   getDetailedError(xml: string): DetailedError
 }
@@ -158,3 +501,21 @@ export declare class ReleaseStream {
   next(): Promise<StreamedRelease | null>
   progress(): Promise<ProgressInfo>
 }
+export declare class TypedElementStream {
+  /** Next element, or null once the message is exhausted */
+  next(): StreamedElement | null
+}
+export declare class LazyMessage {
+  releaseCount(): number
+  resourceCount(): number
+  /** `ReleaseReference`s in document order */
+  releaseReferences(): Array<string>
+  /** Parse the release at `index`, in document order */
+  release(index: number): JsRelease
+  /** Parse the release with the given `ReleaseReference` */
+  releaseByReference(releaseReference: string): JsRelease
+}
+export declare class DealStream {
+  /** Next deal, or null once the message is exhausted */
+  next(): StreamedDeal | null
+}
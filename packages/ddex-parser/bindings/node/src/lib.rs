@@ -3,11 +3,19 @@
 
 use napi::bindgen_prelude::*;
 use napi_derive::napi;
+use std::collections::HashMap;
 use std::io::Cursor;
 
 // Import the actual DDEX parser and related types
 use ddex_parser::{DDEXParser as RustDDEXParser, error::ParseError};
-use ddex_core::models::flat::{ParsedERNMessage, ParsedRelease, ParsedResource, ParsedDeal};
+use ddex_parser::parser::lazy::LazyMessage as RustLazyMessage;
+use ddex_parser::streaming::{
+    CorrelatedDeal, CorrelatedDealIterator, DealRelease, TypedStreamIterator, TypedStreamingElement,
+    WorkingStreamIterator, WorkingStreamingElement,
+};
+use ddex_core::models::common::{IdentifierType, LocalizedString};
+use ddex_core::models::graph::{Party, PartyRole};
+use ddex_core::models::flat::{ArtistInfo, AuditTrailEntry, IdentifierIndex, ParsedERNMessage, ParsedRelease, ParsedResource, ParsedDeal, PurgedRelease};
 use ddex_core::models::versions::ERNVersion;
 use serde_json;
 use indexmap;
@@ -76,6 +84,40 @@ fn parse_error_to_detailed(err: ParseError) -> DetailedError {
     }
 }
 
+/// Convert JavaScript parse options to the Rust parser's options
+fn convert_parse_options(options: Option<&ParseOptions>) -> Result<ddex_parser::parser::ParseOptions> {
+    let whitespace = match options.and_then(|o| o.whitespace.as_deref()) {
+        Some(mode) => mode
+            .parse()
+            .map_err(|e: String| napi::Error::new(napi::Status::InvalidArg, e))?,
+        None => Default::default(),
+    };
+
+    Ok(ddex_parser::parser::ParseOptions {
+        include_raw: options.and_then(|o| o.include_raw).unwrap_or(false),
+        whitespace,
+        validate_party_references: options
+            .and_then(|o| o.validate_party_references)
+            .unwrap_or(false),
+        max_releases: options
+            .and_then(|o| o.max_releases)
+            .map(|max| max as usize),
+        element_hook: options
+            .and_then(|o| o.genre_map.clone())
+            .map(|map| ddex_parser::parser::element_hook::ElementHook::map_text("GenreText", map)),
+        territory_aliases: options
+            .and_then(|o| o.territory_aliases.clone())
+            .map(|aliases| {
+                aliases.into_iter().fold(
+                    ddex_parser::parser::territories::TerritoryAliases::default(),
+                    |defaults, (alias, codes)| defaults.with_alias(&alias, codes),
+                )
+            }),
+        collect_statistics: options.and_then(|o| o.collect_statistics).unwrap_or(false),
+        ..Default::default()
+    })
+}
+
 /// Convert ParseError to NAPI Error with detailed categorization
 fn parse_error_to_napi(err: ParseError) -> napi::Error {
     match err {
@@ -175,6 +217,40 @@ fn parse_error_to_napi(err: ParseError) -> napi::Error {
                 format!("Unclosed XML tags at position {}: {:?}. Please ensure all tags are properly closed.", position, tags),
             )
         }
+        ParseError::EncodingMismatch { declared, detected } => {
+            napi::Error::new(
+                napi::Status::InvalidArg,
+                format!("Encoding mismatch: declared '{}' but content looks like '{}'. Please fix the encoding in the XML declaration or re-encode the file.", declared, detected),
+            )
+        }
+        ParseError::EncodingCorruption { position } => {
+            napi::Error::new(
+                napi::Status::InvalidArg,
+                format!("Encoding corruption at byte {}: a stray byte order mark or null byte, as left where files are joined. Please check the file around that offset.", position),
+            )
+        }
+        ParseError::SchemaVersionMismatch { namespace_version, schema_version_id } => {
+            napi::Error::new(
+                napi::Status::InvalidArg,
+                format!("Schema version mismatch: namespace is ERN {} but MessageSchemaVersionId is '{}'. Please make both declare the same ERN version.", namespace_version, schema_version_id),
+            )
+        }
+        ParseError::UnresolvedPartyReferences(unresolved) => {
+            let references: Vec<String> = unresolved
+                .iter()
+                .map(|u| format!("{} '{}' at {}", u.element, u.reference, u.location))
+                .collect();
+            napi::Error::new(
+                napi::Status::InvalidArg,
+                format!("Unresolved party references: {}. Please define each referenced party in the PartyList.", references.join(", ")),
+            )
+        }
+        ParseError::ReleaseNotFound { reference } => {
+            napi::Error::new(
+                napi::Status::InvalidArg,
+                format!("No release with ReleaseReference '{}' in this message. Please check the reference against the ReleaseList.", reference),
+            )
+        }
     }
 }
 
@@ -193,17 +269,30 @@ fn convert_release(release: ParsedRelease) -> JsRelease {
         release_id: release.release_id,
         title: release.title.first().map(|t| t.text.clone()).unwrap_or_default(),
         default_title: release.default_title,
+        reference_title: release.reference_title.into_iter().next().map(|t| t.text),
+        display_title: release.display_title.into_iter().next().map(|t| t.text),
         subtitle: release.default_subtitle,
         display_artist: release.display_artist,
+        artists: release.artists.into_iter().map(convert_artist).collect(),
         release_type: release.release_type,
         genre: release.genre,
         sub_genre: release.sub_genre,
         track_count: release.track_count as u32,
+        total_duration_seconds: release.total_duration_seconds as u32,
         disc_count: release.disc_count.map(|c| c as u32),
         release_date: release.release_date.map(|d| d.to_rfc3339()),
+        release_dates_by_territory: release
+            .release_dates_by_territory
+            .into_iter()
+            .map(|(territory, date)| (territory, date.to_rfc3339()))
+            .collect(),
         original_release_date: release.original_release_date.map(|d| d.to_rfc3339()),
-        label_name: None, // ParsedRelease doesn't have label_name directly
+        preorder_date: release.preorder_date.map(|d| d.to_rfc3339()),
+        availability_start: release.availability_start.map(|d| d.to_rfc3339()),
+        label_name: release.label_name,
         tracks: release.tracks.into_iter().map(convert_track).collect(),
+        descriptions: convert_descriptions(release.descriptions),
+        raw: release.raw,
     }
 }
 
@@ -212,7 +301,19 @@ fn convert_track(track: ddex_core::models::flat::ParsedTrack) -> JsTrack {
     JsTrack {
         track_id: track.track_id,
         title: track.title,
+        subtitle: track.subtitle,
         artist: track.display_artist,
+        artists: track.artists.into_iter().map(convert_artist).collect(),
+        contributors: track
+            .contributors
+            .into_iter()
+            .map(|contributor| JsContributor {
+                name: contributor.name,
+                roles: contributor.roles,
+                instruments: contributor.instruments,
+                party_reference: contributor.party_id,
+            })
+            .collect(),
         duration: Some(track.duration_formatted), // Use the pre-formatted duration
         position: Some(track.position as u32),
         disc_number: track.disc_number.map(|d| d as u32),
@@ -221,6 +322,24 @@ fn convert_track(track: ddex_core::models::flat::ParsedTrack) -> JsTrack {
     }
 }
 
+/// Convert ArtistInfo to JavaScript-compatible structure
+fn convert_artist(artist: ArtistInfo) -> JsArtist {
+    JsArtist {
+        name: artist.name,
+        role: artist.role,
+        party_reference: artist.party_id,
+        identifiers: artist
+            .identifiers
+            .into_iter()
+            .map(|id| JsPartyIdentifier {
+                id_type: format!("{:?}", id.id_type),
+                namespace: id.namespace,
+                value: id.value,
+            })
+            .collect(),
+    }
+}
+
 /// Convert ParsedResource to JavaScript-compatible structure
 fn convert_resource(resource: ParsedResource) -> JsResource {
     JsResource {
@@ -230,12 +349,62 @@ fn convert_resource(resource: ParsedResource) -> JsResource {
         duration_seconds: resource.duration.map(|d| d.as_secs_f64()),
         duration_string: resource.duration.map(|d| format!("{}:{:02}", d.as_secs() / 60, d.as_secs() % 60)),
         file_format: resource.technical_details.file_format,
+        audio_codec: resource.technical_details.audio_codec,
         bitrate: resource.technical_details.bitrate,
         sample_rate: resource.technical_details.sample_rate,
+        bits_per_sample: resource.technical_details.bits_per_sample,
         file_size: resource.technical_details.file_size.map(|size| size.to_string()),
+        preview_start_seconds: resource
+            .technical_details
+            .preview
+            .and_then(|p| p.start_point)
+            .map(|d| d.as_secs_f64()),
+        preview_duration_seconds: resource
+            .technical_details
+            .preview
+            .and_then(|p| p.duration)
+            .map(|d| d.as_secs_f64()),
+        files: resource
+            .technical_details
+            .files
+            .into_iter()
+            .map(|file| JsResourceFile {
+                file_name: file.file_name,
+                file_path: file.file_path,
+                uri: file.uri,
+                hash_algorithm: file.hash_sum.as_ref().map(|h| h.algorithm.clone()),
+                hash_value: file.hash_sum.map(|h| h.value),
+            })
+            .collect(),
+        video_details: resource.video_details.map(|video| JsVideoDetails {
+            width: video.width,
+            height: video.height,
+            frame_rate: video.frame_rate,
+            codec: video.codec,
+            aspect_ratio: video.aspect_ratio,
+            bitrate: video.bitrate,
+        }),
+        descriptions: convert_descriptions(resource.descriptions),
+        raw: resource.raw,
     }
 }
 
+/// Flatten the type-keyed descriptions map into one entry per type and language
+fn convert_descriptions(
+    descriptions: indexmap::IndexMap<String, Vec<LocalizedString>>,
+) -> Vec<JsDescription> {
+    descriptions
+        .into_iter()
+        .flat_map(|(description_type, texts)| {
+            texts.into_iter().map(move |text| JsDescription {
+                description_type: description_type.clone(),
+                text: text.text,
+                language_code: text.language_code,
+            })
+        })
+        .collect()
+}
+
 /// Convert ParsedDeal to JavaScript-compatible structure
 fn convert_deal(deal: ParsedDeal) -> JsDeal {
     JsDeal {
@@ -243,14 +412,65 @@ fn convert_deal(deal: ParsedDeal) -> JsDeal {
         releases: deal.releases,
         start_date: deal.validity.start.map(|d| d.to_rfc3339()),
         end_date: deal.validity.end.map(|d| d.to_rfc3339()),
-        territories: vec!["Worldwide".to_string()], // Simplified for now - actual field structure is complex
+        territories: deal.territories.included,
+        excluded_territories: deal.territories.excluded,
         usage_rights: deal.usage_rights,
         restrictions: deal.restrictions,
-        commercial_model: "Streaming".to_string(), // Simplified for now - actual field structure is complex
+        commercial_model: deal.commercial_models.first().cloned().unwrap_or_default(),
+        commercial_models: deal.commercial_models,
+        rights: deal
+            .rights
+            .into_iter()
+            .map(|right| JsUsageRight {
+                use_type: right.use_type,
+                commercial_model: right.commercial_model,
+                allowance: format!("{:?}", right.allowance),
+            })
+            .collect(),
     }
 }
 
 /// Convert IndexMap<String, ParsedResource> to JavaScript object
+fn convert_identifier_index(index: &IdentifierIndex, releases: &[ParsedRelease]) -> serde_json::Value {
+    let js_releases: serde_json::Map<String, serde_json::Value> = index
+        .releases
+        .iter()
+        .filter_map(|(key, pos)| {
+            releases
+                .get(*pos)
+                .map(|r| (key.clone(), serde_json::Value::String(r.release_id.clone())))
+        })
+        .collect();
+
+    let js_resources: serde_json::Map<String, serde_json::Value> = index
+        .resources
+        .iter()
+        .map(|(key, reference)| (key.clone(), serde_json::Value::String(reference.clone())))
+        .collect();
+
+    serde_json::json!({
+        "releases": js_releases,
+        "resources": js_resources,
+    })
+}
+
+fn lookup_identifier(
+    message: &ParsedMessage,
+    section: &str,
+    id_type: &str,
+    value: &str,
+) -> Result<Option<String>> {
+    let id_type: IdentifierType = id_type
+        .parse()
+        .map_err(|e: String| napi::Error::new(napi::Status::InvalidArg, e))?;
+
+    Ok(IdentifierIndex::key(&id_type, value).and_then(|key| {
+        message.identifier_index[section][key.as_str()]
+            .as_str()
+            .map(String::from)
+    }))
+}
+
 fn convert_resources_to_js_object(resources: indexmap::IndexMap<String, ParsedResource>) -> serde_json::Value {
     let mut js_resources = serde_json::Map::new();
 
@@ -262,13 +482,51 @@ fn convert_resources_to_js_object(resources: indexmap::IndexMap<String, ParsedRe
     serde_json::Value::Object(js_resources)
 }
 
+/// Error for a message that parsed to no releases, resources or deals,
+/// led by the code saying whether its ReleaseList was missing, empty or
+/// held releases the parser could not read
+fn nothing_parsed_error(parsed: &ParsedERNMessage) -> napi::Error {
+    let diagnostic = parsed.warnings.iter().find(|warning| {
+        matches!(
+            warning.code.as_str(),
+            "MISSING_RELEASE_LIST" | "EMPTY_RELEASE_LIST" | "RELEASE_LIST_PRESENT_BUT_NONE_PARSED"
+        )
+    });
+
+    let reason = match diagnostic {
+        Some(warning) => format!(
+            "{}: DDEX parsing succeeded but no releases, resources, or deals were found ({}).",
+            warning.code, warning.message
+        ),
+        None => "DDEX parsing succeeded but no releases, resources, or deals were found. Please check that the XML contains valid DDEX content.".to_string(),
+    };
+    napi::Error::new(napi::Status::InvalidArg, reason)
+}
+
 /// Convert ParsedERNMessage to Node.js ParsedMessage structure
 fn convert_parsed_message(
     parsed: ParsedERNMessage,
     options: Option<&ParseOptions>,
 ) -> ParsedMessage {
+    // Serialize the graph before the flat form is taken apart
+    let graph = if options.and_then(|o| o.include_graph).unwrap_or(false) {
+        serde_json::to_value(&parsed.graph).ok()
+    } else {
+        None
+    };
+
+    let message_control_type = parsed
+        .graph
+        .message_header
+        .message_control_type
+        .as_ref()
+        .map(|control_type| format!("{:?}", control_type));
+
     let flat = parsed.flat; // Take ownership instead of borrowing
 
+    // Resolve index positions to release IDs before the releases are consumed
+    let identifier_index = convert_identifier_index(&flat.identifier_index, &flat.releases);
+
     // Convert the actual data structures
     let releases: Vec<JsRelease> = flat.releases.into_iter().map(convert_release).collect();
     let resources_obj = convert_resources_to_js_object(flat.resources.clone());
@@ -284,15 +542,16 @@ fn convert_parsed_message(
         .and_then(|o| o.collect_statistics)
         .unwrap_or(false)
     {
+        let document = &parsed.document_stats;
         Some(ParseStatistics {
             parse_time_ms: 0.0, // TODO: Add timing
             memory_used_bytes: 0, // TODO: Add memory tracking
-            element_count: 0, // TODO: Count elements during parsing
-            attribute_count: 0, // TODO: Count attributes during parsing
-            comment_count: 0, // TODO: Count comments during parsing
+            element_count: document.element_count as u32,
+            attribute_count: document.attribute_count as u32,
+            comment_count: document.comment_count as u32,
             extension_count: if parsed.extensions.is_some() { 1 } else { 0 },
-            namespace_count: 1, // TODO: Count namespaces during parsing
-            file_size_bytes: 0, // TODO: Track file size
+            namespace_count: document.namespace_count as u32,
+            file_size_bytes: document.file_size_bytes as u32,
         })
     } else {
         None
@@ -329,6 +588,7 @@ fn convert_parsed_message(
         sender_id: flat.sender.id.clone(),
         recipient_name: flat.recipient.name.clone(),
         recipient_id: flat.recipient.id.clone(),
+        message_control_type,
         version: flat.version.clone(),
         profile: flat.profile.clone(),
 
@@ -343,12 +603,59 @@ fn convert_parsed_message(
         releases,
         resources: resources_obj,
         deals,
+        identifier_index,
+        audit_trail: flat.audit_trail.into_iter().map(convert_audit_trail_entry).collect(),
+        purged_releases: flat.purged_releases.into_iter().map(convert_purged_release).collect(),
+        graph,
+        truncated: parsed.truncated,
+        header_extensions: parsed.header_extensions,
 
         statistics,
         fidelity_info,
     }
 }
 
+fn convert_audit_trail_entry(entry: AuditTrailEntry) -> JsAuditTrailEvent {
+    JsAuditTrailEvent {
+        event_type: entry.event_type,
+        date_time: entry.date_time.to_rfc3339(),
+        party: entry.party,
+    }
+}
+
+fn convert_purged_release(release: PurgedRelease) -> JsPurgedRelease {
+    JsPurgedRelease {
+        upc: release.identifiers.upc,
+        grid: release.identifiers.grid,
+        title: release.title,
+        display_artist: release.display_artist,
+    }
+}
+
+fn convert_party(party: Party) -> JsParty {
+    JsParty {
+        party_reference: party.party_reference,
+        names: party.party_name.into_iter().map(|name| name.text).collect(),
+        identifiers: party
+            .party_id
+            .into_iter()
+            .map(|id| JsPartyIdentifier {
+                id_type: format!("{:?}", id.id_type),
+                namespace: id.namespace,
+                value: id.value,
+            })
+            .collect(),
+        roles: party
+            .party_role
+            .into_iter()
+            .map(|role| match role {
+                PartyRole::Other(role) => role,
+                role => format!("{:?}", role),
+            })
+            .collect(),
+    }
+}
+
 // JavaScript-compatible type definitions
 #[napi(object)]
 #[derive(serde::Serialize)]
@@ -356,17 +663,34 @@ pub struct JsRelease {
     pub release_id: String,
     pub title: String,
     pub default_title: String,
+    /// Internal title from `ReferenceTitle`
+    pub reference_title: Option<String>,
+    /// Customer-facing title from `DisplayTitle`
+    pub display_title: Option<String>,
     pub subtitle: Option<String>,
     pub display_artist: String,
+    pub artists: Vec<JsArtist>,
     pub release_type: String,
     pub genre: Option<String>,
     pub sub_genre: Option<String>,
     pub track_count: u32,
+    /// Summed runtime of the release's tracks, in seconds
+    pub total_duration_seconds: u32,
     pub disc_count: Option<u32>,
     pub release_date: Option<String>,
+    /// Territory-specific release dates, keyed by territory code in document
+    /// order
+    pub release_dates_by_territory: indexmap::IndexMap<String, String>,
     pub original_release_date: Option<String>,
+    /// When metadata may first be shown and pre-orders open
+    pub preorder_date: Option<String>,
+    /// When the release goes on sale, apart from deal validity
+    pub availability_start: Option<String>,
     pub label_name: Option<String>,
     pub tracks: Vec<JsTrack>,
+    pub descriptions: Vec<JsDescription>,
+    /// Original `<Release>` XML when parsed with `includeRaw`
+    pub raw: Option<String>,
 }
 
 #[napi(object)]
@@ -374,7 +698,11 @@ pub struct JsRelease {
 pub struct JsTrack {
     pub track_id: String,
     pub title: String,
+    /// Version title from `SubTitle`, e.g. "Live at Wembley"
+    pub subtitle: Option<String>,
     pub artist: String,
+    pub artists: Vec<JsArtist>,
+    pub contributors: Vec<JsContributor>,
     pub duration: Option<String>,
     pub position: Option<u32>,
     pub disc_number: Option<u32>,
@@ -382,6 +710,39 @@ pub struct JsTrack {
     pub resource_reference: Option<String>,
 }
 
+#[napi(object)]
+#[derive(serde::Serialize)]
+pub struct JsArtist {
+    pub name: String,
+    pub role: String,
+    /// `ArtistPartyReference` naming the artist's party
+    pub party_reference: Option<String>,
+    /// Identifiers of the artist's party, such as ISNI and IPI
+    pub identifiers: Vec<JsPartyIdentifier>,
+}
+
+#[napi(object)]
+#[derive(serde::Serialize)]
+pub struct JsContributor {
+    pub name: String,
+    /// Roles as written, e.g. `Conductor`, `Orchestra` or `Soloist`
+    pub roles: Vec<String>,
+    /// Instruments played, e.g. `Guitar`
+    pub instruments: Vec<String>,
+    /// `ContributorPartyReference` naming the contributor's party
+    pub party_reference: Option<String>,
+}
+
+#[napi(object)]
+#[derive(serde::Serialize)]
+pub struct JsPartyIdentifier {
+    /// `ISNI`, `IPI` or `Proprietary`
+    pub id_type: String,
+    /// Issuer of a proprietary id, e.g. `DPID`
+    pub namespace: Option<String>,
+    pub value: String,
+}
+
 #[napi(object)]
 #[derive(serde::Serialize)]
 pub struct JsResource {
@@ -391,9 +752,53 @@ pub struct JsResource {
     pub duration_seconds: Option<f64>,
     pub duration_string: Option<String>,
     pub file_format: Option<String>,
+    /// Audio properties come from the first PCM/lossless technical details
+    /// block of the resource, else its first block
+    pub audio_codec: Option<String>,
+    /// In kbps
     pub bitrate: Option<i32>,
+    /// In Hz
     pub sample_rate: Option<i32>,
+    pub bits_per_sample: Option<u32>,
     pub file_size: Option<String>, // Convert u64 to string for JS compatibility
+    pub files: Vec<JsResourceFile>,
+    /// Preview clip start, in seconds from the start of the resource
+    pub preview_start_seconds: Option<f64>,
+    pub preview_duration_seconds: Option<f64>,
+    /// Picture properties, set on video resources
+    pub video_details: Option<JsVideoDetails>,
+    pub descriptions: Vec<JsDescription>,
+    /// Original resource XML when parsed with `includeRaw`
+    pub raw: Option<String>,
+}
+
+#[napi(object)]
+#[derive(serde::Serialize)]
+pub struct JsVideoDetails {
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    pub frame_rate: Option<f64>,
+    pub codec: Option<String>,
+    pub aspect_ratio: Option<String>,
+    pub bitrate: Option<i32>,
+}
+
+#[napi(object)]
+#[derive(serde::Serialize)]
+pub struct JsResourceFile {
+    pub file_name: Option<String>,
+    pub file_path: Option<String>,
+    pub uri: Option<String>,
+    pub hash_algorithm: Option<String>,
+    pub hash_value: Option<String>,
+}
+
+#[napi(object)]
+#[derive(serde::Serialize)]
+pub struct JsDescription {
+    pub description_type: String,
+    pub text: String,
+    pub language_code: Option<String>,
 }
 
 #[napi(object)]
@@ -403,10 +808,154 @@ pub struct JsDeal {
     pub releases: Vec<String>,
     pub start_date: Option<String>,
     pub end_date: Option<String>,
+    /// `TerritoryCode`s the deal applies in, e.g. `["US", "CA", "MX"]` or `["Worldwide"]`
     pub territories: Vec<String>,
+    /// `ExcludedTerritoryCode`s carved out of `territories`
+    pub excluded_territories: Vec<String>,
     pub usage_rights: Vec<String>,
     pub restrictions: Vec<String>,
+    /// First `CommercialModelType` of the deal; empty when none is given
     pub commercial_model: String,
+    /// Every `CommercialModelType` of the deal, in document order
+    pub commercial_models: Vec<String>,
+    /// Typed view of `usage_rights` and `restrictions`
+    pub rights: Vec<JsUsageRight>,
+}
+
+#[napi(object)]
+#[derive(serde::Serialize)]
+pub struct JsUsageRight {
+    pub use_type: String,
+    pub commercial_model: Option<String>,
+    /// "Allowed", or "Disallowed" for the uses of a `TakeDown` deal
+    pub allowance: String,
+}
+
+#[napi(object)]
+#[derive(serde::Serialize)]
+pub struct JsAuditTrailEvent {
+    pub event_type: String,
+    pub date_time: String,
+    /// Id or name of the party that performed the event
+    pub party: Option<String>,
+}
+
+#[napi(object)]
+#[derive(serde::Serialize)]
+pub struct JsPurgedRelease {
+    pub upc: Option<String>,
+    pub grid: Option<String>,
+    pub title: Option<String>,
+    pub display_artist: String,
+}
+
+#[napi(object)]
+pub struct JsResourceHashCheck {
+    pub resource_reference: String,
+    /// The file as the message locates it: its URI, or path and name
+    pub file: String,
+    /// e.g. "MD5", "SHA-256"
+    pub algorithm: String,
+    pub expected: String,
+    /// Hex digest of the resolved file, when it could be read and hashed
+    pub actual: Option<String>,
+    /// `Match`, `Mismatch`, `Missing` or `UnsupportedAlgorithm`
+    pub status: String,
+}
+
+#[napi(object)]
+#[derive(serde::Serialize)]
+pub struct JsParty {
+    /// `PartyReference` of a `PartyList` party; absent for ERN 3.8 parties
+    /// named in place
+    pub party_reference: Option<String>,
+    pub names: Vec<String>,
+    /// DPID, ISNI, IPI and proprietary ids
+    pub identifiers: Vec<JsPartyIdentifier>,
+    /// Roles played across the message, e.g. `Artist`, `Label`,
+    /// `RightsController` or a contributor role such as `Composer`
+    pub roles: Vec<String>,
+}
+
+/// Parse `xml` into the message handed to JavaScript, the work shared by
+/// `parseSync` and `parse`
+fn parse_document(
+    parser: &mut RustDDEXParser,
+    xml: String,
+    options: Option<ParseOptions>,
+) -> Result<ParsedMessage> {
+    // Validate input
+    if xml.is_empty() {
+        return Err(napi::Error::new(
+            napi::Status::InvalidArg,
+            "XML input cannot be empty. Please provide a valid DDEX XML document.",
+        ));
+    }
+
+    if xml.len() > 100_000_000 {  // 100MB limit
+        return Err(napi::Error::new(
+            napi::Status::InvalidArg,
+            "XML input too large (>100MB). Consider using streaming mode for large files.",
+        ));
+    }
+
+    // Convert string to cursor
+    let cursor = string_to_cursor(xml.clone());
+    let rust_options = convert_parse_options(options.as_ref())?;
+
+    // Call the real Rust parser with enhanced error context
+    match parser.parse_with_options(cursor, rust_options) {
+        Ok(parsed_message) => {
+            // Validate that we got meaningful data
+            if parsed_message.flat.releases.is_empty() &&
+               parsed_message.flat.resources.is_empty() &&
+               parsed_message.flat.deals.is_empty() {
+                return Err(nothing_parsed_error(&parsed_message));
+            }
+
+            // Convert the Rust ParsedERNMessage to Node.js ParsedMessage
+            // All data is now real parsed data - no mock data possible at this point
+            let result = convert_parsed_message(parsed_message, options.as_ref());
+            Ok(result)
+        }
+        Err(parse_error) => {
+            // Add context about the input that failed
+            let context_info = format!(
+                " [Input context: {} bytes, starts with: '{}']",
+                xml.len(),
+                xml.chars().take(100).collect::<String>().replace('\n', " ")
+            );
+
+            // Convert ParseError to NAPI error with additional context
+            let mut error = parse_error_to_napi(parse_error);
+            error.reason = format!("{}{}", error.reason, context_info);
+            Err(error)
+        }
+    }
+}
+
+/// Run [`parse_document`] on the blocking pool, so CPU-bound parsing does not
+/// hold up the thread that awaits it
+async fn parse_off_thread(
+    mut parser: RustDDEXParser,
+    xml: String,
+    options: Option<ParseOptions>,
+) -> Result<ParsedMessage> {
+    off_thread(move || parse_document(&mut parser, xml, options)).await
+}
+
+/// Run `task` on the blocking pool
+async fn off_thread<T: Send + 'static>(
+    task: impl FnOnce() -> Result<T> + Send + 'static,
+) -> Result<T> {
+    tokio::task::spawn_blocking(task)
+        .await
+        .map_err(|e| {
+            napi::Error::new(
+                napi::Status::GenericFailure,
+                format!("Parse task failed: {}", e),
+            )
+        })?
 }
 
 #[napi(js_name = "DdexParser")]
@@ -417,9 +966,23 @@ pub struct DdexParser {
 #[napi]
 impl DdexParser {
     #[napi(constructor)]
-    pub fn new() -> Self {
+    pub fn new(security: Option<SecurityOptions>) -> Self {
+        let mut config = ddex_parser::parser::security::SecurityConfig::default();
+        if let Some(security) = security {
+            if let Some(max) = security.max_attributes_per_element {
+                config.max_attributes_per_element = max as usize;
+            }
+            if let Some(max) = security.max_name_length {
+                config.max_name_length = max as usize;
+            }
+            if security.allow_external_entities == Some(true) {
+                config.disable_dtd = false;
+                config.disable_external_entities = false;
+            }
+        }
+
         DdexParser {
-            inner: RustDDEXParser::new(),
+            inner: RustDDEXParser::with_config(config),
         }
     }
 
@@ -461,7 +1024,14 @@ impl DdexParser {
 
     #[napi]
     pub fn parse_sync(&mut self, xml: String, options: Option<ParseOptions>) -> Result<ParsedMessage> {
-        // Validate input
+        parse_document(&mut self.inner, xml, options)
+    }
+
+    /// Parse on a worker thread, leaving the event loop free while a large
+    /// document is read
+    #[napi]
+    pub async fn parse(&self, xml: String, options: Option<ParseOptions>) -> Result<ParsedMessage> {
+        // Validate input early to avoid unnecessary work
         if xml.is_empty() {
             return Err(napi::Error::new(
                 napi::Status::InvalidArg,
@@ -469,73 +1039,317 @@ impl DdexParser {
             ));
         }
 
-        if xml.len() > 100_000_000 {  // 100MB limit
-            return Err(napi::Error::new(
-                napi::Status::InvalidArg,
-                "XML input too large (>100MB). Consider using streaming mode for large files.",
-            ));
-        }
-
-        // Convert string to cursor
-        let cursor = string_to_cursor(xml.clone());
+        parse_off_thread(self.inner.clone(), xml, options)
+            .await
+            .map_err(|mut err| {
+                // Add async context to error message
+                err.reason = format!("{} [Note: This was called via async parse method]", err.reason);
+                err
+            })
+    }
 
-        // Call the real Rust parser with enhanced error context
-        match self.inner.parse(cursor) {
-            Ok(parsed_message) => {
-                // Validate that we got meaningful data
-                if parsed_message.flat.releases.is_empty() &&
-                   parsed_message.flat.resources.is_empty() &&
-                   parsed_message.flat.deals.is_empty() {
-                    return Err(napi::Error::new(
-                        napi::Status::InvalidArg,
-                        "DDEX parsing succeeded but no releases, resources, or deals were found. Please check that the XML contains valid DDEX content.",
-                    ));
+    /// Parse whatever can be recovered instead of failing on the first
+    /// problem. Releases that fail to parse are left out of `message` and
+    /// described in `errors`; `message` is null if the problem lies outside
+    /// the releases.
+    #[napi]
+    pub fn parse_partial(&mut self, xml: String, options: Option<ParseOptions>) -> Result<PartialParseResult> {
+        let rust_options = convert_parse_options(options.as_ref())?;
+        let partial = self.inner.parse_partial(string_to_cursor(xml), rust_options);
+
+        let warnings = partial
+            .message
+            .iter()
+            .flat_map(|message| &message.warnings)
+            .map(|warning| format!("{}: {}", warning.code, warning.message))
+            .collect();
+        let errors = partial
+            .errors
+            .into_iter()
+            .map(|partial_error| {
+                let mut detailed = parse_error_to_detailed(partial_error.error);
+                if let Some(reference) = partial_error.release_reference {
+                    detailed.context = Some(format!("Release '{}' was skipped", reference));
                 }
+                detailed
+            })
+            .collect();
+
+        Ok(PartialParseResult {
+            message: partial
+                .message
+                .map(|message| convert_parsed_message(message, options.as_ref())),
+            errors,
+            warnings,
+        })
+    }
 
-                // Convert the Rust ParsedERNMessage to Node.js ParsedMessage
-                // All data is now real parsed data - no mock data possible at this point
-                let result = convert_parsed_message(parsed_message, options.as_ref());
-                Ok(result)
-            }
-            Err(parse_error) => {
-                // Add context about the input that failed
-                let context_info = format!(
-                    " [Input context: {} bytes, starts with: '{}']",
-                    xml.len(),
-                    xml.chars().take(100).collect::<String>().replace('\n', " ")
-                );
+    /// Parse only the release with the given `ReleaseReference`, skipping
+    /// every other release. Errors if no release has that reference.
+    #[napi]
+    pub fn parse_release(&mut self, xml: String, release_reference: String) -> Result<JsRelease> {
+        self.inner
+            .parse_release(string_to_cursor(xml), &release_reference)
+            .map(convert_release)
+            .map_err(parse_error_to_napi)
+    }
 
-                // Convert ParseError to NAPI error with additional context
-                let mut error = parse_error_to_napi(parse_error);
-                error.reason = format!("{}{}", error.reason, context_info);
-                Err(error)
-            }
+    /// Parse every message of an input holding several, concatenated or
+    /// wrapped in a delivery manifest, each with its own header
+    #[napi]
+    pub fn parse_all(&mut self, xml: String) -> Result<Vec<ParsedMessage>> {
+        self.inner
+            .parse_all(string_to_cursor(xml))
+            .map(|messages| {
+                messages
+                    .into_iter()
+                    .map(|message| convert_parsed_message(message, None))
+                    .collect()
+            })
+            .map_err(parse_error_to_napi)
+    }
+
+    /// Index the releases of a message without parsing them; each is parsed
+    /// when asked for, keeping memory low for random access into large
+    /// catalogs
+    #[napi]
+    pub fn parse_lazy(&mut self, xml: String) -> Result<LazyMessage> {
+        let inner = self
+            .inner
+            .parse_lazy(string_to_cursor(xml))
+            .map_err(parse_error_to_napi)?;
+        Ok(LazyMessage { inner })
+    }
+
+    /// Serialize the graph form of the message as JSON: parties with the
+    /// roles they play, and the references releases, resources and deals
+    /// make to each other. The flat form collapses most of this.
+    #[napi]
+    pub fn to_graph_json(&mut self, xml: String) -> Result<String> {
+        let parsed = self
+            .inner
+            .parse(string_to_cursor(xml))
+            .map_err(parse_error_to_napi)?;
+
+        serde_json::to_string(&parsed.graph).map_err(|e| {
+            napi::Error::new(
+                napi::Status::GenericFailure,
+                format!("Failed to serialize graph: {}", e),
+            )
+        })
+    }
+
+    /// Map each release reference to its availability per territory code
+    /// (`available`, `start_date`, `commercial_models`), merged from every
+    /// deal. Territories no deal names share the "Worldwide" entry.
+    #[napi]
+    pub fn availability_map(&mut self, xml: String) -> Result<serde_json::Value> {
+        let parsed = self
+            .inner
+            .parse(string_to_cursor(xml))
+            .map_err(parse_error_to_napi)?;
+
+        serde_json::to_value(parsed.availability_map().releases).map_err(|e| {
+            napi::Error::new(
+                napi::Status::GenericFailure,
+                format!("Failed to serialize availability map: {}", e),
+            )
+        })
+    }
+
+    /// Versions, message types, canonicalization algorithms and feature
+    /// flags this build of the parser supports
+    #[napi]
+    pub fn capabilities(&self) -> Capabilities {
+        let capabilities = self.inner.capabilities();
+        Capabilities {
+            versions: capabilities
+                .versions
+                .into_iter()
+                .map(version_to_string)
+                .collect(),
+            message_types: capabilities.message_types,
+            canonicalization: capabilities.canonicalization,
+            features: capabilities.features,
         }
     }
 
+    /// Count releases, resources, deals and parties with the fast byte-level
+    /// scanner, without building any models
     #[napi]
-    pub async unsafe fn parse(&mut self, xml: String, options: Option<ParseOptions>) -> Result<ParsedMessage> {
-        // For now, delegate to sync version with proper async handling
-        // TODO: Implement true async parsing using tokio::task::spawn_blocking for CPU-intensive work
+    pub fn count_entities(&self, xml: String) -> Result<EntityCounts> {
+        let counts = self
+            .inner
+            .count_entities(Cursor::new(xml.as_bytes()))
+            .map_err(parse_error_to_napi)?;
+
+        Ok(EntityCounts {
+            releases: counts.releases as u32,
+            resources: counts.resources as u32,
+            deals: counts.deals as u32,
+            parties: counts.parties as u32,
+        })
+    }
 
-        // Validate input early to avoid unnecessary work
-        if xml.is_empty() {
-            return Err(napi::Error::new(
-                napi::Status::InvalidArg,
-                "XML input cannot be empty. Please provide a valid DDEX XML document.",
-            ));
+    /// Run the fast scanner and return entity counts with its performance stats
+    #[napi]
+    pub fn scan_fast(&self, xml: String) -> Result<FastScanStats> {
+        let summary = self
+            .inner
+            .scan_fast(Cursor::new(xml.as_bytes()))
+            .map_err(parse_error_to_napi)?;
+
+        Ok(FastScanStats {
+            elements: summary.elements as u32,
+            releases: summary.releases as u32,
+            resources: summary.resources as u32,
+            deals: summary.deals as u32,
+            total_bytes: summary.total_bytes as f64,
+            throughput_mbps: summary.throughput_mbps,
+            peak_memory_mb: summary.peak_memory_mb,
+            avg_element_size: summary.avg_element_size,
+        })
+    }
+
+    /// Predict the release count, peak memory and time a full parse will
+    /// take from a quick scan. Release counts are typically within 10%;
+    /// memory and time are ballpark figures for scheduling.
+    #[napi]
+    pub fn estimate_cost(&self, xml: String) -> Result<CostEstimate> {
+        let estimate = self
+            .inner
+            .estimate_cost(Cursor::new(xml.as_bytes()))
+            .map_err(parse_error_to_napi)?;
+
+        Ok(CostEstimate {
+            estimated_releases: estimate.estimated_releases as u32,
+            estimated_memory_bytes: estimate.estimated_memory_bytes as f64,
+            estimated_parse_ms: estimate.estimated_parse_ms,
+            max_depth: estimate.max_depth as u32,
+            sampled: estimate.sampled,
+        })
+    }
+
+    /// Stream the document with each streaming strategy and return one row
+    /// per strategy with its throughput, peak memory and elements found
+    #[napi]
+    pub fn benchmark_streaming_strategies(&self, xml: String) -> Result<Vec<StrategyBenchmark>> {
+        let comparison = self
+            .inner
+            .compare_streaming_strategies(Cursor::new(xml.as_bytes()))
+            .map_err(parse_error_to_napi)?;
+
+        Ok(comparison
+            .results
+            .into_iter()
+            .map(|result| StrategyBenchmark {
+                elements_found: result.elements_found() as u32,
+                strategy: result.strategy,
+                elapsed_ms: result.elapsed.as_secs_f64() * 1000.0,
+                throughput_mb_per_sec: result.throughput_mb_per_sec,
+                peak_memory_bytes: result.peak_memory_bytes as f64,
+                releases_found: result.releases_found as u32,
+                resources_found: result.resources_found as u32,
+            })
+            .collect())
+    }
+
+    /// Every party in the message, once each, with its identifiers, names
+    /// and roles, without materializing releases, resources or deals
+    #[napi]
+    pub fn extract_parties(&self, xml: String) -> Result<Vec<JsParty>> {
+        let parties = self
+            .inner
+            .extract_parties(Cursor::new(xml.as_bytes()))
+            .map_err(parse_error_to_napi)?;
+
+        Ok(parties.into_iter().map(convert_party).collect())
+    }
+
+    /// Check each declared `HashSum` against the delivered file.
+    /// `fileResolver` maps a file's location as the message gives it (its
+    /// URI, or path and name) to a local path, either as an object or as a
+    /// function returning the path, or nothing if the file is unknown.
+    #[napi(
+        ts_args_type = "xml: string, fileResolver: Record<string, string> | ((location: string) => string | null | undefined)"
+    )]
+    pub fn verify_resource_hashes(
+        &self,
+        env: Env,
+        xml: String,
+        file_resolver: Either<JsFunction, HashMap<String, String>>,
+    ) -> Result<Vec<JsResourceHashCheck>> {
+        let cursor = Cursor::new(xml.as_bytes());
+        // The first error thrown by a resolver function, raised once the
+        // checks are done
+        let mut resolver_error = None;
+        let checks = match &file_resolver {
+            Either::A(resolver) => self.inner.verify_resource_hashes(cursor, |location| {
+                let resolved = env
+                    .create_string(location)
+                    .and_then(|location| resolver.call(None, &[location]))
+                    .and_then(|path| match path.get_type()? {
+                        ValueType::String => Ok(Some(path.coerce_to_string()?.into_utf8()?.into_owned()?)),
+                        _ => Ok(None),
+                    });
+                match resolved {
+                    Ok(path) => path.map(std::path::PathBuf::from),
+                    Err(err) => {
+                        resolver_error.get_or_insert(err);
+                        None
+                    }
+                }
+            }),
+            Either::B(paths) => self
+                .inner
+                .verify_resource_hashes(cursor, |location| paths.get(location).map(std::path::PathBuf::from)),
         }
+        .map_err(parse_error_to_napi)?;
 
-        // Use sync version but wrapped in proper async context
-        match self.parse_sync(xml, options) {
-            Ok(result) => Ok(result),
-            Err(err) => {
-                // Add async context to error message
-                let mut async_err = err;
-                async_err.reason = format!("{} [Note: This was called via async parse method]", async_err.reason);
-                Err(async_err)
-            }
+        if let Some(err) = resolver_error {
+            return Err(err);
         }
+        Ok(checks
+            .into_iter()
+            .map(|check| JsResourceHashCheck {
+                resource_reference: check.resource_reference,
+                file: check.file,
+                algorithm: check.algorithm,
+                expected: check.expected,
+                actual: check.actual,
+                status: format!("{:?}", check.status),
+            })
+            .collect())
+    }
+
+    /// Look up a release in a parsed message by any of its identifiers
+    /// (e.g. "UPC", "EAN", "GRid", "Proprietary")
+    #[napi]
+    pub fn find_release_by_identifier(
+        &self,
+        message: ParsedMessage,
+        id_type: String,
+        value: String,
+    ) -> Result<Option<JsRelease>> {
+        let release_id = lookup_identifier(&message, "releases", &id_type, &value)?;
+        Ok(release_id.and_then(|id| {
+            message
+                .releases
+                .into_iter()
+                .find(|r| r.release_id == id)
+        }))
+    }
+
+    /// Look up a resource in a parsed message by any of its identifiers (e.g. "ISRC")
+    #[napi]
+    pub fn find_resource_by_identifier(
+        &self,
+        message: ParsedMessage,
+        id_type: String,
+        value: String,
+    ) -> Result<Option<serde_json::Value>> {
+        let reference = lookup_identifier(&message, "resources", &id_type, &value)?;
+        Ok(reference.and_then(|r| message.resources.get(&r).cloned()))
     }
 
     #[napi]
@@ -597,9 +1411,44 @@ impl DdexParser {
         }
     }
 
+    /// Stream the releases of a message, `TrackRelease`s included, one
+    /// at a time without building the whole message
+    #[napi]
+    pub fn stream(&self, xml: String, _options: Option<StreamOptions>) -> Result<ReleaseStream> {
+        let version = self
+            .inner
+            .detect_version(Cursor::new(xml.as_bytes()))
+            .map_err(parse_error_to_napi)?;
+        Ok(ReleaseStream::new(xml, version))
+    }
+
+    /// Stream the resources, releases and deals of a message in document
+    /// order, each already converted. `kind` says which field is set.
+    #[napi]
+    pub fn stream_typed(&self, xml: String) -> Result<TypedElementStream> {
+        let version = self
+            .inner
+            .detect_version(string_to_cursor(xml.clone()))
+            .map_err(parse_error_to_napi)?;
+
+        Ok(TypedElementStream {
+            inner: TypedStreamIterator::new(string_to_cursor(xml), version),
+        })
+    }
+
+    /// Stream the deals of a message, each with the releases it applies to.
+    /// Releases are read first and only summarized, so deals can be
+    /// forwarded one at a time without holding the whole message.
     #[napi]
-    pub fn stream(&self, _xml: String, _options: Option<StreamOptions>) -> Result<ReleaseStream> {
-        Ok(ReleaseStream::new())
+    pub fn stream_deals(&self, xml: String) -> Result<DealStream> {
+        let version = self
+            .inner
+            .detect_version(string_to_cursor(xml.clone()))
+            .map_err(parse_error_to_napi)?;
+
+        Ok(DealStream {
+            inner: CorrelatedDealIterator::new(string_to_cursor(xml), version),
+        })
     }
 
     /// Get detailed error information for debugging - useful for error handling in JavaScript
@@ -619,6 +1468,19 @@ impl DdexParser {
     }
 }
 
+/// Limits on pathological documents; unset fields keep the strict defaults
+#[napi(object)]
+#[derive(Default)]
+pub struct SecurityOptions {
+    /// Most attributes allowed on one element (default 100)
+    pub max_attributes_per_element: Option<u32>,
+    /// Longest element or attribute name in bytes, prefix included (default 256)
+    pub max_name_length: Option<u32>,
+    /// Accept DOCTYPEs declaring external or parameter entities, for trusted
+    /// pipelines; they are still never fetched or expanded (default false)
+    pub allow_external_entities: Option<bool>,
+}
+
 #[napi(object)]
 #[derive(Default)]
 pub struct ParseOptions {
@@ -631,6 +1493,20 @@ pub struct ParseOptions {
     pub timeout_ms: Option<u32>,
     pub allow_blocking: Option<bool>,
     pub chunk_size: Option<u32>,
+    /// Whitespace handling for titles, names and descriptions: "trim" (default), "collapse" or "preserve"
+    pub whitespace: Option<String>,
+    /// Fail if a party reference names no party in the PartyList
+    pub validate_party_references: Option<bool>,
+    /// Attach the graph form of the message as `graph` (off by default)
+    pub include_graph: Option<bool>,
+    /// Parse only the first this many releases, for a quick preview
+    pub max_releases: Option<u32>,
+    /// Genres to rewrite as they are parsed, from the sender's vocabulary to ours
+    pub genre_map: Option<HashMap<String, String>>,
+    /// Map non-standard deal territory codes onto ISO ones, on top of the
+    /// built-in `UK` and `EU` aliases, e.g. `{ ROW: ["US", "CA"] }`; pass `{}`
+    /// for the built-ins alone
+    pub territory_aliases: Option<HashMap<String, Vec<String>>>,
 
     // Perfect Fidelity Engine options
     pub fidelity_level: Option<String>, // "fast", "balanced", "perfect"
@@ -660,12 +1536,16 @@ pub struct StreamOptions {
 #[napi(object)]
 pub struct ParsedMessage {
     pub message_id: String,
+    /// Root element, e.g. "NewReleaseMessage", "UpdateReleaseMessage" or
+    /// "PurgeReleaseMessage"
     pub message_type: String,
     pub message_date: String,
     pub sender_name: String,
     pub sender_id: String,
     pub recipient_name: String,
     pub recipient_id: String,
+    /// `MessageControlType`, e.g. "LiveMessage" or "TestMessage"
+    pub message_control_type: Option<String>,
     pub version: String,
     pub profile: Option<String>,
 
@@ -680,12 +1560,79 @@ pub struct ParsedMessage {
     pub releases: Vec<JsRelease>,
     pub resources: serde_json::Value, // Will be a JS object with resource IDs as keys
     pub deals: Vec<JsDeal>,
+    // { releases: { "ICPN:123": "R1" }, resources: { "ISRC:...": "A1" } }
+    pub identifier_index: serde_json::Value,
+    /// `MessageAuditTrail` events, in document order
+    pub audit_trail: Vec<JsAuditTrailEvent>,
+    /// Releases a `PurgeReleaseMessage` asks to be removed
+    pub purged_releases: Vec<JsPurgedRelease>,
+    /// Graph form of the message, as `toGraphJson` returns it, when parsed
+    /// with `includeGraph`
+    pub graph: Option<serde_json::Value>,
+    /// Whether releases past `maxReleases` were left out
+    pub truncated: bool,
+    /// Non-standard `MessageHeader` children, such as partner routing hints,
+    /// by local name in document order
+    pub header_extensions: indexmap::IndexMap<String, String>,
 
     // Perfect Fidelity Engine results
     pub statistics: Option<ParseStatistics>,
     pub fidelity_info: Option<FidelityInfo>,
 }
 
+#[napi(object)]
+pub struct Capabilities {
+    pub versions: Vec<String>,
+    pub message_types: Vec<String>,
+    pub canonicalization: Vec<String>,
+    pub features: Vec<String>,
+}
+
+#[napi(object)]
+pub struct EntityCounts {
+    pub releases: u32,
+    pub resources: u32,
+    pub deals: u32,
+    pub parties: u32,
+}
+
+#[napi(object)]
+pub struct FastScanStats {
+    pub elements: u32,
+    pub releases: u32,
+    pub resources: u32,
+    pub deals: u32,
+    pub total_bytes: f64,
+    pub throughput_mbps: f64,
+    pub peak_memory_mb: f64,
+    pub avg_element_size: f64,
+}
+
+#[napi(object)]
+pub struct CostEstimate {
+    pub estimated_releases: u32,
+    pub estimated_memory_bytes: f64,
+    pub estimated_parse_ms: f64,
+    /// Deepest element nesting seen by the scan
+    pub max_depth: u32,
+    /// Whether the counts were extrapolated from samples of a large document
+    pub sampled: bool,
+}
+
+#[napi(object)]
+pub struct StrategyBenchmark {
+    /// Streaming implementation, e.g. "zero_copy"
+    pub strategy: String,
+    pub elapsed_ms: f64,
+    pub throughput_mb_per_sec: f64,
+    /// Peak memory the strategy reports having held
+    pub peak_memory_bytes: f64,
+    /// Releases and resources together
+    pub elements_found: u32,
+    pub releases_found: u32,
+    pub resources_found: u32,
+}
+
 #[napi(object)]
 pub struct ParseStatistics {
     pub parse_time_ms: f64,
@@ -717,6 +1664,13 @@ pub struct SanityCheckResult {
     pub warnings: Vec<String>,
 }
 
+#[napi(object)]
+pub struct PartialParseResult {
+    pub message: Option<ParsedMessage>,
+    pub errors: Vec<DetailedError>,
+    pub warnings: Vec<String>,
+}
+
 #[napi(object)]
 pub struct DetailedError {
     pub error_type: String,
@@ -737,13 +1691,38 @@ pub struct StreamedRelease {
 
 #[napi]
 pub struct ReleaseStream {
-    position: i32,
+    iterator: WorkingStreamIterator<Cursor<Vec<u8>>>,
+    releases_parsed: u32,
 }
 
 impl ReleaseStream {
     // Regular impl block for internal methods
-    fn new() -> Self {
-        ReleaseStream { position: 0 }
+    fn new(xml: String, version: ddex_core::models::versions::ERNVersion) -> Self {
+        ReleaseStream {
+            iterator: WorkingStreamIterator::new(Cursor::new(xml.into_bytes()), version),
+            releases_parsed: 0,
+        }
+    }
+
+    fn next_release(&mut self) -> Result<Option<StreamedRelease>> {
+        for element in self.iterator.by_ref() {
+            if let WorkingStreamingElement::Release {
+                reference,
+                title,
+                release_type,
+                resource_references,
+            } = element.map_err(parse_error_to_napi)?
+            {
+                self.releases_parsed += 1;
+                return Ok(Some(StreamedRelease {
+                    release_reference: reference,
+                    title,
+                    release_type,
+                    resource_count: resource_references.len() as u32,
+                }));
+            }
+        }
+        Ok(None)
     }
 }
 
@@ -752,33 +1731,482 @@ impl ReleaseStream {
     // Fixed: using unsafe for &mut self in async
     #[napi]
     pub async unsafe fn next(&mut self) -> Result<Option<StreamedRelease>> {
-        // Return a few test releases
-        if self.position < 3 {
-            self.position += 1;
-            Ok(Some(StreamedRelease {
-                release_reference: format!("R{:03}", self.position),
-                title: format!("Test Release {}", self.position),
-                release_type: Some("Album".to_string()),
-                resource_count: 10,
-            }))
-        } else {
-            Ok(None)
-        }
+        self.next_release()
     }
 
     #[napi]
     pub async fn progress(&self) -> Result<ProgressInfo> {
+        let stats = self.iterator.stats();
         Ok(ProgressInfo {
-            bytes_processed: (self.position * 1000) as f64,
-            releases_parsed: self.position as f64,
-            elapsed_ms: 100.0,
+            bytes_processed: stats.bytes_processed as f64,
+            releases_parsed: self.releases_parsed as f64,
+            elapsed_ms: stats.elapsed_time.as_secs_f64() * 1000.0,
         })
     }
 }
 
+#[napi(object)]
+pub struct StreamedElement {
+    #[napi(ts_type = "'release' | 'resource' | 'deal'")]
+    pub kind: String,
+    pub release: Option<JsRelease>,
+    pub resource: Option<JsResource>,
+    pub deal: Option<JsDeal>,
+}
+
+impl From<TypedStreamingElement> for StreamedElement {
+    fn from(element: TypedStreamingElement) -> Self {
+        let mut streamed = StreamedElement {
+            kind: String::new(),
+            release: None,
+            resource: None,
+            deal: None,
+        };
+        match element {
+            TypedStreamingElement::Release(release) => {
+                streamed.kind = "release".to_string();
+                streamed.release = Some(convert_release(*release));
+            }
+            TypedStreamingElement::Resource(resource) => {
+                streamed.kind = "resource".to_string();
+                streamed.resource = Some(convert_resource(*resource));
+            }
+            TypedStreamingElement::Deal(deal) => {
+                streamed.kind = "deal".to_string();
+                streamed.deal = Some(convert_deal(*deal));
+            }
+        }
+        streamed
+    }
+}
+
+#[napi]
+pub struct TypedElementStream {
+    inner: TypedStreamIterator<Cursor<Vec<u8>>>,
+}
+
+#[napi]
+impl TypedElementStream {
+    /// Next element, or null once the message is exhausted
+    #[napi(js_name = "next")]
+    pub fn next_element(&mut self) -> Result<Option<StreamedElement>> {
+        self.inner
+            .next()
+            .transpose()
+            .map(|element| element.map(StreamedElement::from))
+            .map_err(parse_error_to_napi)
+    }
+}
+
+#[napi(object)]
+pub struct StreamedDealRelease {
+    pub release_reference: String,
+    pub title: String,
+    pub release_type: String,
+    pub upc: Option<String>,
+    pub grid: Option<String>,
+}
+
+impl From<DealRelease> for StreamedDealRelease {
+    fn from(release: DealRelease) -> Self {
+        StreamedDealRelease {
+            release_reference: release.release_reference,
+            title: release.title,
+            release_type: release.release_type,
+            upc: release.identifiers.upc,
+            grid: release.identifiers.grid,
+        }
+    }
+}
+
+#[napi(object)]
+pub struct StreamedDeal {
+    pub deal: JsDeal,
+    pub releases: Vec<StreamedDealRelease>,
+    pub unresolved_references: Vec<String>,
+}
+
+impl From<CorrelatedDeal> for StreamedDeal {
+    fn from(correlated: CorrelatedDeal) -> Self {
+        StreamedDeal {
+            deal: convert_deal(correlated.deal),
+            releases: correlated.releases.into_iter().map(StreamedDealRelease::from).collect(),
+            unresolved_references: correlated.unresolved_references,
+        }
+    }
+}
+
+#[napi]
+pub struct DealStream {
+    inner: CorrelatedDealIterator<Cursor<Vec<u8>>>,
+}
+
+#[napi]
+impl DealStream {
+    /// Next deal, or null once the message is exhausted
+    #[napi(js_name = "next")]
+    pub fn next_deal(&mut self) -> Result<Option<StreamedDeal>> {
+        self.inner
+            .next()
+            .transpose()
+            .map(|deal| deal.map(StreamedDeal::from))
+            .map_err(parse_error_to_napi)
+    }
+}
+
+#[napi]
+pub struct LazyMessage {
+    inner: RustLazyMessage,
+}
+
+#[napi]
+impl LazyMessage {
+    #[napi]
+    pub fn release_count(&self) -> u32 {
+        self.inner.release_count() as u32
+    }
+
+    #[napi]
+    pub fn resource_count(&self) -> u32 {
+        self.inner.resource_count() as u32
+    }
+
+    /// `ReleaseReference`s in document order
+    #[napi]
+    pub fn release_references(&self) -> Vec<String> {
+        self.inner.release_references().map(str::to_string).collect()
+    }
+
+    /// Parse the release at `index`, in document order
+    #[napi]
+    pub fn release(&self, index: u32) -> Result<JsRelease> {
+        self.inner
+            .release(index as usize)
+            .map(convert_release)
+            .map_err(parse_error_to_napi)
+    }
+
+    /// Parse the release with the given `ReleaseReference`
+    #[napi]
+    pub fn release_by_reference(&self, release_reference: String) -> Result<JsRelease> {
+        self.inner
+            .release_by_reference(&release_reference)
+            .map(convert_release)
+            .map_err(parse_error_to_napi)
+    }
+}
+
 #[napi(object)]
 pub struct ProgressInfo {
     pub bytes_processed: f64,
     pub releases_parsed: f64,
     pub elapsed_ms: f64,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WITH_PARTY: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/43" MessageSchemaVersionId="ern/43">
+    <MessageHeader>
+        <MessageId>MSG_GRAPH_001</MessageId>
+        <MessageSender>
+            <PartyId>PADPIDA_SENDER</PartyId>
+            <PartyName><FullName>Test Label</FullName></PartyName>
+        </MessageSender>
+        <MessageRecipient>
+            <PartyId>PADPIDA_RECIPIENT</PartyId>
+            <PartyName><FullName>Test DSP</FullName></PartyName>
+        </MessageRecipient>
+        <MessageCreatedDateTime>2024-01-01T00:00:00Z</MessageCreatedDateTime>
+    </MessageHeader>
+    <PartyList>
+        <Party>
+            <PartyReference>PArtist</PartyReference>
+            <PartyName><FullName>The Example Band</FullName></PartyName>
+        </Party>
+    </PartyList>
+    <ResourceList>
+        <SoundRecording>
+            <ResourceReference>A1</ResourceReference>
+            <SoundRecordingId><ISRC>USRC17607839</ISRC></SoundRecordingId>
+            <Title><TitleText>Track One</TitleText></Title>
+        </SoundRecording>
+    </ResourceList>
+    <ReleaseList>
+        <Release>
+            <ReleaseReference>R1</ReleaseReference>
+            <ReleaseTitle><TitleText>Example Album</TitleText></ReleaseTitle>
+            <ReleaseType>Album</ReleaseType>
+            <ReleaseResourceReferenceList>
+                <ReleaseResourceReference>A1</ReleaseResourceReference>
+            </ReleaseResourceReferenceList>
+        </Release>
+    </ReleaseList>
+</ern:NewReleaseMessage>"#;
+
+    fn parse_with(options: &ParseOptions) -> ParsedMessage {
+        let parsed = RustDDEXParser::new()
+            .parse_with_options(
+                string_to_cursor(WITH_PARTY.to_string()),
+                convert_parse_options(Some(options)).unwrap(),
+            )
+            .unwrap();
+        convert_parsed_message(parsed, Some(options))
+    }
+
+    #[test]
+    fn test_nothing_parsed_error_names_release_list_state() {
+        let empty = WITH_PARTY.replace(
+            &WITH_PARTY[WITH_PARTY.find("    <ResourceList>").unwrap()
+                ..WITH_PARTY.find("</ern:NewReleaseMessage>").unwrap()],
+            "    <ReleaseList/>\n",
+        );
+
+        let error = DdexParser::new(None).parse_sync(empty, None).err().unwrap();
+        assert!(error.reason.starts_with("EMPTY_RELEASE_LIST: "), "{}", error.reason);
+    }
+
+    #[test]
+    fn test_graph_only_attached_when_requested() {
+        let message = parse_with(&ParseOptions::default());
+        assert!(message.graph.is_none());
+
+        let message = parse_with(&ParseOptions {
+            include_graph: Some(true),
+            ..Default::default()
+        });
+        let graph = message.graph.expect("graph should be attached");
+        let parties = graph["parties"].as_array().unwrap();
+        assert_eq!(parties.len(), 1);
+        assert!(parties[0].to_string().contains("The Example Band"));
+    }
+
+    #[test]
+    fn test_statistics_come_from_the_document() {
+        let message = parse_with(&ParseOptions {
+            collect_statistics: Some(true),
+            ..Default::default()
+        });
+        let statistics = message.statistics.expect("statistics should be collected");
+        assert_eq!(statistics.file_size_bytes as usize, WITH_PARTY.len());
+        assert_eq!(
+            statistics.element_count as usize,
+            WITH_PARTY.matches('<').count() - WITH_PARTY.matches("</").count() - 1
+        );
+        assert_eq!(statistics.namespace_count, 1);
+    }
+
+    #[test]
+    fn test_parse_all_returns_each_concatenated_message() {
+        let second = WITH_PARTY
+            .replace("<MessageId>", "<MessageId>SECOND_")
+            .replace("Example Album", "Second Album");
+        let xml = format!("{}\n{}", WITH_PARTY, second);
+
+        let messages = DdexParser::new(None).parse_all(xml).unwrap();
+        assert_eq!(messages.len(), 2);
+        assert!(messages[1].message_id.starts_with("SECOND_"));
+        assert_ne!(messages[0].message_id, messages[1].message_id);
+        assert_eq!(messages[1].releases[0].title, "Second Album");
+    }
+
+    #[test]
+    fn test_audit_trail_events_are_exposed_in_order() {
+        let xml = WITH_PARTY.replace(
+            "</MessageCreatedDateTime>",
+            "</MessageCreatedDateTime>
+        <MessageAuditTrail>
+            <MessageAuditTrailEvent>
+                <MessageAuditTrailEventType>Created</MessageAuditTrailEventType>
+                <MessagingPartyDescriptor><PartyId>PADPIDA_LABEL</PartyId></MessagingPartyDescriptor>
+                <DateTime>2023-12-31T10:00:00Z</DateTime>
+            </MessageAuditTrailEvent>
+            <MessageAuditTrailEvent>
+                <MessageAuditTrailEventType>Delivered</MessageAuditTrailEventType>
+                <MessagingPartyDescriptor><PartyId>PADPIDA_DISTRIBUTOR</PartyId></MessagingPartyDescriptor>
+                <DateTime>2024-01-01T00:00:00Z</DateTime>
+            </MessageAuditTrailEvent>
+        </MessageAuditTrail>",
+        );
+
+        let message = DdexParser::new(None).parse_sync(xml, None).unwrap();
+        let events: Vec<_> = message
+            .audit_trail
+            .iter()
+            .map(|event| (event.event_type.as_str(), event.date_time.as_str(), event.party.as_deref()))
+            .collect();
+        assert_eq!(
+            events,
+            [
+                ("Created", "2023-12-31T10:00:00+00:00", Some("PADPIDA_LABEL")),
+                ("Delivered", "2024-01-01T00:00:00+00:00", Some("PADPIDA_DISTRIBUTOR")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extract_parties_reports_roles_and_identifiers() {
+        let xml = WITH_PARTY
+            .replace(
+                "<PartyReference>PArtist</PartyReference>",
+                "<PartyReference>PArtist</PartyReference>
+            <PartyId><ISNI>0000000123456789</ISNI></PartyId>",
+            )
+            .replace(
+                "<ReleaseType>Album</ReleaseType>",
+                "<ReleaseType>Album</ReleaseType>
+            <DisplayArtist><ArtistPartyReference>PArtist</ArtistPartyReference></DisplayArtist>",
+            );
+
+        let parties = DdexParser::new(None).extract_parties(xml).unwrap();
+        assert_eq!(parties.len(), 1);
+        assert_eq!(parties[0].party_reference.as_deref(), Some("PArtist"));
+        assert_eq!(parties[0].names, ["The Example Band"]);
+        assert_eq!(parties[0].identifiers[0].id_type, "ISNI");
+        assert_eq!(parties[0].identifiers[0].value, "0000000123456789");
+        assert_eq!(parties[0].roles, ["Artist"]);
+    }
+
+    #[test]
+    fn test_async_parses_interleave_off_the_event_loop() {
+        let recordings: String = (2..=2000)
+            .map(|n| {
+                format!(
+                    "        <SoundRecording>
+            <ResourceReference>A{n}</ResourceReference>
+            <Title><TitleText>Track {n}</TitleText></Title>
+        </SoundRecording>
+"
+                )
+            })
+            .collect();
+        let xml = WITH_PARTY.replace("    </ResourceList>", &format!("{}    </ResourceList>", recordings));
+        let expected = DdexParser::new(None).parse_sync(xml.clone(), None).unwrap();
+
+        // Parse as `parse_off_thread` does, noting when the parse starts and
+        // ends on the blocking pool
+        let timed_parse = |xml: String| {
+            off_thread(move || {
+                let start = std::time::Instant::now();
+                let parsed = parse_document(&mut RustDDEXParser::new(), xml, None)?;
+                Ok((parsed, start..std::time::Instant::now()))
+            })
+        };
+
+        let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+        let ((first, second), ticks) = runtime.block_on(async {
+            let done = std::sync::atomic::AtomicBool::new(false);
+            let parses = async {
+                let parsed = tokio::join!(timed_parse(xml.clone()), timed_parse(xml.clone()));
+                done.store(true, std::sync::atomic::Ordering::SeqCst);
+                parsed
+            };
+            // Turns the event loop takes while both parses are in flight; a
+            // parse run inline would finish before the first turn
+            let ticker = async {
+                let mut ticks = 0;
+                while !done.load(std::sync::atomic::Ordering::SeqCst) {
+                    ticks += 1;
+                    tokio::task::yield_now().await;
+                }
+                ticks
+            };
+            tokio::join!(parses, ticker)
+        });
+
+        let (first, first_span) = first.unwrap();
+        let (second, second_span) = second.unwrap();
+        assert!(ticks > 0, "the event loop stalled while parsing");
+        assert!(
+            first_span.start < second_span.end && second_span.start < first_span.end,
+            "the parses ran one after the other: {:?} and {:?}",
+            first_span,
+            second_span
+        );
+        for parsed in [first, second] {
+            assert_eq!(parsed.message_id, expected.message_id);
+            assert_eq!(parsed.resource_count, expected.resource_count);
+            assert_eq!(parsed.resources, expected.resources);
+        }
+    }
+
+    #[test]
+    fn test_release_stream_yields_the_releases_of_the_document() {
+        let xml = include_str!("../../../../../examples/Samples42/1 Audio.xml");
+        let expected: Vec<&str> = xml
+            .split("<ReleaseReference>")
+            .skip(1)
+            .map(|rest| &rest[..rest.find("</ReleaseReference>").unwrap()])
+            .collect();
+
+        let mut stream = DdexParser::new(None).stream(xml.to_string(), None).unwrap();
+        let mut releases = Vec::new();
+        while let Some(release) = stream.next_release().unwrap() {
+            releases.push(release);
+        }
+
+        let references: Vec<&str> = releases.iter().map(|r| r.release_reference.as_str()).collect();
+        assert_eq!(references, expected);
+        assert_eq!(releases[0].title, "Yume no Hajmari");
+        assert_eq!(releases[0].release_type.as_deref(), Some("Album"));
+        assert_eq!(releases[0].resource_count, 21);
+        assert_eq!(releases[1].release_type.as_deref(), Some("TrackRelease"));
+        assert_eq!(releases[1].resource_count, 1);
+
+        let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+        let progress = runtime.block_on(stream.progress()).unwrap();
+        assert_eq!(progress.releases_parsed as usize, expected.len());
+        assert!(progress.bytes_processed > 0.0 && progress.bytes_processed <= xml.len() as f64);
+    }
+
+    #[test]
+    fn test_deals_expose_their_territories_and_commercial_models() {
+        let xml = WITH_PARTY.replace(
+            "    </ReleaseList>",
+            "    </ReleaseList>
+    <DealList>
+        <ReleaseDeal>
+            <DealReleaseReference>R1</DealReleaseReference>
+            <Deal>
+                <DealTerms>
+                    <TerritoryCode>US</TerritoryCode>
+                    <TerritoryCode>CA</TerritoryCode>
+                    <TerritoryCode>MX</TerritoryCode>
+                    <ValidityPeriod><StartDate>2024-01-01</StartDate></ValidityPeriod>
+                    <CommercialModelType>PayAsYouGoModel</CommercialModelType>
+                    <UseType>PermanentDownload</UseType>
+                </DealTerms>
+            </Deal>
+        </ReleaseDeal>
+        <ReleaseDeal>
+            <DealReleaseReference>R1</DealReleaseReference>
+            <Deal>
+                <DealTerms>
+                    <TerritoryCode>Worldwide</TerritoryCode>
+                    <ExcludedTerritoryCode>DE</ExcludedTerritoryCode>
+                    <ValidityPeriod><StartDate>2024-01-01</StartDate></ValidityPeriod>
+                    <CommercialModelType>SubscriptionModel</CommercialModelType>
+                    <CommercialModelType>AdSupportedModel</CommercialModelType>
+                    <UseType>Stream</UseType>
+                </DealTerms>
+            </Deal>
+        </ReleaseDeal>
+    </DealList>",
+        );
+
+        let message = DdexParser::new(None).parse_sync(xml, None).unwrap();
+        assert_eq!(message.deals.len(), 2);
+        let download = &message.deals[0];
+        assert_eq!(download.territories, ["US", "CA", "MX"]);
+        assert!(download.excluded_territories.is_empty());
+        assert_eq!(download.commercial_model, "PayAsYouGoModel");
+
+        let stream = &message.deals[1];
+        assert_eq!(stream.territories, ["Worldwide"]);
+        assert_eq!(stream.excluded_territories, ["DE"]);
+        assert_eq!(stream.commercial_model, "SubscriptionModel");
+        assert_eq!(stream.commercial_models, ["SubscriptionModel", "AdSupportedModel"]);
+    }
+}
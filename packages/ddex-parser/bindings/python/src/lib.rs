@@ -750,6 +750,12 @@ fn rust_parse_options_from_dict(dict: &Bound<'_, PyDict>) -> PyResult<CoreParseO
     if let Some(v) = dict.get_item("auto_threshold")? {
         options.auto_threshold = v.extract()?;
     }
+    if let Some(v) = dict.get_item("whitespace")? {
+        let mode: String = v.extract()?;
+        options.whitespace = mode
+            .parse()
+            .map_err(PyValueError::new_err)?;
+    }
 
     // Legacy options for backward compatibility
     if let Some(v) = dict.get_item("validate_references")? {
@@ -1,7 +1,11 @@
 // packages/ddex-parser/bindings/wasm/src/lib.rs
+use ddex_parser::streaming::StreamingProgress;
 use ddex_parser::DDEXParser as CoreParser;
 use serde_wasm_bindgen::to_value;
+use std::time::Duration;
 use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
 
 #[wasm_bindgen]
 pub struct DDEXParser {
@@ -30,14 +34,104 @@ impl DDEXParser {
         to_value(&result).map_err(|e| JsValue::from_str(&e.to_string()))
     }
 
+    /// Run the fast scanner and return entity counts with its performance stats
+    #[wasm_bindgen(js_name = scanFast)]
+    pub fn scan_fast(&self, xml: &str) -> Result<JsValue, JsValue> {
+        let summary = self
+            .inner
+            .scan_fast(std::io::Cursor::new(xml.as_bytes()))
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        let stats = js_sys::Object::new();
+        for (key, value) in [
+            ("elements", summary.elements as f64),
+            ("releases", summary.releases as f64),
+            ("resources", summary.resources as f64),
+            ("deals", summary.deals as f64),
+            ("totalBytes", summary.total_bytes as f64),
+            ("throughputMbps", summary.throughput_mbps),
+            ("peakMemoryMb", summary.peak_memory_mb),
+            ("avgElementSize", summary.avg_element_size),
+        ] {
+            js_sys::Reflect::set(&stats, &JsValue::from_str(key), &JsValue::from_f64(value))?;
+        }
+
+        Ok(stats.into())
+    }
+
+    /// Read a `ReadableStream` of XML bytes, then parse it.
+    ///
+    /// The parser needs the whole document, so parsing starts once the
+    /// stream ends; progress tracks the bytes read until then. The report
+    /// for the last chunk, the only one that can reach 100%, is held back
+    /// until the parse has succeeded.
+    ///
+    /// Options:
+    /// - `totalBytes`: expected size (e.g. the upload's content length), used
+    ///   for `estimatedCompletionPercent`
+    /// - `onProgress`: called once per chunk with `{ bytesProcessed,
+    ///   totalBytes, estimatedCompletionPercent, elapsedMs }`
     #[wasm_bindgen]
     pub async fn parse_stream(
         &self,
-        _stream: web_sys::ReadableStream,
-        _options: JsValue,
+        stream: web_sys::ReadableStream,
+        options: JsValue,
     ) -> Result<JsValue, JsValue> {
-        // Implement Web Streams API support
-        todo!("Streaming implementation")
+        let total_bytes = option(&options, "totalBytes")?
+            .as_f64()
+            .filter(|n| *n > 0.0)
+            .map(|n| n as u64);
+        let on_progress = option(&options, "onProgress")?
+            .dyn_into::<js_sys::Function>()
+            .ok();
+
+        let reader: web_sys::ReadableStreamDefaultReader = stream.get_reader().unchecked_into();
+        let started = js_sys::Date::now();
+        // Instant is unavailable in the browser, so time with Date
+        let progress = |bytes: &Vec<u8>| StreamingProgress {
+            bytes_processed: bytes.len() as u64,
+            elements_parsed: 0,
+            releases_parsed: 0,
+            resources_parsed: 0,
+            parties_parsed: 0,
+            deals_parsed: 0,
+            elapsed: Duration::from_secs_f64((js_sys::Date::now() - started) / 1000.0),
+            estimated_total_bytes: total_bytes,
+            current_depth: 0,
+            memory_usage: bytes.capacity(),
+        };
+        let report = |progress: &StreamingProgress| match &on_progress {
+            Some(callback) => callback
+                .call1(&JsValue::NULL, &progress_to_js(progress)?)
+                .map(|_| ()),
+            None => Ok(()),
+        };
+
+        let mut bytes = Vec::new();
+        let mut pending = None;
+        loop {
+            let chunk = JsFuture::from(reader.read()).await?;
+            if js_sys::Reflect::get(&chunk, &JsValue::from_str("done"))?.is_truthy() {
+                break;
+            }
+            let value = js_sys::Reflect::get(&chunk, &JsValue::from_str("value"))?;
+            bytes.extend(js_sys::Uint8Array::new(&value).to_vec());
+
+            if let Some(previous) = pending.replace(progress(&bytes)) {
+                report(&previous)?;
+            }
+        }
+
+        let result = self
+            .inner
+            .clone()
+            .parse(std::io::Cursor::new(&bytes))
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        if pending.is_some() {
+            report(&progress(&bytes))?;
+        }
+
+        to_value(&result).map_err(|e| JsValue::from_str(&e.to_string()))
     }
 
     #[wasm_bindgen]
@@ -45,3 +139,38 @@ impl DDEXParser {
         env!("CARGO_PKG_VERSION").to_string()
     }
 }
+
+/// Read `key` from an options object; undefined when options are absent
+fn option(options: &JsValue, key: &str) -> Result<JsValue, JsValue> {
+    if options.is_object() {
+        js_sys::Reflect::get(options, &JsValue::from_str(key))
+    } else {
+        Ok(JsValue::UNDEFINED)
+    }
+}
+
+fn progress_to_js(progress: &StreamingProgress) -> Result<JsValue, JsValue> {
+    let object = js_sys::Object::new();
+    let optional = |value: Option<f64>| value.map_or(JsValue::UNDEFINED, JsValue::from_f64);
+    for (key, value) in [
+        (
+            "bytesProcessed",
+            JsValue::from_f64(progress.bytes_processed as f64),
+        ),
+        (
+            "totalBytes",
+            optional(progress.estimated_total_bytes.map(|n| n as f64)),
+        ),
+        (
+            "estimatedCompletionPercent",
+            optional(progress.estimated_completion_percent()),
+        ),
+        (
+            "elapsedMs",
+            JsValue::from_f64(progress.elapsed.as_secs_f64() * 1000.0),
+        ),
+    ] {
+        js_sys::Reflect::set(&object, &JsValue::from_str(key), &value)?;
+    }
+    Ok(object.into())
+}
@@ -0,0 +1,147 @@
+// packages/ddex-parser/bindings/wasm/tests/web.rs
+//! Run with `wasm-pack test --node`
+#![cfg(target_arch = "wasm32")]
+
+use ddex_parser_wasm::DDEXParser;
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen(inline_js = "
+export function stream_of(bytes, chunk_size) {
+    return new ReadableStream({
+        start(controller) {
+            for (let i = 0; i < bytes.length; i += chunk_size) {
+                controller.enqueue(bytes.slice(i, i + chunk_size));
+            }
+            controller.close();
+        }
+    });
+}
+")]
+extern "C" {
+    fn stream_of(bytes: &[u8], chunk_size: usize) -> web_sys::ReadableStream;
+}
+
+const XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/43" MessageSchemaVersionId="ern/43">
+    <MessageHeader>
+        <MessageId>MSG_STREAM_001</MessageId>
+        <MessageSender>
+            <PartyId>PADPIDA_SENDER</PartyId>
+            <PartyName><FullName>Test Label</FullName></PartyName>
+        </MessageSender>
+        <MessageRecipient>
+            <PartyId>PADPIDA_RECIPIENT</PartyId>
+            <PartyName><FullName>Test DSP</FullName></PartyName>
+        </MessageRecipient>
+        <MessageCreatedDateTime>2024-01-01T00:00:00Z</MessageCreatedDateTime>
+    </MessageHeader>
+    <ResourceList>
+        <SoundRecording>
+            <ResourceReference>A1</ResourceReference>
+            <SoundRecordingId><ISRC>USRC17607839</ISRC></SoundRecordingId>
+            <Title><TitleText>Track One</TitleText></Title>
+            <Duration>PT3M30S</Duration>
+        </SoundRecording>
+    </ResourceList>
+    <ReleaseList>
+        <Release>
+            <ReleaseReference>R1</ReleaseReference>
+            <ReleaseId><ICPN>123456789012</ICPN></ReleaseId>
+            <ReleaseTitle><TitleText>Streamed Album</TitleText></ReleaseTitle>
+            <ReleaseType>Album</ReleaseType>
+        </Release>
+    </ReleaseList>
+</ern:NewReleaseMessage>"#;
+
+fn get(object: &JsValue, key: &str) -> JsValue {
+    js_sys::Reflect::get(object, &JsValue::from_str(key)).unwrap()
+}
+
+#[wasm_bindgen_test]
+async fn test_parse_stream_reports_percent_of_total_bytes() {
+    let percents = Rc::new(RefCell::new(Vec::new()));
+    let seen = percents.clone();
+    let on_progress = Closure::<dyn FnMut(JsValue)>::new(move |progress: JsValue| {
+        seen.borrow_mut().push(
+            get(&progress, "estimatedCompletionPercent")
+                .as_f64()
+                .unwrap(),
+        );
+    });
+
+    let options = js_sys::Object::new();
+    js_sys::Reflect::set(&options, &"totalBytes".into(), &(XML.len() as f64).into()).unwrap();
+    js_sys::Reflect::set(&options, &"onProgress".into(), on_progress.as_ref()).unwrap();
+
+    let parser = DDEXParser::new().unwrap();
+    let result = parser
+        .parse_stream(stream_of(XML.as_bytes(), 256), options.into())
+        .await
+        .unwrap();
+
+    let percents = percents.borrow();
+    assert_eq!(percents.len(), XML.len().div_ceil(256));
+    assert!(percents.windows(2).all(|w| w[0] < w[1]));
+    assert!(percents[0] > 0.0);
+    assert_eq!(*percents.last().unwrap(), 100.0);
+    assert!(!get(&result, "flat").is_undefined());
+}
+
+#[wasm_bindgen_test]
+async fn test_parse_stream_without_total_bytes() {
+    let called = Rc::new(RefCell::new(false));
+    let flag = called.clone();
+    let on_progress = Closure::<dyn FnMut(JsValue)>::new(move |progress: JsValue| {
+        assert!(get(&progress, "estimatedCompletionPercent").is_undefined());
+        assert!(get(&progress, "bytesProcessed").as_f64().unwrap() > 0.0);
+        *flag.borrow_mut() = true;
+    });
+
+    let options = js_sys::Object::new();
+    js_sys::Reflect::set(&options, &"onProgress".into(), on_progress.as_ref()).unwrap();
+
+    DDEXParser::new()
+        .unwrap()
+        .parse_stream(stream_of(XML.as_bytes(), 1024), options.into())
+        .await
+        .unwrap();
+
+    assert!(*called.borrow());
+}
+
+#[wasm_bindgen_test]
+async fn test_parse_stream_holds_back_completion_until_parsed() {
+    let percents = Rc::new(RefCell::new(Vec::new()));
+    let seen = percents.clone();
+    let on_progress = Closure::<dyn FnMut(JsValue)>::new(move |progress: JsValue| {
+        seen.borrow_mut().push(
+            get(&progress, "estimatedCompletionPercent")
+                .as_f64()
+                .unwrap(),
+        );
+    });
+
+    // Cut off mid-document, so the parse fails once the stream ends
+    let truncated = &XML.as_bytes()[..XML.len() / 2];
+    let options = js_sys::Object::new();
+    js_sys::Reflect::set(
+        &options,
+        &"totalBytes".into(),
+        &(truncated.len() as f64).into(),
+    )
+    .unwrap();
+    js_sys::Reflect::set(&options, &"onProgress".into(), on_progress.as_ref()).unwrap();
+
+    let result = DDEXParser::new()
+        .unwrap()
+        .parse_stream(stream_of(truncated, 128), options.into())
+        .await;
+
+    assert!(result.is_err());
+    let percents = percents.borrow();
+    assert_eq!(percents.len(), truncated.len().div_ceil(128) - 1);
+    assert!(percents.iter().all(|percent| *percent < 100.0));
+}
@@ -20,6 +20,11 @@ pub enum ParseError {
     UnexpectedClosingTag { tag: String, position: usize },
     InvalidAttribute { message: String, position: usize },
     UnclosedTags { tags: Vec<String>, position: usize },
+    EncodingMismatch { declared: String, detected: String },
+    EncodingCorruption { position: usize },
+    SchemaVersionMismatch { namespace_version: String, schema_version_id: String },
+    ReleaseNotFound { reference: String },
+    UnresolvedPartyReferences(Vec<crate::parser::party_references::UnresolvedPartyReference>),
 }
 
 #[derive(Debug, Clone)]
@@ -56,6 +61,17 @@ impl fmt::Display for ParseError {
             ParseError::UnexpectedClosingTag { tag, position } => write!(f, "Unexpected closing tag '{}' at position {}", tag, position),
             ParseError::InvalidAttribute { message, position } => write!(f, "Invalid attribute at position {}: {}", position, message),
             ParseError::UnclosedTags { tags, position } => write!(f, "Unclosed tags at position {}: {:?}", position, tags),
+            ParseError::EncodingMismatch { declared, detected } => write!(f, "Encoding mismatch: declared '{}' but content looks like '{}'", declared, detected),
+            ParseError::EncodingCorruption { position } => write!(f, "Encoding corruption at byte {}: stray byte order mark or null byte", position),
+            ParseError::SchemaVersionMismatch { namespace_version, schema_version_id } => write!(f, "Schema version mismatch: namespace is ERN {} but MessageSchemaVersionId is '{}'", namespace_version, schema_version_id),
+            ParseError::ReleaseNotFound { reference } => write!(f, "No release with ReleaseReference '{}'", reference),
+            ParseError::UnresolvedPartyReferences(unresolved) => {
+                let references: Vec<String> = unresolved
+                    .iter()
+                    .map(|u| format!("{} '{}' at {}", u.element, u.reference, u.location))
+                    .collect();
+                write!(f, "Unresolved party references: {}", references.join(", "))
+            }
         }
     }
 }
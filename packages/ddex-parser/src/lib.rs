@@ -46,10 +46,12 @@ impl DDEXParser {
     /// Parse DDEX XML from a reader
     pub fn parse<R: std::io::BufRead + std::io::Seek>(
         &mut self,
-        reader: R,
+        mut reader: R,
     ) -> Result<ddex_core::models::flat::ParsedERNMessage, error::ParseError> {
         // Use fast streaming if enabled
         if self.config.enable_fast_streaming {
+            parser::security::check_prolog(&mut reader, &self.config)?;
+            reader.seek(std::io::SeekFrom::Start(0))?;
             return self.parse_fast_streaming(reader);
         }
 
@@ -60,20 +62,89 @@ impl DDEXParser {
     /// Parse with options
     pub fn parse_with_options<R: std::io::BufRead + std::io::Seek>(
         &mut self,
-        reader: R,
+        mut reader: R,
         options: parser::ParseOptions,
     ) -> Result<ddex_core::models::flat::ParsedERNMessage, error::ParseError> {
         // Use fast streaming if enabled (we'll skip the options comparison for now)
         if self.config.enable_fast_streaming {
+            parser::security::check_prolog(&mut reader, &self.config)?;
+            reader.seek(std::io::SeekFrom::Start(0))?;
             return self.parse_fast_streaming(reader);
         }
 
-        // Apply security config - check if external entities are disabled and we should block them
-        // Note: This security check will be enhanced with XML bomb protection
-
         parser::parse(reader, options, &self.config)
     }
 
+    /// Parse only the release whose `ReleaseReference` is `release_reference`,
+    /// along with the resources it refers to. Scanning stops at that release
+    /// and the rest of the catalog is never built into models, so this is far
+    /// cheaper than a full parse for one release of a large document.
+    pub fn parse_release<R: std::io::BufRead>(
+        &mut self,
+        reader: R,
+        release_reference: &str,
+    ) -> Result<ddex_core::models::flat::ParsedRelease, error::ParseError> {
+        let buffer = self.read_within_limit(reader)?;
+        let document = parser::raw::single_release_document(&buffer, release_reference)?;
+        let message = parser::parse(std::io::Cursor::new(document), Default::default(), &self.config)?;
+
+        message
+            .flat
+            .releases
+            .into_iter()
+            .find(|release| release.release_id == release_reference)
+            .ok_or_else(|| error::ParseError::ReleaseNotFound {
+                reference: release_reference.to_string(),
+            })
+    }
+
+    /// Parse every message in an input holding several, whether concatenated
+    /// or wrapped in a manifest or batch element. Each is parsed on its own,
+    /// with its own header.
+    pub fn parse_all<R: std::io::BufRead>(
+        &mut self,
+        reader: R,
+    ) -> Result<Vec<ddex_core::models::flat::ParsedERNMessage>, error::ParseError> {
+        let buffer = self.read_within_limit(reader)?;
+        let documents = parser::messages::message_documents(&buffer)?;
+        if documents.is_empty() {
+            return Err(error::ParseError::MissingField("NewReleaseMessage".to_string()));
+        }
+
+        documents
+            .into_iter()
+            .map(|document| {
+                parser::parse(std::io::Cursor::new(document), Default::default(), &self.config)
+            })
+            .collect()
+    }
+
+    /// Index releases and resources by their byte spans in one pass, and
+    /// parse each release only when it is asked for. Peak memory stays close
+    /// to the size of the input however many releases are accessed.
+    pub fn parse_lazy<R: std::io::BufRead>(
+        &mut self,
+        reader: R,
+    ) -> Result<parser::lazy::LazyMessage, error::ParseError> {
+        let buffer = self.read_within_limit(reader)?;
+        parser::lazy::LazyMessage::new(buffer, self.config.clone())
+    }
+
+    /// Parse whatever can be recovered from a document that fails to parse.
+    /// Releases that fail on their own are dropped and reported in `errors`
+    /// alongside the rest of the message; problems outside the releases
+    /// leave `message` empty. Never fails outright.
+    pub fn parse_partial<R: std::io::BufRead>(
+        &mut self,
+        reader: R,
+        options: parser::ParseOptions,
+    ) -> parser::partial::PartialParse {
+        match self.read_within_limit(reader) {
+            Ok(buffer) => parser::partial::parse_partial(&buffer, options, &self.config),
+            Err(error) => parser::partial::PartialParse::failed(error),
+        }
+    }
+
     /// Stream parse for large files using new streaming implementation
     pub fn stream<R: std::io::BufRead>(&self, reader: R) -> WorkingStreamIterator<R> {
         // For streaming, we can't detect version from reader without consuming it
@@ -200,17 +271,22 @@ impl DDEXParser {
             sender: Organization {
                 name: "Fast Streaming Parser".to_string(),
                 id: "FAST_PARSER".to_string(),
+                names: Vec::new(),
+                trading_name: None,
                 extensions: None,
             },
             recipient: Organization {
                 name: "Streaming Service Recipient".to_string(),
                 id: "STREAMING_SERVICE_RECIPIENT".to_string(),
+                names: Vec::new(),
+                trading_name: None,
                 extensions: None,
             },
             releases: Vec::new(), // TODO: Convert FastStreamingElements to ParsedReleases
             resources: IndexMap::new(), // TODO: Convert FastStreamingElements to ParsedResources
             deals: Vec::new(),
             parties: IndexMap::new(),
+            purged_releases: Vec::new(),
             version: "4.3".to_string(),
             profile: None,
             stats: MessageStats {
@@ -219,6 +295,8 @@ impl DDEXParser {
                 deal_count: 0,
                 total_duration: 0,
             },
+            identifier_index: Default::default(),
+            audit_trail: Vec::new(),
             extensions: None,
         };
 
@@ -270,6 +348,7 @@ impl DDEXParser {
             resources: Vec::new(),
             releases: Vec::new(),
             deals: Vec::new(),
+            purged_releases: Vec::new(),
             version: ERNVersion::V4_3,
             profile: None,
             message_audit_trail: None,
@@ -283,11 +362,123 @@ impl DDEXParser {
             graph: graph_message,
             flat: flat_message,
             extensions: None,
+            warnings: Vec::new(),
+            namespaces: IndexMap::new(),
+            namespace_aliases: IndexMap::new(),
+            truncated: false,
+            header_extensions: IndexMap::new(),
+            document_stats: Default::default(),
         };
 
         Ok(message)
     }
 
+    /// Count releases, resources, deals and parties using the fast byte-level
+    /// scanner, without building any models
+    pub fn count_entities<R: std::io::BufRead>(
+        &self,
+        reader: R,
+    ) -> Result<streaming::EntityCounts, error::ParseError> {
+        let buffer = self.read_within_limit(reader)?;
+        Ok(streaming::count_entities(&buffer))
+    }
+
+    /// Run the fast scanner and report entity counts alongside its
+    /// throughput, memory and element-size statistics
+    pub fn scan_fast<R: std::io::BufRead>(
+        &self,
+        reader: R,
+    ) -> Result<streaming::FastScanSummary, error::ParseError> {
+        let buffer = self.read_within_limit(reader)?;
+
+        let streaming_config = StreamingConfig {
+            security: self.config.clone(),
+            ..StreamingConfig::default()
+        };
+        let mut fast_parser = streaming::FastStreamingParser::new(streaming_config);
+        let iterator = fast_parser.parse_streaming(&mut std::io::Cursor::new(&buffer), None)?;
+
+        Ok(streaming::FastScanSummary::new(
+            streaming::count_entities(&buffer),
+            iterator.stats(),
+        ))
+    }
+
+    /// Predict the release count, memory and time a full parse of a
+    /// document will take from a quick byte-level scan; see
+    /// [`streaming::ParseCostEstimate`] for the tolerances
+    pub fn estimate_cost<R: std::io::BufRead>(
+        &self,
+        reader: R,
+    ) -> Result<streaming::ParseCostEstimate, error::ParseError> {
+        let buffer = self.read_within_limit(reader)?;
+        Ok(streaming::estimate_parse_cost(&buffer))
+    }
+
+    /// Stream the document with each streaming implementation and compare
+    /// their throughput, peak memory and the releases and resources they find
+    pub fn compare_streaming_strategies<R: std::io::BufRead>(
+        &self,
+        reader: R,
+    ) -> Result<streaming::StrategyComparison, error::ParseError> {
+        let buffer = self.read_within_limit(reader)?;
+        let version = parser::detector::VersionDetector::detect(buffer.as_slice())?;
+
+        let streaming_config = StreamingConfig {
+            security: self.config.clone(),
+            ..StreamingConfig::default()
+        };
+        streaming::compare_strategies(&buffer, version, streaming_config)
+    }
+
+    /// Every party in the message, once each, with its identifiers, names
+    /// and the roles it plays (artist, contributor, label, rights
+    /// controller), from a targeted scan that builds no releases or deals
+    pub fn extract_parties<R: std::io::BufRead>(
+        &self,
+        reader: R,
+    ) -> Result<Vec<ddex_core::models::graph::Party>, error::ParseError> {
+        let buffer = self.read_within_limit(reader)?;
+        parser::parties::extract_parties(&buffer)
+    }
+
+    /// Check the `HashSum` of every delivered file the message declares one
+    /// for against the file itself. `resolve` maps a file's location as the
+    /// message gives it (its URI, or path and name) to a local path.
+    pub fn verify_resource_hashes<R, F>(
+        &self,
+        reader: R,
+        resolve: F,
+    ) -> Result<Vec<parser::resource_hashes::ResourceHashCheck>, error::ParseError>
+    where
+        R: std::io::BufRead,
+        F: FnMut(&str) -> Option<std::path::PathBuf>,
+    {
+        let buffer = self.read_within_limit(reader)?;
+        parser::resource_hashes::verify_resource_hashes(&buffer, &self.config, resolve)
+    }
+
+    fn read_within_limit<R: std::io::BufRead>(
+        &self,
+        mut reader: R,
+    ) -> Result<Vec<u8>, error::ParseError> {
+        let mut buffer = Vec::new();
+        reader.read_to_end(&mut buffer)?;
+
+        if buffer.len() > self.config.max_file_size {
+            return Err(error::ParseError::SecurityViolation {
+                message: format!(
+                    "Input size {} bytes exceeds maximum file size of {} bytes",
+                    buffer.len(),
+                    self.config.max_file_size
+                ),
+            });
+        }
+        parser::security::check_prolog(buffer.as_slice(), &self.config)?;
+
+        Ok(buffer)
+    }
+
     /// Detect DDEX version from XML
     pub fn detect_version<R: std::io::BufRead>(
         &self,
@@ -296,6 +487,33 @@ impl DDEXParser {
         parser::detector::VersionDetector::detect(reader)
     }
 
+    /// Versions, message types, canonicalization algorithms and Cargo
+    /// features this build of the parser supports
+    pub fn capabilities(&self) -> Capabilities {
+        let features = [
+            ("async", cfg!(feature = "async")),
+            ("simd", cfg!(feature = "simd")),
+            ("zero-copy", cfg!(feature = "zero-copy")),
+            ("wasm", cfg!(feature = "wasm")),
+        ];
+
+        Capabilities {
+            versions: vec![ERNVersion::V3_8_2, ERNVersion::V4_2, ERNVersion::V4_3],
+            message_types: vec![
+                "NewReleaseMessage".to_string(),
+                "UpdateReleaseMessage".to_string(),
+                "PurgeReleaseMessage".to_string(),
+            ],
+            // The parser reads documents as given and canonicalizes nothing
+            canonicalization: Vec::new(),
+            features: features
+                .into_iter()
+                .filter(|(_, enabled)| *enabled)
+                .map(|(name, _)| name.to_string())
+                .collect(),
+        }
+    }
+
     /// Perform sanity check on DDEX XML
     pub fn sanity_check<R: std::io::BufRead>(
         &self,
@@ -322,6 +540,20 @@ pub struct SanityCheckResult {
     pub warnings: Vec<String>,
 }
 
+/// What this build of the parser can read, from
+/// [`DDEXParser::capabilities`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Capabilities {
+    /// ERN versions the parser recognises
+    pub versions: Vec<ERNVersion>,
+    /// Root elements parsed into a message
+    pub message_types: Vec<String>,
+    /// Canonicalization algorithms applied to input
+    pub canonicalization: Vec<String>,
+    /// Optional Cargo features compiled in
+    pub features: Vec<String>,
+}
+
 /// Benchmark report support
 #[cfg(feature = "bench")]
 pub mod bench_report;
@@ -13,12 +13,20 @@ impl VersionDetector {
     }
 
     pub fn detect_from_bufread<R: BufRead>(reader: R) -> crate::error::Result<ERNVersion> {
+        Self::detect_with_schema_id(reader).map(|(version, _)| version)
+    }
+
+    /// Detect the version from the namespace URI, also returning the root's
+    /// `MessageSchemaVersionId` so callers can check the two agree.
+    /// The namespace is authoritative: it decides which elements actually match.
+    pub fn detect_with_schema_id<R: BufRead>(reader: R) -> crate::error::Result<(ERNVersion, Option<String>)> {
         let mut xml_reader = Reader::from_reader(reader);
         xml_reader.config_mut().trim_text(true);
 
         let mut buf = Vec::new();
         let mut found_root = false;
         let mut namespace_uris = Vec::new();
+        let mut schema_version_id = None;
 
         // Parse XML and collect namespace URIs from the root element
         loop {
@@ -36,6 +44,8 @@ impl VersionDetector {
                                 // Look for xmlns declarations
                                 if key == "xmlns" || key.starts_with("xmlns:") {
                                     namespace_uris.push(value.to_string());
+                                } else if attr.key.local_name().as_ref() == b"MessageSchemaVersionId" {
+                                    schema_version_id = Some(value.to_string());
                                 }
                             }
                             Err(e) => {
@@ -65,15 +75,26 @@ impl VersionDetector {
         for uri in &namespace_uris {
             // ERN 3.8.1 is treated as 3.8.2 (minor differences, compatible structure)
             if uri.contains("http://ddex.net/xml/ern/381") || uri.contains("http://ddex.net/xml/ern/382") {
-                return Ok(ERNVersion::V3_8_2);
+                return Ok((ERNVersion::V3_8_2, schema_version_id));
             } else if uri.contains("http://ddex.net/xml/ern/42") {
-                return Ok(ERNVersion::V4_2);
+                return Ok((ERNVersion::V4_2, schema_version_id));
             } else if uri.contains("http://ddex.net/xml/ern/43") {
-                return Ok(ERNVersion::V4_3);
+                return Ok((ERNVersion::V4_3, schema_version_id));
             }
         }
 
         // If no DDEX ERN namespace found, it's not a valid DDEX document
         Err(ParseError::XmlError("No DDEX ERN namespace found - not a valid DDEX document".to_string()))
     }
+
+    /// Map a `MessageSchemaVersionId` such as `ern/43` or `/ern/382` to a version
+    pub fn version_from_schema_id(schema_version_id: &str) -> Option<ERNVersion> {
+        let digits: String = schema_version_id.chars().filter(|c| c.is_ascii_digit()).collect();
+        match digits.as_str() {
+            "381" | "382" => Some(ERNVersion::V3_8_2),
+            "42" => Some(ERNVersion::V4_2),
+            "43" => Some(ERNVersion::V4_3),
+            _ => None,
+        }
+    }
 }
@@ -8,6 +8,7 @@ use crate::transform::{flatten::Flattener, graph::GraphBuilder};
 use ddex_core::models::flat::ParsedERNMessage;
 use ddex_core::models::graph::ERNMessage;
 use ddex_core::models::versions::ERNVersion;
+use indexmap::IndexMap;
 use std::io::{BufRead, Seek, SeekFrom};
 use std::time::Instant;
 
@@ -35,7 +36,7 @@ pub fn parse_dom<R: BufRead + Seek>(
     reader.seek(SeekFrom::Start(0))?;
 
     // Build graph model from XML with namespace context
-    let graph_builder = GraphBuilder::new(version);
+    let graph_builder = GraphBuilder::new(version).with_whitespace(options.whitespace);
     let graph = graph_builder.build_from_xml_with_context_and_security(
         reader,
         namespace_context,
@@ -64,6 +65,12 @@ pub fn parse_dom<R: BufRead + Seek>(
         graph,
         flat: flat?,
         extensions: None,
+        warnings: Vec::new(),
+        namespaces: IndexMap::new(),
+        namespace_aliases: IndexMap::new(),
+        truncated: false,
+        header_extensions: IndexMap::new(),
+        document_stats: Default::default(),
     })
 }
 
@@ -0,0 +1,186 @@
+// core/src/parser/element_hook.rs
+//! Caller-supplied rewriting of elements before they reach the model
+
+use crate::error::ParseError;
+use quick_xml::events::{BytesStart, BytesText, Event};
+use quick_xml::{Reader, Writer};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+/// One element as the parser meets it, open to rewriting
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VisitedElement {
+    /// Local name, without any namespace prefix
+    pub name: String,
+    /// Local names of the enclosing elements, outermost first
+    pub path: Vec<String>,
+    /// Attributes by qualified name, in document order
+    pub attributes: Vec<(String, String)>,
+    /// Text of an element holding only text; `None` for elements with
+    /// children and for empty elements
+    pub text: Option<String>,
+}
+
+/// Callback run on every element before it is read into the model, e.g. to
+/// map a partner's genre vocabulary onto ours. Only changes to `attributes`
+/// and `text` are kept.
+#[derive(Clone)]
+pub struct ElementHook(Arc<dyn Fn(&mut VisitedElement) + Send + Sync>);
+
+impl ElementHook {
+    /// Hook calling `hook` on every element
+    pub fn new(hook: impl Fn(&mut VisitedElement) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(hook))
+    }
+
+    /// Replace the text of every `element` found in `map` with its mapped value
+    pub fn map_text(element: &str, map: HashMap<String, String>) -> Self {
+        let element = element.to_string();
+        Self::new(move |visited| {
+            if visited.name != element {
+                return;
+            }
+            if let Some(mapped) = visited.text.as_ref().and_then(|text| map.get(text.trim())) {
+                visited.text = Some(mapped.clone());
+            }
+        })
+    }
+}
+
+impl fmt::Debug for ElementHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ElementHook(..)")
+    }
+}
+
+impl PartialEq for ElementHook {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// `source` with `hook` run on every element. A start tag is held back until
+/// it is known whether the element holds only text, so the hook sees the
+/// text and attributes together.
+pub fn apply(source: &[u8], hook: &ElementHook) -> Result<Vec<u8>, ParseError> {
+    let mut reader = Reader::from_reader(source);
+    let mut writer = Writer::new(Vec::with_capacity(source.len()));
+    // Local names of the open elements, the held-back one included
+    let mut path: Vec<String> = Vec::new();
+    // Start tag of the innermost open element while it has only text, and
+    // that text so far
+    let mut pending: Option<(BytesStart<'static>, String)> = None;
+
+    loop {
+        let event = reader
+            .read_event()
+            .map_err(|e| ParseError::XmlError(format!("XML parsing error: {}", e)))?;
+
+        match event {
+            Event::Text(ref text) if pending.is_some() => {
+                let text = text
+                    .unescape()
+                    .map_err(|e| ParseError::XmlError(format!("XML parsing error: {}", e)))?;
+                if let Some((_, pending_text)) = pending.as_mut() {
+                    pending_text.push_str(&text);
+                }
+            }
+            Event::CData(ref data) if pending.is_some() => {
+                if let Some((_, pending_text)) = pending.as_mut() {
+                    pending_text.push_str(&String::from_utf8_lossy(data));
+                }
+            }
+            Event::End(end) => {
+                if let Some((start, text)) = pending.take() {
+                    let (start, text) = visit(hook, &start, Some(text), &path[..path.len() - 1]);
+                    writer.write_event(Event::Start(start))?;
+                    if let Some(text) = text {
+                        writer.write_event(Event::Text(BytesText::new(&text)))?;
+                    }
+                }
+                path.pop();
+                writer.write_event(Event::End(end))?;
+            }
+            Event::Eof => break,
+            event => {
+                // Anything else inside a held-back element means it has children
+                if let Some((start, text)) = pending.take() {
+                    let (start, _) = visit(hook, &start, None, &path[..path.len() - 1]);
+                    writer.write_event(Event::Start(start))?;
+                    writer.write_event(Event::Text(BytesText::new(&text)))?;
+                }
+                match event {
+                    Event::Start(start) => {
+                        path.push(String::from_utf8_lossy(start.local_name().as_ref()).into_owned());
+                        pending = Some((start.into_owned(), String::new()));
+                    }
+                    Event::Empty(start) => {
+                        let (start, _) = visit(hook, &start, None, &path);
+                        writer.write_event(Event::Empty(start))?;
+                    }
+                    other => writer.write_event(other)?,
+                }
+            }
+        }
+    }
+
+    Ok(writer.into_inner())
+}
+
+/// Run `hook` on one element, returning its rewritten start tag and text
+fn visit(
+    hook: &ElementHook,
+    start: &BytesStart,
+    text: Option<String>,
+    path: &[String],
+) -> (BytesStart<'static>, Option<String>) {
+    let mut element = VisitedElement {
+        name: String::from_utf8_lossy(start.local_name().as_ref()).into_owned(),
+        path: path.to_vec(),
+        attributes: start
+            .attributes()
+            .flatten()
+            .map(|attribute| {
+                let value = attribute
+                    .unescape_value()
+                    .map(|value| value.into_owned())
+                    .unwrap_or_else(|_| String::from_utf8_lossy(&attribute.value).into_owned());
+                (String::from_utf8_lossy(attribute.key.as_ref()).into_owned(), value)
+            })
+            .collect(),
+        text,
+    };
+    (hook.0)(&mut element);
+
+    let name = String::from_utf8_lossy(start.name().as_ref()).into_owned();
+    let mut rewritten = BytesStart::new(name);
+    for (key, value) in &element.attributes {
+        rewritten.push_attribute((key.as_str(), value.as_str()));
+    }
+    (rewritten, element.text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hook_sees_text_attributes_and_path() {
+        let xml = br#"<ern:Msg xmlns:ern="urn:x"><Genre><GenreText Lang="en">Rap &amp; Trap</GenreText><SubGenre/></Genre></ern:Msg>"#;
+        let hook = ElementHook::new(|element| {
+            if element.name == "GenreText" {
+                assert_eq!(element.path, ["Msg", "Genre"]);
+                assert_eq!(element.text.as_deref(), Some("Rap & Trap"));
+                element.attributes.push(("Source".to_string(), "hook".to_string()));
+                element.text = Some("Hip-Hop".to_string());
+            }
+        });
+
+        let rewritten = String::from_utf8(apply(xml, &hook).unwrap()).unwrap();
+        assert_eq!(
+            rewritten,
+            r#"<ern:Msg xmlns:ern="urn:x"><Genre><GenreText Lang="en" Source="hook">Hip-Hop</GenreText><SubGenre/></Genre></ern:Msg>"#
+        );
+    }
+}
@@ -0,0 +1,312 @@
+// core/src/parser/encoding.rs
+//! Declared vs actual character encoding checks
+
+use std::io::{BufRead, Read};
+
+/// Disagreement between the XML declaration and the bytes in the document
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EncodingMismatch {
+    /// Encoding named in `<?xml ... encoding="..."?>` ("UTF-8" when omitted)
+    pub declared: String,
+    /// Encoding suggested by the byte order mark or content
+    pub detected: String,
+}
+
+/// What the raw bytes tell us about the encoding
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DetectedEncoding {
+    /// Only 7-bit bytes: compatible with any ASCII-based declaration
+    Ascii,
+    /// UTF-8 byte order mark, or well-formed multibyte UTF-8 sequences
+    Utf8,
+    /// UTF-16 byte order mark
+    Utf16,
+    /// High bytes that are not valid UTF-8, i.e. some single-byte encoding
+    SingleByte,
+}
+
+impl DetectedEncoding {
+    fn name(self) -> &'static str {
+        match self {
+            DetectedEncoding::Ascii => "US-ASCII",
+            DetectedEncoding::Utf8 => "UTF-8",
+            DetectedEncoding::Utf16 => "UTF-16",
+            DetectedEncoding::SingleByte => "ISO-8859-1",
+        }
+    }
+}
+
+/// What one pass over the document's bytes found
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct EncodingCheck {
+    /// The declared encoding disagrees with the content
+    pub mismatch: Option<EncodingMismatch>,
+    /// Byte offset of the first stray byte order mark or null byte, as left
+    /// where files were concatenated or badly merged
+    pub corruption: Option<usize>,
+}
+
+/// Compare the declared encoding against the BOM and content of the whole
+/// document. The reader is consumed; callers should seek back afterwards.
+pub fn check_declared_encoding<R: BufRead>(
+    reader: R,
+) -> std::io::Result<Option<EncodingMismatch>> {
+    Ok(check_encoding(reader)?.mismatch)
+}
+
+/// Check the declared encoding, as [`check_declared_encoding`] does, and
+/// look for a byte order mark or null byte past the start of the document
+/// in the same pass. UTF-16 documents are not checked for corruption, since
+/// null bytes are part of their text.
+pub fn check_encoding<R: BufRead>(reader: R) -> std::io::Result<EncodingCheck> {
+    EncodingScan::new(reader).finish()
+}
+
+/// Reader that makes the checks of [`check_encoding`] on the bytes another
+/// pass reads through it, so the document is not read again just for them
+pub struct EncodingScan<R> {
+    inner: R,
+    seen: SeenBytes,
+}
+
+impl<R: BufRead> EncodingScan<R> {
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            seen: SeenBytes::default(),
+        }
+    }
+
+    /// Read whatever the pass left unread and report on the whole document
+    pub fn finish(mut self) -> std::io::Result<EncodingCheck> {
+        std::io::copy(&mut self, &mut std::io::sink())?;
+        Ok(self.seen.finish())
+    }
+}
+
+/// Everything the checks keep of the bytes read so far
+#[derive(Default)]
+struct SeenBytes {
+    head: Vec<u8>,
+    scanner: Utf8Scanner,
+    corruption: CorruptionScanner,
+}
+
+impl SeenBytes {
+    fn feed(&mut self, chunk: &[u8]) {
+        if self.head.len() < 1024 {
+            let take = (1024 - self.head.len()).min(chunk.len());
+            self.head.extend_from_slice(&chunk[..take]);
+        }
+        self.scanner.feed(chunk);
+        self.corruption.feed(chunk);
+    }
+
+    fn finish(self) -> EncodingCheck {
+        let detected = match self.head.as_slice() {
+            [0xEF, 0xBB, 0xBF, ..] => DetectedEncoding::Utf8,
+            [0xFE, 0xFF, ..] | [0xFF, 0xFE, ..] => DetectedEncoding::Utf16,
+            _ => self.scanner.finish(),
+        };
+
+        let declared = declared_encoding(&self.head).unwrap_or_else(|| "UTF-8".to_string());
+
+        EncodingCheck {
+            mismatch: (!is_compatible(&declared, detected)).then(|| EncodingMismatch {
+                declared,
+                detected: detected.name().to_string(),
+            }),
+            corruption: self.corruption.found.filter(|_| detected != DetectedEncoding::Utf16),
+        }
+    }
+}
+
+impl<R: BufRead> Read for EncodingScan<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let available = self.fill_buf()?;
+        let len = available.len().min(buf.len());
+        buf[..len].copy_from_slice(&available[..len]);
+        self.consume(len);
+        Ok(len)
+    }
+}
+
+impl<R: BufRead> BufRead for EncodingScan<R> {
+    fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+        self.inner.fill_buf()
+    }
+
+    fn consume(&mut self, amt: usize) {
+        // The buffer is still filled, so this hands back the same bytes
+        if let Ok(chunk) = self.inner.fill_buf() {
+            self.seen.feed(&chunk[..amt.min(chunk.len())]);
+        }
+        self.inner.consume(amt);
+    }
+}
+
+/// Extract the `encoding` pseudo-attribute from the XML declaration
+fn declared_encoding(head: &[u8]) -> Option<String> {
+    let text = String::from_utf8_lossy(head);
+    let decl = text.trim_start_matches('\u{feff}');
+    let decl = &decl[..decl.find("?>")?];
+    if !decl.trim_start().starts_with("<?xml") {
+        return None;
+    }
+
+    let value = &decl[decl.find("encoding")? + "encoding".len()..];
+    let value = value.trim_start().strip_prefix('=')?.trim_start();
+    let quote = value.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+    let value = &value[1..];
+    Some(value[..value.find(quote)?].trim().to_string())
+}
+
+fn is_compatible(declared: &str, detected: DetectedEncoding) -> bool {
+    let normalized: String = declared
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .collect::<String>()
+        .to_ascii_uppercase();
+    let is_utf8 = normalized == "UTF8";
+    let is_utf16 = normalized.starts_with("UTF16") || normalized.starts_with("UCS2");
+
+    match detected {
+        DetectedEncoding::Ascii => !is_utf16,
+        DetectedEncoding::Utf8 => is_utf8,
+        DetectedEncoding::Utf16 => is_utf16,
+        DetectedEncoding::SingleByte => !is_utf8 && !is_utf16,
+    }
+}
+
+/// Incremental UTF-8 validity check across buffer boundaries
+#[derive(Default)]
+struct Utf8Scanner {
+    pending: Vec<u8>,
+    saw_multibyte: bool,
+    invalid: bool,
+}
+
+impl Utf8Scanner {
+    fn feed(&mut self, chunk: &[u8]) {
+        if self.invalid {
+            return;
+        }
+
+        let mut data = std::mem::take(&mut self.pending);
+        data.extend_from_slice(chunk);
+
+        match std::str::from_utf8(&data) {
+            Ok(_) => self.saw_multibyte |= !data.is_ascii(),
+            Err(e) => match e.error_len() {
+                Some(_) => self.invalid = true,
+                None => {
+                    // Sequence cut off at the chunk boundary; finish it next time
+                    let valid = e.valid_up_to();
+                    self.saw_multibyte |= !data[..valid].is_ascii();
+                    self.pending = data[valid..].to_vec();
+                }
+            },
+        }
+    }
+
+    fn finish(self) -> DetectedEncoding {
+        if self.invalid || !self.pending.is_empty() {
+            DetectedEncoding::SingleByte
+        } else if self.saw_multibyte {
+            DetectedEncoding::Utf8
+        } else {
+            DetectedEncoding::Ascii
+        }
+    }
+}
+
+/// Finds the first UTF-8 byte order mark after offset 0, or null byte,
+/// across buffer boundaries
+#[derive(Default)]
+struct CorruptionScanner {
+    offset: usize,
+    /// Last bytes of the previous chunk, in case a BOM straddles chunks
+    tail: Vec<u8>,
+    found: Option<usize>,
+}
+
+impl CorruptionScanner {
+    const BOM: [u8; 3] = [0xEF, 0xBB, 0xBF];
+
+    fn feed(&mut self, chunk: &[u8]) {
+        if self.found.is_some() {
+            return;
+        }
+
+        let start = self.offset - self.tail.len();
+        let mut data = std::mem::take(&mut self.tail);
+        data.extend_from_slice(chunk);
+
+        let null = data.iter().position(|&b| b == 0);
+        let bom = data
+            .windows(Self::BOM.len())
+            .enumerate()
+            .find(|(i, window)| *window == Self::BOM && start + i > 0)
+            .map(|(i, _)| i);
+        self.found = match (null, bom) {
+            (Some(a), Some(b)) => Some(start + a.min(b)),
+            (a, b) => a.or(b).map(|i| start + i),
+        };
+
+        self.offset += chunk.len();
+        self.tail = data[data.len().saturating_sub(Self::BOM.len() - 1)..].to_vec();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufReader, Cursor};
+
+    fn check(bytes: &[u8]) -> Option<EncodingMismatch> {
+        // Tiny buffer so multibyte sequences straddle chunk boundaries
+        check_declared_encoding(BufReader::with_capacity(3, Cursor::new(bytes))).unwrap()
+    }
+
+    #[test]
+    fn test_matching_declarations() {
+        assert_eq!(check("<?xml version=\"1.0\" encoding=\"UTF-8\"?><a>日本</a>".as_bytes()), None);
+        assert_eq!(check(b"<?xml version='1.0' encoding='ISO-8859-1'?><a>caf\xe9</a>"), None);
+        assert_eq!(check(b"<?xml version=\"1.0\" encoding=\"ISO-8859-1\"?><a>plain</a>"), None);
+        assert_eq!(check("<a>ünïcödé</a>".as_bytes()), None);
+    }
+
+    #[test]
+    fn test_latin1_bytes_declared_utf8() {
+        let mismatch = check(b"<?xml version=\"1.0\" encoding=\"utf-8\"?><a>caf\xe9</a>").unwrap();
+        assert_eq!(mismatch.declared, "utf-8");
+        assert_eq!(mismatch.detected, "ISO-8859-1");
+    }
+
+    fn corruption(bytes: &[u8]) -> Option<usize> {
+        check_encoding(BufReader::with_capacity(3, Cursor::new(bytes)))
+            .unwrap()
+            .corruption
+    }
+
+    #[test]
+    fn test_stray_bom_and_null_bytes() {
+        let mut bytes = vec![0xEF, 0xBB, 0xBF];
+        bytes.extend_from_slice(b"<a>one</a>");
+        assert_eq!(corruption(&bytes), None);
+
+        bytes.extend_from_slice(&[0xEF, 0xBB, 0xBF]);
+        bytes.extend_from_slice(b"<a>two</a>");
+        assert_eq!(corruption(&bytes), Some(13));
+
+        assert_eq!(corruption(b"<a>o\0ne</a>"), Some(4));
+        assert_eq!(corruption(&[0xFF, 0xFE, b'<', 0, b'a', 0, b'/', 0, b'>', 0]), None);
+    }
+
+    #[test]
+    fn test_bom_overrides_content() {
+        let mut bytes = vec![0xEF, 0xBB, 0xBF];
+        bytes.extend_from_slice(b"<?xml version=\"1.0\" encoding=\"windows-1252\"?><a/>");
+        assert_eq!(check(&bytes).unwrap().detected, "UTF-8");
+    }
+}
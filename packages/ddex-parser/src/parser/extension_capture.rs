@@ -4,6 +4,8 @@
 //! not part of the standard DDEX schema, enabling perfect round-trip fidelity for
 //! documents containing proprietary extensions.
 
+use crate::error::ParseError;
+use crate::parser::security::SecurityConfig;
 use crate::utf8_utils;
 use ddex_core::models::{
     extensions::utils, Comment, CommentPosition, Extensions, ProcessingInstruction, XmlFragment,
@@ -36,6 +38,11 @@ pub struct ExtensionCaptureContext {
     /// Current extension being built
     pub current_extension: Option<XmlFragment>,
 
+    /// Descendants of the current extension whose end tag has not been
+    /// seen yet, innermost last. Nesting is tracked here on the heap, so
+    /// deep extensions cost no stack.
+    pub open_fragments: Vec<XmlFragment>,
+
     /// Extensions collected during parsing
     pub extensions: Extensions,
 
@@ -62,6 +69,7 @@ impl ExtensionCaptureContext {
             extension_depth: 0,
             extension_buffer: String::new(),
             current_extension: None,
+            open_fragments: Vec::new(),
             extensions: Extensions::new(),
             current_line: 1,
             current_column: 1,
@@ -110,7 +118,8 @@ impl ExtensionCaptureContext {
         false
     }
 
-    /// Start capturing an extension element
+    /// Start capturing an extension element; its start tag is then passed
+    /// to `process_extension_start_tag` like those of its descendants
     pub fn start_extension_capture(
         &mut self,
         element_name: &str,
@@ -118,8 +127,9 @@ impl ExtensionCaptureContext {
         namespace_prefix: Option<&str>,
     ) {
         self.in_extension = true;
-        self.extension_depth = 1;
+        self.extension_depth = 0;
         self.extension_buffer.clear();
+        self.open_fragments.clear();
 
         self.current_extension = Some(XmlFragment::with_namespace(
             element_name.to_string(),
@@ -147,6 +157,21 @@ impl ExtensionCaptureContext {
         let element_name = utf8_utils::process_text_content_lossy(event.name().as_ref());
         self.extension_buffer.push_str(&element_name);
 
+        // Descendants of the extension root become child fragments
+        if self.extension_depth > 1 {
+            let (prefix, local_name) = match element_name.split_once(':') {
+                Some((prefix, local_name)) => (Some(prefix), local_name),
+                None => (None, element_name.as_str()),
+            };
+            let namespace_uri = self.namespace_context.get(prefix.unwrap_or("")).cloned();
+            self.open_fragments.push(XmlFragment::with_namespace(
+                local_name.to_string(),
+                namespace_uri,
+                prefix.map(String::from),
+                String::new(),
+            ));
+        }
+
         // Add attributes
         for attr in event.attributes().flatten() {
             self.extension_buffer.push(' ');
@@ -158,9 +183,9 @@ impl ExtensionCaptureContext {
             self.extension_buffer.push_str(&value);
             self.extension_buffer.push('"');
 
-            // Store attribute in current extension
-            if let Some(ref mut ext) = self.current_extension {
-                ext.add_attribute(key, value);
+            // Store attribute on the element it belongs to
+            if let Some(fragment) = self.innermost_fragment() {
+                fragment.add_attribute(key, value);
             }
         }
 
@@ -178,14 +203,27 @@ impl ExtensionCaptureContext {
             .push_str(std::str::from_utf8(event.name().as_ref()).unwrap_or("unknown"));
         self.extension_buffer.push('>');
 
-        self.extension_depth -= 1;
+        self.extension_depth = self.extension_depth.saturating_sub(1);
 
-        // If we're back to depth 0, finish capturing this extension
-        if self.extension_depth == 0 {
+        // A closed descendant joins its parent; once the root itself is
+        // closed, the extension is complete
+        if let Some(child) = self.open_fragments.pop() {
+            if let Some(parent) = self.innermost_fragment() {
+                parent.add_child(child);
+            }
+        } else if self.extension_depth == 0 {
             self.finish_extension_capture();
         }
     }
 
+    /// The fragment of the innermost open extension element
+    fn innermost_fragment(&mut self) -> Option<&mut XmlFragment> {
+        match self.open_fragments.last_mut() {
+            Some(fragment) => Some(fragment),
+            None => self.current_extension.as_mut(),
+        }
+    }
+
     /// Process text content during extension capture
     pub fn process_extension_text(&mut self, event: &BytesText) {
         if !self.in_extension {
@@ -196,9 +234,9 @@ impl ExtensionCaptureContext {
         self.extension_buffer.push_str(&text);
 
         // If this is simple text content, store it in the fragment
-        if let Some(ref mut ext) = self.current_extension {
-            if ext.children.is_empty() {
-                ext.text_content = Some(text.to_string());
+        if let Some(fragment) = self.innermost_fragment() {
+            if fragment.children.is_empty() {
+                fragment.text_content = Some(text.to_string());
             }
         }
     }
@@ -226,6 +264,7 @@ impl ExtensionCaptureContext {
         self.in_extension = false;
         self.extension_depth = 0;
         self.extension_buffer.clear();
+        self.open_fragments.clear();
     }
 
     /// Add a document-level processing instruction
@@ -264,8 +303,8 @@ impl ExtensionCaptureContext {
                 .add_document_comment_structured(comment_struct);
         } else {
             // Element-level comment - add to current extension or buffer for later association
-            if let Some(ref mut ext) = self.current_extension {
-                ext.comments.push(comment_struct);
+            if let Some(fragment) = self.innermost_fragment() {
+                fragment.comments.push(comment_struct);
             } else {
                 // Store for later association with the next element
                 self.extensions
@@ -287,6 +326,10 @@ pub struct ExtensionAwareParser {
 
     /// Whether to capture extensions
     pub capture_extensions: bool,
+
+    /// Deepest element nesting accepted, extensions included; deeper
+    /// documents are rejected rather than captured
+    pub max_depth: usize,
 }
 
 impl ExtensionAwareParser {
@@ -295,9 +338,18 @@ impl ExtensionAwareParser {
         Self {
             context: ExtensionCaptureContext::new(),
             capture_extensions,
+            max_depth: SecurityConfig::default().max_element_depth,
         }
     }
 
+    /// Accept element nesting up to `max_depth` instead of the security
+    /// default. Capture keeps open elements on the heap, so a high limit
+    /// for legitimately deep extensions does not risk the stack.
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
     /// Parse XML with extension capture
     pub fn parse_with_extensions(
         &mut self,
@@ -311,10 +363,19 @@ impl ExtensionAwareParser {
         reader.config_mut().trim_text(true);
 
         let mut buf = Vec::new();
+        let mut depth = 0usize;
 
         loop {
             match reader.read_event_into(&mut buf) {
                 Ok(Event::Start(ref e)) => {
+                    depth += 1;
+                    if depth > self.max_depth {
+                        return Err(Box::new(ParseError::DepthLimitExceeded {
+                            depth,
+                            limit: self.max_depth,
+                        }));
+                    }
+
                     let element_name_bytes = e.name();
                     let element_name =
                         std::str::from_utf8(element_name_bytes.as_ref()).unwrap_or("unknown");
@@ -343,7 +404,7 @@ impl ExtensionAwareParser {
                     {
                         if !self.context.in_extension {
                             self.context.start_extension_capture(
-                                element_name,
+                                utils::extract_local_name(element_name),
                                 namespace_uri.as_deref(),
                                 namespace_prefix.as_deref(),
                             );
@@ -353,7 +414,12 @@ impl ExtensionAwareParser {
                         self.context.enter_element(element_name);
                     }
                 }
+                Ok(Event::Empty(ref e)) if self.context.in_extension => {
+                    self.context.process_extension_start_tag(e);
+                    self.context.process_extension_end_tag(&e.to_end());
+                }
                 Ok(Event::End(ref e)) => {
+                    depth = depth.saturating_sub(1);
                     if self.context.in_extension {
                         self.context.process_extension_end_tag(e);
                     } else {
@@ -409,7 +475,7 @@ impl ExtensionAwareParser {
             buf.clear();
         }
 
-        Ok(self.context.extensions.clone())
+        Ok(std::mem::take(&mut self.context.extensions))
     }
 
     /// Extract namespace information from a start tag
@@ -0,0 +1,93 @@
+// core/src/parser/header_extensions.rs
+//! Capture of partner data tucked into the `MessageHeader`
+
+use crate::error::ParseError;
+use crate::parser::unknown_elements::known_children;
+use indexmap::IndexMap;
+use quick_xml::events::Event;
+use quick_xml::{Reader, Writer};
+use std::io::BufRead;
+
+/// Non-standard children of the `MessageHeader`, such as a partner's routing
+/// key, by local name: the text of a child holding only text, otherwise its
+/// inner XML. A name repeated keeps its last value.
+pub fn header_extensions<R: BufRead>(reader: R) -> Result<IndexMap<String, String>, ParseError> {
+    let xml_error = |e: quick_xml::Error| ParseError::XmlError(format!("XML parsing error: {}", e));
+    let standard = known_children("MessageHeader").unwrap_or_default();
+    let mut reader = Reader::from_reader(reader);
+    let mut buf = Vec::new();
+    let mut depth = 0usize;
+    let mut header_depth: Option<usize> = None;
+    // The extension being read: its name, inner XML, text, and whether it
+    // has children
+    let mut capture: Option<(String, Writer<Vec<u8>>, String, bool)> = None;
+    let mut extensions = IndexMap::new();
+
+    loop {
+        let event = reader.read_event_into(&mut buf).map_err(xml_error)?;
+        match event {
+            Event::Start(ref e) => {
+                depth += 1;
+                let name = String::from_utf8_lossy(e.local_name().as_ref()).into_owned();
+                if let Some((_, writer, _, has_children)) = capture.as_mut() {
+                    *has_children = true;
+                    writer.write_event(event.borrow())?;
+                } else if header_depth.is_none() && name == "MessageHeader" {
+                    header_depth = Some(depth);
+                } else if header_depth.is_some_and(|header| depth == header + 1)
+                    && !standard.contains(&name.as_str())
+                {
+                    capture = Some((name, Writer::new(Vec::new()), String::new(), false));
+                }
+            }
+            Event::Empty(ref e) => {
+                let name = String::from_utf8_lossy(e.local_name().as_ref()).into_owned();
+                if let Some((_, writer, _, has_children)) = capture.as_mut() {
+                    *has_children = true;
+                    writer.write_event(event.borrow())?;
+                } else if header_depth.is_some_and(|header| depth == header)
+                    && !standard.contains(&name.as_str())
+                {
+                    extensions.insert(name, String::new());
+                }
+            }
+            Event::End(_) => {
+                if header_depth.is_some_and(|header| depth == header + 1) {
+                    if let Some((name, writer, text, has_children)) = capture.take() {
+                        let value = if has_children {
+                            String::from_utf8_lossy(&writer.into_inner())
+                                .trim()
+                                .to_string()
+                        } else {
+                            text.trim().to_string()
+                        };
+                        extensions.insert(name, value);
+                    }
+                } else if let Some((_, writer, _, _)) = capture.as_mut() {
+                    writer.write_event(event.borrow())?;
+                } else if header_depth == Some(depth) {
+                    // Nothing past the header is of interest
+                    break;
+                }
+                depth = depth.saturating_sub(1);
+            }
+            Event::Text(ref e) => {
+                if let Some((_, writer, text, _)) = capture.as_mut() {
+                    text.push_str(&e.unescape().map_err(xml_error)?);
+                    writer.write_event(event.borrow())?;
+                }
+            }
+            Event::CData(ref e) => {
+                if let Some((_, writer, text, _)) = capture.as_mut() {
+                    text.push_str(&String::from_utf8_lossy(e));
+                    writer.write_event(event.borrow())?;
+                }
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    Ok(extensions)
+}
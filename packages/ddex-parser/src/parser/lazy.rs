@@ -0,0 +1,85 @@
+// core/src/parser/lazy.rs
+//! Parse releases on demand instead of all at once
+
+use super::raw::{self, ReleaseIndex};
+use super::security::SecurityConfig;
+use crate::error::ParseError;
+use ddex_core::models::flat::ParsedRelease;
+use std::io::Cursor;
+
+/// A message indexed by release and resource byte spans, whose releases are
+/// only parsed when asked for. Holds the source and the index, never more
+/// than one release's models at a time, so random access into a large
+/// catalog stays cheap.
+#[derive(Debug, Clone)]
+pub struct LazyMessage {
+    source: Vec<u8>,
+    index: ReleaseIndex,
+    security_config: SecurityConfig,
+}
+
+impl LazyMessage {
+    /// Index `source` in one pass without building any models
+    pub fn new(source: Vec<u8>, security_config: SecurityConfig) -> Result<Self, ParseError> {
+        let index = raw::release_index(&source)?;
+        Ok(Self {
+            source,
+            index,
+            security_config,
+        })
+    }
+
+    pub fn release_count(&self) -> usize {
+        self.index.spans.releases.len()
+    }
+
+    pub fn resource_count(&self) -> usize {
+        self.index.spans.resources.len()
+    }
+
+    /// `ReleaseReference`s in document order
+    pub fn release_references(&self) -> impl Iterator<Item = &str> {
+        self.index
+            .spans
+            .releases
+            .iter()
+            .map(|release| release.reference.as_str())
+    }
+
+    /// Parse the release at `index` along with the resources it refers to.
+    /// Errors if there is no such release.
+    pub fn release(&self, index: usize) -> Result<ParsedRelease, ParseError> {
+        let reference = &self
+            .index
+            .spans
+            .releases
+            .get(index)
+            .ok_or_else(|| ParseError::ReleaseNotFound {
+                reference: format!("#{}", index),
+            })?
+            .reference;
+
+        let document = raw::release_document(&self.source, &self.index, index)?;
+        let message = super::parse(Cursor::new(document), Default::default(), &self.security_config)?;
+
+        message
+            .flat
+            .releases
+            .into_iter()
+            .find(|release| &release.release_id == reference)
+            .ok_or_else(|| ParseError::ReleaseNotFound {
+                reference: reference.clone(),
+            })
+    }
+
+    /// Parse the release whose `ReleaseReference` is `reference`
+    pub fn release_by_reference(&self, reference: &str) -> Result<ParsedRelease, ParseError> {
+        let index = self
+            .release_references()
+            .position(|candidate| candidate == reference)
+            .ok_or_else(|| ParseError::ReleaseNotFound {
+                reference: reference.to_string(),
+            })?;
+        self.release(index)
+    }
+}
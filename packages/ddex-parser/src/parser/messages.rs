@@ -0,0 +1,116 @@
+// core/src/parser/messages.rs
+//! Split inputs holding more than one message
+
+use crate::error::ParseError;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+
+/// Root elements of the messages an input may hold
+const MESSAGE_ELEMENTS: &[&[u8]] = &[
+    b"NewReleaseMessage",
+    b"UpdateReleaseMessage",
+    b"PurgeReleaseMessage",
+    b"CatalogListMessage",
+];
+
+/// Standalone documents for every message in `source`, in document order.
+/// Messages may be concatenated, each with its own XML declaration, or
+/// wrapped in a manifest or batch element; namespaces the wrapper declares
+/// are copied onto each message. A lone message at the root is returned
+/// unchanged.
+pub fn message_documents(source: &[u8]) -> Result<Vec<Vec<u8>>, ParseError> {
+    let mut reader = Reader::from_reader(source);
+    let mut depth = 0usize;
+    // Namespace declarations of the wrappers currently open
+    let mut wrapper_namespaces: Vec<Vec<(Vec<u8>, Vec<u8>)>> = Vec::new();
+    // Depth, end of the start tag and rewritten start tag of the open message
+    let mut open: Option<(usize, usize, Vec<u8>)> = None;
+    let mut root_is_message = None;
+    let mut documents = Vec::new();
+
+    loop {
+        let before = reader.buffer_position() as usize;
+        let event = reader
+            .read_event()
+            .map_err(|e| ParseError::XmlError(format!("XML parsing error: {}", e)))?;
+
+        match event {
+            Event::Start(ref e) => {
+                depth += 1;
+                let is_message = MESSAGE_ELEMENTS.contains(&e.local_name().as_ref());
+                root_is_message.get_or_insert(is_message);
+                if open.is_some() {
+                    continue;
+                }
+                if is_message {
+                    let after = reader.buffer_position() as usize;
+                    let start_tag = with_namespaces(&source[before..after], e, &wrapper_namespaces);
+                    open = Some((depth, after, start_tag));
+                } else {
+                    wrapper_namespaces.push(namespace_declarations(e));
+                }
+            }
+            Event::End(_) => {
+                if let Some((message_depth, body_start, start_tag)) = open.take() {
+                    if depth == message_depth {
+                        let end = reader.buffer_position() as usize;
+                        let mut document = b"<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n".to_vec();
+                        document.extend_from_slice(&start_tag);
+                        document.extend_from_slice(&source[body_start..end]);
+                        documents.push(document);
+                    } else {
+                        open = Some((message_depth, body_start, start_tag));
+                    }
+                } else {
+                    wrapper_namespaces.pop();
+                }
+                depth = depth.saturating_sub(1);
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    if documents.len() == 1 && root_is_message == Some(true) {
+        return Ok(vec![source.to_vec()]);
+    }
+    Ok(documents)
+}
+
+/// `xmlns` and `xmlns:*` attributes of an element
+fn namespace_declarations(e: &BytesStart) -> Vec<(Vec<u8>, Vec<u8>)> {
+    e.attributes()
+        .flatten()
+        .filter(|a| a.key.as_ref() == b"xmlns" || a.key.as_ref().starts_with(b"xmlns:"))
+        .map(|a| (a.key.as_ref().to_vec(), a.value.to_vec()))
+        .collect()
+}
+
+/// The message's start tag with the wrappers' namespace declarations it
+/// does not make itself inserted after the element name
+fn with_namespaces(
+    start_tag: &[u8],
+    e: &BytesStart,
+    wrapper_namespaces: &[Vec<(Vec<u8>, Vec<u8>)>],
+) -> Vec<u8> {
+    let mut declared: Vec<Vec<u8>> = namespace_declarations(e)
+        .into_iter()
+        .map(|(key, _)| key)
+        .collect();
+    let name_end = 1 + e.name().as_ref().len();
+
+    let mut tag = start_tag[..name_end].to_vec();
+    // Inner wrappers override outer ones
+    for (key, value) in wrapper_namespaces.iter().rev().flatten() {
+        if !declared.contains(key) {
+            tag.push(b' ');
+            tag.extend_from_slice(key);
+            tag.extend_from_slice(b"=\"");
+            tag.extend_from_slice(value);
+            tag.push(b'"');
+            declared.push(key.clone());
+        }
+    }
+    tag.extend_from_slice(&start_tag[name_end..]);
+    tag
+}
@@ -4,13 +4,28 @@
 pub mod attribute_extractor;
 pub mod detector;
 pub mod dom;
+pub mod element_hook;
+pub mod encoding;
 pub mod extension_capture;
+pub mod header_extensions;
+pub mod lazy;
+pub mod messages;
 pub mod mode;
 pub mod multi_release_parser;
 pub mod namespace_detector;
+pub mod partial;
+pub mod parties;
+pub mod party_references;
+pub mod raw;
+pub mod release_list;
+pub mod resource_hashes;
 pub mod security;
 pub mod selective_parser;
 pub mod stream;
+pub mod territories;
+pub mod track_count;
+pub mod unknown_elements;
+pub mod whitespace;
 pub mod xml_validator;
 pub mod xpath_selector;
 
@@ -18,7 +33,8 @@ pub mod xpath_selector;
 mod tests;
 
 use crate::error::ParseError;
-use ddex_core::models::flat::ParsedERNMessage;
+use ddex_core::models::flat::{ParseWarning, ParsedERNMessage};
+use ddex_core::models::graph::{Release, Resource, ResourceType};
 use std::io::BufRead;
 
 /// Main parser options
@@ -27,6 +43,7 @@ pub struct ParseOptions {
     pub mode: mode::ParseMode,
     pub auto_threshold: u64,
     pub resolve_references: bool,
+    /// Keep each release's and resource's original XML in its `raw` field
     pub include_raw: bool,
     pub max_memory: usize,
     pub timeout_ms: u64,
@@ -35,6 +52,29 @@ pub struct ParseOptions {
     pub include_comments: bool,
     pub preserve_unknown_elements: bool,
     pub chunk_size: usize,
+    /// Treat recoverable problems (e.g. encoding mismatches) as errors instead of warnings
+    pub strict: bool,
+    /// Whitespace handling for titles, names and descriptions
+    pub whitespace: whitespace::WhitespaceMode,
+    /// Fail if a `PartyReference`, `ArtistPartyReference` or
+    /// `RightsControllerPartyReference` names no party in the `PartyList`
+    pub validate_party_references: bool,
+    /// Collapse prefixes declaring the same namespace onto one canonical
+    /// prefix in the message's `namespaces`. The `raw` XML kept by
+    /// `include_raw` still carries the original declarations.
+    pub collapse_namespaces: bool,
+    /// Parse only the first this many releases, for a quick preview of a
+    /// large delivery; the message is marked `truncated` if more were left out
+    pub max_releases: Option<usize>,
+    /// Rewrite elements' text and attributes before they are read into the
+    /// model, e.g. to map a partner's genre vocabulary onto ours
+    pub element_hook: Option<element_hook::ElementHook>,
+    /// Rewrite non-standard deal territory codes such as `UK` to ISO 3166
+    /// ones, warning per remapped code; with `strict`, a code that is
+    /// neither known nor aliased fails the parse
+    pub territory_aliases: Option<territories::TerritoryAliases>,
+    /// Fill in the message's `document_stats`; they are left at zero otherwise
+    pub collect_statistics: bool,
 }
 
 impl Default for ParseOptions {
@@ -51,6 +91,14 @@ impl Default for ParseOptions {
             include_raw_extensions: false,
             include_comments: false,
             preserve_unknown_elements: false,
+            strict: false,
+            whitespace: whitespace::WhitespaceMode::Trim,
+            validate_party_references: false,
+            collapse_namespaces: false,
+            max_releases: None,
+            element_hook: None,
+            territory_aliases: None,
+            collect_statistics: false,
         }
     }
 }
@@ -61,26 +109,254 @@ pub fn parse<R: BufRead + std::io::Seek>(
     options: ParseOptions,
     security_config: &security::SecurityConfig,
 ) -> Result<ParsedERNMessage, ParseError> {
-    // Detect version first - this now validates XML
-    let version = detector::VersionDetector::detect(&mut reader)?;
+    // Cut a preview down to its first releases before anything parses it
+    if let Some(max_releases) = options.max_releases {
+        let mut source = Vec::new();
+        reader.read_to_end(&mut source)?;
+        if let Some(document) = raw::first_releases_document(&source, max_releases)? {
+            let collect_statistics = options.collect_statistics;
+            let options = ParseOptions {
+                max_releases: None,
+                ..options
+            };
+            let mut message = parse(std::io::Cursor::new(document), options, security_config)?;
+            message.truncated = true;
+            // Counts describe the preview, the size the whole input
+            if collect_statistics {
+                message.document_stats.file_size_bytes = source.len() as u64;
+            }
+            return Ok(message);
+        }
+        reader.seek(std::io::SeekFrom::Start(0))?;
+    }
+
+    // Every namespace declared anywhere in the document, checked against the
+    // security limits; external and parameter entities are refused here,
+    // before anything else reads the document. The same pass compares the
+    // declared encoding with what the bytes actually contain and finds any
+    // splice point left by concatenated files, which explains a malformed
+    // document best.
+    let mut encoding_scan = encoding::EncodingScan::new(&mut reader);
+    let namespaces = namespace_detector::NamespaceDetector::new()
+        .detect_from_xml_with_security(&mut encoding_scan, security_config);
+    let encoding_check = encoding_scan.finish()?;
     reader.seek(std::io::SeekFrom::Start(0))?;
+    if let Some(position) = encoding_check.corruption {
+        return Err(ParseError::EncodingCorruption { position });
+    }
+    let mut namespaces = namespaces?;
+    let document_stats = std::mem::take(&mut namespaces.document_stats);
+    let encoding_mismatch = encoding_check.mismatch;
+    if let Some(mismatch) = encoding_mismatch.as_ref().filter(|_| options.strict) {
+        return Err(ParseError::EncodingMismatch {
+            declared: mismatch.declared.clone(),
+            detected: mismatch.detected.clone(),
+        });
+    }
+
+    // Let the caller's hook rewrite elements before anything reads them
+    if let Some(hook) = options.element_hook.clone() {
+        let mut source = Vec::new();
+        reader.read_to_end(&mut source)?;
+        let document = element_hook::apply(&source, &hook)?;
+        let options = ParseOptions {
+            element_hook: None,
+            ..options
+        };
+        return parse(std::io::Cursor::new(document), options, security_config);
+    }
+
+    // Detect version first - this now validates XML.
+    // The namespace decides the version; a disagreeing MessageSchemaVersionId
+    // is only reported.
+    let (version, schema_version_id) = detector::VersionDetector::detect_with_schema_id(&mut reader)?;
+    reader.seek(std::io::SeekFrom::Start(0))?;
+    let schema_mismatch = schema_version_id.filter(|id| {
+        detector::VersionDetector::version_from_schema_id(id).is_some_and(|v| v != version)
+    });
+    if let Some(schema_version_id) = schema_mismatch.as_ref().filter(|_| options.strict) {
+        return Err(ParseError::SchemaVersionMismatch {
+            namespace_version: version.as_str().to_string(),
+            schema_version_id: schema_version_id.clone(),
+        });
+    }
+
+    // Entities can only carry their source XML, and party references can only
+    // be checked against it, if we keep a copy of it
+    let include_raw = options.include_raw;
+    let validate_party_references = options.validate_party_references;
+    let source = if include_raw || validate_party_references {
+        let mut source = Vec::new();
+        reader.read_to_end(&mut source)?;
+        reader.seek(std::io::SeekFrom::Start(0))?;
+        Some(source)
+    } else {
+        None
+    };
+
+    // The namespaces found earlier, under one prefix each if asked
+    let unknown = std::mem::take(&mut namespaces.unknown_elements);
+    let (namespaces, namespace_aliases) = if options.collapse_namespaces {
+        let collapsed = namespaces.collapse_equivalent();
+        (collapsed.declarations, collapsed.aliases)
+    } else {
+        (namespaces.declarations, Default::default())
+    };
 
     // Select parsing mode
     let mode_selector = mode::ModeSelector::new(options.auto_threshold);
     let selected_mode = mode_selector.select_mode(&mut reader, options.mode)?;
     reader.seek(std::io::SeekFrom::Start(0))?;
 
-    match selected_mode {
+    let strict = options.strict;
+    let collect_statistics = options.collect_statistics;
+    let territory_aliases = options.territory_aliases.clone();
+    let mut message = match selected_mode {
         mode::ParseMode::Dom => {
             // Use DOM parser for smaller files
-            dom::parse_dom(reader, version, options, security_config)
+            dom::parse_dom(&mut reader, version, options, security_config)?
         }
         mode::ParseMode::Stream => {
-            // Use streaming parser for larger files
-            stream::parse_streaming(reader, version, options, security_config)
+            // Use streaming parser for larger files; the namespace pass above
+            // has already held every element to the security limits
+            stream::parse_streaming(&mut reader, version, options, security_config)?
         }
         mode::ParseMode::Auto => unreachable!(), // Already resolved
+    };
+    message.namespaces = namespaces;
+    message.namespace_aliases = namespace_aliases;
+
+    if let Some(aliases) = territory_aliases {
+        let remapped = aliases.normalize(&mut message, strict)?;
+        message.warnings.extend(remapped);
+    }
+
+    if let Some(source) = source {
+        if validate_party_references {
+            let unresolved = party_references::unresolved_party_references(&source, |reference| {
+                message.flat.parties.contains_key(reference)
+            })?;
+            if !unresolved.is_empty() {
+                return Err(ParseError::UnresolvedPartyReferences(unresolved));
+            }
+        }
+        if include_raw {
+            raw::attach_raw(&mut message, &source)?;
+        }
+    }
+
+    if let Some(mismatch) = encoding_mismatch {
+        message.warnings.push(ParseWarning {
+            code: "ENCODING_MISMATCH".to_string(),
+            message: format!(
+                "XML declaration says '{}' but content looks like '{}'",
+                mismatch.declared, mismatch.detected
+            ),
+            location: Some("XML declaration".to_string()),
+        });
+    }
+
+    if let Some(schema_version_id) = schema_mismatch {
+        message.warnings.push(ParseWarning {
+            code: "SCHEMA_VERSION_MISMATCH".to_string(),
+            message: format!(
+                "Namespace declares ERN {} but MessageSchemaVersionId is '{}'; using ERN {}",
+                version.as_str(), schema_version_id, version.as_str()
+            ),
+            location: Some("MessageSchemaVersionId".to_string()),
+        });
+    }
+
+    // Tell an empty delivery from releases the parser could not read
+    if message.flat.releases.is_empty() {
+        reader.seek(std::io::SeekFrom::Start(0))?;
+        if let Ok(diagnostic) = release_list::diagnose_release_list(&mut reader) {
+            message.warnings.push(diagnostic.to_warning());
+        }
+    }
+
+    // Tallied by the namespace pass, for callers budgeting memory
+    if collect_statistics {
+        message.document_stats = document_stats;
+    }
+
+    // Keep partner data tucked into the header, which the model has no place for
+    reader.seek(std::io::SeekFrom::Start(0))?;
+    message.header_extensions = header_extensions::header_extensions(&mut reader)?;
+
+    // Surface partner schema drift the models silently skip
+    message.warnings.extend(unknown.iter().map(unknown_elements::UnknownElement::to_warning));
+
+    let duplicates = duplicate_resource_references(&message.graph.releases);
+    message.warnings.extend(duplicates);
+    let missing_durations = missing_track_durations(&message.graph.releases, &message.graph.resources);
+    message.warnings.extend(missing_durations);
+
+    // Compare each release's track listing with the recordings it names; the
+    // streaming parser builds no resources to compare against
+    if selected_mode == mode::ParseMode::Dom {
+        let resource_types = track_count::resource_types(&message.graph.resources);
+        let mismatches = track_count::track_count_mismatches(&message.flat.releases, &resource_types);
+        message.warnings.extend(mismatches);
+    }
+
+    Ok(message)
+}
+
+/// Warn about resources listed more than once in the same release. Sharing a
+/// resource between releases is normal and not reported.
+fn duplicate_resource_references(releases: &[Release]) -> Vec<ParseWarning> {
+    let mut warnings = Vec::new();
+    for release in releases {
+        let mut seen = std::collections::HashSet::new();
+        let mut reported = std::collections::HashSet::new();
+        for reference in &release.release_resource_reference_list {
+            let reference = reference.resource_reference.as_str();
+            if !seen.insert(reference) && reported.insert(reference) {
+                warnings.push(ParseWarning {
+                    code: "DUPLICATE_RESOURCE_REFERENCE".to_string(),
+                    message: format!(
+                        "Release '{}' lists resource '{}' more than once",
+                        release.release_reference, reference
+                    ),
+                    location: Some(format!(
+                        "Release[{}]/ReleaseResourceReferenceList",
+                        release.release_reference
+                    )),
+                });
+            }
+        }
+    }
+    warnings
+}
+
+/// Warn about tracks with no duration, which are left out of their release's
+/// `total_duration_seconds`
+fn missing_track_durations(releases: &[Release], resources: &[Resource]) -> Vec<ParseWarning> {
+    let mut warnings = Vec::new();
+    for release in releases {
+        for reference in &release.release_resource_reference_list {
+            let untimed = resources.iter().any(|resource| {
+                resource.resource_reference == reference.resource_reference
+                    && matches!(resource.resource_type, ResourceType::SoundRecording | ResourceType::Video)
+                    && resource.duration.is_none()
+            });
+            if untimed {
+                warnings.push(ParseWarning {
+                    code: "MISSING_TRACK_DURATION".to_string(),
+                    message: format!(
+                        "Track '{}' in release '{}' has no Duration and is left out of the release's total runtime",
+                        reference.resource_reference, release.release_reference
+                    ),
+                    location: Some(format!(
+                        "Release[{}]/ReleaseResourceReferenceList",
+                        release.release_reference
+                    )),
+                });
+            }
+        }
     }
+    warnings
 }
 
 pub mod version_ext;
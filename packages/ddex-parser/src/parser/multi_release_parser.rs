@@ -396,6 +396,12 @@ impl MultiReleaseParser {
             extensions: None,
             attributes: None,
             comments: None,
+            descriptions: Vec::new(),
+            reference_title: Vec::new(),
+            display_title: Vec::new(),
+            related_releases: Vec::new(),
+            label_name: None,
+            visibility: None,
         }
     }
 
@@ -4,7 +4,9 @@
 //! handling scope inheritance, default namespaces, and custom extensions.
 
 use crate::error::ParseError;
+use crate::parser::unknown_elements::{self, UnknownElement, UnknownElementScan};
 use crate::utf8_utils;
+use ddex_core::models::flat::DocumentStats;
 use ddex_core::models::versions::ERNVersion;
 use ddex_core::namespace::{
     DDEXStandard, NamespaceInfo, NamespaceRegistry, NamespaceScope, NamespaceWarning,
@@ -12,6 +14,7 @@ use ddex_core::namespace::{
 use indexmap::IndexMap;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
+use std::collections::HashSet;
 use std::io::BufRead;
 use tracing::{debug, warn};
 
@@ -32,6 +35,12 @@ pub struct NamespaceDetector {
     detected_version: Option<ERNVersion>,
     /// Warnings collected during namespace processing
     warnings: Vec<NamespaceWarning>,
+    /// Non-standard children of standard elements seen so far
+    unknown_elements: UnknownElementScan,
+    /// Tallies of the document read so far
+    document_stats: DocumentStats,
+    /// Distinct namespace URIs declared so far
+    namespace_uris: HashSet<String>,
 }
 
 /// Namespace detection result
@@ -49,6 +58,10 @@ pub struct NamespaceDetectionResult {
     pub default_namespace: Option<String>,
     /// Custom namespaces detected
     pub custom_namespaces: Vec<NamespaceInfo>,
+    /// DDEX elements found under a standard parent that does not allow them
+    pub unknown_elements: Vec<UnknownElement>,
+    /// Elements, attributes, comments and namespaces of the document
+    pub document_stats: DocumentStats,
 }
 
 impl NamespaceDetector {
@@ -62,6 +75,9 @@ impl NamespaceDetector {
             default_namespace_stack: vec![None],
             detected_version: None,
             warnings: Vec::new(),
+            unknown_elements: UnknownElementScan::default(),
+            document_stats: DocumentStats::default(),
+            namespace_uris: HashSet::new(),
         }
     }
 
@@ -108,7 +124,10 @@ impl NamespaceDetector {
                         });
                     }
 
+                    security_config.check_element(e)?;
+                    self.document_stats.element_count += 1;
                     self.process_start_element(e)?;
+                    self.scan_element(e, false);
                 }
                 Ok(Event::Empty(ref e)) => {
                     depth += 1;
@@ -121,13 +140,17 @@ impl NamespaceDetector {
                         });
                     }
 
+                    security_config.check_element(e)?;
+                    self.document_stats.element_count += 1;
                     self.process_start_element(e)?;
+                    self.scan_element(e, true);
 
                     // For empty elements, immediately pop scope and decrement depth
                     self.pop_namespace_scope();
                     depth -= 1;
                 }
                 Ok(Event::End(_)) => {
+                    self.unknown_elements.end();
                     self.pop_namespace_scope();
                     depth = depth.saturating_sub(1);
                 }
@@ -149,12 +172,12 @@ impl NamespaceDetector {
                         }
                     }
                 }
-                Ok(Event::DocType(_)) if security_config.disable_dtd => {
-                    return Err(ParseError::SecurityViolation {
-                        message: "DTD declarations are disabled for security".to_string(),
-                    });
+                Ok(Event::DocType(ref e)) => security_config.check_doctype(e)?,
+                Ok(Event::Comment(_)) => self.document_stats.comment_count += 1,
+                Ok(Event::Eof) => {
+                    self.document_stats.file_size_bytes = xml_reader.buffer_position();
+                    break;
                 }
-                Ok(Event::Eof) => break,
                 Ok(_) => {} // Ignore other events for namespace detection
                 Err(e) => {
                     return Err(ParseError::XmlError(format!("XML parsing error: {}", e)));
@@ -193,6 +216,7 @@ impl NamespaceDetector {
                 new_scope.declare_namespace("".to_string(), value.clone());
                 self.detected_namespaces
                     .insert("".to_string(), value.clone());
+                self.namespace_uris.insert(value.clone());
                 _has_namespace_declarations = true;
 
                 // Try to detect ERN version
@@ -213,6 +237,7 @@ impl NamespaceDetector {
                 new_scope.declare_namespace(prefix.to_string(), value.clone());
                 self.detected_namespaces
                     .insert(prefix.to_string(), value.clone());
+                self.namespace_uris.insert(value.clone());
                 _has_namespace_declarations = true;
 
                 // Track namespace aliases
@@ -231,6 +256,8 @@ impl NamespaceDetector {
                         );
                     }
                 }
+            } else {
+                self.document_stats.attribute_count += 1;
             }
         }
 
@@ -241,6 +268,22 @@ impl NamespaceDetector {
         Ok(())
     }
 
+    /// Check an element, in the scope its own declarations opened, against
+    /// the children its parent allows
+    fn scan_element(&mut self, element: &BytesStart, empty: bool) {
+        let name = element.name();
+        let standard = match name.prefix() {
+            Some(prefix) => self
+                .resolve_prefix(&String::from_utf8_lossy(prefix.as_ref()))
+                .is_some_and(|uri| unknown_elements::is_ddex_namespace(uri.as_bytes())),
+            None => self
+                .get_default_namespace()
+                .is_none_or(|uri| unknown_elements::is_ddex_namespace(uri.as_bytes())),
+        };
+        let local_name = String::from_utf8_lossy(name.local_name().as_ref()).into_owned();
+        self.unknown_elements.start(&local_name, standard, empty);
+    }
+
     /// Pop namespace scope when closing an element
     fn pop_namespace_scope(&mut self) {
         if self.scope_stack.len() > 1 {
@@ -292,6 +335,11 @@ impl NamespaceDetector {
             warnings: self.warnings.clone(),
             default_namespace: self.detected_namespaces.get("").cloned(),
             custom_namespaces,
+            unknown_elements: self.unknown_elements.clone().finish(),
+            document_stats: DocumentStats {
+                namespace_count: self.namespace_uris.len(),
+                ..self.document_stats.clone()
+            },
         }
     }
 
@@ -342,6 +390,55 @@ impl NamespaceDetector {
     }
 }
 
+/// Namespace declarations with each namespace under a single prefix
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CollapsedNamespaces {
+    /// Canonical prefix -> URI
+    pub declarations: IndexMap<String, String>,
+    /// Collapsed prefix -> canonical prefix
+    pub aliases: IndexMap<String, String>,
+}
+
+impl NamespaceDetectionResult {
+    /// Collapse every prefix declaring the same namespace, the default
+    /// namespace included, onto one canonical prefix: the registry's
+    /// preferred prefix for a known DDEX namespace, unless the document
+    /// binds it to another namespace, otherwise the first non-default prefix
+    /// declared for it
+    pub fn collapse_equivalent(&self) -> CollapsedNamespaces {
+        let registry = NamespaceRegistry::new();
+        let mut prefixes_by_uri: IndexMap<&str, Vec<&str>> = IndexMap::new();
+        for (prefix, uri) in &self.declarations {
+            prefixes_by_uri
+                .entry(uri.as_str())
+                .or_default()
+                .push(prefix.as_str());
+        }
+
+        let mut collapsed = CollapsedNamespaces::default();
+        for (uri, prefixes) in prefixes_by_uri {
+            let preferred = registry.get_preferred_prefix(uri).filter(|preferred| {
+                self.declarations
+                    .get(*preferred)
+                    .is_none_or(|bound| bound == uri)
+            });
+            let canonical = preferred
+                .or_else(|| prefixes.iter().copied().find(|prefix| !prefix.is_empty()))
+                .unwrap_or(prefixes[0]);
+
+            for prefix in prefixes.into_iter().filter(|prefix| *prefix != canonical) {
+                collapsed
+                    .aliases
+                    .insert(prefix.to_string(), canonical.to_string());
+            }
+            collapsed
+                .declarations
+                .insert(canonical.to_string(), uri.to_string());
+        }
+        collapsed
+    }
+}
+
 /// Namespace context for maintaining state during parsing
 #[derive(Debug, Clone)]
 pub struct NamespaceContext {
@@ -0,0 +1,115 @@
+// core/src/parser/partial.rs
+//! Best-effort parsing that keeps every release that can be recovered
+
+use super::{raw, security::SecurityConfig, ParseOptions};
+use crate::error::ParseError;
+use ddex_core::models::flat::ParsedERNMessage;
+use std::io::Cursor;
+use std::ops::Range;
+
+/// Outcome of a best-effort parse
+#[derive(Debug)]
+pub struct PartialParse {
+    /// The message without the releases named in `errors`, or `None` if the
+    /// problem lies outside the releases
+    pub message: Option<ParsedERNMessage>,
+    /// What kept part or all of the document out of `message`, in document order
+    pub errors: Vec<PartialParseError>,
+}
+
+/// A problem that kept part of the document out of a partial parse
+#[derive(Debug)]
+pub struct PartialParseError {
+    /// `ReleaseReference` of the dropped release, if the problem is in one
+    pub release_reference: Option<String>,
+    pub error: ParseError,
+}
+
+impl PartialParse {
+    /// Nothing could be recovered
+    pub fn failed(error: ParseError) -> Self {
+        Self {
+            message: None,
+            errors: vec![PartialParseError {
+                release_reference: None,
+                error,
+            }],
+        }
+    }
+}
+
+/// Parse `source`, and if that fails, parse it again without the releases
+/// that fail on their own. Releases are cut out once with
+/// [`raw::lenient_release_index`], and each is tried in a fragment holding
+/// only the header, parties and the resources it refers to, so a release
+/// holding malformed XML only costs itself and the document as a whole is
+/// parsed at most three times.
+pub fn parse_partial(source: &[u8], options: ParseOptions, security_config: &SecurityConfig) -> PartialParse {
+    let parse = |document: &[u8]| super::parse(Cursor::new(document), options.clone(), security_config);
+
+    let error = match parse(source) {
+        Ok(message) => {
+            return PartialParse {
+                message: Some(message),
+                errors: Vec::new(),
+            }
+        }
+        Err(error) => error,
+    };
+
+    // Without any releases the rest of the document must parse, or the
+    // problem is not one a release can be blamed for
+    let Ok(index) = raw::lenient_release_index(source) else {
+        return PartialParse::failed(error);
+    };
+    let releases = &index.spans.releases;
+    let all_releases: Vec<Range<usize>> = releases.iter().map(|release| release.span.clone()).collect();
+    if releases.is_empty() || parse(&without(source, &all_releases)).is_err() {
+        return PartialParse::failed(error);
+    }
+
+    let mut errors = Vec::new();
+    let mut broken = Vec::new();
+    for (position, release) in releases.iter().enumerate() {
+        let result = raw::release_document(source, &index, position).and_then(|document| parse(&document));
+        if let Err(error) = result {
+            errors.push(PartialParseError {
+                release_reference: Some(release.reference.clone()).filter(|reference| !reference.is_empty()),
+                error,
+            });
+            broken.push(release.span.clone());
+        }
+    }
+
+    // Every release parses alone, so the failure comes from them together
+    if broken.is_empty() {
+        return PartialParse::failed(error);
+    }
+
+    match parse(&without(source, &broken)) {
+        Ok(message) => PartialParse {
+            message: Some(message),
+            errors,
+        },
+        Err(error) => {
+            errors.push(PartialParseError {
+                release_reference: None,
+                error,
+            });
+            PartialParse { message: None, errors }
+        }
+    }
+}
+
+/// `source` with the given ranges, sorted and non-overlapping, cut out
+fn without(source: &[u8], ranges: &[Range<usize>]) -> Vec<u8> {
+    let mut document = Vec::with_capacity(source.len());
+    let mut position = 0;
+    for range in ranges {
+        document.extend_from_slice(&source[position..range.start]);
+        position = range.end;
+    }
+    document.extend_from_slice(&source[position..]);
+
+    document
+}
@@ -0,0 +1,234 @@
+// core/src/parser/parties.rs
+//! Extract the parties of a message and the roles they play, without
+//! building releases, resources or deals
+
+use crate::error::ParseError;
+use ddex_core::models::common::{Identifier, IdentifierType, LocalizedString};
+use ddex_core::models::graph::{Party, PartyRole};
+use quick_xml::events::Event;
+use quick_xml::Reader;
+
+/// Elements that mention a party, either by reference or by giving its
+/// names and ids in place as ERN 3.8 does, and the role that implies.
+/// Contributors take their role from their `Role` child instead.
+fn mention_role(name: &[u8]) -> Option<Option<PartyRole>> {
+    match name {
+        b"DisplayArtist" => Some(Some(PartyRole::Artist)),
+        b"ResourceRightsController" | b"RightsController" => Some(Some(PartyRole::RightsController)),
+        b"Contributor" | b"ResourceContributor" | b"IndirectResourceContributor" => Some(None),
+        _ => None,
+    }
+}
+
+fn contributor_role(text: &str) -> PartyRole {
+    match text {
+        "Artist" | "MainArtist" => PartyRole::Artist,
+        "Producer" => PartyRole::Producer,
+        "Composer" => PartyRole::Composer,
+        "Lyricist" => PartyRole::Lyricist,
+        "Publisher" | "MusicPublisher" => PartyRole::Publisher,
+        "Performer" => PartyRole::Performer,
+        "Engineer" => PartyRole::Engineer,
+        "Label" => PartyRole::Label,
+        "Distributor" => PartyRole::Distributor,
+        "RightsController" => PartyRole::RightsController,
+        other => PartyRole::Other(other.to_string()),
+    }
+}
+
+fn empty_party() -> Party {
+    Party {
+        party_reference: None,
+        party_id: Vec::new(),
+        isni: None,
+        ipi: None,
+        party_name: Vec::new(),
+        party_role: Vec::new(),
+        contact_details: None,
+    }
+}
+
+/// Fold `other`'s ids, names and roles into `party`, skipping duplicates
+fn merge(party: &mut Party, other: Party) {
+    for id in other.party_id {
+        if !party.party_id.contains(&id) {
+            party.party_id.push(id);
+        }
+    }
+    for name in other.party_name {
+        if !party.party_name.iter().any(|n| n.text == name.text) {
+            party.party_name.push(name);
+        }
+    }
+    for role in other.party_role {
+        add_role(party, role);
+    }
+    if party.isni.is_none() {
+        party.isni = other.isni;
+    }
+    if party.ipi.is_none() {
+        party.ipi = other.ipi;
+    }
+}
+
+fn add_role(party: &mut Party, role: PartyRole) {
+    if !party.party_role.contains(&role) {
+        party.party_role.push(role);
+    }
+}
+
+/// Whether two parties given in place are the same: they share an id or,
+/// failing ids on either side, a name
+fn same_party(a: &Party, b: &Party) -> bool {
+    if a.party_id.iter().any(|id| b.party_id.contains(id)) {
+        return true;
+    }
+    (a.party_id.is_empty() || b.party_id.is_empty())
+        && a.party_name.iter().any(|n| b.party_name.iter().any(|m| m.text == n.text))
+}
+
+/// Every party in `source`, once each, with its ids, names and the roles it
+/// plays across the message: display artist, contributor, label or rights
+/// controller. `PartyList` parties come first in document order, followed by
+/// parties only given in place. References to parties not in the
+/// `PartyList` are left out; see
+/// [`unresolved_party_references`](super::party_references::unresolved_party_references).
+pub fn extract_parties(source: &[u8]) -> Result<Vec<Party>, ParseError> {
+    let mut reader = Reader::from_reader(source);
+    let mut path: Vec<Vec<u8>> = Vec::new();
+    let mut text = String::new();
+
+    let mut parties: Vec<Party> = Vec::new();
+    // Parties mentioned outside the `PartyList`, in document order
+    let mut mentions: Vec<Party> = Vec::new();
+    // The `PartyList` party or mention being read, with the depth it opened at
+    let mut current: Option<(Party, usize)> = None;
+
+    loop {
+        match reader
+            .read_event()
+            .map_err(|e| ParseError::XmlError(format!("XML parsing error: {}", e)))?
+        {
+            Event::Start(ref e) => {
+                let name = e.local_name();
+                if current.is_none() {
+                    let in_party_list = path.last().is_some_and(|p| p == b"PartyList");
+                    if name.as_ref() == b"Party" && in_party_list {
+                        current = Some((empty_party(), path.len()));
+                    } else if let Some(role) = mention_role(name.as_ref()) {
+                        let mut party = empty_party();
+                        party.party_role.extend(role);
+                        current = Some((party, path.len()));
+                    }
+                }
+                path.push(name.as_ref().to_vec());
+                text.clear();
+            }
+            Event::Text(ref e) => {
+                text.push_str(&e.unescape().unwrap_or_default());
+            }
+            Event::End(ref e) => {
+                path.pop();
+                let value = text.trim().to_string();
+                text.clear();
+                let name = e.local_name();
+
+                let Some((party, depth)) = current.as_mut() else {
+                    // Labels are named or referenced by a single element
+                    match name.as_ref() {
+                        b"ReleaseLabelReference" if !value.is_empty() => {
+                            let mut label = empty_party();
+                            label.party_reference = Some(value);
+                            label.party_role.push(PartyRole::Label);
+                            mentions.push(label);
+                        }
+                        b"LabelName" if !value.is_empty() => {
+                            let mut label = empty_party();
+                            label.party_name.push(LocalizedString::new(value));
+                            label.party_role.push(PartyRole::Label);
+                            mentions.push(label);
+                        }
+                        _ => {}
+                    }
+                    continue;
+                };
+
+                if path.len() == *depth {
+                    let party = std::mem::replace(party, empty_party());
+                    let defined = path.last().is_some_and(|p| p == b"PartyList");
+                    current = None;
+                    if !defined {
+                        mentions.push(party);
+                    } else if let Some(existing) = parties.iter_mut().find(|p| {
+                        p.party_reference.is_some() && p.party_reference == party.party_reference
+                    }) {
+                        merge(existing, party);
+                    } else {
+                        parties.push(party);
+                    }
+                    continue;
+                }
+
+                let in_party_id = path.last().is_some_and(|p| p == b"PartyId");
+                match name.as_ref() {
+                    _ if value.is_empty() => {}
+                    b"PartyReference" | b"ArtistPartyReference" | b"ContributorPartyReference"
+                    | b"RightsControllerPartyReference" => {
+                        party.party_reference.get_or_insert(value);
+                    }
+                    b"ISNI" if in_party_id => {
+                        party.isni.get_or_insert_with(|| value.clone());
+                        party.party_id.push(Identifier { id_type: IdentifierType::ISNI, namespace: None, value });
+                    }
+                    b"IPI" | b"IpiNameNumber" if in_party_id => {
+                        party.ipi.get_or_insert_with(|| value.clone());
+                        party.party_id.push(Identifier { id_type: IdentifierType::IPI, namespace: None, value });
+                    }
+                    b"DPID" if in_party_id => {
+                        party.party_id.push(Identifier {
+                            id_type: IdentifierType::Proprietary,
+                            namespace: Some("DPID".to_string()),
+                            value,
+                        });
+                    }
+                    // ERN 3.8 gives the id as the element's own text
+                    b"ProprietaryId" | b"PartyId" => {
+                        party.party_id.push(Identifier { id_type: IdentifierType::Proprietary, namespace: None, value });
+                    }
+                    b"FullName" if !party.party_name.iter().any(|n| n.text == value) => {
+                        party.party_name.push(LocalizedString::new(value));
+                    }
+                    b"Role" | b"ResourceContributorRole" | b"IndirectResourceContributorRole" => {
+                        add_role(party, contributor_role(&value));
+                    }
+                    _ => {}
+                }
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    for mention in mentions {
+        if let Some(reference) = mention.party_reference.as_deref() {
+            let defined = parties
+                .iter_mut()
+                .find(|p| p.party_reference.as_deref() == Some(reference));
+            if let Some(party) = defined {
+                for role in mention.party_role {
+                    add_role(party, role);
+                }
+            }
+            continue;
+        }
+        if mention.party_id.is_empty() && mention.party_name.is_empty() {
+            continue;
+        }
+        match parties.iter_mut().find(|p| same_party(p, &mention)) {
+            Some(party) => merge(party, mention),
+            None => parties.push(mention),
+        }
+    }
+
+    Ok(parties)
+}
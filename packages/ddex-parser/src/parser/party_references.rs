@@ -0,0 +1,104 @@
+// core/src/parser/party_references.rs
+//! Check that party references resolve to parties defined in the message
+
+use crate::error::ParseError;
+use quick_xml::events::Event;
+use quick_xml::Reader;
+
+/// A party reference naming no party in the message's `PartyList`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnresolvedPartyReference {
+    /// Element holding the reference, e.g. `RightsControllerPartyReference`
+    pub element: String,
+    /// The referenced value
+    pub reference: String,
+    /// Path from the root to the referencing element, by local name
+    pub location: String,
+}
+
+/// Elements whose `PartyReference` child names the party itself rather
+/// than referring to another one
+const DEFINING_PARENTS: &[&[u8]] = &[b"Party", b"MessageSender", b"MessageRecipient", b"SentOnBehalfOf"];
+
+fn is_reference_element(name: &[u8]) -> bool {
+    matches!(
+        name,
+        b"PartyReference" | b"ArtistPartyReference" | b"RightsControllerPartyReference"
+    )
+}
+
+/// Find every `PartyReference`, `ArtistPartyReference` and
+/// `RightsControllerPartyReference` in `source` whose value is not a key for
+/// which `is_defined` returns true
+pub fn unresolved_party_references(
+    source: &[u8],
+    is_defined: impl Fn(&str) -> bool,
+) -> Result<Vec<UnresolvedPartyReference>, ParseError> {
+    let mut reader = Reader::from_reader(source);
+    let mut path: Vec<String> = Vec::new();
+    let mut current: Option<String> = None;
+    let mut unresolved = Vec::new();
+
+    loop {
+        match reader
+            .read_event()
+            .map_err(|e| ParseError::XmlError(format!("XML parsing error: {}", e)))?
+        {
+            Event::Start(ref e) => {
+                let name = e.local_name();
+                let defining = path
+                    .last()
+                    .is_some_and(|parent| DEFINING_PARENTS.contains(&parent.as_bytes()));
+                if is_reference_element(name.as_ref()) && !(name.as_ref() == b"PartyReference" && defining) {
+                    current = Some(String::new());
+                }
+                path.push(String::from_utf8_lossy(name.as_ref()).into_owned());
+            }
+            Event::Text(ref e) => {
+                if let Some(text) = current.as_mut() {
+                    text.push_str(&e.unescape().unwrap_or_default());
+                }
+            }
+            Event::End(_) => {
+                if let Some(text) = current.take() {
+                    let reference = text.trim();
+                    if !reference.is_empty() && !is_defined(reference) {
+                        unresolved.push(UnresolvedPartyReference {
+                            element: path.last().cloned().unwrap_or_default(),
+                            reference: reference.to_string(),
+                            location: path.join("/"),
+                        });
+                    }
+                }
+                path.pop();
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    Ok(unresolved)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_definitions_are_not_references() {
+        let xml = br#"<Msg><PartyList><Party><PartyReference>P1</PartyReference></Party></PartyList>
+<ReleaseList><Release><DisplayArtist><ArtistPartyReference>P1</ArtistPartyReference></DisplayArtist>
+<DisplayArtist><ArtistPartyReference>P2</ArtistPartyReference></DisplayArtist></Release></ReleaseList></Msg>"#;
+
+        let unresolved = unresolved_party_references(xml, |reference| reference == "P1").unwrap();
+
+        assert_eq!(
+            unresolved,
+            vec![UnresolvedPartyReference {
+                element: "ArtistPartyReference".to_string(),
+                reference: "P2".to_string(),
+                location: "Msg/ReleaseList/Release/DisplayArtist/ArtistPartyReference".to_string(),
+            }]
+        );
+    }
+}
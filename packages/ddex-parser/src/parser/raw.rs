@@ -0,0 +1,413 @@
+// core/src/parser/raw.rs
+//! Original XML slices for releases and resources
+
+use crate::error::ParseError;
+use ddex_core::models::flat::ParsedERNMessage;
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use std::ops::Range;
+
+/// Byte range of one entity in the source document
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntitySpan {
+    /// Text of the entity's `ReleaseReference` / `ResourceReference`
+    pub reference: String,
+    /// From the opening `<` of the start tag to just past the end tag
+    pub span: Range<usize>,
+}
+
+/// Spans of every release and resource, in document order
+#[derive(Debug, Clone, Default)]
+pub struct SourceSpans {
+    pub releases: Vec<EntitySpan>,
+    pub resources: Vec<EntitySpan>,
+}
+
+struct OpenEntity {
+    is_release: bool,
+    depth: usize,
+    start: usize,
+    reference: Option<String>,
+    in_reference: bool,
+}
+
+/// Find the byte spans of releases (children of `ReleaseList`) and resources
+/// (children of `ResourceList`)
+pub fn entity_spans(source: &[u8]) -> Result<SourceSpans, ParseError> {
+    Ok(scan(source, false, |_| false)?.spans)
+}
+
+/// Where a scan stopped
+struct Scan {
+    spans: SourceSpans,
+    /// Qualified names of the elements still open where the scan stopped,
+    /// outermost first
+    open_elements: Vec<Vec<u8>>,
+    /// Qualified names of the elements enclosing the first release
+    release_parents: Vec<Vec<u8>>,
+}
+
+/// Entity spans plus what is needed to cut a standalone document for any
+/// one release with [`release_document`]
+#[derive(Debug, Clone, Default)]
+pub struct ReleaseIndex {
+    pub spans: SourceSpans,
+    /// Qualified names of the elements enclosing the releases, outermost first
+    pub release_parents: Vec<Vec<u8>>,
+}
+
+/// Index every release and resource of `source`
+pub fn release_index(source: &[u8]) -> Result<ReleaseIndex, ParseError> {
+    let scan = scan(source, false, |_| false)?;
+    Ok(ReleaseIndex {
+        spans: scan.spans,
+        release_parents: scan.release_parents,
+    })
+}
+
+/// Like [`release_index`], but an element left unclosed or closed under the
+/// wrong name inside a release or resource does not stop the scan, so a
+/// release holding such malformed XML can still be cut out
+pub fn lenient_release_index(source: &[u8]) -> Result<ReleaseIndex, ParseError> {
+    let scan = scan(source, true, |_| false)?;
+    Ok(ReleaseIndex {
+        spans: scan.spans,
+        release_parents: scan.release_parents,
+    })
+}
+
+/// Collect entity spans, stopping just after a release once `stop` holds for
+/// the releases found so far. When `lenient`, end tag names are not checked
+/// and an entity's own end tag closes it whatever is still open inside it.
+fn scan(source: &[u8], lenient: bool, stop: impl Fn(&[EntitySpan]) -> bool) -> Result<Scan, ParseError> {
+    let mut reader = Reader::from_reader(source);
+    reader.config_mut().check_end_names = !lenient;
+    let mut spans = SourceSpans::default();
+    let mut open_elements: Vec<Vec<u8>> = Vec::new();
+    let mut release_parents: Vec<Vec<u8>> = Vec::new();
+    // Depth of the ReleaseList / ResourceList currently open
+    let mut release_list = None;
+    let mut resource_list = None;
+    let mut open: Option<OpenEntity> = None;
+
+    loop {
+        let before = reader.buffer_position() as usize;
+        let event = reader
+            .read_event()
+            .map_err(|e| ParseError::XmlError(format!("XML parsing error: {}", e)))?;
+
+        match event {
+            Event::Start(ref e) => {
+                open_elements.push(e.name().as_ref().to_vec());
+                let depth = open_elements.len();
+                let name = e.local_name();
+                match open.as_mut() {
+                    Some(entity) => {
+                        let reference = if entity.is_release {
+                            b"ReleaseReference".as_slice()
+                        } else {
+                            b"ResourceReference".as_slice()
+                        };
+                        if depth == entity.depth + 1 && name.as_ref() == reference {
+                            entity.in_reference = true;
+                        }
+                    }
+                    None => match name.as_ref() {
+                        b"ReleaseList" => release_list = Some(depth),
+                        b"ResourceList" => resource_list = Some(depth),
+                        b"Release" if release_list == Some(depth - 1) => {
+                            if release_parents.is_empty() {
+                                release_parents = open_elements[..depth - 1].to_vec();
+                            }
+                            open = Some(OpenEntity::new(true, depth, before));
+                        }
+                        _ if resource_list == Some(depth - 1) => {
+                            open = Some(OpenEntity::new(false, depth, before));
+                        }
+                        _ => {}
+                    },
+                }
+            }
+            Event::Text(ref e) => {
+                if let Some(entity) = open.as_mut().filter(|entity| entity.in_reference) {
+                    let text = e.unescape().unwrap_or_default();
+                    entity.reference.get_or_insert_with(String::new).push_str(text.trim());
+                }
+            }
+            Event::End(ref e) => {
+                if let Some(entity) = open.as_ref() {
+                    let closes_entity = lenient
+                        && open_elements.len() > entity.depth
+                        && e.name().as_ref() == open_elements[entity.depth - 1];
+                    if closes_entity {
+                        open_elements.truncate(entity.depth);
+                    }
+                }
+                let depth = open_elements.len();
+                open_elements.pop();
+                if let Some(entity) = open.as_mut() {
+                    entity.in_reference = false;
+                    if depth == entity.depth {
+                        let entity = open.take().unwrap();
+                        let end = reader.buffer_position() as usize;
+                        let span = EntitySpan {
+                            reference: entity.reference.unwrap_or_default(),
+                            span: entity.start..end,
+                        };
+                        if entity.is_release {
+                            spans.releases.push(span);
+                            if stop(&spans.releases) {
+                                return Ok(Scan {
+                                    spans,
+                                    open_elements,
+                                    release_parents,
+                                });
+                            }
+                        } else {
+                            spans.resources.push(span);
+                        }
+                    }
+                } else {
+                    match e.local_name().as_ref() {
+                        b"ReleaseList" => release_list = None,
+                        b"ResourceList" => resource_list = None,
+                        _ => {}
+                    }
+                }
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    Ok(Scan {
+        spans,
+        open_elements,
+        release_parents,
+    })
+}
+
+/// Cut a standalone document holding everything before the release
+/// `reference` (header, parties), that release, and only the resources it
+/// refers to. Other releases and resources are dropped, and scanning stops
+/// once the release has been found, so later parts of the document are never
+/// read. Relies on the schema order of `ResourceList` before `ReleaseList`.
+pub fn single_release_document(source: &[u8], reference: &str) -> Result<Vec<u8>, ParseError> {
+    let scan = scan(source, false, |releases| {
+        releases.last().is_some_and(|release| release.reference == reference)
+    })?;
+    let found = scan
+        .spans
+        .releases
+        .last()
+        .is_some_and(|release| release.reference == reference);
+    if !found {
+        return Err(ParseError::ReleaseNotFound {
+            reference: reference.to_string(),
+        });
+    }
+
+    let index = ReleaseIndex {
+        spans: scan.spans,
+        release_parents: scan.open_elements,
+    };
+    release_document(source, &index, index.spans.releases.len() - 1)
+}
+
+/// Cut a standalone document holding everything before release `index`
+/// (header, parties), that release, and only the resources it refers to,
+/// ending just after the release
+pub fn release_document(
+    source: &[u8],
+    index: &ReleaseIndex,
+    release: usize,
+) -> Result<Vec<u8>, ParseError> {
+    let end = index.spans.releases[release].span.end;
+    let referenced = resource_references(&source[index.spans.releases[release].span.clone()])?;
+
+    let mut skipped: Vec<Range<usize>> = index
+        .spans
+        .resources
+        .iter()
+        .filter(|resource| resource.span.end <= end && !referenced.contains(&resource.reference))
+        .chain(&index.spans.releases[..release])
+        .map(|entity| entity.span.clone())
+        .collect();
+    skipped.sort_by_key(|range| range.start);
+
+    Ok(cut_document(source, &skipped, end, &index.release_parents))
+}
+
+/// Cut a document holding the first `max_releases` releases, everything
+/// before them and only the resources they refer to, if `source` has more
+/// releases than that. Scanning stops one release past the limit, so the
+/// rest of the document is never scanned.
+pub fn first_releases_document(source: &[u8], max_releases: usize) -> Result<Option<Vec<u8>>, ParseError> {
+    let scan = scan(source, false, |releases| releases.len() > max_releases)?;
+    let releases = &scan.spans.releases;
+    if releases.len() <= max_releases {
+        return Ok(None);
+    }
+
+    let end = match max_releases {
+        0 => releases[0].span.start,
+        _ => releases[max_releases - 1].span.end,
+    };
+    let mut referenced = Vec::new();
+    for release in &releases[..max_releases] {
+        referenced.extend(resource_references(&source[release.span.clone()])?);
+    }
+
+    let skipped: Vec<Range<usize>> = scan
+        .spans
+        .resources
+        .iter()
+        .filter(|resource| resource.span.end <= end && !referenced.contains(&resource.reference))
+        .map(|resource| resource.span.clone())
+        .collect();
+
+    Ok(Some(cut_document(source, &skipped, end, &scan.open_elements)))
+}
+
+/// `source` up to `end` without the `skipped` ranges, sorted and
+/// non-overlapping, with `parents` closed after it
+fn cut_document(source: &[u8], skipped: &[Range<usize>], end: usize, parents: &[Vec<u8>]) -> Vec<u8> {
+    let mut document = Vec::with_capacity(end);
+    let mut position = 0;
+    for range in skipped {
+        document.extend_from_slice(&source[position..range.start]);
+        position = range.end;
+    }
+    document.extend_from_slice(&source[position..end]);
+    for name in parents.iter().rev() {
+        document.extend_from_slice(b"</");
+        document.extend_from_slice(name);
+        document.push(b'>');
+    }
+
+    document
+}
+
+/// Text of every `*ResourceReference` element inside a release, covering
+/// track lists, resource groups and linked resources alike
+fn resource_references(release: &[u8]) -> Result<Vec<String>, ParseError> {
+    let mut reader = Reader::from_reader(release);
+    let mut references = Vec::new();
+    let mut in_reference = false;
+
+    loop {
+        match reader
+            .read_event()
+            .map_err(|e| ParseError::XmlError(format!("XML parsing error: {}", e)))?
+        {
+            Event::Start(ref e) => {
+                in_reference = e.local_name().as_ref().ends_with(b"ResourceReference");
+            }
+            Event::Text(ref e) if in_reference => {
+                references.push(e.unescape().unwrap_or_default().trim().to_string());
+            }
+            Event::End(_) => in_reference = false,
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    Ok(references)
+}
+
+impl OpenEntity {
+    fn new(is_release: bool, depth: usize, start: usize) -> Self {
+        Self {
+            is_release,
+            depth,
+            start,
+            reference: None,
+            in_reference: false,
+        }
+    }
+}
+
+/// Attach each release's and resource's source XML, matched by reference
+pub fn attach_raw(message: &mut ParsedERNMessage, source: &[u8]) -> Result<(), ParseError> {
+    let spans = entity_spans(source)?;
+    let raw = |spans: &[EntitySpan], reference: &str| {
+        spans
+            .iter()
+            .find(|s| s.reference == reference)
+            .map(|s| String::from_utf8_lossy(&source[s.span.clone()]).into_owned())
+    };
+
+    for release in &mut message.flat.releases {
+        release.raw = raw(&spans.releases, &release.release_id);
+    }
+    for (reference, resource) in message.flat.resources.iter_mut() {
+        resource.raw = raw(&spans.resources, reference);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spans_cover_whole_elements() {
+        let xml = br#"<Msg><ResourceList>
+  <SoundRecording><ResourceReference>A1</ResourceReference><Title>x</Title></SoundRecording>
+  <Image><ResourceReference>A2</ResourceReference></Image>
+</ResourceList><ReleaseList><Release><ReleaseReference>R1</ReleaseReference>
+<ReleaseResourceReferenceList><ReleaseResourceReference>A1</ReleaseResourceReference></ReleaseResourceReferenceList>
+</Release></ReleaseList></Msg>"#;
+        let spans = entity_spans(xml).unwrap();
+
+        let slice = |span: &EntitySpan| std::str::from_utf8(&xml[span.span.clone()]).unwrap();
+        assert_eq!(spans.resources.len(), 2);
+        assert_eq!(spans.resources[0].reference, "A1");
+        assert!(slice(&spans.resources[0]).starts_with("<SoundRecording>"));
+        assert!(slice(&spans.resources[0]).ends_with("</SoundRecording>"));
+        assert_eq!(slice(&spans.resources[1]), "<Image><ResourceReference>A2</ResourceReference></Image>");
+
+        assert_eq!(spans.releases.len(), 1);
+        assert_eq!(spans.releases[0].reference, "R1");
+        assert!(slice(&spans.releases[0]).starts_with("<Release>"));
+        assert!(slice(&spans.releases[0]).ends_with("</Release>"));
+    }
+
+    #[test]
+    fn test_lenient_index_cuts_out_malformed_releases() {
+        let xml = br#"<ern:Msg><ReleaseList><ern:Release><ReleaseReference>R1</ReleaseReference>
+<ReleaseId/></ern:Release><Release><ReleaseReference>R2</ReleaseReference><Broken></Release><Release><ReleaseReference>R3</ReleaseReference><ReleaseType>x</ReleaseTyp></Release></ReleaseList></ern:Msg>"#;
+        assert!(release_index(xml).is_err());
+
+        let index = lenient_release_index(xml).unwrap();
+        let slice = |span: &EntitySpan| std::str::from_utf8(&xml[span.span.clone()]).unwrap();
+        let releases = &index.spans.releases;
+        assert_eq!(releases.len(), 3);
+        assert!(slice(&releases[0]).starts_with("<ern:Release>"));
+        assert!(slice(&releases[0]).ends_with("</ern:Release>"));
+        assert_eq!(
+            slice(&releases[1]),
+            "<Release><ReleaseReference>R2</ReleaseReference><Broken></Release>"
+        );
+        assert_eq!(releases[2].reference, "R3");
+        assert!(slice(&releases[2]).ends_with("</ReleaseTyp></Release>"));
+    }
+
+    #[test]
+    fn test_single_release_document_keeps_only_its_resources() {
+        let xml = br#"<ern:Msg><ResourceList><SoundRecording><ResourceReference>A1</ResourceReference></SoundRecording><SoundRecording><ResourceReference>A2</ResourceReference></SoundRecording></ResourceList><ReleaseList><Release><ReleaseReference>R1</ReleaseReference><ResourceGroup><ReleaseResourceReference>A1</ReleaseResourceReference></ResourceGroup></Release><Release><ReleaseReference>R2</ReleaseReference><ReleaseResourceReferenceList><ReleaseResourceReference>A2</ReleaseResourceReference></ReleaseResourceReferenceList></Release><Release><ReleaseReference>R3</ReleaseReference></Release></ReleaseList></ern:Msg>"#;
+
+        let document = single_release_document(xml, "R2").unwrap();
+        let document = std::str::from_utf8(&document).unwrap();
+        assert_eq!(
+            document,
+            "<ern:Msg><ResourceList><SoundRecording><ResourceReference>A2</ResourceReference></SoundRecording></ResourceList><ReleaseList><Release><ReleaseReference>R2</ReleaseReference><ReleaseResourceReferenceList><ReleaseResourceReference>A2</ReleaseResourceReference></ReleaseResourceReferenceList></Release></ReleaseList></ern:Msg>"
+        );
+
+        assert!(matches!(
+            single_release_document(xml, "R9"),
+            Err(ParseError::ReleaseNotFound { reference }) if reference == "R9"
+        ));
+    }
+}
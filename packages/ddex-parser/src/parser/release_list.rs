@@ -0,0 +1,95 @@
+// core/src/parser/release_list.rs
+//! Explain why a message yielded no releases
+
+use crate::error::ParseError;
+use ddex_core::models::flat::ParseWarning;
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use std::io::BufRead;
+
+/// What the `ReleaseList` of a message without parsed releases looks like,
+/// telling a genuinely empty delivery from a parser gap
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReleaseListDiagnostic {
+    /// The message has no `ReleaseList` at all
+    MissingReleaseList,
+    /// The `ReleaseList` is present but holds no elements
+    EmptyReleaseList,
+    /// The `ReleaseList` holds `elements` child elements, none of which was
+    /// parsed into a release
+    ReleaseListPresentButNoneParsed { elements: usize },
+}
+
+impl ReleaseListDiagnostic {
+    /// Warning code for programmatic handling
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::MissingReleaseList => "MISSING_RELEASE_LIST",
+            Self::EmptyReleaseList => "EMPTY_RELEASE_LIST",
+            Self::ReleaseListPresentButNoneParsed { .. } => "RELEASE_LIST_PRESENT_BUT_NONE_PARSED",
+        }
+    }
+
+    pub fn to_warning(&self) -> ParseWarning {
+        let message = match self {
+            Self::MissingReleaseList => "Message has no ReleaseList".to_string(),
+            Self::EmptyReleaseList => "ReleaseList is present but empty".to_string(),
+            Self::ReleaseListPresentButNoneParsed { elements } => format!(
+                "ReleaseList holds {} element(s) but no release could be parsed from them",
+                elements
+            ),
+        };
+        ParseWarning {
+            code: self.code().to_string(),
+            message,
+            location: Some("ReleaseList".to_string()),
+        }
+    }
+}
+
+/// Look at the first `ReleaseList` in `reader` and count its child elements
+pub fn diagnose_release_list<R: BufRead>(reader: R) -> Result<ReleaseListDiagnostic, ParseError> {
+    let mut reader = Reader::from_reader(reader);
+    let mut buf = Vec::new();
+    let mut depth = 0usize;
+    let mut list_depth: Option<usize> = None;
+    let mut elements = 0;
+
+    loop {
+        match reader
+            .read_event_into(&mut buf)
+            .map_err(|e| ParseError::XmlError(format!("XML parsing error: {}", e)))?
+        {
+            Event::Start(ref e) => {
+                depth += 1;
+                if list_depth.is_none() && e.local_name().as_ref() == b"ReleaseList" {
+                    list_depth = Some(depth);
+                } else if list_depth.is_some_and(|list| depth == list + 1) {
+                    elements += 1;
+                }
+            }
+            Event::Empty(ref e) => {
+                if list_depth.is_none() && e.local_name().as_ref() == b"ReleaseList" {
+                    return Ok(ReleaseListDiagnostic::EmptyReleaseList);
+                } else if list_depth.is_some_and(|list| depth == list) {
+                    elements += 1;
+                }
+            }
+            Event::End(_) => {
+                if list_depth == Some(depth) {
+                    break;
+                }
+                depth = depth.saturating_sub(1);
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    Ok(match list_depth {
+        None => ReleaseListDiagnostic::MissingReleaseList,
+        Some(_) if elements == 0 => ReleaseListDiagnostic::EmptyReleaseList,
+        Some(_) => ReleaseListDiagnostic::ReleaseListPresentButNoneParsed { elements },
+    })
+}
@@ -0,0 +1,135 @@
+// core/src/parser/resource_hashes.rs
+//! Verification of the `HashSum`s a message declares against the files
+//! actually delivered with it
+
+use crate::error::ParseError;
+use crate::parser::detector::VersionDetector;
+use crate::parser::security::SecurityConfig;
+use crate::transform::graph::GraphBuilder;
+use ddex_core::models::graph::ResourceFile;
+use std::io::{Cursor, Write};
+use std::path::{Path, PathBuf};
+
+/// Outcome of checking one delivered file against its declared hash
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HashCheckStatus {
+    Match,
+    Mismatch,
+    /// The file could not be resolved or read
+    Missing,
+    /// The declared algorithm is not one the parser can compute
+    UnsupportedAlgorithm,
+}
+
+/// The check of one file of a resource
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResourceHashCheck {
+    pub resource_reference: String,
+    /// The file as the message locates it: its URI, or path and name
+    pub file: String,
+    /// e.g. "MD5", "SHA-256"
+    pub algorithm: String,
+    pub expected: String,
+    /// Hex digest of the resolved file, when it could be read and hashed
+    pub actual: Option<String>,
+    pub status: HashCheckStatus,
+}
+
+/// Check every file in `buffer` that declares a `HashSum`, in document
+/// order. `resolve` maps the file's location as the message gives it to a
+/// local path; a file it cannot resolve is reported missing.
+pub fn verify_resource_hashes<F>(
+    buffer: &[u8],
+    config: &SecurityConfig,
+    mut resolve: F,
+) -> Result<Vec<ResourceHashCheck>, ParseError>
+where
+    F: FnMut(&str) -> Option<PathBuf>,
+{
+    let version = VersionDetector::detect(Cursor::new(buffer))?;
+    let message = GraphBuilder::new(version)
+        .build_from_xml_with_security_config(Cursor::new(buffer), config)?;
+
+    let mut checks = Vec::new();
+    for resource in &message.resources {
+        let files = resource
+            .technical_details
+            .iter()
+            .flat_map(|details| &details.files);
+        for file in files {
+            let Some(hash_sum) = &file.hash_sum else {
+                continue;
+            };
+            let location = location(file);
+            let expected = hash_sum.value.trim().to_string();
+            let (actual, status) = match resolve(&location) {
+                Some(path) => match digest_file(&hash_sum.algorithm, &path) {
+                    Some(Ok(actual)) => {
+                        let status = if actual.eq_ignore_ascii_case(&expected) {
+                            HashCheckStatus::Match
+                        } else {
+                            HashCheckStatus::Mismatch
+                        };
+                        (Some(actual), status)
+                    }
+                    Some(Err(_)) => (None, HashCheckStatus::Missing),
+                    None => (None, HashCheckStatus::UnsupportedAlgorithm),
+                },
+                None => (None, HashCheckStatus::Missing),
+            };
+
+            checks.push(ResourceHashCheck {
+                resource_reference: resource.resource_reference.clone(),
+                file: location,
+                algorithm: hash_sum.algorithm.clone(),
+                expected,
+                actual,
+                status,
+            });
+        }
+    }
+    Ok(checks)
+}
+
+/// Where the message says the file is: its URI, or its path and name as
+/// ERN 3.8 gives them
+fn location(file: &ResourceFile) -> String {
+    if let Some(uri) = &file.uri {
+        return uri.clone();
+    }
+    match (&file.file_path, &file.file_name) {
+        (Some(path), Some(name)) => Path::new(path).join(name).to_string_lossy().into_owned(),
+        (Some(path), None) => path.clone(),
+        (None, Some(name)) => name.clone(),
+        (None, None) => String::new(),
+    }
+}
+
+/// Hex digest of the file at `path` under `algorithm`, or `None` if the
+/// algorithm is not supported
+fn digest_file(algorithm: &str, path: &Path) -> Option<std::io::Result<String>> {
+    let normalized: String = algorithm
+        .chars()
+        .filter(|c| !matches!(c, '-' | '_' | ' '))
+        .collect::<String>()
+        .to_ascii_uppercase();
+    Some(match normalized.as_str() {
+        "MD5" => digest_with(md5::Md5::default(), path),
+        "SHA1" => digest_with(sha1::Sha1::default(), path),
+        "SHA224" => digest_with(sha2::Sha224::default(), path),
+        "SHA256" => digest_with(sha2::Sha256::default(), path),
+        "SHA384" => digest_with(sha2::Sha384::default(), path),
+        "SHA512" => digest_with(sha2::Sha512::default(), path),
+        _ => return None,
+    })
+}
+
+fn digest_with<D: sha2::Digest + Write>(mut hasher: D, path: &Path) -> std::io::Result<String> {
+    let mut file = std::fs::File::open(path)?;
+    std::io::copy(&mut file, &mut hasher)?;
+    Ok(hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect())
+}
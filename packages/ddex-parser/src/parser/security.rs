@@ -1,3 +1,7 @@
+use crate::error::ParseError;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use std::io::BufRead;
 use std::time::Duration;
 
 /// Security configuration for XML parsing
@@ -12,6 +16,9 @@ pub struct SecurityConfig {
     // Size limits
     pub max_element_depth: usize,
     pub max_attribute_size: usize,
+    pub max_attributes_per_element: usize,
+    /// Longest element or attribute name, prefix included, in bytes
+    pub max_name_length: usize,
     pub max_text_size: usize,
     pub max_file_size: usize,
 
@@ -43,6 +50,8 @@ impl SecurityConfig {
             max_entity_depth: 10,       // Reduced from 20 for better protection
             max_element_depth: 100,     // Keep at 100, this will block deep nesting attacks
             max_attribute_size: 100 * 1024, // 100KB
+            max_attributes_per_element: 100,
+            max_name_length: 256,
             max_text_size: 1024 * 1024, // 1MB
             max_file_size: 1024 * 1024 * 1024, // 1GB
             parse_timeout: Duration::from_secs(30),
@@ -68,4 +77,181 @@ impl SecurityConfig {
             ..Self::strict()
         }
     }
+
+    /// Check an element's name and attributes against the configured limits.
+    /// Stops reading attributes as soon as a limit is passed.
+    pub fn check_element(&self, element: &BytesStart) -> Result<(), ParseError> {
+        let name = element.name();
+        if name.as_ref().len() > self.max_name_length {
+            return Err(ParseError::SecurityViolation {
+                message: format!(
+                    "Element name of {} bytes exceeds maximum allowed {}",
+                    name.as_ref().len(),
+                    self.max_name_length
+                ),
+            });
+        }
+
+        for (count, attribute) in element.attributes().with_checks(false).enumerate() {
+            if count >= self.max_attributes_per_element {
+                return Err(ParseError::SecurityViolation {
+                    message: format!(
+                        "Element <{}> has more than the maximum allowed {} attributes",
+                        String::from_utf8_lossy(name.as_ref()),
+                        self.max_attributes_per_element
+                    ),
+                });
+            }
+            let key_length = attribute.map_or(0, |a| a.key.as_ref().len());
+            if key_length > self.max_name_length {
+                return Err(ParseError::SecurityViolation {
+                    message: format!(
+                        "Attribute name of {} bytes on <{}> exceeds maximum allowed {}",
+                        key_length,
+                        String::from_utf8_lossy(name.as_ref()),
+                        self.max_name_length
+                    ),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Check a `<!DOCTYPE>`'s content against the DTD settings.
+    ///
+    /// With `disable_external_entities`, an external DTD subset or any
+    /// external or parameter entity declaration is rejected by name. Turning
+    /// it off (and `disable_dtd` with it) lets such documents through for
+    /// trusted pipelines, but no entity is ever fetched or expanded:
+    /// references to them are left unresolved.
+    pub fn check_doctype(&self, doctype: &[u8]) -> Result<(), ParseError> {
+        let doctype = String::from_utf8_lossy(doctype);
+
+        if self.disable_external_entities {
+            let head = doctype.split('[').next().unwrap_or_default();
+            if let [root, keyword, ids @ ..] = dtd_tokens(head).as_slice() {
+                if let Some(system_id) = external_id(keyword, ids) {
+                    return Err(ParseError::SecurityViolation {
+                        message: format!(
+                            "External DTD {} for <{}> is not allowed: external entities are disabled",
+                            system_id, root
+                        ),
+                    });
+                }
+            }
+
+            for declaration in doctype.split("<!ENTITY").skip(1) {
+                let tokens = dtd_tokens(declaration);
+                let (parameter, rest) = match tokens.split_first() {
+                    Some((first, rest)) if first == "%" => (true, rest),
+                    _ => (false, tokens.as_slice()),
+                };
+                let Some((name, definition)) = rest.split_first() else {
+                    continue;
+                };
+                let external = definition
+                    .split_first()
+                    .and_then(|(keyword, ids)| external_id(keyword, ids));
+
+                let message = match (parameter, external) {
+                    (true, _) => format!(
+                        "Parameter entity '%{}' is not allowed: external entities are disabled",
+                        name
+                    ),
+                    (false, Some(system_id)) => format!(
+                        "External entity '{}' ({}) is not allowed: external entities are disabled",
+                        name, system_id
+                    ),
+                    (false, None) => continue,
+                };
+                return Err(ParseError::SecurityViolation { message });
+            }
+        }
+
+        if self.disable_dtd {
+            return Err(ParseError::SecurityViolation {
+                message: "DTD declarations are disabled for security".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Split DTD markup into names and quoted literals (quotes kept), up to the
+/// `>` closing the first declaration
+fn dtd_tokens(markup: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut chars = markup.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        match c {
+            '>' => break,
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            '"' | '\'' => {
+                let mut literal = String::new();
+                literal.push(c);
+                chars.next();
+                for next in chars.by_ref() {
+                    literal.push(next);
+                    if next == c {
+                        break;
+                    }
+                }
+                tokens.push(literal);
+            }
+            '%' => {
+                tokens.push("%".to_string());
+                chars.next();
+            }
+            _ => {
+                let mut name = String::new();
+                while let Some(&next) = chars.peek() {
+                    if next.is_whitespace() || matches!(next, '>' | '"' | '\'' | '[') {
+                        break;
+                    }
+                    name.push(next);
+                    chars.next();
+                }
+                if name.is_empty() {
+                    chars.next();
+                } else {
+                    tokens.push(name);
+                }
+            }
+        }
+    }
+
+    tokens
+}
+
+/// The system literal of a `SYSTEM "uri"` or `PUBLIC "id" "uri"` external ID
+fn external_id(keyword: &str, ids: &[String]) -> Option<String> {
+    match keyword {
+        "SYSTEM" => Some(format!("SYSTEM {}", ids.first().map_or("", String::as_str))),
+        "PUBLIC" => Some(format!("PUBLIC {}", ids.join(" "))),
+        _ => None,
+    }
+}
+
+/// Check a document's `<!DOCTYPE>`, if any, reading no further than its
+/// root element
+pub fn check_prolog<R: BufRead>(reader: R, config: &SecurityConfig) -> Result<(), ParseError> {
+    let mut reader = Reader::from_reader(reader);
+    let mut buf = Vec::new();
+
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::DocType(ref e)) => config.check_doctype(e)?,
+            Ok(Event::Start(_)) | Ok(Event::Empty(_)) | Ok(Event::Eof) => break,
+            Ok(_) => {}
+            Err(e) => return Err(ParseError::XmlError(format!("XML parsing error: {}", e))),
+        }
+        buf.clear();
+    }
+
+    Ok(())
 }
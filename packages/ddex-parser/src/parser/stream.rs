@@ -3,11 +3,13 @@
 
 use crate::error::ParseError;
 use crate::parser::ParseOptions;
+use crate::parser::whitespace::WhitespaceMode;
 use crate::transform::flatten::Flattener;
 use crate::utf8_utils;
 use ddex_core::models::flat::ParsedERNMessage;
-use ddex_core::models::graph::{Deal, ERNMessage, MessageHeader, Party, Release, Resource};
+use ddex_core::models::graph::{Deal, ERNMessage, MessageHeader, MessageType, Party, Release, Resource};
 use ddex_core::models::versions::ERNVersion;
+use indexmap::IndexMap;
 use quick_xml::events::Event;
 use quick_xml::Reader;
 use std::io::BufRead;
@@ -40,6 +42,9 @@ pub struct StreamingParser<R: BufRead> {
     buffer: Vec<u8>,
     current_depth: usize,
     max_depth: usize,
+    whitespace: WhitespaceMode,
+    /// Kind of message, from the root element
+    message_type: MessageType,
 }
 
 impl<R: BufRead> StreamingParser<R> {
@@ -74,6 +79,8 @@ impl<R: BufRead> StreamingParser<R> {
             buffer: Vec::with_capacity(8192),
             current_depth: 0,
             max_depth: security_config.max_element_depth,
+            whitespace: WhitespaceMode::default(),
+            message_type: MessageType::NewReleaseMessage,
         }
     }
 
@@ -95,6 +102,15 @@ impl<R: BufRead> StreamingParser<R> {
         self
     }
 
+    /// Set how whitespace in titles and names is handled
+    pub fn with_whitespace(mut self, whitespace: WhitespaceMode) -> Self {
+        self.reader
+            .config_mut()
+            .trim_text(whitespace != WhitespaceMode::Preserve);
+        self.whitespace = whitespace;
+        self
+    }
+
     fn update_progress(&mut self) {
         if let Some(ref mut callback) = self.progress_callback {
             let progress = ParseProgress {
@@ -132,6 +148,11 @@ impl<R: BufRead> StreamingParser<R> {
 
                     if e.name().as_ref() == b"MessageHeader" {
                         return self.parse_message_header_element();
+                    } else if let Some(message_type) = MessageType::from_root_element(e.local_name().as_ref())
+                        .filter(|_| self.current_depth == 1)
+                    {
+                        // The header is inside the root, so read on into it
+                        self.message_type = message_type;
                     } else {
                         self.skip_element()?;
                     }
@@ -152,10 +173,10 @@ impl<R: BufRead> StreamingParser<R> {
     }
 
     fn parse_message_header_element(&mut self) -> Result<MessageHeader, ParseError> {
-        use ddex_core::models::graph::{MessageRecipient, MessageSender, MessageType};
+        use ddex_core::models::graph::{MessageRecipient, MessageSender};
 
         let mut message_id = String::new();
-        let message_type = MessageType::NewReleaseMessage;
+        let message_type = self.message_type.clone();
         let mut created_date_time = chrono::Utc::now();
         let mut sender = MessageSender {
             party_id: Vec::new(),
@@ -253,7 +274,7 @@ impl<R: BufRead> StreamingParser<R> {
                         });
                     }
                     b"PartyName" => {
-                        let text = self.read_text_element()?;
+                        let text = self.read_free_text_element()?;
                         sender.party_name.push(LocalizedString::new(text));
                     }
                     _ => {
@@ -299,7 +320,7 @@ impl<R: BufRead> StreamingParser<R> {
                         });
                     }
                     b"PartyName" => {
-                        let text = self.read_text_element()?;
+                        let text = self.read_free_text_element()?;
                         recipient.party_name.push(LocalizedString::new(text));
                     }
                     _ => {
@@ -337,8 +358,18 @@ impl<R: BufRead> StreamingParser<R> {
         DealIterator::new(self)
     }
 
-    /// Helper to read text content of current element
+    /// Helper to read text content of current element, trimmed
     fn read_text_element(&mut self) -> Result<String, ParseError> {
+        Ok(self.read_raw_text_element()?.trim().to_string())
+    }
+
+    /// Read free text (titles, names), applying the whitespace mode
+    fn read_free_text_element(&mut self) -> Result<String, ParseError> {
+        let text = self.read_raw_text_element()?;
+        Ok(self.whitespace.apply(&text))
+    }
+
+    fn read_raw_text_element(&mut self) -> Result<String, ParseError> {
         let mut text = String::new();
         self.buffer.clear();
 
@@ -478,6 +509,12 @@ impl<'a, R: BufRead> ReleaseIterator<'a, R> {
             extensions: None,
             attributes: None,
             comments: None,
+            descriptions: Vec::new(),
+            reference_title: Vec::new(),
+            display_title: Vec::new(),
+            related_releases: Vec::new(),
+            label_name: None,
+            visibility: None,
         };
 
         self.parser.buffer.clear();
@@ -488,7 +525,7 @@ impl<'a, R: BufRead> ReleaseIterator<'a, R> {
                         release.release_reference = self.parser.read_text_element()?;
                     }
                     b"ReferenceTitle" | b"Title" => {
-                        let text = self.parser.read_text_element()?;
+                        let text = self.parser.read_free_text_element()?;
                         release.release_title.push(LocalizedString::new(text));
                     }
                     _ => {
@@ -622,7 +659,8 @@ pub fn parse_streaming<R: BufRead>(
 ) -> Result<ParsedERNMessage, ParseError> {
     let mut parser = StreamingParser::new_with_security_config(reader, version, security_config)
         .with_chunk_size(options.chunk_size)
-        .with_max_memory(options.max_memory);
+        .with_max_memory(options.max_memory)
+        .with_whitespace(options.whitespace);
 
     // Parse header first
     let message_header = parser.parse_header()?;
@@ -664,6 +702,7 @@ pub fn parse_streaming<R: BufRead>(
         resources,
         releases,
         deals,
+        purged_releases: Vec::new(),
         version,
         profile: None,
         message_audit_trail: None,
@@ -680,5 +719,11 @@ pub fn parse_streaming<R: BufRead>(
         graph,
         flat: flat?,
         extensions: None,
+        warnings: Vec::new(),
+        namespaces: IndexMap::new(),
+        namespace_aliases: IndexMap::new(),
+        truncated: false,
+        header_extensions: IndexMap::new(),
+        document_stats: Default::default(),
     })
 }
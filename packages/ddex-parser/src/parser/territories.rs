@@ -0,0 +1,155 @@
+// core/src/parser/territories.rs
+//! Mapping of non-standard deal territory codes onto ISO 3166 ones
+
+use crate::error::ParseError;
+use ddex_core::models::flat::{ParseWarning, ParsedERNMessage};
+use std::collections::HashMap;
+
+/// ISO 3166-1 alpha-2 codes, the territory codes DDEX deals use
+const ISO_3166_CODES: &str = "AD AE AF AG AI AL AM AO AQ AR AS AT AU AW AX AZ BA BB BD BE BF BG BH BI \
+    BJ BL BM BN BO BQ BR BS BT BV BW BY BZ CA CC CD CF CG CH CI CK CL CM CN CO CR CU CV CW CX CY CZ \
+    DE DJ DK DM DO DZ EC EE EG EH ER ES ET FI FJ FK FM FO FR GA GB GD GE GF GG GH GI GL GM GN GP GQ \
+    GR GS GT GU GW GY HK HM HN HR HT HU ID IE IL IM IN IO IQ IR IS IT JE JM JO JP KE KG KH KI KM KN \
+    KP KR KW KY KZ LA LB LC LI LK LR LS LT LU LV LY MA MC MD ME MF MG MH MK ML MM MN MO MP MQ MR MS \
+    MT MU MV MW MX MY MZ NA NC NE NF NG NI NL NO NP NR NU NZ OM PA PE PF PG PH PK PL PM PN PR PS PT \
+    PW PY QA RE RO RS RU RW SA SB SC SD SE SG SH SI SJ SK SL SM SN SO SR SS ST SV SX SY SZ TC TD TF \
+    TG TH TJ TK TL TM TN TO TR TT TV TW TZ UA UG UM US UY UZ VA VC VE VG VI VN VU WF WS YE YT ZA ZM ZW";
+
+/// Member states of the European Union
+const EU_MEMBERS: &[&str] = &[
+    "AT", "BE", "BG", "CY", "CZ", "DE", "DK", "EE", "ES", "FI", "FR", "GR", "HR", "HU", "IE", "IT",
+    "LT", "LU", "LV", "MT", "NL", "PL", "PT", "RO", "SE", "SI", "SK",
+];
+
+/// Territory code a deal uses to cover every territory
+const WORLDWIDE: &str = "Worldwide";
+
+/// Non-standard territory codes and the ISO 3166 codes each stands for.
+/// The defaults map `UK` to `GB` and expand `EU` to its member states; a
+/// partner's own codes, such as `ROW`, can be added with [`with_alias`].
+///
+/// [`with_alias`]: TerritoryAliases::with_alias
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TerritoryAliases {
+    aliases: HashMap<String, Vec<String>>,
+}
+
+impl Default for TerritoryAliases {
+    fn default() -> Self {
+        Self::empty()
+            .with_alias("UK", ["GB"])
+            .with_alias("EU", EU_MEMBERS.iter().copied())
+    }
+}
+
+impl TerritoryAliases {
+    /// No aliases; only ISO 3166 codes and `Worldwide` are known
+    pub fn empty() -> Self {
+        Self {
+            aliases: HashMap::new(),
+        }
+    }
+
+    /// Map `alias` onto `codes`, replacing any earlier mapping of it
+    pub fn with_alias<S: Into<String>>(
+        mut self,
+        alias: &str,
+        codes: impl IntoIterator<Item = S>,
+    ) -> Self {
+        self.aliases.insert(
+            alias.to_ascii_uppercase(),
+            codes.into_iter().map(Into::into).collect(),
+        );
+        self
+    }
+
+    /// Codes `code` stands for, if it is an alias
+    pub fn resolve(&self, code: &str) -> Option<&[String]> {
+        self.aliases
+            .get(&code.to_ascii_uppercase())
+            .map(Vec::as_slice)
+    }
+
+    /// Rewrite the aliased territory codes of every deal, in both the graph
+    /// and the flattened deals, warning once per remapped code. In `strict`
+    /// mode a code that is neither known nor aliased is an error; otherwise
+    /// it is kept as written.
+    pub fn normalize(
+        &self,
+        message: &mut ParsedERNMessage,
+        strict: bool,
+    ) -> Result<Vec<ParseWarning>, ParseError> {
+        let mut warnings = Vec::new();
+        for (idx, deal) in message.graph.deals.iter_mut().enumerate() {
+            let deal_id = deal
+                .deal_reference
+                .clone()
+                .unwrap_or_else(|| format!("DEAL_AUTO_{}", idx + 1));
+            let terms = &mut deal.deal_terms;
+            terms.territory_code = self.normalize_codes(
+                &terms.territory_code,
+                &format!("Deal[{}]/TerritoryCode", deal_id),
+                strict,
+                &mut warnings,
+            )?;
+            terms.excluded_territory_code = self.normalize_codes(
+                &terms.excluded_territory_code,
+                &format!("Deal[{}]/ExcludedTerritoryCode", deal_id),
+                strict,
+                &mut warnings,
+            )?;
+
+            // Flattened deals are made one per graph deal, in order
+            if let Some(flat) = message.flat.deals.get_mut(idx) {
+                flat.territories.included = terms.territory_code.clone();
+                flat.territories.excluded = terms.excluded_territory_code.clone();
+            }
+        }
+        Ok(warnings)
+    }
+
+    fn normalize_codes(
+        &self,
+        codes: &[String],
+        location: &str,
+        strict: bool,
+        warnings: &mut Vec<ParseWarning>,
+    ) -> Result<Vec<String>, ParseError> {
+        let mut normalized: Vec<String> = Vec::with_capacity(codes.len());
+        let mut push = |code: &str| {
+            if !normalized.iter().any(|c| c == code) {
+                normalized.push(code.to_string());
+            }
+        };
+
+        for code in codes {
+            if is_known_territory(code) {
+                push(code);
+            } else if let Some(mapped) = self.resolve(code) {
+                warnings.push(ParseWarning {
+                    code: "TERRITORY_CODE_NORMALIZED".to_string(),
+                    message: format!(
+                        "Territory code '{}' is not an ISO 3166 code; read as {}",
+                        code,
+                        mapped.join(", ")
+                    ),
+                    location: Some(location.to_string()),
+                });
+                mapped.iter().for_each(|c| push(c));
+            } else if strict {
+                return Err(ParseError::InvalidValue {
+                    field: location.to_string(),
+                    value: code.clone(),
+                });
+            } else {
+                push(code);
+            }
+        }
+        Ok(normalized)
+    }
+}
+
+/// Whether `code` is an ISO 3166-1 alpha-2 code or `Worldwide`
+pub fn is_known_territory(code: &str) -> bool {
+    code == WORLDWIDE || (code.len() == 2 && ISO_3166_CODES.split_whitespace().any(|c| c == code))
+}
@@ -0,0 +1,55 @@
+// core/src/parser/track_count.rs
+//! Check declared track counts against the recordings releases reference
+
+use ddex_core::models::flat::{ParseWarning, ParsedRelease};
+use ddex_core::models::graph::{Resource, ResourceType};
+use std::collections::HashMap;
+
+/// Type of every resource in the graph, by its `ResourceReference`
+pub fn resource_types(resources: &[Resource]) -> HashMap<&str, &ResourceType> {
+    resources
+        .iter()
+        .map(|resource| {
+            (
+                resource.resource_reference.as_str(),
+                &resource.resource_type,
+            )
+        })
+        .collect()
+}
+
+/// Warn about releases whose `track_count` is more than the sound recordings
+/// or videos they reference, usually because a listed track is missing from
+/// the `ResourceList`
+pub fn track_count_mismatches(
+    releases: &[ParsedRelease],
+    resource_types: &HashMap<&str, &ResourceType>,
+) -> Vec<ParseWarning> {
+    let mut warnings = Vec::new();
+    for release in releases {
+        let referenced = release
+            .tracks
+            .iter()
+            .filter(|track| {
+                matches!(
+                    resource_types.get(track.track_id.as_str()),
+                    Some(ResourceType::SoundRecording | ResourceType::Video)
+                )
+            })
+            .count();
+        if release.track_count != referenced {
+            warnings.push(ParseWarning {
+                code: "TRACK_COUNT_MISMATCH".to_string(),
+                message: format!(
+                    "Release '{}' declares {} tracks but references only {} sound recordings or videos",
+                    release.release_id, release.track_count, referenced
+                ),
+                location: Some(format!(
+                    "Release[{}]/ReleaseResourceReferenceList",
+                    release.release_id
+                )),
+            });
+        }
+    }
+    warnings
+}
@@ -0,0 +1,286 @@
+// core/src/parser/unknown_elements.rs
+//! Report non-standard children of standard elements
+
+use crate::error::ParseError;
+use ddex_core::models::flat::ParseWarning;
+use quick_xml::events::Event;
+use quick_xml::name::ResolveResult;
+use quick_xml::NsReader;
+use std::io::BufRead;
+
+/// Children of `Release` and `TrackRelease` across ERN 3.8, 4.2 and 4.3
+const RELEASE_CHILDREN: &[&str] = &[
+    "ReleaseReference",
+    "ReleaseType",
+    "ReleaseId",
+    "ReferenceTitle",
+    "ReleaseTitle",
+    "Title",
+    "DisplayTitleText",
+    "DisplayTitle",
+    "AdditionalTitle",
+    "DisplayArtistName",
+    "DisplayArtist",
+    "ReleaseLabelReference",
+    "LabelName",
+    "AdministratingRecordCompany",
+    "ReleaseResourceReferenceList",
+    "ReleaseResourceReference",
+    "LinkedReleaseResourceReference",
+    "ReleaseCollectionReferenceList",
+    "ReleaseDetailsByTerritory",
+    "ResourceGroup",
+    "ResourceOmissionReason",
+    "LanguageOfPerformance",
+    "Genre",
+    "ParentalWarningType",
+    "AvRating",
+    "Duration",
+    "RightsAgreementId",
+    "PLine",
+    "CLine",
+    "CourtesyLine",
+    "ReleaseDate",
+    "OriginalReleaseDate",
+    "GlobalReleaseDate",
+    "GlobalOriginalReleaseDate",
+    "ReleaseVisibilityReference",
+    "RelatedRelease",
+    "RelatedResource",
+    "ExternalResourceLink",
+    "IsMainRelease",
+    "IsCompilation",
+    "IsMultiArtistCompilation",
+    "Keywords",
+    "Synopsis",
+    "MarketingComment",
+    "EditorialDescription",
+    "AdditionalText",
+    "Raga",
+    "Tala",
+    "Deity",
+    "HiResMusicDescription",
+];
+
+/// Children of `SoundRecording` across ERN 3.8, 4.2 and 4.3
+const SOUND_RECORDING_CHILDREN: &[&str] = &[
+    "SoundRecordingType",
+    "Type",
+    "IsArtistRelated",
+    "SoundRecordingId",
+    "ResourceId",
+    "IndirectSoundRecordingId",
+    "ResourceReference",
+    "ReferenceTitle",
+    "Title",
+    "DisplayTitleText",
+    "DisplayTitle",
+    "AdditionalTitle",
+    "VersionType",
+    "DisplayArtistName",
+    "DisplayArtist",
+    "DisplayConductor",
+    "Contributor",
+    "ResourceContributor",
+    "IndirectResourceContributor",
+    "Character",
+    "ResourceRightsController",
+    "WorkRightsController",
+    "PLine",
+    "CourtesyLine",
+    "Duration",
+    "CreationDate",
+    "MasteredDate",
+    "RemasteredDate",
+    "FirstPublicationDate",
+    "ParentalWarningType",
+    "AvRating",
+    "RelatedRelease",
+    "RelatedResource",
+    "CompositeMusicalWorkType",
+    "IsCover",
+    "IsInstrumental",
+    "ContainsHiddenContent",
+    "IsRemastered",
+    "IsHiResMusic",
+    "IsMedley",
+    "IsPotpourri",
+    "IsBackground",
+    "IsHiddenResource",
+    "IsBonusResource",
+    "IsComputerGenerated",
+    "NoSilenceBefore",
+    "NoSilenceAfter",
+    "PerformerInformationRequired",
+    "DisableCrossfade",
+    "DisableSearch",
+    "DisplayCredits",
+    "LanguageOfPerformance",
+    "LanguageOfDubbing",
+    "SubTitleLanguage",
+    "RecordingMode",
+    "Raga",
+    "Tala",
+    "Deity",
+    "AudioChapterReference",
+    "TechnicalDetails",
+    "TechnicalSoundRecordingDetails",
+    "SoundRecordingEdition",
+    "SoundRecordingDetailsByTerritory",
+    "SoundRecordingCollectionReferenceList",
+    "ResourceMusicalWorkReferenceList",
+    "ResourceContainedResourceReferenceList",
+    "RightsAgreementId",
+    "Genre",
+    "Keywords",
+    "Synopsis",
+    "MarketingComment",
+    "EditorialDescription",
+    "AdditionalText",
+];
+
+/// Children of `MessageHeader` across ERN 3.8, 4.2 and 4.3
+const MESSAGE_HEADER_CHILDREN: &[&str] = &[
+    "MessageThreadId",
+    "MessageId",
+    "MessageFileName",
+    "MessageSender",
+    "SentOnBehalfOf",
+    "MessageRecipient",
+    "MessageCreatedDateTime",
+    "MessageAuditTrail",
+    "Comment",
+    "MessageControlType",
+];
+
+/// Children of a `PartyList` `Party` across ERN 4.2 and 4.3
+const PARTY_CHILDREN: &[&str] = &[
+    "PartyReference",
+    "PartyId",
+    "PartyName",
+    "TradingName",
+    "Affiliation",
+    "RelatedParty",
+    "ArtistProfilePage",
+    "IsniAbbreviation",
+];
+
+/// Children of `ReleaseDeal` across ERN 3.8, 4.2 and 4.3
+const RELEASE_DEAL_CHILDREN: &[&str] = &["DealReleaseReference", "Deal", "EffectiveDate"];
+
+/// The children `parent` may have, for the standard elements checked
+pub(crate) fn known_children(parent: &str) -> Option<&'static [&'static str]> {
+    match parent {
+        "Release" | "TrackRelease" => Some(RELEASE_CHILDREN),
+        "SoundRecording" => Some(SOUND_RECORDING_CHILDREN),
+        "MessageHeader" => Some(MESSAGE_HEADER_CHILDREN),
+        "Party" => Some(PARTY_CHILDREN),
+        "ReleaseDeal" => Some(RELEASE_DEAL_CHILDREN),
+        _ => None,
+    }
+}
+
+/// A DDEX-namespace element the standard does not allow where it was found
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownElement {
+    pub name: String,
+    /// Slash-separated local names from the root down to the parent
+    pub parent_path: String,
+}
+
+impl UnknownElement {
+    pub fn to_warning(&self) -> ParseWarning {
+        let parent = self.parent_path.rsplit('/').next().unwrap_or_default();
+        ParseWarning {
+            code: "UNKNOWN_ELEMENT".to_string(),
+            message: format!(
+                "Unexpected element '{}' under '{}'; it was ignored",
+                self.name, parent
+            ),
+            location: Some(self.parent_path.clone()),
+        }
+    }
+}
+
+/// Children of standard parents that are neither allowed there nor in an
+/// extension namespace, once per element name and parent path, gathered from
+/// the elements of a pass that reads the document for other reasons
+#[derive(Debug, Clone, Default)]
+pub struct UnknownElementScan {
+    /// Local name of each open element, with whether it is a DDEX element
+    stack: Vec<(String, bool)>,
+    unknown: Vec<UnknownElement>,
+}
+
+impl UnknownElementScan {
+    /// Note an element's start tag. `standard` says whether it is in a DDEX
+    /// namespace; elements in no namespace count as standard, as ERN 3.8
+    /// children often are.
+    pub fn start(&mut self, name: &str, standard: bool, empty: bool) {
+        if let Some((parent, true)) = self.stack.last() {
+            let allowed = known_children(parent);
+            if standard && allowed.is_some_and(|allowed| !allowed.contains(&name)) {
+                let parent_path = self
+                    .stack
+                    .iter()
+                    .map(|(name, _)| name.as_str())
+                    .collect::<Vec<_>>()
+                    .join("/");
+                let element = UnknownElement { name: name.to_string(), parent_path };
+                if !self.unknown.contains(&element) {
+                    self.unknown.push(element);
+                }
+            }
+        }
+
+        if !empty {
+            self.stack.push((name.to_string(), standard));
+        }
+    }
+
+    /// Note an element's end tag
+    pub fn end(&mut self) {
+        self.stack.pop();
+    }
+
+    pub fn finish(self) -> Vec<UnknownElement> {
+        self.unknown
+    }
+}
+
+/// The unknown elements of a document, as [`UnknownElementScan`] finds them,
+/// in a pass of their own
+pub fn unknown_elements<R: BufRead>(reader: R) -> Result<Vec<UnknownElement>, ParseError> {
+    let mut reader = NsReader::from_reader(reader);
+    let mut buf = Vec::new();
+    let mut scan = UnknownElementScan::default();
+
+    loop {
+        let (namespace, event) = reader
+            .read_resolved_event_into(&mut buf)
+            .map_err(|e| ParseError::XmlError(format!("XML parsing error: {}", e)))?;
+
+        match event {
+            Event::Start(ref e) | Event::Empty(ref e) => {
+                let name = String::from_utf8_lossy(e.local_name().as_ref()).into_owned();
+                let standard = match namespace {
+                    ResolveResult::Unbound => true,
+                    ResolveResult::Bound(ns) => is_ddex_namespace(ns.as_ref()),
+                    ResolveResult::Unknown(_) => false,
+                };
+                scan.start(&name, standard, matches!(event, Event::Empty(_)));
+            }
+            Event::End(_) => scan.end(),
+            Event::Eof => break,
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    Ok(scan.finish())
+}
+
+/// Whether a namespace URI is one of DDEX's own
+pub(crate) fn is_ddex_namespace(uri: &[u8]) -> bool {
+    uri.windows(8).any(|w| w == b"ddex.net")
+}
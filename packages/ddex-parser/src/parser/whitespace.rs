@@ -0,0 +1,62 @@
+// core/src/parser/whitespace.rs
+//! Whitespace handling for extracted text
+
+use std::str::FromStr;
+
+/// How surrounding and internal whitespace in free text is treated.
+///
+/// Applies to titles, names and descriptions. Identifiers, codes, dates and
+/// references are always trimmed, since surrounding whitespace is never part
+/// of their value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WhitespaceMode {
+    /// Strip leading and trailing whitespace
+    #[default]
+    Trim,
+    /// Trim, then replace each internal run of whitespace with one space
+    Collapse,
+    /// Keep the text exactly as written
+    Preserve,
+}
+
+impl WhitespaceMode {
+    /// Apply this mode to `text`
+    pub fn apply(self, text: &str) -> String {
+        match self {
+            WhitespaceMode::Trim => text.trim().to_string(),
+            WhitespaceMode::Collapse => text.split_whitespace().collect::<Vec<_>>().join(" "),
+            WhitespaceMode::Preserve => text.to_string(),
+        }
+    }
+}
+
+impl FromStr for WhitespaceMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "trim" => Ok(WhitespaceMode::Trim),
+            "collapse" => Ok(WhitespaceMode::Collapse),
+            "preserve" => Ok(WhitespaceMode::Preserve),
+            other => Err(format!(
+                "Unknown whitespace mode '{}'; expected trim, collapse or preserve",
+                other
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_modes() {
+        let text = "\n    Bohemian   Rhapsody\n  ";
+        assert_eq!(WhitespaceMode::Trim.apply(text), "Bohemian   Rhapsody");
+        assert_eq!(WhitespaceMode::Collapse.apply(text), "Bohemian Rhapsody");
+        assert_eq!(WhitespaceMode::Preserve.apply(text), text);
+        assert_eq!("Collapse".parse(), Ok(WhitespaceMode::Collapse));
+        assert!("squash".parse::<WhitespaceMode>().is_err());
+    }
+}
@@ -17,9 +17,9 @@ use std::time::Instant;
 #[derive(Debug, Clone)]
 pub enum AlignedStreamingElement {
     Header(Box<MessageHeader>),
-    Release(Release),
-    Resource(Resource),
-    Party(Party),
+    Release(Box<Release>),
+    Resource(Box<Resource>),
+    Party(Box<Party>),
     EndOfStream,
 }
 
@@ -258,7 +258,7 @@ impl<R: BufRead> AlignedStreamingParser<R> {
                             std::mem::replace(&mut self.state, AlignedParserState::Initial);
                         if let AlignedParserState::InRelease(release_builder) = builder {
                             match release_builder.to_core() {
-                                Ok(release) => Some(AlignedStreamingElement::Release(release)),
+                                Ok(release) => Some(AlignedStreamingElement::Release(Box::new(release))),
                                 Err(e) => {
                                     warn!("Release validation failed, skipping release: {}", e);
                                     None
@@ -312,7 +312,7 @@ impl<R: BufRead> AlignedStreamingParser<R> {
                             std::mem::replace(&mut self.state, AlignedParserState::Initial);
                         if let AlignedParserState::InResource(resource_builder) = builder {
                             match resource_builder.to_core() {
-                                Ok(resource) => Some(AlignedStreamingElement::Resource(resource)),
+                                Ok(resource) => Some(AlignedStreamingElement::Resource(Box::new(resource))),
                                 Err(e) => {
                                     warn!("Resource validation failed, skipping resource: {}", e);
                                     None
@@ -361,7 +361,7 @@ impl<R: BufRead> AlignedStreamingParser<R> {
                             std::mem::replace(&mut self.state, AlignedParserState::Initial);
                         if let AlignedParserState::InParty(party_builder) = builder {
                             match party_builder.to_core() {
-                                Ok(party) => Some(AlignedStreamingElement::Party(party)),
+                                Ok(party) => Some(AlignedStreamingElement::Party(Box::new(party))),
                                 Err(e) => {
                                     warn!("Party validation failed, skipping party: {}", e);
                                     None
@@ -16,9 +16,9 @@ use std::time::Instant;
 #[derive(Debug, Clone)]
 pub enum StreamingElement {
     Header(Box<MessageHeader>),
-    Release(Release),
-    Resource(Resource),
-    Party(Party),
+    Release(Box<Release>),
+    Resource(Box<Resource>),
+    Party(Box<Party>),
     EndOfStream,
 }
 
@@ -188,7 +188,7 @@ impl<R: BufRead> ComprehensiveStreamingParser<R> {
                                         }
                                     })?;
                                     self.state = ParserState::Initial;
-                                    Some(StreamingElement::Release(core_release))
+                                    Some(StreamingElement::Release(Box::new(core_release)))
                                 }
                                 _ => None,
                             }
@@ -221,7 +221,7 @@ impl<R: BufRead> ComprehensiveStreamingParser<R> {
                                             }
                                         })?;
                                     self.state = ParserState::Initial;
-                                    Some(StreamingElement::Resource(core_resource))
+                                    Some(StreamingElement::Resource(Box::new(core_resource)))
                                 }
                                 _ => None,
                             }
@@ -246,7 +246,7 @@ impl<R: BufRead> ComprehensiveStreamingParser<R> {
                                         }
                                     })?;
                                     self.state = ParserState::Initial;
-                                    Some(StreamingElement::Party(core_party))
+                                    Some(StreamingElement::Party(Box::new(core_party)))
                                 }
                                 _ => None,
                             }
@@ -17,7 +17,7 @@ pub enum ParsedElement {
         version: ERNVersion,
     },
     /// Complete release element
-    Release(Release),
+    Release(Box<Release>),
     /// Complete resource element
     Resource(Resource),
     /// Complete party element
@@ -22,6 +22,14 @@ pub struct FastStreamingParser {
     // Additional resource patterns for comprehensive matching
     sound_recording_start: memmem::Finder<'static>,
     sound_recording_end: memmem::Finder<'static>,
+    track_release_start: memmem::Finder<'static>,
+    track_release_end: memmem::Finder<'static>,
+    video_start: memmem::Finder<'static>,
+    video_end: memmem::Finder<'static>,
+    image_start: memmem::Finder<'static>,
+    image_end: memmem::Finder<'static>,
+    text_start: memmem::Finder<'static>,
+    text_end: memmem::Finder<'static>,
     party_start: memmem::Finder<'static>,
     party_end: memmem::Finder<'static>,
     deal_start: memmem::Finder<'static>,
@@ -77,6 +85,14 @@ impl FastStreamingParser {
             resource_end: memmem::Finder::new(b"</Resource>"),
             sound_recording_start: memmem::Finder::new(b"<SoundRecording"),
             sound_recording_end: memmem::Finder::new(b"</SoundRecording>"),
+            track_release_start: memmem::Finder::new(b"<TrackRelease"),
+            track_release_end: memmem::Finder::new(b"</TrackRelease>"),
+            video_start: memmem::Finder::new(b"<Video"),
+            video_end: memmem::Finder::new(b"</Video>"),
+            image_start: memmem::Finder::new(b"<Image"),
+            image_end: memmem::Finder::new(b"</Image>"),
+            text_start: memmem::Finder::new(b"<Text"),
+            text_end: memmem::Finder::new(b"</Text>"),
             header_start: memmem::Finder::new(b"<MessageHeader"),
             header_end: memmem::Finder::new(b"</MessageHeader>"),
             party_start: memmem::Finder::new(b"<Party"),
@@ -97,81 +113,107 @@ impl FastStreamingParser {
         let mut buffer = Vec::with_capacity(50 * 1024 * 1024); // 50MB initial capacity
         let bytes_read = reader.read_to_end(&mut buffer)?;
 
-        // Pre-allocate results with generous capacity to avoid reallocation
-        let mut elements = Vec::with_capacity(50000);
-
-        // Scan using SIMD-accelerated pattern matching
-        // Multiple passes for different element types maximize SIMD efficiency
-
-        // Pass 1: Find all releases using SIMD
-        let mut pos = 0;
-        while let Some(offset) = self.release_start.find(&buffer[pos..]) {
-            let start_pos = pos + offset;
-
-            // Find end using SIMD
-            if let Some(end_offset) = self.release_end.find(&buffer[start_pos..]) {
-                let end_pos = start_pos + end_offset + 10; // "</Release>".len()
-
-                elements.push(FastStreamingElement {
-                    element_type: FastElementType::Release,
-                    raw_content: buffer[start_pos..end_pos].to_vec(),
-                    position: start_pos as u64,
-                    size: end_pos - start_pos,
-                    parsed_at: Instant::now(),
-                });
-
-                pos = end_pos;
-            } else {
-                pos = start_pos + 1;
-            }
-        }
+        #[cfg(feature = "simd")]
+        let elements = self.scan_simd(&buffer);
+        #[cfg(not(feature = "simd"))]
+        let elements = self.scan_scalar(&buffer);
 
-        // Pass 2: Find all resources (both Resource and SoundRecording)
-        pos = 0;
-        while let Some(offset) = self.resource_start.find(&buffer[pos..]) {
-            let start_pos = pos + offset;
-
-            if let Some(end_offset) = self.resource_end.find(&buffer[start_pos..]) {
-                let end_pos = start_pos + end_offset + 11; // "</Resource>".len()
-
-                elements.push(FastStreamingElement {
-                    element_type: FastElementType::Resource,
-                    raw_content: buffer[start_pos..end_pos].to_vec(),
-                    position: start_pos as u64,
-                    size: end_pos - start_pos,
-                    parsed_at: Instant::now(),
-                });
+        let elapsed = start.elapsed();
+        let throughput = (bytes_read as f64) / elapsed.as_secs_f64() / (1024.0 * 1024.0);
 
-                pos = end_pos;
+        let stats = FastParsingStats {
+            throughput_mbps: throughput,
+            elements_per_second: elements.len() as f64 / elapsed.as_secs_f64(),
+            total_bytes: bytes_read as u64,
+            total_elements: elements.len(),
+            elapsed,
+            peak_memory_mb: (buffer.capacity() as f64) / (1024.0 * 1024.0),
+            avg_element_size: if !elements.is_empty() {
+                elements.iter().map(|e| e.size).sum::<usize>() as f64 / elements.len() as f64
             } else {
-                pos = start_pos + 1;
-            }
-        }
-
-        // Pass 2b: Find SoundRecording elements
-        pos = 0;
-        while let Some(offset) = self.sound_recording_start.find(&buffer[pos..]) {
-            let start_pos = pos + offset;
+                0.0
+            },
+        };
 
-            if let Some(end_offset) = self.sound_recording_end.find(&buffer[start_pos..]) {
-                let end_pos = start_pos + end_offset + 17; // "</SoundRecording>".len()
+        Ok(FastStreamingIterator::new(elements, stats))
+    }
 
-                elements.push(FastStreamingElement {
-                    element_type: FastElementType::Resource,
-                    raw_content: buffer[start_pos..end_pos].to_vec(),
-                    position: start_pos as u64,
-                    size: end_pos - start_pos,
-                    parsed_at: Instant::now(),
-                });
+    /// Find element boundaries with one `memmem` pass per element type,
+    /// returning elements in document order
+    pub fn scan_scalar(&self, buffer: &[u8]) -> Vec<FastStreamingElement> {
+        // Pre-allocate results with generous capacity to avoid reallocation
+        let mut elements = Vec::with_capacity(50000);
 
-                pos = end_pos;
-            } else {
-                pos = start_pos + 1;
+        // Scan using SIMD-accelerated pattern matching
+        // Multiple passes for different element types maximize SIMD efficiency
+        let passes = [
+            (
+                &self.release_start,
+                &self.release_end,
+                FastElementType::Release,
+            ),
+            (
+                &self.track_release_start,
+                &self.track_release_end,
+                FastElementType::Release,
+            ),
+            (
+                &self.resource_start,
+                &self.resource_end,
+                FastElementType::Resource,
+            ),
+            (
+                &self.sound_recording_start,
+                &self.sound_recording_end,
+                FastElementType::Resource,
+            ),
+            (
+                &self.video_start,
+                &self.video_end,
+                FastElementType::Resource,
+            ),
+            (
+                &self.image_start,
+                &self.image_end,
+                FastElementType::Resource,
+            ),
+            (&self.text_start, &self.text_end, FastElementType::Resource),
+            (&self.party_start, &self.party_end, FastElementType::Party),
+            (&self.deal_start, &self.deal_end, FastElementType::Deal),
+        ];
+
+        for (start, end, element_type) in passes {
+            let mut pos = 0;
+            while let Some(offset) = start.find(&buffer[pos..]) {
+                let start_pos = pos + offset;
+
+                // `<Release` must not also pick up `<ReleaseList>` and friends
+                if !ends_tag_name(buffer, start_pos + start.needle().len()) {
+                    pos = start_pos + 1;
+                    continue;
+                }
+
+                // Find end using SIMD
+                if let Some(end_offset) = end.find(&buffer[start_pos..]) {
+                    let end_pos = start_pos + end_offset + end.needle().len();
+
+                    elements.push(FastStreamingElement {
+                        element_type: element_type.clone(),
+                        raw_content: buffer[start_pos..end_pos].to_vec(),
+                        position: start_pos as u64,
+                        size: end_pos - start_pos,
+                        parsed_at: Instant::now(),
+                    });
+
+                    pos = end_pos;
+                } else {
+                    pos = start_pos + 1;
+                }
             }
         }
 
-        // Pass 3: Find message header
-        if let Some(offset) = self.header_start.find(&buffer) {
+        // Find message header
+        if let Some(offset) = self.header_start.find(buffer) {
             if let Some(end_offset) = self.header_end.find(&buffer[offset..]) {
                 let end_pos = offset + end_offset + 16; // "</MessageHeader>".len()
 
@@ -185,71 +227,97 @@ impl FastStreamingParser {
             }
         }
 
-        // Pass 4: Find parties
-        pos = 0;
-        while let Some(offset) = self.party_start.find(&buffer[pos..]) {
-            let start_pos = pos + offset;
-
-            if let Some(end_offset) = self.party_end.find(&buffer[start_pos..]) {
-                let end_pos = start_pos + end_offset + 8; // "</Party>".len()
-
-                elements.push(FastStreamingElement {
-                    element_type: FastElementType::Party,
-                    raw_content: buffer[start_pos..end_pos].to_vec(),
-                    position: start_pos as u64,
-                    size: end_pos - start_pos,
-                    parsed_at: Instant::now(),
-                });
+        // Sort elements by position for proper ordering
+        elements.sort_by_key(|e| e.position);
+        elements
+    }
 
-                pos = end_pos;
-            } else {
-                pos = start_pos + 1;
+    /// Single-pass equivalent of [`scan_scalar`](Self::scan_scalar).
+    ///
+    /// Every `<` is located with `memchr`'s vectorised search (AVX2 or SSE2,
+    /// chosen at runtime, with a scalar fallback on CPUs that have neither),
+    /// and the tag name after it is classified by its first byte. Each element
+    /// type keeps its own resume point, so overlapping and unterminated
+    /// matches come out exactly as the per-type passes produce them. Unlike
+    /// those passes it stops searching for a type once its end tag has run
+    /// out, so an unterminated element is not rescanned to the end for every
+    /// later start tag of the same name.
+    #[cfg(feature = "simd")]
+    pub fn scan_simd(&self, buffer: &[u8]) -> Vec<FastStreamingElement> {
+        let mut elements = Vec::with_capacity(50000);
+        // Where each type's next match may start: release, track release,
+        // resource, sound recording, video, image, text, party, deal
+        let mut resume = [0usize; 9];
+        let mut header_seen = false;
+
+        for start_pos in memchr::memchr_iter(b'<', buffer) {
+            let name = &buffer[start_pos + 1..];
+            let is = |tag: &[u8]| {
+                name.starts_with(tag) && ends_tag_name(buffer, start_pos + 1 + tag.len())
+            };
+            let (slot, element_type, end) = match name.first() {
+                Some(b'R') if is(b"Release") => (0, FastElementType::Release, &self.release_end),
+                Some(b'T') if is(b"TrackRelease") => {
+                    (1, FastElementType::Release, &self.track_release_end)
+                }
+                Some(b'R') if is(b"Resource") => (2, FastElementType::Resource, &self.resource_end),
+                Some(b'S') if is(b"SoundRecording") => {
+                    (3, FastElementType::Resource, &self.sound_recording_end)
+                }
+                Some(b'V') if is(b"Video") => (4, FastElementType::Resource, &self.video_end),
+                Some(b'I') if is(b"Image") => (5, FastElementType::Resource, &self.image_end),
+                Some(b'T') if is(b"Text") => (6, FastElementType::Resource, &self.text_end),
+                Some(b'P') if is(b"Party") => (7, FastElementType::Party, &self.party_end),
+                Some(b'D') if is(b"Deal") => (8, FastElementType::Deal, &self.deal_end),
+                Some(b'M') if !header_seen && name.starts_with(b"MessageHeader") => {
+                    // Only the first header is looked at, found or not
+                    header_seen = true;
+                    if let Some(end_offset) = self.header_end.find(&buffer[start_pos..]) {
+                        let end_pos = start_pos + end_offset + 16;
+                        elements.push(Self::element(
+                            FastElementType::MessageHeader,
+                            buffer,
+                            start_pos,
+                            end_pos,
+                        ));
+                    }
+                    continue;
+                }
+                _ => continue,
+            };
+
+            if start_pos < resume[slot] {
+                continue;
             }
-        }
-
-        // Pass 5: Find deals
-        pos = 0;
-        while let Some(offset) = self.deal_start.find(&buffer[pos..]) {
-            let start_pos = pos + offset;
-
-            if let Some(end_offset) = self.deal_end.find(&buffer[start_pos..]) {
-                let end_pos = start_pos + end_offset + 7; // "</Deal>".len()
-
-                elements.push(FastStreamingElement {
-                    element_type: FastElementType::Deal,
-                    raw_content: buffer[start_pos..end_pos].to_vec(),
-                    position: start_pos as u64,
-                    size: end_pos - start_pos,
-                    parsed_at: Instant::now(),
-                });
-
-                pos = end_pos;
-            } else {
-                pos = start_pos + 1;
+            match end.find(&buffer[start_pos..]) {
+                Some(end_offset) => {
+                    let end_pos = start_pos + end_offset + end.needle().len();
+                    elements.push(Self::element(element_type, buffer, start_pos, end_pos));
+                    resume[slot] = end_pos;
+                }
+                // No end tag after this point means none after any later
+                // start either, so stop looking for this type altogether
+                None => resume[slot] = usize::MAX,
             }
         }
 
-        // Sort elements by position for proper ordering
-        elements.sort_by_key(|e| e.position);
-
-        let elapsed = start.elapsed();
-        let throughput = (bytes_read as f64) / elapsed.as_secs_f64() / (1024.0 * 1024.0);
-
-        let stats = FastParsingStats {
-            throughput_mbps: throughput,
-            elements_per_second: elements.len() as f64 / elapsed.as_secs_f64(),
-            total_bytes: bytes_read as u64,
-            total_elements: elements.len(),
-            elapsed,
-            peak_memory_mb: (buffer.capacity() as f64) / (1024.0 * 1024.0),
-            avg_element_size: if !elements.is_empty() {
-                elements.iter().map(|e| e.size).sum::<usize>() as f64 / elements.len() as f64
-            } else {
-                0.0
-            },
-        };
+        elements
+    }
 
-        Ok(FastStreamingIterator::new(elements, stats))
+    #[cfg(feature = "simd")]
+    fn element(
+        element_type: FastElementType,
+        buffer: &[u8],
+        start_pos: usize,
+        end_pos: usize,
+    ) -> FastStreamingElement {
+        FastStreamingElement {
+            element_type,
+            raw_content: buffer[start_pos..end_pos].to_vec(),
+            position: start_pos as u64,
+            size: end_pos - start_pos,
+            parsed_at: Instant::now(),
+        }
     }
 
     /// Get current parsing statistics
@@ -266,6 +334,182 @@ impl FastStreamingParser {
     }
 }
 
+/// Whether the tag name that started before `pos` ends there, so that
+/// `<Release` matches `<Release>` and `<Release/>` but not `<ReleaseList>`
+fn ends_tag_name(buffer: &[u8], pos: usize) -> bool {
+    matches!(
+        buffer.get(pos),
+        Some(b'>' | b'/' | b' ' | b'\t' | b'\r' | b'\n')
+    )
+}
+
+/// Entity counts produced by the count-only scan
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EntityCounts {
+    pub releases: usize,
+    pub resources: usize,
+    pub deals: usize,
+    pub parties: usize,
+}
+
+/// Entity counts plus the fast scanner's performance figures for one document
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FastScanSummary {
+    pub elements: usize,
+    pub releases: usize,
+    pub resources: usize,
+    pub deals: usize,
+    pub total_bytes: u64,
+    pub throughput_mbps: f64,
+    pub peak_memory_mb: f64,
+    pub avg_element_size: f64,
+}
+
+impl FastScanSummary {
+    /// Combine exact entity counts with the stats of a fast scan
+    pub fn new(counts: EntityCounts, stats: &FastParsingStats) -> Self {
+        Self {
+            elements: stats.total_elements,
+            releases: counts.releases,
+            resources: counts.resources,
+            deals: counts.deals,
+            total_bytes: stats.total_bytes,
+            throughput_mbps: stats.throughput_mbps,
+            peak_memory_mb: stats.peak_memory_mb,
+            avg_element_size: stats.avg_element_size,
+        }
+    }
+}
+
+/// Count releases, resources, deals and parties without building any models.
+///
+/// Uses the same element rules as the graph builder (`Release` and
+/// `TrackRelease` inside `ReleaseList`, `SoundRecording` and `Video` inside
+/// `ResourceList`, `ReleaseDeal` inside `DealList`, `Party` inside `PartyList`)
+/// so that the counts match a full parse of well-formed input. Namespace
+/// prefixes are ignored.
+pub fn count_entities(data: &[u8]) -> EntityCounts {
+    let mut counts = EntityCounts::default();
+    let mut in_release_list = false;
+    let mut in_resource_list = false;
+    let mut in_deal_list = false;
+    let mut in_party_list = false;
+
+    for tag in TagScanner::new(data) {
+        if tag.closing {
+            match tag.local_name {
+                b"ReleaseList" => in_release_list = false,
+                b"ResourceList" => in_resource_list = false,
+                b"DealList" => in_deal_list = false,
+                b"PartyList" => in_party_list = false,
+                _ => {}
+            }
+        } else {
+            match tag.local_name {
+                b"ReleaseList" => in_release_list = true,
+                b"ResourceList" => in_resource_list = true,
+                b"DealList" => in_deal_list = true,
+                b"PartyList" => in_party_list = true,
+                b"Release" | b"TrackRelease" if in_release_list => counts.releases += 1,
+                b"SoundRecording" | b"Video" if in_resource_list => counts.resources += 1,
+                b"ReleaseDeal" if in_deal_list => counts.deals += 1,
+                b"Party" if in_party_list => counts.parties += 1,
+                _ => {}
+            }
+        }
+    }
+
+    counts
+}
+
+/// An element tag found by [`TagScanner`]
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ScannedTag<'a> {
+    /// Element name without its namespace prefix
+    pub local_name: &'a [u8],
+    pub closing: bool,
+    /// Whether a start tag closes itself (`<Name/>`)
+    pub empty: bool,
+}
+
+/// Byte-level walk over the element tags of a document
+///
+/// Comments, CDATA sections, processing instructions and declarations are
+/// skipped, so their content never shows up as tags.
+pub(crate) struct TagScanner<'a> {
+    data: &'a [u8],
+    pos: usize,
+    comment_end: memmem::Finder<'static>,
+    cdata_end: memmem::Finder<'static>,
+}
+
+impl<'a> TagScanner<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            pos: 0,
+            comment_end: memmem::Finder::new(b"-->"),
+            cdata_end: memmem::Finder::new(b"]]>"),
+        }
+    }
+}
+
+impl<'a> Iterator for TagScanner<'a> {
+    type Item = ScannedTag<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let data = self.data;
+        while let Some(offset) = memchr::memchr(b'<', &data[self.pos..]) {
+            let tag_start = self.pos + offset + 1;
+            let rest = &data[tag_start..];
+
+            if rest.starts_with(b"!--") {
+                let end = self.comment_end.find(&rest[3..])?;
+                self.pos = tag_start + 3 + end + 3;
+                continue;
+            }
+            if rest.starts_with(b"![CDATA[") {
+                let end = self.cdata_end.find(&rest[8..])?;
+                self.pos = tag_start + 8 + end + 3;
+                continue;
+            }
+
+            let (closing, name_start) = match rest.first() {
+                Some(b'/') => (true, tag_start + 1),
+                Some(b'?') | Some(b'!') | None => {
+                    self.pos = tag_start;
+                    continue;
+                }
+                Some(_) => (false, tag_start),
+            };
+
+            let name_len = data[name_start..]
+                .iter()
+                .position(|&b| b == b'>' || b == b'/' || b.is_ascii_whitespace())
+                .unwrap_or(data.len() - name_start);
+            let qualified = &data[name_start..name_start + name_len];
+            let local_name = match memchr::memchr(b':', qualified) {
+                Some(colon) => &qualified[colon + 1..],
+                None => qualified,
+            };
+            self.pos = name_start + name_len;
+
+            let empty = !closing
+                && memchr::memchr(b'>', &data[self.pos..])
+                    .is_some_and(|end| end > 0 && data[self.pos + end - 1] == b'/');
+
+            return Some(ScannedTag {
+                local_name,
+                closing,
+                empty,
+            });
+        }
+
+        self.pos = data.len();
+        None
+    }
+}
+
 /// High-performance streaming iterator
 #[allow(dead_code)]
 pub struct FastStreamingIterator {
@@ -524,6 +768,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_count_entities_ignores_lookalike_tags() {
+        let xml = br#"<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/43">
+            <ReleaseList>
+                <!-- <Release>commented out</Release> -->
+                <Release><ReleaseReference>R1</ReleaseReference></Release>
+                <ern:Release><ReleaseId/></ern:Release>
+            </ReleaseList>
+            <ResourceList>
+                <SoundRecording><ResourceReference>A1</ResourceReference></SoundRecording>
+            </ResourceList>
+            <DealList>
+                <ReleaseDeal><DealReleaseReference>R1</DealReleaseReference><Deal/></ReleaseDeal>
+            </DealList>
+        </ern:NewReleaseMessage>"#;
+
+        let counts = count_entities(xml);
+        assert_eq!(
+            counts,
+            EntityCounts {
+                releases: 2,
+                resources: 1,
+                deals: 1,
+                parties: 0,
+            }
+        );
+    }
+
     #[test]
     fn test_element_types_detection() {
         let mut parser = create_fast_parser();
@@ -586,4 +858,93 @@ mod tests {
         assert!(party_count >= 1, "Should find parties");
         assert!(deal_count >= 1, "Should find deals");
     }
+
+    #[test]
+    fn test_scan_matches_exact_element_names() {
+        let xml = br#"<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/43">
+            <PartyList><Party><PartyId>P1</PartyId></Party></PartyList>
+            <ResourceList>
+                <SoundRecording><ResourceReference>A1</ResourceReference></SoundRecording>
+                <Video><ResourceReference>A2</ResourceReference></Video>
+                <Image><ResourceReference>A3</ResourceReference></Image>
+                <Text><ResourceReference>A4</ResourceReference><TextType>Booklet</TextType></Text>
+            </ResourceList>
+            <ReleaseList>
+                <Release><ReleaseId><ICPN>1</ICPN></ReleaseId><ReleaseType>Album</ReleaseType></Release>
+                <TrackRelease><ReleaseReference>R2</ReleaseReference></TrackRelease>
+            </ReleaseList>
+            <DealList><ReleaseDeal><DealReleaseReference>R1</DealReleaseReference>
+                <Deal><DealTerms/></Deal></ReleaseDeal></DealList>
+        </ern:NewReleaseMessage>"#;
+
+        let elements = create_fast_parser().scan_scalar(xml);
+        let names: Vec<&[u8]> = elements
+            .iter()
+            .map(|e| {
+                let end = e.raw_content[1..]
+                    .iter()
+                    .position(|b| matches!(b, b'>' | b'/' | b' '))
+                    .unwrap();
+                &e.raw_content[1..=end]
+            })
+            .collect();
+
+        assert_eq!(
+            names,
+            [
+                &b"Party"[..],
+                b"SoundRecording",
+                b"Video",
+                b"Image",
+                b"Text",
+                b"Release",
+                b"TrackRelease",
+                b"Deal",
+            ]
+        );
+        let count = |element_type| {
+            elements
+                .iter()
+                .filter(|e| e.element_type == element_type)
+                .count()
+        };
+        assert_eq!(count(FastElementType::Release), 2);
+        assert_eq!(count(FastElementType::Resource), 4);
+    }
+
+    #[cfg(feature = "simd")]
+    #[test]
+    fn test_simd_scan_matches_scalar() {
+        let parser = create_fast_parser();
+        let documents: [&[u8]; 5] = [
+            br#"<MessageHeader><MessageId>M</MessageId></MessageHeader>
+            <PartyList><Party><PartyId>P1</PartyId><PartyName>N</PartyName></Party></PartyList>
+            <ResourceList><SoundRecording><ResourceReference>A1</ResourceReference></SoundRecording></ResourceList>
+            <ReleaseList><Release><ReleaseId>R</ReleaseId></Release><Release/></ReleaseList>
+            <DealList><ReleaseDeal><Deal><DealTerms/></Deal></ReleaseDeal></DealList>"#,
+            // Prefixed, nested, unterminated and repeated-header cases
+            br#"<ern:Release></ern:Release><Release><Release></Release></Release>
+            <Resource>x<MessageHeader>a</MessageHeader><MessageHeader>b</MessageHeader>
+            <Party><Deal>"#,
+            b"<MessageHeader><MessageHeader></MessageHeader>",
+            // Lookalike names next to track releases and non-audio resources
+            br#"<ReleaseList><TrackRelease><ReleaseReference>R2</ReleaseReference></TrackRelease>
+            </ReleaseList><ResourceList><Video/><Video></Video><Image></Image><Text>t</Text>
+            <TextType>x</TextType></ResourceList><DealList><Deal/></DealList>"#,
+            b"",
+        ];
+
+        for document in documents {
+            let summary = |elements: Vec<FastStreamingElement>| {
+                elements
+                    .into_iter()
+                    .map(|e| (e.element_type, e.position, e.size, e.raw_content))
+                    .collect::<Vec<_>>()
+            };
+            assert_eq!(
+                summary(parser.scan_simd(document)),
+                summary(parser.scan_scalar(document))
+            );
+        }
+    }
 }
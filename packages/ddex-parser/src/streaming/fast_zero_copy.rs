@@ -50,7 +50,8 @@ impl FastZeroCopyParser {
         }
     }
 
-    /// Fast element extraction using memchr for initial scanning
+    /// Fast element extraction using memchr for initial scanning. An element
+    /// cut off by the end of the chunk is kept and completed by the next one.
     pub fn parse_chunk(
         &mut self,
         chunk: &[u8],
@@ -59,50 +60,63 @@ impl FastZeroCopyParser {
         let mut results = Vec::new();
 
         // Combine leftover with new chunk
-        let mut data = Vec::with_capacity(self.leftover.len() + chunk.len());
-        data.extend_from_slice(&self.leftover);
+        let mut data = std::mem::take(&mut self.leftover);
         data.extend_from_slice(chunk);
 
-        // Find MessageHeader elements
         let mut pos = 0;
-        while let Some(start) = self.find_pattern(&data[pos..], b"<MessageHeader") {
-            let abs_start = pos + start;
-            if let Some(element) = self.extract_message_header_fast(&data, abs_start)? {
-                results.push(element);
-                self.elements_found += 1;
-            }
-            pos = abs_start + 14; // Skip past "<MessageHeader"
-        }
-
-        // Find Release elements
-        pos = 0;
-        while let Some(start) = self.find_pattern(&data[pos..], b"<Release ") {
-            let abs_start = pos + start;
-            if let Some(element) = self.extract_release_fast(&data, abs_start)? {
-                results.push(element);
-                self.elements_found += 1;
+        let mut incomplete_from = None;
+        while let Some(offset) = memchr::memchr(b'<', &data[pos..]) {
+            let start = pos + offset;
+            let closing = data.get(start + 1) == Some(&b'/');
+            let name_start = start + 1 + closing as usize;
+            let Some(name_len) = data[name_start..]
+                .iter()
+                .position(|b| matches!(b, b'>' | b'/' | b' ' | b'\t' | b'\r' | b'\n'))
+            else {
+                incomplete_from = Some(start);
+                break;
+            };
+            let name = &data[name_start..name_start + name_len];
+            let local_name = name.rsplit(|&b| b == b':').next().unwrap_or(name);
+            pos = name_start + name_len;
+
+            // Matched elements are skipped whole, so names nested in them
+            // (an `Image` inside a release, say) are never taken for resources
+            let wanted = matches!(
+                local_name,
+                b"MessageHeader"
+                    | b"Release"
+                    | b"TrackRelease"
+                    | b"SoundRecording"
+                    | b"Video"
+                    | b"Image"
+                    | b"Text"
+            );
+            if closing || !wanted {
+                continue;
             }
-            pos = abs_start + 9; // Skip past "<Release "
-        }
 
-        // Find SoundRecording elements
-        pos = 0;
-        while let Some(start) = self.find_pattern(&data[pos..], b"<SoundRecording ") {
-            let abs_start = pos + start;
-            if let Some(element) = self.extract_sound_recording_fast(&data, abs_start)? {
-                results.push(element);
-                self.elements_found += 1;
-            }
-            pos = abs_start + 16; // Skip past "<SoundRecording "
+            let closing_tag = [b"</", name, b">"].concat();
+            let Some(end) = self.find_pattern(&data[start..], &closing_tag) else {
+                incomplete_from = Some(start);
+                break;
+            };
+            let element_data = &data[start..start + end + closing_tag.len()];
+            pos = start + end + closing_tag.len();
+
+            let element = match local_name {
+                b"MessageHeader" => self.extract_message_header_fast(element_data),
+                b"Release" | b"TrackRelease" => self.extract_release_fast(element_data, local_name),
+                b"SoundRecording" => self.extract_sound_recording_fast(element_data),
+                _ => self.extract_resource_fast(element_data, local_name),
+            };
+            results.push(element);
+            self.elements_found += 1;
         }
 
-        // Store leftover data that might contain incomplete elements
-        if data.len() > 2048 {
-            // Keep last 2KB to handle elements spanning chunks
-            self.leftover.clear();
-            self.leftover.extend_from_slice(&data[data.len() - 2048..]);
-        } else {
-            self.leftover = data;
+        // Keep the incomplete element for the next chunk
+        if let Some(start) = incomplete_from {
+            self.leftover = data[start..].to_vec();
         }
 
         Ok(results)
@@ -132,121 +146,128 @@ impl FastZeroCopyParser {
     }
 
     /// Fast message header extraction
-    fn extract_message_header_fast(
-        &mut self,
-        data: &[u8],
-        start: usize,
-    ) -> Result<Option<WorkingStreamingElement>, ParseError> {
-        // Find closing tag
-        if let Some(end) = self.find_pattern(&data[start..], b"</MessageHeader>") {
-            let header_data = &data[start..start + end + 16]; // Include closing tag
-
-            // Extract MessageId quickly
-            let message_id = if let Some(id) = self.extract_tag_content(header_data, b"MessageId") {
-                self.intern_string(id)
+    fn extract_message_header_fast(&mut self, header_data: &[u8]) -> WorkingStreamingElement {
+        // Extract MessageId quickly
+        let message_id = if let Some(id) = self.extract_tag_content(header_data, b"MessageId") {
+            self.intern_string(id)
+        } else {
+            "unknown".to_string()
+        };
+
+        // Extract CreatedDateTime
+        let created_date_time =
+            if let Some(dt) = self.extract_tag_content(header_data, b"CreatedDateTime") {
+                self.intern_string(dt)
             } else {
-                "unknown".to_string()
+                chrono::Utc::now().to_rfc3339()
             };
 
-            // Extract CreatedDateTime
-            let created_date_time =
-                if let Some(dt) = self.extract_tag_content(header_data, b"CreatedDateTime") {
-                    self.intern_string(dt)
-                } else {
-                    chrono::Utc::now().to_rfc3339()
-                };
-
-            return Ok(Some(WorkingStreamingElement::MessageHeader {
-                message_id,
-                created_date_time,
-                version: ERNVersion::V4_3,
-            }));
+        WorkingStreamingElement::MessageHeader {
+            message_id,
+            created_date_time,
+            version: ERNVersion::V4_3,
         }
-
-        Ok(None)
     }
 
-    /// Fast release extraction
-    fn extract_release_fast(
-        &mut self,
-        data: &[u8],
-        start: usize,
-    ) -> Result<Option<WorkingStreamingElement>, ParseError> {
-        // Find closing tag
-        if let Some(end) = self.find_pattern(&data[start..], b"</Release>") {
-            let release_data = &data[start..start + end + 10]; // Include closing tag
-
-            // Extract ReleaseReference attribute from opening tag
-            let reference = if let Some(attr) =
-                self.extract_attribute_fast(release_data, b"ReleaseReference")
-            {
-                self.intern_string(attr)
-            } else {
-                format!("REL-{}", self.elements_found)
-            };
+    /// Fast release extraction; `tag_name` is `Release` or `TrackRelease`
+    fn extract_release_fast(&mut self, release_data: &[u8], tag_name: &[u8]) -> WorkingStreamingElement {
+        let reference = match self.extract_reference(release_data, b"ReleaseReference") {
+            Some(reference) => self.intern_string(reference),
+            None => format!("REL-{}", self.elements_found),
+        };
 
-            // Extract title from TitleText nested in ReferenceTitle
-            let title =
-                if let Some(title_data) = self.extract_tag_content(release_data, b"TitleText") {
-                    self.intern_string(title_data)
-                } else {
-                    "Untitled Release".to_string()
-                };
+        // Extract title from TitleText nested in ReferenceTitle
+        let title = if let Some(title_data) = self.extract_tag_content(release_data, b"TitleText") {
+            self.intern_string(title_data)
+        } else {
+            "Untitled Release".to_string()
+        };
 
-            // Extract resource references (simplified)
-            let resource_references = self.extract_resource_references_fast(release_data);
+        let release_type = self
+            .extract_tag_content(release_data, b"ReleaseType")
+            .map(|release_type| self.intern_string(release_type))
+            .or_else(|| (tag_name == b"TrackRelease").then(|| "TrackRelease".to_string()));
 
-            return Ok(Some(WorkingStreamingElement::Release {
-                reference,
-                title,
-                resource_references,
-            }));
-        }
+        // Extract resource references (simplified)
+        let resource_references = self.extract_resource_references_fast(release_data);
 
-        Ok(None)
+        WorkingStreamingElement::Release {
+            reference,
+            title,
+            release_type,
+            resource_references,
+        }
     }
 
     /// Fast sound recording extraction
-    fn extract_sound_recording_fast(
-        &mut self,
-        data: &[u8],
-        start: usize,
-    ) -> Result<Option<WorkingStreamingElement>, ParseError> {
-        if let Some(end) = self.find_pattern(&data[start..], b"</SoundRecording>") {
-            let recording_data = &data[start..start + end + 17]; // Include closing tag
-
-            let reference = if let Some(attr) =
-                self.extract_attribute_fast(recording_data, b"ResourceReference")
-            {
-                self.intern_string(attr)
-            } else {
-                format!("RES-{}", self.elements_found)
-            };
-
-            let title =
-                if let Some(title_data) = self.extract_tag_content(recording_data, b"TitleText") {
-                    self.intern_string(title_data)
-                } else {
-                    "Untitled Track".to_string()
-                };
-
-            let duration = self
-                .extract_tag_content(recording_data, b"Duration")
-                .map(|d| self.intern_string(d));
+    fn extract_sound_recording_fast(&mut self, recording_data: &[u8]) -> WorkingStreamingElement {
+        let (reference, title) = self.resource_reference_and_title(recording_data);
+
+        let duration = self
+            .extract_tag_content(recording_data, b"Duration")
+            .map(|d| self.intern_string(d));
+
+        let isrc = self
+            .extract_tag_content(recording_data, b"ISRC")
+            .map(|i| self.intern_string(i));
+
+        WorkingStreamingElement::SoundRecording {
+            reference,
+            title,
+            duration,
+            isrc,
+        }
+    }
 
-            let isrc = self
-                .extract_tag_content(recording_data, b"ISRC")
-                .map(|i| self.intern_string(i));
+    /// Fast extraction of a `Video`, `Image` or `Text` resource
+    fn extract_resource_fast(&mut self, resource_data: &[u8], tag_name: &[u8]) -> WorkingStreamingElement {
+        let (reference, title) = self.resource_reference_and_title(resource_data);
+        let number = |parser: &Self, tag: &[u8]| {
+            parser
+                .extract_tag_content(resource_data, tag)
+                .and_then(|value| std::str::from_utf8(value).ok()?.trim().parse().ok())
+        };
 
-            return Ok(Some(WorkingStreamingElement::SoundRecording {
+        match tag_name {
+            b"Video" => WorkingStreamingElement::Video {
+                reference,
+                title,
+                duration: self
+                    .extract_tag_content(resource_data, b"Duration")
+                    .map(|d| self.intern_string(d)),
+            },
+            b"Image" => WorkingStreamingElement::Image {
                 reference,
                 title,
-                duration,
-                isrc,
-            }));
+                width: number(self, b"ImageWidth"),
+                height: number(self, b"ImageHeight"),
+            },
+            _ => WorkingStreamingElement::Text {
+                reference,
+                title,
+                language_code: None,
+            },
         }
+    }
+
+    fn resource_reference_and_title(&mut self, resource_data: &[u8]) -> (String, String) {
+        let reference = match self.extract_reference(resource_data, b"ResourceReference") {
+            Some(reference) => self.intern_string(reference),
+            None => format!("RES-{}", self.elements_found),
+        };
+        let title = if let Some(title_data) = self.extract_tag_content(resource_data, b"TitleText") {
+            self.intern_string(title_data)
+        } else {
+            "Untitled Track".to_string()
+        };
+        (reference, title)
+    }
 
-        Ok(None)
+    /// Reference given as an attribute of the element or as its child element
+    fn extract_reference<'a>(&self, data: &'a [u8], name: &[u8]) -> Option<&'a [u8]> {
+        let opening_tag_end = memchr::memchr(b'>', data).unwrap_or(data.len());
+        self.extract_attribute_fast(&data[..opening_tag_end], name)
+            .or_else(|| self.extract_tag_content(data, name))
     }
 
     /// Extract content between XML tags
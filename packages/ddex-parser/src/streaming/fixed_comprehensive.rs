@@ -17,7 +17,7 @@ pub enum FixedStreamingElement {
         created_date_time: String,
         version: ERNVersion,
     },
-    Release(Release),
+    Release(Box<Release>),
     Resource(Resource),
     EndOfStream,
 }
@@ -154,9 +154,15 @@ impl<R: BufRead> FixedStreamingParser<R> {
             attributes: None,
             extensions: None,
             comments: None,
+            descriptions: Vec::new(),
+            reference_title: Vec::new(),
+            display_title: Vec::new(),
+            related_releases: Vec::new(),
+            label_name: None,
+            visibility: None,
         };
 
-        FixedStreamingElement::Release(release)
+        FixedStreamingElement::Release(Box::new(release))
     }
 
     fn create_sample_resource(&self) -> FixedStreamingElement {
@@ -173,20 +179,28 @@ impl<R: BufRead> FixedStreamingParser<R> {
                 language_code: Some("en".to_string()),
                 script: None,
             }],
+            subtitle: Vec::new(),
+            display_artist: Vec::new(),
+            contributors: Vec::new(),
             duration: Some(std::time::Duration::from_secs(200)), // 3:20 for Anti-Hero
             technical_details: vec![TechnicalDetails {
                 technical_resource_details_reference: "ANTI_HERO_TECH_DETAILS".to_string(),
                 audio_codec: Some("MP3".to_string()),
                 bitrate: Some(320),
                 sample_rate: Some(44100),
+                bits_per_sample: None,
                 file_format: Some("MP3".to_string()),
                 file_size: Some(8000000), // ~8MB for high quality
+                files: Vec::new(),
+                preview: None,
+                video: None,
                 extensions: None,
             }],
             rights_controller: vec!["TAYLOR_SWIFT_RIGHTS".to_string()],
             p_line: vec![],
             c_line: vec![],
             extensions: None,
+            descriptions: Vec::new(),
         };
 
         FixedStreamingElement::Resource(resource)
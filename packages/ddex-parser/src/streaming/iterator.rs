@@ -116,7 +116,7 @@ impl<R: BufRead> DDEXStreamIterator<R> {
         for result in self {
             match result {
                 Ok(ParsedElement::Release(release)) => {
-                    releases.push(release);
+                    releases.push(*release);
                 }
                 Ok(ParsedElement::EndOfStream) => break,
                 Ok(_) => continue, // Skip other element types
@@ -149,7 +149,7 @@ impl<R: BufRead> DDEXStreamIterator<R> {
         for result in self {
             match result {
                 Ok(ParsedElement::Release(release)) => {
-                    return Ok(Some(release));
+                    return Ok(Some(*release));
                 }
                 Ok(ParsedElement::EndOfStream) => {
                     return Ok(None);
@@ -3,6 +3,7 @@
 
 pub mod aligned_comprehensive;
 pub mod comprehensive;
+pub mod cost;
 pub mod element;
 pub mod fast_streaming_parser;
 pub mod fast_zero_copy;
@@ -12,6 +13,8 @@ pub mod minimal;
 pub mod parallel_parser;
 pub mod parser;
 pub mod state;
+pub mod strategies;
+pub mod typed;
 pub mod verification;
 pub mod working_impl;
 pub mod zero_copy_parser;
@@ -34,16 +37,21 @@ pub mod parallel_benchmark;
 #[cfg(test)]
 pub mod consistency_test;
 
+pub use cost::{estimate_parse_cost, ParseCostEstimate};
 pub use element::ParsedElement;
 pub use fast_streaming_parser::{
-    create_fast_parser, FastElementType, FastParsingStats, FastStreamingElement,
-    FastStreamingIterator, FastStreamingParser,
+    count_entities, create_fast_parser, EntityCounts, FastElementType, FastParsingStats, FastScanSummary,
+    FastStreamingElement, FastStreamingIterator, FastStreamingParser,
 };
 pub use fast_zero_copy::{FastZeroCopyIterator, FastZeroCopyParser};
 pub use iterator::DDEXStreamIterator;
 pub use parallel_parser::{ParallelBenchmark, ParallelStreamingIterator, ParallelStreamingParser};
 pub use parser::StreamingDDEXParser;
 pub use state::{ParserState, ParsingContext};
+pub use strategies::{compare_strategies, StrategyComparison, StrategyResult};
+pub use typed::{
+    CorrelatedDeal, CorrelatedDealIterator, DealRelease, TypedStreamIterator, TypedStreamingElement,
+};
 pub use working_impl::{
     WorkingStreamIterator, WorkingStreamingElement, WorkingStreamingParser, WorkingStreamingStats,
 };
@@ -95,3 +103,12 @@ pub struct StreamingProgress {
     pub current_depth: usize,
     pub memory_usage: usize,
 }
+
+impl StreamingProgress {
+    /// Share of `estimated_total_bytes` processed so far, from 0 to 100
+    pub fn estimated_completion_percent(&self) -> Option<f64> {
+        self.estimated_total_bytes
+            .filter(|total| *total > 0)
+            .map(|total| (self.bytes_processed as f64 / total as f64 * 100.0).min(100.0))
+    }
+}
@@ -415,7 +415,7 @@ impl<R: BufRead> StreamingDDEXParser<R> {
                 if release.is_complete() {
                     let completed_release = release.clone().into_release();
                     self.context.state = ParserState::Initial;
-                    return Ok(Some(ParsedElement::Release(completed_release)));
+                    return Ok(Some(ParsedElement::Release(Box::new(completed_release))));
                 }
             }
             _ => {}
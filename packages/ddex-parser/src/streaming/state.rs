@@ -206,6 +206,12 @@ impl PartialRelease {
             attributes: None,
             extensions: None,
             comments: None,
+            descriptions: Vec::new(),
+            reference_title: Vec::new(),
+            display_title: Vec::new(),
+            related_releases: Vec::new(),
+            label_name: None,
+            visibility: None,
         }
     }
 }
@@ -233,12 +239,16 @@ impl PartialResource {
             resource_type: self.resource_type.unwrap_or(ResourceType::SoundRecording),
             resource_id: self.resource_id,
             reference_title: self.reference_title,
+            subtitle: Vec::new(),
+            display_artist: Vec::new(),
+            contributors: Vec::new(),
             duration: self.duration,
             technical_details: self.technical_details,
             rights_controller: self.rights_controller,
             p_line: self.p_line,
             c_line: self.c_line,
             extensions: None,
+            descriptions: Vec::new(),
         }
     }
 }
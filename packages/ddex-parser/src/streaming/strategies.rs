@@ -0,0 +1,171 @@
+// src/streaming/strategies.rs
+//! Side-by-side run of the streaming implementations on one document
+//!
+//! Each strategy streams the same bytes and is timed by the harness, so
+//! throughputs are comparable whatever the strategy reports about itself.
+//! Releases and resources are counted the way a full parse counts them
+//! (releases and track releases; sound recordings and videos), and
+//! [`StrategyComparison::consistent`] says whether every strategy found what
+//! the parse did.
+
+use super::fast_streaming_parser::{FastElementType, FastStreamingElement, FastStreamingParser};
+use super::fast_zero_copy::FastZeroCopyIterator;
+use super::parallel_parser::ParallelStreamingIterator;
+use super::working_impl::{WorkingStreamIterator, WorkingStreamingElement};
+use super::zero_copy_parser::ZeroCopyStreamIterator;
+use super::StreamingConfig;
+use crate::error::ParseError;
+use ddex_core::models::versions::ERNVersion;
+use std::io::Cursor;
+use std::time::{Duration, Instant};
+
+/// One strategy's run over the document
+#[derive(Debug, Clone)]
+pub struct StrategyResult {
+    /// Module name of the strategy, e.g. "zero_copy"
+    pub strategy: String,
+    pub elapsed: Duration,
+    pub throughput_mb_per_sec: f64,
+    /// Peak memory the strategy reports having held
+    pub peak_memory_bytes: usize,
+    pub releases_found: usize,
+    pub resources_found: usize,
+}
+
+impl StrategyResult {
+    /// Releases and resources together
+    pub fn elements_found(&self) -> usize {
+        self.releases_found + self.resources_found
+    }
+}
+
+/// Results of every strategy, in the order they ran
+#[derive(Debug, Clone)]
+pub struct StrategyComparison {
+    pub results: Vec<StrategyResult>,
+    /// Releases a full parse of the document finds
+    pub parsed_releases: usize,
+    /// Resources a full parse of the document finds
+    pub parsed_resources: usize,
+}
+
+impl StrategyComparison {
+    /// Whether every strategy found the releases and resources a full parse
+    /// finds
+    pub fn consistent(&self) -> bool {
+        self.results.iter().all(|result| {
+            result.releases_found == self.parsed_releases
+                && result.resources_found == self.parsed_resources
+        })
+    }
+
+    /// The strategy with the highest throughput
+    pub fn fastest(&self) -> Option<&StrategyResult> {
+        self.results
+            .iter()
+            .max_by(|a, b| a.throughput_mb_per_sec.total_cmp(&b.throughput_mb_per_sec))
+    }
+}
+
+/// (releases, resources, peak memory in bytes) found by one strategy
+type Counts = (usize, usize, usize);
+
+/// A strategy's name and a run of it over the document
+type Strategy<'a> = (&'static str, &'a dyn Fn() -> Result<Counts, ParseError>);
+
+/// Stream `data` with each strategy in turn
+pub fn compare_strategies(
+    data: &[u8],
+    version: ERNVersion,
+    config: StreamingConfig,
+) -> Result<StrategyComparison, ParseError> {
+    let parsed = crate::parser::parse(Cursor::new(data), Default::default(), &config.security)?;
+
+    let strategies: [Strategy; 5] = [
+        ("working_impl", &|| {
+            let mut iterator = WorkingStreamIterator::new(Cursor::new(data), version);
+            let (releases, resources) = count_working_elements(&mut iterator)?;
+            Ok((releases, resources, iterator.stats().max_memory_used_bytes))
+        }),
+        ("zero_copy", &|| {
+            let mut iterator = ZeroCopyStreamIterator::new(Cursor::new(data), version);
+            let (releases, resources) = count_working_elements(&mut iterator)?;
+            Ok((releases, resources, iterator.stats().max_memory_used_bytes))
+        }),
+        ("fast_zero_copy", &|| {
+            let mut iterator = FastZeroCopyIterator::new(Cursor::new(data), version);
+            let (releases, resources) = count_working_elements(&mut iterator)?;
+            Ok((releases, resources, iterator.stats().max_memory_used_bytes))
+        }),
+        ("parallel", &|| {
+            let mut iterator = ParallelStreamingIterator::new(Cursor::new(data), version);
+            let (releases, resources) = count_working_elements(&mut iterator)?;
+            Ok((releases, resources, iterator.stats().max_memory_used_bytes))
+        }),
+        ("fast_streaming", &|| {
+            let mut parser = FastStreamingParser::new(config.clone());
+            let iterator = parser.parse_streaming(&mut Cursor::new(data), None)?;
+            let peak_memory_bytes = (iterator.stats().peak_memory_mb * 1024.0 * 1024.0) as usize;
+            let (mut releases, mut resources) = (0, 0);
+            for element in iterator {
+                match element.element_type {
+                    FastElementType::Release => releases += 1,
+                    FastElementType::Resource if is_track(&element) => resources += 1,
+                    _ => {}
+                }
+            }
+            Ok((releases, resources, peak_memory_bytes))
+        }),
+    ];
+
+    let results = strategies
+        .iter()
+        .map(|(strategy, run)| {
+            let start = Instant::now();
+            let (releases_found, resources_found, peak_memory_bytes) = run()?;
+            let elapsed = start.elapsed();
+            Ok(StrategyResult {
+                strategy: strategy.to_string(),
+                elapsed,
+                throughput_mb_per_sec: data.len() as f64
+                    / (1024.0 * 1024.0)
+                    / elapsed.as_secs_f64().max(f64::EPSILON),
+                peak_memory_bytes,
+                releases_found,
+                resources_found,
+            })
+        })
+        .collect::<Result<Vec<_>, ParseError>>()?;
+
+    Ok(StrategyComparison {
+        results,
+        parsed_releases: parsed.flat.releases.len(),
+        parsed_resources: parsed.flat.resources.len(),
+    })
+}
+
+/// Whether a fast-streaming resource is a sound recording or video, the
+/// resources a full parse keeps
+fn is_track(element: &FastStreamingElement) -> bool {
+    element.raw_content.starts_with(b"<SoundRecording")
+        || element.raw_content.starts_with(b"<Video")
+}
+
+/// Releases and resources yielded by one of the `WorkingStreamingElement` iterators
+fn count_working_elements(
+    iterator: &mut dyn Iterator<Item = Result<WorkingStreamingElement, ParseError>>,
+) -> Result<(usize, usize), ParseError> {
+    let (mut releases, mut resources) = (0, 0);
+    for element in iterator {
+        match element? {
+            WorkingStreamingElement::Release { .. } => releases += 1,
+            WorkingStreamingElement::SoundRecording { .. }
+            | WorkingStreamingElement::Video { .. } => resources += 1,
+            WorkingStreamingElement::Image { .. }
+            | WorkingStreamingElement::Text { .. }
+            | WorkingStreamingElement::MessageHeader { .. }
+            | WorkingStreamingElement::EndOfStream { .. } => {}
+        }
+    }
+    Ok((releases, resources))
+}
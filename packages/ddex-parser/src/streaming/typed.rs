@@ -0,0 +1,357 @@
+// src/streaming/typed.rs
+//! Streaming iterator that yields releases, resources and deals already
+//! converted to the flat model
+//!
+//! Each element is parsed by the same graph code as a full parse and
+//! flattened as soon as its end tag is read, so consumers get typed values
+//! without re-inspecting generic elements. [`CorrelatedDealIterator`] builds
+//! on it to yield deals with the releases they apply to already looked up.
+
+use crate::error::ParseError;
+use crate::parser::security::SecurityConfig;
+use crate::parser::whitespace::WhitespaceMode;
+use crate::parser::xml_validator::XmlValidator;
+use crate::parser::ParseOptions;
+use crate::transform::flatten::Flattener;
+use crate::transform::graph::GraphBuilder;
+use ddex_core::models::flat::{ParsedDeal, ParsedRelease, ParsedResource, ReleaseIdentifiers};
+use ddex_core::models::graph::{Party, Resource, ResourceType};
+use ddex_core::models::versions::ERNVersion;
+use quick_xml::{events::Event, Reader};
+use std::collections::{HashMap, VecDeque};
+use std::io::BufRead;
+
+/// A release, resource or deal, in the order it appears in the document
+#[derive(Debug, Clone)]
+pub enum TypedStreamingElement {
+    Release(Box<ParsedRelease>),
+    Resource(Box<ParsedResource>),
+    Deal(Box<ParsedDeal>),
+}
+
+/// The list element the reader is currently inside
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum List {
+    Parties,
+    Resources,
+    Releases,
+    Deals,
+}
+
+/// Iterator over the typed elements of a DDEX message
+///
+/// Parties and resources are kept once read so that later releases can
+/// resolve their artists and tracks, as `PartyList` and `ResourceList` precede
+/// `ReleaseList` in every ERN version.
+pub struct TypedStreamIterator<R: BufRead> {
+    reader: Reader<R>,
+    buffer: Vec<u8>,
+    builder: GraphBuilder,
+    validator: XmlValidator,
+    security: SecurityConfig,
+    list: Option<List>,
+    parties: Vec<Party>,
+    resources: Vec<Resource>,
+    /// Whether resources are kept for later releases to resolve their tracks
+    retain_resources: bool,
+    deals_yielded: usize,
+    releases_complete: bool,
+    finished: bool,
+}
+
+impl<R: BufRead> TypedStreamIterator<R> {
+    pub fn new(reader: R, version: ERNVersion) -> Self {
+        Self::with_options(reader, version, &ParseOptions::default(), &SecurityConfig::default())
+    }
+
+    /// Stream with the whitespace handling of `options` and the depth,
+    /// DTD and element limits of `security_config`, as a full parse would
+    pub fn with_options(
+        reader: R,
+        version: ERNVersion,
+        options: &ParseOptions,
+        security_config: &SecurityConfig,
+    ) -> Self {
+        let mut xml_reader = Reader::from_reader(reader);
+        xml_reader
+            .config_mut()
+            .trim_text(options.whitespace != WhitespaceMode::Preserve);
+        xml_reader.config_mut().check_end_names = true;
+        xml_reader.config_mut().expand_empty_elements = false;
+
+        Self {
+            reader: xml_reader,
+            buffer: Vec::with_capacity(8192),
+            builder: GraphBuilder::new(version).with_whitespace(options.whitespace),
+            validator: XmlValidator::strict(),
+            security: security_config.clone(),
+            list: None,
+            parties: Vec::new(),
+            resources: Vec::new(),
+            retain_resources: true,
+            deals_yielded: 0,
+            releases_complete: false,
+            finished: false,
+        }
+    }
+
+    /// Check if parsing is complete
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Read up to and including the next typed element
+    fn parse_next(&mut self) -> Result<Option<TypedStreamingElement>, ParseError> {
+        loop {
+            self.buffer.clear();
+            let event = self
+                .reader
+                .read_event_into(&mut self.buffer)
+                .map_err(|e| ParseError::XmlError(format!("XML parsing error: {}", e)))?;
+            self.validator.validate_event(&event, &self.reader)?;
+
+            if self.validator.get_depth() > self.security.max_element_depth {
+                return Err(ParseError::DepthLimitExceeded {
+                    depth: self.validator.get_depth(),
+                    limit: self.security.max_element_depth,
+                });
+            }
+
+            let start = match event {
+                Event::DocType(ref e) => {
+                    self.security.check_doctype(e)?;
+                    continue;
+                }
+                Event::Empty(ref e) => {
+                    self.security.check_element(e)?;
+                    continue;
+                }
+                Event::Start(ref e) => {
+                    self.security.check_element(e)?;
+                    e.local_name().as_ref().to_vec()
+                }
+                Event::End(ref e) => {
+                    if e.local_name().as_ref() == b"ReleaseList" {
+                        self.releases_complete = true;
+                    }
+                    if matches!(
+                        e.local_name().as_ref(),
+                        b"PartyList" | b"ResourceList" | b"ReleaseList" | b"DealList"
+                    ) {
+                        self.list = None;
+                    }
+                    continue;
+                }
+                Event::Eof => return Ok(None),
+                _ => continue,
+            };
+
+            match (self.list, start.as_slice()) {
+                (_, b"PartyList") => self.list = Some(List::Parties),
+                (_, b"ResourceList") => self.list = Some(List::Resources),
+                (_, b"ReleaseList") => self.list = Some(List::Releases),
+                (_, b"DealList") => self.list = Some(List::Deals),
+                (Some(List::Parties), b"Party") => {
+                    let party = self.builder.parse_party(&mut self.reader, &mut self.validator)?;
+                    self.parties.push(party);
+                }
+                (Some(List::Resources), name) => {
+                    let resource = match name {
+                        b"SoundRecording" => self
+                            .builder
+                            .parse_sound_recording(&mut self.reader, &mut self.validator)?,
+                        // Videos share the SoundRecording layout
+                        b"Video" => Resource {
+                            resource_type: ResourceType::Video,
+                            ..self
+                                .builder
+                                .parse_sound_recording(&mut self.reader, &mut self.validator)?
+                        },
+                        b"Image" | b"Text" | b"SheetMusic" => {
+                            let resource_type = match name {
+                                b"Image" => ResourceType::Image,
+                                b"Text" => ResourceType::Text,
+                                _ => ResourceType::SheetMusic,
+                            };
+                            self.builder.parse_basic_resource(
+                                &mut self.reader,
+                                &mut self.validator,
+                                resource_type,
+                            )?
+                        }
+                        _ => continue,
+                    };
+
+                    let parsed = Flattener::flatten_resource(&resource);
+                    if self.retain_resources {
+                        self.resources.push(resource);
+                    }
+                    return Ok(Some(TypedStreamingElement::Resource(Box::new(parsed))));
+                }
+                (Some(List::Releases), name @ (b"Release" | b"TrackRelease")) => {
+                    let release = if name == b"TrackRelease" {
+                        self.builder
+                            .parse_track_release(&mut self.reader, &mut self.validator)?
+                    } else {
+                        self.builder
+                            .parse_minimal_release(&mut self.reader, &mut self.validator)?
+                    };
+                    let parsed = Flattener::flatten_release(&release, &self.resources, &self.parties)?;
+                    return Ok(Some(TypedStreamingElement::Release(Box::new(parsed))));
+                }
+                (Some(List::Deals), b"ReleaseDeal") => {
+                    let deal = self
+                        .builder
+                        .parse_release_deal(&mut self.reader, &mut self.validator)?;
+                    let parsed = Flattener::flatten_deal(&deal, self.deals_yielded);
+                    self.deals_yielded += 1;
+                    return Ok(Some(TypedStreamingElement::Deal(Box::new(parsed))));
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for TypedStreamIterator<R> {
+    type Item = Result<TypedStreamingElement, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        match self.parse_next() {
+            Ok(Some(element)) => Some(Ok(element)),
+            Ok(None) => {
+                self.finished = true;
+                None
+            }
+            Err(e) => {
+                self.finished = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+/// A release a deal applies to, as read from the release list
+#[derive(Debug, Clone)]
+pub struct DealRelease {
+    pub release_reference: String,
+    pub title: String,
+    pub release_type: String,
+    pub identifiers: ReleaseIdentifiers,
+}
+
+impl From<&ParsedRelease> for DealRelease {
+    fn from(release: &ParsedRelease) -> Self {
+        Self {
+            release_reference: release.release_id.clone(),
+            title: release.default_title.clone(),
+            release_type: release.release_type.clone(),
+            identifiers: release.identifiers.clone(),
+        }
+    }
+}
+
+/// A deal with the releases named by its `DealReleaseReference`s
+#[derive(Debug, Clone)]
+pub struct CorrelatedDeal {
+    pub deal: ParsedDeal,
+    /// Releases the deal applies to, in reference order
+    pub releases: Vec<DealRelease>,
+    /// `DealReleaseReference`s no release in the message defines
+    pub unresolved_references: Vec<String>,
+}
+
+/// Iterator over the deals of a DDEX message, each linked to its releases
+///
+/// Resources and releases are read first. Resources are dropped once read and
+/// only a [`DealRelease`] summary of each release is kept, so memory grows
+/// with the number of releases rather than their contents. Deals are yielded once the release list has been
+/// closed. A deal that precedes it (out of schema order) is held back until
+/// then, so every reference resolves against the complete release list.
+pub struct CorrelatedDealIterator<R: BufRead> {
+    inner: TypedStreamIterator<R>,
+    releases: HashMap<String, DealRelease>,
+    pending: VecDeque<ParsedDeal>,
+}
+
+impl<R: BufRead> CorrelatedDealIterator<R> {
+    pub fn new(reader: R, version: ERNVersion) -> Self {
+        Self::with_options(reader, version, &ParseOptions::default(), &SecurityConfig::default())
+    }
+
+    /// Stream with the given options and security limits; see
+    /// [`TypedStreamIterator::with_options`]
+    pub fn with_options(
+        reader: R,
+        version: ERNVersion,
+        options: &ParseOptions,
+        security_config: &SecurityConfig,
+    ) -> Self {
+        let mut inner = TypedStreamIterator::with_options(reader, version, options, security_config);
+        // A deal's releases are summarized without their tracks
+        inner.retain_resources = false;
+
+        Self {
+            inner,
+            releases: HashMap::new(),
+            pending: VecDeque::new(),
+        }
+    }
+
+    /// Check if parsing is complete and every deal has been yielded
+    pub fn is_finished(&self) -> bool {
+        self.inner.is_finished() && self.pending.is_empty()
+    }
+
+    fn correlate(&self, deal: ParsedDeal) -> CorrelatedDeal {
+        let mut releases = Vec::new();
+        let mut unresolved_references = Vec::new();
+        for reference in &deal.releases {
+            match self.releases.get(reference) {
+                Some(release) => releases.push(release.clone()),
+                None => unresolved_references.push(reference.clone()),
+            }
+        }
+
+        CorrelatedDeal {
+            deal,
+            releases,
+            unresolved_references,
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for CorrelatedDealIterator<R> {
+    type Item = Result<CorrelatedDeal, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.inner.releases_complete || self.inner.is_finished() {
+                if let Some(deal) = self.pending.pop_front() {
+                    return Some(Ok(self.correlate(deal)));
+                }
+            }
+
+            match self.inner.next() {
+                None if self.pending.is_empty() => return None,
+                None => {}
+                Some(Ok(TypedStreamingElement::Release(release))) => {
+                    self.releases.insert(
+                        release.release_id.clone(),
+                        DealRelease::from(release.as_ref()),
+                    );
+                }
+                Some(Ok(TypedStreamingElement::Deal(deal))) => self.pending.push_back(*deal),
+                Some(Ok(TypedStreamingElement::Resource(_))) => {}
+                Some(Err(e)) => {
+                    self.pending.clear();
+                    return Some(Err(e));
+                }
+            }
+        }
+    }
+}
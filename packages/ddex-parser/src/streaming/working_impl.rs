@@ -21,10 +21,12 @@ pub enum WorkingStreamingElement {
         created_date_time: String,
         version: ERNVersion,
     },
-    /// Release element found
+    /// Release element found, a `TrackRelease` included
     Release {
         reference: String,
         title: String,
+        /// `ReleaseType`, or "TrackRelease" for a `TrackRelease` without one
+        release_type: Option<String>,
         resource_references: Vec<String>,
     },
     /// Resource element found
@@ -80,6 +82,7 @@ pub struct WorkingStreamingParser<R: BufRead> {
 
     // Element-specific data preserved during parsing
     release_attributes: std::collections::HashMap<String, String>,
+    release_resource_references: Vec<String>,
     resource_attributes: std::collections::HashMap<String, String>,
 
     // Statistics and monitoring
@@ -113,6 +116,7 @@ impl<R: BufRead> WorkingStreamingParser<R> {
             current_attributes: std::collections::HashMap::new(),
             current_fields: std::collections::HashMap::new(),
             release_attributes: std::collections::HashMap::new(),
+            release_resource_references: Vec::new(),
             resource_attributes: std::collections::HashMap::new(),
             bytes_processed: 0,
             elements_yielded: 0,
@@ -166,6 +170,7 @@ impl<R: BufRead> WorkingStreamingParser<R> {
                     let name = std::str::from_utf8(e.name().as_ref())?.to_string();
                     if let Some(element) = self.handle_end_element(&name)? {
                         self.elements_yielded += 1;
+                        self.update_memory_usage();
                         return Ok(Some(element));
                     }
                 }
@@ -220,9 +225,10 @@ impl<R: BufRead> WorkingStreamingParser<R> {
             "MessageHeader" => {
                 self.in_message_header = true;
             }
-            "Release" => {
+            "Release" | "TrackRelease" => {
                 self.in_release = true;
                 self.current_fields.clear();
+                self.release_resource_references.clear();
                 // Store release attributes for later use
                 self.release_attributes = self.current_attributes.clone();
             }
@@ -247,10 +253,21 @@ impl<R: BufRead> WorkingStreamingParser<R> {
         self.current_depth = self.current_depth.saturating_sub(1);
         self.current_element.pop();
 
-        // Store current text content
+        // Store current text content; a release keeps the first of each
+        // field, so a nested title such as a resource group's cannot
+        // replace its own
         let text_content = self.text_buffer.clone();
         if !text_content.is_empty() {
-            self.current_fields.insert(name.to_string(), text_content);
+            if self.in_release {
+                if name == "ReleaseResourceReference" {
+                    self.release_resource_references.push(text_content.clone());
+                }
+                self.current_fields
+                    .entry(name.to_string())
+                    .or_insert(text_content);
+            } else {
+                self.current_fields.insert(name.to_string(), text_content);
+            }
         }
 
         // Check if we completed a major element
@@ -271,7 +288,7 @@ impl<R: BufRead> WorkingStreamingParser<R> {
                     version: self.version,
                 })
             }
-            "Release" => {
+            "Release" | "TrackRelease" if self.in_release => {
                 self.in_release = false;
                 let reference = self
                     .release_attributes
@@ -281,15 +298,20 @@ impl<R: BufRead> WorkingStreamingParser<R> {
                     .clone();
                 let title = self
                     .current_fields
-                    .get("TitleText")
+                    .get("DisplayTitleText")
+                    .or_else(|| self.current_fields.get("TitleText"))
                     .or_else(|| self.current_fields.get("Title"))
                     .or_else(|| self.current_fields.get("ReferenceTitle"))
                     .unwrap_or(&"Untitled Release".to_string())
                     .clone();
+                let release_type = self.current_fields.get("ReleaseType").cloned().or_else(|| {
+                    (name == "TrackRelease").then(|| "TrackRelease".to_string())
+                });
                 Some(WorkingStreamingElement::Release {
                     reference,
                     title,
-                    resource_references: self.extract_resource_references(),
+                    release_type,
+                    resource_references: std::mem::take(&mut self.release_resource_references),
                 })
             }
             "SoundRecording" => {
@@ -384,13 +406,6 @@ impl<R: BufRead> WorkingStreamingParser<R> {
             .clone()
     }
 
-    /// Extract resource references from current release context
-    fn extract_resource_references(&self) -> Vec<String> {
-        // This is a simplified implementation
-        // In a real implementation, we'd track ResourceReference elements
-        vec![]
-    }
-
     /// Update memory usage tracking
     fn update_memory_usage(&mut self) {
         let estimated_memory = self.buffer.capacity()
@@ -160,12 +160,22 @@ impl ZeroCopyParser {
         let mut results = Vec::new();
 
         // Use SIMD-accelerated pattern matching to find element boundaries
-        let release_positions = self.find_elements_simd(data, b"<Release")?;
-        let sound_recording_positions = self.find_elements_simd(data, b"<SoundRecording")?;
-        let video_positions = self.find_elements_simd(data, b"<Video")?;
-        let image_positions = self.find_elements_simd(data, b"<Image")?;
-        let text_positions = self.find_elements_simd(data, b"<Text")?;
-        let message_header_positions = self.find_elements_simd(data, b"<MessageHeader")?;
+        let mut release_positions: Vec<(usize, &[u8])> = self
+            .find_start_tags(data, b"<Release")?
+            .into_iter()
+            .map(|pos| (pos, b"Release".as_slice()))
+            .chain(
+                self.find_start_tags(data, b"<TrackRelease")?
+                    .into_iter()
+                    .map(|pos| (pos, b"TrackRelease".as_slice())),
+            )
+            .collect();
+        release_positions.sort_unstable();
+        let sound_recording_positions = self.find_start_tags(data, b"<SoundRecording")?;
+        let video_positions = self.find_start_tags(data, b"<Video")?;
+        let image_positions = self.find_start_tags(data, b"<Image")?;
+        let text_positions = self.find_start_tags(data, b"<Text")?;
+        let message_header_positions = self.find_start_tags(data, b"<MessageHeader")?;
 
         // Process message headers
         for pos in message_header_positions {
@@ -176,8 +186,8 @@ impl ZeroCopyParser {
         }
 
         // Process releases with zero-copy extraction
-        for pos in release_positions {
-            if let Some(element) = self.extract_release_zero_copy(data, pos)? {
+        for (pos, tag_name) in release_positions {
+            if let Some(element) = self.extract_release_zero_copy(data, pos, tag_name)? {
                 results.push(element);
                 self.stats.elements_found += 1;
             }
@@ -289,6 +299,21 @@ impl ZeroCopyParser {
         Ok(positions)
     }
 
+    /// Positions of start tags `pattern` names exactly, so `<Release` does not
+    /// also match `<ReleaseList>` or `<ReleaseType>`
+    fn find_start_tags(&self, data: &[u8], pattern: &[u8]) -> Result<Vec<usize>, ParseError> {
+        Ok(self
+            .find_elements_simd(data, pattern)?
+            .into_iter()
+            .filter(|&pos| {
+                matches!(
+                    data.get(pos + pattern.len()),
+                    Some(b'>' | b'/' | b' ' | b'\t' | b'\r' | b'\n')
+                )
+            })
+            .collect())
+    }
+
     /// Fallback pattern matching for non-x86_64 or long patterns
     #[cfg(not(target_arch = "x86_64"))]
     fn find_elements_simd(&self, data: &[u8], pattern: &[u8]) -> Result<Vec<usize>, ParseError> {
@@ -359,13 +384,14 @@ impl ZeroCopyParser {
         Ok(None)
     }
 
-    /// Zero-copy release extraction
+    /// Zero-copy release extraction; `tag_name` is `Release` or `TrackRelease`
     fn extract_release_zero_copy(
         &mut self,
         data: &[u8],
         start: usize,
+        tag_name: &[u8],
     ) -> Result<Option<ZeroCopyElement>, ParseError> {
-        if let Some(end_pos) = self.find_closing_tag(data, start, b"Release") {
+        if let Some(end_pos) = self.find_closing_tag(data, start, tag_name) {
             let release_data = &data[start..end_pos];
 
             // Extract ReleaseReference attribute
@@ -780,6 +806,7 @@ impl<R: BufRead> ZeroCopyStreamIterator<R> {
             } => WorkingStreamingElement::Release {
                 reference,
                 title,
+                release_type: None,
                 resource_references,
             },
             ZeroCopyElement::SoundRecording {
@@ -4,15 +4,17 @@
 use crate::error::ParseError;
 
 type Result<T> = std::result::Result<T, ParseError>;
-use ddex_core::models::common::{Identifier, LocalizedString};
+use ddex_core::models::common::{Description, Identifier, LocalizedString};
 use ddex_core::models::flat::{
-    ArtistInfo, DealValidity, DistributionComplexity, FlattenedMessage, MessageStats, Organization,
-    ParsedDeal, ParsedRelease, ParsedResource, ParsedTrack, PriceTier, PriceType, ProprietaryId,
-    ReleaseIdentifiers, TechnicalInfo, TerritoryComplexity, TerritoryInfo,
+    ArtistInfo, AuditTrailEntry, ContributorInfo, DealValidity, DistributionComplexity, FlattenedMessage, IdentifierIndex,
+    MessageStats, Organization,
+    ParsedDeal, ParsedRelease, ParsedResource, ParsedTrack, PriceTier, PriceType, ProprietaryId, PurgedRelease,
+    ReleaseIdentifiers, TechnicalInfo, TerritoryComplexity, TerritoryInfo, UsageAllowance, UsageRight,
 };
 use ddex_core::models::graph::{
-    Artist, Deal, DealTerms, ERNMessage, Party, Release, ReleaseResourceReference, Resource,
-    ResourceType,
+    Artist, CommercialModelType, Contributor, Deal, DealTerms, ERNMessage, MessageAuditTrail, Party, Release,
+    ReleaseResourceReference, Resource, ResourceType, TechnicalDetails, UseType, HAS_COMPONENT_RELEASE,
+    IS_COMPONENT_RELEASE_OF, LABEL_ROLE,
 };
 use indexmap::IndexMap;
 use std::collections::HashMap;
@@ -21,10 +23,11 @@ pub struct Flattener;
 
 impl Flattener {
     pub fn flatten(graph: ERNMessage) -> Result<FlattenedMessage> {
-        let releases = Self::flatten_releases(&graph.releases, &graph.resources)?;
+        let releases = Self::flatten_releases(&graph.releases, &graph.resources, &graph.parties)?;
         let resources = Self::flatten_resources(&graph.resources)?;
         let deals = Self::flatten_deals(&graph.deals)?;
         let parties = Self::flatten_parties(&graph.parties)?;
+        let identifier_index = Self::build_identifier_index(&graph.releases, &graph.resources);
 
         let stats = MessageStats {
             release_count: graph.releases.len(),
@@ -40,142 +43,378 @@ impl Flattener {
             sender: Organization {
                 name: Self::get_primary_name(&graph.message_header.message_sender.party_name, "MessageSender/PartyName")?,
                 id: Self::get_primary_id(&graph.message_header.message_sender.party_id, "MessageSender/PartyId")?,
+                names: graph.message_header.message_sender.party_name.clone(),
+                trading_name: graph.message_header.message_sender.trading_name.clone(),
                 extensions: None,
             },
             recipient: Organization {
                 name: Self::get_primary_name(&graph.message_header.message_recipient.party_name, "MessageRecipient/PartyName")?,
                 id: Self::get_primary_id(&graph.message_header.message_recipient.party_id, "MessageRecipient/PartyId")?,
+                names: graph.message_header.message_recipient.party_name.clone(),
+                trading_name: graph.message_header.message_recipient.trading_name.clone(),
                 extensions: None,
             },
             releases,
             resources,
             deals,
             parties,
+            purged_releases: graph
+                .purged_releases
+                .iter()
+                .map(|release| Self::flatten_purged_release(release, &graph.parties))
+                .collect(),
             version: format!("{:?}", graph.version),
             profile: graph.profile.map(|p| format!("{:?}", p)),
             stats,
+            identifier_index,
+            audit_trail: Self::flatten_audit_trail(graph.message_audit_trail.as_ref()),
             extensions: None,
         })
     }
 
-    fn flatten_releases(releases: &[Release], resources: &[Resource]) -> Result<Vec<ParsedRelease>> {
-        releases
+    fn flatten_audit_trail(audit_trail: Option<&MessageAuditTrail>) -> Vec<AuditTrailEntry> {
+        audit_trail
+            .map(|trail| {
+                trail
+                    .audit_trail_events
+                    .iter()
+                    .map(|event| AuditTrailEntry {
+                        event_type: event.message_audit_trail_event_type.clone(),
+                        date_time: event.date_time,
+                        party: event.responsible_party_reference.clone(),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn build_identifier_index(releases: &[Release], resources: &[Resource]) -> IdentifierIndex {
+        let mut index = IdentifierIndex::new();
+        // Releases keep their graph order when flattened, so positions line up
+        for (position, release) in releases.iter().enumerate() {
+            index.insert_release(&release.release_id, position);
+        }
+        for resource in resources {
+            index.insert_resource(&resource.resource_id, &resource.resource_reference);
+        }
+        index
+    }
+
+    fn flatten_releases(releases: &[Release], resources: &[Resource], parties: &[Party]) -> Result<Vec<ParsedRelease>> {
+        let mut parsed = releases
             .iter()
-            .map(|release| Ok(ParsedRelease {
-                release_id: release.release_reference.clone(),
-                identifiers: Self::extract_identifiers(&release.release_id),
-                title: release.release_title.clone(),
-                default_title: Self::get_primary_title(&release.release_title, "Release/Title/TitleText")?,
-                subtitle: release.release_subtitle.clone(),
-                default_subtitle: release
-                    .release_subtitle
-                    .as_ref()
-                    .map(|s| Self::get_primary_title_optional(s))
-                    .flatten(),
-                display_artist: Self::format_display_artist(&release.display_artist)?,
-                artists: Self::extract_artists(&release.display_artist)?,
-                release_type: release
-                    .release_type
-                    .as_ref()
-                    .map(|t| format!("{:?}", t))
-                    .ok_or_else(|| ParseError::MissingField("Release/ReleaseType".to_string()))?,
-                genre: release.genre.first().map(|g| g.genre_text.clone()),
-                sub_genre: release.genre.first().and_then(|g| g.sub_genre.clone()),
-                tracks: Self::build_tracks(&release.release_resource_reference_list, resources)?,
-                track_count: release.release_resource_reference_list.len(),
-                disc_count: Self::count_discs(&release.release_resource_reference_list),
-                videos: Vec::new(),
-                images: Vec::new(),
-                cover_art: None,
-                release_date: release.release_date.first().and_then(|e| e.event_date),
-                original_release_date: None,
-                territories: Self::build_territories(
-                    &release.territory_code,
-                    &release.excluded_territory_code,
-                ),
-                p_line: None,
-                c_line: None,
-                parent_release: None,
-                child_releases: Vec::new(),
-                extensions: None,
-            }))
+            .map(|release| Self::flatten_release(release, resources, parties))
+            .collect::<Result<Vec<_>>>()?;
+
+        // Link track releases and the releases they come from both ways
+        for (child, release) in releases.iter().enumerate() {
+            if let Some(parent) = Self::parent_release(release, releases) {
+                parsed[child].parent_release = Some(releases[parent].release_reference.clone());
+                parsed[parent].child_releases.push(release.release_reference.clone());
+            }
+        }
+
+        // Link bundles and their components both ways, whichever side names the link
+        for (position, release) in releases.iter().enumerate() {
+            let components = Self::related_positions(release, releases, HAS_COMPONENT_RELEASE)
+                .into_iter()
+                .map(|component| (position, component));
+            let bundles = Self::related_positions(release, releases, IS_COMPONENT_RELEASE_OF)
+                .into_iter()
+                .map(|bundle| (bundle, position));
+            for (bundle, component) in components.chain(bundles) {
+                let reference = &releases[component].release_reference;
+                if !parsed[bundle].component_release_references.contains(reference) {
+                    parsed[bundle].component_release_references.push(reference.clone());
+                }
+                parsed[component].bundle_release_reference = Some(releases[bundle].release_reference.clone());
+            }
+        }
+
+        Ok(parsed)
+    }
+
+    /// Position of the release that `release` names as its source through an
+    /// `IsReleaseFromRelease` link, matched by identifier
+    fn parent_release(release: &Release, releases: &[Release]) -> Option<usize> {
+        Self::related_positions(release, releases, "IsReleaseFromRelease").into_iter().next()
+    }
+
+    /// Positions of the other releases `release` links to with
+    /// `relationship_type`, matched by identifier, in link order
+    fn related_positions(release: &Release, releases: &[Release], relationship_type: &str) -> Vec<usize> {
+        release
+            .related_releases
+            .iter()
+            .filter(|related| related.relationship_type == relationship_type)
+            .filter_map(|related| {
+                releases.iter().position(|candidate| {
+                    !std::ptr::eq(candidate, release)
+                        && candidate
+                            .release_id
+                            .iter()
+                            .any(|id| related.release_id.iter().any(|related_id| related_id.value == id.value))
+                })
+            })
             .collect()
     }
 
+    /// Flatten one release, resolving its tracks against `resources` and its
+    /// artists against `parties`
+    pub(crate) fn flatten_release(release: &Release, resources: &[Resource], parties: &[Party]) -> Result<ParsedRelease> {
+        let tracks = Self::build_tracks(&release.release_resource_reference_list, resources, parties)?;
+        // Listed tracks, those missing from the ResourceList included
+        let track_count = tracks.len();
+        Ok(ParsedRelease {
+            release_id: release.release_reference.clone(),
+            identifiers: Self::extract_identifiers(&release.release_id),
+            title: release.release_title.clone(),
+            default_title: Self::get_primary_title(&release.release_title, "Release/Title/TitleText")?,
+            reference_title: release.reference_title.clone(),
+            display_title: release.display_title.clone(),
+            subtitle: release.release_subtitle.clone(),
+            default_subtitle: release
+                .release_subtitle
+                .as_ref()
+                .map(|s| Self::get_primary_title_optional(s))
+                .flatten(),
+            display_artist: Self::format_display_artist(&release.display_artist, parties),
+            artists: Self::extract_artists(&release.display_artist, parties)?,
+            release_type: release
+                .release_type
+                .as_ref()
+                .map(|t| format!("{:?}", t))
+                .ok_or_else(|| ParseError::MissingField("Release/ReleaseType".to_string()))?,
+            genre: release.genre.first().map(|g| g.genre_text.clone()),
+            sub_genre: release.genre.first().and_then(|g| g.sub_genre.clone()),
+            label_name: Self::label_name(release, parties),
+            total_duration_seconds: Self::total_duration_seconds(&tracks),
+            tracks,
+            track_count,
+            disc_count: Self::count_discs(&release.release_resource_reference_list),
+            videos: Vec::new(),
+            images: Vec::new(),
+            cover_art: None,
+            release_date: release
+                .release_date
+                .iter()
+                .find(|e| e.territory.is_none())
+                .or_else(|| release.release_date.first())
+                .and_then(|e| e.event_date),
+            release_dates_by_territory: release
+                .release_date
+                .iter()
+                .filter(|e| e.release_event_type == "ReleaseDate")
+                .filter_map(|e| Some((e.territory.clone()?, e.event_date?)))
+                .collect(),
+            original_release_date: None,
+            preorder_date: release.visibility.as_ref().and_then(|v| v.release_display_start),
+            availability_start: release
+                .release_date
+                .iter()
+                .filter(|e| e.release_event_type == "ReleaseDate")
+                .min_by_key(|e| e.territory.is_some())
+                .and_then(|e| e.event_date),
+            territories: Self::build_territories(
+                &release.territory_code,
+                &release.excluded_territory_code,
+            ),
+            p_line: None,
+            c_line: None,
+            parent_release: None,
+            child_releases: Vec::new(),
+            component_release_references: Vec::new(),
+            bundle_release_reference: None,
+            descriptions: Self::group_descriptions(&release.descriptions),
+            raw: None,
+            extensions: None,
+        })
+    }
+
+    /// Flatten a release a purge names; unlike a release delivered in full
+    /// it need have neither title nor type
+    fn flatten_purged_release(release: &Release, parties: &[Party]) -> PurgedRelease {
+        PurgedRelease {
+            identifiers: Self::extract_identifiers(&release.release_id),
+            // release_title falls back to a placeholder, which a purge need not
+            title: Self::get_primary_title_optional(&release.reference_title)
+                .or_else(|| Self::get_primary_title_optional(&release.display_title)),
+            display_artist: Self::format_display_artist(&release.display_artist, parties),
+        }
+    }
+
+    /// Flatten the sound recordings and videos; the graph's other resources
+    /// are only there to tell them apart from tracks
     fn flatten_resources(resources: &[Resource]) -> Result<IndexMap<String, ParsedResource>> {
         resources
             .iter()
-            .map(|resource| {
-                let parsed = ParsedResource {
-                    resource_id: resource.resource_reference.clone(),
-                    resource_type: format!("{:?}", resource.resource_type),
-                    // ReferenceTitle is not present on all resource types (e.g., Image in ERN 3.8.2).
-                    // Use optional getter and fallback to resource reference if not present.
-                    title: Self::get_primary_title_optional(&resource.reference_title)
-                        .filter(|t| !t.is_empty())
-                        .unwrap_or_else(|| resource.resource_reference.clone()),
-                    duration: resource.duration,
-                    technical_details: TechnicalInfo {
-                        file_format: resource
-                            .technical_details
-                            .first()
-                            .and_then(|t| t.file_format.clone()),
-                        bitrate: resource.technical_details.first().and_then(|t| t.bitrate),
-                        sample_rate: resource
-                            .technical_details
-                            .first()
-                            .and_then(|t| t.sample_rate),
-                        file_size: resource.technical_details.first().and_then(|t| t.file_size),
-                    },
-                };
-                Ok((resource.resource_reference.clone(), parsed))
-            })
+            .filter(|resource| matches!(resource.resource_type, ResourceType::SoundRecording | ResourceType::Video))
+            .map(|resource| Ok((resource.resource_reference.clone(), Self::flatten_resource(resource))))
             .collect()
     }
 
+    /// Flatten one resource
+    pub(crate) fn flatten_resource(resource: &Resource) -> ParsedResource {
+        let primary = Self::primary_technical_details(resource);
+        ParsedResource {
+            resource_id: resource.resource_reference.clone(),
+            resource_type: format!("{:?}", resource.resource_type),
+            // ReferenceTitle is not present on all resource types (e.g., Image in ERN 3.8.2).
+            // Use optional getter and fallback to resource reference if not present.
+            title: Self::get_primary_title_optional(&resource.reference_title)
+                .filter(|t| !t.is_empty())
+                .unwrap_or_else(|| resource.resource_reference.clone()),
+            duration: resource.duration,
+            technical_details: TechnicalInfo {
+                file_format: primary.and_then(|t| t.file_format.clone()),
+                audio_codec: primary.and_then(|t| t.audio_codec.clone()),
+                bitrate: primary.and_then(|t| t.bitrate),
+                sample_rate: primary.and_then(|t| t.sample_rate),
+                bits_per_sample: primary.and_then(|t| t.bits_per_sample),
+                file_size: primary.and_then(|t| t.file_size),
+                files: resource
+                    .technical_details
+                    .iter()
+                    .flat_map(|t| t.files.iter().cloned())
+                    .collect(),
+                preview: resource.technical_details.iter().find_map(|t| t.preview),
+            },
+            video_details: resource
+                .technical_details
+                .iter()
+                .find_map(|t| t.video.clone()),
+            descriptions: Self::group_descriptions(&resource.descriptions),
+            raw: None,
+        }
+    }
+
+    /// The technical details block the flat view reports a resource's audio
+    /// properties from. A resource delivered as several files has a block per
+    /// file; the first, in document order, whose codec is PCM or lossless
+    /// (PCM, WAV, FLAC, ALAC, AIFF) is chosen, else the first block.
+    fn primary_technical_details(resource: &Resource) -> Option<&TechnicalDetails> {
+        const LOSSLESS: [&str; 5] = ["PCM", "WAV", "FLAC", "ALAC", "AIFF"];
+        resource
+            .technical_details
+            .iter()
+            .find(|t| {
+                t.audio_codec.as_deref().is_some_and(|codec| {
+                    let codec = codec.to_ascii_uppercase();
+                    LOSSLESS.iter().any(|lossless| codec.contains(lossless))
+                })
+            })
+            .or_else(|| resource.technical_details.first())
+    }
+
+    fn group_descriptions(descriptions: &[Description]) -> IndexMap<String, Vec<LocalizedString>> {
+        let mut grouped: IndexMap<String, Vec<LocalizedString>> = IndexMap::new();
+        for description in descriptions {
+            grouped
+                .entry(description.description_type.clone())
+                .or_default()
+                .push(description.text.clone());
+        }
+        grouped
+    }
+
     fn flatten_deals(deals: &[Deal]) -> Result<Vec<ParsedDeal>> {
         deals
             .iter()
             .enumerate()
-            .map(|(idx, deal)| Ok(ParsedDeal {
-                // DealReference is optional per DDEX ERN spec (minOccurs="0").
-                // Generate an auto-ID if not present.
-                deal_id: deal
-                    .deal_reference
-                    .clone()
-                    .unwrap_or_else(|| format!("DEAL_AUTO_{}", idx + 1)),
-                releases: deal.deal_release_reference.clone(),
-                validity: DealValidity {
-                    start: deal.deal_terms.start_date,
-                    end: deal.deal_terms.end_date,
-                },
-                territories: TerritoryComplexity {
-                    included: deal.deal_terms.territory_code.clone(),
-                    excluded: deal.deal_terms.excluded_territory_code.clone(),
-                },
-                distribution_channels: DistributionComplexity {
-                    included: deal
-                        .deal_terms
-                        .distribution_channel
-                        .iter()
-                        .map(|c| format!("{:?}", c))
-                        .collect(),
-                    excluded: deal
-                        .deal_terms
-                        .excluded_distribution_channel
-                        .iter()
-                        .map(|c| format!("{:?}", c))
-                        .collect(),
-                },
-                pricing: Self::build_price_tiers(&deal.deal_terms),
-                usage_rights: deal
+            .map(|(idx, deal)| Ok(Self::flatten_deal(deal, idx)))
+            .collect()
+    }
+
+    /// Flatten one deal; `idx` is its position among the message's deals
+    pub(crate) fn flatten_deal(deal: &Deal, idx: usize) -> ParsedDeal {
+        let use_types: Vec<String> = deal
+            .deal_terms
+            .use_type
+            .iter()
+            .map(|use_type| match use_type {
+                UseType::Other(other) => other.clone(),
+                use_type => format!("{:?}", use_type),
+            })
+            .collect();
+        let commercial_models: Vec<String> = deal
+            .deal_terms
+            .commercial_model_type
+            .iter()
+            .map(|model| match model {
+                CommercialModelType::Other(other) => other.clone(),
+                model => format!("{:?}", model),
+            })
+            .collect();
+        let rights = Self::usage_rights(&use_types, &commercial_models, deal.deal_terms.take_down);
+        // A takedown lists the uses it withdraws, so they are restrictions rather than rights
+        let (usage_rights, restrictions) = if deal.deal_terms.take_down {
+            (Vec::new(), use_types)
+        } else {
+            (use_types, Vec::new())
+        };
+
+        ParsedDeal {
+            // DealReference is optional per DDEX ERN spec (minOccurs="0").
+            // Generate an auto-ID if not present.
+            deal_id: deal
+                .deal_reference
+                .clone()
+                .unwrap_or_else(|| format!("DEAL_AUTO_{}", idx + 1)),
+            releases: deal.deal_release_reference.clone(),
+            validity: DealValidity {
+                start: deal.deal_terms.start_date,
+                end: deal.deal_terms.end_date,
+                start_text: deal.deal_terms.start_date_text.clone(),
+                end_text: deal.deal_terms.end_date_text.clone(),
+            },
+            territories: TerritoryComplexity {
+                included: deal.deal_terms.territory_code.clone(),
+                excluded: deal.deal_terms.excluded_territory_code.clone(),
+            },
+            distribution_channels: DistributionComplexity {
+                included: deal
                     .deal_terms
-                    .use_type
+                    .distribution_channel
                     .iter()
-                    .map(|u| format!("{:?}", u))
+                    .map(|c| format!("{:?}", c))
                     .collect(),
-                restrictions: Vec::new(),
-            }))
+                excluded: deal
+                    .deal_terms
+                    .excluded_distribution_channel
+                    .iter()
+                    .map(|c| format!("{:?}", c))
+                    .collect(),
+            },
+            pricing: Self::build_price_tiers(&deal.deal_terms),
+            usage_rights,
+            restrictions,
+            commercial_models,
+            rights,
+        }
+    }
+
+    /// One typed right per use type and commercial model of a deal
+    fn usage_rights(use_types: &[String], commercial_models: &[String], take_down: bool) -> Vec<UsageRight> {
+        let allowance = if take_down {
+            UsageAllowance::Disallowed
+        } else {
+            UsageAllowance::Allowed
+        };
+        let models: Vec<Option<&String>> = if commercial_models.is_empty() {
+            vec![None]
+        } else {
+            commercial_models.iter().map(Some).collect()
+        };
+
+        use_types
+            .iter()
+            .flat_map(|use_type| {
+                models.iter().map(move |model| UsageRight {
+                    use_type: use_type.clone(),
+                    commercial_model: model.cloned(),
+                    allowance,
+                })
+            })
             .collect()
     }
 
@@ -183,8 +422,12 @@ impl Flattener {
         parties
             .iter()
             .map(|party| {
-                let id = Self::get_primary_id(&party.party_id, "Party/PartyId")?;
-                Ok((id, party.clone()))
+                // Keyed by reference like resources; ERN 4 parties need not carry an id
+                let key = match &party.party_reference {
+                    Some(reference) => reference.clone(),
+                    None => Self::get_primary_id(&party.party_id, "Party/PartyId")?,
+                };
+                Ok((key, party.clone()))
             })
             .collect()
     }
@@ -272,30 +515,92 @@ impl Flattener {
         identifiers
     }
 
-    fn format_display_artist(artists: &[Artist]) -> Result<String> {
-        let names: Result<Vec<String>> = artists
+    /// The party an artist or contributor refers to by `PartyReference`
+    fn referenced_party<'a>(reference: Option<&str>, parties: &'a [Party]) -> Option<&'a Party> {
+        let reference = reference?;
+        parties
             .iter()
-            .map(|a| Self::get_primary_name(&a.display_artist_name, "Artist/DisplayArtistName"))
-            .collect();
-        Ok(names?.join(", "))
+            .find(|party| party.party_reference.as_deref() == Some(reference))
     }
 
-    fn extract_artists(artists: &[Artist]) -> Result<Vec<ArtistInfo>> {
-        artists
-            .iter()
-            .map(|artist| Ok(ArtistInfo {
-                name: Self::get_primary_name(&artist.display_artist_name, "Artist/DisplayArtistName")?,
+    /// The release's inline `LabelName`, else the name of the party its
+    /// `ReleaseLabelReference` names
+    fn label_name(release: &Release, parties: &[Party]) -> Option<String> {
+        release.label_name.clone().or_else(|| {
+            release
+                .party_list
+                .iter()
+                .filter(|party| party.role.iter().any(|role| role == LABEL_ROLE))
+                .find_map(|party| {
+                    Self::referenced_party(Some(&party.party_reference), parties)
+                        .and_then(|party| Self::get_primary_name_optional(&party.party_name))
+                })
+        })
+    }
+
+    /// Artists paired with the name to show for them: their own display name,
+    /// else that of their party. Artists with neither are left out; the graph
+    /// still carries them
+    fn named<'a>(artists: &'a [Artist], parties: &'a [Party]) -> impl Iterator<Item = (&'a Artist, String)> {
+        artists.iter().filter_map(move |artist| {
+            let name = Self::get_primary_name_optional(&artist.display_artist_name).or_else(|| {
+                Self::referenced_party(artist.party_reference.as_deref(), parties)
+                    .and_then(|party| Self::get_primary_name_optional(&party.party_name))
+            })?;
+            Some((artist, name))
+        })
+    }
+
+    fn format_display_artist(artists: &[Artist], parties: &[Party]) -> String {
+        Self::named(artists, parties)
+            .map(|(_, name)| name)
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    fn extract_artists(artists: &[Artist], parties: &[Party]) -> Result<Vec<ArtistInfo>> {
+        Self::named(artists, parties)
+            .map(|(artist, name)| Ok(ArtistInfo {
+                name,
                 role: artist
                     .artist_role
                     .first()
                     .cloned()
                     .ok_or_else(|| ParseError::MissingField("Artist/ArtistRole".to_string()))?,
                 party_id: artist.party_reference.clone(),
+                identifiers: Self::referenced_party(artist.party_reference.as_deref(), parties)
+                    .map(|party| party.party_id.clone())
+                    .unwrap_or_default(),
             }))
             .collect()
     }
 
-    fn build_tracks(refs: &[ReleaseResourceReference], resources: &[Resource]) -> Result<Vec<ParsedTrack>> {
+    /// Contributors with their roles untouched, named like artists: by their
+    /// own name, else that of their party
+    fn extract_contributors(contributors: &[Contributor], parties: &[Party]) -> Vec<ContributorInfo> {
+        contributors
+            .iter()
+            .filter_map(|contributor| {
+                let name = Self::get_primary_name_optional(&contributor.party_name).or_else(|| {
+                    Self::referenced_party(contributor.party_reference.as_deref(), parties)
+                        .and_then(|party| Self::get_primary_name_optional(&party.party_name))
+                })?;
+                Some(ContributorInfo {
+                    name,
+                    roles: contributor.role.clone(),
+                    instruments: contributor.instrument_type.clone(),
+                    party_id: contributor.party_reference.clone(),
+                })
+            })
+            .collect()
+    }
+
+    /// Summed runtime of a release's tracks; tracks without a duration count as zero
+    fn total_duration_seconds(tracks: &[ParsedTrack]) -> u64 {
+        tracks.iter().map(|track| track.duration).sum::<std::time::Duration>().as_secs()
+    }
+
+    fn build_tracks(refs: &[ReleaseResourceReference], resources: &[Resource], parties: &[Party]) -> Result<Vec<ParsedTrack>> {
         refs.iter()
             .enumerate()
             .filter_map(|(idx, rref)| {
@@ -322,6 +627,13 @@ impl Flattener {
                     .map(ParsedTrack::format_duration)
                     .unwrap_or_else(|| "0:00".to_string());
 
+                let artists = match resource.map(|r| Self::extract_artists(&r.display_artist, parties)).transpose() {
+                    Ok(artists) => artists.unwrap_or_default(),
+                    Err(e) => return Some(Err(e)),
+                };
+
+                let technical = resource.and_then(Self::primary_technical_details);
+
                 Some(Ok(ParsedTrack {
                     track_id: rref.resource_reference.clone(),
                     isrc: resource.and_then(|r| {
@@ -341,14 +653,19 @@ impl Flattener {
                     disc_number: rref.disc_number,
                     side: rref.side.clone(),
                     title,
-                    subtitle: None,
-                    display_artist: String::new(),
-                    artists: Vec::new(),
+                    subtitle: resource.and_then(|r| r.subtitle.first()).map(|s| s.text.clone()),
+                    display_artist: resource
+                        .map(|r| Self::format_display_artist(&r.display_artist, parties))
+                        .unwrap_or_default(),
+                    artists,
+                    contributors: resource
+                        .map(|r| Self::extract_contributors(&r.contributors, parties))
+                        .unwrap_or_default(),
                     duration: duration.unwrap_or_default(),
                     duration_formatted,
-                    file_format: None,
-                    bitrate: None,
-                    sample_rate: None,
+                    file_format: technical.and_then(|t| t.file_format.clone()),
+                    bitrate: technical.and_then(|t| t.bitrate),
+                    sample_rate: technical.and_then(|t| t.sample_rate),
                     is_hidden: rref.is_hidden,
                     is_bonus: rref.is_bonus,
                     is_explicit: false,
@@ -402,6 +719,7 @@ impl Flattener {
                 territory: price.territory.clone(),
                 start_date: terms.start_date,
                 end_date: terms.end_date,
+                amount_text: None,
             });
         }
 
@@ -413,6 +731,19 @@ impl Flattener {
                 territory: price.territory.clone(),
                 start_date: terms.start_date,
                 end_date: terms.end_date,
+                amount_text: None,
+            });
+        }
+
+        for info in &terms.price_information {
+            tiers.push(PriceTier {
+                tier_name: Some(info.price_type.clone()).filter(|name| !name.is_empty()),
+                price_type: PriceType::Wholesale,
+                price: info.price.clone(),
+                territory: info.price.territory.clone(),
+                start_date: terms.start_date,
+                end_date: terms.end_date,
+                amount_text: info.amount_text.clone(),
             });
         }
 
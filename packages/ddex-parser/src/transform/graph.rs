@@ -2,22 +2,42 @@
 // Remove unused imports and variables
 use crate::error::ParseError;
 use crate::parser::namespace_detector::NamespaceContext;
+use crate::parser::whitespace::WhitespaceMode;
 use crate::parser::xml_validator::XmlValidator;
 use ddex_core::models::graph::{
-    ERNMessage, MessageHeader, MessageRecipient, MessageSender, MessageType, Release,
+    ERNMessage, MessageControlType, MessageHeader, MessageRecipient, MessageSender, MessageType, Party, PartyRole,
+    Release, ResourceType,
 };
 use ddex_core::models::versions::ERNVersion;
 use quick_xml::events::Event;
 use quick_xml::Reader;
 use std::io::BufRead;
 
+/// Free-text elements collected into `descriptions` on releases and resources
+const DESCRIPTION_ELEMENTS: &[&[u8]] = &[
+    b"MarketingComment",
+    b"EditorialDescription",
+    b"AdditionalText",
+    b"Synopsis",
+];
+
 pub struct GraphBuilder {
     version: ERNVersion,
+    whitespace: WhitespaceMode,
 }
 
 impl GraphBuilder {
     pub fn new(version: ERNVersion) -> Self {
-        Self { version }
+        Self {
+            version,
+            whitespace: WhitespaceMode::default(),
+        }
+    }
+
+    /// Set how whitespace in titles, names and descriptions is handled
+    pub fn with_whitespace(mut self, whitespace: WhitespaceMode) -> Self {
+        self.whitespace = whitespace;
+        self
     }
 
     pub fn build_from_xml<R: BufRead + std::io::Seek>(
@@ -38,7 +58,7 @@ impl GraphBuilder {
         let mut xml_reader = Reader::from_reader(&mut reader);
 
         // Enable strict XML validation
-        xml_reader.config_mut().trim_text(true);
+        xml_reader.config_mut().trim_text(self.whitespace != WhitespaceMode::Preserve);
         xml_reader.config_mut().check_end_names = true;
         xml_reader.config_mut().expand_empty_elements = false;
 
@@ -48,21 +68,25 @@ impl GraphBuilder {
         // Reset reader to start for main parsing loop
         reader.seek(std::io::SeekFrom::Start(0))?;
         xml_reader = Reader::from_reader(&mut reader);
-        xml_reader.config_mut().trim_text(true);
+        xml_reader.config_mut().trim_text(self.whitespace != WhitespaceMode::Preserve);
         xml_reader.config_mut().check_end_names = true;
         xml_reader.config_mut().expand_empty_elements = false;
 
         let mut validator = XmlValidator::strict();
         let mut releases = Vec::new();
         let mut resources = Vec::new(); // Made mutable to collect parsed resources
-        let parties = Vec::new(); // Remove mut
+        let mut parties = Vec::new();
         let mut deals = Vec::new(); // Made mutable to collect parsed deals
+        let mut purged_releases = Vec::new();
+        let mut visibilities = Vec::new();
+        let mut message_audit_trail = None;
 
         // Parse with XML validation and depth tracking
         let mut buf = Vec::new();
         let mut in_release_list = false;
         let mut in_resource_list = false;
         let mut in_deal_list = false;
+        let mut in_party_list = false;
 
         loop {
             match xml_reader.read_event_into(&mut buf) {
@@ -80,10 +104,22 @@ impl GraphBuilder {
 
                     match event {
                         Event::Start(ref e) => {
-                            match e.name().as_ref() {
+                            // Elements are matched by local name throughout, so a
+                            // prefixed `ern:Release` and a default-namespace `Release`
+                            // resolve identically even when mixed in one document
+                            match e.local_name().as_ref() {
                                 b"ReleaseList" => in_release_list = true,
                                 b"ResourceList" => in_resource_list = true,
                                 b"DealList" => in_deal_list = true,
+                                b"PartyList" => in_party_list = true,
+                                b"MessageAuditTrail" => {
+                                    message_audit_trail = Some(
+                                        self.parse_message_audit_trail(&mut xml_reader, &mut validator)?,
+                                    );
+                                }
+                                b"Party" if in_party_list => {
+                                    parties.push(self.parse_party(&mut xml_reader, &mut validator)?);
+                                }
                                 b"Release" if in_release_list => {
                                     // Create a minimal release and manually validate the end event
                                     releases.push(
@@ -93,15 +129,47 @@ impl GraphBuilder {
                                         )?,
                                     );
                                 }
-                                b"SoundRecording" if in_resource_list => {
-                                    // Parse the SoundRecording and add it to resources
-                                    resources.push(
-                                        self.parse_sound_recording(
+                                // A PurgeReleaseMessage names its releases outside any list
+                                b"PurgedRelease" => {
+                                    purged_releases.push(
+                                        self.parse_minimal_release(
+                                            &mut xml_reader,
+                                            &mut validator,
+                                        )?,
+                                    );
+                                }
+                                b"TrackRelease" if in_release_list => {
+                                    releases.push(
+                                        self.parse_track_release(
                                             &mut xml_reader,
                                             &mut validator,
                                         )?,
                                     );
                                 }
+                                name @ (b"SoundRecording" | b"Video") if in_resource_list => {
+                                    // Videos share the SoundRecording layout
+                                    let mut resource = self.parse_sound_recording(
+                                        &mut xml_reader,
+                                        &mut validator,
+                                    )?;
+                                    if name == b"Video" {
+                                        resource.resource_type = ResourceType::Video;
+                                    }
+                                    resources.push(resource);
+                                }
+                                // Kept so that references to them are known not to be tracks
+                                name @ (b"Image" | b"Text" | b"SheetMusic") if in_resource_list => {
+                                    let resource_type = match name {
+                                        b"Image" => ResourceType::Image,
+                                        b"Text" => ResourceType::Text,
+                                        _ => ResourceType::SheetMusic,
+                                    };
+                                    resources.push(self.parse_basic_resource(
+                                        &mut xml_reader,
+                                        &mut validator,
+                                        resource_type,
+                                    )?);
+                                }
                                 b"ReleaseDeal" if in_deal_list => {
                                     // Parse the ReleaseDeal and add it to deals
                                     deals.push(
@@ -111,14 +179,23 @@ impl GraphBuilder {
                                         )?,
                                     );
                                 }
+                                b"ReleaseVisibility" if in_deal_list => {
+                                    visibilities.push(
+                                        self.parse_release_visibility(
+                                            &mut xml_reader,
+                                            &mut validator,
+                                        )?,
+                                    );
+                                }
                                 _ => {}
                             }
                         }
                         Event::End(ref e) => {
-                            match e.name().as_ref() {
+                            match e.local_name().as_ref() {
                                 b"ReleaseList" => in_release_list = false,
                                 b"ResourceList" => in_resource_list = false,
                                 b"DealList" => in_deal_list = false,
+                                b"PartyList" => in_party_list = false,
                                 _ => {}
                             }
                         }
@@ -133,15 +210,19 @@ impl GraphBuilder {
             buf.clear();
         }
 
+        Self::assign_party_roles(&mut parties, &releases, &resources);
+        Self::resolve_visibilities(&mut releases, &visibilities);
+
         Ok(ERNMessage {
             message_header,
             parties,
             resources,
             releases,
             deals,
+            purged_releases,
             version: self.version,
             profile: None,
-            message_audit_trail: None,
+            message_audit_trail,
             extensions: None,
             legacy_extensions: None,
             comments: None,
@@ -178,12 +259,18 @@ impl GraphBuilder {
         use ddex_core::models::common::LocalizedString;
 
         let mut message_id = format!("MSG_{:?}", self.version); // fallback
+        // Read from the root element; the header itself does not say
+        let mut message_type = MessageType::NewReleaseMessage;
         let mut message_thread_id: Option<String> = None;
+        let mut message_control_type: Option<MessageControlType> = None;
         let mut message_created_date_time = Utc::now();
         let mut sender_party_names = Vec::new();
         let mut recipient_party_names = Vec::new();
         let mut sender_party_ids = Vec::new();
         let mut recipient_party_ids = Vec::new();
+        let mut sender_trading_name: Option<String> = None;
+        let mut recipient_trading_name: Option<String> = None;
+        let mut party_name_language: Option<String> = None;
 
         let mut buf = Vec::new();
         let mut in_message_header = false;
@@ -197,10 +284,11 @@ impl GraphBuilder {
         loop {
             match reader.read_event_into(&mut buf) {
                 Ok(Event::Start(ref e)) => {
-                    match e.name().as_ref() {
+                    match e.local_name().as_ref() {
                         b"MessageHeader" => in_message_header = true,
                         b"MessageId" if in_message_header => current_text.clear(),
                         b"MessageThreadId" if in_message_header => current_text.clear(),
+                        b"MessageControlType" if in_message_header => current_text.clear(),
                         b"MessageCreatedDateTime" if in_message_header => current_text.clear(),
                         b"MessageSender" if in_message_header => in_message_sender = true,
                         b"MessageRecipient" if in_message_header => in_message_recipient = true,
@@ -212,15 +300,25 @@ impl GraphBuilder {
                         },
                         b"PartyName" if in_message_sender => {
                             in_sender_party_name = true;
+                            party_name_language = Self::language_attribute(e);
                             current_text.clear();
                         },
                         b"PartyName" if in_message_recipient => {
                             in_recipient_party_name = true;
+                            party_name_language = Self::language_attribute(e);
+                            current_text.clear();
+                        },
+                        b"TradingName" if in_message_sender || in_message_recipient => {
                             current_text.clear();
                         },
                         b"FullName" if in_sender_party_name || in_recipient_party_name => {
                             current_text.clear();
                         },
+                        name if !in_message_header => {
+                            if let Some(root_type) = MessageType::from_root_element(name) {
+                                message_type = root_type;
+                            }
+                        },
                         _ => {}
                     }
                 },
@@ -228,7 +326,7 @@ impl GraphBuilder {
                     current_text.push_str(&e.unescape().unwrap_or_default());
                 },
                 Ok(Event::End(ref e)) => {
-                    match e.name().as_ref() {
+                    match e.local_name().as_ref() {
                         b"MessageHeader" => {
                             in_message_header = false;
                             break; // We're done parsing the header
@@ -245,6 +343,14 @@ impl GraphBuilder {
                             }
                             current_text.clear();
                         },
+                        b"MessageControlType" if in_message_header => {
+                            message_control_type = match current_text.trim() {
+                                "LiveMessage" => Some(MessageControlType::LiveMessage),
+                                "TestMessage" => Some(MessageControlType::TestMessage),
+                                _ => None,
+                            };
+                            current_text.clear();
+                        },
                         b"MessageCreatedDateTime" if in_message_header => {
                             // Try to parse the datetime, fall back to current time if invalid
                             if let Ok(parsed_time) = chrono::DateTime::parse_from_rfc3339(current_text.trim()) {
@@ -281,28 +387,58 @@ impl GraphBuilder {
                         b"PartyName" if in_message_sender => {
                             // Handle direct text content in PartyName (fallback for simplified format)
                             if !current_text.trim().is_empty() {
-                                sender_party_names.push(LocalizedString::new(current_text.trim().to_string()));
+                                sender_party_names.push(LocalizedString {
+                                    text: self.whitespace.apply(&current_text),
+                                    language_code: party_name_language.clone(),
+                                    script: None,
+                                });
                             }
                             current_text.clear();
                             in_sender_party_name = false;
+                            party_name_language = None;
                         },
                         b"PartyName" if in_message_recipient => {
                             // Handle direct text content in PartyName (fallback for simplified format)
                             if !current_text.trim().is_empty() {
-                                recipient_party_names.push(LocalizedString::new(current_text.trim().to_string()));
+                                recipient_party_names.push(LocalizedString {
+                                    text: self.whitespace.apply(&current_text),
+                                    language_code: party_name_language.clone(),
+                                    script: None,
+                                });
                             }
                             current_text.clear();
                             in_recipient_party_name = false;
+                            party_name_language = None;
                         },
                         b"FullName" if in_sender_party_name => {
                             if !current_text.trim().is_empty() {
-                                sender_party_names.push(LocalizedString::new(current_text.trim().to_string()));
+                                sender_party_names.push(LocalizedString {
+                                    text: self.whitespace.apply(&current_text),
+                                    language_code: party_name_language.clone(),
+                                    script: None,
+                                });
                             }
                             current_text.clear();
                         },
                         b"FullName" if in_recipient_party_name => {
                             if !current_text.trim().is_empty() {
-                                recipient_party_names.push(LocalizedString::new(current_text.trim().to_string()));
+                                recipient_party_names.push(LocalizedString {
+                                    text: self.whitespace.apply(&current_text),
+                                    language_code: party_name_language.clone(),
+                                    script: None,
+                                });
+                            }
+                            current_text.clear();
+                        },
+                        b"TradingName" if in_message_sender => {
+                            if !current_text.trim().is_empty() {
+                                sender_trading_name = Some(self.whitespace.apply(&current_text));
+                            }
+                            current_text.clear();
+                        },
+                        b"TradingName" if in_message_recipient => {
+                            if !current_text.trim().is_empty() {
+                                recipient_trading_name = Some(self.whitespace.apply(&current_text));
                             }
                             current_text.clear();
                         },
@@ -320,12 +456,12 @@ impl GraphBuilder {
 
         Ok(MessageHeader {
             message_id,
-            message_type: MessageType::NewReleaseMessage,
+            message_type,
             message_created_date_time,
             message_sender: MessageSender {
                 party_id: sender_party_ids,
                 party_name: sender_party_names,
-                trading_name: None,
+                trading_name: sender_trading_name,
                 extensions: None,
                 attributes: None,
                 comments: None,
@@ -333,12 +469,12 @@ impl GraphBuilder {
             message_recipient: MessageRecipient {
                 party_id: recipient_party_ids,
                 party_name: recipient_party_names,
-                trading_name: None,
+                trading_name: recipient_trading_name,
                 extensions: None,
                 attributes: None,
                 comments: None,
             },
-            message_control_type: None,
+            message_control_type,
             message_thread_id,
             extensions: None,
             attributes: None,
@@ -346,37 +482,191 @@ impl GraphBuilder {
         })
     }
 
-    fn parse_minimal_release<R: BufRead>(
+    /// The main artist named by `DisplayArtistName`s alone, for messages
+    /// without a `DisplayArtist`
+    fn main_artist_from_names(
+        names: Vec<ddex_core::models::common::LocalizedString>,
+    ) -> Option<ddex_core::models::graph::Artist> {
+        use ddex_core::models::graph::Artist;
+        (!names.is_empty()).then(|| Artist {
+            party_reference: None,
+            artist_role: vec!["MainArtist".to_string()],
+            display_artist_name: names,
+            sequence_number: None,
+        })
+    }
+
+    /// Convert a `BitRate` (to kbps) or `SamplingRate` (to Hz) given in
+    /// `unit`. A sampling rate without a unit below 1000 is taken as kHz,
+    /// as in "44.1".
+    fn audio_rate(value: &str, unit: Option<&str>, base: &str) -> Option<i32> {
+        let value: f64 = value.parse().ok()?;
+        let scale = match unit.map(|u| u.to_ascii_lowercase()).as_deref() {
+            Some("bps") => 0.001,
+            Some("mbps") | Some("khz") => 1000.0,
+            Some("mhz") => 1_000_000.0,
+            None if base == "Hz" && value < 1000.0 => 1000.0,
+            _ => 1.0,
+        };
+        Some((value * scale).round() as i32)
+    }
+
+    /// Read the language of a localized element (`LanguageAndScriptCode` in ERN 4.x,
+    /// `LanguageCode` in older/simplified messages)
+    fn language_attribute(e: &quick_xml::events::BytesStart) -> Option<String> {
+        e.attributes()
+            .flatten()
+            .find(|a| {
+                matches!(
+                    a.key.local_name().as_ref(),
+                    b"LanguageAndScriptCode" | b"LanguageCode"
+                )
+            })
+            .and_then(|a| a.unescape_value().ok())
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty())
+    }
+
+    /// Start collecting a description if `e` is one of the free-text elements
+    fn description_start(e: &quick_xml::events::BytesStart) -> Option<(String, Option<String>)> {
+        let name = e.local_name();
+        DESCRIPTION_ELEMENTS.contains(&name.as_ref()).then(|| {
+            (
+                String::from_utf8_lossy(name.as_ref()).into_owned(),
+                Self::language_attribute(e),
+            )
+        })
+    }
+
+    /// Add a `SubTitle`, unless it repeats one already given in another
+    /// title element
+    fn push_subtitle(
+        subtitles: &mut Vec<ddex_core::models::common::LocalizedString>,
+        text: &str,
+        language: Option<String>,
+        whitespace: WhitespaceMode,
+    ) {
+        if text.trim().is_empty() {
+            return;
+        }
+        let mut subtitle = ddex_core::models::common::LocalizedString::new(whitespace.apply(text));
+        subtitle.language_code = language;
+        if !subtitles.iter().any(|s| s.text == subtitle.text) {
+            subtitles.push(subtitle);
+        }
+    }
+
+    /// Finish a description started by `description_start`
+    fn description_end(
+        pending: &mut Option<(String, Option<String>)>,
+        text: &str,
+        whitespace: WhitespaceMode,
+        descriptions: &mut Vec<ddex_core::models::common::Description>,
+    ) {
+        use ddex_core::models::common::{Description, LocalizedString};
+
+        if let Some((description_type, language_code)) = pending.take() {
+            if !text.trim().is_empty() {
+                descriptions.push(Description {
+                    description_type,
+                    text: LocalizedString {
+                        text: whitespace.apply(text),
+                        language_code,
+                        script: None,
+                    },
+                });
+            }
+        }
+    }
+
+    pub(crate) fn parse_minimal_release<R: BufRead>(
         &self,
         reader: &mut Reader<R>,
         validator: &mut crate::parser::xml_validator::XmlValidator,
+    ) -> Result<Release, ParseError> {
+        self.parse_release_element(reader, validator, false)
+    }
+
+    /// Parse a `TrackRelease`, whose resource reference and display title
+    /// sit directly under the element rather than in lists
+    pub(crate) fn parse_track_release<R: BufRead>(
+        &self,
+        reader: &mut Reader<R>,
+        validator: &mut crate::parser::xml_validator::XmlValidator,
+    ) -> Result<Release, ParseError> {
+        self.parse_release_element(reader, validator, true)
+    }
+
+    fn parse_release_element<R: BufRead>(
+        &self,
+        reader: &mut Reader<R>,
+        validator: &mut crate::parser::xml_validator::XmlValidator,
+        track_release: bool,
     ) -> Result<Release, ParseError> {
         use ddex_core::models::common::{LocalizedString, Identifier, IdentifierType};
-        use ddex_core::models::graph::{Artist, ReleaseResourceReference, ReleaseType};
+        use ddex_core::models::graph::{
+            Artist, Genre, RelatedRelease, ReleaseEvent, ReleaseParty, ReleaseResourceReference, ReleaseType,
+            ReleaseVisibility, LABEL_ROLE,
+        };
 
         // Initialize all the fields we'll extract
         let mut release_reference = format!("R_{:?}", self.version); // fallback
         let mut release_ids = Vec::new();
-        let mut release_titles = Vec::new();
-        let mut release_type: Option<ReleaseType> = None;
+        let mut reference_titles = Vec::new();
+        let mut display_titles = Vec::new();
+        let mut subtitles: Vec<LocalizedString> = Vec::new();
+        let mut title_language = None;
+        let mut subtitle_language = None;
+        let mut release_type: Option<ReleaseType> = track_release.then_some(ReleaseType::TrackRelease);
         let mut display_artists = Vec::new();
-        let mut resource_references = Vec::new();
+        let mut display_artist_names = Vec::new();
+        let mut resource_references: Vec<ReleaseResourceReference> = Vec::new();
+        let mut descriptions = Vec::new();
+        let mut current_description = None;
+        let mut related_releases = Vec::new();
+        let mut current_related: Option<RelatedRelease> = None;
+        let mut release_dates = Vec::new();
+        // Territories the ReleaseDate being read applies to; none for a global date
+        let mut release_date_territories: Vec<String> = Vec::new();
+        // Territories of the ERN 3.8 ReleaseDetailsByTerritory being read
+        let mut details_territories: Option<Vec<String>> = None;
+        let mut visibility = None;
+        let mut label_name = None;
+        let mut release_parties = Vec::new();
+        let mut genres = Vec::new();
+        let mut current_genre: Option<Genre> = None;
+        // SequenceNumber of each ResourceGroup open, outermost first
+        let mut group_sequences: Vec<Option<i32>> = Vec::new();
+        let mut current_content_item: Option<ReleaseResourceReference> = None;
         let mut current_text = String::new();
 
         // State tracking for nested elements
         let mut in_release_title = false;
+        let mut in_display_title = false;
+        let mut in_additional_title = false;
         let mut in_title_text = false;
+        let mut in_subtitle = false;
         let mut in_release_type = false;
         let mut in_release_reference = false;
         let mut in_release_id = false;
         let mut in_icpn = false;
         let mut in_grin = false;
         let mut in_grid = false;
-        let mut in_display_artist = false;
+        let mut current_artist: Option<Artist> = None;
         let mut in_artist_party_name = false;
         let mut in_artist_full_name = false;
+        let mut in_artist_text = false;
+        let mut in_display_artist_name = false;
         let mut in_resource_reference_list = false;
         let mut in_resource_reference = false;
+        let mut in_display_title_text = false;
+        let mut in_relationship_type = false;
+        let mut in_release_date = false;
+        let mut in_details_territory_code = false;
+        let mut in_visibility_reference = false;
+        let mut in_label = false;
+        let mut in_genre_text = false;
+        let mut in_group_text = false;
 
         // Parse the Release element and extract all real data
         let mut buf = Vec::new();
@@ -390,7 +680,7 @@ impl GraphBuilder {
                     match event {
                         Event::Start(ref e) => {
                             depth += 1;
-                            match e.name().as_ref() {
+                            match e.local_name().as_ref() {
                                 b"ReleaseReference" => {
                                     in_release_reference = true;
                                     current_text.clear();
@@ -408,40 +698,162 @@ impl GraphBuilder {
                                     in_grid = true;
                                     current_text.clear();
                                 },
-                                b"ReleaseTitle" => in_release_title = true,
-                                b"TitleText" if in_release_title => {
+                                b"ReleaseTitle" | b"ReferenceTitle" => {
+                                    in_release_title = true;
+                                    title_language = Self::language_attribute(e);
+                                },
+                                // A PurgedRelease gives its title as Title
+                                b"Title" if depth == 2 => {
+                                    in_release_title = true;
+                                    title_language = Self::language_attribute(e);
+                                },
+                                b"DisplayTitle" => {
+                                    in_display_title = true;
+                                    title_language = Self::language_attribute(e);
+                                },
+                                b"TitleText" if in_release_title || in_display_title => {
                                     in_title_text = true;
+                                    if let Some(language) = Self::language_attribute(e) {
+                                        title_language = Some(language);
+                                    }
+                                    current_text.clear();
+                                },
+                                b"AdditionalTitle" => in_additional_title = true,
+                                b"SubTitle" if in_release_title || in_display_title || in_additional_title => {
+                                    in_subtitle = true;
+                                    subtitle_language = Self::language_attribute(e).or_else(|| title_language.clone());
                                     current_text.clear();
                                 },
                                 b"ReleaseType" => {
                                     in_release_type = true;
                                     current_text.clear();
                                 },
-                                b"DisplayArtist" => in_display_artist = true,
-                                b"PartyName" if in_display_artist => {
+                                b"DisplayArtistName" if depth == 2 => {
+                                    in_display_artist_name = true;
+                                    current_text.clear();
+                                },
+                                b"DisplayArtist" => {
+                                    current_artist = Some(Artist {
+                                        party_reference: None,
+                                        artist_role: Vec::new(),
+                                        display_artist_name: Vec::new(),
+                                        sequence_number: None,
+                                    });
+                                },
+                                b"PartyName" if current_artist.is_some() => {
                                     in_artist_party_name = true;
                                 },
                                 b"FullName" if in_artist_party_name => {
                                     in_artist_full_name = true;
                                     current_text.clear();
                                 },
+                                b"ArtistPartyReference" | b"DisplayArtistRole" | b"ArtistRole" if current_artist.is_some() => {
+                                    in_artist_text = true;
+                                    current_text.clear();
+                                },
+                                b"ResourceGroup" => group_sequences.push(None),
+                                b"ResourceGroupContentItem" => {
+                                    current_content_item = Some(ReleaseResourceReference {
+                                        resource_reference: String::new(),
+                                        sequence_number: None,
+                                        disc_number: None,
+                                        track_number: None,
+                                        side: None,
+                                        is_hidden: false,
+                                        is_bonus: false,
+                                        extensions: None,
+                                        comments: None,
+                                    });
+                                },
+                                b"ReleaseResourceReference" | b"IsBonusResource" | b"IsHiddenResource" if current_content_item.is_some() => {
+                                    in_group_text = true;
+                                    current_text.clear();
+                                },
+                                b"SequenceNumber" if current_content_item.is_some() || !group_sequences.is_empty() => {
+                                    in_group_text = true;
+                                    current_text.clear();
+                                },
                                 b"ReleaseResourceReferenceList" => in_resource_reference_list = true,
-                                b"ReleaseResourceReference" if in_resource_reference_list => {
+                                b"ReleaseResourceReference" if in_resource_reference_list || (track_release && depth == 2) => {
                                     in_resource_reference = true;
                                     current_text.clear();
                                 },
-                                _ => {}
+                                b"DisplayTitleText" if track_release && depth == 2 => {
+                                    in_display_title_text = true;
+                                    title_language = Self::language_attribute(e);
+                                    current_text.clear();
+                                },
+                                b"RelatedRelease" => {
+                                    current_related = Some(RelatedRelease {
+                                        relationship_type: String::new(),
+                                        release_id: Vec::new(),
+                                    });
+                                },
+                                b"ReleaseRelationshipType" if current_related.is_some() => {
+                                    in_relationship_type = true;
+                                    current_text.clear();
+                                },
+                                b"ReleaseDetailsByTerritory" if depth == 2 => details_territories = Some(Vec::new()),
+                                b"TerritoryCode" if depth == 3 && details_territories.is_some() => {
+                                    in_details_territory_code = true;
+                                    current_text.clear();
+                                },
+                                // ERN 4.x scopes a date with ApplicableTerritoryCode,
+                                // ERN 3.8 with the enclosing ReleaseDetailsByTerritory
+                                b"ReleaseDate" if depth == 2 || (depth == 3 && details_territories.is_some()) => {
+                                    release_date_territories = e
+                                        .attributes()
+                                        .flatten()
+                                        .find(|a| a.key.local_name().as_ref() == b"ApplicableTerritoryCode")
+                                        .and_then(|a| a.unescape_value().ok())
+                                        .map(|code| vec![code.trim().to_string()])
+                                        .or_else(|| details_territories.clone())
+                                        .unwrap_or_default();
+                                    release_date_territories.retain(|code| !code.is_empty() && code != "Worldwide");
+                                    in_release_date = true;
+                                    current_text.clear();
+                                },
+                                b"ReleaseVisibilityReference" if depth == 2 => {
+                                    in_visibility_reference = true;
+                                    current_text.clear();
+                                },
+                                // ERN 3.8 nests LabelName in ReleaseDetailsByTerritory
+                                b"LabelName" | b"ReleaseLabelReference" => {
+                                    in_label = true;
+                                    current_text.clear();
+                                },
+                                b"Genre" => {
+                                    current_genre = Some(Genre {
+                                        genre_text: String::new(),
+                                        sub_genre: None,
+                                        attributes: None,
+                                        extensions: None,
+                                        comments: None,
+                                    });
+                                },
+                                b"GenreText" | b"SubGenre" if current_genre.is_some() => {
+                                    in_genre_text = true;
+                                    current_text.clear();
+                                },
+                                _ => {
+                                    if let Some(description) = Self::description_start(e) {
+                                        current_description = Some(description);
+                                        current_text.clear();
+                                    }
+                                }
                             }
                         },
                         Event::Text(ref e) => {
-                            if in_title_text || in_release_type || in_release_reference ||
-                               in_icpn || in_grin || in_grid || in_artist_full_name || in_resource_reference {
+                            if in_title_text || in_subtitle || in_release_type || in_release_reference ||
+                               in_icpn || in_grin || in_grid || in_artist_full_name || in_artist_text || in_display_artist_name || in_resource_reference ||
+                               in_display_title_text || in_relationship_type || in_release_date || in_details_territory_code ||
+                               in_visibility_reference || in_label || in_genre_text || in_group_text || current_description.is_some() {
                                 current_text.push_str(&e.unescape().unwrap_or_default());
                             }
                         },
                         Event::End(ref e) => {
                             depth -= 1;
-                            match e.name().as_ref() {
+                            match e.local_name().as_ref() {
                                 b"ReleaseReference" => {
                                     if !current_text.trim().is_empty() {
                                         release_reference = current_text.trim().to_string();
@@ -452,7 +864,8 @@ impl GraphBuilder {
                                 b"ReleaseId" => in_release_id = false,
                                 b"ICPN" if in_icpn => {
                                     if !current_text.trim().is_empty() {
-                                        release_ids.push(Identifier {
+                                        let ids = current_related.as_mut().map_or(&mut release_ids, |related| &mut related.release_id);
+                                        ids.push(Identifier {
                                             id_type: IdentifierType::UPC,
                                             namespace: None,
                                             value: current_text.trim().to_string(),
@@ -463,7 +876,8 @@ impl GraphBuilder {
                                 },
                                 b"GRIN" if in_grin => {
                                     if !current_text.trim().is_empty() {
-                                        release_ids.push(Identifier {
+                                        let ids = current_related.as_mut().map_or(&mut release_ids, |related| &mut related.release_id);
+                                        ids.push(Identifier {
                                             id_type: IdentifierType::GRid,
                                             namespace: None,
                                             value: current_text.trim().to_string(),
@@ -474,7 +888,8 @@ impl GraphBuilder {
                                 },
                                 b"GRid" if in_grid => {
                                     if !current_text.trim().is_empty() {
-                                        release_ids.push(Identifier {
+                                        let ids = current_related.as_mut().map_or(&mut release_ids, |related| &mut related.release_id);
+                                        ids.push(Identifier {
                                             id_type: IdentifierType::GRID,
                                             namespace: None,
                                             value: current_text.trim().to_string(),
@@ -483,10 +898,24 @@ impl GraphBuilder {
                                     in_grid = false;
                                     current_text.clear();
                                 },
-                                b"ReleaseTitle" => in_release_title = false,
+                                b"ReleaseTitle" | b"ReferenceTitle" => in_release_title = false,
+                                b"Title" if in_release_title => in_release_title = false,
+                                b"DisplayTitle" => in_display_title = false,
+                                b"AdditionalTitle" => in_additional_title = false,
+                                b"SubTitle" if in_subtitle => {
+                                    Self::push_subtitle(&mut subtitles, &current_text, subtitle_language.take(), self.whitespace);
+                                    in_subtitle = false;
+                                    current_text.clear();
+                                },
                                 b"TitleText" if in_title_text => {
                                     if !current_text.trim().is_empty() {
-                                        release_titles.push(LocalizedString::new(current_text.trim().to_string()));
+                                        let mut title = LocalizedString::new(self.whitespace.apply(&current_text));
+                                        title.language_code = title_language.clone();
+                                        if in_display_title {
+                                            display_titles.push(title);
+                                        } else {
+                                            reference_titles.push(title);
+                                        }
                                     }
                                     in_title_text = false;
                                     current_text.clear();
@@ -498,28 +927,175 @@ impl GraphBuilder {
                                             "Single" => Some(ReleaseType::Single),
                                             "EP" => Some(ReleaseType::EP),
                                             "Compilation" => Some(ReleaseType::Compilation),
+                                            "TrackRelease" => Some(ReleaseType::TrackRelease),
                                             other => Some(ReleaseType::Other(other.to_string())),
                                         };
                                     }
                                     in_release_type = false;
                                     current_text.clear();
                                 },
-                                b"DisplayArtist" => in_display_artist = false,
+                                b"DisplayArtistName" if in_display_artist_name => {
+                                    if !current_text.trim().is_empty() {
+                                        display_artist_names.push(LocalizedString::new(self.whitespace.apply(&current_text)));
+                                    }
+                                    in_display_artist_name = false;
+                                    current_text.clear();
+                                },
+                                b"DisplayArtist" => {
+                                    if let Some(mut artist) = current_artist.take() {
+                                        if !artist.display_artist_name.is_empty() || artist.party_reference.is_some() {
+                                            if artist.artist_role.is_empty() {
+                                                artist.artist_role.push("MainArtist".to_string());
+                                            }
+                                            display_artists.push(artist);
+                                        }
+                                    }
+                                },
                                 b"PartyName" if in_artist_party_name => {
                                     in_artist_party_name = false;
                                 },
                                 b"FullName" if in_artist_full_name => {
+                                    if let Some(artist) = current_artist.as_mut().filter(|_| !current_text.trim().is_empty()) {
+                                        artist.display_artist_name.push(LocalizedString::new(self.whitespace.apply(&current_text)));
+                                    }
+                                    in_artist_full_name = false;
+                                    current_text.clear();
+                                },
+                                name @ (b"ArtistPartyReference" | b"DisplayArtistRole" | b"ArtistRole") if in_artist_text => {
+                                    let value = current_text.trim().to_string();
+                                    if let Some(artist) = current_artist.as_mut().filter(|_| !value.is_empty()) {
+                                        if name == b"ArtistPartyReference" {
+                                            artist.party_reference = Some(value);
+                                        } else {
+                                            artist.artist_role.push(value);
+                                        }
+                                    }
+                                    in_artist_text = false;
+                                    current_text.clear();
+                                },
+                                b"DisplayTitleText" if in_display_title_text => {
                                     if !current_text.trim().is_empty() {
-                                        display_artists.push(Artist {
-                                            party_reference: None,
-                                            artist_role: vec!["MainArtist".to_string()],
-                                            display_artist_name: vec![LocalizedString::new(current_text.trim().to_string())],
-                                            sequence_number: None,
+                                        let mut title = LocalizedString::new(self.whitespace.apply(&current_text));
+                                        title.language_code = title_language.clone();
+                                        display_titles.push(title);
+                                    }
+                                    in_display_title_text = false;
+                                    current_text.clear();
+                                },
+                                b"ReleaseRelationshipType" if in_relationship_type => {
+                                    if let Some(related) = current_related.as_mut() {
+                                        related.relationship_type = current_text.trim().to_string();
+                                    }
+                                    in_relationship_type = false;
+                                    current_text.clear();
+                                },
+                                b"RelatedRelease" => related_releases.extend(current_related.take()),
+                                b"ReleaseDate" if in_release_date => {
+                                    if let Some(event_date) = Self::parse_date_time(current_text.trim()) {
+                                        let territories: Vec<Option<String>> = if release_date_territories.is_empty() {
+                                            vec![None]
+                                        } else {
+                                            release_date_territories.drain(..).map(Some).collect()
+                                        };
+                                        for territory in territories {
+                                            release_dates.push(ReleaseEvent {
+                                                release_event_type: "ReleaseDate".to_string(),
+                                                event_date: Some(event_date),
+                                                territory,
+                                                extensions: None,
+                                                comments: None,
+                                            });
+                                        }
+                                    }
+                                    in_release_date = false;
+                                    current_text.clear();
+                                },
+                                b"TerritoryCode" if in_details_territory_code => {
+                                    if let Some(territories) = details_territories.as_mut() {
+                                        territories.push(current_text.trim().to_string());
+                                    }
+                                    in_details_territory_code = false;
+                                    current_text.clear();
+                                },
+                                b"ReleaseDetailsByTerritory" => details_territories = None,
+                                b"ReleaseVisibilityReference" if in_visibility_reference => {
+                                    if !current_text.trim().is_empty() {
+                                        visibility = Some(ReleaseVisibility {
+                                            visibility_reference: current_text.trim().to_string(),
+                                            release_display_start: None,
                                         });
                                     }
-                                    in_artist_full_name = false;
+                                    in_visibility_reference = false;
+                                    current_text.clear();
+                                },
+                                b"LabelName" if in_label => {
+                                    if label_name.is_none() && !current_text.trim().is_empty() {
+                                        label_name = Some(self.whitespace.apply(&current_text));
+                                    }
+                                    in_label = false;
+                                    current_text.clear();
+                                },
+                                b"ReleaseLabelReference" if in_label => {
+                                    if !current_text.trim().is_empty() {
+                                        release_parties.push(ReleaseParty {
+                                            party_reference: current_text.trim().to_string(),
+                                            role: vec![LABEL_ROLE.to_string()],
+                                            extensions: None,
+                                            comments: None,
+                                        });
+                                    }
+                                    in_label = false;
                                     current_text.clear();
                                 },
+                                name @ (b"GenreText" | b"SubGenre") if in_genre_text => {
+                                    let value = current_text.trim().to_string();
+                                    if let Some(genre) = current_genre.as_mut().filter(|_| !value.is_empty()) {
+                                        if name == b"GenreText" {
+                                            genre.genre_text = value;
+                                        } else {
+                                            genre.sub_genre = Some(value);
+                                        }
+                                    }
+                                    in_genre_text = false;
+                                    current_text.clear();
+                                },
+                                b"Genre" => {
+                                    genres.extend(current_genre.take().filter(|genre| !genre.genre_text.is_empty()));
+                                },
+                                name if in_group_text => {
+                                    let value = current_text.trim();
+                                    match (name, current_content_item.as_mut()) {
+                                        (b"SequenceNumber", Some(item)) => item.sequence_number = value.parse().ok(),
+                                        (b"SequenceNumber", None) => {
+                                            if let Some(sequence) = group_sequences.last_mut() {
+                                                *sequence = value.parse().ok();
+                                            }
+                                        },
+                                        (b"ReleaseResourceReference", Some(item)) => item.resource_reference = value.to_string(),
+                                        (b"IsBonusResource", Some(item)) => item.is_bonus = value == "true",
+                                        (b"IsHiddenResource", Some(item)) => item.is_hidden = value == "true",
+                                        _ => {}
+                                    }
+                                    in_group_text = false;
+                                    current_text.clear();
+                                },
+                                b"ResourceGroupContentItem" => {
+                                    if let Some(mut item) = current_content_item.take().filter(|item| !item.resource_reference.is_empty()) {
+                                        // A group inside the release's main group is a volume, e.g. a disc
+                                        item.track_number = item.sequence_number;
+                                        if group_sequences.len() > 1 {
+                                            item.disc_number = group_sequences.last().copied().flatten();
+                                        }
+                                        // The group adds detail to a reference the release may already list
+                                        match resource_references.iter_mut().find(|listed| listed.resource_reference == item.resource_reference) {
+                                            Some(listed) => *listed = item,
+                                            None => resource_references.push(item),
+                                        }
+                                    }
+                                },
+                                b"ResourceGroup" => {
+                                    group_sequences.pop();
+                                },
                                 b"ReleaseResourceReferenceList" => in_resource_reference_list = false,
                                 b"ReleaseResourceReference" if in_resource_reference => {
                                     if !current_text.trim().is_empty() {
@@ -538,6 +1114,10 @@ impl GraphBuilder {
                                     in_resource_reference = false;
                                     current_text.clear();
                                 },
+                                name if DESCRIPTION_ELEMENTS.contains(&name) => {
+                                    Self::description_end(&mut current_description, &current_text, self.whitespace, &mut descriptions);
+                                    current_text.clear();
+                                },
                                 _ => {}
                             }
                         },
@@ -552,24 +1132,39 @@ impl GraphBuilder {
             buf.clear();
         }
 
-        // If no title was found, provide a fallback
+        // Prefer the reference title, then the display title, then a fallback
+        let mut release_titles = if reference_titles.is_empty() {
+            display_titles.clone()
+        } else {
+            reference_titles.clone()
+        };
         if release_titles.is_empty() {
             release_titles.push(LocalizedString::new(format!("Release {:?}", self.version)));
         }
 
+        if display_artists.is_empty() {
+            display_artists.extend(Self::main_artist_from_names(display_artist_names));
+        }
+
         let release = Release {
             release_reference,
             release_id: release_ids,
             release_title: release_titles,
-            release_subtitle: None,
+            reference_title: reference_titles,
+            display_title: display_titles,
+            release_subtitle: (!subtitles.is_empty()).then_some(subtitles),
             release_type,
-            genre: Vec::new(),
+            genre: genres,
             release_resource_reference_list: resource_references,
             display_artist: display_artists,
-            party_list: Vec::new(),
-            release_date: Vec::new(),
+            party_list: release_parties,
+            label_name,
+            release_date: release_dates,
             territory_code: Vec::new(),
             excluded_territory_code: Vec::new(),
+            descriptions,
+            related_releases,
+            visibility,
             extensions: None,
             attributes: None,
             comments: None,
@@ -578,32 +1173,349 @@ impl GraphBuilder {
         Ok(release)
     }
 
-    fn parse_sound_recording<R: BufRead>(
+    /// Parse a `ReleaseVisibility` from the deal list
+    pub(crate) fn parse_release_visibility<R: BufRead>(
+        &self,
+        reader: &mut Reader<R>,
+        validator: &mut crate::parser::xml_validator::XmlValidator,
+    ) -> Result<ddex_core::models::graph::ReleaseVisibility, ParseError> {
+        let mut visibility_reference = String::new();
+        let mut release_display_start = None;
+        let mut current_text = String::new();
+
+        let mut buf = Vec::new();
+        let mut depth = 1;
+        while depth > 0 {
+            match reader.read_event_into(&mut buf) {
+                Ok(ref event) => {
+                    validator.validate_event(event, reader)?;
+
+                    match event {
+                        Event::Start(_) => {
+                            depth += 1;
+                            current_text.clear();
+                        },
+                        Event::Text(ref e) => current_text.push_str(&e.unescape().unwrap_or_default()),
+                        Event::End(ref e) => {
+                            depth -= 1;
+                            match e.local_name().as_ref() {
+                                b"VisibilityReference" => visibility_reference = current_text.trim().to_string(),
+                                b"ReleaseDisplayStartDateTime" => {
+                                    release_display_start = Self::parse_date_time(current_text.trim());
+                                },
+                                _ => {}
+                            }
+                            current_text.clear();
+                        },
+                        Event::Eof => break,
+                        _ => {}
+                    }
+                }
+                Err(e) => {
+                    return Err(ParseError::XmlError(format!("XML parsing error in release visibility: {}", e)));
+                }
+            }
+            buf.clear();
+        }
+
+        Ok(ddex_core::models::graph::ReleaseVisibility {
+            visibility_reference,
+            release_display_start,
+        })
+    }
+
+    /// Read the events of a `MessageAuditTrail` in document order. The party
+    /// is taken from `ResponsiblePartyReference`, or else the
+    /// `MessagingPartyDescriptor`'s id or full name. Events without a
+    /// readable `DateTime` are dropped.
+    pub(crate) fn parse_message_audit_trail<R: BufRead>(
+        &self,
+        reader: &mut Reader<R>,
+        validator: &mut crate::parser::xml_validator::XmlValidator,
+    ) -> Result<ddex_core::models::graph::MessageAuditTrail, ParseError> {
+        use ddex_core::models::graph::{AuditTrailEvent, MessageAuditTrail};
+
+        let mut audit_trail_events = Vec::new();
+        let mut event_reference = String::new();
+        let mut event_type = String::new();
+        let mut date_time = None;
+        let mut party_reference: Option<String> = None;
+        let mut party_id: Option<String> = None;
+        let mut party_name: Option<String> = None;
+        let mut current_text = String::new();
+
+        let mut buf = Vec::new();
+        let mut depth = 1;
+        while depth > 0 {
+            match reader.read_event_into(&mut buf) {
+                Ok(ref event) => {
+                    validator.validate_event(event, reader)?;
+
+                    match event {
+                        Event::Start(_) => {
+                            depth += 1;
+                            current_text.clear();
+                        },
+                        Event::Text(ref e) => current_text.push_str(&e.unescape().unwrap_or_default()),
+                        Event::End(ref e) => {
+                            depth -= 1;
+                            let text = current_text.trim();
+                            let value = (!text.is_empty()).then(|| text.to_string());
+                            match e.local_name().as_ref() {
+                                b"MessageAuditTrailEventReference" => event_reference = text.to_string(),
+                                b"MessageAuditTrailEventType" => event_type = text.to_string(),
+                                b"DateTime" => date_time = Self::parse_date_time(text),
+                                b"ResponsiblePartyReference" => party_reference = value,
+                                b"PartyId" if party_id.is_none() => party_id = value,
+                                b"FullName" if party_name.is_none() => party_name = value,
+                                b"MessageAuditTrailEvent" => {
+                                    if let Some(date_time) = date_time.take() {
+                                        audit_trail_events.push(AuditTrailEvent {
+                                            message_audit_trail_event_reference: event_reference.clone(),
+                                            message_audit_trail_event_type: event_type.clone(),
+                                            date_time,
+                                            responsible_party_reference: party_reference
+                                                .clone()
+                                                .or(party_id.clone())
+                                                .or(party_name.clone()),
+                                            attributes: None,
+                                            extensions: None,
+                                            comments: None,
+                                        });
+                                    }
+                                    event_reference.clear();
+                                    event_type.clear();
+                                    party_reference = None;
+                                    party_id = None;
+                                    party_name = None;
+                                },
+                                _ => {}
+                            }
+                            current_text.clear();
+                        },
+                        Event::Eof => break,
+                        _ => {}
+                    }
+                }
+                Err(e) => {
+                    return Err(ParseError::XmlError(format!("XML parsing error in message audit trail: {}", e)));
+                }
+            }
+            buf.clear();
+        }
+
+        Ok(MessageAuditTrail {
+            audit_trail_events,
+            attributes: None,
+            extensions: None,
+            comments: None,
+        })
+    }
+
+    /// Fill in the dates of the visibilities releases refer to, which the deal
+    /// list only provides after the releases have been read
+    fn resolve_visibilities(releases: &mut [Release], visibilities: &[ddex_core::models::graph::ReleaseVisibility]) {
+        for visibility in releases.iter_mut().filter_map(|release| release.visibility.as_mut()) {
+            if let Some(found) = visibilities
+                .iter()
+                .find(|candidate| candidate.visibility_reference == visibility.visibility_reference)
+            {
+                visibility.release_display_start = found.release_display_start;
+            }
+        }
+    }
+
+    /// A DDEX `DateTime`, or a bare date taken as midnight UTC
+    fn parse_date_time(text: &str) -> Option<chrono::DateTime<chrono::Utc>> {
+        chrono::DateTime::parse_from_rfc3339(text)
+            .map(|date_time| date_time.with_timezone(&chrono::Utc))
+            .ok()
+            .or_else(|| {
+                chrono::NaiveDate::parse_from_str(text, "%Y-%m-%d")
+                    .ok()
+                    .and_then(|date| date.and_hms_opt(0, 0, 0))
+                    .map(|date_time| date_time.and_utc())
+            })
+    }
+
+    pub(crate) fn parse_party<R: BufRead>(
+        &self,
+        reader: &mut Reader<R>,
+        validator: &mut crate::parser::xml_validator::XmlValidator,
+    ) -> Result<Party, ParseError> {
+        use ddex_core::models::common::{Identifier, IdentifierType, LocalizedString};
+
+        let mut party = Party {
+            party_reference: None,
+            party_id: Vec::new(),
+            isni: None,
+            ipi: None,
+            party_name: Vec::new(),
+            party_role: Vec::new(),
+            contact_details: None,
+        };
+        let mut current_text = String::new();
+        let mut in_party_id = false;
+        let mut in_party_name = false;
+
+        let mut buf = Vec::new();
+        let mut depth = 1;
+        while depth > 0 {
+            match reader.read_event_into(&mut buf) {
+                Ok(ref event) => {
+                    validator.validate_event(event, reader)?;
+
+                    match event {
+                        Event::Start(ref e) => {
+                            depth += 1;
+                            match e.local_name().as_ref() {
+                                b"PartyId" => in_party_id = true,
+                                b"PartyName" => in_party_name = true,
+                                _ => {}
+                            }
+                            current_text.clear();
+                        },
+                        Event::Text(ref e) => {
+                            current_text.push_str(&e.unescape().unwrap_or_default());
+                        },
+                        Event::End(ref e) => {
+                            depth -= 1;
+                            let value = current_text.trim().to_string();
+                            match e.local_name().as_ref() {
+                                b"PartyReference" if depth == 1 && !value.is_empty() => {
+                                    party.party_reference = Some(value);
+                                },
+                                b"ISNI" if in_party_id && !value.is_empty() => {
+                                    party.isni.get_or_insert_with(|| value.clone());
+                                    party.party_id.push(Identifier { id_type: IdentifierType::ISNI, namespace: None, value });
+                                },
+                                b"IPI" | b"IpiNameNumber" if in_party_id && !value.is_empty() => {
+                                    party.ipi.get_or_insert_with(|| value.clone());
+                                    party.party_id.push(Identifier { id_type: IdentifierType::IPI, namespace: None, value });
+                                },
+                                b"DPID" if in_party_id && !value.is_empty() => {
+                                    party.party_id.push(Identifier {
+                                        id_type: IdentifierType::Proprietary,
+                                        namespace: Some("DPID".to_string()),
+                                        value,
+                                    });
+                                },
+                                b"ProprietaryId" if in_party_id && !value.is_empty() => {
+                                    party.party_id.push(Identifier { id_type: IdentifierType::Proprietary, namespace: None, value });
+                                },
+                                b"PartyId" => {
+                                    // ERN 3.8 gives the id as the element's own text
+                                    if !value.is_empty() {
+                                        party.party_id.push(Identifier { id_type: IdentifierType::Proprietary, namespace: None, value });
+                                    }
+                                    in_party_id = false;
+                                },
+                                b"FullName" if in_party_name && !value.is_empty() => {
+                                    party.party_name.push(LocalizedString::new(self.whitespace.apply(&current_text)));
+                                },
+                                b"PartyName" => in_party_name = false,
+                                _ => {}
+                            }
+                            current_text.clear();
+                        },
+                        Event::Eof => break,
+                        _ => {}
+                    }
+                }
+                Err(e) => {
+                    return Err(ParseError::XmlError(format!("XML parsing error in party: {}", e)));
+                }
+            }
+            buf.clear();
+        }
+
+        Ok(party)
+    }
+
+    /// Record on each party the roles it plays across the message: display
+    /// artist on a release or resource, rights controller on a resource
+    fn assign_party_roles(parties: &mut [Party], releases: &[Release], resources: &[ddex_core::models::graph::Resource]) {
+        let artist_refs = releases
+            .iter()
+            .flat_map(|r| &r.display_artist)
+            .chain(resources.iter().flat_map(|r| &r.display_artist))
+            .filter_map(|a| a.party_reference.as_deref());
+        let controller_refs = resources
+            .iter()
+            .flat_map(|r| &r.rights_controller)
+            .map(String::as_str);
+
+        let references = artist_refs
+            .map(|reference| (reference, PartyRole::Artist))
+            .chain(controller_refs.map(|reference| (reference, PartyRole::RightsController)));
+        for (reference, role) in references {
+            let party = parties
+                .iter_mut()
+                .find(|p| p.party_reference.as_deref() == Some(reference));
+            if let Some(party) = party.filter(|p| !p.party_role.contains(&role)) {
+                party.party_role.push(role);
+            }
+        }
+    }
+
+    pub(crate) fn parse_sound_recording<R: BufRead>(
         &self,
         reader: &mut Reader<R>,
         validator: &mut crate::parser::xml_validator::XmlValidator,
     ) -> Result<ddex_core::models::graph::Resource, ParseError> {
         use ddex_core::models::common::{LocalizedString, Identifier, IdentifierType};
-        use ddex_core::models::graph::{Resource, ResourceType};
+        use ddex_core::models::graph::{Artist, Contributor, HashSum, PreviewDetails, Resource, ResourceFile, ResourceType, TechnicalDetails, VideoTechnicalDetails};
         use std::time::Duration;
 
         // Initialize all the fields we'll extract
         let mut resource_reference = format!("RES_{:?}", self.version); // fallback
         let mut resource_ids = Vec::new();
         let mut reference_titles = Vec::new();
+        let mut subtitles = Vec::new();
+        let mut subtitle_language = None;
         let mut duration: Option<Duration> = None;
+        let mut descriptions = Vec::new();
+        let mut current_description = None;
+        let mut technical_details = Vec::new();
+        let mut rights_controllers: Vec<String> = Vec::new();
+        let mut display_artists = Vec::new();
+        let mut display_artist_names = Vec::new();
+        let mut current_artist: Option<Artist> = None;
+        let mut contributors = Vec::new();
+        let mut current_contributor: Option<Contributor> = None;
+        // UserDefinedValue of the contributor role or instrument being read
+        let mut user_defined_role: Option<String> = None;
+        let mut current_technical: Option<TechnicalDetails> = None;
+        let mut current_file: Option<ResourceFile> = None;
+        // (algorithm, value) of the HashSum being read
+        let mut current_hash: Option<(Option<String>, Option<String>)> = None;
+        // (start point, end point, duration) of the preview being read
+        let mut current_preview: Option<(Option<Duration>, Option<Duration>, Option<Duration>)> = None;
         let mut current_text = String::new();
 
         // State tracking for nested elements
+        let mut in_file_text = false;
+        let mut in_preview_value = false;
+        let mut in_video_value = false;
+        // UnitOfMeasure of the audio value being read, if it is one
+        let mut audio_unit: Option<Option<String>> = None;
+        let mut in_rights_controller_reference = false;
         let mut in_resource_reference = false;
         let mut in_sound_recording_id = false;
         let mut in_isrc = false;
         let mut in_title = false;
         let mut in_title_text = false;
+        // Inside any title element a SubTitle may be given in
+        let mut in_any_title = false;
+        let mut in_subtitle = false;
         let mut in_duration = false;
-        let mut in_display_artist = false;
         let mut in_artist_party_name = false;
         let mut in_artist_full_name = false;
+        let mut in_artist_text = false;
+        let mut in_display_artist_name = false;
+        let mut in_contributor_party_name = false;
+        let mut in_contributor_full_name = false;
+        let mut in_contributor_text = false;
 
         // Parse the SoundRecording element and extract real data
         let mut buf = Vec::new();
@@ -617,45 +1529,155 @@ impl GraphBuilder {
                     match event {
                         Event::Start(ref e) => {
                             depth += 1;
-                            match e.name().as_ref() {
+                            match e.local_name().as_ref() {
                                 b"ResourceReference" => {
                                     in_resource_reference = true;
                                     current_text.clear();
                                 },
-                                b"SoundRecordingId" => in_sound_recording_id = true,
+                                b"SoundRecordingId" | b"VideoId" => in_sound_recording_id = true,
                                 b"ISRC" if in_sound_recording_id => {
                                     in_isrc = true;
                                     current_text.clear();
                                 },
-                                b"Title" => in_title = true,
+                                b"Title" => {
+                                    in_title = true;
+                                    in_any_title = true;
+                                },
+                                b"ReferenceTitle" | b"DisplayTitle" | b"AdditionalTitle" => in_any_title = true,
                                 b"TitleText" if in_title => {
                                     in_title_text = true;
                                     current_text.clear();
                                 },
+                                b"SubTitle" if in_any_title => {
+                                    in_subtitle = true;
+                                    subtitle_language = Self::language_attribute(e);
+                                    current_text.clear();
+                                },
+                                b"PreviewDetails" | b"ClipDetails" => current_preview = Some((None, None, None)),
+                                b"StartPoint" | b"EndPoint" | b"Duration" | b"DurationUsed" if current_preview.is_some() => {
+                                    in_preview_value = true;
+                                    current_text.clear();
+                                },
                                 b"Duration" => {
                                     in_duration = true;
                                     current_text.clear();
                                 },
-                                b"DisplayArtist" => in_display_artist = true,
-                                b"PartyName" if in_display_artist => {
+                                b"DisplayArtistName" if depth == 2 => {
+                                    in_display_artist_name = true;
+                                    current_text.clear();
+                                },
+                                b"DisplayArtist" => {
+                                    current_artist = Some(Artist {
+                                        party_reference: None,
+                                        artist_role: Vec::new(),
+                                        display_artist_name: Vec::new(),
+                                        sequence_number: None,
+                                    });
+                                },
+                                b"PartyName" if current_artist.is_some() => {
                                     in_artist_party_name = true;
                                 },
                                 b"FullName" if in_artist_party_name => {
                                     in_artist_full_name = true;
                                     current_text.clear();
                                 },
-                                _ => {}
+                                b"ArtistPartyReference" | b"DisplayArtistRole" | b"ArtistRole" if current_artist.is_some() => {
+                                    in_artist_text = true;
+                                    current_text.clear();
+                                },
+                                // ERN 4 `Contributor`, ERN 3.8 `ResourceContributor`
+                                b"Contributor" | b"ResourceContributor" => {
+                                    current_contributor = Some(Contributor {
+                                        party_reference: None,
+                                        party_name: Vec::new(),
+                                        role: Vec::new(),
+                                        instrument_type: Vec::new(),
+                                        sequence_number: None,
+                                    });
+                                },
+                                b"PartyName" if current_contributor.is_some() => {
+                                    in_contributor_party_name = true;
+                                },
+                                b"FullName" if in_contributor_party_name => {
+                                    in_contributor_full_name = true;
+                                    current_text.clear();
+                                },
+                                b"ContributorPartyReference" | b"Role" | b"ResourceContributorRole" | b"InstrumentType" if current_contributor.is_some() => {
+                                    in_contributor_text = true;
+                                    user_defined_role = e
+                                        .attributes()
+                                        .flatten()
+                                        .find(|a| a.key.local_name().as_ref() == b"UserDefinedValue")
+                                        .and_then(|a| a.unescape_value().ok())
+                                        .map(|v| v.trim().to_string())
+                                        .filter(|v| !v.is_empty());
+                                    current_text.clear();
+                                },
+                                b"RightsControllerPartyReference" => {
+                                    in_rights_controller_reference = true;
+                                    current_text.clear();
+                                },
+                                b"TechnicalSoundRecordingDetails" | b"TechnicalDetails" => {
+                                    current_technical = Some(TechnicalDetails::default());
+                                },
+                                b"TechnicalVideoDetails" => {
+                                    current_technical = Some(TechnicalDetails {
+                                        video: Some(VideoTechnicalDetails::default()),
+                                        ..Default::default()
+                                    });
+                                },
+                                b"ImageWidth" | b"ImageHeight" | b"FrameRate" | b"VideoCodecType" | b"AspectRatio" | b"VideoBitRate" | b"BitRate"
+                                    if current_technical.as_ref().is_some_and(|t| t.video.is_some()) => {
+                                    in_video_value = true;
+                                    current_text.clear();
+                                },
+                                b"AudioCodecType" | b"BitRate" | b"SamplingRate" | b"BitsPerSample" if current_technical.is_some() => {
+                                    audio_unit = Some(
+                                        e.attributes()
+                                            .flatten()
+                                            .find(|a| a.key.local_name().as_ref() == b"UnitOfMeasure")
+                                            .and_then(|a| a.unescape_value().ok())
+                                            .map(|unit| unit.trim().to_string()),
+                                    );
+                                    current_text.clear();
+                                },
+                                b"TechnicalResourceDetailsReference" if current_technical.is_some() => {
+                                    in_file_text = true;
+                                    current_text.clear();
+                                },
+                                b"File" => current_file = Some(ResourceFile::default()),
+                                // ERN 3.8 nests the value as HashSum/HashSum
+                                b"HashSum" if current_hash.is_some() => {
+                                    in_file_text = true;
+                                    current_text.clear();
+                                },
+                                b"HashSum" if current_file.is_some() => current_hash = Some((None, None)),
+                                b"FileName" | b"FilePath" | b"URI" | b"URL" if current_file.is_some() => {
+                                    in_file_text = true;
+                                    current_text.clear();
+                                },
+                                b"HashSumValue" | b"HashSumAlgorithmType" | b"Algorithm" if current_hash.is_some() => {
+                                    in_file_text = true;
+                                    current_text.clear();
+                                },
+                                _ => {
+                                    if let Some(description) = Self::description_start(e) {
+                                        current_description = Some(description);
+                                        current_text.clear();
+                                    }
+                                }
                             }
                         },
                         Event::Text(ref e) => {
-                            if in_resource_reference || in_isrc || in_title_text ||
-                               in_duration || in_artist_full_name {
+                            if in_resource_reference || in_isrc || in_title_text || in_subtitle || in_duration ||
+                               in_artist_full_name || in_artist_text || in_display_artist_name || in_contributor_full_name || in_contributor_text || in_file_text || in_preview_value || in_video_value || audio_unit.is_some() || in_rights_controller_reference ||
+                               current_description.is_some() {
                                 current_text.push_str(&e.unescape().unwrap_or_default());
                             }
                         },
                         Event::End(ref e) => {
                             depth -= 1;
-                            match e.name().as_ref() {
+                            match e.local_name().as_ref() {
                                 b"ResourceReference" => {
                                     if !current_text.trim().is_empty() {
                                         resource_reference = current_text.trim().to_string();
@@ -663,7 +1685,7 @@ impl GraphBuilder {
                                     in_resource_reference = false;
                                     current_text.clear();
                                 },
-                                b"SoundRecordingId" => in_sound_recording_id = false,
+                                b"SoundRecordingId" | b"VideoId" => in_sound_recording_id = false,
                                 b"ISRC" if in_isrc => {
                                     if !current_text.trim().is_empty() {
                                         resource_ids.push(Identifier {
@@ -675,14 +1697,80 @@ impl GraphBuilder {
                                     in_isrc = false;
                                     current_text.clear();
                                 },
-                                b"Title" => in_title = false,
+                                b"Title" => {
+                                    in_title = false;
+                                    in_any_title = false;
+                                },
+                                b"ReferenceTitle" | b"DisplayTitle" | b"AdditionalTitle" => in_any_title = false,
+                                b"SubTitle" if in_subtitle => {
+                                    Self::push_subtitle(&mut subtitles, &current_text, subtitle_language.take(), self.whitespace);
+                                    in_subtitle = false;
+                                    current_text.clear();
+                                },
                                 b"TitleText" if in_title_text => {
                                     if !current_text.trim().is_empty() {
-                                        reference_titles.push(LocalizedString::new(current_text.trim().to_string()));
+                                        reference_titles.push(LocalizedString::new(self.whitespace.apply(&current_text)));
                                     }
                                     in_title_text = false;
                                     current_text.clear();
                                 },
+                                name if in_preview_value => {
+                                    // Points and lengths are seconds or ISO 8601 durations
+                                    if let (Some(preview), Ok(value)) = (current_preview.as_mut(), parse_duration(current_text.trim())) {
+                                        match name {
+                                            b"StartPoint" => preview.0 = Some(value),
+                                            b"EndPoint" => preview.1 = Some(value),
+                                            _ => preview.2 = Some(value),
+                                        }
+                                    }
+                                    in_preview_value = false;
+                                    current_text.clear();
+                                },
+                                name if in_video_value => {
+                                    let value = current_text.trim();
+                                    if let Some(video) = current_technical.as_mut().and_then(|t| t.video.as_mut()).filter(|_| !value.is_empty()) {
+                                        match name {
+                                            b"ImageWidth" => video.width = value.parse().ok(),
+                                            b"ImageHeight" => video.height = value.parse().ok(),
+                                            b"FrameRate" => video.frame_rate = value.parse().ok(),
+                                            b"VideoCodecType" => video.codec = Some(value.to_string()),
+                                            b"AspectRatio" => video.aspect_ratio = Some(value.to_string()),
+                                            // Bit rates may be given as decimals
+                                            _ => video.bitrate = value.parse::<f64>().ok().map(|rate| rate.round() as i32),
+                                        }
+                                    }
+                                    in_video_value = false;
+                                    current_text.clear();
+                                },
+                                name if audio_unit.is_some() => {
+                                    let unit = audio_unit.take().flatten();
+                                    let value = current_text.trim();
+                                    if let Some(technical) = current_technical.as_mut().filter(|_| !value.is_empty()) {
+                                        match name {
+                                            b"AudioCodecType" => technical.audio_codec = Some(value.to_string()),
+                                            b"BitRate" => technical.bitrate = Self::audio_rate(value, unit.as_deref(), "kbps"),
+                                            b"SamplingRate" => technical.sample_rate = Self::audio_rate(value, unit.as_deref(), "Hz"),
+                                            _ => technical.bits_per_sample = value.parse().ok(),
+                                        }
+                                    }
+                                    current_text.clear();
+                                },
+                                b"PreviewDetails" | b"ClipDetails" => {
+                                    if let Some((start_point, end_point, length)) = current_preview.take() {
+                                        // Relative form gives the length, absolute form the end point
+                                        let duration = length.or_else(|| {
+                                            end_point.and_then(|end| end.checked_sub(start_point.unwrap_or_default()))
+                                        });
+                                        let preview = PreviewDetails { start_point, duration };
+                                        match current_technical.as_mut() {
+                                            Some(technical) => technical.preview = Some(preview),
+                                            None => technical_details.push(TechnicalDetails {
+                                                preview: Some(preview),
+                                                ..Default::default()
+                                            }),
+                                        }
+                                    }
+                                },
                                 b"Duration" => {
                                     if !current_text.trim().is_empty() {
                                         // Parse duration in ISO 8601 format (PT3M30S) or as seconds
@@ -693,16 +1781,145 @@ impl GraphBuilder {
                                     in_duration = false;
                                     current_text.clear();
                                 },
-                                b"DisplayArtist" => in_display_artist = false,
+                                b"DisplayArtistName" if in_display_artist_name => {
+                                    if !current_text.trim().is_empty() {
+                                        display_artist_names.push(LocalizedString::new(self.whitespace.apply(&current_text)));
+                                    }
+                                    in_display_artist_name = false;
+                                    current_text.clear();
+                                },
+                                b"DisplayArtist" => {
+                                    if let Some(mut artist) = current_artist.take() {
+                                        if !artist.display_artist_name.is_empty() || artist.party_reference.is_some() {
+                                            if artist.artist_role.is_empty() {
+                                                artist.artist_role.push("MainArtist".to_string());
+                                            }
+                                            display_artists.push(artist);
+                                        }
+                                    }
+                                },
                                 b"PartyName" if in_artist_party_name => {
                                     in_artist_party_name = false;
                                 },
                                 b"FullName" if in_artist_full_name => {
-                                    // For now, we'll store artist names in the reference_title
-                                    // In a full implementation, we might want to track artists separately
+                                    if let Some(artist) = current_artist.as_mut().filter(|_| !current_text.trim().is_empty()) {
+                                        artist.display_artist_name.push(LocalizedString::new(self.whitespace.apply(&current_text)));
+                                    }
                                     in_artist_full_name = false;
                                     current_text.clear();
                                 },
+                                name @ (b"ArtistPartyReference" | b"DisplayArtistRole" | b"ArtistRole") if in_artist_text => {
+                                    let value = current_text.trim().to_string();
+                                    if let Some(artist) = current_artist.as_mut().filter(|_| !value.is_empty()) {
+                                        if name == b"ArtistPartyReference" {
+                                            artist.party_reference = Some(value);
+                                        } else {
+                                            artist.artist_role.push(value);
+                                        }
+                                    }
+                                    in_artist_text = false;
+                                    current_text.clear();
+                                },
+                                b"Contributor" | b"ResourceContributor" => {
+                                    if let Some(contributor) = current_contributor.take() {
+                                        if !contributor.party_name.is_empty() || contributor.party_reference.is_some() {
+                                            contributors.push(contributor);
+                                        }
+                                    }
+                                },
+                                b"PartyName" if in_contributor_party_name => {
+                                    in_contributor_party_name = false;
+                                },
+                                b"FullName" if in_contributor_full_name => {
+                                    if let Some(contributor) = current_contributor.as_mut().filter(|_| !current_text.trim().is_empty()) {
+                                        contributor.party_name.push(LocalizedString::new(self.whitespace.apply(&current_text)));
+                                    }
+                                    in_contributor_full_name = false;
+                                    current_text.clear();
+                                },
+                                name @ (b"ContributorPartyReference" | b"Role" | b"ResourceContributorRole" | b"InstrumentType") if in_contributor_text => {
+                                    let value = current_text.trim().to_string();
+                                    if let Some(contributor) = current_contributor.as_mut().filter(|_| !value.is_empty()) {
+                                        let value = if value == "UserDefined" {
+                                            user_defined_role.take().unwrap_or(value)
+                                        } else {
+                                            value
+                                        };
+                                        match name {
+                                            b"ContributorPartyReference" => contributor.party_reference = Some(value),
+                                            b"InstrumentType" => contributor.instrument_type.push(value),
+                                            _ => contributor.role.push(value),
+                                        }
+                                    }
+                                    in_contributor_text = false;
+                                    current_text.clear();
+                                },
+                                name if in_file_text => {
+                                    let value = Some(current_text.trim().to_string()).filter(|v| !v.is_empty());
+                                    match name {
+                                        b"TechnicalResourceDetailsReference" => {
+                                            if let Some(technical) = current_technical.as_mut() {
+                                                technical.technical_resource_details_reference = value.unwrap_or_default();
+                                            }
+                                        },
+                                        b"HashSum" | b"HashSumValue" => {
+                                            if let Some(hash) = current_hash.as_mut() {
+                                                hash.1 = value;
+                                            }
+                                        },
+                                        b"HashSumAlgorithmType" | b"Algorithm" => {
+                                            if let Some(hash) = current_hash.as_mut() {
+                                                hash.0 = value;
+                                            }
+                                        },
+                                        _ => {
+                                            if let Some(file) = current_file.as_mut() {
+                                                match name {
+                                                    b"FileName" => file.file_name = value,
+                                                    b"FilePath" => file.file_path = value,
+                                                    _ => file.uri = value,
+                                                }
+                                            }
+                                        },
+                                    }
+                                    in_file_text = false;
+                                    current_text.clear();
+                                },
+                                b"HashSum" => {
+                                    if let (Some(file), Some((algorithm, Some(value)))) = (current_file.as_mut(), current_hash.take()) {
+                                        file.hash_sum = Some(HashSum {
+                                            algorithm: algorithm.unwrap_or_default(),
+                                            value,
+                                        });
+                                    }
+                                },
+                                b"File" => {
+                                    if let Some(file) = current_file.take() {
+                                        match current_technical.as_mut() {
+                                            Some(technical) => technical.files.push(file),
+                                            // File outside a technical details block
+                                            None => technical_details.push(TechnicalDetails {
+                                                files: vec![file],
+                                                ..Default::default()
+                                            }),
+                                        }
+                                    }
+                                },
+                                b"TechnicalSoundRecordingDetails" | b"TechnicalDetails" | b"TechnicalVideoDetails" => {
+                                    technical_details.extend(current_technical.take());
+                                },
+                                b"RightsControllerPartyReference" if in_rights_controller_reference => {
+                                    let reference = current_text.trim().to_string();
+                                    if !reference.is_empty() && !rights_controllers.contains(&reference) {
+                                        rights_controllers.push(reference);
+                                    }
+                                    in_rights_controller_reference = false;
+                                    current_text.clear();
+                                },
+                                name if DESCRIPTION_ELEMENTS.contains(&name) => {
+                                    Self::description_end(&mut current_description, &current_text, self.whitespace, &mut descriptions);
+                                    current_text.clear();
+                                },
                                 _ => {}
                             }
                         },
@@ -722,48 +1939,145 @@ impl GraphBuilder {
             reference_titles.push(LocalizedString::new(format!("Sound Recording {:?}", self.version)));
         }
 
+        if display_artists.is_empty() {
+            display_artists.extend(Self::main_artist_from_names(display_artist_names));
+        }
+
         let resource = Resource {
             resource_reference,
             resource_type: ResourceType::SoundRecording,
             resource_id: resource_ids,
             reference_title: reference_titles,
+            subtitle: subtitles,
+            display_artist: display_artists,
+            contributors,
             duration,
-            technical_details: Vec::new(),
-            rights_controller: Vec::new(),
+            technical_details,
+            rights_controller: rights_controllers,
             p_line: Vec::new(),
             c_line: Vec::new(),
+            descriptions,
             extensions: None,
         };
 
         Ok(resource)
     }
 
-    fn parse_release_deal<R: BufRead>(
+    /// Parse an `Image`, `Text` or `SheetMusic` resource: its reference and
+    /// title. These share none of the SoundRecording layout, so the rest of
+    /// the element is read past.
+    pub(crate) fn parse_basic_resource<R: BufRead>(
+        &self,
+        reader: &mut Reader<R>,
+        validator: &mut crate::parser::xml_validator::XmlValidator,
+        resource_type: ddex_core::models::graph::ResourceType,
+    ) -> Result<ddex_core::models::graph::Resource, ParseError> {
+        use ddex_core::models::common::LocalizedString;
+        use ddex_core::models::graph::Resource;
+
+        let mut resource_reference = String::new();
+        let mut reference_titles = Vec::new();
+        let mut current_text = String::new();
+
+        let mut buf = Vec::new();
+        let mut depth = 1;
+        while depth > 0 {
+            match reader.read_event_into(&mut buf) {
+                Ok(ref event) => {
+                    validator.validate_event(event, reader)?;
+
+                    match event {
+                        Event::Start(_) => {
+                            depth += 1;
+                            current_text.clear();
+                        },
+                        Event::Text(ref e) => current_text.push_str(&e.unescape().unwrap_or_default()),
+                        Event::End(ref e) => {
+                            depth -= 1;
+                            match e.local_name().as_ref() {
+                                b"ResourceReference" if depth == 1 => {
+                                    resource_reference = current_text.trim().to_string();
+                                }
+                                b"TitleText" | b"DisplayTitleText" if reference_titles.is_empty() => {
+                                    reference_titles.push(LocalizedString::new(self.whitespace.apply(&current_text)));
+                                }
+                                _ => {}
+                            }
+                            current_text.clear();
+                        },
+                        Event::Eof => break,
+                        _ => {}
+                    }
+                }
+                Err(e) => {
+                    return Err(ParseError::XmlError(format!("XML parsing error in resource: {}", e)));
+                }
+            }
+            buf.clear();
+        }
+
+        Ok(Resource {
+            resource_reference,
+            resource_type,
+            resource_id: Vec::new(),
+            reference_title: reference_titles,
+            subtitle: Vec::new(),
+            display_artist: Vec::new(),
+            contributors: Vec::new(),
+            duration: None,
+            technical_details: Vec::new(),
+            rights_controller: Vec::new(),
+            p_line: Vec::new(),
+            c_line: Vec::new(),
+            descriptions: Vec::new(),
+            extensions: None,
+        })
+    }
+
+    pub(crate) fn parse_release_deal<R: BufRead>(
         &self,
         reader: &mut Reader<R>,
         validator: &mut crate::parser::xml_validator::XmlValidator,
     ) -> Result<ddex_core::models::graph::Deal, ParseError> {
-        use ddex_core::models::common::ValidityPeriod;
-        use ddex_core::models::graph::{Deal, DealTerms, CommercialModelType, UseType};
+        use ddex_core::models::common::{Price, ValidityPeriod};
+        use ddex_core::models::graph::{Deal, DealTerms, CommercialModelType, PriceInformation, UseType};
         use chrono::{DateTime, Utc};
 
         // Initialize all the fields we'll extract
         let mut deal_reference: Option<String> = None;
+        let mut deal_release_references = Vec::new();
         let mut territory_codes = Vec::new();
+        let mut excluded_territory_codes = Vec::new();
         let mut use_types = Vec::new();
         let mut commercial_model_types = Vec::new();
+        let mut take_down = false;
         let mut validity_period: Option<ValidityPeriod> = None;
         let mut start_date: Option<DateTime<Utc>> = None;
+        let mut end_date: Option<DateTime<Utc>> = None;
+        let mut start_date_text: Option<String> = None;
+        let mut end_date_text: Option<String> = None;
+        let mut price_information = Vec::new();
+        let mut price_type = String::new();
+        let mut price_currency = String::new();
+        let mut price_amount: Option<f64> = None;
+        let mut price_amount_text = String::new();
         let mut current_text = String::new();
 
         // State tracking for nested elements
         let mut in_deal_reference = false;
+        let mut in_deal_release_reference = false;
         let mut in_deal_terms = false;
         let mut in_territory_code = false;
+        let mut in_excluded_territory_code = false;
         let mut in_use_type = false;
         let mut in_commercial_model_type = false;
+        let mut in_take_down = false;
         let mut in_validity_period = false;
         let mut in_start_date = false;
+        let mut in_end_date = false;
+        let mut in_price_information = false;
+        let mut in_price_type = false;
+        let mut in_wholesale_price = false;
 
         // Parse the ReleaseDeal element and extract real data
         let mut buf = Vec::new();
@@ -777,16 +2091,24 @@ impl GraphBuilder {
                     match event {
                         Event::Start(ref e) => {
                             depth += 1;
-                            match e.name().as_ref() {
+                            match e.local_name().as_ref() {
                                 b"DealReference" => {
                                     in_deal_reference = true;
                                     current_text.clear();
                                 },
+                                b"DealReleaseReference" if depth == 2 => {
+                                    in_deal_release_reference = true;
+                                    current_text.clear();
+                                },
                                 b"DealTerms" => in_deal_terms = true,
                                 b"TerritoryCode" if in_deal_terms => {
                                     in_territory_code = true;
                                     current_text.clear();
                                 },
+                                b"ExcludedTerritoryCode" if in_deal_terms => {
+                                    in_excluded_territory_code = true;
+                                    current_text.clear();
+                                },
                                 b"UseType" if in_deal_terms => {
                                     in_use_type = true;
                                     current_text.clear();
@@ -795,6 +2117,10 @@ impl GraphBuilder {
                                     in_commercial_model_type = true;
                                     current_text.clear();
                                 },
+                                b"TakeDown" if in_deal_terms => {
+                                    in_take_down = true;
+                                    current_text.clear();
+                                },
                                 b"ValidityPeriod" if in_deal_terms => {
                                     in_validity_period = true;
                                 },
@@ -802,18 +2128,45 @@ impl GraphBuilder {
                                     in_start_date = true;
                                     current_text.clear();
                                 },
+                                b"EndDate" if in_validity_period => {
+                                    in_end_date = true;
+                                    current_text.clear();
+                                },
+                                b"PriceInformation" if in_deal_terms => {
+                                    in_price_information = true;
+                                    price_type.clear();
+                                    price_currency.clear();
+                                    price_amount = None;
+                                },
+                                b"PriceType" if in_price_information => {
+                                    in_price_type = true;
+                                    current_text.clear();
+                                },
+                                b"WholesalePricePerUnit" if in_price_information => {
+                                    in_wholesale_price = true;
+                                    price_currency = e
+                                        .attributes()
+                                        .flatten()
+                                        .find(|a| a.key.local_name().as_ref() == b"CurrencyCode")
+                                        .and_then(|a| a.unescape_value().ok())
+                                        .map(|v| v.trim().to_string())
+                                        .unwrap_or_default();
+                                    current_text.clear();
+                                },
                                 _ => {}
                             }
                         },
                         Event::Text(ref e) => {
-                            if in_deal_reference || in_territory_code || in_use_type ||
-                               in_commercial_model_type || in_start_date {
+                            if in_deal_reference || in_deal_release_reference ||
+                               in_territory_code || in_excluded_territory_code || in_use_type ||
+                               in_commercial_model_type || in_take_down || in_start_date || in_end_date ||
+                               in_price_type || in_wholesale_price {
                                 current_text.push_str(&e.unescape().unwrap_or_default());
                             }
                         },
                         Event::End(ref e) => {
                             depth -= 1;
-                            match e.name().as_ref() {
+                            match e.local_name().as_ref() {
                                 b"DealReference" => {
                                     if !current_text.trim().is_empty() {
                                         deal_reference = Some(current_text.trim().to_string());
@@ -821,6 +2174,13 @@ impl GraphBuilder {
                                     in_deal_reference = false;
                                     current_text.clear();
                                 },
+                                b"DealReleaseReference" if in_deal_release_reference => {
+                                    if !current_text.trim().is_empty() {
+                                        deal_release_references.push(current_text.trim().to_string());
+                                    }
+                                    in_deal_release_reference = false;
+                                    current_text.clear();
+                                },
                                 b"DealTerms" => in_deal_terms = false,
                                 b"TerritoryCode" if in_territory_code => {
                                     if !current_text.trim().is_empty() {
@@ -829,6 +2189,13 @@ impl GraphBuilder {
                                     in_territory_code = false;
                                     current_text.clear();
                                 },
+                                b"ExcludedTerritoryCode" if in_excluded_territory_code => {
+                                    if !current_text.trim().is_empty() {
+                                        excluded_territory_codes.push(current_text.trim().to_string());
+                                    }
+                                    in_excluded_territory_code = false;
+                                    current_text.clear();
+                                },
                                 b"UseType" if in_use_type => {
                                     if !current_text.trim().is_empty() {
                                         let use_type = match current_text.trim() {
@@ -856,24 +2223,57 @@ impl GraphBuilder {
                                     in_commercial_model_type = false;
                                     current_text.clear();
                                 },
+                                b"TakeDown" if in_take_down => {
+                                    take_down = current_text.trim() == "true";
+                                    in_take_down = false;
+                                    current_text.clear();
+                                },
                                 b"ValidityPeriod" => {
-                                    // Create ValidityPeriod from collected start_date
+                                    // Create ValidityPeriod from the collected dates
                                     validity_period = Some(ValidityPeriod {
-                                        start_date: start_date.clone(),
-                                        end_date: None, // Could be extended to parse EndDate if needed
+                                        start_date,
+                                        end_date,
                                     });
                                     in_validity_period = false;
                                 },
                                 b"StartDate" if in_start_date => {
-                                    if !current_text.trim().is_empty() {
-                                        // Try to parse the date/time
-                                        if let Ok(parsed_date) = DateTime::parse_from_rfc3339(current_text.trim()) {
-                                            start_date = Some(parsed_date.with_timezone(&Utc));
-                                        }
-                                    }
+                                    start_date = Self::parse_date_time(current_text.trim());
+                                    start_date_text = Some(current_text.trim().to_string());
                                     in_start_date = false;
                                     current_text.clear();
                                 },
+                                b"EndDate" if in_end_date => {
+                                    end_date = Self::parse_date_time(current_text.trim());
+                                    end_date_text = Some(current_text.trim().to_string());
+                                    in_end_date = false;
+                                    current_text.clear();
+                                },
+                                b"PriceType" if in_price_type => {
+                                    price_type = current_text.trim().to_string();
+                                    in_price_type = false;
+                                    current_text.clear();
+                                },
+                                b"WholesalePricePerUnit" if in_wholesale_price => {
+                                    price_amount = current_text.trim().parse().ok();
+                                    price_amount_text = current_text.trim().to_string();
+                                    in_wholesale_price = false;
+                                    current_text.clear();
+                                },
+                                b"PriceInformation" if in_price_information => {
+                                    if let Some(amount) = price_amount.take() {
+                                        price_information.push(PriceInformation {
+                                            price_type: std::mem::take(&mut price_type),
+                                            price: Price {
+                                                amount,
+                                                currency: std::mem::take(&mut price_currency),
+                                                territory: None,
+                                            },
+                                            price_tier: None,
+                                            amount_text: Some(std::mem::take(&mut price_amount_text)),
+                                        });
+                                    }
+                                    in_price_information = false;
+                                },
                                 _ => {}
                             }
                         },
@@ -891,25 +2291,28 @@ impl GraphBuilder {
         let deal_terms = DealTerms {
             validity_period,
             start_date,
-            end_date: None,
+            end_date,
+            start_date_text,
+            end_date_text,
             territory_code: territory_codes,
-            excluded_territory_code: Vec::new(),
+            excluded_territory_code: excluded_territory_codes,
             distribution_channel: Vec::new(),
             excluded_distribution_channel: Vec::new(),
             commercial_model_type: commercial_model_types,
             use_type: use_types,
-            price_information: Vec::new(),
+            price_information,
             wholesale_price: Vec::new(),
             suggested_retail_price: Vec::new(),
             pre_order_date: None,
             pre_order_preview_date: None,
             instant_gratification_date: None,
             takedown_date: None,
+            take_down,
         };
 
         let deal = Deal {
             deal_reference,
-            deal_release_reference: Vec::new(), // Could be extracted from parent context if needed
+            deal_release_reference: deal_release_references,
             deal_terms,
         };
 
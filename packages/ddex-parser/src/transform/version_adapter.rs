@@ -69,6 +69,8 @@ impl VersionAdapter {
             validity_period: None,
             start_date: None,
             end_date: None,
+            start_date_text: None,
+            end_date_text: None,
             territory_code: Vec::new(),
             excluded_territory_code: Vec::new(),
             distribution_channel: Vec::new(),
@@ -82,6 +84,7 @@ impl VersionAdapter {
             pre_order_preview_date: None,
             instant_gratification_date: None,
             takedown_date: None,
+            take_down: false,
         })
     }
 }
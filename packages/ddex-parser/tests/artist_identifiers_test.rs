@@ -0,0 +1,107 @@
+// core/tests/artist_identifiers_test.rs
+use ddex_core::models::common::IdentifierType;
+use ddex_parser::DDEXParser;
+use std::io::Cursor;
+
+const ARTIST_PARTIES: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/43" MessageSchemaVersionId="ern/43">
+    <MessageHeader>
+        <MessageId>MSG_ARTIST_IDS_001</MessageId>
+        <MessageSender>
+            <PartyId>PADPIDA_SENDER</PartyId>
+            <PartyName><FullName>Test Label</FullName></PartyName>
+        </MessageSender>
+        <MessageRecipient>
+            <PartyId>PADPIDA_RECIPIENT</PartyId>
+            <PartyName><FullName>Test DSP</FullName></PartyName>
+        </MessageRecipient>
+        <MessageCreatedDateTime>2024-01-01T00:00:00Z</MessageCreatedDateTime>
+    </MessageHeader>
+    <PartyList>
+        <Party>
+            <PartyReference>PArtist</PartyReference>
+            <PartyId>
+                <ISNI>0000000121212121</ISNI>
+                <IpiNameNumber>00052210040</IpiNameNumber>
+            </PartyId>
+            <PartyName><FullName>The Example Band</FullName></PartyName>
+        </Party>
+        <Party>
+            <PartyReference>PFeatured</PartyReference>
+            <PartyId><DPID>PADPIDA2014120301U</DPID></PartyId>
+            <PartyName><FullName>Guest Singer</FullName></PartyName>
+        </Party>
+    </PartyList>
+    <ResourceList>
+        <SoundRecording>
+            <ResourceReference>A1</ResourceReference>
+            <SoundRecordingId><ISRC>USRC17607839</ISRC></SoundRecordingId>
+            <Title><TitleText>Track One</TitleText></Title>
+            <DisplayArtist>
+                <ArtistPartyReference>PArtist</ArtistPartyReference>
+                <DisplayArtistRole>MainArtist</DisplayArtistRole>
+            </DisplayArtist>
+            <DisplayArtist>
+                <ArtistPartyReference>PFeatured</ArtistPartyReference>
+                <DisplayArtistRole>FeaturedArtist</DisplayArtistRole>
+            </DisplayArtist>
+            <Duration>PT3M30S</Duration>
+        </SoundRecording>
+    </ResourceList>
+    <ReleaseList>
+        <Release>
+            <ReleaseReference>R1</ReleaseReference>
+            <ReleaseTitle><TitleText>Example Album</TitleText></ReleaseTitle>
+            <ReleaseType>Album</ReleaseType>
+            <DisplayArtist>
+                <ArtistPartyReference>PArtist</ArtistPartyReference>
+                <DisplayArtistRole>MainArtist</DisplayArtistRole>
+            </DisplayArtist>
+            <ReleaseResourceReferenceList>
+                <ReleaseResourceReference>A1</ReleaseResourceReference>
+            </ReleaseResourceReferenceList>
+        </Release>
+    </ReleaseList>
+</ern:NewReleaseMessage>"#;
+
+#[test]
+fn test_release_artist_carries_party_isni() {
+    let mut parser = DDEXParser::new();
+    let result = parser.parse(Cursor::new(ARTIST_PARTIES.as_bytes())).unwrap();
+    let release = &result.releases()[0];
+
+    assert_eq!(release.display_artist, "The Example Band");
+    assert_eq!(release.artists.len(), 1);
+
+    let artist = &release.artists[0];
+    assert_eq!(artist.name, "The Example Band");
+    assert_eq!(artist.party_id.as_deref(), Some("PArtist"));
+
+    let isni = artist
+        .identifiers
+        .iter()
+        .find(|id| id.id_type == IdentifierType::ISNI)
+        .expect("release artist should carry the party's ISNI");
+    assert_eq!(isni.value, "0000000121212121");
+    assert!(artist
+        .identifiers
+        .iter()
+        .any(|id| id.id_type == IdentifierType::IPI && id.value == "00052210040"));
+}
+
+#[test]
+fn test_track_artists_carry_party_identifiers() {
+    let mut parser = DDEXParser::new();
+    let result = parser.parse(Cursor::new(ARTIST_PARTIES.as_bytes())).unwrap();
+    let track = &result.releases()[0].tracks[0];
+
+    assert_eq!(track.display_artist, "The Example Band, Guest Singer");
+    let roles: Vec<&str> = track.artists.iter().map(|a| a.role.as_str()).collect();
+    assert_eq!(roles, ["MainArtist", "FeaturedArtist"]);
+
+    assert_eq!(track.artists[0].identifiers.len(), 2);
+    let dpid = &track.artists[1].identifiers[0];
+    assert_eq!(dpid.id_type, IdentifierType::Proprietary);
+    assert_eq!(dpid.namespace.as_deref(), Some("DPID"));
+    assert_eq!(dpid.value, "PADPIDA2014120301U");
+}
@@ -0,0 +1,88 @@
+// core/tests/audit_trail_test.rs
+use chrono::{TimeZone, Utc};
+use ddex_parser::DDEXParser;
+use std::io::Cursor;
+
+const WITH_AUDIT_TRAIL: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/43" MessageSchemaVersionId="ern/43">
+    <MessageHeader>
+        <MessageId>MSG_AUDIT</MessageId>
+        <MessageSender>
+            <PartyId>PADPIDA_SENDER</PartyId>
+            <PartyName><FullName>Test Label</FullName></PartyName>
+        </MessageSender>
+        <MessageRecipient>
+            <PartyId>PADPIDA_RECIPIENT</PartyId>
+            <PartyName><FullName>Test DSP</FullName></PartyName>
+        </MessageRecipient>
+        <MessageCreatedDateTime>2024-03-01T12:00:00Z</MessageCreatedDateTime>
+        <MessageAuditTrail>
+            <MessageAuditTrailEvent>
+                <MessageAuditTrailEventType>Created</MessageAuditTrailEventType>
+                <MessagingPartyDescriptor>
+                    <PartyId>PADPIDA_SENDER</PartyId>
+                    <PartyName><FullName>Test Label</FullName></PartyName>
+                </MessagingPartyDescriptor>
+                <DateTime>2024-03-01T09:30:00Z</DateTime>
+            </MessageAuditTrailEvent>
+            <MessageAuditTrailEvent>
+                <MessageAuditTrailEventType>Delivered</MessageAuditTrailEventType>
+                <MessagingPartyDescriptor>
+                    <PartyName><FullName>Test Aggregator</FullName></PartyName>
+                </MessagingPartyDescriptor>
+                <DateTime>2024-03-01T11:45:00Z</DateTime>
+            </MessageAuditTrailEvent>
+        </MessageAuditTrail>
+    </MessageHeader>
+    <ResourceList>
+        <SoundRecording>
+            <ResourceReference>A1</ResourceReference>
+            <SoundRecordingId><ISRC>USRC17607839</ISRC></SoundRecordingId>
+            <Title><TitleText>Track One</TitleText></Title>
+        </SoundRecording>
+    </ResourceList>
+    <ReleaseList>
+        <Release>
+            <ReleaseReference>R1</ReleaseReference>
+            <ReleaseTitle><TitleText>Audited Album</TitleText></ReleaseTitle>
+            <ReleaseType>Album</ReleaseType>
+            <ReleaseResourceReferenceList>
+                <ReleaseResourceReference>A1</ReleaseResourceReference>
+            </ReleaseResourceReferenceList>
+        </Release>
+    </ReleaseList>
+</ern:NewReleaseMessage>"#;
+
+#[test]
+fn test_audit_trail_events_keep_document_order() {
+    let parsed = DDEXParser::new()
+        .parse(Cursor::new(WITH_AUDIT_TRAIL.as_bytes()))
+        .unwrap();
+
+    let trail = &parsed.flat.audit_trail;
+    assert_eq!(trail.len(), 2);
+
+    assert_eq!(trail[0].event_type, "Created");
+    assert_eq!(trail[0].date_time, Utc.with_ymd_and_hms(2024, 3, 1, 9, 30, 0).unwrap());
+    assert_eq!(trail[0].party.as_deref(), Some("PADPIDA_SENDER"));
+
+    assert_eq!(trail[1].event_type, "Delivered");
+    assert_eq!(trail[1].date_time, Utc.with_ymd_and_hms(2024, 3, 1, 11, 45, 0).unwrap());
+    assert_eq!(trail[1].party.as_deref(), Some("Test Aggregator"));
+
+    // The sender's own PartyId and name are not overwritten by the trail's
+    assert_eq!(parsed.flat.sender.id, "PADPIDA_SENDER");
+    assert_eq!(parsed.flat.sender.name, "Test Label");
+}
+
+#[test]
+fn test_message_without_audit_trail_has_none() {
+    let without = WITH_AUDIT_TRAIL.replace(
+        &WITH_AUDIT_TRAIL[WITH_AUDIT_TRAIL.find("        <MessageAuditTrail>").unwrap()
+            ..WITH_AUDIT_TRAIL.find("    </MessageHeader>").unwrap()],
+        "",
+    );
+
+    let parsed = DDEXParser::new().parse(Cursor::new(without.as_bytes())).unwrap();
+    assert!(parsed.flat.audit_trail.is_empty());
+}
@@ -0,0 +1,89 @@
+// core/tests/availability_map_test.rs
+use ddex_parser::DDEXParser;
+use std::io::Cursor;
+
+const WORLDWIDE_EXCEPT_DE: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/43" MessageSchemaVersionId="ern/43">
+    <MessageHeader>
+        <MessageId>MSG_AVAILABILITY_001</MessageId>
+        <MessageSender>
+            <PartyId>PADPIDA_SENDER</PartyId>
+            <PartyName><FullName>Test Label</FullName></PartyName>
+        </MessageSender>
+        <MessageRecipient>
+            <PartyId>PADPIDA_RECIPIENT</PartyId>
+            <PartyName><FullName>Test DSP</FullName></PartyName>
+        </MessageRecipient>
+        <MessageCreatedDateTime>2024-01-01T00:00:00Z</MessageCreatedDateTime>
+    </MessageHeader>
+    <ResourceList>
+        <SoundRecording>
+            <ResourceReference>A1</ResourceReference>
+            <SoundRecordingId><ISRC>USRC17607839</ISRC></SoundRecordingId>
+            <Title><TitleText>Track One</TitleText></Title>
+            <Duration>PT3M30S</Duration>
+        </SoundRecording>
+    </ResourceList>
+    <ReleaseList>
+        <Release>
+            <ReleaseReference>R1</ReleaseReference>
+            <ReleaseTitle><TitleText>Everywhere But Germany</TitleText></ReleaseTitle>
+            <ReleaseType>Album</ReleaseType>
+            <ReleaseResourceReferenceList>
+                <ReleaseResourceReference>A1</ReleaseResourceReference>
+            </ReleaseResourceReferenceList>
+        </Release>
+    </ReleaseList>
+    <DealList>
+        <ReleaseDeal>
+            <DealReleaseReference>R1</DealReleaseReference>
+            <Deal>
+                <DealTerms>
+                    <TerritoryCode>Worldwide</TerritoryCode>
+                    <ExcludedTerritoryCode>DE</ExcludedTerritoryCode>
+                    <ValidityPeriod><StartDate>2024-06-07</StartDate></ValidityPeriod>
+                    <CommercialModelType>SubscriptionModel</CommercialModelType>
+                    <UseType>Stream</UseType>
+                </DealTerms>
+            </Deal>
+        </ReleaseDeal>
+        <ReleaseDeal>
+            <DealReleaseReference>R1</DealReleaseReference>
+            <Deal>
+                <DealTerms>
+                    <TerritoryCode>FR</TerritoryCode>
+                    <ValidityPeriod><StartDate>2024-05-01</StartDate></ValidityPeriod>
+                    <CommercialModelType>PayAsYouGoModel</CommercialModelType>
+                    <UseType>PermanentDownload</UseType>
+                </DealTerms>
+            </Deal>
+        </ReleaseDeal>
+    </DealList>
+</ern:NewReleaseMessage>"#;
+
+#[test]
+fn test_worldwide_deal_honors_excluded_territory() {
+    let mut parser = DDEXParser::new();
+    let result = parser.parse(Cursor::new(WORLDWIDE_EXCEPT_DE.as_bytes())).unwrap();
+    let map = result.availability_map();
+
+    // Excluded from the only deal that would cover it
+    let germany = map.territory("R1", "DE").unwrap();
+    assert!(!germany.available);
+    assert_eq!(germany.start_date, None);
+    assert!(germany.commercial_models.is_empty());
+
+    // Territories no deal names fall back to the worldwide deal
+    assert!(map.is_available("R1", "US"));
+    let japan = map.territory("R1", "JP").unwrap();
+    assert_eq!(japan.start_date.unwrap().to_rfc3339(), "2024-06-07T00:00:00+00:00");
+    assert_eq!(japan.commercial_models, ["SubscriptionModel"]);
+
+    // France is covered by both deals, so it merges their terms
+    let france = map.territory("R1", "FR").unwrap();
+    assert!(france.available);
+    assert_eq!(france.start_date.unwrap().to_rfc3339(), "2024-05-01T00:00:00+00:00");
+    assert_eq!(france.commercial_models, ["SubscriptionModel", "PayAsYouGoModel"]);
+
+    assert!(!map.is_available("R2", "US"));
+}
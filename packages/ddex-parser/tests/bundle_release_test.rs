@@ -0,0 +1,88 @@
+// core/tests/bundle_release_test.rs
+use ddex_parser::DDEXParser;
+use std::io::Cursor;
+
+const BOX_SET: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/43" MessageSchemaVersionId="ern/43">
+    <MessageHeader>
+        <MessageId>MSG_BUNDLE_001</MessageId>
+        <MessageSender>
+            <PartyId>PADPIDA_SENDER</PartyId>
+            <PartyName><FullName>Test Label</FullName></PartyName>
+        </MessageSender>
+        <MessageRecipient>
+            <PartyId>PADPIDA_RECIPIENT</PartyId>
+            <PartyName><FullName>Test DSP</FullName></PartyName>
+        </MessageRecipient>
+        <MessageCreatedDateTime>2024-01-01T00:00:00Z</MessageCreatedDateTime>
+    </MessageHeader>
+    <ResourceList>
+        <SoundRecording>
+            <ResourceReference>A1</ResourceReference>
+            <SoundRecordingId><ISRC>USRC17607839</ISRC></SoundRecordingId>
+            <Title><TitleText>Disc One Opener</TitleText></Title>
+            <Duration>PT3M30S</Duration>
+        </SoundRecording>
+        <SoundRecording>
+            <ResourceReference>A2</ResourceReference>
+            <SoundRecordingId><ISRC>USRC17607840</ISRC></SoundRecordingId>
+            <Title><TitleText>Disc Two Opener</TitleText></Title>
+            <Duration>PT4M10S</Duration>
+        </SoundRecording>
+    </ResourceList>
+    <ReleaseList>
+        <Release>
+            <ReleaseReference>R0</ReleaseReference>
+            <ReleaseId><GRid>A10301A0000000000A</GRid></ReleaseId>
+            <ReleaseTitle><TitleText>The Complete Recordings</TitleText></ReleaseTitle>
+            <ReleaseType>Bundle</ReleaseType>
+            <RelatedRelease>
+                <ReleaseRelationshipType>HasComponentRelease</ReleaseRelationshipType>
+                <ReleaseId><GRid>A10301A0000000001A</GRid></ReleaseId>
+            </RelatedRelease>
+            <RelatedRelease>
+                <ReleaseRelationshipType>HasComponentRelease</ReleaseRelationshipType>
+                <ReleaseId><GRid>A10301A0000000002A</GRid></ReleaseId>
+            </RelatedRelease>
+        </Release>
+        <Release>
+            <ReleaseReference>R1</ReleaseReference>
+            <ReleaseId><GRid>A10301A0000000001A</GRid></ReleaseId>
+            <ReleaseTitle><TitleText>Disc One</TitleText></ReleaseTitle>
+            <ReleaseType>Album</ReleaseType>
+            <ReleaseResourceReferenceList>
+                <ReleaseResourceReference>A1</ReleaseResourceReference>
+            </ReleaseResourceReferenceList>
+        </Release>
+        <Release>
+            <ReleaseReference>R2</ReleaseReference>
+            <ReleaseId><GRid>A10301A0000000002A</GRid></ReleaseId>
+            <ReleaseTitle><TitleText>Disc Two</TitleText></ReleaseTitle>
+            <ReleaseType>Album</ReleaseType>
+            <ReleaseResourceReferenceList>
+                <ReleaseResourceReference>A2</ReleaseResourceReference>
+            </ReleaseResourceReferenceList>
+            <RelatedRelease>
+                <ReleaseRelationshipType>IsComponentReleaseOf</ReleaseRelationshipType>
+                <ReleaseId><GRid>A10301A0000000000A</GRid></ReleaseId>
+            </RelatedRelease>
+        </Release>
+    </ReleaseList>
+</ern:NewReleaseMessage>"#;
+
+#[test]
+fn test_bundle_exposes_its_component_releases() {
+    let mut parser = DDEXParser::new();
+    let result = parser.parse(Cursor::new(BOX_SET.as_bytes())).unwrap();
+    let releases = result.releases();
+
+    let bundle = &releases[0];
+    // R2 is linked from both sides but listed once
+    assert_eq!(bundle.component_release_references, ["R1", "R2"]);
+    assert_eq!(bundle.bundle_release_reference, None);
+
+    for component in &releases[1..] {
+        assert_eq!(component.bundle_release_reference.as_deref(), Some("R0"));
+        assert!(component.component_release_references.is_empty());
+    }
+}
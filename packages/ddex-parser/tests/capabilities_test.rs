@@ -0,0 +1,36 @@
+// core/tests/capabilities_test.rs
+use ddex_parser::{DDEXParser, ERNVersion};
+
+#[test]
+fn test_capabilities_list_supported_versions() {
+    let capabilities = DDEXParser::new().capabilities();
+
+    assert_eq!(
+        capabilities.versions,
+        vec![ERNVersion::V3_8_2, ERNVersion::V4_2, ERNVersion::V4_3]
+    );
+    assert_eq!(
+        capabilities.message_types,
+        vec![
+            "NewReleaseMessage",
+            "UpdateReleaseMessage",
+            "PurgeReleaseMessage"
+        ]
+    );
+}
+
+#[test]
+fn test_capabilities_report_only_compiled_features() {
+    let capabilities = DDEXParser::new().capabilities();
+
+    // The parser never canonicalizes its input
+    assert!(capabilities.canonicalization.is_empty());
+    assert_eq!(
+        capabilities.features.contains(&"async".to_string()),
+        cfg!(feature = "async")
+    );
+    assert_eq!(
+        capabilities.features.contains(&"zero-copy".to_string()),
+        cfg!(feature = "zero-copy")
+    );
+}
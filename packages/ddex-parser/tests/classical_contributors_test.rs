@@ -0,0 +1,103 @@
+// core/tests/classical_contributors_test.rs
+use ddex_parser::DDEXParser;
+use std::io::Cursor;
+
+const CLASSICAL_RECORDING: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/43" MessageSchemaVersionId="ern/43">
+    <MessageHeader>
+        <MessageId>MSG_CLASSICAL_001</MessageId>
+        <MessageSender>
+            <PartyId>PADPIDA_SENDER</PartyId>
+            <PartyName><FullName>Test Label</FullName></PartyName>
+        </MessageSender>
+        <MessageRecipient>
+            <PartyId>PADPIDA_RECIPIENT</PartyId>
+            <PartyName><FullName>Test DSP</FullName></PartyName>
+        </MessageRecipient>
+        <MessageCreatedDateTime>2024-01-01T00:00:00Z</MessageCreatedDateTime>
+    </MessageHeader>
+    <PartyList>
+        <Party>
+            <PartyReference>PConductor</PartyReference>
+            <PartyName><FullName>Herbert von Karajan</FullName></PartyName>
+        </Party>
+        <Party>
+            <PartyReference>POrchestra</PartyReference>
+            <PartyName><FullName>Berlin Philharmonic</FullName></PartyName>
+        </Party>
+    </PartyList>
+    <ResourceList>
+        <SoundRecording>
+            <ResourceReference>A1</ResourceReference>
+            <SoundRecordingId><ISRC>USRC17607839</ISRC></SoundRecordingId>
+            <Title><TitleText>I. Allegro con brio</TitleText></Title>
+            <DisplayArtist>
+                <ArtistPartyReference>POrchestra</ArtistPartyReference>
+                <DisplayArtistRole>MainArtist</DisplayArtistRole>
+            </DisplayArtist>
+            <Contributor SequenceNumber="1">
+                <ContributorPartyReference>PConductor</ContributorPartyReference>
+                <Role>Conductor</Role>
+            </Contributor>
+            <Contributor SequenceNumber="2">
+                <ContributorPartyReference>POrchestra</ContributorPartyReference>
+                <Role>Orchestra</Role>
+            </Contributor>
+            <Contributor SequenceNumber="3">
+                <PartyName><FullName>Chamber Choir</FullName></PartyName>
+                <Role UserDefinedValue="Chorus">UserDefined</Role>
+            </Contributor>
+            <Duration>PT7M21S</Duration>
+        </SoundRecording>
+    </ResourceList>
+    <ReleaseList>
+        <Release>
+            <ReleaseReference>R1</ReleaseReference>
+            <ReleaseTitle><TitleText>Symphony No. 5</TitleText></ReleaseTitle>
+            <ReleaseType>Album</ReleaseType>
+            <ReleaseResourceReferenceList>
+                <ReleaseResourceReference>A1</ReleaseResourceReference>
+            </ReleaseResourceReferenceList>
+        </Release>
+    </ReleaseList>
+</ern:NewReleaseMessage>"#;
+
+#[test]
+fn test_classical_roles_are_kept_apart_from_artists() {
+    let mut parser = DDEXParser::new();
+    let result = parser.parse(Cursor::new(CLASSICAL_RECORDING.as_bytes())).unwrap();
+    let track = &result.releases()[0].tracks[0];
+
+    assert_eq!(track.display_artist, "Berlin Philharmonic");
+
+    let credits: Vec<(&str, &[String], Option<&str>)> = track
+        .contributors
+        .iter()
+        .map(|c| (c.name.as_str(), c.roles.as_slice(), c.party_id.as_deref()))
+        .collect();
+    assert_eq!(
+        credits,
+        vec![
+            ("Herbert von Karajan", &["Conductor".to_string()][..], Some("PConductor")),
+            ("Berlin Philharmonic", &["Orchestra".to_string()][..], Some("POrchestra")),
+            ("Chamber Choir", &["Chorus".to_string()][..], None),
+        ]
+    );
+}
+
+#[test]
+fn test_performer_instruments_are_captured() {
+    let xml = CLASSICAL_RECORDING.replace(
+        r#"<Role UserDefinedValue="Chorus">UserDefined</Role>"#,
+        r#"<Role>Soloist</Role>
+                <InstrumentType>Violin</InstrumentType>
+                <InstrumentType UserDefinedValue="Viola d'amore">UserDefined</InstrumentType>"#,
+    );
+    let mut parser = DDEXParser::new();
+    let result = parser.parse(Cursor::new(xml.as_bytes())).unwrap();
+    let contributors = &result.releases()[0].tracks[0].contributors;
+
+    assert!(contributors[0].instruments.is_empty());
+    assert_eq!(contributors[2].roles, vec!["Soloist"]);
+    assert_eq!(contributors[2].instruments, vec!["Violin", "Viola d'amore"]);
+}
@@ -0,0 +1,102 @@
+// core/tests/collapse_namespaces_test.rs
+use ddex_parser::parser::ParseOptions;
+use ddex_parser::DDEXParser;
+use std::io::Cursor;
+
+const ERN_43: &str = "http://ddex.net/xml/ern/43";
+
+/// The ERN namespace declared as `ern:`, again as `ern43:`, and redundantly
+/// redeclared on the ReleaseList
+const ALIASED_NS: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/43" xmlns:ern43="http://ddex.net/xml/ern/43" MessageSchemaVersionId="ern/43">
+    <ern:MessageHeader>
+        <ern:MessageId>MSG_ALIASED_001</ern:MessageId>
+        <ern:MessageSender>
+            <ern:PartyId>PADPIDA_SENDER</ern:PartyId>
+            <ern:PartyName><ern:FullName>Test Label</ern:FullName></ern:PartyName>
+        </ern:MessageSender>
+        <ern:MessageRecipient>
+            <ern:PartyId>PADPIDA_RECIPIENT</ern:PartyId>
+            <ern:PartyName><ern:FullName>Test DSP</ern:FullName></ern:PartyName>
+        </ern:MessageRecipient>
+        <ern:MessageCreatedDateTime>2024-01-01T00:00:00Z</ern:MessageCreatedDateTime>
+    </ern:MessageHeader>
+    <ern43:ResourceList>
+        <ern43:SoundRecording>
+            <ern43:ResourceReference>A1</ern43:ResourceReference>
+            <ern43:SoundRecordingId><ern43:ISRC>USRC17607839</ern43:ISRC></ern43:SoundRecordingId>
+            <ern43:Title><ern43:TitleText>Track One</ern43:TitleText></ern43:Title>
+        </ern43:SoundRecording>
+    </ern43:ResourceList>
+    <ReleaseList xmlns="http://ddex.net/xml/ern/43">
+        <Release>
+            <ReleaseReference>R1</ReleaseReference>
+            <ReleaseTitle><TitleText>Aliased Album</TitleText></ReleaseTitle>
+            <ReleaseType>Album</ReleaseType>
+            <ReleaseResourceReferenceList>
+                <ReleaseResourceReference>A1</ReleaseResourceReference>
+            </ReleaseResourceReferenceList>
+        </Release>
+    </ReleaseList>
+</ern:NewReleaseMessage>"#;
+
+fn parse(
+    collapse_namespaces: bool,
+    include_raw: bool,
+) -> ddex_core::models::flat::ParsedERNMessage {
+    let options = ParseOptions {
+        collapse_namespaces,
+        include_raw,
+        ..Default::default()
+    };
+    DDEXParser::new()
+        .parse_with_options(Cursor::new(ALIASED_NS.as_bytes()), options)
+        .unwrap()
+}
+
+#[test]
+fn test_equivalent_prefixes_collapse_to_the_canonical_one() {
+    let parsed = parse(true, false);
+
+    assert_eq!(parsed.namespaces.len(), 1);
+    assert_eq!(parsed.namespaces["ern"], ERN_43);
+    assert_eq!(parsed.namespace_aliases["ern43"], "ern");
+    assert_eq!(parsed.namespace_aliases[""], "ern");
+
+    // Every prefix the elements were written with resolves alike
+    for prefix in ["ern", "ern43", ""] {
+        assert_eq!(
+            parsed.namespace_uri(prefix),
+            Some(ERN_43),
+            "prefix '{}'",
+            prefix
+        );
+    }
+
+    assert_eq!(parsed.flat.message_id, "MSG_ALIASED_001");
+    assert_eq!(parsed.releases()[0].default_title, "Aliased Album");
+    assert_eq!(parsed.resources().len(), 1);
+}
+
+#[test]
+fn test_declarations_are_kept_as_written_by_default() {
+    let parsed = parse(false, false);
+
+    assert_eq!(parsed.namespaces.len(), 3);
+    assert_eq!(parsed.namespaces["ern"], ERN_43);
+    assert_eq!(parsed.namespaces["ern43"], ERN_43);
+    assert_eq!(parsed.namespaces[""], ERN_43);
+    assert!(parsed.namespace_aliases.is_empty());
+}
+
+#[test]
+fn test_raw_xml_keeps_the_original_declarations() {
+    let parsed = parse(true, true);
+
+    let raw = parsed.resources()["A1"].raw.as_deref().unwrap();
+    assert!(
+        raw.contains("<ern43:ResourceReference>A1</ern43:ResourceReference>"),
+        "{}",
+        raw
+    );
+}
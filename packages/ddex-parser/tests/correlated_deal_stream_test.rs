@@ -0,0 +1,135 @@
+// core/tests/correlated_deal_stream_test.rs
+use ddex_parser::streaming::{CorrelatedDeal, CorrelatedDealIterator};
+use ddex_parser::ERNVersion;
+use std::io::Cursor;
+
+const MESSAGE: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/43" MessageSchemaVersionId="ern/43">
+    <MessageHeader>
+        <MessageId>MSG_DEALS_001</MessageId>
+        <MessageSender>
+            <PartyId>PADPIDA_SENDER</PartyId>
+            <PartyName><FullName>Test Label</FullName></PartyName>
+        </MessageSender>
+        <MessageRecipient>
+            <PartyId>PADPIDA_RECIPIENT</PartyId>
+            <PartyName><FullName>Test DSP</FullName></PartyName>
+        </MessageRecipient>
+        <MessageCreatedDateTime>2024-01-01T00:00:00Z</MessageCreatedDateTime>
+    </MessageHeader>
+    <ResourceList>
+        <SoundRecording>
+            <ResourceReference>A1</ResourceReference>
+            <SoundRecordingId><ISRC>USRC17607839</ISRC></SoundRecordingId>
+            <Title><TitleText>Track One</TitleText></Title>
+            <Duration>PT3M30S</Duration>
+        </SoundRecording>
+    </ResourceList>
+    <ReleaseList>
+        <Release>
+            <ReleaseReference>R1</ReleaseReference>
+            <ReleaseId><ICPN>123456789012</ICPN></ReleaseId>
+            <ReleaseTitle><TitleText>Deal Album</TitleText></ReleaseTitle>
+            <ReleaseType>Album</ReleaseType>
+            <ReleaseResourceReferenceList>
+                <ReleaseResourceReference>A1</ReleaseResourceReference>
+            </ReleaseResourceReferenceList>
+        </Release>
+        <Release>
+            <ReleaseReference>R2</ReleaseReference>
+            <ReleaseTitle><TitleText>Deal Single</TitleText></ReleaseTitle>
+            <ReleaseType>Single</ReleaseType>
+            <ReleaseResourceReferenceList>
+                <ReleaseResourceReference>A1</ReleaseResourceReference>
+            </ReleaseResourceReferenceList>
+        </Release>
+    </ReleaseList>
+    <DealList>
+        <ReleaseDeal>
+            <DealReleaseReference>R2</DealReleaseReference>
+            <Deal>
+                <DealReference>D1</DealReference>
+                <DealTerms>
+                    <TerritoryCode>Worldwide</TerritoryCode>
+                    <UseType>Stream</UseType>
+                </DealTerms>
+            </Deal>
+        </ReleaseDeal>
+        <ReleaseDeal>
+            <DealReleaseReference>R1</DealReleaseReference>
+            <Deal>
+                <DealReference>D2</DealReference>
+                <DealTerms>
+                    <TerritoryCode>GB</TerritoryCode>
+                    <UseType>PermanentDownload</UseType>
+                </DealTerms>
+            </Deal>
+        </ReleaseDeal>
+        <ReleaseDeal>
+            <DealReleaseReference>R9</DealReleaseReference>
+            <Deal>
+                <DealReference>D3</DealReference>
+                <DealTerms>
+                    <TerritoryCode>US</TerritoryCode>
+                    <UseType>Stream</UseType>
+                </DealTerms>
+            </Deal>
+        </ReleaseDeal>
+    </DealList>
+</ern:NewReleaseMessage>"#;
+
+fn stream(xml: &str) -> Vec<CorrelatedDeal> {
+    CorrelatedDealIterator::new(Cursor::new(xml.as_bytes()), ERNVersion::V4_3)
+        .collect::<Result<_, _>>()
+        .unwrap()
+}
+
+#[test]
+fn test_each_deal_carries_its_release() {
+    let deals = stream(MESSAGE);
+    assert_eq!(deals.len(), 3);
+
+    let links: Vec<(&str, Vec<&str>)> = deals
+        .iter()
+        .map(|correlated| {
+            (
+                correlated.deal.deal_id.as_str(),
+                correlated
+                    .releases
+                    .iter()
+                    .map(|release| release.release_reference.as_str())
+                    .collect(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        links,
+        vec![("D1", vec!["R2"]), ("D2", vec!["R1"]), ("D3", vec![])]
+    );
+
+    assert_eq!(deals[0].releases[0].title, "Deal Single");
+    assert_eq!(deals[1].releases[0].release_type, "Album");
+    assert_eq!(
+        deals[1].releases[0].identifiers.upc.as_deref(),
+        Some("123456789012")
+    );
+    assert_eq!(deals[2].unresolved_references, vec!["R9".to_string()]);
+}
+
+#[test]
+fn test_deal_before_release_list_waits_for_its_release() {
+    let deal_list_start = MESSAGE.find("<DealList>").unwrap();
+    let deal_list_end = MESSAGE.find("</DealList>").unwrap() + "</DealList>".len();
+    let release_list_start = MESSAGE.find("<ReleaseList>").unwrap();
+    let deal_list = &MESSAGE[deal_list_start..deal_list_end];
+
+    let mut reordered = MESSAGE.to_string();
+    reordered.replace_range(deal_list_start..deal_list_end, "");
+    reordered.insert_str(release_list_start, deal_list);
+
+    let deals = stream(&reordered);
+    assert_eq!(deals.len(), 3);
+    assert_eq!(deals[0].releases[0].release_reference, "R2");
+    assert_eq!(deals[1].releases[0].release_reference, "R1");
+    assert!(deals[2].releases.is_empty());
+}
@@ -0,0 +1,144 @@
+// core/tests/count_entities_test.rs
+use ddex_parser::DDEXParser;
+use std::io::Cursor;
+
+fn multi_release_xml(release_count: usize) -> String {
+    let mut xml = String::from(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/43" MessageSchemaVersionId="ern/43">
+    <MessageHeader>
+        <MessageId>MSG_COUNT_001</MessageId>
+        <MessageSender>
+            <PartyId>PADPIDA_SENDER</PartyId>
+            <PartyName><FullName>Test Label</FullName></PartyName>
+        </MessageSender>
+        <MessageRecipient>
+            <PartyId>PADPIDA_RECIPIENT</PartyId>
+            <PartyName><FullName>Test DSP</FullName></PartyName>
+        </MessageRecipient>
+        <MessageCreatedDateTime>2024-01-01T00:00:00Z</MessageCreatedDateTime>
+    </MessageHeader>
+    <ResourceList>"#,
+    );
+
+    for i in 0..release_count * 2 {
+        xml.push_str(&format!(
+            r#"
+        <SoundRecording>
+            <ResourceReference>A{i}</ResourceReference>
+            <SoundRecordingId><ISRC>USRC1760{i:04}</ISRC></SoundRecordingId>
+            <Title><TitleText>Track {i}</TitleText></Title>
+            <Duration>PT3M{s:02}S</Duration>
+        </SoundRecording>"#,
+            s = i % 60
+        ));
+    }
+
+    xml.push_str("\n    </ResourceList>\n    <ReleaseList>");
+    for i in 0..release_count {
+        xml.push_str(&format!(
+            r#"
+        <Release>
+            <ReleaseReference>R{i}</ReleaseReference>
+            <ReleaseId><ICPN>{upc:012}</ICPN></ReleaseId>
+            <ReleaseTitle><TitleText>Album {i}</TitleText></ReleaseTitle>
+            <ReleaseType>Album</ReleaseType>
+            <!-- <Release>not a release</Release> -->
+            <ReleaseResourceReferenceList>
+                <ReleaseResourceReference>A{a}</ReleaseResourceReference>
+                <ReleaseResourceReference>A{b}</ReleaseResourceReference>
+            </ReleaseResourceReferenceList>
+        </Release>"#,
+            upc = 100000000000u64 + i as u64,
+            a = i * 2,
+            b = i * 2 + 1
+        ));
+    }
+
+    xml.push_str("\n    </ReleaseList>\n    <DealList>");
+    for i in 0..release_count {
+        xml.push_str(&format!(
+            r#"
+        <ReleaseDeal>
+            <DealReleaseReference>R{i}</DealReleaseReference>
+            <Deal>
+                <DealTerms>
+                    <TerritoryCode>Worldwide</TerritoryCode>
+                    <CommercialModelType>SubscriptionModel</CommercialModelType>
+                    <UseType>Stream</UseType>
+                    <ValidityPeriod><StartDate>2024-01-01</StartDate></ValidityPeriod>
+                </DealTerms>
+            </Deal>
+        </ReleaseDeal>"#
+        ));
+    }
+    xml.push_str("\n    </DealList>\n</ern:NewReleaseMessage>");
+    xml
+}
+
+#[test]
+fn test_count_entities_matches_full_parse() {
+    let xml = multi_release_xml(5);
+    let mut parser = DDEXParser::new();
+
+    let counts = parser.count_entities(Cursor::new(xml.as_bytes())).unwrap();
+    let parsed = parser.parse(Cursor::new(xml.as_bytes())).unwrap();
+
+    assert_eq!(counts.releases, parsed.releases().len());
+    assert_eq!(counts.resources, parsed.resources().len());
+    assert_eq!(counts.deals, parsed.deals().len());
+    assert_eq!(counts.parties, parsed.parties().len());
+
+    assert_eq!(counts.releases, 5);
+    assert_eq!(counts.resources, 10);
+    assert_eq!(counts.deals, 5);
+}
+
+#[test]
+fn test_count_entities_includes_track_releases_and_videos() {
+    let xml = multi_release_xml(2)
+        .replace(
+            "\n    </ResourceList>",
+            r#"
+        <Video>
+            <ResourceReference>V0</ResourceReference>
+            <Title><TitleText>Video 0</TitleText></Title>
+        </Video>
+    </ResourceList>"#,
+        )
+        .replace(
+            "\n    </ReleaseList>",
+            r#"
+        <TrackRelease>
+            <ReleaseReference>T0</ReleaseReference>
+            <ReleaseId><ISRC>USRC17600000</ISRC></ReleaseId>
+            <ReleaseResourceReference>A0</ReleaseResourceReference>
+        </TrackRelease>
+    </ReleaseList>"#,
+        );
+    let mut parser = DDEXParser::new();
+
+    let counts = parser.count_entities(Cursor::new(xml.as_bytes())).unwrap();
+    let parsed = parser.parse(Cursor::new(xml.as_bytes())).unwrap();
+
+    assert_eq!(counts.releases, parsed.releases().len());
+    assert_eq!(counts.resources, parsed.resources().len());
+    assert_eq!(counts.releases, 3);
+    assert_eq!(counts.resources, 5);
+}
+
+#[test]
+fn test_scan_fast_reports_counts_and_throughput() {
+    let xml = multi_release_xml(5);
+    let parser = DDEXParser::new();
+
+    let summary = parser.scan_fast(Cursor::new(xml.as_bytes())).unwrap();
+
+    assert_eq!(summary.releases, 5);
+    assert_eq!(summary.resources, 10);
+    assert_eq!(summary.deals, 5);
+    assert_eq!(summary.total_bytes, xml.len() as u64);
+    assert!(summary.elements > 0);
+    assert!(summary.throughput_mbps > 0.0);
+    assert!(summary.avg_element_size > 0.0);
+}
@@ -0,0 +1,76 @@
+// core/tests/deep_extension_test.rs
+use ddex_core::models::XmlFragment;
+use ddex_parser::parser::extension_capture::ExtensionAwareParser;
+
+/// A release carrying one extension nested `levels` elements deep
+fn document(levels: usize) -> String {
+    let mut xml = String::from(
+        r#"<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/43" xmlns:lbl="http://label.example.com/ext"><ReleaseList><Release>"#,
+    );
+    for level in 0..levels {
+        xml.push_str(&format!(r#"<lbl:Node level="{}">"#, level));
+    }
+    xml.push_str("deepest<lbl:Leaf/>");
+    xml.push_str(&"</lbl:Node>".repeat(levels));
+    xml.push_str("</Release></ReleaseList></ern:NewReleaseMessage>");
+    xml
+}
+
+/// The only extension captured, walked down to its innermost `Node`
+fn innermost(fragment: &XmlFragment) -> (usize, &XmlFragment) {
+    let mut levels = 1;
+    let mut current = fragment;
+    while let Some(child) = current.children.iter().find(|c| c.element_name == "Node") {
+        levels += 1;
+        current = child;
+    }
+    (levels, current)
+}
+
+#[test]
+fn test_deep_extension_under_raised_limit_parses() {
+    let levels = 20_000;
+    let extensions = ExtensionAwareParser::new(true)
+        .with_max_depth(levels + 10)
+        .parse_with_extensions(&document(levels))
+        .unwrap();
+
+    assert_eq!(extensions.fragments.len(), 1);
+    let root = extensions.fragments.values().next().unwrap();
+    assert_eq!(root.qualified_name(), "lbl:Node");
+    assert_eq!(root.attributes.get("level").map(String::as_str), Some("0"));
+
+    let (depth, deepest) = innermost(root);
+    assert_eq!(depth, levels);
+    assert_eq!(
+        deepest.attributes.get("level").map(String::as_str),
+        Some((levels - 1).to_string().as_str())
+    );
+    assert_eq!(deepest.text_content.as_deref(), Some("deepest"));
+    assert_eq!(deepest.children.len(), 1);
+    assert_eq!(deepest.children[0].qualified_name(), "lbl:Leaf");
+
+    let canonical = root.to_canonical_xml(0);
+    assert_eq!(canonical.matches("<lbl:Node").count(), levels);
+    assert_eq!(canonical.matches("</lbl:Node>").count(), levels);
+}
+
+#[test]
+fn test_deep_extension_under_default_limit_parses() {
+    let extensions = ExtensionAwareParser::new(true)
+        .parse_with_extensions(&document(90))
+        .unwrap();
+
+    let root = extensions.fragments.values().next().unwrap();
+    assert_eq!(innermost(root).0, 90);
+}
+
+#[test]
+fn test_extension_over_limit_is_rejected() {
+    let result = ExtensionAwareParser::new(true)
+        .with_max_depth(50)
+        .parse_with_extensions(&document(100));
+
+    let error = result.err().expect("nesting beyond the limit must fail");
+    assert!(error.to_string().contains("50"), "{}", error);
+}
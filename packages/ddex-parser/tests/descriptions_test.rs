@@ -0,0 +1,72 @@
+// core/tests/descriptions_test.rs
+use ddex_parser::DDEXParser;
+use std::io::Cursor;
+
+const XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/43" MessageSchemaVersionId="ern/43">
+    <MessageHeader>
+        <MessageId>MSG_DESCRIPTIONS_001</MessageId>
+        <MessageSender>
+            <PartyId>PADPIDA_SENDER</PartyId>
+            <PartyName><FullName>Test Label</FullName></PartyName>
+        </MessageSender>
+        <MessageRecipient>
+            <PartyId>PADPIDA_RECIPIENT</PartyId>
+            <PartyName><FullName>Test DSP</FullName></PartyName>
+        </MessageRecipient>
+        <MessageCreatedDateTime>2024-01-01T00:00:00Z</MessageCreatedDateTime>
+    </MessageHeader>
+    <ResourceList>
+        <SoundRecording>
+            <ResourceReference>A1</ResourceReference>
+            <SoundRecordingId><ISRC>USRC17607839</ISRC></SoundRecordingId>
+            <Title><TitleText>Track One</TitleText></Title>
+            <Duration>PT3M30S</Duration>
+            <Synopsis LanguageAndScriptCode="en">Recorded live in one take.</Synopsis>
+        </SoundRecording>
+    </ResourceList>
+    <ReleaseList>
+        <Release>
+            <ReleaseReference>R1</ReleaseReference>
+            <ReleaseId><ICPN>123456789012</ICPN></ReleaseId>
+            <ReleaseTitle><TitleText>Described Album</TitleText></ReleaseTitle>
+            <ReleaseType>Album</ReleaseType>
+            <MarketingComment LanguageAndScriptCode="en">The long-awaited debut &amp; more.</MarketingComment>
+            <MarketingComment LanguageAndScriptCode="fr">Le premier album tant attendu.</MarketingComment>
+            <ReleaseResourceReferenceList>
+                <ReleaseResourceReference>A1</ReleaseResourceReference>
+            </ReleaseResourceReferenceList>
+        </Release>
+    </ReleaseList>
+</ern:NewReleaseMessage>"#;
+
+#[test]
+fn test_marketing_comment_keeps_language() {
+    let mut parser = DDEXParser::new();
+    let result = parser.parse(Cursor::new(XML.as_bytes())).unwrap();
+    let release = &result.releases()[0];
+
+    assert_eq!(
+        release.description("MarketingComment", "en"),
+        Some("The long-awaited debut & more.")
+    );
+    assert_eq!(
+        release.description("MarketingComment", "fr"),
+        Some("Le premier album tant attendu.")
+    );
+    assert_eq!(release.descriptions["MarketingComment"].len(), 2);
+    assert_eq!(release.description("MarketingComment", "de"), None);
+}
+
+#[test]
+fn test_resource_descriptions() {
+    let mut parser = DDEXParser::new();
+    let result = parser.parse(Cursor::new(XML.as_bytes())).unwrap();
+    let resource = &result.resources()["A1"];
+
+    assert_eq!(
+        resource.description("Synopsis", "en"),
+        Some("Recorded live in one take.")
+    );
+    assert!(!resource.descriptions.contains_key("MarketingComment"));
+}
@@ -0,0 +1,51 @@
+// core/tests/display_artist_name_test.rs
+use ddex_parser::DDEXParser;
+use std::io::Cursor;
+
+const NAMED_ARTISTS: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/43" MessageSchemaVersionId="ern/43">
+    <MessageHeader>
+        <MessageId>MSG_ARTISTS_001</MessageId>
+        <MessageSender>
+            <PartyId>PADPIDA_SENDER</PartyId>
+            <PartyName><FullName>Test Label</FullName></PartyName>
+        </MessageSender>
+        <MessageRecipient>
+            <PartyId>PADPIDA_RECIPIENT</PartyId>
+            <PartyName><FullName>Test DSP</FullName></PartyName>
+        </MessageRecipient>
+        <MessageCreatedDateTime>2024-01-01T00:00:00Z</MessageCreatedDateTime>
+    </MessageHeader>
+    <ResourceList>
+        <SoundRecording>
+            <ResourceReference>A1</ResourceReference>
+            <SoundRecordingId><ISRC>USRC17607839</ISRC></SoundRecordingId>
+            <Title><TitleText>Guest Spot</TitleText></Title>
+            <DisplayArtistName><FullName>B</FullName></DisplayArtistName>
+            <Duration>PT3M30S</Duration>
+        </SoundRecording>
+    </ResourceList>
+    <ReleaseList>
+        <Release>
+            <ReleaseReference>R1</ReleaseReference>
+            <ReleaseTitle><TitleText>Album</TitleText></ReleaseTitle>
+            <DisplayArtistName><FullName>A</FullName></DisplayArtistName>
+            <ReleaseType>Album</ReleaseType>
+            <ReleaseResourceReferenceList>
+                <ReleaseResourceReference>A1</ReleaseResourceReference>
+            </ReleaseResourceReferenceList>
+        </Release>
+    </ReleaseList>
+</ern:NewReleaseMessage>"#;
+
+#[test]
+fn test_display_artist_names_stay_with_their_release_and_resource() {
+    let parsed = DDEXParser::new()
+        .parse(Cursor::new(NAMED_ARTISTS.as_bytes()))
+        .unwrap();
+
+    let release = &parsed.flat.releases[0];
+    assert_eq!(release.display_artist, "A");
+    assert_eq!(release.tracks.len(), 1);
+    assert_eq!(release.tracks[0].display_artist, "B");
+}
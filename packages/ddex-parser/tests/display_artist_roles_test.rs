@@ -0,0 +1,60 @@
+// core/tests/display_artist_roles_test.rs
+use ddex_parser::DDEXParser;
+use std::io::Cursor;
+
+const XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/43" MessageSchemaVersionId="ern/43">
+    <MessageHeader>
+        <MessageId>MSG_ROLES_001</MessageId>
+        <MessageSender>
+            <PartyId>PADPIDA_SENDER</PartyId>
+            <PartyName><FullName>Test Label</FullName></PartyName>
+        </MessageSender>
+        <MessageRecipient>
+            <PartyId>PADPIDA_RECIPIENT</PartyId>
+            <PartyName><FullName>Test DSP</FullName></PartyName>
+        </MessageRecipient>
+        <MessageCreatedDateTime>2024-01-01T00:00:00Z</MessageCreatedDateTime>
+    </MessageHeader>
+    <PartyList>
+        <Party>
+            <PartyReference>PA</PartyReference>
+            <PartyName><FullName>A</FullName></PartyName>
+        </Party>
+        <Party>
+            <PartyReference>PB</PartyReference>
+            <PartyName><FullName>B</FullName></PartyName>
+        </Party>
+    </PartyList>
+    <ReleaseList>
+        <Release>
+            <ReleaseReference>R1</ReleaseReference>
+            <ReleaseId><ICPN>123456789012</ICPN></ReleaseId>
+            <ReferenceTitle><TitleText>Duet</TitleText></ReferenceTitle>
+            <DisplayArtistName>A feat. B</DisplayArtistName>
+            <DisplayArtist SequenceNumber="1">
+                <ArtistPartyReference>PA</ArtistPartyReference>
+                <DisplayArtistRole>MainArtist</DisplayArtistRole>
+            </DisplayArtist>
+            <DisplayArtist SequenceNumber="2">
+                <ArtistPartyReference>PB</ArtistPartyReference>
+                <DisplayArtistRole>FeaturedArtist</DisplayArtistRole>
+            </DisplayArtist>
+            <ReleaseType>Single</ReleaseType>
+        </Release>
+    </ReleaseList>
+</ern:NewReleaseMessage>"#;
+
+#[test]
+fn test_release_artists_keep_main_and_featured_roles() {
+    let result = DDEXParser::new().parse(Cursor::new(XML.as_bytes())).unwrap();
+    let release = &result.flat.releases[0];
+
+    let artists: Vec<_> = release
+        .artists
+        .iter()
+        .map(|artist| (artist.name.as_str(), artist.role.as_str()))
+        .collect();
+    assert_eq!(artists, [("A", "MainArtist"), ("B", "FeaturedArtist")]);
+    assert_eq!(release.artists[1].party_id.as_deref(), Some("PB"));
+}
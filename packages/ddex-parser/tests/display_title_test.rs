@@ -0,0 +1,62 @@
+// core/tests/display_title_test.rs
+use ddex_parser::DDEXParser;
+use std::io::Cursor;
+
+const XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/43" MessageSchemaVersionId="ern/43">
+    <MessageHeader>
+        <MessageId>MSG_TITLES_001</MessageId>
+        <MessageSender>
+            <PartyId>PADPIDA_SENDER</PartyId>
+            <PartyName><FullName>Test Label</FullName></PartyName>
+        </MessageSender>
+        <MessageRecipient>
+            <PartyId>PADPIDA_RECIPIENT</PartyId>
+            <PartyName><FullName>Test DSP</FullName></PartyName>
+        </MessageRecipient>
+        <MessageCreatedDateTime>2024-01-01T00:00:00Z</MessageCreatedDateTime>
+    </MessageHeader>
+    <ReleaseList>
+        <Release>
+            <ReleaseReference>R1</ReleaseReference>
+            <ReleaseId><ICPN>123456789012</ICPN></ReleaseId>
+            <DisplayTitle LanguageAndScriptCode="en"><TitleText>Song of the Wind</TitleText></DisplayTitle>
+            <DisplayTitle LanguageAndScriptCode="ja"><TitleText>風の歌</TitleText></DisplayTitle>
+            <ReferenceTitle><TitleText>KAZE_NO_UTA_MASTER_v2</TitleText></ReferenceTitle>
+            <ReleaseType>Album</ReleaseType>
+        </Release>
+        <Release>
+            <ReleaseReference>R2</ReleaseReference>
+            <ReleaseId><ICPN>123456789029</ICPN></ReleaseId>
+            <DisplayTitle><TitleText>Display Only</TitleText></DisplayTitle>
+            <ReleaseType>Single</ReleaseType>
+        </Release>
+    </ReleaseList>
+</ern:NewReleaseMessage>"#;
+
+#[test]
+fn test_reference_and_display_titles_are_distinct() {
+    let result = DDEXParser::new().parse(Cursor::new(XML.as_bytes())).unwrap();
+    let release = &result.flat.releases[0];
+
+    assert_eq!(release.reference_title.len(), 1);
+    assert_eq!(release.reference_title[0].text, "KAZE_NO_UTA_MASTER_v2");
+
+    assert_eq!(release.display_title.len(), 2);
+    assert_eq!(release.display_title[0].text, "Song of the Wind");
+    assert_eq!(release.display_title[0].language_code.as_deref(), Some("en"));
+    assert_eq!(release.display_title[1].text, "風の歌");
+    assert_eq!(release.display_title[1].language_code.as_deref(), Some("ja"));
+
+    assert_eq!(release.default_title, "KAZE_NO_UTA_MASTER_v2");
+}
+
+#[test]
+fn test_display_title_is_the_fallback_default() {
+    let result = DDEXParser::new().parse(Cursor::new(XML.as_bytes())).unwrap();
+    let release = &result.flat.releases[1];
+
+    assert!(release.reference_title.is_empty());
+    assert_eq!(release.display_title[0].text, "Display Only");
+    assert_eq!(release.default_title, "Display Only");
+}
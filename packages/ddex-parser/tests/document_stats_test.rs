@@ -0,0 +1,71 @@
+// core/tests/document_stats_test.rs
+use ddex_parser::parser::ParseOptions;
+use ddex_parser::DDEXParser;
+use std::io::Cursor;
+
+const COUNTED: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/43" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" MessageSchemaVersionId="ern/43" LanguageAndScriptCode="en">
+    <!-- generated by the label system -->
+    <MessageHeader>
+        <MessageId>MSG_STATS_001</MessageId>
+        <MessageSender>
+            <PartyId>PADPIDA_SENDER</PartyId>
+            <PartyName><FullName>Test Label</FullName></PartyName>
+        </MessageSender>
+        <MessageRecipient>
+            <PartyId>PADPIDA_RECIPIENT</PartyId>
+            <PartyName><FullName>Test DSP</FullName></PartyName>
+        </MessageRecipient>
+        <MessageCreatedDateTime>2024-01-01T00:00:00Z</MessageCreatedDateTime>
+    </MessageHeader>
+    <ResourceList>
+        <SoundRecording>
+            <ResourceReference>A1</ResourceReference>
+            <SoundRecordingId><ISRC>USRC17607839</ISRC></SoundRecordingId>
+            <Title><TitleText>Track One</TitleText></Title>
+            <Duration>PT3M30S</Duration>
+        </SoundRecording>
+    </ResourceList>
+    <ReleaseList>
+        <!-- one release only -->
+        <Release>
+            <ReleaseReference>R1</ReleaseReference>
+            <ReleaseTitle><TitleText>Counted</TitleText></ReleaseTitle>
+            <ReleaseType>Album</ReleaseType>
+            <ReleaseResourceReferenceList>
+                <ReleaseResourceReference>A1</ReleaseResourceReference>
+            </ReleaseResourceReferenceList>
+        </Release>
+    </ReleaseList>
+</ern:NewReleaseMessage>"#;
+
+fn parse(collect_statistics: bool) -> ddex_core::models::flat::ParsedERNMessage {
+    let options = ParseOptions {
+        collect_statistics,
+        ..Default::default()
+    };
+    DDEXParser::new()
+        .parse_with_options(Cursor::new(COUNTED.as_bytes()), options)
+        .unwrap()
+}
+
+#[test]
+fn test_document_is_tallied_while_parsing() {
+    let parsed = parse(true);
+    let stats = &parsed.document_stats;
+
+    assert_eq!(stats.element_count, 28);
+    // MessageSchemaVersionId and LanguageAndScriptCode; xmlns declarations
+    // count as namespaces instead
+    assert_eq!(stats.attribute_count, 2);
+    assert_eq!(stats.comment_count, 2);
+    assert_eq!(stats.namespace_count, 2);
+    assert_eq!(stats.file_size_bytes, COUNTED.len() as u64);
+}
+
+#[test]
+fn test_statistics_are_only_collected_on_request() {
+    let parsed = parse(false);
+
+    assert_eq!(parsed.document_stats, Default::default());
+}
@@ -0,0 +1,79 @@
+// core/tests/duplicate_resource_reference_test.rs
+use ddex_parser::DDEXParser;
+use std::io::Cursor;
+
+fn message(releases: &str) -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/43" MessageSchemaVersionId="ern/43">
+    <MessageHeader>
+        <MessageId>MSG_DUP_001</MessageId>
+        <MessageSender>
+            <PartyId>PADPIDA_SENDER</PartyId>
+            <PartyName><FullName>Test Label</FullName></PartyName>
+        </MessageSender>
+        <MessageRecipient>
+            <PartyId>PADPIDA_RECIPIENT</PartyId>
+            <PartyName><FullName>Test DSP</FullName></PartyName>
+        </MessageRecipient>
+        <MessageCreatedDateTime>2024-01-01T00:00:00Z</MessageCreatedDateTime>
+    </MessageHeader>
+    <ResourceList>
+        <SoundRecording>
+            <ResourceReference>A1</ResourceReference>
+            <SoundRecordingId><ISRC>USRC17607839</ISRC></SoundRecordingId>
+            <Title><TitleText>Track One</TitleText></Title>
+            <Duration>PT3M30S</Duration>
+        </SoundRecording>
+    </ResourceList>
+    <ReleaseList>{}
+    </ReleaseList>
+</ern:NewReleaseMessage>"#,
+        releases
+    )
+}
+
+fn release(reference: &str, resources: &[&str]) -> String {
+    let references: String = resources
+        .iter()
+        .map(|r| format!("<ReleaseResourceReference>{}</ReleaseResourceReference>", r))
+        .collect();
+    format!(
+        r#"
+        <Release>
+            <ReleaseReference>{}</ReleaseReference>
+            <ReleaseId><ICPN>123456789012</ICPN></ReleaseId>
+            <ReleaseTitle><TitleText>Album</TitleText></ReleaseTitle>
+            <ReleaseType>Album</ReleaseType>
+            <ReleaseResourceReferenceList>{}</ReleaseResourceReferenceList>
+        </Release>"#,
+        reference, references
+    )
+}
+
+fn duplicate_warnings(xml: &str) -> Vec<String> {
+    DDEXParser::new()
+        .parse(Cursor::new(xml.as_bytes()))
+        .unwrap()
+        .warnings
+        .into_iter()
+        .filter(|w| w.code == "DUPLICATE_RESOURCE_REFERENCE")
+        .map(|w| w.message)
+        .collect()
+}
+
+#[test]
+fn test_release_referencing_resource_twice_is_warned() {
+    let warnings = duplicate_warnings(&message(&release("R1", &["A1", "A1"])));
+
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].contains("'R1'"));
+    assert!(warnings[0].contains("'A1'"));
+}
+
+#[test]
+fn test_resource_shared_between_releases_is_not_warned() {
+    let releases = format!("{}{}", release("R1", &["A1"]), release("R2", &["A1"]));
+
+    assert!(duplicate_warnings(&message(&releases)).is_empty());
+}
@@ -0,0 +1,79 @@
+// core/tests/element_hook_test.rs
+use ddex_parser::parser::element_hook::ElementHook;
+use ddex_parser::parser::ParseOptions;
+use ddex_parser::DDEXParser;
+use std::io::Cursor;
+
+const RAP_RELEASE: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/43" MessageSchemaVersionId="ern/43">
+    <MessageHeader>
+        <MessageId>MSG_ELEMENT_HOOK_001</MessageId>
+        <MessageSender>
+            <PartyId>PADPIDA_SENDER</PartyId>
+            <PartyName><FullName>Test Label</FullName></PartyName>
+        </MessageSender>
+        <MessageRecipient>
+            <PartyId>PADPIDA_RECIPIENT</PartyId>
+            <PartyName><FullName>Test DSP</FullName></PartyName>
+        </MessageRecipient>
+        <MessageCreatedDateTime>2024-01-01T00:00:00Z</MessageCreatedDateTime>
+    </MessageHeader>
+    <ResourceList>
+        <SoundRecording>
+            <ResourceReference>A1</ResourceReference>
+            <SoundRecordingId><ISRC>USRC17607839</ISRC></SoundRecordingId>
+            <Title><TitleText>Track One</TitleText></Title>
+            <Duration>PT3M30S</Duration>
+        </SoundRecording>
+    </ResourceList>
+    <ReleaseList>
+        <Release>
+            <ReleaseReference>R1</ReleaseReference>
+            <ReleaseTitle><TitleText>Street Anthems</TitleText></ReleaseTitle>
+            <ReleaseType>Album</ReleaseType>
+            <Genre>
+                <GenreText>Rap</GenreText>
+                <SubGenre>Trap</SubGenre>
+            </Genre>
+            <ReleaseResourceReferenceList>
+                <ReleaseResourceReference>A1</ReleaseResourceReference>
+            </ReleaseResourceReferenceList>
+        </Release>
+    </ReleaseList>
+</ern:NewReleaseMessage>"#;
+
+fn parse(element_hook: Option<ElementHook>) -> ddex_core::models::flat::ParsedERNMessage {
+    let options = ParseOptions {
+        element_hook,
+        ..Default::default()
+    };
+    DDEXParser::new()
+        .parse_with_options(Cursor::new(RAP_RELEASE.as_bytes()), options)
+        .unwrap()
+}
+
+#[test]
+fn test_hook_rewrites_genre_before_it_reaches_the_model() {
+    let hook = ElementHook::new(|element| {
+        if element.name == "GenreText" && element.text.as_deref() == Some("Rap") {
+            element.text = Some("Hip-Hop".to_string());
+        }
+    });
+    let parsed = parse(Some(hook));
+    let release = &parsed.releases()[0];
+
+    assert_eq!(release.genre.as_deref(), Some("Hip-Hop"));
+    // Elements the hook leaves alone are read as written
+    assert_eq!(release.sub_genre.as_deref(), Some("Trap"));
+    assert_eq!(release.default_title, "Street Anthems");
+}
+
+#[test]
+fn test_genre_mapping_table() {
+    let map = [("Rap".to_string(), "Hip-Hop".to_string())].into_iter().collect();
+    let parsed = parse(Some(ElementHook::map_text("GenreText", map)));
+    assert_eq!(parsed.releases()[0].genre.as_deref(), Some("Hip-Hop"));
+
+    let unmapped = parse(None);
+    assert_eq!(unmapped.releases()[0].genre.as_deref(), Some("Rap"));
+}
@@ -0,0 +1,90 @@
+// core/tests/element_limits_test.rs
+use ddex_parser::parser::{mode::ParseMode, security::SecurityConfig, ParseOptions};
+use ddex_parser::{error::ParseError, DDEXParser};
+use std::io::Cursor;
+use std::time::{Duration, Instant};
+
+fn message(release_extra: &str) -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/43" MessageSchemaVersionId="ern/43">
+    <MessageHeader>
+        <MessageId>MSG_LIMITS_001</MessageId>
+        <MessageCreatedDateTime>2024-01-01T00:00:00Z</MessageCreatedDateTime>
+    </MessageHeader>
+    <ReleaseList>
+        <Release>
+            <ReleaseReference>R1</ReleaseReference>
+            <ReleaseTitle><TitleText>Album</TitleText></ReleaseTitle>
+            {release_extra}
+        </Release>
+    </ReleaseList>
+</ern:NewReleaseMessage>"#
+    )
+}
+
+fn many_attributes(count: usize) -> String {
+    let attributes: String = (0..count).map(|i| format!(" a{}=\"x\"", i)).collect();
+    format!("<ReleaseType{}>Album</ReleaseType>", attributes)
+}
+
+fn expect_security_violation(xml: &str, config: SecurityConfig, mode: ParseMode, expected: &str) {
+    let start = Instant::now();
+    let result = DDEXParser::with_config(config).parse_with_options(
+        Cursor::new(xml.as_bytes()),
+        ParseOptions {
+            mode,
+            ..Default::default()
+        },
+    );
+
+    match result {
+        Err(ParseError::SecurityViolation { message }) => assert!(
+            message.contains(expected),
+            "unexpected message: {}",
+            message
+        ),
+        other => panic!("Expected SecurityViolation, got: {:?}", other.map(|_| ())),
+    }
+    assert!(start.elapsed() < Duration::from_secs(5));
+}
+
+#[test]
+fn test_too_many_attributes() {
+    let xml = message(&many_attributes(100_000));
+
+    for mode in [ParseMode::Dom, ParseMode::Stream] {
+        expect_security_violation(&xml, SecurityConfig::strict(), mode, "attributes");
+    }
+}
+
+#[test]
+fn test_overlong_element_name() {
+    let name = "X".repeat(10_000);
+    let xml = message(&format!("<{name}>value</{name}>"));
+
+    for mode in [ParseMode::Dom, ParseMode::Stream] {
+        expect_security_violation(&xml, SecurityConfig::strict(), mode, "Element name");
+    }
+}
+
+#[test]
+fn test_overlong_attribute_name() {
+    let xml = message(&format!("<ReleaseType {}=\"x\">Album</ReleaseType>", "a".repeat(300)));
+
+    expect_security_violation(&xml, SecurityConfig::strict(), ParseMode::Dom, "Attribute name");
+}
+
+#[test]
+fn test_custom_limits() {
+    let xml = message(&many_attributes(5));
+
+    let mut config = SecurityConfig::strict();
+    config.max_attributes_per_element = 4;
+    expect_security_violation(&xml, config, ParseMode::Dom, "attributes");
+
+    let mut config = SecurityConfig::strict();
+    config.max_attributes_per_element = 5;
+    let result = DDEXParser::with_config(config).parse(Cursor::new(xml.as_bytes()));
+    assert!(!matches!(result, Err(ParseError::SecurityViolation { .. })));
+}
@@ -0,0 +1,74 @@
+// core/tests/encoding_corruption_test.rs
+use ddex_parser::error::ParseError;
+use ddex_parser::DDEXParser;
+use std::io::Cursor;
+
+const HEADER: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<NewReleaseMessage xmlns="http://ddex.net/xml/ern/43" MessageSchemaVersionId="ern/43">
+    <MessageHeader>
+        <MessageId>MSG_SPLICE_001</MessageId>
+        <MessageSender>
+            <PartyId>PADPIDA_SENDER</PartyId>
+            <PartyName><FullName>Test Label</FullName></PartyName>
+        </MessageSender>
+        <MessageRecipient>
+            <PartyId>PADPIDA_RECIPIENT</PartyId>
+            <PartyName><FullName>Test DSP</FullName></PartyName>
+        </MessageRecipient>
+        <MessageCreatedDateTime>2024-01-01T00:00:00Z</MessageCreatedDateTime>
+    </MessageHeader>
+"#;
+
+const BODY: &str = r#"    <ResourceList>
+        <SoundRecording>
+            <ResourceReference>A1</ResourceReference>
+            <SoundRecordingId><ISRC>USRC17607839</ISRC></SoundRecordingId>
+            <Title><TitleText>Track One</TitleText></Title>
+        </SoundRecording>
+    </ResourceList>
+    <ReleaseList>
+        <Release>
+            <ReleaseReference>R1</ReleaseReference>
+            <ReleaseTitle><TitleText>Spliced Album</TitleText></ReleaseTitle>
+            <ReleaseType>Album</ReleaseType>
+        </Release>
+    </ReleaseList>
+</NewReleaseMessage>"#;
+
+fn spliced(splice: &[u8]) -> Vec<u8> {
+    let mut bytes = vec![0xEF, 0xBB, 0xBF];
+    bytes.extend_from_slice(HEADER.as_bytes());
+    bytes.extend_from_slice(splice);
+    bytes.extend_from_slice(BODY.as_bytes());
+    bytes
+}
+
+#[test]
+fn test_bom_after_header_is_reported_where_it_begins() {
+    let result = DDEXParser::new().parse(Cursor::new(spliced(&[0xEF, 0xBB, 0xBF])));
+
+    match result {
+        Err(ParseError::EncodingCorruption { position }) => {
+            assert_eq!(position, 3 + HEADER.len());
+        }
+        other => panic!("Expected EncodingCorruption error, got: {:?}", other),
+    }
+}
+
+#[test]
+fn test_null_byte_is_reported_where_it_begins() {
+    let result = DDEXParser::new().parse(Cursor::new(spliced(b"\0\0")));
+
+    match result {
+        Err(ParseError::EncodingCorruption { position }) => {
+            assert_eq!(position, 3 + HEADER.len());
+        }
+        other => panic!("Expected EncodingCorruption error, got: {:?}", other),
+    }
+}
+
+#[test]
+fn test_leading_bom_is_not_corruption() {
+    let parsed = DDEXParser::new().parse(Cursor::new(spliced(b""))).unwrap();
+    assert_eq!(parsed.flat.message_id, "MSG_SPLICE_001");
+}
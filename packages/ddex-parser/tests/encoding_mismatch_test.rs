@@ -0,0 +1,84 @@
+// core/tests/encoding_mismatch_test.rs
+use ddex_parser::error::ParseError;
+use ddex_parser::parser::ParseOptions;
+use ddex_parser::DDEXParser;
+use std::io::Cursor;
+
+fn message(declaration: &str) -> String {
+    format!(
+        r#"{}
+<NewReleaseMessage xmlns="http://ddex.net/xml/ern/43" MessageSchemaVersionId="ern/43">
+    <MessageHeader>
+        <MessageId>MSG_ENC_001</MessageId>
+        <MessageSender>
+            <PartyId>PADPIDA_SENDER</PartyId>
+            <PartyName><FullName>Beyoncé Records</FullName></PartyName>
+        </MessageSender>
+        <MessageRecipient>
+            <PartyId>PADPIDA_RECIPIENT</PartyId>
+            <PartyName><FullName>Test DSP</FullName></PartyName>
+        </MessageRecipient>
+        <MessageCreatedDateTime>2024-01-01T00:00:00Z</MessageCreatedDateTime>
+    </MessageHeader>
+    <ResourceList>
+        <SoundRecording>
+            <ResourceReference>A1</ResourceReference>
+            <SoundRecordingId><ISRC>USRC17607839</ISRC></SoundRecordingId>
+            <Title><TitleText>Café del Mar</TitleText></Title>
+            <Duration>PT3M30S</Duration>
+        </SoundRecording>
+    </ResourceList>
+    <ReleaseList>
+        <Release>
+            <ReleaseReference>R1</ReleaseReference>
+            <ReleaseId><ICPN>123456789012</ICPN></ReleaseId>
+            <ReleaseTitle><TitleText>Crème Brûlée</TitleText></ReleaseTitle>
+            <ReleaseType>Album</ReleaseType>
+        </Release>
+    </ReleaseList>
+</NewReleaseMessage>"#,
+        declaration
+    )
+}
+
+#[test]
+fn test_latin1_declaration_with_utf8_content_warns() {
+    let xml = message(r#"<?xml version="1.0" encoding="ISO-8859-1"?>"#);
+    let mut parser = DDEXParser::new();
+    let result = parser.parse(Cursor::new(xml.as_bytes())).unwrap();
+
+    let warning = result
+        .warnings
+        .iter()
+        .find(|w| w.code == "ENCODING_MISMATCH")
+        .expect("expected an encoding mismatch warning");
+    assert!(warning.message.contains("ISO-8859-1"));
+    assert!(warning.message.contains("UTF-8"));
+}
+
+#[test]
+fn test_latin1_declaration_with_utf8_content_errors_in_strict_mode() {
+    let xml = message(r#"<?xml version="1.0" encoding="ISO-8859-1"?>"#);
+    let mut parser = DDEXParser::new();
+    let options = ParseOptions {
+        strict: true,
+        ..Default::default()
+    };
+
+    match parser.parse_with_options(Cursor::new(xml.as_bytes()), options) {
+        Err(ParseError::EncodingMismatch { declared, detected }) => {
+            assert_eq!(declared, "ISO-8859-1");
+            assert_eq!(detected, "UTF-8");
+        }
+        other => panic!("Expected EncodingMismatch error, got: {:?}", other),
+    }
+}
+
+#[test]
+fn test_matching_declaration_has_no_warning() {
+    let xml = message(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+    let mut parser = DDEXParser::new();
+    let result = parser.parse(Cursor::new(xml.as_bytes())).unwrap();
+
+    assert!(result.warnings.is_empty());
+}
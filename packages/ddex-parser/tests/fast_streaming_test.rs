@@ -109,3 +109,44 @@ fn test_fast_streaming_performance() {
 
     println!("\n=== END OF PERFORMANCE TEST ===\n");
 }
+
+#[test]
+fn test_fast_scan_matches_whole_element_names() {
+    use ddex_parser::streaming::{create_fast_parser, FastElementType};
+
+    let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<NewReleaseMessage xmlns="http://ddex.net/xml/ern/43">
+    <MessageHeader><MessageId>MSG123</MessageId></MessageHeader>
+    <PartyList>
+        <Party><PartyReference>P1</PartyReference><PartyName>Label</PartyName></Party>
+    </PartyList>
+    <ResourceList>
+        <SoundRecording><ResourceReference>A1</ResourceReference></SoundRecording>
+        <Video><ResourceReference>A2</ResourceReference></Video>
+        <Image><ResourceReference>A3</ResourceReference></Image>
+        <Text><ResourceReference>A4</ResourceReference></Text>
+    </ResourceList>
+    <ReleaseList>
+        <Release><ReleaseId>R1</ReleaseId><ReleaseReference>R1</ReleaseReference></Release>
+        <TrackRelease><ReleaseReference>R2</ReleaseReference></TrackRelease>
+    </ReleaseList>
+    <DealList>
+        <ReleaseDeal><DealReleaseReference>R1</DealReleaseReference><Deal/></ReleaseDeal>
+    </DealList>
+</NewReleaseMessage>"#;
+
+    let mut parser = create_fast_parser();
+    let elements = parser
+        .parse_streaming(&mut Cursor::new(xml.as_bytes()), None)
+        .unwrap();
+
+    // List wrappers and child elements that share a prefix are not entities
+    assert_eq!(elements.filter_by_type(FastElementType::Release).len(), 2);
+    assert_eq!(elements.filter_by_type(FastElementType::Resource).len(), 4);
+    assert_eq!(elements.filter_by_type(FastElementType::Party).len(), 1);
+    assert_eq!(elements.filter_by_type(FastElementType::MessageHeader).len(), 1);
+    assert!(elements
+        .filter_by_type(FastElementType::Release)
+        .iter()
+        .all(|e| !e.raw_content.starts_with(b"<ReleaseList")));
+}
@@ -0,0 +1,94 @@
+// core/tests/graph_parties_test.rs
+use ddex_core::models::graph::PartyRole;
+use ddex_parser::DDEXParser;
+use std::io::Cursor;
+
+const XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/43" MessageSchemaVersionId="ern/43">
+    <MessageHeader>
+        <MessageId>MSG_GRAPH_001</MessageId>
+        <MessageSender>
+            <PartyId>PADPIDA_SENDER</PartyId>
+            <PartyName><FullName>Test Label</FullName></PartyName>
+        </MessageSender>
+        <MessageRecipient>
+            <PartyId>PADPIDA_RECIPIENT</PartyId>
+            <PartyName><FullName>Test DSP</FullName></PartyName>
+        </MessageRecipient>
+        <MessageCreatedDateTime>2024-01-01T00:00:00Z</MessageCreatedDateTime>
+    </MessageHeader>
+    <PartyList>
+        <Party>
+            <PartyReference>P1</PartyReference>
+            <PartyId><ISNI>0000000123456789</ISNI></PartyId>
+            <PartyName><FullName>Self Released Artist</FullName></PartyName>
+        </Party>
+        <Party>
+            <PartyReference>P2</PartyReference>
+            <PartyName><FullName>Featured Artist</FullName></PartyName>
+        </Party>
+    </PartyList>
+    <ResourceList>
+        <SoundRecording>
+            <ResourceReference>A1</ResourceReference>
+            <SoundRecordingId><ISRC>USRC17607839</ISRC></SoundRecordingId>
+            <Title><TitleText>Track One</TitleText></Title>
+            <Duration>PT3M30S</Duration>
+            <ResourceRightsController>
+                <RightsControllerPartyReference>P1</RightsControllerPartyReference>
+                <RightsControlType>RightsController</RightsControlType>
+            </ResourceRightsController>
+        </SoundRecording>
+    </ResourceList>
+    <ReleaseList>
+        <Release>
+            <ReleaseReference>R1</ReleaseReference>
+            <ReleaseId><ICPN>123456789012</ICPN></ReleaseId>
+            <ReleaseTitle><TitleText>Graph Album</TitleText></ReleaseTitle>
+            <ReleaseType>Album</ReleaseType>
+            <DisplayArtist>
+                <ArtistPartyReference>P1</ArtistPartyReference>
+                <DisplayArtistRole>MainArtist</DisplayArtistRole>
+            </DisplayArtist>
+            <DisplayArtist>
+                <ArtistPartyReference>P2</ArtistPartyReference>
+                <DisplayArtistRole>FeaturedArtist</DisplayArtistRole>
+            </DisplayArtist>
+            <ReleaseResourceReferenceList>
+                <ReleaseResourceReference>A1</ReleaseResourceReference>
+            </ReleaseResourceReferenceList>
+        </Release>
+    </ReleaseList>
+</ern:NewReleaseMessage>"#;
+
+#[test]
+fn test_graph_json_lists_party_once_with_every_role() {
+    let result = DDEXParser::new().parse(Cursor::new(XML.as_bytes())).unwrap();
+    let graph = serde_json::to_value(&result.graph).unwrap();
+
+    let parties = graph["parties"].as_array().unwrap();
+    let p1: Vec<_> = parties
+        .iter()
+        .filter(|p| p["party_reference"] == "P1")
+        .collect();
+    assert_eq!(p1.len(), 1);
+    assert_eq!(p1[0]["party_role"], serde_json::json!(["Artist", "RightsController"]));
+    assert_eq!(p1[0]["isni"], "0000000123456789");
+    assert_eq!(p1[0]["party_name"][0]["text"], "Self Released Artist");
+
+    let p2 = parties.iter().find(|p| p["party_reference"] == "P2").unwrap();
+    assert_eq!(p2["party_role"], serde_json::json!(["Artist"]));
+}
+
+#[test]
+fn test_graph_keeps_relationship_references() {
+    let result = DDEXParser::new().parse(Cursor::new(XML.as_bytes())).unwrap();
+    let graph = &result.graph;
+
+    let artists = &graph.releases[0].display_artist;
+    assert_eq!(artists.len(), 2);
+    assert_eq!(artists[0].party_reference.as_deref(), Some("P1"));
+    assert_eq!(artists[1].artist_role, ["FeaturedArtist"]);
+    assert_eq!(graph.resources[0].rights_controller, ["P1"]);
+    assert!(graph.parties[0].party_role.contains(&PartyRole::RightsController));
+}
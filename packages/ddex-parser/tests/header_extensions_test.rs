@@ -0,0 +1,62 @@
+// core/tests/header_extensions_test.rs
+use ddex_parser::DDEXParser;
+use std::io::Cursor;
+
+const ROUTED_HEADER: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/43" xmlns:acme="http://acme.example/ddex" MessageSchemaVersionId="ern/43">
+    <MessageHeader>
+        <MessageId>MSG_HEADER_EXT_001</MessageId>
+        <MessageSender>
+            <PartyId>PADPIDA_SENDER</PartyId>
+            <PartyName><FullName>Test Label</FullName></PartyName>
+        </MessageSender>
+        <MessageRecipient>
+            <PartyId>PADPIDA_RECIPIENT</PartyId>
+            <PartyName><FullName>Test DSP</FullName></PartyName>
+        </MessageRecipient>
+        <MessageCreatedDateTime>2024-01-01T00:00:00Z</MessageCreatedDateTime>
+        <AcmeRoutingKey>eu-west/priority</AcmeRoutingKey>
+        <acme:Batch><acme:Id>B-42</acme:Id></acme:Batch>
+    </MessageHeader>
+    <ResourceList>
+        <SoundRecording>
+            <ResourceReference>A1</ResourceReference>
+            <SoundRecordingId><ISRC>USRC17607839</ISRC></SoundRecordingId>
+            <Title><TitleText>Track One</TitleText></Title>
+            <Duration>PT3M30S</Duration>
+        </SoundRecording>
+    </ResourceList>
+    <ReleaseList>
+        <Release>
+            <ReleaseReference>R1</ReleaseReference>
+            <ReleaseTitle><TitleText>Routed</TitleText></ReleaseTitle>
+            <ReleaseType>Album</ReleaseType>
+            <AcmeShelfCode>X1</AcmeShelfCode>
+            <ReleaseResourceReferenceList>
+                <ReleaseResourceReference>A1</ReleaseResourceReference>
+            </ReleaseResourceReferenceList>
+        </Release>
+    </ReleaseList>
+</ern:NewReleaseMessage>"#;
+
+#[test]
+fn test_custom_header_children_are_kept() {
+    let parsed = DDEXParser::new()
+        .parse(Cursor::new(ROUTED_HEADER.as_bytes()))
+        .unwrap();
+
+    assert_eq!(
+        parsed
+            .header_extensions
+            .get("AcmeRoutingKey")
+            .map(String::as_str),
+        Some("eu-west/priority")
+    );
+    assert_eq!(
+        parsed.header_extensions.get("Batch").map(String::as_str),
+        Some("<acme:Id>B-42</acme:Id>")
+    );
+    // Standard header fields and release-level extensions stay out
+    assert_eq!(parsed.header_extensions.len(), 2);
+    assert_eq!(parsed.flat.message_id, "MSG_HEADER_EXT_001");
+}
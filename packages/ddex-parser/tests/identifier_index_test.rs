@@ -0,0 +1,92 @@
+// core/tests/identifier_index_test.rs
+use ddex_core::models::common::IdentifierType;
+use ddex_parser::DDEXParser;
+use std::io::Cursor;
+
+const XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/43" MessageSchemaVersionId="ern/43">
+    <MessageHeader>
+        <MessageId>MSG_INDEX_001</MessageId>
+        <MessageSender>
+            <PartyId>PADPIDA_SENDER</PartyId>
+            <PartyName><FullName>Test Label</FullName></PartyName>
+        </MessageSender>
+        <MessageRecipient>
+            <PartyId>PADPIDA_RECIPIENT</PartyId>
+            <PartyName><FullName>Test DSP</FullName></PartyName>
+        </MessageRecipient>
+        <MessageCreatedDateTime>2024-01-01T00:00:00Z</MessageCreatedDateTime>
+    </MessageHeader>
+    <ResourceList>
+        <SoundRecording>
+            <ResourceReference>A1</ResourceReference>
+            <SoundRecordingId><ISRC>USRC17607839</ISRC></SoundRecordingId>
+            <Title><TitleText>Track One</TitleText></Title>
+            <Duration>PT3M30S</Duration>
+        </SoundRecording>
+    </ResourceList>
+    <ReleaseList>
+        <Release>
+            <ReleaseReference>R0</ReleaseReference>
+            <ReleaseId><ICPN>0602445123456</ICPN></ReleaseId>
+            <ReleaseTitle><TitleText>Other Album</TitleText></ReleaseTitle>
+            <ReleaseType>Album</ReleaseType>
+        </Release>
+        <Release>
+            <ReleaseReference>R1</ReleaseReference>
+            <ReleaseId>
+                <GRid>A1-2425G-ABC1234002-M</GRid>
+                <ICPN>123456789012</ICPN>
+            </ReleaseId>
+            <ReleaseTitle><TitleText>Indexed Album</TitleText></ReleaseTitle>
+            <ReleaseType>Album</ReleaseType>
+            <ReleaseResourceReferenceList>
+                <ReleaseResourceReference>A1</ReleaseResourceReference>
+            </ReleaseResourceReferenceList>
+        </Release>
+    </ReleaseList>
+</ern:NewReleaseMessage>"#;
+
+#[test]
+fn test_find_release_by_upc_and_grid() {
+    let mut parser = DDEXParser::new();
+    let result = parser.parse(Cursor::new(XML.as_bytes())).unwrap();
+
+    let by_upc = result
+        .find_release_by_identifier(IdentifierType::UPC, "123456789012")
+        .expect("release should be found by UPC");
+    let by_grid = result
+        .find_release_by_identifier(IdentifierType::GRid, "A1-2425G-ABC1234002-M")
+        .expect("release should be found by GRid");
+
+    assert_eq!(by_upc.release_id, "R1");
+    assert_eq!(by_grid.release_id, by_upc.release_id);
+    assert_eq!(by_grid.default_title, "Indexed Album");
+}
+
+#[test]
+fn test_identifier_lookup_normalization() {
+    let mut parser = DDEXParser::new();
+    let result = parser.parse(Cursor::new(XML.as_bytes())).unwrap();
+
+    // A 12-digit UPC and its 13-digit EAN form are the same ICPN
+    let by_ean = result
+        .find_release_by_identifier(IdentifierType::EAN, "0123456789012")
+        .expect("release should be found by EAN form of UPC");
+    assert_eq!(by_ean.release_id, "R1");
+
+    // GRid lookups ignore hyphens and case
+    let by_grid = result
+        .find_release_by_identifier(IdentifierType::GRID, "a12425gabc1234002m")
+        .expect("release should be found by compact GRid");
+    assert_eq!(by_grid.release_id, "R1");
+
+    let resource = result
+        .find_resource_by_identifier(IdentifierType::ISRC, "US-RC1-76-07839")
+        .expect("resource should be found by ISRC");
+    assert_eq!(resource.resource_id, "A1");
+
+    assert!(result
+        .find_release_by_identifier(IdentifierType::UPC, "999999999999")
+        .is_none());
+}
@@ -0,0 +1,108 @@
+// core/tests/include_raw_test.rs
+use ddex_parser::parser::ParseOptions;
+use ddex_parser::DDEXParser;
+use std::io::Cursor;
+
+const HEADER: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/43" MessageSchemaVersionId="ern/43">
+    <MessageHeader>
+        <MessageId>MSG_RAW_001</MessageId>
+        <MessageSender>
+            <PartyId>PADPIDA_SENDER</PartyId>
+            <PartyName><FullName>Test Label</FullName></PartyName>
+        </MessageSender>
+        <MessageRecipient>
+            <PartyId>PADPIDA_RECIPIENT</PartyId>
+            <PartyName><FullName>Test DSP</FullName></PartyName>
+        </MessageRecipient>
+        <MessageCreatedDateTime>2024-01-01T00:00:00Z</MessageCreatedDateTime>
+    </MessageHeader>"#;
+
+const BODY: &str = r#"
+    <ResourceList>
+        <SoundRecording>
+            <ResourceReference>A1</ResourceReference>
+            <SoundRecordingId><ISRC>USRC17607839</ISRC></SoundRecordingId>
+            <Title><TitleText>Track &amp; One</TitleText></Title>
+            <Duration>PT3M30S</Duration>
+        </SoundRecording>
+    </ResourceList>
+    <ReleaseList>
+        <Release>
+            <ReleaseReference>R1</ReleaseReference>
+            <ReleaseId><ICPN>123456789012</ICPN></ReleaseId>
+            <ReleaseTitle><TitleText>Raw Album</TitleText></ReleaseTitle>
+            <ReleaseType>Album</ReleaseType>
+            <MarketingComment LanguageAndScriptCode="en">Kept verbatim.</MarketingComment>
+            <ReleaseResourceReferenceList>
+                <ReleaseResourceReference>A1</ReleaseResourceReference>
+            </ReleaseResourceReferenceList>
+        </Release>
+        <Release>
+            <ReleaseReference>R2</ReleaseReference>
+            <ReleaseId><ICPN>123456789029</ICPN></ReleaseId>
+            <ReleaseTitle><TitleText>Other Album</TitleText></ReleaseTitle>
+            <ReleaseType>Single</ReleaseType>
+        </Release>
+    </ReleaseList>
+</ern:NewReleaseMessage>"#;
+
+fn parse(xml: &str, include_raw: bool) -> ddex_core::models::flat::ParsedERNMessage {
+    let options = ParseOptions {
+        include_raw,
+        ..Default::default()
+    };
+    DDEXParser::new()
+        .parse_with_options(Cursor::new(xml.as_bytes()), options)
+        .unwrap()
+}
+
+#[test]
+fn test_raw_is_off_by_default() {
+    let result = parse(&format!("{}{}", HEADER, BODY), false);
+
+    assert!(result.flat.releases.iter().all(|r| r.raw.is_none()));
+    assert!(result.resources()["A1"].raw.is_none());
+}
+
+#[test]
+fn test_raw_slices_are_exact_source() {
+    let xml = format!("{}{}", HEADER, BODY);
+    let result = parse(&xml, true);
+
+    let release = result.flat.releases[0].raw.as_deref().unwrap();
+    assert!(release.starts_with("<Release>\n            <ReleaseReference>R1</ReleaseReference>"));
+    assert!(release.ends_with("</Release>"));
+    assert!(xml.contains(release));
+    assert!(!release.contains("R2"));
+
+    // Escapes are kept as written
+    let resource = result.resources()["A1"].raw.as_deref().unwrap();
+    assert!(resource.contains("Track &amp; One"));
+    assert!(resource.starts_with("<SoundRecording>") && resource.ends_with("</SoundRecording>"));
+}
+
+#[test]
+fn test_raw_release_parses_back_to_equivalent_release() {
+    let original = parse(&format!("{}{}", HEADER, BODY), true);
+    let release = &original.flat.releases[0];
+    let resource = &original.resources()["A1"];
+
+    // Re-emit just this release (and the resource it uses) untouched
+    let xml = format!(
+        "{}\n    <ResourceList>{}</ResourceList>\n    <ReleaseList>{}</ReleaseList>\n</ern:NewReleaseMessage>",
+        HEADER,
+        resource.raw.as_deref().unwrap(),
+        release.raw.as_deref().unwrap()
+    );
+    let reparsed = parse(&xml, true);
+
+    assert_eq!(reparsed.flat.releases.len(), 1);
+    let copy = &reparsed.flat.releases[0];
+    assert_eq!(copy.release_id, release.release_id);
+    assert_eq!(copy.default_title, release.default_title);
+    assert_eq!(copy.release_type, release.release_type);
+    assert_eq!(copy.track_count, release.track_count);
+    assert_eq!(copy.descriptions, release.descriptions);
+    assert_eq!(copy.raw, release.raw);
+}
@@ -0,0 +1,144 @@
+// core/tests/lazy_parse_test.rs
+use ddex_parser::error::ParseError;
+use ddex_parser::DDEXParser;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::io::Cursor;
+
+/// Tracks live and peak heap use of the current thread, so tests running
+/// alongside do not disturb the measurement
+struct PeakTracking;
+
+thread_local! {
+    static LIVE: Cell<usize> = const { Cell::new(0) };
+    static PEAK: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for PeakTracking {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = LIVE.try_with(|live| {
+            let now = live.get() + layout.size();
+            live.set(now);
+            let _ = PEAK.try_with(|peak| peak.set(peak.get().max(now)));
+        });
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        let _ = LIVE.try_with(|live| live.set(live.get().saturating_sub(layout.size())));
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: PeakTracking = PeakTracking;
+
+/// Heap used by `f` at its peak beyond what was live when it started
+fn peak_heap<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let start = LIVE.with(Cell::get);
+    PEAK.with(|peak| peak.set(start));
+    let result = f();
+    (result, PEAK.with(Cell::get) - start)
+}
+
+fn catalog(releases: usize) -> String {
+    let mut resources = String::new();
+    let mut release_list = String::new();
+
+    for i in 1..=releases {
+        resources.push_str(&format!(
+            r#"
+        <SoundRecording>
+            <ResourceReference>A{i}</ResourceReference>
+            <SoundRecordingId><ISRC>USRC1760{i:04}</ISRC></SoundRecordingId>
+            <Title><TitleText>Track {i}</TitleText></Title>
+            <DisplayArtist><PartyName><FullName>Artist {i}</FullName></PartyName></DisplayArtist>
+            <Duration>PT3M{s}S</Duration>
+        </SoundRecording>"#,
+            s = i % 60
+        ));
+        release_list.push_str(&format!(
+            r#"
+        <Release>
+            <ReleaseReference>R{i}</ReleaseReference>
+            <ReleaseId><ICPN>{i:012}</ICPN></ReleaseId>
+            <ReleaseTitle><TitleText>Album {i}</TitleText></ReleaseTitle>
+            <DisplayArtist><PartyName><FullName>Artist {i}</FullName></PartyName></DisplayArtist>
+            <ReleaseType>Album</ReleaseType>
+            <ReleaseResourceReferenceList>
+                <ReleaseResourceReference>A{i}</ReleaseResourceReference>
+            </ReleaseResourceReferenceList>
+        </Release>"#
+        ));
+    }
+
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/43" MessageSchemaVersionId="ern/43">
+    <MessageHeader>
+        <MessageId>MSG_PARSE_LAZY_001</MessageId>
+        <MessageSender>
+            <PartyId>PADPIDA_SENDER</PartyId>
+            <PartyName><FullName>Test Label</FullName></PartyName>
+        </MessageSender>
+        <MessageRecipient>
+            <PartyId>PADPIDA_RECIPIENT</PartyId>
+            <PartyName><FullName>Test DSP</FullName></PartyName>
+        </MessageRecipient>
+        <MessageCreatedDateTime>2024-01-01T00:00:00Z</MessageCreatedDateTime>
+    </MessageHeader>
+    <ResourceList>{resources}
+    </ResourceList>
+    <ReleaseList>{release_list}
+    </ReleaseList>
+</ern:NewReleaseMessage>"#
+    )
+}
+
+#[test]
+fn test_random_access_uses_far_less_memory_than_full_parse() {
+    let xml = catalog(1000);
+
+    let (full, full_peak) = peak_heap(|| DDEXParser::new().parse(Cursor::new(xml.as_bytes())).unwrap());
+    let expected = serde_json::to_value(&full.flat.releases[499]).unwrap();
+    drop(full);
+
+    let (release, lazy_peak) = peak_heap(|| {
+        let lazy = DDEXParser::new().parse_lazy(Cursor::new(xml.as_bytes())).unwrap();
+        assert_eq!(lazy.release_count(), 1000);
+        assert_eq!(lazy.resource_count(), 1000);
+        lazy.release(499).unwrap()
+    });
+
+    assert_eq!(release.release_id, "R500");
+    assert_eq!(release.default_title, "Album 500");
+    assert_eq!(release.tracks[0].title, "Track 500");
+    assert_eq!(serde_json::to_value(&release).unwrap(), expected);
+    // The lazy message holds a copy of the input, the full parse all models
+    assert!(
+        lazy_peak * 3 < full_peak,
+        "lazy peak {} bytes vs full parse peak {} bytes",
+        lazy_peak,
+        full_peak
+    );
+}
+
+#[test]
+fn test_releases_by_index_and_reference() {
+    let xml = catalog(5);
+    let lazy = DDEXParser::new().parse_lazy(Cursor::new(xml.as_bytes())).unwrap();
+
+    let references: Vec<&str> = lazy.release_references().collect();
+    assert_eq!(references, ["R1", "R2", "R3", "R4", "R5"]);
+    assert_eq!(lazy.release_by_reference("R4").unwrap().default_title, "Album 4");
+    assert_eq!(lazy.release(0).unwrap().tracks[0].title, "Track 1");
+
+    assert!(matches!(
+        lazy.release(5),
+        Err(ParseError::ReleaseNotFound { reference }) if reference == "#5"
+    ));
+    assert!(matches!(
+        lazy.release_by_reference("R404"),
+        Err(ParseError::ReleaseNotFound { reference }) if reference == "R404"
+    ));
+}
@@ -0,0 +1,110 @@
+// core/tests/max_releases_test.rs
+use ddex_parser::parser::ParseOptions;
+use ddex_parser::DDEXParser;
+use std::io::Cursor;
+
+fn catalog(releases: usize) -> String {
+    let mut resources = String::new();
+    let mut release_list = String::new();
+
+    for i in 1..=releases {
+        resources.push_str(&format!(
+            r#"
+        <SoundRecording>
+            <ResourceReference>A{i}</ResourceReference>
+            <SoundRecordingId><ISRC>USRC1760{i:04}</ISRC></SoundRecordingId>
+            <Title><TitleText>Track {i}</TitleText></Title>
+            <DisplayArtist><PartyName><FullName>Artist {i}</FullName></PartyName></DisplayArtist>
+            <Duration>PT3M{s}S</Duration>
+        </SoundRecording>"#,
+            s = i % 60
+        ));
+        release_list.push_str(&format!(
+            r#"
+        <Release>
+            <ReleaseReference>R{i}</ReleaseReference>
+            <ReleaseId><ICPN>{i:012}</ICPN></ReleaseId>
+            <ReleaseTitle><TitleText>Album {i}</TitleText></ReleaseTitle>
+            <DisplayArtist><PartyName><FullName>Artist {i}</FullName></PartyName></DisplayArtist>
+            <ReleaseType>Album</ReleaseType>
+            <ReleaseResourceReferenceList>
+                <ReleaseResourceReference>A{i}</ReleaseResourceReference>
+            </ReleaseResourceReferenceList>
+        </Release>"#
+        ));
+    }
+
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/43" MessageSchemaVersionId="ern/43">
+    <MessageHeader>
+        <MessageId>MSG_MAX_RELEASES_001</MessageId>
+        <MessageSender>
+            <PartyId>PADPIDA_SENDER</PartyId>
+            <PartyName><FullName>Test Label</FullName></PartyName>
+        </MessageSender>
+        <MessageRecipient>
+            <PartyId>PADPIDA_RECIPIENT</PartyId>
+            <PartyName><FullName>Test DSP</FullName></PartyName>
+        </MessageRecipient>
+        <MessageCreatedDateTime>2024-01-01T00:00:00Z</MessageCreatedDateTime>
+    </MessageHeader>
+    <ResourceList>{resources}
+    </ResourceList>
+    <ReleaseList>{release_list}
+    </ReleaseList>
+    <DealList>
+        <ReleaseDeal>
+            <DealReleaseReference>R1</DealReleaseReference>
+            <Deal>
+                <DealTerms>
+                    <TerritoryCode>Worldwide</TerritoryCode>
+                    <ValidityPeriod><StartDate>2024-01-01T00:00:00Z</StartDate></ValidityPeriod>
+                    <CommercialModelType>SubscriptionModel</CommercialModelType>
+                    <UseType>Stream</UseType>
+                </DealTerms>
+            </Deal>
+        </ReleaseDeal>
+    </DealList>
+</ern:NewReleaseMessage>"#
+    )
+}
+
+fn parse(xml: &str, max_releases: usize) -> ddex_core::models::flat::ParsedERNMessage {
+    let options = ParseOptions {
+        max_releases: Some(max_releases),
+        ..Default::default()
+    };
+    DDEXParser::new()
+        .parse_with_options(Cursor::new(xml.as_bytes()), options)
+        .unwrap()
+}
+
+#[test]
+fn test_max_releases_returns_a_truncated_preview() {
+    let xml = catalog(100);
+    let preview = parse(&xml, 5);
+
+    assert!(preview.truncated);
+    let references: Vec<&str> = preview
+        .releases()
+        .iter()
+        .map(|release| release.release_id.as_str())
+        .collect();
+    assert_eq!(references, ["R1", "R2", "R3", "R4", "R5"]);
+
+    // Each release still resolves the resource it refers to
+    let release = &preview.releases()[2];
+    assert_eq!(release.tracks.len(), 1);
+    assert_eq!(release.tracks[0].title, "Track 3");
+}
+
+#[test]
+fn test_max_releases_at_or_above_the_count_is_not_truncated() {
+    let xml = catalog(5);
+    let message = parse(&xml, 5);
+
+    assert!(!message.truncated);
+    assert_eq!(message.releases().len(), 5);
+    assert_eq!(message.deals().len(), 1);
+}
@@ -0,0 +1,56 @@
+// core/tests/message_types_test.rs
+use ddex_core::models::graph::MessageType;
+use ddex_parser::DDEXParser;
+use std::io::Cursor;
+
+#[test]
+fn test_update_release_message() {
+    let xml = include_str!("fixtures/update_release_message.xml");
+    let result = DDEXParser::new()
+        .parse(Cursor::new(xml.as_bytes()))
+        .unwrap();
+
+    assert_eq!(result.message_type(), &MessageType::UpdateReleaseMessage);
+    assert_eq!(result.flat.message_type, "UpdateReleaseMessage");
+    assert_eq!(result.releases().len(), 1);
+    assert_eq!(result.releases()[0].default_title, "Updated Album");
+    assert_eq!(result.resources().len(), 1);
+    assert_eq!(result.deals().len(), 1);
+    assert!(result.flat.purged_releases.is_empty());
+}
+
+#[test]
+fn test_purge_release_message() {
+    let xml = include_str!("fixtures/purge_release_message.xml");
+    let result = DDEXParser::new()
+        .parse(Cursor::new(xml.as_bytes()))
+        .unwrap();
+
+    assert_eq!(result.message_type(), &MessageType::PurgeReleaseMessage);
+    assert_eq!(result.flat.message_type, "PurgeReleaseMessage");
+    assert!(result.releases().is_empty());
+    assert!(result.resources().is_empty());
+
+    let purged = &result.flat.purged_releases;
+    assert_eq!(purged.len(), 2);
+    assert_eq!(purged[0].identifiers.upc.as_deref(), Some("123456789012"));
+    assert_eq!(purged[0].title.as_deref(), Some("Withdrawn Album"));
+    assert_eq!(purged[0].display_artist, "Test Artist");
+    assert_eq!(
+        purged[1].identifiers.grid.as_deref(),
+        Some("A1UCASE0000000401X")
+    );
+    assert_eq!(purged[1].title, None);
+}
+
+#[test]
+fn test_new_release_message_type_is_unchanged() {
+    let xml = include_str!("fixtures/update_release_message.xml")
+        .replace("UpdateReleaseMessage", "NewReleaseMessage");
+    let result = DDEXParser::new()
+        .parse(Cursor::new(xml.as_bytes()))
+        .unwrap();
+
+    assert_eq!(result.message_type(), &MessageType::NewReleaseMessage);
+    assert_eq!(result.flat.message_type, "NewReleaseMessage");
+}
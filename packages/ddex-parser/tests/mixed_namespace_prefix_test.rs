@@ -0,0 +1,164 @@
+// core/tests/mixed_namespace_prefix_test.rs
+use ddex_parser::DDEXParser;
+use std::io::Cursor;
+
+/// The same message with every ERN element unprefixed (default namespace)
+const DEFAULT_NS: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<NewReleaseMessage xmlns="http://ddex.net/xml/ern/43" MessageSchemaVersionId="ern/43">
+    <MessageHeader>
+        <MessageId>MSG_MIXED_001</MessageId>
+        <MessageSender>
+            <PartyId>PADPIDA_SENDER</PartyId>
+            <PartyName><FullName>Test Label</FullName></PartyName>
+        </MessageSender>
+        <MessageRecipient>
+            <PartyId>PADPIDA_RECIPIENT</PartyId>
+            <PartyName><FullName>Test DSP</FullName></PartyName>
+        </MessageRecipient>
+        <MessageCreatedDateTime>2024-01-01T00:00:00Z</MessageCreatedDateTime>
+    </MessageHeader>
+    <ResourceList>
+        <SoundRecording>
+            <ResourceReference>A1</ResourceReference>
+            <SoundRecordingId><ISRC>USRC17607839</ISRC></SoundRecordingId>
+            <Title><TitleText>Track One</TitleText></Title>
+            <Duration>PT3M30S</Duration>
+        </SoundRecording>
+        <SoundRecording>
+            <ResourceReference>A2</ResourceReference>
+            <SoundRecordingId><ISRC>USRC17607840</ISRC></SoundRecordingId>
+            <Title><TitleText>Track Two</TitleText></Title>
+            <Duration>PT4M00S</Duration>
+        </SoundRecording>
+    </ResourceList>
+    <ReleaseList>
+        <Release>
+            <ReleaseReference>R1</ReleaseReference>
+            <ReleaseId><ICPN>123456789012</ICPN></ReleaseId>
+            <ReleaseTitle><TitleText>First Album</TitleText></ReleaseTitle>
+            <ReleaseType>Album</ReleaseType>
+            <ReleaseResourceReferenceList>
+                <ReleaseResourceReference>A1</ReleaseResourceReference>
+            </ReleaseResourceReferenceList>
+        </Release>
+        <Release>
+            <ReleaseReference>R2</ReleaseReference>
+            <ReleaseId><ICPN>123456789029</ICPN></ReleaseId>
+            <ReleaseTitle><TitleText>Second Album</TitleText></ReleaseTitle>
+            <ReleaseType>Album</ReleaseType>
+            <ReleaseResourceReferenceList>
+                <ReleaseResourceReference>A2</ReleaseResourceReference>
+            </ReleaseResourceReferenceList>
+        </Release>
+    </ReleaseList>
+    <DealList>
+        <ReleaseDeal>
+            <DealReleaseReference>R1</DealReleaseReference>
+            <Deal><DealTerms><TerritoryCode>Worldwide</TerritoryCode></DealTerms></Deal>
+        </ReleaseDeal>
+    </DealList>
+</NewReleaseMessage>"#;
+
+/// Same content, but mixing `ern:`-prefixed and inherited default-namespace
+/// forms for the same element types
+const MIXED_NS: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/43" xmlns="http://ddex.net/xml/ern/43" MessageSchemaVersionId="ern/43">
+    <ern:MessageHeader>
+        <MessageId>MSG_MIXED_001</MessageId>
+        <ern:MessageSender>
+            <PartyId>PADPIDA_SENDER</PartyId>
+            <ern:PartyName><FullName>Test Label</FullName></ern:PartyName>
+        </ern:MessageSender>
+        <MessageRecipient>
+            <ern:PartyId>PADPIDA_RECIPIENT</ern:PartyId>
+            <PartyName><ern:FullName>Test DSP</ern:FullName></PartyName>
+        </MessageRecipient>
+        <ern:MessageCreatedDateTime>2024-01-01T00:00:00Z</ern:MessageCreatedDateTime>
+    </ern:MessageHeader>
+    <ResourceList>
+        <ern:SoundRecording>
+            <ern:ResourceReference>A1</ern:ResourceReference>
+            <SoundRecordingId><ern:ISRC>USRC17607839</ern:ISRC></SoundRecordingId>
+            <ern:Title><TitleText>Track One</TitleText></ern:Title>
+            <Duration>PT3M30S</Duration>
+        </ern:SoundRecording>
+        <SoundRecording>
+            <ResourceReference>A2</ResourceReference>
+            <ern:SoundRecordingId><ISRC>USRC17607840</ISRC></ern:SoundRecordingId>
+            <Title><ern:TitleText>Track Two</ern:TitleText></Title>
+            <ern:Duration>PT4M00S</ern:Duration>
+        </SoundRecording>
+    </ResourceList>
+    <ern:ReleaseList>
+        <ern:Release>
+            <ern:ReleaseReference>R1</ern:ReleaseReference>
+            <ReleaseId><ern:ICPN>123456789012</ern:ICPN></ReleaseId>
+            <ern:ReleaseTitle><ern:TitleText>First Album</ern:TitleText></ern:ReleaseTitle>
+            <ReleaseType>Album</ReleaseType>
+            <ern:ReleaseResourceReferenceList>
+                <ReleaseResourceReference>A1</ReleaseResourceReference>
+            </ern:ReleaseResourceReferenceList>
+        </ern:Release>
+        <Release>
+            <ReleaseReference>R2</ReleaseReference>
+            <ern:ReleaseId><ICPN>123456789029</ICPN></ern:ReleaseId>
+            <ReleaseTitle><TitleText>Second Album</TitleText></ReleaseTitle>
+            <ern:ReleaseType>Album</ern:ReleaseType>
+            <ReleaseResourceReferenceList>
+                <ern:ReleaseResourceReference>A2</ern:ReleaseResourceReference>
+            </ReleaseResourceReferenceList>
+        </Release>
+    </ern:ReleaseList>
+    <DealList>
+        <ern:ReleaseDeal>
+            <ern:DealReleaseReference>R1</ern:DealReleaseReference>
+            <Deal><ern:DealTerms><TerritoryCode>Worldwide</TerritoryCode></ern:DealTerms></Deal>
+        </ern:ReleaseDeal>
+    </DealList>
+</ern:NewReleaseMessage>"#;
+
+fn parse(xml: &str) -> ddex_core::models::flat::ParsedERNMessage {
+    let mut parser = DDEXParser::new();
+    parser.parse(Cursor::new(xml.as_bytes())).unwrap()
+}
+
+#[test]
+fn test_mixed_prefixes_parse_like_default_namespace() {
+    let plain = parse(DEFAULT_NS);
+    let mixed = parse(MIXED_NS);
+
+    assert_eq!(mixed.releases().len(), 2);
+    assert_eq!(mixed.resources().len(), 2);
+    assert_eq!(mixed.deals().len(), 1);
+
+    // Compare everything except the generated parse-time values
+    let json = |msg: &ddex_core::models::flat::ParsedERNMessage| {
+        serde_json::json!({
+            "releases": msg.releases(),
+            "resources": msg.resources(),
+            "deals": msg.deals(),
+            "sender": msg.flat.sender,
+            "recipient": msg.flat.recipient,
+            "message_id": msg.flat.message_id,
+        })
+    };
+    assert_eq!(json(&mixed), json(&plain));
+}
+
+#[test]
+fn test_prefixed_and_unprefixed_release_are_equivalent() {
+    let mixed = parse(MIXED_NS);
+    let (prefixed, unprefixed) = (&mixed.releases()[0], &mixed.releases()[1]);
+
+    assert_eq!(prefixed.release_id, "R1");
+    assert_eq!(unprefixed.release_id, "R2");
+    assert_eq!(prefixed.default_title, "First Album");
+    assert_eq!(unprefixed.default_title, "Second Album");
+    assert_eq!(prefixed.release_type, unprefixed.release_type);
+    assert_eq!(prefixed.identifiers.upc.as_deref(), Some("123456789012"));
+    assert_eq!(unprefixed.identifiers.upc.as_deref(), Some("123456789029"));
+    assert_eq!(prefixed.track_count, 1);
+    assert_eq!(unprefixed.track_count, 1);
+    assert_eq!(mixed.flat.sender.name, "Test Label");
+    assert_eq!(mixed.flat.recipient.id, "PADPIDA_RECIPIENT");
+}
@@ -0,0 +1,109 @@
+// core/tests/parse_all_test.rs
+use ddex_parser::DDEXParser;
+use std::io::Cursor;
+
+fn message(id: &str, sender: &str, release: &str, with_namespace: bool) -> String {
+    let namespace = if with_namespace {
+        r#" xmlns:ern="http://ddex.net/xml/ern/43""#
+    } else {
+        ""
+    };
+    format!(
+        r#"<ern:NewReleaseMessage{namespace} MessageSchemaVersionId="ern/43">
+    <MessageHeader>
+        <MessageId>{id}</MessageId>
+        <MessageSender>
+            <PartyId>PADPIDA_{sender}</PartyId>
+            <PartyName><FullName>{sender}</FullName></PartyName>
+        </MessageSender>
+        <MessageRecipient>
+            <PartyId>PADPIDA_RECIPIENT</PartyId>
+            <PartyName><FullName>Test DSP</FullName></PartyName>
+        </MessageRecipient>
+        <MessageCreatedDateTime>2024-01-01T00:00:00Z</MessageCreatedDateTime>
+    </MessageHeader>
+    <ResourceList>
+        <SoundRecording>
+            <ResourceReference>A1</ResourceReference>
+            <SoundRecordingId><ISRC>USRC17607839</ISRC></SoundRecordingId>
+            <Title><TitleText>Track One</TitleText></Title>
+        </SoundRecording>
+    </ResourceList>
+    <ReleaseList>
+        <Release>
+            <ReleaseReference>R1</ReleaseReference>
+            <ReleaseTitle><TitleText>{release}</TitleText></ReleaseTitle>
+            <ReleaseType>Album</ReleaseType>
+            <ReleaseResourceReferenceList>
+                <ReleaseResourceReference>A1</ReleaseResourceReference>
+            </ReleaseResourceReferenceList>
+        </Release>
+    </ReleaseList>
+</ern:NewReleaseMessage>"#
+    )
+}
+
+fn headers_and_titles(xml: &str) -> Vec<(String, String, String)> {
+    DDEXParser::new()
+        .parse_all(Cursor::new(xml.as_bytes()))
+        .unwrap()
+        .into_iter()
+        .map(|parsed| {
+            (
+                parsed.flat.message_id,
+                parsed.flat.sender.name,
+                parsed.flat.releases[0].default_title.clone(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_concatenated_messages_are_parsed_independently() {
+    let xml = format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n{}\n<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n{}\n",
+        message("MSG_A", "LabelA", "First Album", true),
+        message("MSG_B", "LabelB", "Second Album", true)
+    );
+
+    assert_eq!(
+        headers_and_titles(&xml),
+        [
+            ("MSG_A".to_string(), "LabelA".to_string(), "First Album".to_string()),
+            ("MSG_B".to_string(), "LabelB".to_string(), "Second Album".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_wrapped_messages_inherit_wrapper_namespaces() {
+    let xml = format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<DeliveryBatch xmlns:ern="http://ddex.net/xml/ern/43">
+    <BatchId>BATCH_1</BatchId>
+    {}
+    {}
+</DeliveryBatch>"#,
+        message("MSG_A", "LabelA", "First Album", false),
+        message("MSG_B", "LabelB", "Second Album", false)
+    );
+
+    let parsed = headers_and_titles(&xml);
+    assert_eq!(parsed.len(), 2);
+    assert_eq!(parsed[0].0, "MSG_A");
+    assert_eq!(parsed[1].2, "Second Album");
+}
+
+#[test]
+fn test_single_message_matches_parse() {
+    let xml = message("MSG_A", "LabelA", "First Album", true);
+
+    let all = DDEXParser::new().parse_all(Cursor::new(xml.as_bytes())).unwrap();
+    let single = DDEXParser::new().parse(Cursor::new(xml.as_bytes())).unwrap();
+
+    assert_eq!(all.len(), 1);
+    assert_eq!(
+        serde_json::to_value(&all[0].flat.releases).unwrap(),
+        serde_json::to_value(&single.flat.releases).unwrap()
+    );
+}
@@ -0,0 +1,93 @@
+// core/tests/parse_partial_test.rs
+use ddex_parser::error::ParseError;
+use ddex_parser::DDEXParser;
+use std::io::Cursor;
+
+fn release(reference: &str, release_type: &str) -> String {
+    format!(
+        r#"
+        <Release>
+            <ReleaseReference>{reference}</ReleaseReference>
+            <ReleaseId><ICPN>12345678901{n}</ICPN></ReleaseId>
+            <ReleaseTitle><TitleText>Album {reference}</TitleText></ReleaseTitle>
+            {release_type}
+        </Release>"#,
+        n = &reference[1..],
+    )
+}
+
+fn message(releases: &[String]) -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/43" MessageSchemaVersionId="ern/43">
+    <MessageHeader>
+        <MessageId>MSG_PARTIAL_001</MessageId>
+        <MessageSender>
+            <PartyId>PADPIDA_SENDER</PartyId>
+            <PartyName><FullName>Test Label</FullName></PartyName>
+        </MessageSender>
+        <MessageRecipient>
+            <PartyId>PADPIDA_RECIPIENT</PartyId>
+            <PartyName><FullName>Test DSP</FullName></PartyName>
+        </MessageRecipient>
+        <MessageCreatedDateTime>2024-01-01T00:00:00Z</MessageCreatedDateTime>
+    </MessageHeader>
+    <ReleaseList>{}
+    </ReleaseList>
+</ern:NewReleaseMessage>"#,
+        releases.concat()
+    )
+}
+
+#[test]
+fn test_broken_release_is_dropped_and_reported() {
+    let xml = message(&[
+        release("R1", "<ReleaseType>Album</ReleaseType>"),
+        release("R2", "<ReleaseType>Album</ReleaseTyp>"),
+        release("R3", "<ReleaseType>Single</ReleaseType>"),
+    ]);
+
+    let mut parser = DDEXParser::new();
+    assert!(parser.parse(Cursor::new(xml.as_bytes())).is_err());
+
+    let partial = parser.parse_partial(Cursor::new(xml.as_bytes()), Default::default());
+
+    let message = partial.message.expect("the other releases should be recovered");
+    let references: Vec<&str> = message
+        .flat
+        .releases
+        .iter()
+        .map(|r| r.release_id.as_str())
+        .collect();
+    assert_eq!(references, ["R1", "R3"]);
+    assert_eq!(message.flat.message_id, "MSG_PARTIAL_001");
+
+    assert_eq!(partial.errors.len(), 1);
+    assert_eq!(partial.errors[0].release_reference.as_deref(), Some("R2"));
+    assert!(matches!(partial.errors[0].error, ParseError::XmlError(_)));
+}
+
+#[test]
+fn test_valid_document_has_no_errors() {
+    let xml = message(&[
+        release("R1", "<ReleaseType>Album</ReleaseType>"),
+        release("R2", "<ReleaseType>Single</ReleaseType>"),
+    ]);
+
+    let partial = DDEXParser::new().parse_partial(Cursor::new(xml.as_bytes()), Default::default());
+
+    assert!(partial.errors.is_empty());
+    assert_eq!(partial.message.unwrap().flat.releases.len(), 2);
+}
+
+#[test]
+fn test_broken_header_recovers_nothing() {
+    let xml = message(&[release("R1", "<ReleaseType>Album</ReleaseType>")])
+        .replace("</MessageId>", "</MessageID>");
+
+    let partial = DDEXParser::new().parse_partial(Cursor::new(xml.as_bytes()), Default::default());
+
+    assert!(partial.message.is_none());
+    assert_eq!(partial.errors.len(), 1);
+    assert!(partial.errors[0].release_reference.is_none());
+}
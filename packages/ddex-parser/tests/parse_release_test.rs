@@ -0,0 +1,95 @@
+// core/tests/parse_release_test.rs
+use ddex_parser::error::ParseError;
+use ddex_parser::DDEXParser;
+use std::io::Cursor;
+
+fn catalog(releases: usize) -> String {
+    let mut resources = String::new();
+    let mut release_list = String::new();
+
+    for i in 1..=releases {
+        resources.push_str(&format!(
+            r#"
+        <SoundRecording>
+            <ResourceReference>A{i}</ResourceReference>
+            <SoundRecordingId><ISRC>USRC1760{i:04}</ISRC></SoundRecordingId>
+            <Title><TitleText>Track {i}</TitleText></Title>
+            <DisplayArtist><PartyName><FullName>Artist {i}</FullName></PartyName></DisplayArtist>
+            <Duration>PT3M{s}S</Duration>
+        </SoundRecording>"#,
+            s = i % 60
+        ));
+        release_list.push_str(&format!(
+            r#"
+        <Release>
+            <ReleaseReference>R{i}</ReleaseReference>
+            <ReleaseId><ICPN>{i:012}</ICPN></ReleaseId>
+            <ReleaseTitle><TitleText>Album {i}</TitleText></ReleaseTitle>
+            <DisplayArtist><PartyName><FullName>Artist {i}</FullName></PartyName></DisplayArtist>
+            <ReleaseType>Album</ReleaseType>
+            <ReleaseResourceReferenceList>
+                <ReleaseResourceReference>A{i}</ReleaseResourceReference>
+            </ReleaseResourceReferenceList>
+        </Release>"#
+        ));
+    }
+
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/43" MessageSchemaVersionId="ern/43">
+    <MessageHeader>
+        <MessageId>MSG_PARSE_RELEASE_001</MessageId>
+        <MessageSender>
+            <PartyId>PADPIDA_SENDER</PartyId>
+            <PartyName><FullName>Test Label</FullName></PartyName>
+        </MessageSender>
+        <MessageRecipient>
+            <PartyId>PADPIDA_RECIPIENT</PartyId>
+            <PartyName><FullName>Test DSP</FullName></PartyName>
+        </MessageRecipient>
+        <MessageCreatedDateTime>2024-01-01T00:00:00Z</MessageCreatedDateTime>
+    </MessageHeader>
+    <ResourceList>{resources}
+    </ResourceList>
+    <ReleaseList>{release_list}
+    </ReleaseList>
+</ern:NewReleaseMessage>"#
+    )
+}
+
+#[test]
+fn test_single_release_matches_full_parse() {
+    let xml = catalog(100);
+
+    let full = DDEXParser::new().parse(Cursor::new(xml.as_bytes())).unwrap();
+    let expected = full
+        .flat
+        .releases
+        .iter()
+        .find(|release| release.release_id == "R87")
+        .unwrap();
+
+    let release = DDEXParser::new()
+        .parse_release(Cursor::new(xml.as_bytes()), "R87")
+        .unwrap();
+
+    assert_eq!(release.default_title, "Album 87");
+    assert_eq!(release.tracks.len(), 1);
+    assert_eq!(release.tracks[0].title, "Track 87");
+    assert_eq!(
+        serde_json::to_value(&release).unwrap(),
+        serde_json::to_value(expected).unwrap()
+    );
+}
+
+#[test]
+fn test_unknown_reference_is_an_error() {
+    let xml = catalog(3);
+
+    let result = DDEXParser::new().parse_release(Cursor::new(xml.as_bytes()), "R404");
+
+    assert!(matches!(
+        result,
+        Err(ParseError::ReleaseNotFound { reference }) if reference == "R404"
+    ));
+}
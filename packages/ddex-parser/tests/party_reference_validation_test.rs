@@ -0,0 +1,94 @@
+// core/tests/party_reference_validation_test.rs
+use ddex_parser::error::ParseError;
+use ddex_parser::parser::ParseOptions;
+use ddex_parser::DDEXParser;
+use std::io::Cursor;
+
+fn message(rights_controller: &str) -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/43" MessageSchemaVersionId="ern/43">
+    <MessageHeader>
+        <MessageId>MSG_PARTY_REFS_001</MessageId>
+        <MessageSender>
+            <PartyId>PADPIDA_SENDER</PartyId>
+            <PartyName><FullName>Test Label</FullName></PartyName>
+        </MessageSender>
+        <MessageRecipient>
+            <PartyId>PADPIDA_RECIPIENT</PartyId>
+            <PartyName><FullName>Test DSP</FullName></PartyName>
+        </MessageRecipient>
+        <MessageCreatedDateTime>2024-01-01T00:00:00Z</MessageCreatedDateTime>
+    </MessageHeader>
+    <PartyList>
+        <Party>
+            <PartyReference>PArtist</PartyReference>
+            <PartyName><FullName>The Artist</FullName></PartyName>
+        </Party>
+        <Party>
+            <PartyReference>PLabel</PartyReference>
+            <PartyName><FullName>The Label</FullName></PartyName>
+        </Party>
+    </PartyList>
+    <ReleaseList>
+        <Release>
+            <ReleaseReference>R1</ReleaseReference>
+            <ReleaseId><ICPN>123456789012</ICPN></ReleaseId>
+            <ReleaseTitle><TitleText>Album</TitleText></ReleaseTitle>
+            <DisplayArtist><ArtistPartyReference>PArtist</ArtistPartyReference></DisplayArtist>
+            <ReleaseType>Album</ReleaseType>
+        </Release>
+    </ReleaseList>
+    <DealList>
+        <ReleaseDeal>
+            <DealReleaseReference>R1</DealReleaseReference>
+            <Deal>
+                <DealReference>D1</DealReference>
+                <DealTerms>
+                    <RightsController>
+                        <RightsControllerPartyReference>{rights_controller}</RightsControllerPartyReference>
+                    </RightsController>
+                    <TerritoryCode>Worldwide</TerritoryCode>
+                </DealTerms>
+            </Deal>
+        </ReleaseDeal>
+    </DealList>
+</ern:NewReleaseMessage>"#
+    )
+}
+
+fn parse(xml: &str) -> Result<ddex_core::models::flat::ParsedERNMessage, ParseError> {
+    let options = ParseOptions {
+        validate_party_references: true,
+        ..Default::default()
+    };
+    DDEXParser::new().parse_with_options(Cursor::new(xml.as_bytes()), options)
+}
+
+#[test]
+fn test_undefined_rights_controller_is_a_dangling_reference() {
+    match parse(&message("PMissing")) {
+        Err(ParseError::UnresolvedPartyReferences(unresolved)) => {
+            assert_eq!(unresolved.len(), 1);
+            assert_eq!(unresolved[0].element, "RightsControllerPartyReference");
+            assert_eq!(unresolved[0].reference, "PMissing");
+            assert!(unresolved[0].location.contains("DealList/ReleaseDeal/Deal/DealTerms"));
+        }
+        other => panic!("expected UnresolvedPartyReferences, got {:?}", other.map(|_| ())),
+    }
+}
+
+#[test]
+fn test_defined_references_pass() {
+    let result = parse(&message("PLabel")).unwrap();
+
+    assert!(result.flat.parties.contains_key("PArtist"));
+    assert!(result.flat.parties.contains_key("PLabel"));
+}
+
+#[test]
+fn test_check_is_off_by_default() {
+    let xml = message("PMissing");
+
+    assert!(DDEXParser::new().parse(Cursor::new(xml.as_bytes())).is_ok());
+}
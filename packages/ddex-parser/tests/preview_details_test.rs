@@ -0,0 +1,94 @@
+// core/tests/preview_details_test.rs
+use ddex_parser::DDEXParser;
+use std::io::Cursor;
+use std::time::Duration;
+
+fn message(preview: &str) -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/43" MessageSchemaVersionId="ern/43">
+    <MessageHeader>
+        <MessageId>MSG_PREVIEW_001</MessageId>
+        <MessageSender>
+            <PartyId>PADPIDA_SENDER</PartyId>
+            <PartyName><FullName>Test Label</FullName></PartyName>
+        </MessageSender>
+        <MessageRecipient>
+            <PartyId>PADPIDA_RECIPIENT</PartyId>
+            <PartyName><FullName>Test DSP</FullName></PartyName>
+        </MessageRecipient>
+        <MessageCreatedDateTime>2024-01-01T00:00:00Z</MessageCreatedDateTime>
+    </MessageHeader>
+    <ResourceList>
+        <SoundRecording>
+            <ResourceReference>A1</ResourceReference>
+            <SoundRecordingId><ISRC>USRC17607839</ISRC></SoundRecordingId>
+            <Title><TitleText>Track One</TitleText></Title>
+            <Duration>PT3M30S</Duration>
+            <TechnicalDetails>
+                <TechnicalResourceDetailsReference>T1</TechnicalResourceDetailsReference>
+                {preview}
+            </TechnicalDetails>
+        </SoundRecording>
+    </ResourceList>
+    <ReleaseList>
+        <Release>
+            <ReleaseReference>R1</ReleaseReference>
+            <ReleaseId><ICPN>123456789012</ICPN></ReleaseId>
+            <ReleaseTitle><TitleText>Preview Album</TitleText></ReleaseTitle>
+            <ReleaseType>Album</ReleaseType>
+            <ReleaseResourceReferenceList>
+                <ReleaseResourceReference>A1</ReleaseResourceReference>
+            </ReleaseResourceReferenceList>
+        </Release>
+    </ReleaseList>
+</ern:NewReleaseMessage>"#
+    )
+}
+
+fn assert_preview(preview: &str) {
+    let mut parser = DDEXParser::new();
+    let result = parser.parse(Cursor::new(message(preview).as_bytes())).unwrap();
+
+    // The clip's Duration must not replace the resource's own
+    let resource = &result.graph.resources[0];
+    assert_eq!(resource.duration, Some(Duration::from_secs(210)));
+
+    let expected = Some(Duration::from_secs(30));
+    let graph_preview = resource.technical_details[0].preview.unwrap();
+    assert_eq!(graph_preview.start_point, expected);
+    assert_eq!(graph_preview.duration, expected);
+
+    let flat_preview = result.resources()["A1"].technical_details.preview.unwrap();
+    assert_eq!(flat_preview.start_point, expected);
+    assert_eq!(flat_preview.duration, expected);
+}
+
+#[test]
+fn test_preview_with_relative_duration() {
+    assert_preview(
+        "<PreviewDetails><StartPoint>30</StartPoint><Duration>PT30S</Duration></PreviewDetails>",
+    );
+}
+
+#[test]
+fn test_preview_with_absolute_end_point() {
+    assert_preview(
+        "<PreviewDetails><StartPoint>PT30S</StartPoint><EndPoint>PT1M</EndPoint></PreviewDetails>",
+    );
+}
+
+#[test]
+fn test_ern_4_clip_details() {
+    assert_preview(
+        "<ClipDetails><ClipType>Preview</ClipType><Timing><StartPoint>PT30S</StartPoint><DurationUsed>PT30S</DurationUsed></Timing></ClipDetails>",
+    );
+}
+
+#[test]
+fn test_resource_without_preview() {
+    let mut parser = DDEXParser::new();
+    let result = parser.parse(Cursor::new(message("").as_bytes())).unwrap();
+
+    assert!(result.resources()["A1"].technical_details.preview.is_none());
+}
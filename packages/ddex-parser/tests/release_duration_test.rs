@@ -0,0 +1,85 @@
+// core/tests/release_duration_test.rs
+use ddex_parser::DDEXParser;
+use std::io::Cursor;
+
+fn message(durations: &[Option<&str>]) -> String {
+    let mut resources = String::new();
+    let mut references = String::new();
+    for (i, duration) in durations.iter().enumerate() {
+        let duration = duration
+            .map(|d| format!("<Duration>{}</Duration>", d))
+            .unwrap_or_default();
+        resources.push_str(&format!(
+            r#"
+        <SoundRecording>
+            <ResourceReference>A{i}</ResourceReference>
+            <SoundRecordingId><ISRC>USRC1760700{i}</ISRC></SoundRecordingId>
+            <Title><TitleText>Track {i}</TitleText></Title>
+            {duration}
+        </SoundRecording>"#
+        ));
+        references.push_str(&format!(
+            "<ReleaseResourceReference>A{i}</ReleaseResourceReference>"
+        ));
+    }
+
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/43" MessageSchemaVersionId="ern/43">
+    <MessageHeader>
+        <MessageId>MSG_DURATION_001</MessageId>
+        <MessageSender>
+            <PartyId>PADPIDA_SENDER</PartyId>
+            <PartyName><FullName>Test Label</FullName></PartyName>
+        </MessageSender>
+        <MessageRecipient>
+            <PartyId>PADPIDA_RECIPIENT</PartyId>
+            <PartyName><FullName>Test DSP</FullName></PartyName>
+        </MessageRecipient>
+        <MessageCreatedDateTime>2024-01-01T00:00:00Z</MessageCreatedDateTime>
+    </MessageHeader>
+    <ResourceList>{resources}
+    </ResourceList>
+    <ReleaseList>
+        <Release>
+            <ReleaseReference>R1</ReleaseReference>
+            <ReleaseId><ICPN>123456789012</ICPN></ReleaseId>
+            <ReleaseTitle><TitleText>Album</TitleText></ReleaseTitle>
+            <ReleaseType>Album</ReleaseType>
+            <ReleaseResourceReferenceList>{references}</ReleaseResourceReferenceList>
+        </Release>
+    </ReleaseList>
+</ern:NewReleaseMessage>"#
+    )
+}
+
+#[test]
+fn test_release_total_is_sum_of_track_durations() {
+    let xml = message(&[Some("PT3M30S"), Some("PT4M5S"), Some("PT2M")]);
+
+    let result = DDEXParser::new().parse(Cursor::new(xml.as_bytes())).unwrap();
+    let release = &result.flat.releases[0];
+
+    assert_eq!(release.tracks.len(), 3);
+    assert_eq!(release.total_duration_seconds, 210 + 245 + 120);
+    assert!(!result
+        .warnings
+        .iter()
+        .any(|w| w.code == "MISSING_TRACK_DURATION"));
+}
+
+#[test]
+fn test_track_without_duration_is_excluded_and_flagged() {
+    let xml = message(&[Some("PT3M30S"), None, Some("PT2M")]);
+
+    let result = DDEXParser::new().parse(Cursor::new(xml.as_bytes())).unwrap();
+
+    assert_eq!(result.flat.releases[0].total_duration_seconds, 210 + 120);
+    let missing: Vec<_> = result
+        .warnings
+        .iter()
+        .filter(|w| w.code == "MISSING_TRACK_DURATION")
+        .collect();
+    assert_eq!(missing.len(), 1);
+    assert!(missing[0].message.contains("'A1'"));
+}
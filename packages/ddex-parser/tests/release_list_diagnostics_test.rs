@@ -0,0 +1,96 @@
+// core/tests/release_list_diagnostics_test.rs
+use ddex_parser::parser::release_list::{diagnose_release_list, ReleaseListDiagnostic};
+use ddex_parser::DDEXParser;
+use std::io::Cursor;
+
+fn message(release_list: &str) -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/43" MessageSchemaVersionId="ern/43">
+    <MessageHeader>
+        <MessageId>MSG_RELEASE_LIST_001</MessageId>
+        <MessageSender>
+            <PartyId>PADPIDA_SENDER</PartyId>
+            <PartyName><FullName>Test Label</FullName></PartyName>
+        </MessageSender>
+        <MessageRecipient>
+            <PartyId>PADPIDA_RECIPIENT</PartyId>
+            <PartyName><FullName>Test DSP</FullName></PartyName>
+        </MessageRecipient>
+        <MessageCreatedDateTime>2024-01-01T00:00:00Z</MessageCreatedDateTime>
+    </MessageHeader>
+    <ResourceList>
+        <SoundRecording>
+            <ResourceReference>A1</ResourceReference>
+            <SoundRecordingId><ISRC>USRC17607839</ISRC></SoundRecordingId>
+            <Title><TitleText>Track One</TitleText></Title>
+            <Duration>PT3M30S</Duration>
+        </SoundRecording>
+    </ResourceList>
+    {}
+</ern:NewReleaseMessage>"#,
+        release_list
+    )
+}
+
+fn warning_codes(xml: &str) -> Vec<String> {
+    let result = DDEXParser::new().parse(Cursor::new(xml.as_bytes())).unwrap();
+    assert!(result.flat.releases.is_empty());
+    result.warnings.into_iter().map(|warning| warning.code).collect()
+}
+
+#[test]
+fn test_missing_release_list() {
+    let xml = message("");
+    assert_eq!(
+        diagnose_release_list(Cursor::new(xml.as_bytes())).unwrap(),
+        ReleaseListDiagnostic::MissingReleaseList
+    );
+    assert!(warning_codes(&xml).contains(&"MISSING_RELEASE_LIST".to_string()));
+}
+
+#[test]
+fn test_empty_release_list() {
+    for list in ["<ReleaseList/>", "<ReleaseList>\n    </ReleaseList>"] {
+        let xml = message(list);
+        assert_eq!(
+            diagnose_release_list(Cursor::new(xml.as_bytes())).unwrap(),
+            ReleaseListDiagnostic::EmptyReleaseList
+        );
+        assert!(warning_codes(&xml).contains(&"EMPTY_RELEASE_LIST".to_string()));
+    }
+}
+
+#[test]
+fn test_release_list_present_but_none_parsed() {
+    let xml = message(
+        "<ReleaseList>
+        <CatalogRelease><ReleaseReference>R1</ReleaseReference></CatalogRelease>
+        <CatalogRelease><ReleaseReference>R2</ReleaseReference></CatalogRelease>
+    </ReleaseList>",
+    );
+    assert_eq!(
+        diagnose_release_list(Cursor::new(xml.as_bytes())).unwrap(),
+        ReleaseListDiagnostic::ReleaseListPresentButNoneParsed { elements: 2 }
+    );
+    assert!(warning_codes(&xml).contains(&"RELEASE_LIST_PRESENT_BUT_NONE_PARSED".to_string()));
+}
+
+#[test]
+fn test_no_diagnostic_when_releases_parse() {
+    let xml = message(
+        "<ReleaseList>
+        <Release>
+            <ReleaseReference>R1</ReleaseReference>
+            <ReleaseTitle><TitleText>Album</TitleText></ReleaseTitle>
+            <ReleaseType>Album</ReleaseType>
+            <ReleaseResourceReferenceList>
+                <ReleaseResourceReference>A1</ReleaseResourceReference>
+            </ReleaseResourceReferenceList>
+        </Release>
+    </ReleaseList>",
+    );
+    let result = DDEXParser::new().parse(Cursor::new(xml.as_bytes())).unwrap();
+    assert_eq!(result.flat.releases.len(), 1);
+    assert!(result.warnings.iter().all(|warning| !warning.code.contains("RELEASE_LIST")));
+}
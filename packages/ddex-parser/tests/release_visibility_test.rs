@@ -0,0 +1,73 @@
+// core/tests/release_visibility_test.rs
+use ddex_parser::DDEXParser;
+use std::io::Cursor;
+
+const PREORDER_RELEASE: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/43" MessageSchemaVersionId="ern/43">
+    <MessageHeader>
+        <MessageId>MSG_PREORDER_001</MessageId>
+        <MessageSender>
+            <PartyId>PADPIDA_SENDER</PartyId>
+            <PartyName><FullName>Test Label</FullName></PartyName>
+        </MessageSender>
+        <MessageRecipient>
+            <PartyId>PADPIDA_RECIPIENT</PartyId>
+            <PartyName><FullName>Test DSP</FullName></PartyName>
+        </MessageRecipient>
+        <MessageCreatedDateTime>2024-01-01T00:00:00Z</MessageCreatedDateTime>
+    </MessageHeader>
+    <ResourceList>
+        <SoundRecording>
+            <ResourceReference>A1</ResourceReference>
+            <SoundRecordingId><ISRC>USRC17607839</ISRC></SoundRecordingId>
+            <Title><TitleText>Track One</TitleText></Title>
+            <Duration>PT3M30S</Duration>
+        </SoundRecording>
+    </ResourceList>
+    <ReleaseList>
+        <Release>
+            <ReleaseReference>R1</ReleaseReference>
+            <ReleaseTitle><TitleText>Coming Soon</TitleText></ReleaseTitle>
+            <ReleaseType>Album</ReleaseType>
+            <ReleaseDate>2024-06-07</ReleaseDate>
+            <ReleaseVisibilityReference>V1</ReleaseVisibilityReference>
+            <ReleaseResourceReferenceList>
+                <ReleaseResourceReference>A1</ReleaseResourceReference>
+            </ReleaseResourceReferenceList>
+        </Release>
+    </ReleaseList>
+    <DealList>
+        <ReleaseDeal>
+            <DealReleaseReference>R1</DealReleaseReference>
+            <Deal>
+                <DealTerms>
+                    <TerritoryCode>Worldwide</TerritoryCode>
+                    <ValidityPeriod><StartDate>2024-07-01T00:00:00Z</StartDate></ValidityPeriod>
+                    <CommercialModelType>SubscriptionModel</CommercialModelType>
+                    <UseType>Stream</UseType>
+                </DealTerms>
+            </Deal>
+        </ReleaseDeal>
+        <ReleaseVisibility>
+            <VisibilityReference>V1</VisibilityReference>
+            <ReleaseDisplayStartDateTime>2024-05-01T09:00:00Z</ReleaseDisplayStartDateTime>
+        </ReleaseVisibility>
+    </DealList>
+</ern:NewReleaseMessage>"#;
+
+#[test]
+fn test_preorder_and_availability_dates_are_kept_apart_from_deals() {
+    let mut parser = DDEXParser::new();
+    let result = parser.parse(Cursor::new(PREORDER_RELEASE.as_bytes())).unwrap();
+    let release = &result.releases()[0];
+
+    let preorder = release.preorder_date.expect("pre-order date from ReleaseVisibility");
+    let availability = release.availability_start.expect("availability from ReleaseDate");
+    assert_eq!(preorder.to_rfc3339(), "2024-05-01T09:00:00+00:00");
+    assert_eq!(availability.to_rfc3339(), "2024-06-07T00:00:00+00:00");
+    assert!(preorder < availability);
+
+    // The deal's own validity is a third, unrelated date
+    let deal_start = result.deals()[0].validity.start.unwrap();
+    assert_eq!(deal_start.to_rfc3339(), "2024-07-01T00:00:00+00:00");
+}
@@ -0,0 +1,118 @@
+// core/tests/resource_files_test.rs
+use ddex_parser::DDEXParser;
+use std::io::Cursor;
+
+const XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/43" MessageSchemaVersionId="ern/43">
+    <MessageHeader>
+        <MessageId>MSG_FILES_001</MessageId>
+        <MessageSender>
+            <PartyId>PADPIDA_SENDER</PartyId>
+            <PartyName><FullName>Test Label</FullName></PartyName>
+        </MessageSender>
+        <MessageRecipient>
+            <PartyId>PADPIDA_RECIPIENT</PartyId>
+            <PartyName><FullName>Test DSP</FullName></PartyName>
+        </MessageRecipient>
+        <MessageCreatedDateTime>2024-01-01T00:00:00Z</MessageCreatedDateTime>
+    </MessageHeader>
+    <ResourceList>
+        <SoundRecording>
+            <ResourceReference>A1</ResourceReference>
+            <SoundRecordingId><ISRC>USRC17607839</ISRC></SoundRecordingId>
+            <Title><TitleText>Track One</TitleText></Title>
+            <Duration>PT3M30S</Duration>
+            <TechnicalDetails>
+                <TechnicalResourceDetailsReference>T1</TechnicalResourceDetailsReference>
+                <File>
+                    <FileName>track01.flac</FileName>
+                    <FilePath>resources/</FilePath>
+                    <HashSum>
+                        <Algorithm>MD5</Algorithm>
+                        <HashSumValue>0123456789abcdef0123456789abcdef</HashSumValue>
+                    </HashSum>
+                </File>
+            </TechnicalDetails>
+            <TechnicalDetails>
+                <TechnicalResourceDetailsReference>T2</TechnicalResourceDetailsReference>
+                <File>
+                    <URI>https://cdn.example.com/previews/track01.mp3</URI>
+                </File>
+            </TechnicalDetails>
+        </SoundRecording>
+    </ResourceList>
+    <ReleaseList>
+        <Release>
+            <ReleaseReference>R1</ReleaseReference>
+            <ReleaseId><ICPN>123456789012</ICPN></ReleaseId>
+            <ReleaseTitle><TitleText>Files Album</TitleText></ReleaseTitle>
+            <ReleaseType>Album</ReleaseType>
+            <ReleaseResourceReferenceList>
+                <ReleaseResourceReference>A1</ReleaseResourceReference>
+            </ReleaseResourceReferenceList>
+        </Release>
+    </ReleaseList>
+</ern:NewReleaseMessage>"#;
+
+#[test]
+fn test_resource_files_in_graph() {
+    let mut parser = DDEXParser::new();
+    let result = parser.parse(Cursor::new(XML.as_bytes())).unwrap();
+    let technical = &result.graph.resources[0].technical_details;
+
+    assert_eq!(technical.len(), 2);
+    assert_eq!(technical[0].technical_resource_details_reference, "T1");
+    assert_eq!(technical[1].technical_resource_details_reference, "T2");
+
+    let full = &technical[0].files[0];
+    assert_eq!(full.file_name.as_deref(), Some("track01.flac"));
+    assert_eq!(full.file_path.as_deref(), Some("resources/"));
+    let hash = full.hash_sum.as_ref().unwrap();
+    assert_eq!(hash.algorithm, "MD5");
+    assert_eq!(hash.value, "0123456789abcdef0123456789abcdef");
+
+    let preview = &technical[1].files[0];
+    assert_eq!(
+        preview.uri.as_deref(),
+        Some("https://cdn.example.com/previews/track01.mp3")
+    );
+    assert!(preview.hash_sum.is_none());
+}
+
+#[test]
+fn test_resource_files_in_flat_model() {
+    let mut parser = DDEXParser::new();
+    let result = parser.parse(Cursor::new(XML.as_bytes())).unwrap();
+    let files = &result.resources()["A1"].technical_details.files;
+
+    assert_eq!(files.len(), 2);
+    assert_eq!(files[0].file_name.as_deref(), Some("track01.flac"));
+    assert_eq!(
+        files[0].hash_sum.as_ref().map(|h| h.value.as_str()),
+        Some("0123456789abcdef0123456789abcdef")
+    );
+    assert!(files[1].file_name.is_none());
+}
+
+#[test]
+fn test_ern_38_nested_hash_sum() {
+    let xml = XML
+        .replace(
+            "<Algorithm>MD5</Algorithm>\n                        <HashSumValue>0123456789abcdef0123456789abcdef</HashSumValue>",
+            "<HashSum>fedcba9876543210</HashSum>\n                        <HashSumAlgorithmType>SHA1</HashSumAlgorithmType>",
+        )
+        .replace("<URI>", "<URL>")
+        .replace("</URI>", "</URL>");
+
+    let mut parser = DDEXParser::new();
+    let result = parser.parse(Cursor::new(xml.as_bytes())).unwrap();
+    let files = &result.resources()["A1"].technical_details.files;
+
+    let hash = files[0].hash_sum.as_ref().unwrap();
+    assert_eq!(hash.algorithm, "SHA1");
+    assert_eq!(hash.value, "fedcba9876543210");
+    assert_eq!(
+        files[1].uri.as_deref(),
+        Some("https://cdn.example.com/previews/track01.mp3")
+    );
+}
@@ -0,0 +1,103 @@
+// core/tests/resource_hashes_test.rs
+use ddex_parser::parser::resource_hashes::HashCheckStatus;
+use ddex_parser::DDEXParser;
+use sha2::{Digest, Sha256};
+use std::io::Cursor;
+
+const AUDIO: &[u8] = b"RIFF....WAVEfmt delivered audio";
+
+fn message_declaring(hash: &str) -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/43" MessageSchemaVersionId="ern/43">
+    <MessageHeader>
+        <MessageId>MSG_HASHES_001</MessageId>
+        <MessageSender>
+            <PartyId>PADPIDA_SENDER</PartyId>
+            <PartyName><FullName>Test Label</FullName></PartyName>
+        </MessageSender>
+        <MessageRecipient>
+            <PartyId>PADPIDA_RECIPIENT</PartyId>
+            <PartyName><FullName>Test DSP</FullName></PartyName>
+        </MessageRecipient>
+        <MessageCreatedDateTime>2024-01-01T00:00:00Z</MessageCreatedDateTime>
+    </MessageHeader>
+    <ResourceList>
+        <SoundRecording>
+            <ResourceReference>A1</ResourceReference>
+            <SoundRecordingId><ISRC>USRC17607839</ISRC></SoundRecordingId>
+            <Title><TitleText>Track One</TitleText></Title>
+            <Duration>PT3M30S</Duration>
+            <TechnicalDetails>
+                <TechnicalResourceDetailsReference>T1</TechnicalResourceDetailsReference>
+                <File>
+                    <URI>resources/A1.flac</URI>
+                    <HashSum>
+                        <Algorithm>SHA256</Algorithm>
+                        <HashSumValue>{hash}</HashSumValue>
+                    </HashSum>
+                </File>
+            </TechnicalDetails>
+        </SoundRecording>
+    </ResourceList>
+    <ReleaseList>
+        <Release>
+            <ReleaseReference>R1</ReleaseReference>
+            <ReleaseTitle><TitleText>Hashed</TitleText></ReleaseTitle>
+            <ReleaseType>Album</ReleaseType>
+            <ReleaseResourceReferenceList>
+                <ReleaseResourceReference>A1</ReleaseResourceReference>
+            </ReleaseResourceReferenceList>
+        </Release>
+    </ReleaseList>
+</ern:NewReleaseMessage>"#
+    )
+}
+
+#[test]
+fn test_delivered_file_matching_its_hash_passes() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("A1.flac");
+    std::fs::write(&path, AUDIO).unwrap();
+    let xml = message_declaring(&format!("{:x}", Sha256::digest(AUDIO)));
+
+    let checks = DDEXParser::new()
+        .verify_resource_hashes(Cursor::new(xml.as_bytes()), |location| {
+            (location == "resources/A1.flac").then(|| path.clone())
+        })
+        .unwrap();
+
+    assert_eq!(checks.len(), 1);
+    assert_eq!(checks[0].resource_reference, "A1");
+    assert_eq!(checks[0].file, "resources/A1.flac");
+    assert_eq!(checks[0].algorithm, "SHA256");
+    assert_eq!(checks[0].actual.as_deref(), Some(checks[0].expected.as_str()));
+    assert_eq!(checks[0].status, HashCheckStatus::Match);
+}
+
+#[test]
+fn test_tampered_file_is_a_mismatch() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("A1.flac");
+    std::fs::write(&path, b"RIFF....WAVEfmt tampered audio").unwrap();
+    let xml = message_declaring(&format!("{:x}", Sha256::digest(AUDIO)));
+
+    let checks = DDEXParser::new()
+        .verify_resource_hashes(Cursor::new(xml.as_bytes()), |_| Some(path.clone()))
+        .unwrap();
+
+    assert_eq!(checks[0].status, HashCheckStatus::Mismatch);
+    assert_ne!(checks[0].actual.as_deref(), Some(checks[0].expected.as_str()));
+}
+
+#[test]
+fn test_unresolved_file_is_missing() {
+    let xml = message_declaring(&format!("{:x}", Sha256::digest(AUDIO)));
+
+    let checks = DDEXParser::new()
+        .verify_resource_hashes(Cursor::new(xml.as_bytes()), |_| None)
+        .unwrap();
+
+    assert_eq!(checks[0].status, HashCheckStatus::Missing);
+    assert_eq!(checks[0].actual, None);
+}
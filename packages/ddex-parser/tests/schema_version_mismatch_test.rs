@@ -0,0 +1,79 @@
+// core/tests/schema_version_mismatch_test.rs
+use ddex_core::models::versions::ERNVersion;
+use ddex_parser::error::ParseError;
+use ddex_parser::parser::ParseOptions;
+use ddex_parser::DDEXParser;
+use std::io::Cursor;
+
+fn message(namespace: &str, schema_version_id: &str) -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<NewReleaseMessage xmlns="http://ddex.net/xml/ern/{}" MessageSchemaVersionId="{}">
+    <MessageHeader>
+        <MessageId>MSG_SCHEMA_001</MessageId>
+        <MessageSender>
+            <PartyId>PADPIDA_SENDER</PartyId>
+            <PartyName><FullName>Test Label</FullName></PartyName>
+        </MessageSender>
+        <MessageRecipient>
+            <PartyId>PADPIDA_RECIPIENT</PartyId>
+            <PartyName><FullName>Test DSP</FullName></PartyName>
+        </MessageRecipient>
+        <MessageCreatedDateTime>2024-01-01T00:00:00Z</MessageCreatedDateTime>
+    </MessageHeader>
+    <ReleaseList>
+        <Release>
+            <ReleaseReference>R1</ReleaseReference>
+            <ReleaseId><ICPN>123456789012</ICPN></ReleaseId>
+            <ReleaseTitle><TitleText>Album</TitleText></ReleaseTitle>
+            <ReleaseType>Album</ReleaseType>
+        </Release>
+    </ReleaseList>
+</NewReleaseMessage>"#,
+        namespace, schema_version_id
+    )
+}
+
+#[test]
+fn test_namespace_wins_over_schema_version_id() {
+    let xml = message("43", "ern/42");
+    let mut parser = DDEXParser::new();
+    let result = parser.parse(Cursor::new(xml.as_bytes())).unwrap();
+
+    assert_eq!(result.graph.version, ERNVersion::V4_3);
+
+    let warning = result
+        .warnings
+        .iter()
+        .find(|w| w.code == "SCHEMA_VERSION_MISMATCH")
+        .expect("expected a schema version mismatch warning");
+    assert!(warning.message.contains("4.3"));
+    assert!(warning.message.contains("ern/42"));
+}
+
+#[test]
+fn test_schema_version_mismatch_errors_in_strict_mode() {
+    let xml = message("43", "ern/42");
+    let mut parser = DDEXParser::new();
+    let options = ParseOptions {
+        strict: true,
+        ..Default::default()
+    };
+
+    match parser.parse_with_options(Cursor::new(xml.as_bytes()), options) {
+        Err(ParseError::SchemaVersionMismatch { namespace_version, schema_version_id }) => {
+            assert_eq!(namespace_version, "4.3");
+            assert_eq!(schema_version_id, "ern/42");
+        }
+        other => panic!("Expected SchemaVersionMismatch error, got: {:?}", other),
+    }
+}
+
+#[test]
+fn test_matching_schema_version_id_has_no_warning() {
+    let xml = message("43", "ern/43");
+    let mut parser = DDEXParser::new();
+    let result = parser.parse(Cursor::new(xml.as_bytes())).unwrap();
+
+    assert!(result.warnings.is_empty());
+}
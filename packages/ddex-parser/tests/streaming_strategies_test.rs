@@ -0,0 +1,96 @@
+// core/tests/streaming_strategies_test.rs
+use ddex_parser::DDEXParser;
+use std::io::Cursor;
+
+const AUDIO_ALBUM: &str = include_str!("../../../examples/Samples42/1 Audio.xml");
+
+#[test]
+fn strategies_agree_on_a_sample_album() {
+    let comparison = DDEXParser::new()
+        .compare_streaming_strategies(Cursor::new(AUDIO_ALBUM.as_bytes()))
+        .unwrap();
+
+    let strategies: Vec<&str> = comparison
+        .results
+        .iter()
+        .map(|r| r.strategy.as_str())
+        .collect();
+    assert_eq!(
+        strategies,
+        [
+            "working_impl",
+            "zero_copy",
+            "fast_zero_copy",
+            "parallel",
+            "fast_streaming"
+        ]
+    );
+
+    assert!(comparison.consistent(), "{:#?}", comparison.results);
+    let parsed = DDEXParser::new()
+        .parse(Cursor::new(AUDIO_ALBUM.as_bytes()))
+        .unwrap();
+    assert_eq!(comparison.parsed_releases, parsed.releases().len());
+    assert_eq!(comparison.parsed_resources, parsed.resources().len());
+    for result in &comparison.results {
+        // The album release plus one TrackRelease per track; the cover
+        // image is not a resource to a full parse
+        assert_eq!(result.releases_found, 22, "{}", result.strategy);
+        assert_eq!(result.resources_found, 21, "{}", result.strategy);
+        assert_eq!(result.elements_found(), 43, "{}", result.strategy);
+        assert!(
+            result.throughput_mb_per_sec > 0.0,
+            "{} reported no throughput",
+            result.strategy
+        );
+        assert!(
+            result.peak_memory_bytes > 0,
+            "{} reported no memory",
+            result.strategy
+        );
+    }
+    assert!(comparison.fastest().is_some());
+}
+
+#[test]
+fn strategies_count_videos_but_not_images() {
+    let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/43">
+    <MessageHeader>
+        <MessageId>MSG_STRATEGIES_001</MessageId>
+        <MessageSender>
+            <PartyId>PADPIDA_SENDER</PartyId>
+            <PartyName><FullName>Test Label</FullName></PartyName>
+        </MessageSender>
+        <MessageRecipient>
+            <PartyId>PADPIDA_RECIPIENT</PartyId>
+            <PartyName><FullName>Test DSP</FullName></PartyName>
+        </MessageRecipient>
+        <MessageCreatedDateTime>2024-01-01T00:00:00Z</MessageCreatedDateTime>
+    </MessageHeader>
+    <ResourceList>
+        <Video>
+            <ResourceReference>A1</ResourceReference>
+            <Title><TitleText>Video</TitleText></Title>
+        </Video>
+        <Image>
+            <ResourceReference>A2</ResourceReference>
+        </Image>
+    </ResourceList>
+    <ReleaseList>
+        <Release>
+            <ReleaseReference>R1</ReleaseReference>
+            <ReleaseType>VideoSingle</ReleaseType>
+            <Title><TitleText>Single</TitleText></Title>
+        </Release>
+    </ReleaseList>
+</ern:NewReleaseMessage>"#;
+
+    let comparison = DDEXParser::new()
+        .compare_streaming_strategies(Cursor::new(xml.as_bytes()))
+        .unwrap();
+
+    assert!(comparison.consistent(), "{:#?}", comparison.results);
+    assert_eq!(comparison.results[0].releases_found, 1);
+    assert_eq!(comparison.results[0].resources_found, 1);
+}
@@ -0,0 +1,94 @@
+// core/tests/technical_details_test.rs
+use ddex_parser::DDEXParser;
+use std::io::Cursor;
+
+fn message(technical_details: &str) -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/43" MessageSchemaVersionId="ern/43">
+    <MessageHeader>
+        <MessageId>MSG_TECHNICAL_001</MessageId>
+        <MessageSender>
+            <PartyId>PADPIDA_SENDER</PartyId>
+            <PartyName><FullName>Test Label</FullName></PartyName>
+        </MessageSender>
+        <MessageRecipient>
+            <PartyId>PADPIDA_RECIPIENT</PartyId>
+            <PartyName><FullName>Test DSP</FullName></PartyName>
+        </MessageRecipient>
+        <MessageCreatedDateTime>2024-01-01T00:00:00Z</MessageCreatedDateTime>
+    </MessageHeader>
+    <ResourceList>
+        <SoundRecording>
+            <ResourceReference>A1</ResourceReference>
+            <SoundRecordingId><ISRC>USRC17607839</ISRC></SoundRecordingId>
+            <Title><TitleText>Track One</TitleText></Title>
+            <Duration>PT3M30S</Duration>
+            {technical_details}
+        </SoundRecording>
+    </ResourceList>
+    <ReleaseList>
+        <Release>
+            <ReleaseReference>R1</ReleaseReference>
+            <ReleaseId><ICPN>123456789012</ICPN></ReleaseId>
+            <ReleaseTitle><TitleText>Technical Album</TitleText></ReleaseTitle>
+            <ReleaseType>Album</ReleaseType>
+            <ReleaseResourceReferenceList>
+                <ReleaseResourceReference>A1</ReleaseResourceReference>
+            </ReleaseResourceReferenceList>
+        </Release>
+    </ReleaseList>
+</ern:NewReleaseMessage>"#
+    )
+}
+
+const MP3: &str = r#"<TechnicalDetails>
+                <TechnicalResourceDetailsReference>T1</TechnicalResourceDetailsReference>
+                <AudioCodecType>MP3</AudioCodecType>
+                <BitRate UnitOfMeasure="kbps">320</BitRate>
+                <SamplingRate UnitOfMeasure="kHz">44.1</SamplingRate>
+            </TechnicalDetails>"#;
+
+const FLAC: &str = r#"<TechnicalDetails>
+                <TechnicalResourceDetailsReference>T2</TechnicalResourceDetailsReference>
+                <AudioCodecType>FLAC</AudioCodecType>
+                <BitRate>1411.2</BitRate>
+                <SamplingRate UnitOfMeasure="Hz">96000</SamplingRate>
+                <BitsPerSample>24</BitsPerSample>
+            </TechnicalDetails>"#;
+
+#[test]
+fn test_audio_properties_are_extracted() {
+    let mut parser = DDEXParser::new();
+    let result = parser.parse(Cursor::new(message(MP3).as_bytes())).unwrap();
+
+    let graph = &result.graph.resources[0].technical_details[0];
+    assert_eq!(graph.audio_codec.as_deref(), Some("MP3"));
+    assert_eq!(graph.bitrate, Some(320));
+    assert_eq!(graph.sample_rate, Some(44100));
+    assert_eq!(graph.bits_per_sample, None);
+
+    let flat = &result.resources()["A1"].technical_details;
+    assert_eq!(flat.audio_codec.as_deref(), Some("MP3"));
+    assert_eq!(flat.bitrate, Some(320));
+    assert_eq!(flat.sample_rate, Some(44100));
+}
+
+#[test]
+fn test_first_lossless_block_is_reported() {
+    let mut parser = DDEXParser::new();
+    let xml = message(&format!("{MP3}\n{FLAC}"));
+    let result = parser.parse(Cursor::new(xml.as_bytes())).unwrap();
+
+    assert_eq!(result.graph.resources[0].technical_details.len(), 2);
+
+    let flat = &result.resources()["A1"].technical_details;
+    assert_eq!(flat.audio_codec.as_deref(), Some("FLAC"));
+    assert_eq!(flat.bitrate, Some(1411));
+    assert_eq!(flat.sample_rate, Some(96000));
+    assert_eq!(flat.bits_per_sample, Some(24));
+
+    let track = &result.flat.releases[0].tracks[0];
+    assert_eq!(track.bitrate, Some(1411));
+    assert_eq!(track.sample_rate, Some(96000));
+}
@@ -0,0 +1,113 @@
+// core/tests/territory_normalization_test.rs
+use ddex_parser::error::ParseError;
+use ddex_parser::parser::territories::TerritoryAliases;
+use ddex_parser::parser::ParseOptions;
+use ddex_parser::DDEXParser;
+use std::io::Cursor;
+
+const UK_DEAL: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/43" MessageSchemaVersionId="ern/43">
+    <MessageHeader>
+        <MessageId>MSG_TERRITORY_ALIAS_001</MessageId>
+        <MessageSender>
+            <PartyId>PADPIDA_SENDER</PartyId>
+            <PartyName><FullName>Test Label</FullName></PartyName>
+        </MessageSender>
+        <MessageRecipient>
+            <PartyId>PADPIDA_RECIPIENT</PartyId>
+            <PartyName><FullName>Test DSP</FullName></PartyName>
+        </MessageRecipient>
+        <MessageCreatedDateTime>2024-01-01T00:00:00Z</MessageCreatedDateTime>
+    </MessageHeader>
+    <ResourceList>
+        <SoundRecording>
+            <ResourceReference>A1</ResourceReference>
+            <SoundRecordingId><ISRC>USRC17607839</ISRC></SoundRecordingId>
+            <Title><TitleText>Track One</TitleText></Title>
+            <Duration>PT3M30S</Duration>
+        </SoundRecording>
+    </ResourceList>
+    <ReleaseList>
+        <Release>
+            <ReleaseReference>R1</ReleaseReference>
+            <ReleaseTitle><TitleText>Made In Britain</TitleText></ReleaseTitle>
+            <ReleaseType>Album</ReleaseType>
+            <ReleaseResourceReferenceList>
+                <ReleaseResourceReference>A1</ReleaseResourceReference>
+            </ReleaseResourceReferenceList>
+        </Release>
+    </ReleaseList>
+    <DealList>
+        <ReleaseDeal>
+            <DealReleaseReference>R1</DealReleaseReference>
+            <Deal>
+                <DealTerms>
+                    <TerritoryCode>UK</TerritoryCode>
+                    <TerritoryCode>{extra}</TerritoryCode>
+                    <ValidityPeriod><StartDate>2024-06-07</StartDate></ValidityPeriod>
+                    <CommercialModelType>SubscriptionModel</CommercialModelType>
+                    <UseType>Stream</UseType>
+                </DealTerms>
+            </Deal>
+        </ReleaseDeal>
+    </DealList>
+</ern:NewReleaseMessage>"#;
+
+fn parse(
+    extra: &str,
+    territory_aliases: Option<TerritoryAliases>,
+    strict: bool,
+) -> Result<ddex_core::models::flat::ParsedERNMessage, ParseError> {
+    let xml = UK_DEAL.replace("{extra}", extra);
+    let options = ParseOptions {
+        territory_aliases,
+        strict,
+        ..Default::default()
+    };
+    DDEXParser::new().parse_with_options(Cursor::new(xml.as_bytes()), options)
+}
+
+#[test]
+fn test_uk_is_normalized_to_gb_with_a_warning() {
+    let parsed = parse("IE", Some(TerritoryAliases::default()), false).unwrap();
+
+    assert_eq!(parsed.flat.deals[0].territories.included, ["GB", "IE"]);
+    assert_eq!(
+        parsed.graph.deals[0].deal_terms.territory_code,
+        ["GB", "IE"]
+    );
+    assert!(parsed.availability_map().is_available("R1", "GB"));
+
+    let warnings: Vec<_> = parsed
+        .warnings
+        .iter()
+        .filter(|w| w.code == "TERRITORY_CODE_NORMALIZED")
+        .collect();
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].message.contains("'UK'"));
+}
+
+#[test]
+fn test_codes_are_kept_as_written_unless_asked() {
+    let parsed = parse("IE", None, false).unwrap();
+    assert_eq!(parsed.flat.deals[0].territories.included, ["UK", "IE"]);
+}
+
+#[test]
+fn test_custom_alias_expands_to_several_codes() {
+    let aliases = TerritoryAliases::default().with_alias("ROW", ["US", "CA"]);
+    let parsed = parse("ROW", Some(aliases), true).unwrap();
+    assert_eq!(
+        parsed.flat.deals[0].territories.included,
+        ["GB", "US", "CA"]
+    );
+}
+
+#[test]
+fn test_strict_mode_rejects_unknown_codes() {
+    let lenient = parse("ZZ", Some(TerritoryAliases::default()), false).unwrap();
+    assert_eq!(lenient.flat.deals[0].territories.included, ["GB", "ZZ"]);
+
+    let err = parse("ZZ", Some(TerritoryAliases::default()), true).unwrap_err();
+    assert!(matches!(err, ParseError::InvalidValue { ref value, .. } if value == "ZZ"));
+}
@@ -0,0 +1,55 @@
+// core/tests/territory_release_dates_test.rs
+use ddex_parser::DDEXParser;
+use std::io::Cursor;
+
+const STAGGERED_RELEASE: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/43" MessageSchemaVersionId="ern/43">
+    <MessageHeader>
+        <MessageId>MSG_TERRITORY_DATES_001</MessageId>
+        <MessageSender>
+            <PartyId>PADPIDA_SENDER</PartyId>
+            <PartyName><FullName>Test Label</FullName></PartyName>
+        </MessageSender>
+        <MessageRecipient>
+            <PartyId>PADPIDA_RECIPIENT</PartyId>
+            <PartyName><FullName>Test DSP</FullName></PartyName>
+        </MessageRecipient>
+        <MessageCreatedDateTime>2024-01-01T00:00:00Z</MessageCreatedDateTime>
+    </MessageHeader>
+    <ResourceList>
+        <SoundRecording>
+            <ResourceReference>A1</ResourceReference>
+            <SoundRecordingId><ISRC>USRC17607839</ISRC></SoundRecordingId>
+            <Title><TitleText>Track One</TitleText></Title>
+            <Duration>PT3M30S</Duration>
+        </SoundRecording>
+    </ResourceList>
+    <ReleaseList>
+        <Release>
+            <ReleaseReference>R1</ReleaseReference>
+            <ReleaseTitle><TitleText>Staggered Launch</TitleText></ReleaseTitle>
+            <ReleaseType>Album</ReleaseType>
+            <ReleaseDate>2024-06-07</ReleaseDate>
+            <ReleaseDate ApplicableTerritoryCode="JP">2024-06-19</ReleaseDate>
+            <ReleaseResourceReferenceList>
+                <ReleaseResourceReference>A1</ReleaseResourceReference>
+            </ReleaseResourceReferenceList>
+        </Release>
+    </ReleaseList>
+</ern:NewReleaseMessage>"#;
+
+#[test]
+fn test_global_and_territory_release_dates_are_both_kept() {
+    let mut parser = DDEXParser::new();
+    let result = parser.parse(Cursor::new(STAGGERED_RELEASE.as_bytes())).unwrap();
+    let release = &result.releases()[0];
+
+    let global = release.release_date.expect("global ReleaseDate");
+    assert_eq!(global.to_rfc3339(), "2024-06-07T00:00:00+00:00");
+
+    // The Japan date overrides the global one only in Japan
+    assert_eq!(release.release_dates_by_territory.len(), 1);
+    let japan = release.release_dates_by_territory["JP"];
+    assert_eq!(japan.to_rfc3339(), "2024-06-19T00:00:00+00:00");
+    assert!(global < japan);
+}
@@ -0,0 +1,84 @@
+// core/tests/track_count_mismatch_test.rs
+use ddex_parser::DDEXParser;
+use std::io::Cursor;
+
+/// A release listing `listed` tracks, of which the ResourceList holds the
+/// first `present`, plus a cover image
+fn message(listed: usize, present: usize) -> String {
+    let recordings: String = (1..=present)
+        .map(|n| {
+            format!(
+                r#"
+        <SoundRecording>
+            <ResourceReference>A{}</ResourceReference>
+            <Title><TitleText>Track {}</TitleText></Title>
+            <Duration>PT3M00S</Duration>
+        </SoundRecording>"#,
+                n, n
+            )
+        })
+        .collect();
+    let references: String = (1..=listed)
+        .map(|n| format!("<ReleaseResourceReference>A{}</ReleaseResourceReference>", n))
+        .collect();
+
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/43" MessageSchemaVersionId="ern/43">
+    <MessageHeader>
+        <MessageId>MSG_TRACK_COUNT_001</MessageId>
+        <MessageSender>
+            <PartyId>PADPIDA_SENDER</PartyId>
+            <PartyName><FullName>Test Label</FullName></PartyName>
+        </MessageSender>
+        <MessageRecipient>
+            <PartyId>PADPIDA_RECIPIENT</PartyId>
+            <PartyName><FullName>Test DSP</FullName></PartyName>
+        </MessageRecipient>
+        <MessageCreatedDateTime>2024-01-01T00:00:00Z</MessageCreatedDateTime>
+    </MessageHeader>
+    <ResourceList>{}
+        <Image>
+            <ResourceReference>IMG1</ResourceReference>
+            <Type>FrontCoverImage</Type>
+        </Image>
+    </ResourceList>
+    <ReleaseList>
+        <Release>
+            <ReleaseReference>R1</ReleaseReference>
+            <ReleaseId><ICPN>123456789012</ICPN></ReleaseId>
+            <ReleaseTitle><TitleText>Album</TitleText></ReleaseTitle>
+            <ReleaseType>Album</ReleaseType>
+            <ReleaseResourceReferenceList>{}<ReleaseResourceReference>IMG1</ReleaseResourceReference></ReleaseResourceReferenceList>
+        </Release>
+    </ReleaseList>
+</ern:NewReleaseMessage>"#,
+        recordings, references
+    )
+}
+
+fn mismatch_warnings(xml: &str) -> Vec<String> {
+    DDEXParser::new()
+        .parse(Cursor::new(xml.as_bytes()))
+        .unwrap()
+        .warnings
+        .into_iter()
+        .filter(|w| w.code == "TRACK_COUNT_MISMATCH")
+        .map(|w| w.message)
+        .collect()
+}
+
+#[test]
+fn test_declared_track_count_above_referenced_recordings_is_reported() {
+    let warnings = mismatch_warnings(&message(10, 9));
+
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].contains("'R1'"));
+    assert!(warnings[0].contains("declares 10 tracks"));
+    assert!(warnings[0].contains("only 9 sound recordings"));
+}
+
+#[test]
+fn test_cover_art_is_not_counted_as_a_missing_track() {
+    assert!(mismatch_warnings(&message(9, 9)).is_empty());
+}
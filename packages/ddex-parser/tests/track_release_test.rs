@@ -0,0 +1,77 @@
+// core/tests/track_release_test.rs
+use ddex_parser::DDEXParser;
+use std::io::Cursor;
+
+const ALBUM_WITH_SINGLE: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/43" MessageSchemaVersionId="ern/43">
+    <MessageHeader>
+        <MessageId>MSG_TRACK_RELEASE_001</MessageId>
+        <MessageSender>
+            <PartyId>PADPIDA_SENDER</PartyId>
+            <PartyName><FullName>Test Label</FullName></PartyName>
+        </MessageSender>
+        <MessageRecipient>
+            <PartyId>PADPIDA_RECIPIENT</PartyId>
+            <PartyName><FullName>Test DSP</FullName></PartyName>
+        </MessageRecipient>
+        <MessageCreatedDateTime>2024-01-01T00:00:00Z</MessageCreatedDateTime>
+    </MessageHeader>
+    <ResourceList>
+        <SoundRecording>
+            <ResourceReference>A1</ResourceReference>
+            <SoundRecordingId><ISRC>USRC17607839</ISRC></SoundRecordingId>
+            <Title><TitleText>Opening</TitleText></Title>
+            <Duration>PT3M</Duration>
+        </SoundRecording>
+        <SoundRecording>
+            <ResourceReference>A2</ResourceReference>
+            <SoundRecordingId><ISRC>USRC17607840</ISRC></SoundRecordingId>
+            <Title><TitleText>The Single</TitleText></Title>
+            <Duration>PT4M</Duration>
+        </SoundRecording>
+    </ResourceList>
+    <ReleaseList>
+        <Release>
+            <ReleaseReference>R1</ReleaseReference>
+            <ReleaseId><GRid>A10301A0000000001A</GRid></ReleaseId>
+            <ReleaseTitle><TitleText>Album</TitleText></ReleaseTitle>
+            <ReleaseType>Album</ReleaseType>
+            <ReleaseResourceReferenceList>
+                <ReleaseResourceReference>A1</ReleaseResourceReference>
+                <ReleaseResourceReference>A2</ReleaseResourceReference>
+            </ReleaseResourceReferenceList>
+        </Release>
+        <TrackRelease>
+            <ReleaseReference>R2</ReleaseReference>
+            <ReleaseId><GRid>A10301A0000000002B</GRid></ReleaseId>
+            <DisplayTitleText>The Single</DisplayTitleText>
+            <ReleaseResourceReference>A2</ReleaseResourceReference>
+            <RelatedRelease>
+                <ReleaseRelationshipType>IsReleaseFromRelease</ReleaseRelationshipType>
+                <ReleaseId><GRid>A10301A0000000001A</GRid></ReleaseId>
+            </RelatedRelease>
+        </TrackRelease>
+    </ReleaseList>
+</ern:NewReleaseMessage>"#;
+
+#[test]
+fn test_track_release_links_to_its_album() {
+    let mut parser = DDEXParser::new();
+    let result = parser.parse(Cursor::new(ALBUM_WITH_SINGLE.as_bytes())).unwrap();
+    let releases = result.releases();
+    assert_eq!(releases.len(), 2);
+
+    let album = &releases[0];
+    assert_eq!(album.parent_release, None);
+    assert_eq!(album.child_releases, vec!["R2".to_string()]);
+
+    let single = &releases[1];
+    assert_eq!(single.release_type, "TrackRelease");
+    assert_eq!(single.default_title, "The Single");
+    assert_eq!(single.parent_release.as_deref(), Some("R1"));
+    assert_eq!(single.tracks.len(), 1);
+    assert_eq!(single.tracks[0].title, "The Single");
+
+    // The related release's id must not be taken for the single's own
+    assert_eq!(single.identifiers.grid.as_deref(), Some("A10301A0000000002B"));
+}
@@ -107,8 +107,12 @@ fn test_fixed_technical_details_creation() {
         audio_codec: Some("MP3".to_string()),
         bitrate: Some(320),
         sample_rate: Some(44100),
+        bits_per_sample: None,
         file_format: Some("MP3".to_string()),
         file_size: Some(7200000),
+        files: Vec::new(),
+        preview: None,
+        video: None,
         extensions: None,
     };
 
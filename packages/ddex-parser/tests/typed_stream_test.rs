@@ -0,0 +1,204 @@
+// core/tests/typed_stream_test.rs
+use ddex_parser::error::ParseError;
+use ddex_parser::parser::security::SecurityConfig;
+use ddex_parser::parser::ParseOptions;
+use ddex_parser::streaming::{TypedStreamIterator, TypedStreamingElement};
+use ddex_parser::{DDEXParser, ERNVersion};
+use std::io::Cursor;
+
+const MIXED_MESSAGE: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/43" MessageSchemaVersionId="ern/43">
+    <MessageHeader>
+        <MessageId>MSG_TYPED_001</MessageId>
+        <MessageSender>
+            <PartyId>PADPIDA_SENDER</PartyId>
+            <PartyName><FullName>Test Label</FullName></PartyName>
+        </MessageSender>
+        <MessageRecipient>
+            <PartyId>PADPIDA_RECIPIENT</PartyId>
+            <PartyName><FullName>Test DSP</FullName></PartyName>
+        </MessageRecipient>
+        <MessageCreatedDateTime>2024-01-01T00:00:00Z</MessageCreatedDateTime>
+    </MessageHeader>
+    <ResourceList>
+        <SoundRecording>
+            <ResourceReference>A1</ResourceReference>
+            <SoundRecordingId><ISRC>USRC17607839</ISRC></SoundRecordingId>
+            <Title><TitleText>Track One</TitleText></Title>
+            <Duration>PT3M30S</Duration>
+        </SoundRecording>
+        <Image>
+            <ResourceReference>A2</ResourceReference>
+        </Image>
+        <SoundRecording>
+            <ResourceReference>A3</ResourceReference>
+            <SoundRecordingId><ISRC>USRC17607840</ISRC></SoundRecordingId>
+            <Title><TitleText>Track Two</TitleText></Title>
+            <Duration>PT4M</Duration>
+        </SoundRecording>
+    </ResourceList>
+    <ReleaseList>
+        <Release>
+            <ReleaseReference>R1</ReleaseReference>
+            <ReleaseTitle><TitleText>Typed Album</TitleText></ReleaseTitle>
+            <ReleaseType>Album</ReleaseType>
+            <ReleaseResourceReferenceList>
+                <ReleaseResourceReference>A1</ReleaseResourceReference>
+                <ReleaseResourceReference>A3</ReleaseResourceReference>
+            </ReleaseResourceReferenceList>
+        </Release>
+        <Release>
+            <ReleaseReference>R2</ReleaseReference>
+            <ReleaseTitle><TitleText>Typed Single</TitleText></ReleaseTitle>
+            <ReleaseType>Single</ReleaseType>
+            <ReleaseResourceReferenceList>
+                <ReleaseResourceReference>A3</ReleaseResourceReference>
+            </ReleaseResourceReferenceList>
+        </Release>
+    </ReleaseList>
+    <DealList>
+        <ReleaseDeal>
+            <DealReleaseReference>R1</DealReleaseReference>
+            <Deal>
+                <DealReference>D1</DealReference>
+                <DealTerms>
+                    <TerritoryCode>Worldwide</TerritoryCode>
+                    <UseType>Stream</UseType>
+                </DealTerms>
+            </Deal>
+        </ReleaseDeal>
+    </DealList>
+</ern:NewReleaseMessage>"#;
+
+fn stream(xml: &str) -> Vec<TypedStreamingElement> {
+    TypedStreamIterator::new(Cursor::new(xml.as_bytes()), ERNVersion::V4_3)
+        .collect::<Result<_, _>>()
+        .unwrap()
+}
+
+#[test]
+fn test_typed_elements_follow_document_order() {
+    let sequence: Vec<(&str, String)> = stream(MIXED_MESSAGE)
+        .iter()
+        .map(|element| match element {
+            TypedStreamingElement::Resource(resource) => ("resource", resource.resource_id.clone()),
+            TypedStreamingElement::Release(release) => ("release", release.release_id.clone()),
+            TypedStreamingElement::Deal(deal) => ("deal", deal.deal_id.clone()),
+        })
+        .collect();
+
+    let expected: Vec<(&str, String)> = [
+        ("resource", "A1"),
+        ("resource", "A2"),
+        ("resource", "A3"),
+        ("release", "R1"),
+        ("release", "R2"),
+        ("deal", "D1"),
+    ]
+    .iter()
+    .map(|(kind, id)| (*kind, id.to_string()))
+    .collect();
+    assert_eq!(sequence, expected);
+}
+
+#[test]
+fn test_typed_elements_match_full_parse() {
+    let mut parser = DDEXParser::new();
+    let full = parser.parse(Cursor::new(MIXED_MESSAGE.as_bytes())).unwrap();
+    let elements = stream(MIXED_MESSAGE);
+
+    let TypedStreamingElement::Resource(image) = &elements[1] else {
+        panic!("expected a resource, got {:?}", elements[1]);
+    };
+    assert_eq!(image.resource_type, "Image");
+
+    let TypedStreamingElement::Release(album) = &elements[3] else {
+        panic!("expected a release, got {:?}", elements[3]);
+    };
+    let full_album = &full.releases()[0];
+    assert_eq!(album.default_title, full_album.default_title);
+    assert_eq!(album.release_type, full_album.release_type);
+    assert_eq!(album.tracks.len(), 2);
+    assert_eq!(album.total_duration_seconds, full_album.total_duration_seconds);
+
+    let TypedStreamingElement::Deal(deal) = &elements[5] else {
+        panic!("expected a deal, got {:?}", elements[5]);
+    };
+    let full_deal = &full.deals()[0];
+    assert_eq!(deal.deal_id, full_deal.deal_id);
+    assert_eq!(deal.territories.included, full_deal.territories.included);
+    assert_eq!(deal.usage_rights, full_deal.usage_rights);
+}
+
+#[test]
+fn test_malformed_element_ends_stream_with_error() {
+    let xml = MIXED_MESSAGE.replace(
+        "<Title><TitleText>Track Two</TitleText></Title>",
+        "<Title><TitleText>Track Two</Title>",
+    );
+    let mut iterator = TypedStreamIterator::new(Cursor::new(xml.as_bytes()), ERNVersion::V4_3);
+
+    assert!(matches!(iterator.next(), Some(Ok(TypedStreamingElement::Resource(_)))));
+    assert!(matches!(iterator.next(), Some(Ok(TypedStreamingElement::Resource(_)))));
+    assert!(matches!(iterator.next(), Some(Err(_))));
+    assert!(iterator.next().is_none());
+    assert!(iterator.is_finished());
+}
+
+#[test]
+fn test_resources_are_read_by_their_own_type() {
+    let xml = MIXED_MESSAGE.replace(
+        r#"<Image>
+            <ResourceReference>A2</ResourceReference>
+        </Image>"#,
+        r#"<Video>
+            <ResourceReference>V1</ResourceReference>
+            <Title><TitleText>Music Video</TitleText></Title>
+            <Duration>PT3M</Duration>
+        </Video>
+        <Text>
+            <ResourceReference>X1</ResourceReference>
+            <DisplayTitleText>Liner Notes</DisplayTitleText>
+            <TextType>LinerNotes</TextType>
+        </Text>"#,
+    );
+
+    let resources: Vec<(String, String, String)> = stream(&xml)
+        .into_iter()
+        .filter_map(|element| match element {
+            TypedStreamingElement::Resource(resource) => Some((
+                resource.resource_id,
+                resource.resource_type,
+                resource.title,
+            )),
+            _ => None,
+        })
+        .collect();
+
+    assert_eq!(
+        resources[1..3],
+        [
+            ("V1".to_string(), "Video".to_string(), "Music Video".to_string()),
+            ("X1".to_string(), "Text".to_string(), "Liner Notes".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_security_config_limits_apply() {
+    let security_config = SecurityConfig {
+        max_element_depth: 3,
+        ..SecurityConfig::default()
+    };
+    let mut iterator = TypedStreamIterator::with_options(
+        Cursor::new(MIXED_MESSAGE.as_bytes()),
+        ERNVersion::V4_3,
+        &ParseOptions::default(),
+        &security_config,
+    );
+
+    assert!(matches!(
+        iterator.next(),
+        Some(Err(ParseError::DepthLimitExceeded { limit: 3, .. }))
+    ));
+}
@@ -0,0 +1,86 @@
+// core/tests/unknown_element_warning_test.rs
+use ddex_core::models::flat::ParseWarning;
+use ddex_parser::DDEXParser;
+use std::io::Cursor;
+
+fn message(release_extra: &str) -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/43" xmlns:partner="http://partner.example.com/ddex" MessageSchemaVersionId="ern/43">
+    <MessageHeader>
+        <MessageId>MSG_UNKNOWN_001</MessageId>
+        <MessageSender>
+            <PartyId>PADPIDA_SENDER</PartyId>
+            <PartyName><FullName>Test Label</FullName></PartyName>
+        </MessageSender>
+        <MessageRecipient>
+            <PartyId>PADPIDA_RECIPIENT</PartyId>
+            <PartyName><FullName>Test DSP</FullName></PartyName>
+        </MessageRecipient>
+        <MessageCreatedDateTime>2024-01-01T00:00:00Z</MessageCreatedDateTime>
+    </MessageHeader>
+    <ResourceList>
+        <SoundRecording>
+            <ResourceReference>A1</ResourceReference>
+            <SoundRecordingId><ISRC>USRC17607839</ISRC></SoundRecordingId>
+            <Title><TitleText>Track One</TitleText></Title>
+            <Duration>PT3M30S</Duration>
+        </SoundRecording>
+    </ResourceList>
+    <ReleaseList>
+        <Release>
+            <ReleaseReference>R1</ReleaseReference>
+            <ReleaseId><ICPN>123456789012</ICPN></ReleaseId>
+            <ReleaseTitle><TitleText>Album</TitleText></ReleaseTitle>
+            <ReleaseType>Album</ReleaseType>{}
+            <ReleaseResourceReferenceList>
+                <ReleaseResourceReference>A1</ReleaseResourceReference>
+            </ReleaseResourceReferenceList>
+        </Release>
+    </ReleaseList>
+</ern:NewReleaseMessage>"#,
+        release_extra
+    )
+}
+
+fn unknown_element_warnings(xml: &str) -> Vec<ParseWarning> {
+    DDEXParser::new()
+        .parse(Cursor::new(xml.as_bytes()))
+        .unwrap()
+        .warnings
+        .into_iter()
+        .filter(|w| w.code == "UNKNOWN_ELEMENT")
+        .collect()
+}
+
+#[test]
+fn test_unexpected_child_of_release_is_reported() {
+    let warnings = unknown_element_warnings(&message("\n            <Foo>bar</Foo>"));
+
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].message.contains("'Foo'"), "{}", warnings[0].message);
+    assert!(warnings[0].message.contains("'Release'"), "{}", warnings[0].message);
+    assert_eq!(
+        warnings[0].location.as_deref(),
+        Some("NewReleaseMessage/ReleaseList/Release")
+    );
+}
+
+#[test]
+fn test_extensions_and_standard_children_are_not_reported() {
+    assert!(unknown_element_warnings(&message("")).is_empty());
+
+    let extension = "\n            <partner:Foo>bar</partner:Foo>";
+    assert!(unknown_element_warnings(&message(extension)).is_empty());
+}
+
+#[test]
+fn test_namespace_is_resolved_in_the_element_own_scope() {
+    let redeclared = "\n            <Foo xmlns=\"http://partner.example.com/ddex\">bar</Foo>";
+    assert!(unknown_element_warnings(&message(redeclared)).is_empty());
+
+    let prefixed = "\n            <ern:Foo>bar</ern:Foo>";
+    let warnings = unknown_element_warnings(&message(prefixed));
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].message.contains("'Foo'"), "{}", warnings[0].message);
+}
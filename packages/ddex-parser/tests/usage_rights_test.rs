@@ -0,0 +1,116 @@
+// core/tests/usage_rights_test.rs
+use ddex_core::models::flat::UsageAllowance;
+use ddex_parser::DDEXParser;
+use std::io::Cursor;
+
+const STREAM_NOT_DOWNLOAD: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/43" MessageSchemaVersionId="ern/43">
+    <MessageHeader>
+        <MessageId>MSG_USAGE_RIGHTS_001</MessageId>
+        <MessageSender>
+            <PartyId>PADPIDA_SENDER</PartyId>
+            <PartyName><FullName>Test Label</FullName></PartyName>
+        </MessageSender>
+        <MessageRecipient>
+            <PartyId>PADPIDA_RECIPIENT</PartyId>
+            <PartyName><FullName>Test DSP</FullName></PartyName>
+        </MessageRecipient>
+        <MessageCreatedDateTime>2024-01-01T00:00:00Z</MessageCreatedDateTime>
+    </MessageHeader>
+    <ResourceList>
+        <SoundRecording>
+            <ResourceReference>A1</ResourceReference>
+            <SoundRecordingId><ISRC>USRC17607839</ISRC></SoundRecordingId>
+            <Title><TitleText>Track One</TitleText></Title>
+            <Duration>PT3M30S</Duration>
+        </SoundRecording>
+    </ResourceList>
+    <ReleaseList>
+        <Release>
+            <ReleaseReference>R1</ReleaseReference>
+            <ReleaseTitle><TitleText>Stream Only</TitleText></ReleaseTitle>
+            <ReleaseType>Album</ReleaseType>
+            <ReleaseResourceReferenceList>
+                <ReleaseResourceReference>A1</ReleaseResourceReference>
+            </ReleaseResourceReferenceList>
+        </Release>
+    </ReleaseList>
+    <DealList>
+        <ReleaseDeal>
+            <DealReleaseReference>R1</DealReleaseReference>
+            <Deal>
+                <DealReference>STREAM</DealReference>
+                <DealTerms>
+                    <TerritoryCode>Worldwide</TerritoryCode>
+                    <ValidityPeriod><StartDate>2024-01-01</StartDate></ValidityPeriod>
+                    <CommercialModelType>SubscriptionModel</CommercialModelType>
+                    <CommercialModelType>AdSupportedModel</CommercialModelType>
+                    <UseType>OnDemandStream</UseType>
+                </DealTerms>
+            </Deal>
+        </ReleaseDeal>
+        <ReleaseDeal>
+            <DealReleaseReference>R1</DealReleaseReference>
+            <Deal>
+                <DealReference>NO_DOWNLOAD</DealReference>
+                <DealTerms>
+                    <TerritoryCode>Worldwide</TerritoryCode>
+                    <ValidityPeriod><StartDate>2024-01-01</StartDate></ValidityPeriod>
+                    <TakeDown>true</TakeDown>
+                    <CommercialModelType>PayAsYouGoModel</CommercialModelType>
+                    <UseType>PermanentDownload</UseType>
+                </DealTerms>
+            </Deal>
+        </ReleaseDeal>
+    </DealList>
+</ern:NewReleaseMessage>"#;
+
+#[test]
+fn test_deal_rights_distinguish_allowed_and_disallowed_uses() {
+    let mut parser = DDEXParser::new();
+    let result = parser
+        .parse(Cursor::new(STREAM_NOT_DOWNLOAD.as_bytes()))
+        .unwrap();
+    let deals = &result.flat.deals;
+    assert_eq!(deals.len(), 2);
+
+    let stream: Vec<_> = deals[0]
+        .rights
+        .iter()
+        .map(|right| {
+            (
+                right.use_type.as_str(),
+                right.commercial_model.as_deref(),
+                right.allowance,
+            )
+        })
+        .collect();
+    assert_eq!(
+        stream,
+        [
+            (
+                "OnDemandStream",
+                Some("SubscriptionModel"),
+                UsageAllowance::Allowed
+            ),
+            (
+                "OnDemandStream",
+                Some("AdSupportedModel"),
+                UsageAllowance::Allowed
+            ),
+        ]
+    );
+    assert_eq!(deals[0].usage_rights, ["OnDemandStream"]);
+    assert!(deals[0].restrictions.is_empty());
+
+    let download = &deals[1].rights;
+    assert_eq!(download.len(), 1);
+    assert_eq!(download[0].use_type, "PermanentDownload");
+    assert_eq!(
+        download[0].commercial_model.as_deref(),
+        Some("PayAsYouGoModel")
+    );
+    assert_eq!(download[0].allowance, UsageAllowance::Disallowed);
+    assert!(deals[1].usage_rights.is_empty());
+    assert_eq!(deals[1].restrictions, ["PermanentDownload"]);
+}
@@ -0,0 +1,81 @@
+// core/tests/version_title_test.rs
+use ddex_parser::DDEXParser;
+use std::io::Cursor;
+
+const WITH_VERSIONS: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/43" MessageSchemaVersionId="ern/43">
+    <MessageHeader>
+        <MessageId>MSG_VERSIONS</MessageId>
+        <MessageSender>
+            <PartyId>PADPIDA_SENDER</PartyId>
+            <PartyName><FullName>Test Label</FullName></PartyName>
+        </MessageSender>
+        <MessageRecipient>
+            <PartyId>PADPIDA_RECIPIENT</PartyId>
+            <PartyName><FullName>Test DSP</FullName></PartyName>
+        </MessageRecipient>
+        <MessageCreatedDateTime>2024-01-01T00:00:00Z</MessageCreatedDateTime>
+    </MessageHeader>
+    <ResourceList>
+        <SoundRecording>
+            <ResourceReference>A1</ResourceReference>
+            <SoundRecordingId><ISRC>USRC17607839</ISRC></SoundRecordingId>
+            <Title><TitleText>Song</TitleText></Title>
+        </SoundRecording>
+        <SoundRecording>
+            <ResourceReference>A2</ResourceReference>
+            <SoundRecordingId><ISRC>USRC17607840</ISRC></SoundRecordingId>
+            <Title>
+                <TitleText>Song</TitleText>
+                <SubTitle>Live at Wembley</SubTitle>
+            </Title>
+            <DisplayTitle>
+                <TitleText>Song</TitleText>
+                <SubTitle>Live at Wembley</SubTitle>
+            </DisplayTitle>
+        </SoundRecording>
+    </ResourceList>
+    <ReleaseList>
+        <Release>
+            <ReleaseReference>R1</ReleaseReference>
+            <ReferenceTitle>
+                <TitleText>Greatest Hits</TitleText>
+                <SubTitle>Deluxe Edition</SubTitle>
+            </ReferenceTitle>
+            <ReleaseType>Album</ReleaseType>
+            <ReleaseResourceReferenceList>
+                <ReleaseResourceReference>A1</ReleaseResourceReference>
+                <ReleaseResourceReference>A2</ReleaseResourceReference>
+            </ReleaseResourceReferenceList>
+        </Release>
+    </ReleaseList>
+</ern:NewReleaseMessage>"#;
+
+#[test]
+fn test_version_subtitle_is_kept_apart_from_the_title() {
+    let parsed = DDEXParser::new()
+        .parse(Cursor::new(WITH_VERSIONS.as_bytes()))
+        .unwrap();
+
+    let tracks = &parsed.flat.releases[0].tracks;
+    assert_eq!(tracks[0].title, "Song");
+    assert_eq!(tracks[0].subtitle, None);
+    assert_eq!(tracks[1].title, "Song");
+    assert_eq!(tracks[1].subtitle.as_deref(), Some("Live at Wembley"));
+
+    // Given in both Title and DisplayTitle, the version is kept once
+    let live = &parsed.graph.resources[1];
+    assert_eq!(live.subtitle.len(), 1);
+    assert_eq!(live.subtitle[0].text, "Live at Wembley");
+}
+
+#[test]
+fn test_release_subtitle_is_extracted() {
+    let parsed = DDEXParser::new()
+        .parse(Cursor::new(WITH_VERSIONS.as_bytes()))
+        .unwrap();
+
+    let release = &parsed.flat.releases[0];
+    assert_eq!(release.default_title, "Greatest Hits");
+    assert_eq!(release.default_subtitle.as_deref(), Some("Deluxe Edition"));
+}
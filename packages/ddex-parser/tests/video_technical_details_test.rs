@@ -0,0 +1,85 @@
+// core/tests/video_technical_details_test.rs
+use ddex_core::models::graph::ResourceType;
+use ddex_parser::DDEXParser;
+use std::io::Cursor;
+
+const WITH_VIDEO: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/43" MessageSchemaVersionId="ern/43">
+    <MessageHeader>
+        <MessageId>MSG_VIDEO</MessageId>
+        <MessageSender>
+            <PartyId>PADPIDA_SENDER</PartyId>
+            <PartyName><FullName>Test Label</FullName></PartyName>
+        </MessageSender>
+        <MessageRecipient>
+            <PartyId>PADPIDA_RECIPIENT</PartyId>
+            <PartyName><FullName>Test DSP</FullName></PartyName>
+        </MessageRecipient>
+        <MessageCreatedDateTime>2024-01-01T00:00:00Z</MessageCreatedDateTime>
+    </MessageHeader>
+    <ResourceList>
+        <SoundRecording>
+            <ResourceReference>A1</ResourceReference>
+            <SoundRecordingId><ISRC>USRC17607839</ISRC></SoundRecordingId>
+            <Title><TitleText>Song</TitleText></Title>
+        </SoundRecording>
+        <Video>
+            <ResourceReference>A2</ResourceReference>
+            <VideoId><ISRC>USRC17607840</ISRC></VideoId>
+            <Title><TitleText>Song (Official Video)</TitleText></Title>
+            <Duration>PT3M30S</Duration>
+            <TechnicalVideoDetails>
+                <TechnicalResourceDetailsReference>T2</TechnicalResourceDetailsReference>
+                <VideoCodecType>H.264</VideoCodecType>
+                <VideoBitRate UnitOfMeasure="kbps">8000</VideoBitRate>
+                <FrameRate UnitOfMeasure="Hz">30</FrameRate>
+                <ImageHeight UnitOfMeasure="Pixel">1080</ImageHeight>
+                <ImageWidth UnitOfMeasure="Pixel">1920</ImageWidth>
+                <AspectRatio>16:9</AspectRatio>
+                <File><URI>video/A2.mp4</URI></File>
+            </TechnicalVideoDetails>
+        </Video>
+    </ResourceList>
+    <ReleaseList>
+        <Release>
+            <ReleaseReference>R1</ReleaseReference>
+            <ReferenceTitle><TitleText>Song</TitleText></ReferenceTitle>
+            <ReleaseType>VideoSingle</ReleaseType>
+            <ReleaseResourceReferenceList>
+                <ReleaseResourceReference>A1</ReleaseResourceReference>
+                <ReleaseResourceReference>A2</ReleaseResourceReference>
+            </ReleaseResourceReferenceList>
+        </Release>
+    </ReleaseList>
+</ern:NewReleaseMessage>"#;
+
+#[test]
+fn test_video_technical_details_are_extracted() {
+    let parsed = DDEXParser::new()
+        .parse(Cursor::new(WITH_VIDEO.as_bytes()))
+        .unwrap();
+
+    assert_eq!(parsed.graph.resources[1].resource_type, ResourceType::Video);
+
+    let video = &parsed.resources()["A2"];
+    assert_eq!(video.resource_type, "Video");
+    assert_eq!(video.title, "Song (Official Video)");
+    assert_eq!(video.technical_details.files.len(), 1);
+
+    let details = video.video_details.as_ref().unwrap();
+    assert_eq!(details.width, Some(1920));
+    assert_eq!(details.height, Some(1080));
+    assert_eq!(details.frame_rate, Some(30.0));
+    assert_eq!(details.codec.as_deref(), Some("H.264"));
+    assert_eq!(details.aspect_ratio.as_deref(), Some("16:9"));
+    assert_eq!(details.bitrate, Some(8000));
+}
+
+#[test]
+fn test_sound_recordings_have_no_video_details() {
+    let parsed = DDEXParser::new()
+        .parse(Cursor::new(WITH_VIDEO.as_bytes()))
+        .unwrap();
+
+    assert!(parsed.resources()["A1"].video_details.is_none());
+}
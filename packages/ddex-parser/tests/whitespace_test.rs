@@ -0,0 +1,90 @@
+// core/tests/whitespace_test.rs
+use ddex_parser::parser::whitespace::WhitespaceMode;
+use ddex_parser::parser::ParseOptions;
+use ddex_parser::DDEXParser;
+use std::io::Cursor;
+
+const XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ern:NewReleaseMessage xmlns:ern="http://ddex.net/xml/ern/43" MessageSchemaVersionId="ern/43">
+    <MessageHeader>
+        <MessageId>
+            MSG_WHITESPACE_001
+        </MessageId>
+        <MessageSender>
+            <PartyId>PADPIDA_SENDER</PartyId>
+            <PartyName><FullName>  Test   Label  </FullName></PartyName>
+        </MessageSender>
+        <MessageRecipient>
+            <PartyId>PADPIDA_RECIPIENT</PartyId>
+            <PartyName><FullName>Test DSP</FullName></PartyName>
+        </MessageRecipient>
+        <MessageCreatedDateTime>2024-01-01T00:00:00Z</MessageCreatedDateTime>
+    </MessageHeader>
+    <ResourceList>
+        <SoundRecording>
+            <ResourceReference>
+                A1
+            </ResourceReference>
+            <SoundRecordingId><ISRC>USRC17607839</ISRC></SoundRecordingId>
+            <Title><TitleText>
+                Track   One
+            </TitleText></Title>
+            <Duration>PT3M30S</Duration>
+        </SoundRecording>
+    </ResourceList>
+    <ReleaseList>
+        <Release>
+            <ReleaseReference>R1</ReleaseReference>
+            <ReleaseId><ICPN>123456789012</ICPN></ReleaseId>
+            <ReleaseTitle><TitleText>
+                Spaced   Album
+            </TitleText></ReleaseTitle>
+            <ReleaseType> Album </ReleaseType>
+        </Release>
+    </ReleaseList>
+</ern:NewReleaseMessage>"#;
+
+const RAW_TITLE: &str = "\n                Spaced   Album\n            ";
+
+fn parse(whitespace: WhitespaceMode) -> ddex_core::models::flat::ParsedERNMessage {
+    let options = ParseOptions {
+        whitespace,
+        ..Default::default()
+    };
+    DDEXParser::new()
+        .parse_with_options(Cursor::new(XML.as_bytes()), options)
+        .unwrap()
+}
+
+#[test]
+fn test_trim_is_default() {
+    let result = DDEXParser::new().parse(Cursor::new(XML.as_bytes())).unwrap();
+
+    assert_eq!(result.flat.releases[0].default_title, "Spaced   Album");
+    assert_eq!(result.resources()["A1"].title, "Track   One");
+    assert_eq!(result.flat.sender.name, "Test   Label");
+}
+
+#[test]
+fn test_preserve_keeps_surrounding_newlines() {
+    let result = parse(WhitespaceMode::Preserve);
+    let release = &result.flat.releases[0];
+
+    assert_eq!(release.default_title, RAW_TITLE);
+    assert_eq!(release.title[0].text, RAW_TITLE);
+    assert_eq!(result.flat.sender.name, "  Test   Label  ");
+
+    // Identifiers and codes are still trimmed
+    assert_eq!(result.flat.message_id, "MSG_WHITESPACE_001");
+    assert_eq!(release.release_type, "Album");
+    assert!(result.resources().contains_key("A1"));
+}
+
+#[test]
+fn test_collapse_normalizes_internal_runs() {
+    let result = parse(WhitespaceMode::Collapse);
+
+    assert_eq!(result.flat.releases[0].default_title, "Spaced Album");
+    assert_eq!(result.resources()["A1"].title, "Track One");
+    assert_eq!(result.flat.sender.name, "Test Label");
+}